@@ -1,12 +1,8 @@
 use colored::{Color, Colorize};
 use phf::{phf_set, Set};
 use regex::{Captures, Regex};
-use rustyline::{
-    error::ReadlineError,
-    highlight::Highlighter,
-    validate::{MatchingBracketValidator, ValidationContext, ValidationResult, Validator},
-};
-use rustyline_derive::{Completer, Helper, Hinter};
+use rustyline::highlight::Highlighter;
+use rustyline_derive::{Completer, Helper, Hinter, Validator};
 use std::borrow::Cow;
 
 const STRING_COLOR: Color = Color::Green;
@@ -34,10 +30,9 @@ const IDENTIFIER_COLOR: Color = Color::TrueColor {
 };
 
 #[allow(clippy::upper_case_acronyms)]
-#[derive(Completer, Helper, Hinter)]
+#[derive(Completer, Helper, Hinter, Validator)]
 pub(crate) struct RLHelper {
     highlighter: LineHighlighter,
-    validator: MatchingBracketValidator,
 }
 
 impl RLHelper {
@@ -45,24 +40,10 @@ impl RLHelper {
     pub(crate) fn new() -> Self {
         Self {
             highlighter: LineHighlighter,
-            validator: MatchingBracketValidator::new(),
         }
     }
 }
 
-impl Validator for RLHelper {
-    fn validate(
-        &self,
-        context: &mut ValidationContext<'_>,
-    ) -> Result<ValidationResult, ReadlineError> {
-        self.validator.validate(context)
-    }
-
-    fn validate_while_typing(&self) -> bool {
-        self.validator.validate_while_typing()
-    }
-}
-
 impl Highlighter for RLHelper {
     fn highlight_hint<'h>(&self, hint: &'h str) -> Cow<'h, str> {
         hint.into()
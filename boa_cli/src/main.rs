@@ -59,12 +59,28 @@
     rustdoc::missing_doc_code_examples
 )]
 
-use boa_engine::{syntax::ast::node::StatementList, Context};
+use boa_engine::{
+    context::Completion,
+    syntax::{
+        ast::{node::StatementList, Position},
+        lexer::{Error as LexError, TokenStream},
+        parser::{ParseError, Parser as JsParser},
+    },
+    Context, JsValue,
+};
+use boa_profiler::Profiler;
 use clap::{ArgEnum, Parser};
 use colored::{Color, Colorize};
 use rustyline::{config::Config, error::ReadlineError, EditMode, Editor};
-use std::{fs::read, fs::OpenOptions, io, path::PathBuf};
+use std::{
+    fs::read,
+    fs::OpenOptions,
+    io::{self, Read},
+    path::{Path, PathBuf},
+    process,
+};
 mod helper;
+mod promise;
 
 #[cfg(all(target_arch = "x86_64", target_os = "linux", target_env = "gnu"))]
 #[cfg_attr(
@@ -78,14 +94,17 @@ static CLI_HISTORY: &str = ".boa_history";
 
 const READLINE_COLOR: Color = Color::Cyan;
 
+/// The prompt used while the REPL waits for the rest of an incomplete input.
+const CONTINUATION_PROMPT: &str = "... ";
+
 // Added #[allow(clippy::option_option)] because to StructOpt an Option<Option<T>>
 // is an optional argument that optionally takes a value ([--opt=[val]]).
 // https://docs.rs/structopt/0.3.11/structopt/#type-magic
-#[allow(clippy::option_option)]
+#[allow(clippy::option_option, clippy::struct_excessive_bools)]
 #[derive(Debug, Parser)]
 #[clap(author, version, about, name = "boa")]
 struct Opt {
-    /// The JavaScript file(s) to be evaluated.
+    /// The JavaScript file(s) to be evaluated, or `-` to read from the standard input.
     #[clap(name = "FILE", parse(from_os_str))]
     files: Vec<PathBuf>,
 
@@ -93,6 +112,15 @@ struct Opt {
     #[clap(long, short = 'a', value_name = "FORMAT", ignore_case = true, arg_enum)]
     dump_ast: Option<Option<DumpFormat>>,
 
+    /// Dump the tokens of the lexer to stdout.
+    #[clap(long)]
+    dump_tokens: bool,
+
+    /// Only check the syntax of the files, exiting with a non-zero code if any of them has a
+    /// syntax error.
+    #[clap(long, conflicts_with_all = &["dump-ast", "dump-tokens"])]
+    check: bool,
+
     /// Dump the AST to stdout with the given format.
     #[clap(long = "trace", short = 't')]
     trace: bool,
@@ -100,12 +128,16 @@ struct Opt {
     /// Use vi mode in the REPL
     #[clap(long = "vi")]
     vi_mode: bool,
+
+    /// Print a summary of the time spent in each profiled event to stderr before exiting.
+    #[clap(long)]
+    profile: bool,
 }
 
 impl Opt {
     /// Returns whether a dump flag has been used.
     fn has_dump_flag(&self) -> bool {
-        self.dump_ast.is_some()
+        self.dump_ast.is_some() || self.dump_tokens
     }
 }
 
@@ -138,14 +170,32 @@ fn parse_tokens<S>(src: S, context: &mut Context) -> Result<StatementList, Strin
 where
     S: AsRef<[u8]>,
 {
-    use boa_engine::syntax::parser::Parser;
-
     let src_bytes = src.as_ref();
-    Parser::new(src_bytes)
+    JsParser::new(src_bytes)
         .parse_all(context)
         .map_err(|e| format!("ParsingError: {e}"))
 }
 
+/// Dumps the tokens of the source to stdout, one token per line.
+fn dump_tokens<S>(src: S, context: &mut Context) -> Result<(), String>
+where
+    S: AsRef<[u8]>,
+{
+    let mut tokens = TokenStream::new(src.as_ref());
+    while let Some(token) = tokens
+        .next(context.interner_mut())
+        .map_err(|e| format!("LexingError: {e}"))?
+    {
+        println!(
+            "{} {:?}",
+            token.span(),
+            context.interner().debug(token.kind())
+        );
+    }
+
+    Ok(())
+}
+
 /// Dumps the AST to stdout with format controlled by the given arguments.
 ///
 /// Returns a error of type String with a error message,
@@ -154,51 +204,215 @@ fn dump<S>(src: S, args: &Opt, context: &mut Context) -> Result<(), String>
 where
     S: AsRef<[u8]>,
 {
+    if args.dump_tokens {
+        dump_tokens(&src, context)?;
+    }
+
     if let Some(ref arg) = args.dump_ast {
         let ast = parse_tokens(src, context)?;
 
         match arg {
-            Some(format) => match format {
-                DumpFormat::Debug => println!("{ast:#?}"),
-                DumpFormat::Json => println!(
+            Some(DumpFormat::Json) => println!(
+                "{}",
+                serde_json::to_string(&ast).expect("could not convert AST to a JSON string")
+            ),
+            Some(DumpFormat::JsonPretty) => {
+                println!(
                     "{}",
-                    serde_json::to_string(&ast).expect("could not convert AST to a JSON string")
-                ),
-                DumpFormat::JsonPretty => {
-                    println!(
-                        "{}",
-                        serde_json::to_string_pretty(&ast)
-                            .expect("could not convert AST to a pretty JSON string")
-                    );
-                }
-            },
+                    serde_json::to_string_pretty(&ast)
+                        .expect("could not convert AST to a pretty JSON string")
+                );
+            }
             // Default ast dumping format.
-            None => println!("{ast:#?}"),
+            Some(DumpFormat::Debug) | None => {
+                println!("{:#?}", context.interner().debug(&ast));
+            }
         }
     }
 
     Ok(())
 }
 
+/// Reads the source code of a file, or of the standard input if the path is `-`.
+fn read_source(path: &Path) -> io::Result<Vec<u8>> {
+    if path == Path::new("-") {
+        let mut buffer = Vec::new();
+        io::stdin().read_to_end(&mut buffer)?;
+        Ok(buffer)
+    } else {
+        read(path)
+    }
+}
+
+/// Returns the name of a source file in messages, which is `<stdin>` for the standard input.
+fn source_name(path: &Path) -> String {
+    if path == Path::new("-") {
+        "<stdin>".to_owned()
+    } else {
+        path.display().to_string()
+    }
+}
+
+/// Prints a syntax error to stderr, followed by the line of the source where it happened with
+/// the location of the error underlined.
+fn report_syntax_error(name: &str, src: &str, error: &ParseError) {
+    eprintln!("{}: {}", "error".red().bold(), error.to_string().bold());
+
+    let (start, end) = match error {
+        ParseError::Expected { span, .. } | ParseError::Unexpected { span, .. } => {
+            (span.start(), Some(span.end()))
+        }
+        ParseError::General { position, .. }
+        | ParseError::Unimplemented { position, .. }
+        | ParseError::Lex {
+            err: LexError::Syntax(_, position),
+        } => (*position, None),
+        ParseError::AbruptEnd => (end_of_source(src), None),
+        ParseError::Lex {
+            err: LexError::IO(e),
+        } if e.kind() == io::ErrorKind::UnexpectedEof => (end_of_source(src), None),
+        ParseError::Lex { .. } => {
+            eprintln!(" {} {name}", "-->".blue().bold());
+            return;
+        }
+    };
+
+    let line_number = start.line_number().to_string();
+    let gutter = " ".repeat(line_number.len());
+    eprintln!(
+        "{gutter}{} {name}:{}:{}",
+        "-->".blue().bold(),
+        start.line_number(),
+        start.column_number()
+    );
+
+    let line = match src.lines().nth(start.line_number() as usize - 1) {
+        Some(line) => line,
+        None => return,
+    };
+    let column = start.column_number() as usize - 1;
+    let width = match end {
+        Some(end) if end.line_number() == start.line_number() => {
+            (end.column_number() as usize).saturating_sub(column + 1)
+        }
+        Some(_) => line.chars().count().saturating_sub(column),
+        None => 1,
+    };
+    let prefix: String = line
+        .chars()
+        .take(column)
+        .map(|c| if c == '\t' { '\t' } else { ' ' })
+        .collect();
+
+    eprintln!("{gutter} {}", "|".blue().bold());
+    eprintln!("{} {line}", format!("{line_number} |").blue().bold());
+    eprintln!(
+        "{gutter} {} {prefix}{}",
+        "|".blue().bold(),
+        "^".repeat(width.max(1)).red().bold()
+    );
+}
+
+/// Returns the position right after the last character of the source.
+fn end_of_source(src: &str) -> Position {
+    let src = src.trim_end();
+    let line = src.lines().count().max(1);
+    let column = src.lines().last().map_or(0, |line| line.chars().count()) + 1;
+    Position::new(line as u32, column as u32)
+}
+
+/// Returns whether the source only failed to parse because it ended too early, like the first
+/// line of a function declaration, so the REPL should keep reading lines.
+fn is_incomplete(src: &str, context: &mut Context) -> bool {
+    match JsParser::new(src.as_bytes()).parse_all(context) {
+        Err(ParseError::AbruptEnd) => true,
+        Err(ParseError::Lex {
+            err: LexError::IO(e),
+        }) => e.kind() == io::ErrorKind::UnexpectedEof,
+        _ => false,
+    }
+}
+
+/// Formats a value thrown by the evaluated code.
+///
+/// Error objects are formatted as `name: message`, followed by their `cause` if they have one,
+/// instead of the debug representation of the object.
+fn uncaught_error(error: &JsValue, context: &mut Context) -> String {
+    let object = match error.as_object() {
+        Some(object) if object.is_error() => object.clone(),
+        _ => return error.display().to_string(),
+    };
+
+    let mut output = match error.to_string(context) {
+        Ok(string) => string.to_string(),
+        Err(_) => return error.display().to_string(),
+    };
+    if let Ok(true) = object.has_own_property("cause", context) {
+        if let Ok(cause) = object.get("cause", context) {
+            output.push_str("\n  [cause]: ");
+            output.push_str(&uncaught_error(&cause, context));
+        }
+    }
+    output
+}
+
 pub fn main() -> Result<(), io::Error> {
     let args = Opt::parse();
 
-    let mut context = Context::default();
+    // The context is dropped before exiting, so its garbage collected values are freed while the
+    // thread is still alive.
+    if !run(&args)? {
+        process::exit(1);
+    }
+
+    Ok(())
+}
+
+/// Runs the given files, or the REPL if there are none, and returns whether they succeeded.
+fn run(args: &Opt) -> Result<bool, io::Error> {
+    let tracker = promise::RejectionTracker::default();
+    let mut context = Context::builder().host_hooks(tracker.clone()).build();
 
     // Trace Output
     context.set_trace(args.trace);
 
+    context.set_profiling(args.profile);
+
+    if args.check {
+        let mut valid = true;
+        for file in &args.files {
+            let buffer = read_source(file)?;
+            if let Err(e) = JsParser::new(buffer.as_slice()).parse_all(&mut context) {
+                let src = String::from_utf8_lossy(&buffer);
+                report_syntax_error(&source_name(file), &src, &e);
+                valid = false;
+            }
+        }
+        return Ok(valid);
+    }
+
+    let mut unhandled_rejections = false;
     for file in &args.files {
-        let buffer = read(file)?;
+        let buffer = read_source(file)?;
 
         if args.has_dump_flag() {
-            if let Err(e) = dump(&buffer, &args, &mut context) {
+            if let Err(e) = dump(&buffer, args, &mut context) {
                 eprintln!("{e}");
             }
         } else {
             match context.eval(&buffer) {
                 Ok(v) => println!("{}", v.display()),
-                Err(v) => eprintln!("Uncaught {}", v.display()),
+                Err(e) => {
+                    let e = e.to_opaque(&mut context);
+                    eprintln!("Uncaught {}", uncaught_error(&e, &mut context));
+                }
+            }
+            for reason in tracker.take_unhandled() {
+                eprintln!(
+                    "Uncaught (in promise) {}",
+                    uncaught_error(&reason, &mut context)
+                );
+                unhandled_rejections = true;
             }
         }
     }
@@ -228,31 +442,69 @@ pub fn main() -> Result<(), io::Error> {
         editor.set_helper(Some(helper::RLHelper::new()));
 
         let readline = ">> ".color(READLINE_COLOR).bold().to_string();
+        let continuation = CONTINUATION_PROMPT.color(READLINE_COLOR).bold().to_string();
+
+        // The lines of an input that is not complete yet.
+        let mut input = String::new();
 
         loop {
-            match editor.readline(&readline) {
-                Ok(line) if line == ".exit" => break,
+            let prompt = if input.is_empty() {
+                &readline
+            } else {
+                &continuation
+            };
+
+            match editor.readline(prompt) {
+                Ok(line) if input.is_empty() && line == ".exit" => break,
+
+                // Like in node, an interrupt discards the incomplete input.
+                Err(ReadlineError::Interrupted) if !input.is_empty() => input.clear(),
                 Err(ReadlineError::Interrupted | ReadlineError::Eof) => break,
 
                 Ok(line) => {
-                    editor.add_history_entry(&line);
+                    input.push_str(&line);
+                    input.push('\n');
+
+                    if !args.has_dump_flag() && is_incomplete(&input, &mut context) {
+                        continue;
+                    }
+
+                    let source = input.trim_end();
+                    editor.add_history_entry(source);
 
                     if args.has_dump_flag() {
-                        if let Err(e) = dump(&line, &args, &mut context) {
+                        if let Err(e) = dump(source, args, &mut context) {
                             eprintln!("{e}");
                         }
                     } else {
-                        match context.eval(line.trim_end()) {
+                        // Promises are awaited, like with a top-level `await`. Input whose
+                        // statements don't produce a value, like declarations, prints `undefined`.
+                        let result = match context.eval_with_completion(source) {
+                            Completion::Normal(Some(v)) => tracker.settle(v),
+                            Completion::Normal(None) => Ok(JsValue::undefined()),
+                            Completion::Throw(v) => Err(v),
+                        };
+                        match result {
                             Ok(v) => println!("{}", v.display()),
-                            Err(v) => {
+                            Err(e) => {
+                                let e = e.to_opaque(&mut context);
                                 eprintln!(
                                     "{}: {}",
                                     "Uncaught".red(),
-                                    v.display().to_string().red()
+                                    uncaught_error(&e, &mut context).red()
                                 );
                             }
                         }
+                        for reason in tracker.take_unhandled() {
+                            eprintln!(
+                                "{}: {}",
+                                "Uncaught (in promise)".red(),
+                                uncaught_error(&reason, &mut context).red()
+                            );
+                        }
                     }
+
+                    input.clear();
                 }
 
                 Err(err) => {
@@ -267,5 +519,10 @@ pub fn main() -> Result<(), io::Error> {
             .expect("could not save CLI history");
     }
 
-    Ok(())
+    if args.profile {
+        context.set_profiling(false);
+        eprint!("{}", Profiler::global().report());
+    }
+
+    Ok(!unhandled_rejections)
 }
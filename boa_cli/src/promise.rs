@@ -0,0 +1,75 @@
+use boa_engine::{
+    builtins::promise::PromiseState,
+    context::{HostHooks, OperationType},
+    object::JsObject,
+    Context, JsError, JsResult, JsValue,
+};
+use std::{cell::RefCell, rc::Rc};
+
+/// Host hooks that keep track of the promises that are rejected without a handler.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct RejectionTracker {
+    unhandled: Rc<RefCell<Vec<JsObject>>>,
+}
+
+impl RejectionTracker {
+    /// Marks a rejected promise as handled.
+    fn handle(&self, promise: &JsObject) {
+        self.unhandled
+            .borrow_mut()
+            .retain(|unhandled| !JsObject::equals(unhandled, promise));
+    }
+
+    /// Takes the reasons of the promises that are still rejected without a handler.
+    pub(crate) fn take_unhandled(&self) -> Vec<JsValue> {
+        self.unhandled
+            .take()
+            .iter()
+            .filter_map(|promise| {
+                promise
+                    .borrow()
+                    .as_promise()
+                    .and_then(|promise| promise.result().cloned())
+            })
+            .collect()
+    }
+
+    /// Gets the value a promise settled to, as if the promise was awaited.
+    ///
+    /// All the jobs are run at the end of an evaluation, so a promise that is still pending can
+    /// never settle, and is returned as is. Other values are returned unchanged.
+    pub(crate) fn settle(&self, value: JsValue) -> JsResult<JsValue> {
+        let object = match value.as_object() {
+            Some(object) if object.is_promise() => object.clone(),
+            _ => return Ok(value),
+        };
+
+        let (state, result) = {
+            let object = object.borrow();
+            let promise = object.as_promise().expect("the object must be a promise");
+            (promise.state(), promise.result().cloned())
+        };
+        match (state, result) {
+            (PromiseState::Fulfilled, Some(value)) => Ok(value),
+            (PromiseState::Rejected, Some(reason)) => {
+                self.handle(&object);
+                Err(JsError::from_opaque(reason))
+            }
+            _ => Ok(value),
+        }
+    }
+}
+
+impl HostHooks for RejectionTracker {
+    fn promise_rejection_tracker(
+        &self,
+        promise: &JsObject,
+        operation: OperationType,
+        _context: &mut Context,
+    ) {
+        match operation {
+            OperationType::Reject => self.unhandled.borrow_mut().push(promise.clone()),
+            OperationType::Handle => self.handle(promise),
+        }
+    }
+}
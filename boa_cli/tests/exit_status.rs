@@ -0,0 +1,65 @@
+use std::{
+    io::Write,
+    process::{Command, Output, Stdio},
+};
+
+/// Runs the `boa` binary with the given arguments, passing `source` as the standard input.
+fn boa(args: &[&str], source: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_boa"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("could not run boa");
+    child
+        .stdin
+        .take()
+        .expect("stdin should be piped")
+        .write_all(source.as_bytes())
+        .expect("could not write the source");
+    child.wait_with_output().expect("could not wait for boa")
+}
+
+#[test]
+fn check_valid_source() {
+    let output = boa(&["--check", "-"], "1 + 1");
+    assert_eq!(output.status.code(), Some(0));
+    assert!(output.stderr.is_empty());
+}
+
+#[test]
+fn check_syntax_error() {
+    let output = boa(&["--check", "-"], "let = ;");
+    assert_eq!(output.status.code(), Some(1));
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("error: expected token 'identifier', got '='"),
+        "unexpected stderr: {stderr}"
+    );
+    assert!(
+        stderr.contains(" --> <stdin>:1:5\n  |\n1 | let = ;\n  |     ^\n"),
+        "unexpected stderr: {stderr}"
+    );
+    assert!(!stderr.contains("fatal runtime error"));
+}
+
+#[test]
+fn unhandled_rejection() {
+    let output = boa(&["-"], "Promise.reject(new Error('rejected'));");
+    assert_eq!(output.status.code(), Some(1));
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Uncaught (in promise) Error: rejected"),
+        "unexpected stderr: {stderr}"
+    );
+    assert!(!stderr.contains("fatal runtime error"));
+}
+
+#[test]
+fn handled_rejection() {
+    let output = boa(&["-"], "Promise.reject(1).catch(() => {});");
+    assert_eq!(output.status.code(), Some(0));
+}
@@ -0,0 +1,112 @@
+//! Benchmarks of the hot paths of Boa: parsing real-world code, property access, calls and the
+//! most used builtins.
+//!
+//! Every benchmark has its own name, so a single one can be run by passing its name as a filter:
+//!
+//! ```bash
+//! cargo bench -p boa_engine --bench hot_paths -- "Hot paths/Fibonacci"
+//! ```
+
+use boa_engine::{
+    context::{EvalCacheConfig, ResourceLimits},
+    vm::CodeBlock,
+    Context,
+};
+use boa_gc::Gc;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+#[cfg(all(target_arch = "x86_64", target_os = "linux", target_env = "gnu"))]
+#[cfg_attr(
+    all(target_arch = "x86_64", target_os = "linux", target_env = "gnu"),
+    global_allocator
+)]
+static ALLOC: jemallocator::Jemalloc = jemallocator::Jemalloc;
+
+/// A real-world script of a few hundred KB: the Ace editor.
+static ACE: &str = include_str!("hot_paths/ace.js");
+
+/// Creates the context used by every benchmark.
+///
+/// All the knobs of the engine that affect its performance are set explicitly, so a change of
+/// their defaults doesn't silently change the results.
+fn context() -> Context {
+    let mut context = Context::builder()
+        .resource_limits(ResourceLimits::default())
+        .strict(false)
+        .memory_measurement(false)
+        .build();
+    context.set_trace(false);
+    context.set_profiling(false);
+    context
+}
+
+/// Parses and compiles a script, so that only its execution is measured.
+fn compile(context: &mut Context, code: &str) -> Gc<CodeBlock> {
+    let statement_list = context.parse(code).expect("parsing failed");
+    context
+        .compile(&statement_list)
+        .expect("compilation failed")
+}
+
+fn parse_real_world(c: &mut Criterion) {
+    let mut context = context();
+    c.bench_function("Hot paths/Parse Ace", move |b| {
+        b.iter(|| context.parse(black_box(ACE)).expect("parsing failed"));
+    });
+}
+
+/// A formula of about 200 bytes, like the ones an embedder evaluates over and over.
+static FORMULA: &str = "var price = 19.99, quantity = 3, rate = 0.0825; \
+    var subtotal = price * quantity; \
+    var discount = subtotal > 50 ? subtotal * 0.1 : 0; \
+    var total = (subtotal - discount) * (1 + rate); \
+    Math.round(total * 100) / 100;";
+
+/// Evaluates the same formula 10000 times, with and without the eval cache.
+fn eval_formula(c: &mut Criterion) {
+    for (id, max_entries) in [("uncached", 0), ("cached", 16)] {
+        let mut context = context();
+        context.set_eval_cache(EvalCacheConfig {
+            max_entries,
+            max_source_len: 1024,
+        });
+        c.bench_function(&format!("Hot paths/Eval formula ({id})"), move |b| {
+            b.iter(|| {
+                for _ in 0..10_000 {
+                    context.eval(black_box(FORMULA)).unwrap();
+                }
+            });
+        });
+    }
+}
+
+macro_rules! execution_benchmarks {
+    ($({$id:literal, $name:ident}),*) => {
+        $(
+            fn $name(c: &mut Criterion) {
+                static CODE: &str = include_str!(concat!("hot_paths/", stringify!($name), ".js"));
+                let mut context = context();
+                let code_block = compile(&mut context, CODE);
+                c.bench_function(concat!("Hot paths/", $id), move |b| {
+                    b.iter(|| context.execute(black_box(code_block.clone())).unwrap());
+                });
+            }
+        )*
+
+        criterion_group!(execution, $($name),*);
+    };
+}
+
+execution_benchmarks!(
+    {"Property access and method calls", property_access},
+    {"Primitive property access", primitive_access},
+    {"Array building and iteration", array},
+    {"String concatenation", string_concat},
+    {"RegExp matching", regexp},
+    {"JSON round-trip", json},
+    {"Fibonacci", fibonacci}
+);
+
+criterion_group!(parser, parse_real_world);
+criterion_group!(eval, eval_formula);
+criterion_main!(parser, execution, eval);
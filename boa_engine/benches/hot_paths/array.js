@@ -0,0 +1,16 @@
+(function () {
+  const numbers = [];
+  for (let i = 0; i < 1000; i++) {
+    numbers.push(i);
+  }
+
+  let sum = 0;
+  for (const number of numbers) {
+    sum += number;
+  }
+
+  return numbers
+    .map((number) => number * 2)
+    .filter((number) => number % 3 === 0)
+    .reduce((total, number) => total + number, sum);
+})();
@@ -0,0 +1,17 @@
+(function () {
+  const data = [];
+  for (let i = 0; i < 100; i++) {
+    data.push({
+      id: i,
+      name: "entry " + i,
+      enabled: i % 2 === 0,
+      tags: ["a", "b", "c"],
+      position: { x: i * 1.5, y: -i },
+    });
+  }
+
+  const text = JSON.stringify(data);
+  const parsed = JSON.parse(text);
+
+  return parsed.length + text.length;
+})();
@@ -0,0 +1,10 @@
+(function () {
+  const text = "The quick brown fox jumps over the lazy dog";
+  let hash = 0;
+  for (let i = 0; i < 1000; i++) {
+    const index = i % text.length;
+    hash = (hash + text.charCodeAt(index) + text[index].length) % 65536;
+  }
+
+  return hash.toString(16) + (1.5).toFixed(2) + true.toString();
+})();
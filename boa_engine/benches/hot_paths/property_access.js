@@ -0,0 +1,26 @@
+(function () {
+  class Point {
+    constructor(x, y) {
+      this.x = x;
+      this.y = y;
+    }
+
+    add(other) {
+      return new Point(this.x + other.x, this.y + other.y);
+    }
+
+    get length() {
+      return Math.sqrt(this.x * this.x + this.y * this.y);
+    }
+  }
+
+  const step = { x: 1, y: 2 };
+  let point = new Point(0, 0);
+  let total = 0;
+  for (let i = 0; i < 1000; i++) {
+    point = point.add(step);
+    total += point.length + point["x"] - point.y;
+  }
+
+  return total;
+})();
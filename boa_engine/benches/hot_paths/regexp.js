@@ -0,0 +1,18 @@
+(function () {
+  const text =
+    "The quick brown fox jumps over the lazy dog. 2022-09-01, 2022-10-15 and 2023-01-31. ".repeat(
+      20
+    );
+  const date = /(\d{4})-(\d{2})-(\d{2})/g;
+
+  let count = 0;
+  let match;
+  while ((match = date.exec(text)) !== null) {
+    count += Number(match[2]);
+  }
+
+  const words = text.match(/\b\w+\b/g);
+  const replaced = text.replace(/o/g, "0");
+
+  return count + words.length + replaced.length;
+})();
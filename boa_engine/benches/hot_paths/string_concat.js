@@ -0,0 +1,8 @@
+(function () {
+  let text = "";
+  for (let i = 0; i < 1000; i++) {
+    text += "item " + i + ", ";
+  }
+
+  return text.length;
+})();
@@ -1,6 +1,9 @@
 //! This module implements the JavaScript bigint primitive rust type.
 
-use crate::{builtins::Number, Context, JsValue};
+use crate::{
+    builtins::{string::is_trimmable_whitespace, Number},
+    Context, JsResult,
+};
 use num_integer::Integer;
 use num_traits::{pow::Pow, FromPrimitive, One, ToPrimitive, Zero};
 use std::{
@@ -86,26 +89,39 @@ impl JsBigInt {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-stringtobigint
     #[inline]
-    pub fn from_string(mut string: &str) -> Option<Self> {
-        string = string.trim();
+    pub fn from_string(string: &str) -> Option<Self> {
+        // 1. Let text be StringToCodePoints(str).
+        // 2. Let literal be ParseText(text, StringIntegerLiteral).
+        // 3. If literal is a List of errors, return undefined.
+        let string = string.trim_matches(is_trimmable_whitespace);
 
+        // 4. Let mv be the MV of literal.
+        // 5. Assert: mv is an integer.
+        // 6. Return ℤ(mv).
         if string.is_empty() {
             return Some(Self::zero());
         }
 
-        let mut radix = 10;
-        if string.starts_with("0b") || string.starts_with("0B") {
-            radix = 2;
-            string = &string[2..];
-        } else if string.starts_with("0x") || string.starts_with("0X") {
-            radix = 16;
-            string = &string[2..];
-        } else if string.starts_with("0o") || string.starts_with("0O") {
-            radix = 8;
-            string = &string[2..];
+        let (digits, radix) = match string.get(..2) {
+            Some("0b" | "0B") => (&string[2..], 2),
+            Some("0o" | "0O") => (&string[2..], 8),
+            Some("0x" | "0X") => (&string[2..], 16),
+            _ => (string, 10),
+        };
+
+        // Only decimal literals can be signed, and numeric separators are not allowed.
+        let unsigned = if radix == 10 {
+            digits
+                .strip_prefix(|c| c == '+' || c == '-')
+                .unwrap_or(digits)
+        } else {
+            digits
+        };
+        if unsigned.is_empty() || !unsigned.chars().all(|c| c.is_digit(radix)) {
+            return None;
         }
 
-        Self::from_string_radix(string, radix)
+        Self::from_string_radix(digits, radix)
     }
 
     /// Checks for `SameValueZero` equality.
@@ -148,7 +164,7 @@ impl JsBigInt {
     }
 
     #[inline]
-    pub fn pow(x: &Self, y: &Self, context: &mut Context) -> Result<Self, JsValue> {
+    pub fn pow(x: &Self, y: &Self, context: &mut Context) -> JsResult<Self> {
         let y = if let Some(y) = y.inner.to_biguint() {
             y
         } else {
@@ -168,7 +184,7 @@ impl JsBigInt {
     }
 
     #[inline]
-    pub fn shift_right(x: &Self, y: &Self, context: &mut Context) -> Result<Self, JsValue> {
+    pub fn shift_right(x: &Self, y: &Self, context: &mut Context) -> JsResult<Self> {
         if let Some(n) = y.inner.to_i32() {
             let inner = if n > 0 {
                 x.inner.as_ref().clone().shr(n as usize)
@@ -183,7 +199,7 @@ impl JsBigInt {
     }
 
     #[inline]
-    pub fn shift_left(x: &Self, y: &Self, context: &mut Context) -> Result<Self, JsValue> {
+    pub fn shift_left(x: &Self, y: &Self, context: &mut Context) -> JsResult<Self> {
         if let Some(n) = y.inner.to_i32() {
             let inner = if n > 0 {
                 x.inner.as_ref().clone().shl(n as usize)
@@ -426,21 +442,16 @@ impl PartialEq<JsBigInt> for i32 {
 impl PartialEq<f64> for JsBigInt {
     #[inline]
     fn eq(&self, other: &f64) -> bool {
-        if other.fract() != 0.0 {
-            return false;
-        }
-
-        self.inner.as_ref() == &RawBigInt::from(*other as i64)
+        // Only finite integral numbers can be converted exactly.
+        RawBigInt::from_f64(*other)
+            .filter(|_| other.fract() == 0.0)
+            .map_or(false, |other| *self.inner == other)
     }
 }
 
 impl PartialEq<JsBigInt> for f64 {
     #[inline]
     fn eq(&self, other: &JsBigInt) -> bool {
-        if self.fract() != 0.0 {
-            return false;
-        }
-
-        &RawBigInt::from(*self as i64) == other.inner.as_ref()
+        other == self
     }
 }
@@ -1,9 +1,11 @@
 use crate::{
-    builtins::{function::make_builtin_fn, iterable::create_iter_result_object, Array, JsValue},
+    builtins::{
+        function::make_builtin_fn, iterable::create_iter_result_object_or_throw, Array, JsValue,
+    },
     object::{JsObject, ObjectData},
     property::{PropertyDescriptor, PropertyNameKind},
     symbol::WellKnownSymbols,
-    Context, JsResult,
+    Context, JsNativeError, JsResult,
 };
 use boa_gc::{Finalize, Trace};
 use boa_profiler::Profiler;
@@ -72,14 +74,10 @@ impl ArrayIterator {
         let array_iterator = array_iterator
             .as_mut()
             .and_then(|obj| obj.as_array_iterator_mut())
-            .ok_or_else(|| context.construct_type_error("`this` is not an ArrayIterator"))?;
+            .ok_or_else(|| JsNativeError::typ().with_message("`this` is not an ArrayIterator"))?;
         let index = array_iterator.next_index;
         if array_iterator.done {
-            return Ok(create_iter_result_object(
-                JsValue::undefined(),
-                true,
-                context,
-            ));
+            return create_iter_result_object_or_throw(JsValue::undefined(), true, context);
         }
 
         let len = if let Some(f) = array_iterator.array.borrow().as_typed_array() {
@@ -96,23 +94,21 @@ impl ArrayIterator {
 
         if index >= len {
             array_iterator.done = true;
-            return Ok(create_iter_result_object(
-                JsValue::undefined(),
-                true,
-                context,
-            ));
+            return create_iter_result_object_or_throw(JsValue::undefined(), true, context);
         }
         array_iterator.next_index = index + 1;
         match array_iterator.kind {
-            PropertyNameKind::Key => Ok(create_iter_result_object(index.into(), false, context)),
+            PropertyNameKind::Key => {
+                create_iter_result_object_or_throw(index.into(), false, context)
+            }
             PropertyNameKind::Value => {
                 let element_value = array_iterator.array.get(index, context)?;
-                Ok(create_iter_result_object(element_value, false, context))
+                create_iter_result_object_or_throw(element_value, false, context)
             }
             PropertyNameKind::KeyAndValue => {
                 let element_value = array_iterator.array.get(index, context)?;
-                let result = Array::create_array_from_list([index.into(), element_value], context);
-                Ok(create_iter_result_object(result.into(), false, context))
+                let result = Array::create_array_from_list([index.into(), element_value], context)?;
+                create_iter_result_object_or_throw(result.into(), false, context)
             }
         }
     }
@@ -0,0 +1,488 @@
+//! This module implements the asynchronous part of `Array.fromAsync`.
+//!
+//! The specification describes `Array.fromAsync` as an abstract closure that is run with
+//! `AsyncFunctionStart`, suspending itself on every `Await`. Native functions cannot be
+//! suspended, so the closure is implemented here as a state machine that is resumed by the
+//! reactions of the awaited promises.
+//!
+//! More information:
+//!  - [ECMAScript proposal][spec]
+//!
+//! [spec]: https://tc39.es/proposal-array-from-async/#sec-array.fromAsync
+
+use super::Array;
+use crate::{
+    builtins::{
+        iterable::{AsyncFromSyncIterator, IteratorHint, IteratorRecord, IteratorResult},
+        promise::PromiseCapability,
+        JsArgs, Promise,
+    },
+    object::{FunctionBuilder, JsObject},
+    symbol::WellKnownSymbols,
+    Context, JsError, JsNativeError, JsResult, JsValue,
+};
+use boa_gc::{Cell as GcCell, Finalize, Gc, Trace};
+
+/// Where the values of the new array come from.
+#[derive(Debug, Trace, Finalize)]
+enum Source {
+    /// An asynchronous iterator, or a synchronous one wrapped by `CreateAsyncFromSyncIterator`.
+    Iterator(IteratorRecord),
+
+    /// An array-like object of length `len`.
+    ArrayLike { array_like: JsObject, len: u64 },
+}
+
+/// The `Await` the execution of `Array.fromAsync` is suspended on.
+#[derive(Debug, Clone, Copy)]
+enum Awaiting {
+    /// The result of the `next` method of the iterator.
+    NextResult,
+
+    /// The result of the mapping function, called with a value of the iterator.
+    IteratorMappedValue,
+
+    /// A value of the array-like object.
+    ArrayLikeValue,
+
+    /// The result of the mapping function, called with a value of the array-like object.
+    ArrayLikeMappedValue,
+
+    /// The result of the `return` method of the iterator, called while closing it because of an
+    /// error.
+    IteratorClose,
+}
+
+/// What to do after running `Array.fromAsync` until its next suspension point.
+enum Step {
+    /// Suspends the execution until the value is settled.
+    Await(JsValue, Awaiting),
+
+    /// Finishes the execution, returning the new array.
+    Return(JsObject),
+}
+
+/// The state of a running `Array.fromAsync` call.
+#[derive(Debug, Trace, Finalize)]
+pub(super) struct FromAsync {
+    promise_capability: PromiseCapability,
+    mapping: Option<JsObject>,
+    this_arg: JsValue,
+    a: JsObject,
+    source: Source,
+    k: u64,
+
+    /// The error to reject the promise with once the iterator is closed.
+    error: Option<JsError>,
+}
+
+/// The captures of the reactions that resume the execution of `Array.fromAsync`.
+#[derive(Debug, Trace, Finalize)]
+struct Continuation {
+    state: Gc<GcCell<FromAsync>>,
+    #[unsafe_ignore_trace]
+    awaiting: Awaiting,
+}
+
+impl FromAsync {
+    /// Runs the steps of the `fromAsyncClosure` of `Array.fromAsync`, settling
+    /// `promise_capability` with its result.
+    pub(super) fn start(
+        this: &JsValue,
+        args: &[JsValue],
+        promise_capability: &PromiseCapability,
+        context: &mut Context,
+    ) {
+        match Self::new(this, args, promise_capability.clone(), context) {
+            Ok(mut state) => {
+                let step = state.step(context);
+                Self::drive(Gc::new(GcCell::new(state)), step, context);
+            }
+            Err(error) => {
+                promise_capability
+                    .reject()
+                    .call(&JsValue::undefined(), &[error.to_opaque(context)], context)
+                    .expect("cannot fail per spec");
+            }
+        }
+    }
+
+    /// Runs the steps of the `fromAsyncClosure` that come before its loop.
+    fn new(
+        this: &JsValue,
+        args: &[JsValue],
+        promise_capability: PromiseCapability,
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        let items = args.get_or_undefined(0);
+        let mapfn = args.get_or_undefined(1);
+        let this_arg = args.get_or_undefined(2).clone();
+
+        // a. If mapfn is undefined, let mapping be false.
+        // b. Else,
+        //     i. If IsCallable(mapfn) is false, throw a TypeError exception.
+        //     ii. Let mapping be true.
+        let mapping = match mapfn {
+            JsValue::Undefined => None,
+            JsValue::Object(o) if o.is_callable() => Some(o.clone()),
+            _ => return context.throw_type_error(format!("{} is not a function", mapfn.type_of())),
+        };
+
+        // c. Let usingAsyncIterator be ? GetMethod(asyncItems, @@asyncIterator).
+        let using_async_iterator = items.get_method(WellKnownSymbols::async_iterator(), context)?;
+
+        // d. If usingAsyncIterator is undefined, then
+        //     i. Let usingSyncIterator be ? GetMethod(asyncItems, @@iterator).
+        let using_iterator = if using_async_iterator.is_none() {
+            items.get_method(WellKnownSymbols::iterator(), context)?
+        } else {
+            None
+        };
+
+        // e. If usingAsyncIterator is not undefined or usingSyncIterator is not undefined, then
+        if using_async_iterator.is_some() || using_iterator.is_some() {
+            // i. If IsConstructor(C) is true, then
+            //     1. Let A be ? Construct(C).
+            // ii. Else,
+            //     1. Let A be ! ArrayCreate(0).
+            let a = match this.as_constructor() {
+                Some(constructor) => constructor.construct(&[], None, context)?,
+                _ => Array::array_create(0, None, context)?,
+            };
+
+            let iterator_record = if let Some(method) = using_async_iterator {
+                // iii. If usingAsyncIterator is not undefined, then
+                //     1. Let iteratorRecord be ? GetIterator(asyncItems, async, usingAsyncIterator).
+                items.get_iterator(context, Some(IteratorHint::Async), Some(method.into()))?
+            } else {
+                // iv. Else,
+                //     1. Let iteratorRecord be ? CreateAsyncFromSyncIterator(GetIterator(asyncItems, sync, usingSyncIterator)).
+                let sync_iterator_record = items.get_iterator(
+                    context,
+                    Some(IteratorHint::Sync),
+                    using_iterator.map(JsValue::from),
+                )?;
+                AsyncFromSyncIterator::create(sync_iterator_record, context)
+            };
+
+            // v. Let k be 0.
+            return Ok(Self {
+                promise_capability,
+                mapping,
+                this_arg,
+                a,
+                source: Source::Iterator(iterator_record),
+                k: 0,
+                error: None,
+            });
+        }
+
+        // f. Else,
+        //     i. NOTE: asyncItems is neither an AsyncIterable nor an Iterable so assume it is an array-like object.
+        //     ii. Let arrayLike be ! ToObject(asyncItems).
+        let array_like = items
+            .to_object(context)
+            .expect("should not fail according to spec");
+
+        // iii. Let len be ? LengthOfArrayLike(arrayLike).
+        let len = array_like.length_of_array_like(context)?;
+
+        // iv. If IsConstructor(C) is true, then
+        //     1. Let A be ? Construct(C, « 𝔽(len) »).
+        // v. Else,
+        //     1. Let A be ? ArrayCreate(len).
+        let a = match this.as_constructor() {
+            Some(constructor) => constructor.construct(&[len.into()], None, context)?,
+            _ => Array::array_create(len, None, context)?,
+        };
+
+        // vi. Let k be 0.
+        Ok(Self {
+            promise_capability,
+            mapping,
+            this_arg,
+            a,
+            source: Source::ArrayLike { array_like, len },
+            k: 0,
+            error: None,
+        })
+    }
+
+    /// Runs an iteration of the loop of the `fromAsyncClosure`, until its first `Await`.
+    fn step(&mut self, context: &mut Context) -> JsResult<Step> {
+        match &self.source {
+            Source::Iterator(iterator_record) => {
+                // 1. If k ≥ 2^53 - 1, then
+                if self.k >= 9_007_199_254_740_991 {
+                    // a. Let error be ThrowCompletion(a newly created TypeError object).
+                    let error = JsNativeError::typ()
+                        .with_message("Invalid array length")
+                        .into();
+
+                    // b. Return ? AsyncIteratorClose(iteratorRecord, error).
+                    return self.close(error, context);
+                }
+
+                // 2. Let Pk be ! ToString(𝔽(k)).
+                // 3. Let nextResult be ? Call(iteratorRecord.[[NextMethod]], iteratorRecord.[[Iterator]]).
+                let next_method = iterator_record.next_method().as_callable().ok_or_else(|| {
+                    JsNativeError::typ().with_message("iterable next method not a function")
+                })?;
+                let next_result =
+                    next_method.call(&iterator_record.iterator().clone().into(), &[], context)?;
+
+                // 4. Set nextResult to ? Await(nextResult).
+                Ok(Step::Await(next_result, Awaiting::NextResult))
+            }
+            Source::ArrayLike { array_like, len } => {
+                // vii. Repeat, while k < len,
+                if self.k < *len {
+                    // 1. Let Pk be ! ToString(𝔽(k)).
+                    // 2. Let kValue be ? Get(arrayLike, Pk).
+                    let k_value = array_like.get(self.k, context)?;
+
+                    // 3. Set kValue to ? Await(kValue).
+                    return Ok(Step::Await(k_value, Awaiting::ArrayLikeValue));
+                }
+
+                // viii. Perform ? Set(A, "length", 𝔽(len), true).
+                self.a.set("length", *len, true, context)?;
+
+                // ix. Return Completion Record { [[Type]]: return, [[Value]]: A, [[Target]]: empty }.
+                Ok(Step::Return(self.a.clone()))
+            }
+        }
+    }
+
+    /// Resumes the execution of the `fromAsyncClosure` after `awaiting` was settled with
+    /// `result`, until its next `Await`.
+    fn resume(
+        &mut self,
+        awaiting: Awaiting,
+        result: JsResult<JsValue>,
+        context: &mut Context,
+    ) -> JsResult<Step> {
+        match awaiting {
+            Awaiting::NextResult => {
+                let next_result = result?;
+
+                // 5. If Type(nextResult) is not Object, throw a TypeError exception.
+                let next_result = next_result
+                    .as_object()
+                    .map(|o| IteratorResult::new(o.clone()))
+                    .ok_or_else(|| {
+                        JsNativeError::typ().with_message("iterator result is not an object")
+                    })?;
+
+                // 6. Let done be ? IteratorComplete(nextResult).
+                // 7. If done is true,
+                if next_result.complete(context)? {
+                    // a. Perform ? Set(A, "length", 𝔽(k), true).
+                    self.a.set("length", self.k, true, context)?;
+
+                    // b. Return Completion Record { [[Type]]: return, [[Value]]: A, [[Target]]: empty }.
+                    return Ok(Step::Return(self.a.clone()));
+                }
+
+                // 8. Let nextValue be ? IteratorValue(nextResult).
+                let next_value = next_result.value(context)?;
+
+                // 9. If mapping is true, then
+                if let Some(mapfn) = &self.mapping {
+                    // a. Let mappedValue be Call(mapfn, thisArg, « nextValue, 𝔽(k) »).
+                    // b. IfAbruptCloseAsyncIterator(mappedValue, iteratorRecord).
+                    // c. Set mappedValue to Await(mappedValue).
+                    return match mapfn.call(&self.this_arg, &[next_value, self.k.into()], context) {
+                        Ok(mapped_value) => {
+                            Ok(Step::Await(mapped_value, Awaiting::IteratorMappedValue))
+                        }
+                        Err(error) => self.close(error, context),
+                    };
+                }
+
+                // 10. Else, let mappedValue be nextValue.
+                self.define(next_value, context)
+            }
+            Awaiting::IteratorMappedValue => match result {
+                // d. IfAbruptCloseAsyncIterator(mappedValue, iteratorRecord).
+                Ok(mapped_value) => self.define(mapped_value, context),
+                Err(error) => self.close(error, context),
+            },
+            Awaiting::ArrayLikeValue => {
+                let k_value = result?;
+
+                // 4. If mapping is true, then
+                if let Some(mapfn) = &self.mapping {
+                    // a. Let mappedValue be ? Call(mapfn, thisArg, « kValue, 𝔽(k) »).
+                    let mapped_value =
+                        mapfn.call(&self.this_arg, &[k_value, self.k.into()], context)?;
+
+                    // b. Set mappedValue to ? Await(mappedValue).
+                    return Ok(Step::Await(mapped_value, Awaiting::ArrayLikeMappedValue));
+                }
+
+                // 5. Else, let mappedValue be kValue.
+                self.define(k_value, context)
+            }
+            Awaiting::ArrayLikeMappedValue => self.define(result?, context),
+            Awaiting::IteratorClose => {
+                // The completion passed to AsyncIteratorClose is always a throw completion, so the
+                // result of the `return` method is ignored.
+                Err(self
+                    .error
+                    .take()
+                    .expect("the error must be set while closing the iterator"))
+            }
+        }
+    }
+
+    /// Defines `value` as the element `k` of the new array and moves on to the next iteration.
+    fn define(&mut self, value: JsValue, context: &mut Context) -> JsResult<Step> {
+        // Let defineStatus be CreateDataPropertyOrThrow(A, Pk, mappedValue).
+        if let Err(error) = self.a.create_data_property_or_throw(self.k, value, context) {
+            return match self.source {
+                // If defineStatus is an abrupt completion, return ? AsyncIteratorClose(iteratorRecord, defineStatus).
+                Source::Iterator(_) => self.close(error, context),
+                Source::ArrayLike { .. } => Err(error),
+            };
+        }
+
+        // Set k to k + 1.
+        self.k += 1;
+
+        self.step(context)
+    }
+
+    /// `AsyncIteratorClose ( iteratorRecord, completion )`, for a throw completion.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-asynciteratorclose
+    fn close(&mut self, error: JsError, context: &mut Context) -> JsResult<Step> {
+        let iterator = if let Source::Iterator(iterator_record) = &self.source {
+            iterator_record.iterator().clone()
+        } else {
+            unreachable!("only iterators can be closed")
+        };
+
+        // 3. Let innerResult be Completion(GetMethod(iterator, "return")).
+        // 4. If innerResult.[[Type]] is normal, then
+        //     a. Let return be innerResult.[[Value]].
+        //     b. If return is undefined, return ? completion.
+        //     c. Set innerResult to Completion(Call(return, iterator)).
+        //     d. If innerResult.[[Type]] is normal, set innerResult to Completion(Await(innerResult.[[Value]])).
+        // 5. If completion.[[Type]] is throw, return ? completion.
+        match iterator.get_method("return", context) {
+            Ok(Some(r#return)) => match r#return.call(&iterator.into(), &[], context) {
+                Ok(inner_result) => {
+                    self.error = Some(error);
+                    Ok(Step::Await(inner_result, Awaiting::IteratorClose))
+                }
+                Err(_) => Err(error),
+            },
+            _ => Err(error),
+        }
+    }
+
+    /// Keeps running `Array.fromAsync` from `step`, awaiting the values it is suspended on and
+    /// settling its promise once it finishes.
+    fn drive(state: Gc<GcCell<Self>>, mut step: JsResult<Step>, context: &mut Context) {
+        loop {
+            match step {
+                Ok(Step::Await(value, awaiting)) => {
+                    // `Await ( value )`
+                    // 2. Let promise be ? PromiseResolve(%Promise%, value).
+                    let promise = match Promise::promise_resolve(
+                        context.intrinsics().constructors().promise().constructor(),
+                        value,
+                        context,
+                    ) {
+                        Ok(promise) => promise,
+                        Err(error) => {
+                            step = state.borrow_mut().resume(awaiting, Err(error), context);
+                            continue;
+                        }
+                    };
+
+                    // 3. Let fulfilledClosure be a new Abstract Closure with parameters (value) that captures asyncContext and performs the following steps when called:
+                    // 4. Let onFulfilled be CreateBuiltinFunction(fulfilledClosure, 1, "", « »).
+                    let on_fulfilled = FunctionBuilder::closure_with_captures(
+                        context,
+                        |_this, args, captures: &mut Continuation, context| {
+                            let step = captures.state.borrow_mut().resume(
+                                captures.awaiting,
+                                Ok(args.get_or_undefined(0).clone()),
+                                context,
+                            );
+                            Self::drive(captures.state.clone(), step, context);
+                            Ok(JsValue::undefined())
+                        },
+                        Continuation {
+                            state: state.clone(),
+                            awaiting,
+                        },
+                    )
+                    .name("")
+                    .length(1)
+                    .build();
+
+                    // 5. Let rejectedClosure be a new Abstract Closure with parameters (reason) that captures asyncContext and performs the following steps when called:
+                    // 6. Let onRejected be CreateBuiltinFunction(rejectedClosure, 1, "", « »).
+                    let on_rejected = FunctionBuilder::closure_with_captures(
+                        context,
+                        |_this, args, captures: &mut Continuation, context| {
+                            let step = captures.state.borrow_mut().resume(
+                                captures.awaiting,
+                                Err(JsError::from_opaque(args.get_or_undefined(0).clone())),
+                                context,
+                            );
+                            Self::drive(captures.state.clone(), step, context);
+                            Ok(JsValue::undefined())
+                        },
+                        Continuation { state, awaiting },
+                    )
+                    .name("")
+                    .length(1)
+                    .build();
+
+                    // 7. Perform PerformPromiseThen(promise, onFulfilled, onRejected).
+                    Promise::perform_promise_then(
+                        promise
+                            .as_object()
+                            .expect("PromiseResolve must return a promise"),
+                        &on_fulfilled.into(),
+                        &on_rejected.into(),
+                        None,
+                        context,
+                    );
+                    return;
+                }
+                // AsyncBlockStart ( promiseCapability, asyncBody, asyncContext )
+                // e. If result.[[Type]] is normal, then
+                //     i. Perform ! Call(promiseCapability.[[Resolve]], undefined, « undefined »).
+                // f. Else if result.[[Type]] is return, then
+                //     i. Perform ! Call(promiseCapability.[[Resolve]], undefined, « result.[[Value]] »).
+                Ok(Step::Return(a)) => {
+                    let promise_capability = state.borrow().promise_capability.clone();
+                    promise_capability
+                        .resolve()
+                        .call(&JsValue::undefined(), &[a.into()], context)
+                        .expect("cannot fail per spec");
+                    return;
+                }
+                // g. Else,
+                //     i. Assert: result.[[Type]] is throw.
+                //     ii. Perform ! Call(promiseCapability.[[Reject]], undefined, « result.[[Value]] »).
+                Err(error) => {
+                    let promise_capability = state.borrow().promise_capability.clone();
+                    promise_capability
+                        .reject()
+                        .call(&JsValue::undefined(), &[error.to_opaque(context)], context)
+                        .expect("cannot fail per spec");
+                    return;
+                }
+            }
+        }
+    }
+}
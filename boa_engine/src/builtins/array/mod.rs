@@ -10,22 +10,25 @@
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array
 
 pub mod array_iterator;
+mod from_async;
 #[cfg(test)]
 mod tests;
 
 use boa_profiler::Profiler;
 use tap::{Conv, Pipe};
 
+use self::from_async::FromAsync;
 use super::JsArgs;
 use crate::{
     builtins::array::array_iterator::ArrayIterator,
     builtins::iterable::{if_abrupt_close_iterator, IteratorHint},
+    builtins::promise::PromiseCapability,
     builtins::BuiltIn,
     builtins::Number,
     context::intrinsics::StandardConstructors,
     object::{
-        internal_methods::get_prototype_from_constructor, ConstructorBuilder, FunctionBuilder,
-        JsFunction, JsObject, ObjectData,
+        internal_methods::{check_property_count, get_prototype_from_constructor},
+        ConstructorBuilder, FunctionBuilder, JsFunction, JsObject, ObjectData,
     },
     property::{Attribute, PropertyDescriptor, PropertyNameKind},
     symbol::WellKnownSymbols,
@@ -53,7 +56,7 @@ impl BuiltIn for Array {
             .build();
 
         let values_function = context.intrinsics().objects().array_prototype_values();
-        let unscopables_object = Self::unscopables_intrinsic(context);
+        let unscopables_object = Self::unscopables_intrinsic();
 
         ConstructorBuilder::with_standard_constructor(
             context,
@@ -120,8 +123,13 @@ impl BuiltIn for Array {
         .method(Self::keys, "keys", 0)
         .method(Self::entries, "entries", 0)
         .method(Self::copy_within, "copyWithin", 2)
+        .method(Self::to_reversed, "toReversed", 0)
+        .method(Self::to_sorted, "toSorted", 1)
+        .method(Self::to_spliced, "toSpliced", 2)
+        .method(Self::with, "with", 2)
         // Static Methods
         .static_method(Self::from, "from", 1)
+        .static_method(Self::from_async, "fromAsync", 1)
         .static_method(Self::is_array, "isArray", 1)
         .static_method(Self::of, "of", 0)
         .build()
@@ -163,9 +171,7 @@ impl Array {
             #[allow(clippy::if_not_else)]
             let int_len = if !len.is_number() {
                 // i. Perform ! CreateDataPropertyOrThrow(array, "0", len).
-                array
-                    .create_data_property_or_throw(0, len, context)
-                    .expect("this CreateDataPropertyOrThrow call must not fail");
+                array.create_data_property_or_throw(0, len, context)?;
                 // ii. Let intLen be 1𝔽.
                 1
             // d. Else,
@@ -178,6 +184,12 @@ impl Array {
                 if !JsValue::same_value_zero(&int_len.into(), len) {
                     return context.throw_range_error("invalid array length");
                 }
+                let max_length = context.resource_limits().max_array_length();
+                if u64::from(int_len) > max_length {
+                    return context.throw_range_error(format!(
+                        "array length exceeds the maximum array length ({max_length})"
+                    ));
+                }
                 int_len
             };
             // e. Perform ! Set(array, "length", intLen, true).
@@ -199,9 +211,8 @@ impl Array {
                 // i. Let Pk be ! ToString(𝔽(k)).
                 // ii. Let itemK be values[k].
                 // iii. Perform ! CreateDataPropertyOrThrow(array, Pk, itemK).
-                array
-                    .create_data_property_or_throw(i, item, context)
-                    .expect("this CreateDataPropertyOrThrow must not fail");
+                // This can only fail if the resource limits of the context are exceeded.
+                array.create_data_property_or_throw(i, item, context)?;
                 // iv. Set k to k + 1.
             }
             // e. Assert: The mathematical value of array's "length" property is numberOfArgs.
@@ -225,6 +236,12 @@ impl Array {
         if length > 2u64.pow(32) - 1 {
             return context.throw_range_error("array exceeded max size");
         }
+        let max_length = context.resource_limits().max_array_length();
+        if length > max_length {
+            return context.throw_range_error(format!(
+                "array length exceeds the maximum array length ({max_length})"
+            ));
+        }
         // 7. Return A.
         // 2. If proto is not present, set proto to %Array.prototype%.
         // 3. Let A be ! MakeBasicObject(« [[Prototype]], [[Extensible]] »).
@@ -237,31 +254,53 @@ impl Array {
         let array = JsObject::from_proto_and_data(prototype, ObjectData::array());
 
         // 6. Perform ! OrdinaryDefineOwnProperty(A, "length", PropertyDescriptor { [[Value]]: 𝔽(length), [[Writable]]: true, [[Enumerable]]: false, [[Configurable]]: false }).
-        crate::object::internal_methods::ordinary_define_own_property(
-            &array,
-            "length".into(),
+        //
+        // NOTE: The property is inserted directly, since the `length` of a new array isn't
+        // counted against the maximum property count.
+        array.insert(
+            "length",
             PropertyDescriptor::builder()
                 .value(length)
                 .writable(true)
                 .enumerable(false)
-                .configurable(false)
-                .build(),
-            context,
-        )?;
+                .configurable(false),
+        );
 
         Ok(array)
     }
 
     /// Utility for constructing `Array` objects from an iterator of `JsValue`s.
     ///
+    /// Throws a `RangeError` if the array exceeds the maximum property count of the
+    /// [`ResourceLimits`][crate::context::ResourceLimits].
+    ///
     /// More information:
     ///  - [ECMAScript reference][spec]
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-createarrayfromlist
-    pub(crate) fn create_array_from_list<I>(elements: I, context: &mut Context) -> JsObject
+    pub(crate) fn create_array_from_list<I>(
+        elements: I,
+        context: &mut Context,
+    ) -> JsResult<JsObject>
     where
         I: IntoIterator<Item = JsValue>,
     {
+        let elements: Vec<_> = elements.into_iter().collect();
+
+        // NOTE: Like in `ArrayCreate`, the `length` of the array isn't counted.
+        check_property_count(elements.len(), context)?;
+
+        Ok(Self::create_array_from_vec(elements, context))
+    }
+
+    /// Creates an `Array` object with the given elements, without checking the
+    /// [`ResourceLimits`][crate::context::ResourceLimits].
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-createarrayfromlist
+    pub(crate) fn create_array_from_vec(elements: Vec<JsValue>, context: &mut Context) -> JsObject {
         // 1. Assert: elements is a List whose elements are all ECMAScript language values.
         // 2. Let array be ! ArrayCreate(0).
         let array = Self::array_create(0, None, context)
@@ -273,12 +312,8 @@ impl Array {
         //     b. Set n to n + 1.
         //
         // NOTE: This deviates from the spec, but it should have the same behaviour.
-        let elements: Vec<_> = elements.into_iter().collect();
         let length = elements.len();
-        array
-            .borrow_mut()
-            .properties_mut()
-            .override_indexed_properties(elements);
+        array.borrow_mut().override_indexed_properties(elements);
         array
             .set("length", length, true, context)
             .expect("Should not fail");
@@ -309,7 +344,7 @@ impl Array {
         }
 
         // 4. Return ? IsArray(O).
-        o.is_array_abstract(context)
+        o.is_array_abstract()
     }
 
     /// `get Array [ @@species ]`
@@ -339,7 +374,7 @@ impl Array {
     ) -> JsResult<JsObject> {
         // 1. Let isArray be ? IsArray(originalArray).
         // 2. If isArray is false, return ? ArrayCreate(length).
-        if !original_array.is_array_abstract(context)? {
+        if !original_array.is_array_abstract()? {
             return Self::array_create(length, None, context);
         }
         // 3. Let C be ? Get(originalArray, "constructor").
@@ -530,6 +565,44 @@ impl Array {
         }
     }
 
+    /// `Array.fromAsync ( asyncItems [ , mapfn [ , thisArg ] ] )`
+    ///
+    /// The `Array.fromAsync()` static method creates a new, shallow-copied Array instance from an
+    /// async iterable, iterable, or array-like object, returning a promise for it.
+    ///
+    /// More information:
+    ///  - [ECMAScript proposal][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-array-from-async/#sec-array.fromAsync
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/fromAsync
+    #[allow(clippy::unnecessary_wraps)]
+    pub(crate) fn from_async(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let C be the this value.
+        // 2. Let promiseCapability be ! NewPromiseCapability(%Promise%).
+        let promise_capability = PromiseCapability::new(
+            &context
+                .intrinsics()
+                .constructors()
+                .promise()
+                .constructor()
+                .into(),
+            context,
+        )
+        .expect("cannot fail with the %Promise% constructor");
+
+        // 3. Let fromAsyncClosure be a new Abstract Closure with no parameters that captures C, mapfn, and thisArg and performs the following steps when called:
+        // 4. Perform AsyncFunctionStart(promiseCapability, fromAsyncClosure).
+        FromAsync::start(this, args, &promise_capability, context);
+
+        // 5. Return promiseCapability.[[Promise]].
+        Ok(promise_capability.promise().clone().into())
+    }
+
     /// `Array.isArray( arg )`
     ///
     /// The isArray function takes one argument arg, and returns the Boolean value true
@@ -544,10 +617,10 @@ impl Array {
     pub(crate) fn is_array(
         _: &JsValue,
         args: &[JsValue],
-        context: &mut Context,
+        _context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Return ? IsArray(arg).
-        args.get_or_undefined(0).is_array(context).map(Into::into)
+        args.get_or_undefined(0).is_array().map(Into::into)
     }
 
     /// `Array.of(...items)`
@@ -810,9 +883,7 @@ impl Array {
         // 2. Let len be ? LengthOfArrayLike(O).
         let len = o.length_of_array_like(context)?;
         // 3. If IsCallable(callbackfn) is false, throw a TypeError exception.
-        let callback = args.get_or_undefined(0).as_callable().ok_or_else(|| {
-            context.construct_type_error("Array.prototype.forEach: invalid callback function")
-        })?;
+        let callback = args.to_callable(0, "Array.prototype.forEach", context)?;
         // 4. Let k be 0.
         // 5. Repeat, while k < len,
         for k in 0..len {
@@ -864,14 +935,16 @@ impl Array {
             separator.to_string(context)?
         };
 
+        let max_length = context.resource_limits().max_string_length();
+
         // 5. Let R be the empty String.
-        let mut r = String::new();
+        let mut r = Vec::new();
         // 6. Let k be 0.
         // 7. Repeat, while k < len,
         for k in 0..len {
             // a. If k > 0, set R to the string-concatenation of R and sep.
             if k > 0 {
-                r.push_str(&separator);
+                r.extend(separator.encode_utf16());
             }
             // b. Let element be ? Get(O, ! ToString(𝔽(k))).
             let element = o.get(k, context)?;
@@ -882,11 +955,16 @@ impl Array {
                 element.to_string(context)?
             };
             // d. Set R to the string-concatenation of R and next.
-            r.push_str(&next);
+            r.extend(next.encode_utf16());
+            if r.len() > max_length {
+                return context.throw_range_error(format!(
+                    "joined string length exceeds the maximum string length ({max_length})"
+                ));
+            }
             // e. Set k to k + 1.
         }
         // 8. Return R.
-        Ok(r.into())
+        Ok(JsString::from_utf16(&r).into())
     }
 
     /// `Array.prototype.toString( separator )`
@@ -1003,6 +1081,48 @@ impl Array {
         Ok(o.into())
     }
 
+    /// `Array.prototype.toReversed ( )`
+    ///
+    /// The toReversed method returns a new array with the elements of the array in reverse
+    /// order, leaving the original array untouched. Holes are read as `undefined`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.toreversed
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/toReversed
+    pub(crate) fn to_reversed(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be ? ToObject(this value).
+        let o = this.to_object(context)?;
+
+        // 2. Let len be ? LengthOfArrayLike(O).
+        let len = o.length_of_array_like(context)?;
+
+        // 3. Let A be ? ArrayCreate(len).
+        let a = Self::array_create(len, None, context)?;
+
+        // 4. Let k be 0.
+        // 5. Repeat, while k < len,
+        for k in 0..len {
+            // a. Let from be ! ToString(𝔽(len - k - 1)).
+            let from = len - k - 1;
+            // b. Let Pk be ! ToString(𝔽(k)).
+            // c. Let fromValue be ? Get(O, from).
+            let from_value = o.get(from, context)?;
+            // d. Perform ! CreateDataPropertyOrThrow(A, Pk, fromValue).
+            a.create_data_property_or_throw(k, from_value, context)?;
+            // e. Set k to k + 1.
+        }
+
+        // 6. Return A.
+        Ok(a.into())
+    }
+
     /// `Array.prototype.shift()`
     ///
     /// The first element of the array is removed from the array and returned.
@@ -1151,9 +1271,7 @@ impl Array {
         // 2. Let len be ? LengthOfArrayLike(O).
         let len = o.length_of_array_like(context)?;
         // 3. If IsCallable(callbackfn) is false, throw a TypeError exception.
-        let callback = args.get_or_undefined(0).as_callable().ok_or_else(|| {
-            context.construct_type_error("Array.prototype.every: callback is not callable")
-        })?;
+        let callback = args.to_callable(0, "Array.prototype.every", context)?;
 
         let this_arg = args.get_or_undefined(1);
 
@@ -1203,9 +1321,7 @@ impl Array {
         // 2. Let len be ? LengthOfArrayLike(O).
         let len = o.length_of_array_like(context)?;
         // 3. If IsCallable(callbackfn) is false, throw a TypeError exception.
-        let callback = args.get_or_undefined(0).as_callable().ok_or_else(|| {
-            context.construct_type_error("Array.prototype.map: Callbackfn is not callable")
-        })?;
+        let callback = args.to_callable(0, "Array.prototype.map", context)?;
 
         // 4. Let A be ? ArraySpeciesCreate(O, len).
         let a = Self::array_species_create(&o, len, context)?;
@@ -1411,9 +1527,7 @@ impl Array {
         let len = o.length_of_array_like(context)?;
 
         // 3. If IsCallable(predicate) is false, throw a TypeError exception.
-        let predicate = args.get_or_undefined(0).as_callable().ok_or_else(|| {
-            context.construct_type_error("Array.prototype.find: predicate is not callable")
-        })?;
+        let predicate = args.to_callable(0, "Array.prototype.find", context)?;
 
         let this_arg = args.get_or_undefined(1);
 
@@ -1468,9 +1582,7 @@ impl Array {
         let len = o.length_of_array_like(context)?;
 
         // 3. If IsCallable(predicate) is false, throw a TypeError exception.
-        let predicate = args.get_or_undefined(0).as_callable().ok_or_else(|| {
-            context.construct_type_error("Array.prototype.findIndex: predicate is not callable")
-        })?;
+        let predicate = args.to_callable(0, "Array.prototype.findIndex", context)?;
 
         let this_arg = args.get_or_undefined(1);
 
@@ -1519,9 +1631,7 @@ impl Array {
         let len = o.length_of_array_like(context)?;
 
         // 3. If IsCallable(predicate) is false, throw a TypeError exception.
-        let predicate = args.get_or_undefined(0).as_callable().ok_or_else(|| {
-            context.construct_type_error("Array.prototype.findLast: predicate is not callable")
-        })?;
+        let predicate = args.to_callable(0, "Array.prototype.findLast", context)?;
 
         let this_arg = args.get_or_undefined(1);
 
@@ -1571,9 +1681,7 @@ impl Array {
         let len = o.length_of_array_like(context)?;
 
         // 3. If IsCallable(predicate) is false, throw a TypeError exception.
-        let predicate = args.get_or_undefined(0).as_callable().ok_or_else(|| {
-            context.construct_type_error("Array.prototype.findLastIndex: predicate is not callable")
-        })?;
+        let predicate = args.to_callable(0, "Array.prototype.findLastIndex", context)?;
 
         let this_arg = args.get_or_undefined(1);
 
@@ -1643,6 +1751,7 @@ impl Array {
             source_len,
             0,
             depth_num,
+            1,
             None,
             &JsValue::undefined(),
             context,
@@ -1676,9 +1785,7 @@ impl Array {
         let source_len = o.length_of_array_like(context)?;
 
         // 3. If ! IsCallable(mapperFunction) is false, throw a TypeError exception.
-        let mapper_function = args.get_or_undefined(0).as_callable().ok_or_else(|| {
-            context.construct_type_error("flatMap mapper function is not callable")
-        })?;
+        let mapper_function = args.to_callable(0, "Array.prototype.flatMap", context)?;
 
         // 4. Let A be ? ArraySpeciesCreate(O, 0).
         let a = Self::array_species_create(&o, 0, context)?;
@@ -1690,6 +1797,7 @@ impl Array {
             source_len,
             0,
             1,
+            1,
             Some(mapper_function),
             args.get_or_undefined(1),
             context,
@@ -1712,12 +1820,14 @@ impl Array {
         source_len: u64,
         start: u64,
         depth: u64,
+        nesting: usize,
         mapper_function: Option<&JsObject>,
         this_arg: &JsValue,
         context: &mut Context,
     ) -> JsResult<u64> {
         // 1. Assert target is Object
         // 2. Assert source is Object
+        context.resource_limits().check_object_nesting(nesting)?;
 
         // 3. Assert if mapper_function is present, then:
         // - IsCallable(mapper_function) is true
@@ -1758,7 +1868,7 @@ impl Array {
                 // iv. If depth > 0, then
                 if depth > 0 {
                     // 1. Set shouldFlatten to ? IsArray(element).
-                    should_flatten = element.is_array(context)?;
+                    should_flatten = element.is_array()?;
                 }
 
                 // v. If shouldFlatten is true
@@ -1784,6 +1894,7 @@ impl Array {
                         element_len,
                         target_index,
                         new_depth,
+                        nesting + 1,
                         None,
                         &JsValue::undefined(),
                         context,
@@ -1846,6 +1957,13 @@ impl Array {
         // 10. Else, let final be min(relativeEnd, len).
         let final_ = Self::get_relative_end(context, args.get(2), len)?;
 
+        let max_length = context.resource_limits().max_array_length();
+        if final_.saturating_sub(k) > max_length {
+            return context.throw_range_error(format!(
+                "fill count exceeds the maximum array length ({max_length})"
+            ));
+        }
+
         let value = args.get_or_undefined(0);
 
         // 11. Repeat, while k < final,
@@ -2039,28 +2157,12 @@ impl Array {
             // 9a. Let insertCount be the number of elements in items.
             items.len() as u64
         };
-        let actual_delete_count = if start.is_none() {
-            // 7b. Let actualDeleteCount be 0.
-            0
-            // 8. Else if deleteCount is not present, then
-        } else if delete_count.is_none() {
-            // 8b. Let actualDeleteCount be len - actualStart.
-            len - actual_start
-        // 9. Else,
-        } else {
-            // b. Let dc be ? ToIntegerOrInfinity(deleteCount).
-            let dc = delete_count
-                .cloned()
-                .unwrap_or_default()
-                .to_integer_or_infinity(context)?;
-            // c. Let actualDeleteCount be the result of clamping dc between 0 and len - actualStart.
-            let max = len - actual_start;
-            match dc {
-                IntegerOrInfinity::Integer(i) => u64::try_from(i).unwrap_or_default().clamp(0, max),
-                IntegerOrInfinity::PositiveInfinity => max,
-                IntegerOrInfinity::NegativeInfinity => 0,
-            }
-        };
+        // 7b. Let actualDeleteCount be 0.
+        // 8b. Let actualDeleteCount be len - actualStart.
+        // 9b. Let dc be ? ToIntegerOrInfinity(deleteCount).
+        // 9c. Let actualDeleteCount be the result of clamping dc between 0 and len - actualStart.
+        let actual_delete_count =
+            Self::get_delete_count(context, start, delete_count, len, actual_start)?;
 
         // 10. If len + insertCount - actualDeleteCount > 2^53 - 1, throw a TypeError exception.
         if len + insert_count - actual_delete_count > Number::MAX_SAFE_INTEGER as u64 {
@@ -2182,6 +2284,96 @@ impl Array {
         Ok(JsValue::from(arr))
     }
 
+    /// `Array.prototype.toSpliced ( start, skipCount, ...items )`
+    ///
+    /// The toSpliced method returns a new array with `skipCount` elements removed from `start`
+    /// and replaced by `items`, leaving the original array untouched. Holes are read as
+    /// `undefined`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.tospliced
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/toSpliced
+    pub(crate) fn to_spliced(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be ? ToObject(this value).
+        let o = this.to_object(context)?;
+        // 2. Let len be ? LengthOfArrayLike(O).
+        let len = o.length_of_array_like(context)?;
+
+        let start = args.get(0);
+        let skip_count = args.get(1);
+        let items = args.get(2..).unwrap_or(&[]);
+        // 3. Let relativeStart be ? ToIntegerOrInfinity(start).
+        // 4. If relativeStart is -∞, let actualStart be 0.
+        // 5. Else if relativeStart < 0, let actualStart be max(len + relativeStart, 0).
+        // 6. Else, let actualStart be min(relativeStart, len).
+        let actual_start = Self::get_relative_start(context, start, len)?;
+        // 7. Let insertCount be the number of elements in items.
+        let insert_count = items.len() as u64;
+        // 8. If start is not present, then
+        //     a. Let actualSkipCount be 0.
+        // 9. Else if skipCount is not present, then
+        //     a. Let actualSkipCount be len - actualStart.
+        // 10. Else,
+        //     a. Let sc be ? ToIntegerOrInfinity(skipCount).
+        //     b. Let actualSkipCount be the result of clamping sc between 0 and len - actualStart.
+        let actual_skip_count =
+            Self::get_delete_count(context, start, skip_count, len, actual_start)?;
+
+        // 11. Let newLen be len + insertCount - actualSkipCount.
+        let new_len = len + insert_count - actual_skip_count;
+
+        // 12. If newLen > 2^53 - 1, throw a TypeError exception.
+        if new_len > Number::MAX_SAFE_INTEGER as u64 {
+            return context.throw_type_error("Target splice exceeded max safe integer value");
+        }
+
+        // 13. Let A be ? ArrayCreate(newLen).
+        let a = Self::array_create(new_len, None, context)?;
+
+        // 14. Let i be 0.
+        // 15. Let r be actualStart + actualSkipCount.
+        // 16. Repeat, while i < actualStart,
+        for i in 0..actual_start {
+            // a. Let Pi be ! ToString(𝔽(i)).
+            // b. Let iValue be ? Get(O, Pi).
+            let i_value = o.get(i, context)?;
+            // c. Perform ! CreateDataPropertyOrThrow(A, Pi, iValue).
+            a.create_data_property_or_throw(i, i_value, context)?;
+            // d. Set i to i + 1.
+        }
+
+        // 17. For each element E of items, do
+        for (i, item) in (actual_start..).zip(items) {
+            // a. Let Pi be ! ToString(𝔽(i)).
+            // b. Perform ! CreateDataPropertyOrThrow(A, Pi, E).
+            a.create_data_property_or_throw(i, item.clone(), context)?;
+            // c. Set i to i + 1.
+        }
+
+        // 18. Repeat, while i < newLen,
+        let r = actual_start + actual_skip_count;
+        for (i, from) in (actual_start + insert_count..new_len).zip(r..) {
+            // a. Let Pi be ! ToString(𝔽(i)).
+            // b. Let from be ! ToString(𝔽(r)).
+            // c. Let fromValue be ? Get(O, from).
+            let from_value = o.get(from, context)?;
+            // d. Perform ! CreateDataPropertyOrThrow(A, Pi, fromValue).
+            a.create_data_property_or_throw(i, from_value, context)?;
+            // e. Set i to i + 1.
+            // f. Set r to r + 1.
+        }
+
+        // 19. Return A.
+        Ok(a.into())
+    }
+
     /// `Array.prototype.filter( callback, [ thisArg ] )`
     ///
     /// For each element in the array the callback function is called, and a new
@@ -2205,9 +2397,7 @@ impl Array {
         let length = o.length_of_array_like(context)?;
 
         // 3. If IsCallable(callbackfn) is false, throw a TypeError exception.
-        let callback = args.get_or_undefined(0).as_callable().ok_or_else(|| {
-            context.construct_type_error("Array.prototype.filter: `callback` must be callable")
-        })?;
+        let callback = args.to_callable(0, "Array.prototype.filter", context)?;
         let this_arg = args.get_or_undefined(1);
 
         // 4. Let A be ? ArraySpeciesCreate(O, 0).
@@ -2269,9 +2459,7 @@ impl Array {
         // 2. Let len be ? LengthOfArrayLike(O).
         let len = o.length_of_array_like(context)?;
         // 3. If IsCallable(callbackfn) is false, throw a TypeError exception.
-        let callback = args.get_or_undefined(0).as_callable().ok_or_else(|| {
-            context.construct_type_error("Array.prototype.some: callback is not callable")
-        })?;
+        let callback = args.to_callable(0, "Array.prototype.some", context)?;
 
         // 4. Let k be 0.
         // 5. Repeat, while k < len,
@@ -2317,60 +2505,7 @@ impl Array {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. If comparefn is not undefined and IsCallable(comparefn) is false, throw a TypeError exception.
-        let comparefn = match args.get_or_undefined(0) {
-            JsValue::Object(ref obj) if obj.is_callable() => Some(obj),
-            JsValue::Undefined => None,
-            _ => {
-                return context.throw_type_error(
-                    "The comparison function must be either a function or undefined",
-                )
-            }
-        };
-
-        // Abstract method `SortCompare`.
-        //
-        // More information:
-        //  - [ECMAScript reference][spec]
-        //
-        // [spec]: https://tc39.es/ecma262/#sec-sortcompare
-        let sort_compare =
-            |x: &JsValue, y: &JsValue, context: &mut Context| -> JsResult<Ordering> {
-                match (x.is_undefined(), y.is_undefined()) {
-                    // 1. If x and y are both undefined, return +0𝔽.
-                    (true, true) => return Ok(Ordering::Equal),
-                    // 2. If x is undefined, return 1𝔽.
-                    (true, false) => return Ok(Ordering::Greater),
-                    // 3. If y is undefined, return -1𝔽.
-                    (false, true) => return Ok(Ordering::Less),
-                    _ => {}
-                }
-
-                // 4. If comparefn is not undefined, then
-                if let Some(cmp) = comparefn {
-                    let args = [x.clone(), y.clone()];
-                    // a. Let v be ? ToNumber(? Call(comparefn, undefined, « x, y »)).
-                    let v = cmp
-                        .call(&JsValue::Undefined, &args, context)?
-                        .to_number(context)?;
-                    // b. If v is NaN, return +0𝔽.
-                    // c. Return v.
-                    return Ok(v.partial_cmp(&0.0).unwrap_or(Ordering::Equal));
-                }
-                // 5. Let xString be ? ToString(x).
-                // 6. Let yString be ? ToString(y).
-                let x_str = x.to_string(context)?;
-                let y_str = y.to_string(context)?;
-
-                // 7. Let xSmaller be IsLessThan(xString, yString, true).
-                // 8. If xSmaller is true, return -1𝔽.
-                // 9. Let ySmaller be IsLessThan(yString, xString, true).
-                // 10. If ySmaller is true, return 1𝔽.
-                // 11. Return +0𝔽.
-
-                // NOTE: skipped IsLessThan because it just makes a lexicographic comparison
-                // when x and y are strings
-                Ok(x_str.cmp(&y_str))
-            };
+        let comparefn = Self::get_comparefn(args, context)?;
 
         // 2. Let obj be ? ToObject(this value).
         let obj = this.to_object(context)?;
@@ -2378,30 +2513,152 @@ impl Array {
         // 3. Let len be ? LengthOfArrayLike(obj).
         let length = obj.length_of_array_like(context)?;
 
-        // 4. Let items be a new empty List.
-        let mut items = Vec::with_capacity(length as usize);
+        // 4. Let SortCompare be a new Abstract Closure with parameters (x, y) that captures comparefn and performs the following steps when called:
+        //     a. Return ? CompareArrayElements(x, y, comparefn).
+        // 5. Let sortedList be ? SortIndexedProperties(obj, len, SortCompare, skip-holes).
+        let sorted_list = Self::sort_indexed_properties(
+            &obj,
+            length,
+            |x, y, context| Self::compare_array_elements(x, y, comparefn, context),
+            true,
+            context,
+        )?;
+
+        // 6. Let itemCount be the number of elements in sortedList.
+        let item_count = sorted_list.len() as u64;
 
-        // 5. Let k be 0.
-        // 6. Repeat, while k < len,
-        for k in 0..length {
+        // 7. Let j be 0.
+        // 8. Repeat, while j < itemCount,
+        for (j, item) in sorted_list.into_iter().enumerate() {
+            // a. Perform ? Set(obj, ! ToString(𝔽(j)), sortedList[j], true).
+            obj.set(j, item, true, context)?;
+            // b. Set j to j + 1.
+        }
+
+        // 9. NOTE: The call to SortIndexedProperties in step 5 uses skip-holes. The remaining
+        //    indices are deleted to preserve the number of holes that were detected and
+        //    excluded from the sort.
+        // 10. Repeat, while j < len,
+        for j in item_count..length {
+            // a. Perform ? DeletePropertyOrThrow(obj, ! ToString(𝔽(j))).
+            obj.delete_property_or_throw(j, context)?;
+            // b. Set j to j + 1.
+        }
+
+        // 11. Return obj.
+        Ok(obj.into())
+    }
+
+    /// `Array.prototype.toSorted ( comparefn )`
+    ///
+    /// The toSorted method returns a new array with the elements of the array sorted, leaving the
+    /// original array untouched. Holes are read as `undefined`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.tosorted
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/toSorted
+    pub(crate) fn to_sorted(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If comparefn is not undefined and IsCallable(comparefn) is false, throw a TypeError exception.
+        let comparefn = Self::get_comparefn(args, context)?;
+
+        // 2. Let O be ? ToObject(this value).
+        let o = this.to_object(context)?;
+
+        // 3. Let len be ? LengthOfArrayLike(O).
+        let len = o.length_of_array_like(context)?;
+
+        // 4. Let A be ? ArrayCreate(len).
+        let a = Self::array_create(len, None, context)?;
+
+        // 5. Let SortCompare be a new Abstract Closure with parameters (x, y) that captures comparefn and performs the following steps when called:
+        //     a. Return ? CompareArrayElements(x, y, comparefn).
+        // 6. Let sortedList be ? SortIndexedProperties(O, len, SortCompare, read-through-holes).
+        let sorted_list = Self::sort_indexed_properties(
+            &o,
+            len,
+            |x, y, context| Self::compare_array_elements(x, y, comparefn, context),
+            false,
+            context,
+        )?;
+
+        // 7. Let j be 0.
+        // 8. Repeat, while j < len,
+        for (j, item) in sorted_list.into_iter().enumerate() {
+            // a. Perform ! CreateDataPropertyOrThrow(A, ! ToString(𝔽(j)), sortedList[j]).
+            a.create_data_property_or_throw(j, item, context)?;
+            // b. Set j to j + 1.
+        }
+
+        // 9. Return A.
+        Ok(a.into())
+    }
+
+    /// Gets the `comparefn` argument of the sorting methods, which must be callable or `undefined`.
+    fn get_comparefn<'a>(
+        args: &'a [JsValue],
+        context: &mut Context,
+    ) -> JsResult<Option<&'a JsObject>> {
+        match args.get_or_undefined(0) {
+            JsValue::Object(ref obj) if obj.is_callable() => Ok(Some(obj)),
+            JsValue::Undefined => Ok(None),
+            _ => context
+                .throw_type_error("The comparison function must be either a function or undefined"),
+        }
+    }
+
+    /// Abstract operation `SortIndexedProperties ( obj, len, SortCompare, holes )`
+    ///
+    /// Collects the elements of `obj` up to `len` and sorts them using `sort_compare`. Holes are
+    /// left out of the result if `skip_holes` is `true`, and read as `undefined` otherwise.
+    ///
+    /// The first error returned by `sort_compare` stops the sort and is returned.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-sortindexedproperties
+    pub(crate) fn sort_indexed_properties<F>(
+        obj: &JsObject,
+        len: u64,
+        mut sort_compare: F,
+        skip_holes: bool,
+        context: &mut Context,
+    ) -> JsResult<Vec<JsValue>>
+    where
+        F: FnMut(&JsValue, &JsValue, &mut Context) -> JsResult<Ordering>,
+    {
+        // 1. Let items be a new empty List.
+        let mut items = Vec::with_capacity(len as usize);
+
+        // 2. Let k be 0.
+        // 3. Repeat, while k < len,
+        for k in 0..len {
             // a. Let Pk be ! ToString(𝔽(k)).
-            // b. Let kPresent be ? HasProperty(obj, Pk).
-            // c. If kPresent is true, then
-            if obj.has_property(k, context)? {
+            // b. If holes is skip-holes, then
+            //     i. Let kRead be ? HasProperty(obj, Pk).
+            // c. Else,
+            //     i. Assert: holes is read-through-holes.
+            //     ii. Let kRead be true.
+            // d. If kRead is true, then
+            if !skip_holes || obj.has_property(k, context)? {
                 // i. Let kValue be ? Get(obj, Pk).
-                let kval = obj.get(k, context)?;
+                let k_value = obj.get(k, context)?;
                 // ii. Append kValue to items.
-                items.push(kval);
+                items.push(k_value);
             }
-            // d. Set k to k + 1.
+            // e. Set k to k + 1.
         }
 
-        // 7. Let itemCount be the number of elements in items.
-        let item_count = items.len() as u64;
-
-        // 8. Sort items using an implementation-defined sequence of calls to SortCompare.
+        // 4. Sort items using an implementation-defined sequence of calls to SortCompare.
         // If any such call returns an abrupt completion, stop before performing any further
-        // calls to SortCompare or steps in this algorithm and return that completion.
+        // calls to SortCompare and return that completion.
         let mut sort_err = Ok(());
         items.sort_by(|x, y| {
             if sort_err.is_ok() {
@@ -2415,23 +2672,57 @@ impl Array {
         });
         sort_err?;
 
-        // 9. Let j be 0.
-        // 10. Repeat, while j < itemCount,
-        for (j, item) in items.into_iter().enumerate() {
-            // a. Perform ? Set(obj, ! ToString(𝔽(j)), items[j], true).
-            obj.set(j, item, true, context)?;
-            // b. Set j to j + 1.
-        }
+        // 5. Return items.
+        Ok(items)
+    }
 
-        // 11. Repeat, while j < len,
-        for j in item_count..length {
-            // a. Perform ? DeletePropertyOrThrow(obj, ! ToString(𝔽(j))).
-            obj.delete_property_or_throw(j, context)?;
-            // b. Set j to j + 1.
+    /// Abstract operation `CompareArrayElements ( x, y, comparefn )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-comparearrayelements
+    fn compare_array_elements(
+        x: &JsValue,
+        y: &JsValue,
+        comparefn: Option<&JsObject>,
+        context: &mut Context,
+    ) -> JsResult<Ordering> {
+        match (x.is_undefined(), y.is_undefined()) {
+            // 1. If x and y are both undefined, return +0𝔽.
+            (true, true) => return Ok(Ordering::Equal),
+            // 2. If x is undefined, return 1𝔽.
+            (true, false) => return Ok(Ordering::Greater),
+            // 3. If y is undefined, return -1𝔽.
+            (false, true) => return Ok(Ordering::Less),
+            _ => {}
         }
 
-        // 12. Return obj.
-        Ok(obj.into())
+        // 4. If comparefn is not undefined, then
+        if let Some(cmp) = comparefn {
+            let args = [x.clone(), y.clone()];
+            // a. Let v be ? ToNumber(? Call(comparefn, undefined, « x, y »)).
+            let v = cmp
+                .call(&JsValue::Undefined, &args, context)?
+                .to_number(context)?;
+            // b. If v is NaN, return +0𝔽.
+            // c. Return v.
+            return Ok(v.partial_cmp(&0.0).unwrap_or(Ordering::Equal));
+        }
+        // 5. Let xString be ? ToString(x).
+        // 6. Let yString be ? ToString(y).
+        let x_str = x.to_string(context)?;
+        let y_str = y.to_string(context)?;
+
+        // 7. Let xSmaller be IsLessThan(xString, yString, true).
+        // 8. If xSmaller is true, return -1𝔽.
+        // 9. Let ySmaller be IsLessThan(yString, xString, true).
+        // 10. If ySmaller is true, return 1𝔽.
+        // 11. Return +0𝔽.
+
+        // NOTE: skipped IsLessThan because it just makes a lexicographic comparison
+        // when x and y are strings
+        Ok(x_str.cmp(&y_str))
     }
 
     /// `Array.prototype.reduce( callbackFn [ , initialValue ] )`
@@ -2454,10 +2745,7 @@ impl Array {
         let len = o.length_of_array_like(context)?;
 
         // 3. If IsCallable(callbackfn) is false, throw a TypeError exception.
-        let callback = args.get_or_undefined(0).as_callable().ok_or_else(|| {
-            context
-                .construct_type_error("Array.prototype.reduce: callback function is not callable")
-        })?;
+        let callback = args.to_callable(0, "Array.prototype.reduce", context)?;
 
         // 4. If len = 0 and initialValue is not present, throw a TypeError exception.
         if len == 0 && args.get(1).is_none() {
@@ -2549,11 +2837,7 @@ impl Array {
         let len = o.length_of_array_like(context)?;
 
         // 3. If IsCallable(callbackfn) is false, throw a TypeError exception.
-        let callback = args.get_or_undefined(0).as_callable().ok_or_else(|| {
-            context.construct_type_error(
-                "Array.prototype.reduceRight: callback function is not callable",
-            )
-        })?;
+        let callback = args.to_callable(0, "Array.prototype.reduceRight", context)?;
 
         // 4. If len is 0 and initialValue is not present, throw a TypeError exception.
         if len == 0 && args.get(1).is_none() {
@@ -2712,6 +2996,69 @@ impl Array {
         Ok(o.into())
     }
 
+    /// `Array.prototype.with ( index, value )`
+    ///
+    /// The with method returns a new array with the element at `index` replaced by `value`,
+    /// leaving the original array untouched. Negative indices count back from the end of the
+    /// array. Holes are read as `undefined`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.with
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/with
+    pub(crate) fn with(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be ? ToObject(this value).
+        let o = this.to_object(context)?;
+
+        // 2. Let len be ? LengthOfArrayLike(O).
+        let len = o.length_of_array_like(context)?;
+
+        // 3. Let relativeIndex be ? ToIntegerOrInfinity(index).
+        let relative_index = args.get_or_undefined(0).to_integer_or_infinity(context)?;
+
+        let actual_index = match relative_index {
+            // 4. If relativeIndex ≥ 0, let actualIndex be relativeIndex.
+            IntegerOrInfinity::Integer(i) if i >= 0 => Some(i as u64),
+            // 5. Else, let actualIndex be len + relativeIndex.
+            IntegerOrInfinity::Integer(i) => len.checked_sub(i.unsigned_abs()),
+            IntegerOrInfinity::PositiveInfinity | IntegerOrInfinity::NegativeInfinity => None,
+        };
+
+        // 6. If actualIndex ≥ len or actualIndex < 0, throw a RangeError exception.
+        let actual_index = match actual_index {
+            Some(i) if i < len => i,
+            _ => return context.throw_range_error("Array.prototype.with: index out of range"),
+        };
+
+        // 7. Let A be ? ArrayCreate(len).
+        let a = Self::array_create(len, None, context)?;
+
+        // 8. Let k be 0.
+        // 9. Repeat, while k < len,
+        for k in 0..len {
+            // a. Let Pk be ! ToString(𝔽(k)).
+            // b. If k is actualIndex, let fromValue be value.
+            // c. Else, let fromValue be ? Get(O, Pk).
+            let from_value = if k == actual_index {
+                args.get_or_undefined(1).clone()
+            } else {
+                o.get(k, context)?
+            };
+            // d. Perform ! CreateDataPropertyOrThrow(A, Pk, fromValue).
+            a.create_data_property_or_throw(k, from_value, context)?;
+            // e. Set k to k + 1.
+        }
+
+        // 10. Return A.
+        Ok(a.into())
+    }
+
     /// `Array.prototype.values( )`
     ///
     /// The values method returns an iterable that iterates over the values in the array.
@@ -2849,12 +3196,43 @@ impl Array {
         }
     }
 
+    /// Represents the algorithm to calculate `actualDeleteCount` (or `actualSkipCount`) in
+    /// `splice` and `toSpliced`.
+    fn get_delete_count(
+        context: &mut Context,
+        start: Option<&JsValue>,
+        delete_count: Option<&JsValue>,
+        len: u64,
+        actual_start: u64,
+    ) -> JsResult<u64> {
+        // 1. If start is not present, then
+        if start.is_none() {
+            // a. Let actualDeleteCount be 0.
+            return Ok(0);
+        }
+        // 2. Else if deleteCount is not present, then
+        let delete_count = match delete_count {
+            // a. Let actualDeleteCount be len - actualStart.
+            None => return Ok(len - actual_start),
+            Some(delete_count) => delete_count,
+        };
+        // 3. Else,
+        //     a. Let dc be ? ToIntegerOrInfinity(deleteCount).
+        //     b. Let actualDeleteCount be the result of clamping dc between 0 and len - actualStart.
+        let max = len - actual_start;
+        match delete_count.to_integer_or_infinity(context)? {
+            IntegerOrInfinity::Integer(i) => Ok(u64::try_from(i).unwrap_or_default().clamp(0, max)),
+            IntegerOrInfinity::PositiveInfinity => Ok(max),
+            IntegerOrInfinity::NegativeInfinity => Ok(0),
+        }
+    }
+
     /// `Array.prototype [ @@unscopables ]`
     ///
     /// The initial value of the 'unscopables' data property is an ordinary object
     /// with the following boolean properties set to true:
-    /// 'at', 'copyWithin', 'entries', 'fill', 'find', 'findIndex', 'flat',
-    /// 'flatMap', 'includes', 'keys', 'values'
+    /// 'at', 'copyWithin', 'entries', 'fill', 'find', 'findIndex', 'findLast', 'findLastIndex',
+    /// 'flat', 'flatMap', 'includes', 'keys', 'toReversed', 'toSorted', 'toSpliced', 'values'
     ///
     /// More information:
     ///  - [ECMAScript reference][spec]
@@ -2862,55 +3240,57 @@ impl Array {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-array.prototype-@@unscopables
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/@@unscopables
-    pub(crate) fn unscopables_intrinsic(context: &mut Context) -> JsObject {
+    pub(crate) fn unscopables_intrinsic() -> JsObject {
         // 1. Let unscopableList be OrdinaryObjectCreate(null).
         let unscopable_list = JsObject::empty();
         // 2. Perform ! CreateDataPropertyOrThrow(unscopableList, "at", true).
-        unscopable_list
-            .create_data_property_or_throw("at", true, context)
-            .expect("CreateDataPropertyOrThrow for 'at' must not fail");
         // 3. Perform ! CreateDataPropertyOrThrow(unscopableList, "copyWithin", true).
-        unscopable_list
-            .create_data_property_or_throw("copyWithin", true, context)
-            .expect("CreateDataPropertyOrThrow for 'copyWithin' must not fail");
         // 4. Perform ! CreateDataPropertyOrThrow(unscopableList, "entries", true).
-        unscopable_list
-            .create_data_property_or_throw("entries", true, context)
-            .expect("CreateDataPropertyOrThrow for 'entries' must not fail");
         // 5. Perform ! CreateDataPropertyOrThrow(unscopableList, "fill", true).
-        unscopable_list
-            .create_data_property_or_throw("fill", true, context)
-            .expect("CreateDataPropertyOrThrow for 'fill' must not fail");
         // 6. Perform ! CreateDataPropertyOrThrow(unscopableList, "find", true).
-        unscopable_list
-            .create_data_property_or_throw("find", true, context)
-            .expect("CreateDataPropertyOrThrow for 'find' must not fail");
         // 7. Perform ! CreateDataPropertyOrThrow(unscopableList, "findIndex", true).
-        unscopable_list
-            .create_data_property_or_throw("findIndex", true, context)
-            .expect("CreateDataPropertyOrThrow for 'findIndex' must not fail");
-        // 8. Perform ! CreateDataPropertyOrThrow(unscopableList, "flat", true).
-        unscopable_list
-            .create_data_property_or_throw("flat", true, context)
-            .expect("CreateDataPropertyOrThrow for 'flat' must not fail");
-        // 9. Perform ! CreateDataPropertyOrThrow(unscopableList, "flatMap", true).
-        unscopable_list
-            .create_data_property_or_throw("flatMap", true, context)
-            .expect("CreateDataPropertyOrThrow for 'flatMap' must not fail");
-        // 10. Perform ! CreateDataPropertyOrThrow(unscopableList, "includes", true).
-        unscopable_list
-            .create_data_property_or_throw("includes", true, context)
-            .expect("CreateDataPropertyOrThrow for 'includes' must not fail");
-        // 11. Perform ! CreateDataPropertyOrThrow(unscopableList, "keys", true).
-        unscopable_list
-            .create_data_property_or_throw("keys", true, context)
-            .expect("CreateDataPropertyOrThrow for 'keys' must not fail");
-        // 12. Perform ! CreateDataPropertyOrThrow(unscopableList, "values", true).
-        unscopable_list
-            .create_data_property_or_throw("values", true, context)
-            .expect("CreateDataPropertyOrThrow for 'values' must not fail");
+        // 8. Perform ! CreateDataPropertyOrThrow(unscopableList, "findLast", true).
+        // 9. Perform ! CreateDataPropertyOrThrow(unscopableList, "findLastIndex", true).
+        // 10. Perform ! CreateDataPropertyOrThrow(unscopableList, "flat", true).
+        // 11. Perform ! CreateDataPropertyOrThrow(unscopableList, "flatMap", true).
+        // 12. Perform ! CreateDataPropertyOrThrow(unscopableList, "includes", true).
+        // 13. Perform ! CreateDataPropertyOrThrow(unscopableList, "keys", true).
+        // 14. Perform ! CreateDataPropertyOrThrow(unscopableList, "toReversed", true).
+        // 15. Perform ! CreateDataPropertyOrThrow(unscopableList, "toSorted", true).
+        // 16. Perform ! CreateDataPropertyOrThrow(unscopableList, "toSpliced", true).
+        // 17. Perform ! CreateDataPropertyOrThrow(unscopableList, "values", true).
+        //
+        // NOTE: The intrinsic is created before the resource limits of the context are applied,
+        // so the properties are inserted directly.
+        for name in [
+            "at",
+            "copyWithin",
+            "entries",
+            "fill",
+            "find",
+            "findIndex",
+            "findLast",
+            "findLastIndex",
+            "flat",
+            "flatMap",
+            "includes",
+            "keys",
+            "toReversed",
+            "toSorted",
+            "toSpliced",
+            "values",
+        ] {
+            unscopable_list.insert(
+                name,
+                PropertyDescriptor::builder()
+                    .value(true)
+                    .writable(true)
+                    .enumerable(true)
+                    .configurable(true),
+            );
+        }
 
-        // 13. Return unscopableList.
+        // 18. Return unscopableList.
         unscopable_list
     }
 }
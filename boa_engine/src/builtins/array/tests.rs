@@ -68,6 +68,36 @@ fn is_array() {
     );
 }
 
+#[test]
+fn is_array_proxy() {
+    let mut context = Context::default();
+    let init = r"
+        var nested = new Proxy(new Proxy([1, 2], {}), {});
+        var revocable = Proxy.revocable([], {});
+        revocable.revoke();
+        var revoked = revocable.proxy;
+        function throwsTypeError(f) {
+            try { f(); } catch (e) { return e instanceof TypeError; }
+            return false;
+        }
+    ";
+    context.eval(init).unwrap();
+    let checks = [
+        "Array.isArray(nested)",
+        "!Array.isArray(new Proxy({}, {}))",
+        "Object.prototype.toString.call(nested) === '[object Array]'",
+        "JSON.stringify({ a: nested }) === '{\"a\":[1,2]}'",
+        "[0].concat(nested).length === 3",
+        "throwsTypeError(() => Array.isArray(revoked))",
+        "throwsTypeError(() => Object.prototype.toString.call(revoked))",
+        "throwsTypeError(() => JSON.stringify(revoked))",
+        "throwsTypeError(() => [].concat(revoked))",
+    ];
+    for check in checks {
+        assert_eq!(context.eval(check), Ok(JsValue::new(true)), "{check}");
+    }
+}
+
 #[test]
 fn of() {
     let mut context = Context::default();
@@ -486,6 +516,27 @@ fn reverse() {
     assert_eq!(forward(&mut context, "reversed[1]"), "1");
     assert_eq!(forward(&mut context, "arr[0]"), "2");
     assert_eq!(forward(&mut context, "arr[1]"), "1");
+
+    // Holes are swapped instead of being read as `undefined`.
+    let init = r"
+        var holes = [1, , 3, , ].reverse();
+        var like = Array.prototype.reverse.call({ length: 3, 0: 'a', 1: 'b' });
+        ";
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "holes.length"), "4");
+    assert_eq!(
+        forward(
+            &mut context,
+            "[0 in holes, 1 in holes, 2 in holes, 3 in holes]"
+        ),
+        "[ false, true, false, true ]"
+    );
+    assert_eq!(forward(&mut context, "holes[1] + holes[3]"), "4");
+    assert_eq!(
+        forward(&mut context, "Object.keys(like).join()"),
+        "\"1,2,length\""
+    );
+    assert_eq!(forward(&mut context, "like[1] + like[2]"), "\"ba\"");
 }
 
 #[test]
@@ -612,6 +663,28 @@ fn last_index_of() {
     // Negative fromIndex with duplicates
     let second_in_many = forward(&mut context, "duplicates.lastIndexOf('b', -2)");
     assert_eq!(second_in_many, String::from("1"));
+
+    // Present but undefined fromIndex
+    assert_eq!(
+        forward(&mut context, "duplicates.lastIndexOf('a', undefined)"),
+        "0"
+    );
+    // Holes are skipped
+    assert_eq!(forward(&mut context, "[, 1].lastIndexOf(undefined)"), "-1");
+    // Array-like objects, reading the length once
+    let init = r"
+        var reads = 0;
+        var like = { get length() { reads++; return 3; }, 0: 'a', 2: 'a' };
+        ";
+    forward(&mut context, init);
+    assert_eq!(
+        forward(
+            &mut context,
+            "Array.prototype.lastIndexOf.call(like, 'a', -2)"
+        ),
+        "0"
+    );
+    assert_eq!(forward(&mut context, "reads"), "1");
 }
 
 #[test]
@@ -1111,7 +1184,7 @@ fn reduce() {
     );
     assert_eq!(
         result,
-        "\"Array.prototype.reduce: callback function is not callable\""
+        "\"Array.prototype.reduce: argument 1 is not callable\""
     );
 }
 
@@ -1236,8 +1309,22 @@ fn reduce_right() {
     );
     assert_eq!(
         result,
-        "\"Array.prototype.reduceRight: callback function is not callable\""
+        "\"Array.prototype.reduceRight: argument 1 is not callable\""
+    );
+    // Array-like objects, skipping missing indices and reading the length once
+    let init = r"
+        var reads = 0;
+        var like = { get length() { reads++; return 4; }, 0: 'a', 2: 'c', 3: 'd' };
+        ";
+    forward(&mut context, init);
+    assert_eq!(
+        forward(
+            &mut context,
+            "Array.prototype.reduceRight.call(like, (acc, x, i) => acc + x + i)"
+        ),
+        "\"dc2a0\""
     );
+    assert_eq!(forward(&mut context, "reads"), "1");
 }
 
 #[test]
@@ -1572,3 +1659,310 @@ fn array_sort() {
         "\"1,5,9,40,80,200,700\""
     );
 }
+
+#[test]
+fn array_with() {
+    let mut context = Context::default();
+    forward(&mut context, "let arr = [3, 1, , 2];");
+    assert_eq!(forward(&mut context, "arr.with(1, 9).join()"), "\"3,9,,2\"");
+    assert_eq!(
+        forward(&mut context, "arr.with(-1, 9).join()"),
+        "\"3,1,,9\""
+    );
+    assert_eq!(
+        forward(&mut context, "arr.with(-4, 9).join()"),
+        "\"9,1,,2\""
+    );
+    assert_eq!(forward(&mut context, "arr.join()"), "\"3,1,,2\"");
+    // Holes are read as `undefined`.
+    assert_eq!(forward(&mut context, "2 in arr.with(0, 0)"), "true");
+
+    let expected = "Uncaught \"RangeError\": \"Array.prototype.with: index out of range\"";
+    assert_eq!(forward(&mut context, "arr.with(4, 9)"), expected);
+    assert_eq!(forward(&mut context, "arr.with(-5, 9)"), expected);
+    assert_eq!(forward(&mut context, "arr.with(Infinity, 9)"), expected);
+}
+
+#[test]
+fn array_to_reversed() {
+    let mut context = Context::default();
+    forward(&mut context, "let arr = [3, 1, , 2];");
+    assert_eq!(
+        forward(&mut context, "arr.toReversed().join()"),
+        "\"2,,1,3\""
+    );
+    assert_eq!(forward(&mut context, "arr.join()"), "\"3,1,,2\"");
+    assert_eq!(forward(&mut context, "1 in arr.toReversed()"), "true");
+    assert_eq!(
+        forward(
+            &mut context,
+            "Array.prototype.toReversed.call({ length: 2, 0: 'a', 1: 'b' }).join()"
+        ),
+        "\"b,a\""
+    );
+}
+
+#[test]
+fn array_to_sorted() {
+    let mut context = Context::default();
+    forward(&mut context, "let arr = [3, 1, , 2];");
+    assert_eq!(forward(&mut context, "arr.toSorted().join()"), "\"1,2,3,\"");
+    assert_eq!(
+        forward(&mut context, "arr.toSorted((a, b) => b - a).join()"),
+        "\"3,2,1,\""
+    );
+    assert_eq!(forward(&mut context, "arr.join()"), "\"3,1,,2\"");
+    assert_eq!(forward(&mut context, "3 in arr.toSorted()"), "true");
+    assert_eq!(
+        forward(&mut context, "arr.toSorted(1)"),
+        "Uncaught \"TypeError\": \"The comparison function must be either a function or undefined\""
+    );
+
+    // The original array is untouched when the comparator throws.
+    let init = r#"
+        let calls = 0;
+        let numbers = [5, 4, 3, 2, 1];
+        try {
+            numbers.toSorted((a, b) => {
+                if (++calls > 3) throw new Error("stop");
+                return a - b;
+            });
+        } catch (e) {}
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "calls"), "4");
+    assert_eq!(forward(&mut context, "numbers.join()"), "\"5,4,3,2,1\"");
+}
+
+#[test]
+fn array_to_spliced() {
+    let mut context = Context::default();
+    forward(&mut context, "let arr = [3, 1, , 2];");
+    assert_eq!(
+        forward(&mut context, "arr.toSpliced(1, 1, 'x', 'y').join()"),
+        "\"3,x,y,,2\""
+    );
+    assert_eq!(
+        forward(&mut context, "arr.toSpliced().join()"),
+        "\"3,1,,2\""
+    );
+    assert_eq!(forward(&mut context, "arr.toSpliced(2).join()"), "\"3,1\"");
+    assert_eq!(
+        forward(&mut context, "arr.toSpliced(-2, Infinity).join()"),
+        "\"3,1\""
+    );
+    assert_eq!(
+        forward(&mut context, "arr.toSpliced(1, -3, 7).join()"),
+        "\"3,7,1,,2\""
+    );
+    assert_eq!(forward(&mut context, "arr.join()"), "\"3,1,,2\"");
+    assert_eq!(forward(&mut context, "2 in arr.toSpliced()"), "true");
+}
+
+#[test]
+fn array_change_by_copy_ignores_species() {
+    let mut context = Context::default();
+    let init = r#"
+        let arr = [1, 2];
+        arr.constructor = {};
+        arr.constructor[Symbol.species] = function () { throw new Error("species"); };
+    "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "arr.toReversed().constructor === Array"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "arr.toSorted().constructor === Array"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "arr.toSpliced(0, 0).constructor === Array"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "arr.with(0, 0).constructor === Array"),
+        "true"
+    );
+}
+
+#[test]
+fn array_from_async() {
+    let mut context = Context::default();
+    let init = r#"
+        var log = [];
+        async function* gen() {
+            log.push("start");
+            yield 1;
+            await null;
+            await null;
+            yield 2;
+            await null;
+            yield 3;
+            log.push("end");
+        }
+        var result;
+        Array.fromAsync(gen(), async function (x, i) {
+            await null;
+            log.push("map " + x);
+            return x * 10 + i;
+        }).then((a) => { result = a; });
+        log.push("sync");
+    "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "log.join()"),
+        "\"start,sync,map 1,map 2,map 3,end\""
+    );
+    assert_eq!(forward(&mut context, "Array.isArray(result)"), "true");
+    assert_eq!(forward(&mut context, "result.join()"), "\"10,21,32\"");
+    assert_eq!(
+        forward(&mut context, "Array.fromAsync([]) instanceof Promise"),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "Array.fromAsync.length"), "1");
+}
+
+#[test]
+fn array_from_async_sync_iterable_and_array_like() {
+    let mut context = Context::default();
+    let init = r#"
+        var fromIterable, fromArrayLike, fromAsyncIterator;
+        Array.fromAsync([Promise.resolve(1), 2, new Promise((r) => r(3))])
+            .then((a) => { fromIterable = a; });
+        Array.fromAsync({ length: 3, 0: Promise.resolve("a"), 1: "b", 2: Promise.resolve("c") })
+            .then((a) => { fromArrayLike = a; });
+        var iterable = {
+            [Symbol.asyncIterator]() {
+                var i = 0;
+                return { next() { i++; return Promise.resolve({ value: i, done: i > 2 }); } };
+            },
+            [Symbol.iterator]() { throw new Error("sync iterator"); }
+        };
+        Array.fromAsync(iterable, function (x) { return this.m * x; }, { m: 5 })
+            .then((a) => { fromAsyncIterator = a; });
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "fromIterable.join()"), "\"1,2,3\"");
+    assert_eq!(forward(&mut context, "fromArrayLike.join()"), "\"a,b,c\"");
+    assert_eq!(
+        forward(&mut context, "fromAsyncIterator.join()"),
+        "\"5,10\""
+    );
+}
+
+#[test]
+fn array_from_async_rejects() {
+    let mut context = Context::default();
+    let init = r#"
+        var closed = false;
+        var iterable = {
+            [Symbol.asyncIterator]() {
+                return {
+                    next() { return Promise.resolve({ value: 1, done: false }); },
+                    return() { closed = true; return {}; }
+                };
+            }
+        };
+        var mapError, rejected, notCallable, undefinedItems;
+        Array.fromAsync(iterable, () => { throw "map"; }).catch((e) => { mapError = e; });
+        Array.fromAsync([Promise.reject("rejected")]).catch((e) => { rejected = e; });
+        Array.fromAsync([1], 5).catch((e) => { notCallable = e; });
+        Array.fromAsync(undefined).catch((e) => { undefinedItems = e; });
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "mapError"), "\"map\"");
+    assert_eq!(forward(&mut context, "closed"), "true");
+    assert_eq!(forward(&mut context, "rejected"), "\"rejected\"");
+    assert_eq!(
+        forward(&mut context, "notCallable instanceof TypeError"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "undefinedItems instanceof TypeError"),
+        "true"
+    );
+}
+
+#[test]
+fn array_from_async_constructor() {
+    let mut context = Context::default();
+    let init = r"
+        function C(length) { this.args = arguments.length; }
+        var fromIterable, fromArrayLike;
+        Array.fromAsync.call(C, [1, 2]).then((a) => { fromIterable = a; });
+        Array.fromAsync.call(C, { length: 1, 0: 7 }).then((a) => { fromArrayLike = a; });
+    ";
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "fromIterable instanceof C"), "true");
+    assert_eq!(forward(&mut context, "fromIterable.args"), "0");
+    assert_eq!(forward(&mut context, "fromIterable.length"), "2");
+    assert_eq!(forward(&mut context, "fromIterable[1]"), "2");
+    assert_eq!(forward(&mut context, "fromArrayLike.args"), "1");
+    assert_eq!(forward(&mut context, "fromArrayLike[0]"), "7");
+}
+
+#[test]
+fn array_prototype_unscopables() {
+    let mut context = Context::default();
+    let init = r"
+        var unscopables = Array.prototype[Symbol.unscopables];
+        var desc = Object.getOwnPropertyDescriptor(Array.prototype, Symbol.unscopables);
+    ";
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "Object.getPrototypeOf(unscopables)"),
+        "null"
+    );
+    assert_eq!(
+        forward(&mut context, "Object.keys(unscopables).join()"),
+        "\"at,copyWithin,entries,fill,find,findIndex,findLast,findLastIndex,flat,flatMap,includes,keys,toReversed,toSorted,toSpliced,values\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.values(unscopables).every((v) => v === true)"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "desc.writable || desc.enumerable"),
+        "false"
+    );
+    assert_eq!(forward(&mut context, "desc.configurable"), "true");
+}
+
+#[test]
+fn callback_argument_errors() {
+    let mut context = Context::default();
+    let init = r#"
+        function attempt(f) {
+            try { return f(); } catch (e) { return e.constructor.name + ': ' + e.message; }
+        }
+        "#;
+    forward(&mut context, init);
+
+    for method in [
+        "every",
+        "filter",
+        "find",
+        "findIndex",
+        "findLast",
+        "findLastIndex",
+        "flatMap",
+        "forEach",
+        "map",
+        "reduce",
+        "reduceRight",
+        "some",
+    ] {
+        assert_eq!(
+            forward(&mut context, format!("attempt(() => [1].{method}({{}}))")),
+            format!("\"TypeError: Array.prototype.{method}: argument 1 is not callable\"")
+        );
+        assert_eq!(
+            forward(&mut context, format!("attempt(() => [].{method}())")),
+            format!("\"TypeError: Array.prototype.{method}: argument 1 is not callable\"")
+        );
+    }
+}
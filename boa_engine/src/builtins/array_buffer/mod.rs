@@ -3,7 +3,10 @@ mod tests;
 
 use crate::{
     builtins::{typed_array::TypedArrayKind, BuiltIn, JsArgs},
-    context::intrinsics::StandardConstructors,
+    context::{
+        intrinsics::StandardConstructors,
+        memory::{Allocation, MemoryKind},
+    },
     object::{
         internal_methods::get_prototype_from_constructor, ConstructorBuilder, FunctionBuilder,
         JsObject, ObjectData,
@@ -11,7 +14,7 @@ use crate::{
     property::Attribute,
     symbol::WellKnownSymbols,
     value::{IntegerOrInfinity, Numeric},
-    Context, JsResult, JsValue,
+    Context, JsNativeError, JsResult, JsValue,
 };
 use boa_gc::{Finalize, Trace};
 use boa_profiler::Profiler;
@@ -23,6 +26,7 @@ pub struct ArrayBuffer {
     pub array_buffer_data: Option<Vec<u8>>,
     pub array_buffer_byte_length: u64,
     pub array_buffer_detach_key: JsValue,
+    pub(crate) allocation: Allocation,
 }
 
 impl ArrayBuffer {
@@ -247,7 +251,7 @@ impl ArrayBuffer {
             let new_obj = new.borrow();
             // 17. Perform ? RequireInternalSlot(new, [[ArrayBufferData]]).
             let new_array_buffer = new_obj.as_array_buffer().ok_or_else(|| {
-                context.construct_type_error("ArrayBuffer constructor returned invalid object")
+                JsNativeError::typ().with_message("ArrayBuffer constructor returned invalid object")
             })?;
 
             // TODO: Shared Array Buffer
@@ -332,6 +336,7 @@ impl ArrayBuffer {
         // 3. Set obj.[[ArrayBufferData]] to block.
         // 4. Set obj.[[ArrayBufferByteLength]] to byteLength.
         obj.borrow_mut().data = ObjectData::array_buffer(Self {
+            allocation: Allocation::new(MemoryKind::ArrayBuffers, block.capacity()),
             array_buffer_data: Some(block),
             array_buffer_byte_length: byte_length,
             array_buffer_detach_key: JsValue::Undefined,
@@ -748,9 +753,15 @@ impl ArrayBuffer {
 pub fn create_byte_data_block(size: u64, context: &mut Context) -> JsResult<Vec<u8>> {
     // 1. Let db be a new Data Block value consisting of size bytes. If it is impossible to
     //    create such a Data Block, throw a RangeError exception.
+    let max_byte_length = context.resource_limits().max_array_buffer_byte_length();
+    if size > max_byte_length {
+        return context.throw_range_error(format!(
+            "data block size exceeds the maximum allocation size ({max_byte_length})"
+        ));
+    }
     let mut data_block = Vec::new();
     data_block.try_reserve(size as usize).map_err(|e| {
-        context.construct_range_error(format!("couldn't allocate the data block: {e}"))
+        JsNativeError::range().with_message(format!("couldn't allocate the data block: {e}"))
     })?;
 
     // 2. Set all of the bytes of db to 0.
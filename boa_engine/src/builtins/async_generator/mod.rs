@@ -7,15 +7,16 @@
 
 use crate::{
     builtins::{
-        generator::GeneratorContext, iterable::create_iter_result_object,
+        generator::GeneratorContext, iterable::create_iter_result_object_or_throw,
         promise::if_abrupt_reject_promise, promise::PromiseCapability, BuiltIn, JsArgs, Promise,
     },
+    environments::DeclarativeEnvironmentStack,
     object::{ConstructorBuilder, FunctionBuilder, JsObject, ObjectData},
     property::{Attribute, PropertyDescriptor},
     symbol::WellKnownSymbols,
     value::JsValue,
-    vm::GeneratorResumeKind,
-    Context, JsResult,
+    vm::{CallFrame, GeneratorResumeKind},
+    Context, JsError, JsNativeError, JsResult,
 };
 use boa_gc::{Cell, Finalize, Gc, Trace};
 use boa_profiler::Profiler;
@@ -173,12 +174,12 @@ impl AsyncGenerator {
         // 3. Let result be Completion(AsyncGeneratorValidate(generator, empty)).
         // 4. IfAbruptRejectPromise(result, promiseCapability).
         let generator_object = generator.as_object().ok_or_else(|| {
-            context.construct_type_error("generator resumed on non generator object")
+            JsNativeError::typ().with_message("generator resumed on non generator object")
         });
         if_abrupt_reject_promise!(generator_object, promise_capability, context);
         let mut generator_obj_mut = generator_object.borrow_mut();
         let generator = generator_obj_mut.as_async_generator_mut().ok_or_else(|| {
-            context.construct_type_error("generator resumed on non generator object")
+            JsNativeError::typ().with_message("generator resumed on non generator object")
         });
         if_abrupt_reject_promise!(generator, promise_capability, context);
 
@@ -190,7 +191,9 @@ impl AsyncGenerator {
             drop(generator_obj_mut);
 
             // a. Let iteratorResult be CreateIterResultObject(undefined, true).
-            let iterator_result = create_iter_result_object(JsValue::undefined(), true, context);
+            let iterator_result =
+                create_iter_result_object_or_throw(JsValue::undefined(), true, context);
+            if_abrupt_reject_promise!(iterator_result, promise_capability, context);
 
             // b. Perform ! Call(promiseCapability.[[Resolve]], undefined, « iteratorResult »).
             promise_capability
@@ -262,12 +265,12 @@ impl AsyncGenerator {
         // 3. Let result be Completion(AsyncGeneratorValidate(generator, empty)).
         // 4. IfAbruptRejectPromise(result, promiseCapability).
         let generator_object = generator.as_object().ok_or_else(|| {
-            context.construct_type_error("generator resumed on non generator object")
+            JsNativeError::typ().with_message("generator resumed on non generator object")
         });
         if_abrupt_reject_promise!(generator_object, promise_capability, context);
         let mut generator_obj_mut = generator_object.borrow_mut();
         let generator = generator_obj_mut.as_async_generator_mut().ok_or_else(|| {
-            context.construct_type_error("generator resumed on non generator object")
+            JsNativeError::typ().with_message("generator resumed on non generator object")
         });
         if_abrupt_reject_promise!(generator, promise_capability, context);
 
@@ -346,12 +349,12 @@ impl AsyncGenerator {
         // 3. Let result be Completion(AsyncGeneratorValidate(generator, empty)).
         // 4. IfAbruptRejectPromise(result, promiseCapability).
         let generator_object = generator.as_object().ok_or_else(|| {
-            context.construct_type_error("generator resumed on non generator object")
+            JsNativeError::typ().with_message("generator resumed on non generator object")
         });
         if_abrupt_reject_promise!(generator_object, promise_capability, context);
         let mut generator_obj_mut = generator_object.borrow_mut();
         let generator = generator_obj_mut.as_async_generator_mut().ok_or_else(|| {
-            context.construct_type_error("generator resumed on non generator object")
+            JsNativeError::typ().with_message("generator resumed on non generator object")
         });
         if_abrupt_reject_promise!(generator, promise_capability, context);
 
@@ -386,7 +389,10 @@ impl AsyncGenerator {
         }
 
         // 8. Let completion be ThrowCompletion(exception).
-        let completion = (Err(args.get_or_undefined(0).clone()), false);
+        let completion = (
+            Err(JsError::from_opaque(args.get_or_undefined(0).clone())),
+            false,
+        );
 
         // 9. Perform AsyncGeneratorEnqueue(generator, completion, promiseCapability).
         generator.enqueue(completion.clone(), promise_capability.clone());
@@ -456,7 +462,9 @@ impl AsyncGenerator {
         // 6. Let value be completion.[[Value]].
         match completion {
             // 7. If completion.[[Type]] is throw, then
-            Err(value) => {
+            Err(error) => {
+                let value = error.to_opaque(context);
+
                 // a. Perform ! Call(promiseCapability.[[Reject]], undefined, « value »).
                 promise_capability
                     .reject()
@@ -475,13 +483,24 @@ impl AsyncGenerator {
                 // iv. Set the running execution context's Realm to oldRealm.
                 // c. Else,
                 // i. Let iteratorResult be CreateIterResultObject(value, done).
-                let iterator_result = create_iter_result_object(value, done, context);
+                let iterator_result = create_iter_result_object_or_throw(value, done, context);
 
                 // d. Perform ! Call(promiseCapability.[[Resolve]], undefined, « iteratorResult »).
-                promise_capability
-                    .resolve()
-                    .call(&JsValue::undefined(), &[iterator_result], context)
-                    .expect("cannot fail per spec");
+                // The iterator result can only fail to be created by exceeding the resource
+                // limits, in which case the promise is rejected with that error instead.
+                match iterator_result {
+                    Ok(iterator_result) => promise_capability
+                        .resolve()
+                        .call(&JsValue::undefined(), &[iterator_result], context)
+                        .expect("cannot fail per spec"),
+                    Err(error) => {
+                        let value = error.to_opaque(context);
+                        promise_capability
+                            .reject()
+                            .call(&JsValue::undefined(), &[value], context)
+                    }
+                    .expect("cannot fail per spec"),
+                };
             }
         }
     }
@@ -538,7 +557,8 @@ impl AsyncGenerator {
                     context.vm.frame_mut().generator_resume_kind = GeneratorResumeKind::Normal;
                 }
             }
-            (Err(value), _) => {
+            (Err(error), _) => {
+                let value = error.to_opaque(context);
                 context.vm.push(value);
                 context.vm.frame_mut().generator_resume_kind = GeneratorResumeKind::Throw;
             }
@@ -563,6 +583,31 @@ impl AsyncGenerator {
         // 10. Return unused.
     }
 
+    /// Stores the state of an async generator that was resumed by an `Await` in its
+    /// `[[AsyncGeneratorContext]]`.
+    ///
+    /// The execution of the generator can reach a `yield` after being resumed from an `await`,
+    /// and the next request to the generator must continue from there.
+    pub(crate) fn save_context(
+        frame: &CallFrame,
+        environments: &DeclarativeEnvironmentStack,
+        stack: &[JsValue],
+    ) {
+        let generator_context = frame.async_generator.as_ref().and_then(|generator| {
+            generator
+                .borrow()
+                .as_async_generator()
+                .and_then(|generator| generator.context.clone())
+        });
+
+        if let Some(generator_context) = generator_context {
+            let mut generator_context = generator_context.borrow_mut();
+            generator_context.environments = environments.clone();
+            generator_context.stack = stack.to_vec();
+            generator_context.call_frame = frame.clone();
+        }
+    }
+
     /// `AsyncGeneratorAwaitReturn ( generator )`
     ///
     /// More information:
@@ -655,7 +700,7 @@ impl AsyncGenerator {
                 gen.state = AsyncGeneratorState::Completed;
 
                 // b. Let result be ThrowCompletion(reason).
-                let result = Err(args.get_or_undefined(0).clone());
+                let result = Err(JsError::from_opaque(args.get_or_undefined(0).clone()));
 
                 // c. Perform AsyncGeneratorCompleteStep(generator, result, true).
                 let next = gen.queue.pop_front().expect("must have one entry");
@@ -678,11 +723,13 @@ impl AsyncGenerator {
         let promise_obj = promise
             .as_object()
             .expect("constructed promise must be a promise");
-        promise_obj
-            .borrow_mut()
-            .as_promise_mut()
-            .expect("constructed promise must be a promise")
-            .perform_promise_then(&on_fulfilled.into(), &on_rejected.into(), None, context);
+        Promise::perform_promise_then(
+            promise_obj,
+            &on_fulfilled.into(),
+            &on_rejected.into(),
+            None,
+            context,
+        );
     }
 
     /// `AsyncGeneratorDrainQueue ( generator )`
@@ -18,7 +18,7 @@ use crate::{
     property::Attribute,
     symbol::WellKnownSymbols,
     value::{IntegerOrInfinity, PreferredType},
-    Context, JsBigInt, JsResult, JsValue,
+    Context, JsBigInt, JsNativeError, JsResult, JsValue,
 };
 use boa_profiler::Profiler;
 use num_bigint::ToBigInt;
@@ -129,7 +129,7 @@ impl BigInt {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-thisbigintvalue
     #[inline]
-    fn this_bigint_value(value: &JsValue, context: &mut Context) -> JsResult<JsBigInt> {
+    fn this_bigint_value(value: &JsValue) -> JsResult<JsBigInt> {
         value
             // 1. If Type(value) is BigInt, return value.
             .as_bigint()
@@ -143,7 +143,11 @@ impl BigInt {
                     .and_then(|obj| obj.borrow().as_bigint().cloned())
             })
             // 3. Throw a TypeError exception.
-            .ok_or_else(|| context.construct_type_error("'this' is not a BigInt"))
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("'this' is not a BigInt")
+                    .into()
+            })
     }
 
     /// `BigInt.prototype.toString( [radix] )`
@@ -163,7 +167,7 @@ impl BigInt {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let x be ? thisBigIntValue(this value).
-        let x = Self::this_bigint_value(this, context)?;
+        let x = Self::this_bigint_value(this)?;
 
         let radix = args.get_or_undefined(0);
 
@@ -212,9 +216,9 @@ impl BigInt {
     pub(crate) fn value_of(
         this: &JsValue,
         _: &[JsValue],
-        context: &mut Context,
+        _context: &mut Context,
     ) -> JsResult<JsValue> {
-        Ok(JsValue::new(Self::this_bigint_value(this, context)?))
+        Ok(JsValue::new(Self::this_bigint_value(this)?))
     }
 
     /// `BigInt.asIntN()`
@@ -145,6 +145,14 @@ fn bigint_function_conversion_from_string() {
     assert_eq!(forward(&mut context, "BigInt('0b1111')"), "15n");
     assert_eq!(forward(&mut context, "BigInt('0o70')"), "56n");
     assert_eq!(forward(&mut context, "BigInt('0xFF')"), "255n");
+    assert_eq!(forward(&mut context, "BigInt(' -12 ')"), "-12n");
+
+    for invalid in [
+        "'1_000'", "'0x_1'", "'-0x1'", "'0b+1'", "'0x'", "'1n'", "'1.0'",
+    ] {
+        assert!(forward(&mut context, format!("BigInt({invalid})"))
+            .starts_with("Uncaught \"SyntaxError\": "));
+    }
 }
 
 #[test]
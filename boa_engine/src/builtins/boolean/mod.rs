@@ -18,7 +18,7 @@ use crate::{
     object::{
         internal_methods::get_prototype_from_constructor, ConstructorBuilder, JsObject, ObjectData,
     },
-    Context, JsResult, JsValue,
+    Context, JsNativeError, JsResult, JsValue,
 };
 use boa_profiler::Profiler;
 use tap::{Conv, Pipe};
@@ -79,11 +79,15 @@ impl Boolean {
     ///  - [ECMAScript reference][spec]
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-thisbooleanvalue
-    fn this_boolean_value(value: &JsValue, context: &mut Context) -> JsResult<bool> {
+    fn this_boolean_value(value: &JsValue) -> JsResult<bool> {
         value
             .as_boolean()
             .or_else(|| value.as_object().and_then(|obj| obj.borrow().as_boolean()))
-            .ok_or_else(|| context.construct_type_error("'this' is not a boolean"))
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("'this' is not a boolean")
+                    .into()
+            })
     }
 
     /// The `toString()` method returns a string representing the specified `Boolean` object.
@@ -98,9 +102,9 @@ impl Boolean {
     pub(crate) fn to_string(
         this: &JsValue,
         _: &[JsValue],
-        context: &mut Context,
+        _context: &mut Context,
     ) -> JsResult<JsValue> {
-        let boolean = Self::this_boolean_value(this, context)?;
+        let boolean = Self::this_boolean_value(this)?;
         Ok(JsValue::new(boolean.to_string()))
     }
 
@@ -116,8 +120,8 @@ impl Boolean {
     pub(crate) fn value_of(
         this: &JsValue,
         _: &[JsValue],
-        context: &mut Context,
+        _context: &mut Context,
     ) -> JsResult<JsValue> {
-        Ok(JsValue::new(Self::this_boolean_value(this, context)?))
+        Ok(JsValue::new(Self::this_boolean_value(this)?))
     }
 }
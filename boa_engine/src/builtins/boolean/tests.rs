@@ -63,3 +63,34 @@ fn instances_have_correct_proto_set() {
         &bool_prototype.as_object().cloned()
     );
 }
+
+#[test]
+fn boolean_object_wrapper_identity() {
+    let mut context = Context::default();
+    let init = r#"
+        var b = new Boolean(false);
+        function attempt(f) {
+            try { return f(); } catch (e) { return e.constructor.name; }
+        }
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "typeof b"), "\"object\"");
+    assert_eq!(
+        forward(&mut context, "b ? 'truthy' : 'falsy'"),
+        "\"truthy\""
+    );
+    assert_eq!(forward(&mut context, "b.valueOf()"), "false");
+    assert_eq!(forward(&mut context, "b == false"), "true");
+    assert_eq!(
+        forward(&mut context, "Object.getOwnPropertyNames(b).length"),
+        "0"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "attempt(() => Boolean.prototype.valueOf.call(0))"
+        ),
+        "\"TypeError\""
+    );
+}
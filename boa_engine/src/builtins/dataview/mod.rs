@@ -8,7 +8,7 @@ use crate::{
     property::Attribute,
     symbol::WellKnownSymbols,
     value::JsValue,
-    Context, JsResult,
+    Context, JsNativeError, JsResult,
 };
 use boa_gc::{Finalize, Trace};
 use tap::{Conv, Pipe};
@@ -103,7 +103,7 @@ impl DataView {
         let buffer_obj = args
             .get_or_undefined(0)
             .as_object()
-            .ok_or_else(|| context.construct_type_error("buffer must be an ArrayBuffer"))?;
+            .ok_or_else(|| JsNativeError::typ().with_message("buffer must be an ArrayBuffer"))?;
 
         // 1. If NewTarget is undefined, throw a TypeError exception.
         let (offset, view_byte_length) = {
@@ -112,9 +112,9 @@ impl DataView {
             }
             // 2. Perform ? RequireInternalSlot(buffer, [[ArrayBufferData]]).
             let buffer_borrow = buffer_obj.borrow();
-            let buffer = buffer_borrow
-                .as_array_buffer()
-                .ok_or_else(|| context.construct_type_error("buffer must be an ArrayBuffer"))?;
+            let buffer = buffer_borrow.as_array_buffer().ok_or_else(|| {
+                JsNativeError::typ().with_message("buffer must be an ArrayBuffer")
+            })?;
 
             // 3. Let offset be ? ToIndex(byteOffset).
             let offset = args.get_or_undefined(1).to_index(context)?;
@@ -154,7 +154,7 @@ impl DataView {
         if buffer_obj
             .borrow()
             .as_array_buffer()
-            .ok_or_else(|| context.construct_type_error("buffer must be an ArrayBuffer"))?
+            .ok_or_else(|| JsNativeError::typ().with_message("buffer must be an ArrayBuffer"))?
             .is_detached_buffer()
         {
             return context.throw_type_error("ArrayBuffer can't be detached");
@@ -190,7 +190,7 @@ impl DataView {
     pub(crate) fn get_buffer(
         this: &JsValue,
         _args: &[JsValue],
-        context: &mut Context,
+        _context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let O be the this value.
         // 2. Perform ? RequireInternalSlot(O, [[DataView]]).
@@ -198,7 +198,7 @@ impl DataView {
         let dataview = dataview
             .as_ref()
             .and_then(|obj| obj.as_data_view())
-            .ok_or_else(|| context.construct_type_error("`this` is not a DataView"))?;
+            .ok_or_else(|| JsNativeError::typ().with_message("`this` is not a DataView"))?;
         // 3. Assert: O has a [[ViewedArrayBuffer]] internal slot.
         // 4. Let buffer be O.[[ViewedArrayBuffer]].
         let buffer = dataview.viewed_array_buffer.clone();
@@ -227,7 +227,7 @@ impl DataView {
         let dataview = dataview
             .as_ref()
             .and_then(|obj| obj.as_data_view())
-            .ok_or_else(|| context.construct_type_error("`this` is not a DataView"))?;
+            .ok_or_else(|| JsNativeError::typ().with_message("`this` is not a DataView"))?;
         // 3. Assert: O has a [[ViewedArrayBuffer]] internal slot.
         // 4. Let buffer be O.[[ViewedArrayBuffer]].
         let buffer_borrow = dataview.viewed_array_buffer.borrow();
@@ -266,7 +266,7 @@ impl DataView {
         let dataview = dataview
             .as_ref()
             .and_then(|obj| obj.as_data_view())
-            .ok_or_else(|| context.construct_type_error("`this` is not a DataView"))?;
+            .ok_or_else(|| JsNativeError::typ().with_message("`this` is not a DataView"))?;
         // 3. Assert: O has a [[ViewedArrayBuffer]] internal slot.
         // 4. Let buffer be O.[[ViewedArrayBuffer]].
         let buffer_borrow = dataview.viewed_array_buffer.borrow();
@@ -306,7 +306,7 @@ impl DataView {
         let view = view
             .as_ref()
             .and_then(|obj| obj.as_data_view())
-            .ok_or_else(|| context.construct_type_error("`this` is not a DataView"))?;
+            .ok_or_else(|| JsNativeError::typ().with_message("`this` is not a DataView"))?;
         // 3. Let getIndex be ? ToIndex(requestIndex).
         let get_index = request_index.to_index(context)?;
 
@@ -665,7 +665,7 @@ impl DataView {
         let view = view
             .as_ref()
             .and_then(|obj| obj.as_data_view())
-            .ok_or_else(|| context.construct_type_error("`this` is not a DataView"))?;
+            .ok_or_else(|| JsNativeError::typ().with_message("`this` is not a DataView"))?;
         // 3. Let getIndex be ? ToIndex(requestIndex).
         let get_index = request_index.to_index(context)?;
 
@@ -10,7 +10,7 @@ use crate::{
     },
     symbol::WellKnownSymbols,
     value::{JsValue, PreferredType},
-    Context, JsResult, JsString,
+    Context, JsNativeError, JsResult, JsString,
 };
 use boa_profiler::Profiler;
 use chrono::{prelude::*, Duration, LocalResult};
@@ -47,8 +47,8 @@ fn ignore_ambiguity<T>(result: LocalResult<T>) -> Option<T> {
 
 macro_rules! getter_method {
     ($name:ident) => {{
-        fn get_value(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
-            Ok(JsValue::new(this_time_value(this, context)?.$name()))
+        fn get_value(this: &JsValue, _: &[JsValue], _context: &mut Context) -> JsResult<JsValue> {
+            Ok(JsValue::new(this_time_value(this)?.$name()))
         }
         get_value
     }};
@@ -331,12 +331,12 @@ impl Date {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         if new_target.is_undefined() {
-            Ok(Self::make_date_string())
+            Ok(Self::make_date_string(context))
         } else {
             let prototype =
                 get_prototype_from_constructor(new_target, StandardConstructors::date, context)?;
             Ok(if args.is_empty() {
-                Self::make_date_now(prototype)
+                Self::make_date_now(prototype, context)
             } else if args.len() == 1 {
                 Self::make_date_single(prototype, args, context)?
             } else {
@@ -356,8 +356,11 @@ impl Date {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-date-constructor
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/Date
-    pub(crate) fn make_date_string() -> JsValue {
-        JsValue::new(Local::now().to_rfc3339())
+    pub(crate) fn make_date_string(context: &Context) -> JsValue {
+        Self::current_time(context).map_or_else(
+            || JsValue::new("Invalid Date"),
+            |now| JsValue::new(Local.from_utc_datetime(&now).to_rfc3339()),
+        )
     }
 
     /// `Date()`
@@ -370,8 +373,22 @@ impl Date {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-date-constructor
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/Date
-    pub(crate) fn make_date_now(prototype: JsObject) -> JsObject {
-        JsObject::from_proto_and_data(prototype, ObjectData::date(Self::default()))
+    pub(crate) fn make_date_now(prototype: JsObject, context: &Context) -> JsObject {
+        let now = Self(Self::current_time(context));
+        JsObject::from_proto_and_data(prototype, ObjectData::date(now))
+    }
+
+    /// Gets the current time from the clock of the context.
+    ///
+    /// Returns `None` if the clock returns a time that cannot be represented, or if there's no
+    /// clock available.
+    fn current_time(context: &Context) -> Option<NaiveDateTime> {
+        time_from_millis(Self::current_time_value(context))
+    }
+
+    /// Gets the current time value from the clock of the context, clipped with `TimeClip`.
+    fn current_time_value(context: &Context) -> f64 {
+        Self::time_clip(context.current_time_millis()).map_or(f64::NAN, f64::trunc)
     }
 
     /// `Date(value)`
@@ -390,7 +407,7 @@ impl Date {
         context: &mut Context,
     ) -> JsResult<JsObject> {
         let value = &args[0];
-        let tv = match this_time_value(value, context) {
+        let tv = match this_time_value(value) {
             Ok(dt) => dt.0,
             _ => match value.to_primitive(context, PreferredType::Default)? {
                 JsValue::String(ref str) => match chrono::DateTime::parse_from_rfc3339(str) {
@@ -432,54 +449,25 @@ impl Date {
         args: &[JsValue],
         context: &mut Context,
     ) -> JsResult<JsObject> {
-        let mut year = args[0].to_number(context)?;
-        let month = args[1].to_number(context)?;
-        let day = args
-            .get(2)
-            .map_or(Ok(1f64), |value| value.to_number(context))?;
-        let hour = args
-            .get(3)
-            .map_or(Ok(0f64), |value| value.to_number(context))?;
-        let min = args
-            .get(4)
-            .map_or(Ok(0f64), |value| value.to_number(context))?;
-        let sec = args
-            .get(5)
-            .map_or(Ok(0f64), |value| value.to_number(context))?;
-        let milli = args
-            .get(6)
-            .map_or(Ok(0f64), |value| value.to_number(context))?;
-
-        // If any of the args are infinity or NaN, return an invalid date.
-        if !check_normal_opt!(year, month, day, hour, min, sec, milli) {
-            return Ok(JsObject::from_proto_and_data(
-                prototype,
-                ObjectData::date(Self(None)),
-            ));
-        }
-
-        if (0.0..=99.0).contains(&year) {
-            year += 1900.0;
-        }
-
-        let mut date = Self(
-            NaiveDateTime::from_timestamp_opt(0, 0)
+        // 1. Let y be ? ToNumber(year).
+        // 2. Let m be ? ToNumber(month).
+        // 3. If date is present, let dt be ? ToNumber(date); else let dt be 1𝔽.
+        // 4. If hours is present, let h be ? ToNumber(hours); else let h be +0𝔽.
+        // 5. If minutes is present, let min be ? ToNumber(minutes); else let min be +0𝔽.
+        // 6. If seconds is present, let s be ? ToNumber(seconds); else let s be +0𝔽.
+        // 7. If ms is present, let milli be ? ToNumber(ms); else let milli be +0𝔽.
+        // 8. Let yr be MakeFullYear(y).
+        // 9. Let finalDate be MakeDate(MakeDay(yr, m, dt), MakeTime(h, min, s, milli)).
+        let final_date = Self::date_from_components(args, 1f64, context)?;
+
+        // 10. Let dv be TimeClip(UTC(finalDate)).
+        let date = Self(
+            time_from_millis(final_date)
                 .and_then(|local| ignore_ambiguity(Local.from_local_datetime(&local)))
                 .map(|local| local.naive_utc())
                 .filter(|time| Self::time_clip(time.timestamp_millis() as f64).is_some()),
         );
 
-        date.set_components(
-            false,
-            Some(year),
-            Some(month),
-            Some(day),
-            Some(hour),
-            Some(min),
-            Some(sec),
-            Some(milli),
-        );
-
         Ok(JsObject::from_proto_and_data(
             prototype,
             ObjectData::date(date),
@@ -700,10 +688,10 @@ impl Date {
     pub fn get_timezone_offset(
         this: &JsValue,
         _: &[JsValue],
-        context: &mut Context,
+        _context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let t be ? thisTimeValue(this value).
-        let t = this_time_value(this, context)?;
+        let t = this_time_value(this)?;
 
         // 2. If t is NaN, return NaN.
         if t.0.is_none() {
@@ -849,7 +837,7 @@ impl Date {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setDate
     pub fn set_date(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
         // 1. Let t be LocalTime(? thisTimeValue(this value)).
-        let mut t = this_time_value(this, context)?;
+        let mut t = this_time_value(this)?;
 
         // 2. Let dt be ? ToNumber(date).
         let dt = args
@@ -888,7 +876,7 @@ impl Date {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let t be ? thisTimeValue(this value).
-        let mut t = this_time_value(this, context)?;
+        let mut t = this_time_value(this)?;
 
         // 2. If t is NaN, set t to +0𝔽; otherwise, set t to LocalTime(t).
         if t.0.is_none() {
@@ -946,7 +934,7 @@ impl Date {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setHours
     pub fn set_hours(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
         // 1. Let t be LocalTime(? thisTimeValue(this value)).
-        let mut t = this_time_value(this, context)?;
+        let mut t = this_time_value(this)?;
 
         // 2. Let h be ? ToNumber(hour).
         let h = args
@@ -1005,7 +993,7 @@ impl Date {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let t be LocalTime(? thisTimeValue(this value)).
-        let mut t = this_time_value(this, context)?;
+        let mut t = this_time_value(this)?;
 
         // 2. Set ms to ? ToNumber(ms).
         let ms = args
@@ -1043,7 +1031,7 @@ impl Date {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let t be LocalTime(? thisTimeValue(this value)).
-        let mut t = this_time_value(this, context)?;
+        let mut t = this_time_value(this)?;
 
         // 2. Let m be ? ToNumber(min).
         let m = args
@@ -1091,7 +1079,7 @@ impl Date {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setMonth
     pub fn set_month(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
         // 1. Let t be LocalTime(? thisTimeValue(this value)).
-        let mut t = this_time_value(this, context)?;
+        let mut t = this_time_value(this)?;
 
         // 2. Let m be ? ToNumber(month).
         let m = args
@@ -1136,7 +1124,7 @@ impl Date {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let t be LocalTime(? thisTimeValue(this value)).
-        let mut t = this_time_value(this, context)?;
+        let mut t = this_time_value(this)?;
 
         // 2. Let s be ? ToNumber(sec).
         let s = args
@@ -1177,7 +1165,7 @@ impl Date {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setYear
     pub fn set_year(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
         // 1. Let t be ? thisTimeValue(this value).
-        let mut t = this_time_value(this, context)?;
+        let mut t = this_time_value(this)?;
 
         // 2. If t is NaN, set t to +0𝔽; otherwise, set t to LocalTime(t).
         if t.0.is_none() {
@@ -1188,7 +1176,7 @@ impl Date {
         }
 
         // 3. Let y be ? ToNumber(year).
-        let mut y = args
+        let y = args
             .get(0)
             .cloned()
             .unwrap_or_default()
@@ -1206,13 +1194,11 @@ impl Date {
         // 5. Let yi be ! ToIntegerOrInfinity(y).
         // 6. If 0 ≤ yi ≤ 99, let yyyy be 1900𝔽 + 𝔽(yi).
         // 7. Else, let yyyy be y.
-        if (0f64..=99f64).contains(&y) {
-            y += 1900f64;
-        }
+        let yyyy = make_full_year(y);
 
         // 8. Let d be MakeDay(yyyy, MonthFromTime(t), DateFromTime(t)).
         // 9. Let date be UTC(MakeDate(d, TimeWithinDay(t))).
-        t.set_components(false, Some(y), None, None, None, None, None, None);
+        t.set_components(false, Some(yyyy), None, None, None, None, None, None);
 
         // 10. Set the [[DateValue]] internal slot of this Date object to TimeClip(date).
         this.set_data(ObjectData::date(t));
@@ -1234,7 +1220,7 @@ impl Date {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setTime
     pub fn set_time(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
         // 1. Perform ? thisTimeValue(this value).
-        this_time_value(this, context)?;
+        this_time_value(this)?;
 
         // 2. Let t be ? ToNumber(time).
         let t = if let Some(t) = args.get(0) {
@@ -1275,7 +1261,7 @@ impl Date {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let t be ? thisTimeValue(this value).
-        let mut t = this_time_value(this, context)?;
+        let mut t = this_time_value(this)?;
 
         // 2. Let dt be ? ToNumber(date).
         let dt = args
@@ -1314,7 +1300,7 @@ impl Date {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let t be ? thisTimeValue(this value).
-        let mut t = this_time_value(this, context)?;
+        let mut t = this_time_value(this)?;
 
         // 2. If t is NaN, set t to +0𝔽.
         if t.0.is_none() {
@@ -1376,7 +1362,7 @@ impl Date {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let t be ? thisTimeValue(this value).
-        let mut t = this_time_value(this, context)?;
+        let mut t = this_time_value(this)?;
 
         // 2. Let h be ? ToNumber(hour).
         let h = args
@@ -1435,7 +1421,7 @@ impl Date {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let t be ? thisTimeValue(this value).
-        let mut t = this_time_value(this, context)?;
+        let mut t = this_time_value(this)?;
 
         // 2. Let milli be ? ToNumber(ms).
         let ms = args
@@ -1473,7 +1459,7 @@ impl Date {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let t be ? thisTimeValue(this value).
-        let mut t = this_time_value(this, context)?;
+        let mut t = this_time_value(this)?;
 
         // 2. Let m be ? ToNumber(min).
         let m = args
@@ -1529,7 +1515,7 @@ impl Date {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let t be ? thisTimeValue(this value).
-        let mut t = this_time_value(this, context)?;
+        let mut t = this_time_value(this)?;
 
         // 2. Let m be ? ToNumber(month).
         let m = args
@@ -1576,7 +1562,7 @@ impl Date {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let t be ? thisTimeValue(this value).
-        let mut t = this_time_value(this, context)?;
+        let mut t = this_time_value(this)?;
 
         // 2. Let s be ? ToNumber(sec).
         let s = args
@@ -1621,11 +1607,11 @@ impl Date {
     pub fn to_date_string(
         this: &JsValue,
         _: &[JsValue],
-        context: &mut Context,
+        _context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let O be this Date object.
         // 2. Let tv be ? thisTimeValue(O).
-        let tv = this_time_value(this, context)?;
+        let tv = this_time_value(this)?;
 
         // 3. If tv is NaN, return "Invalid Date".
         // 4. Let t be LocalTime(tv).
@@ -1674,7 +1660,7 @@ impl Date {
         _: &[JsValue],
         context: &mut Context,
     ) -> JsResult<JsValue> {
-        if let Some(t) = this_time_value(this, context)?.0 {
+        if let Some(t) = this_time_value(this)?.0 {
             Ok(Utc::now()
                 .timezone()
                 .from_utc_datetime(&t)
@@ -1727,9 +1713,9 @@ impl Date {
     /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.tostring
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/toString
     #[allow(clippy::wrong_self_convention)]
-    pub fn to_string(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+    pub fn to_string(this: &JsValue, _: &[JsValue], _context: &mut Context) -> JsResult<JsValue> {
         // 1. Let tv be ? thisTimeValue(this value).
-        let tv = this_time_value(this, context)?;
+        let tv = this_time_value(this)?;
 
         // 2. Return ToDateString(tv).
         if let Some(t) = tv.0 {
@@ -1759,11 +1745,11 @@ impl Date {
     pub fn to_time_string(
         this: &JsValue,
         _: &[JsValue],
-        context: &mut Context,
+        _context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let O be this Date object.
         // 2. Let tv be ? thisTimeValue(O).
-        let tv = this_time_value(this, context)?;
+        let tv = this_time_value(this)?;
 
         // 3. If tv is NaN, return "Invalid Date".
         // 4. Let t be LocalTime(tv).
@@ -1822,8 +1808,8 @@ impl Date {
     /// [spec]: https://tc39.es/ecma262/#sec-date.now
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/now
     #[allow(clippy::unnecessary_wraps)]
-    pub(crate) fn now(_: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
-        Ok(JsValue::new(Utc::now().timestamp_millis() as f64))
+    pub(crate) fn now(_: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        Ok(JsValue::new(Self::current_time_value(context)))
     }
 
     /// `Date.parse()`
@@ -1863,50 +1849,176 @@ impl Date {
     /// [spec]: https://tc39.es/ecma262/#sec-date.utc
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/UTC
     pub(crate) fn utc(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
-        let year = args
-            .get(0)
-            .map_or(Ok(f64::NAN), |value| value.to_number(context))?;
-        let month = args
-            .get(1)
-            .map_or(Ok(0f64), |value| value.to_number(context))?;
-        let day = args
-            .get(2)
-            .map_or(Ok(1f64), |value| value.to_number(context))?;
-        let hour = args
-            .get(3)
-            .map_or(Ok(0f64), |value| value.to_number(context))?;
-        let min = args
-            .get(4)
-            .map_or(Ok(0f64), |value| value.to_number(context))?;
-        let sec = args
-            .get(5)
-            .map_or(Ok(0f64), |value| value.to_number(context))?;
-        let milli = args
-            .get(6)
-            .map_or(Ok(0f64), |value| value.to_number(context))?;
-
-        if !check_normal_opt!(year, month, day, hour, min, sec, milli) {
-            return Ok(JsValue::nan());
-        }
-
-        let year = year as i32;
-        let month = month as u32;
-        let day = day as u32;
-        let hour = hour as u32;
-        let min = min as u32;
-        let sec = sec as u32;
-        let milli = milli as u32;
+        // 1. Let y be ? ToNumber(year).
+        // 2. If month is present, let m be ? ToNumber(month); else let m be +0𝔽.
+        // 3. If date is present, let dt be ? ToNumber(date); else let dt be 1𝔽.
+        // 4. If hours is present, let h be ? ToNumber(hours); else let h be +0𝔽.
+        // 5. If minutes is present, let min be ? ToNumber(minutes); else let min be +0𝔽.
+        // 6. If seconds is present, let s be ? ToNumber(seconds); else let s be +0𝔽.
+        // 7. If ms is present, let milli be ? ToNumber(ms); else let milli be +0𝔽.
+        // 8. Let yr be MakeFullYear(y).
+        // 9. Return TimeClip(MakeDate(MakeDay(yr, m, dt), MakeTime(h, min, s, milli))).
+        let date = Self::date_from_components(args, 0f64, context)?;
+        Ok(Self::time_clip(date).map_or(f64::NAN, f64::trunc).into())
+    }
 
-        let year = if (0..=99).contains(&year) {
-            1900 + year
-        } else {
-            year
+    /// Converts the `year, month [ , date [ , hours [ , minutes [ , seconds [ , ms ] ] ] ] ]` arguments of the
+    /// `Date` constructor and `Date.UTC()` to a time value, without converting it from local time or clipping it.
+    ///
+    /// Every argument is converted with `ToNumber`, in order, even if a previous one is not finite. A missing
+    /// `year` is `NaN`, a missing `month` is `default_month`, a missing `date` is `1` and the rest default to `0`.
+    fn date_from_components(
+        args: &[JsValue],
+        default_month: f64,
+        context: &mut Context,
+    ) -> JsResult<f64> {
+        let mut component = |index: usize, default: f64| {
+            args.get(index)
+                .map_or(Ok(default), |value| value.to_number(context))
         };
+        let year = component(0, f64::NAN)?;
+        let month = component(1, default_month)?;
+        let date = component(2, 1f64)?;
+        let hours = component(3, 0f64)?;
+        let minutes = component(4, 0f64)?;
+        let seconds = component(5, 0f64)?;
+        let ms = component(6, 0f64)?;
+
+        Ok(make_date(
+            make_day(make_full_year(year), month, date),
+            make_time(hours, minutes, seconds, ms),
+        ))
+    }
+}
+
+/// Converts a number of milliseconds since the epoch to a `NaiveDateTime`.
+///
+/// Returns `None` if the time is not finite or cannot be represented.
+fn time_from_millis(millis: f64) -> Option<NaiveDateTime> {
+    if !millis.is_finite() {
+        return None;
+    }
+    let secs = (millis / 1_000f64).floor() as i64;
+    let nano_secs = (millis.rem_euclid(1_000f64) * 1_000_000f64) as u32;
+    NaiveDateTime::from_timestamp_opt(secs, nano_secs)
+}
+
+/// Abstract operation `MakeTime ( hour, min, sec, ms )`
+///
+/// Calculates a number of milliseconds from its arguments, which must be ECMAScript Number values.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-maketime
+fn make_time(hour: f64, min: f64, sec: f64, ms: f64) -> f64 {
+    // 1. If hour is not finite, min is not finite, sec is not finite, or ms is not finite, return NaN.
+    if !(hour.is_finite() && min.is_finite() && sec.is_finite() && ms.is_finite()) {
+        return f64::NAN;
+    }
+
+    // 2. Let h be 𝔽(! ToIntegerOrInfinity(hour)).
+    // 3. Let m be 𝔽(! ToIntegerOrInfinity(min)).
+    // 4. Let s be 𝔽(! ToIntegerOrInfinity(sec)).
+    // 5. Let milli be 𝔽(! ToIntegerOrInfinity(ms)).
+    // 6. Let t be ((h * msPerHour + m * msPerMinute) + s * msPerSecond) + milli.
+    // 7. Return t.
+    ((hour.trunc() * MILLIS_PER_HOUR as f64 + min.trunc() * MILLIS_PER_MINUTE as f64)
+        + sec.trunc() * MILLIS_PER_SECOND as f64)
+        + ms.trunc()
+}
 
-        NaiveDate::from_ymd_opt(year, month + 1, day)
-            .and_then(|f| f.and_hms_milli_opt(hour, min, sec, milli))
-            .and_then(|f| Self::time_clip(f.timestamp_millis() as f64))
-            .map_or(Ok(JsValue::nan()), |time| Ok(JsValue::new(time)))
+/// Abstract operation `MakeDay ( year, month, date )`
+///
+/// Calculates a number of days from its arguments, which must be ECMAScript Number values.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-makeday
+fn make_day(year: f64, month: f64, date: f64) -> f64 {
+    // 1. If year is not finite or month is not finite or date is not finite, return NaN.
+    if !(year.is_finite() && month.is_finite() && date.is_finite()) {
+        return f64::NAN;
+    }
+
+    // 2. Let y be 𝔽(! ToIntegerOrInfinity(year)).
+    // 3. Let m be 𝔽(! ToIntegerOrInfinity(month)).
+    // 4. Let dt be 𝔽(! ToIntegerOrInfinity(date)).
+    let (year, month, date) = (year.trunc(), month.trunc(), date.trunc());
+
+    // 5. Let ym be y + 𝔽(floor(ℝ(m) / 12)).
+    // 6. If ym is not finite, return NaN.
+    let year = year + (month / 12f64).floor();
+    if !year.is_finite() {
+        return f64::NAN;
+    }
+
+    // 7. Let mn be 𝔽(ℝ(m) modulo 12).
+    let month = month.rem_euclid(12f64);
+
+    // 8. Find a finite time value t such that YearFromTime(t) is ym and MonthFromTime(t) is mn and
+    //    DateFromTime(t) is 1𝔽; but if this is not possible (because some argument is out of range), return NaN.
+    // This computes the number of days from the epoch to the first day of the month in the proleptic
+    // Gregorian calendar, with years starting in March so that leap days fall at their end.
+    let year = if month < 2f64 { year - 1f64 } else { year };
+    let era = (year / 400f64).floor();
+    let year_of_era = year - era * 400f64;
+    let day_of_year =
+        ((153f64 * (month + if month < 2f64 { 10f64 } else { -2f64 }) + 2f64) / 5f64).floor();
+    let day_of_era = year_of_era * 365f64 + (year_of_era / 4f64).floor()
+        - (year_of_era / 100f64).floor()
+        + day_of_year;
+    let day = era * 146_097f64 + day_of_era - 719_468f64;
+
+    // 9. Return Day(t) + dt - 1𝔽.
+    day + date - 1f64
+}
+
+/// Abstract operation `MakeDate ( day, time )`
+///
+/// Calculates a number of milliseconds from its arguments, which must be ECMAScript Number values.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-makedate
+fn make_date(day: f64, time: f64) -> f64 {
+    // 1. If day is not finite or time is not finite, return NaN.
+    if !(day.is_finite() && time.is_finite()) {
+        return f64::NAN;
+    }
+
+    // 2. Let tv be day × msPerDay + time.
+    let tv = day * 86_400_000f64 + time;
+
+    // 3. If tv is not finite, return NaN.
+    // 4. Return tv.
+    if tv.is_finite() {
+        tv
+    } else {
+        f64::NAN
+    }
+}
+
+/// Abstract operation `MakeFullYear ( year )`
+///
+/// Maps the years `0` to `99` to the years `1900` to `1999`, as the legacy `Date` APIs do.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-makefullyear
+fn make_full_year(year: f64) -> f64 {
+    // 1. If year is NaN, return NaN.
+    // 2. Let truncated be ! ToIntegerOrInfinity(year).
+    // 3. If truncated is in the inclusive interval from 0 to 99, return 1900𝔽 + 𝔽(truncated).
+    // 4. Return 𝔽(truncated).
+    let truncated = year.trunc();
+    if (0f64..=99f64).contains(&truncated) {
+        1900f64 + truncated
+    } else {
+        truncated
     }
 }
 
@@ -1923,9 +2035,13 @@ impl Date {
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-thistimevalue
 #[inline]
-pub fn this_time_value(value: &JsValue, context: &mut Context) -> JsResult<Date> {
+pub fn this_time_value(value: &JsValue) -> JsResult<Date> {
     value
         .as_object()
         .and_then(|obj| obj.borrow().as_date().copied())
-        .ok_or_else(|| context.construct_type_error("'this' is not a Date"))
+        .ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("'this' is not a Date")
+                .into()
+        })
 }
@@ -59,7 +59,8 @@ fn date_this_time_value() {
         &mut context,
         "({toString: Date.prototype.toString}).toString()",
     )
-    .expect_err("Expected error");
+    .expect_err("Expected error")
+    .to_opaque(&mut context);
     let message_property = &error
         .get_property("message")
         .expect("Expected 'message' property")
@@ -163,6 +164,23 @@ fn date_ctor_call_multiple_90s() {
     );
 }
 
+#[test]
+fn date_ctor_call_multiple_two_digit_year() {
+    let mut context = Context::default();
+
+    let date_time = forward_dt_local(&mut context, "new Date(99.5, 0)");
+    assert_eq!(
+        Some(NaiveDate::from_ymd(1999, 01, 01).and_hms(0, 0, 0)),
+        date_time
+    );
+
+    let date_time = forward_dt_local(&mut context, "new Date(0, 12, 32)");
+    assert_eq!(
+        Some(NaiveDate::from_ymd(1901, 02, 01).and_hms(0, 0, 0)),
+        date_time
+    );
+}
+
 #[test]
 fn date_ctor_call_multiple_nan() {
     fn check(src: &str) {
@@ -230,6 +248,92 @@ fn date_ctor_utc_call_nan() {
     check("Date.UTC(2020, 06, 08, 09, 16, 15, 1/0)");
 }
 
+#[test]
+fn date_ctor_utc_call_defaults() {
+    let mut context = Context::default();
+
+    assert_eq!(
+        Ok(JsValue::new(1483228800000f64)),
+        forward_val(&mut context, "Date.UTC(2017)")
+    );
+    assert_eq!(
+        Ok(JsValue::new(1504224000000f64)),
+        forward_val(&mut context, "Date.UTC(2017, 8)")
+    );
+    assert_eq!(Ok(JsValue::nan()), forward_val(&mut context, "Date.UTC()"));
+    assert_eq!(
+        Ok(JsValue::nan()),
+        forward_val(&mut context, "Date.UTC(2017, undefined)")
+    );
+}
+
+#[test]
+fn date_ctor_utc_call_out_of_range_components() {
+    let mut context = Context::default();
+
+    // Out of range components roll over into the next larger component.
+    assert_eq!(
+        forward_val(&mut context, "Date.UTC(2024, 0, 1)"),
+        forward_val(&mut context, "Date.UTC(2023, 12, 1)")
+    );
+    assert_eq!(
+        forward_val(&mut context, "Date.UTC(2022, 11, 31)"),
+        forward_val(&mut context, "Date.UTC(2023, 0, 0)")
+    );
+    assert_eq!(
+        forward_val(&mut context, "Date.UTC(2023, 1, 28, 23, 59, 59, 999)"),
+        forward_val(&mut context, "Date.UTC(2023, 2, 1, 0, 0, 0, -1)")
+    );
+    assert_eq!(
+        forward_val(&mut context, "Date.UTC(2020, 1, 29)"),
+        forward_val(&mut context, "Date.UTC(2020, 0, 29.9, 24 * 31)")
+    );
+
+    // The result must be within 8.64e15 milliseconds of the epoch.
+    assert_eq!(
+        Ok(JsValue::new(8.64e15)),
+        forward_val(&mut context, "Date.UTC(275760, 8, 13)")
+    );
+    assert_eq!(
+        Ok(JsValue::nan()),
+        forward_val(&mut context, "Date.UTC(275760, 8, 13, 0, 0, 0, 1)")
+    );
+    assert_eq!(
+        Ok(JsValue::new(-8.64e15)),
+        forward_val(&mut context, "Date.UTC(-271821, 3, 20)")
+    );
+    assert_eq!(
+        Ok(JsValue::nan()),
+        forward_val(&mut context, "Date.UTC(-271821, 3, 19, 23, 59, 59, 999)")
+    );
+    assert_eq!(
+        Ok(JsValue::nan()),
+        forward_val(&mut context, "Date.UTC(2020, 1e20)")
+    );
+}
+
+#[test]
+fn date_ctor_utc_call_two_digit_year() {
+    let mut context = Context::default();
+
+    assert_eq!(
+        forward_val(&mut context, "Date.UTC(1999, 0)"),
+        forward_val(&mut context, "Date.UTC(99, 0)")
+    );
+    assert_eq!(
+        forward_val(&mut context, "Date.UTC(1999, 0)"),
+        forward_val(&mut context, "Date.UTC(99.5, 0)")
+    );
+    assert_eq!(
+        forward_val(&mut context, "Date.UTC(1900, 0)"),
+        forward_val(&mut context, "Date.UTC(-0.5, 0)")
+    );
+    assert_eq!(
+        Ok(JsValue::new(-59011459200000f64)),
+        forward_val(&mut context, "Date.UTC(100, 0)")
+    );
+}
+
 #[test]
 fn date_proto_get_date_call() {
     let mut context = Context::default();
@@ -829,6 +933,15 @@ fn set_year() {
         Some(NaiveDate::from_ymd(2001, 07, 08).and_hms_milli(09, 16, 15, 779)),
         actual
     );
+
+    let actual = forward_dt_local(
+        &mut context,
+        "dt = new Date(2020, 06, 08, 09, 16, 15, 779); dt.setYear(99.5); dt",
+    );
+    assert_eq!(
+        Some(NaiveDate::from_ymd(1999, 07, 08).and_hms_milli(09, 16, 15, 779)),
+        actual
+    );
 }
 
 #[test]
@@ -82,7 +82,7 @@ impl AggregateError {
             let msg = message.to_string(context)?;
 
             // b. Perform CreateNonEnumerableDataPropertyOrThrow(O, "message", msg).
-            o.create_non_enumerable_data_property_or_throw("message", msg, context);
+            o.create_non_enumerable_data_property_or_throw("message", msg);
         }
 
         // 4. Perform ? InstallErrorCause(O, options).
@@ -104,7 +104,7 @@ impl AggregateError {
                 .configurable(true)
                 .enumerable(false)
                 .writable(true)
-                .value(Array::create_array_from_list(errors_list, context))
+                .value(Array::create_array_from_list(errors_list, context)?)
                 .build(),
             context,
         )
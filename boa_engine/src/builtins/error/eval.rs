@@ -79,7 +79,7 @@ impl EvalError {
             let msg = message.to_string(context)?;
 
             // b. Perform CreateNonEnumerableDataPropertyOrThrow(O, "message", msg).
-            o.create_non_enumerable_data_property_or_throw("message", msg, context);
+            o.create_non_enumerable_data_property_or_throw("message", msg);
         }
 
         // 4. Perform ? InstallErrorCause(O, options).
@@ -64,6 +64,7 @@ impl BuiltIn for Error {
         .property("name", Self::NAME, attribute)
         .property("message", "", attribute)
         .method(Self::to_string, "toString", 0)
+        .static_method(Self::is_error, "isError", 1)
         .build()
         .conv::<JsValue>()
         .pipe(Some)
@@ -86,7 +87,7 @@ impl Error {
                 let cause = options.get("cause", context)?;
 
                 // b. Perform CreateNonEnumerableDataPropertyOrThrow(O, "cause", cause).
-                o.create_non_enumerable_data_property_or_throw("cause", cause, context);
+                o.create_non_enumerable_data_property_or_throw("cause", cause);
             }
         }
 
@@ -116,7 +117,7 @@ impl Error {
             let msg = message.to_string(context)?;
 
             // b. Perform CreateNonEnumerableDataPropertyOrThrow(O, "message", msg).
-            o.create_non_enumerable_data_property_or_throw("message", msg, context);
+            o.create_non_enumerable_data_property_or_throw("message", msg);
         }
 
         // 4. Perform ? InstallErrorCause(O, options).
@@ -126,6 +127,25 @@ impl Error {
         Ok(o.into())
     }
 
+    /// `Error.isError( arg )`
+    ///
+    /// Returns `true` if the argument is an error object, checking the `[[ErrorData]]` internal
+    /// slot instead of the prototype chain.
+    ///
+    /// More information:
+    ///  - [Proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-is-error/#sec-error.iserror
+    #[allow(clippy::unnecessary_wraps)]
+    pub(crate) fn is_error(_: &JsValue, args: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 1. Return IsError(arg).
+        Ok(args
+            .get_or_undefined(0)
+            .as_object()
+            .map_or(false, JsObject::is_error)
+            .into())
+    }
+
     /// `Error.prototype.toString()`
     ///
     /// The toString() method returns a string representing the specified Error object.
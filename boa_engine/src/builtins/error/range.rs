@@ -77,7 +77,7 @@ impl RangeError {
             let msg = message.to_string(context)?;
 
             // b. Perform CreateNonEnumerableDataPropertyOrThrow(O, "message", msg).
-            o.create_non_enumerable_data_property_or_throw("message", msg, context);
+            o.create_non_enumerable_data_property_or_throw("message", msg);
         }
 
         // 4. Perform ? InstallErrorCause(O, options).
@@ -1,4 +1,9 @@
-use crate::{forward, Context};
+use crate::{
+    error::{JsNativeErrorKind, JsStructuredError},
+    forward,
+    syntax::Parser,
+    Context, JsBigInt, JsError, JsNativeError, JsResult, JsValue,
+};
 
 #[test]
 fn error_to_string() {
@@ -84,3 +89,239 @@ fn uri_error_to_string() {
         "\"URIError\""
     );
 }
+
+#[test]
+fn native_error_to_opaque() {
+    let mut context = Context::default();
+    let error = JsNativeError::typ()
+        .with_message("not a function")
+        .with_cause(5)
+        .to_opaque(&mut context);
+    context.register_global_property("error", error, crate::property::Attribute::all());
+
+    assert_eq!(forward(&mut context, "error instanceof TypeError"), "true");
+    assert_eq!(forward(&mut context, "error.message"), "\"not a function\"");
+    assert_eq!(forward(&mut context, "error.cause"), "5");
+    assert_eq!(forward(&mut context, "Object.keys(error).length"), "0");
+}
+
+#[test]
+fn native_error_display() {
+    assert_eq!(JsNativeError::range().to_string(), "RangeError");
+    assert_eq!(
+        JsNativeError::uri().with_message("malformed").to_string(),
+        "URIError: malformed"
+    );
+}
+
+#[test]
+fn native_error_from_parse_error() {
+    let mut context = Context::default();
+    let parse_error = Parser::new(&b"let = ;"[..])
+        .parse_all(&mut context)
+        .unwrap_err();
+    let error = JsNativeError::from(parse_error);
+    assert_eq!(error.kind(), JsNativeErrorKind::Syntax);
+    assert!(!error.message().is_empty());
+    assert!(error.cause().is_none());
+}
+
+#[test]
+fn js_error_native_until_caught() {
+    let mut context = Context::default();
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { null.property } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+
+    let error = context.eval("null.property").unwrap_err();
+    assert!(error.as_opaque().is_none());
+    assert_eq!(
+        error.as_native().map(JsNativeError::kind),
+        Some(JsNativeErrorKind::Type)
+    );
+    assert!(error.to_opaque(&mut context).is_object());
+
+    let error = context.eval("throw new RangeError('thrown')").unwrap_err();
+    assert!(error.as_native().is_none());
+    assert_eq!(
+        JsStructuredError::from_error(&error).to_string(),
+        "RangeError: thrown"
+    );
+    let error = context.eval("throw 5").unwrap_err();
+    assert_eq!(error, JsError::from_opaque(JsValue::new(5)));
+}
+
+#[test]
+fn js_error_from_utility_errors() {
+    fn parse(context: &mut Context, source: &str) -> JsResult<()> {
+        context.parse(source)?;
+        Ok(())
+    }
+
+    fn to_bigint(number: f64) -> JsResult<JsBigInt> {
+        Ok(JsBigInt::try_from(number)?)
+    }
+
+    let mut context = Context::default();
+    let error = parse(&mut context, "let = ;").unwrap_err();
+    assert_eq!(
+        error.as_native().map(JsNativeError::kind),
+        Some(JsNativeErrorKind::Syntax)
+    );
+
+    let error = to_bigint(0.5).unwrap_err();
+    assert_eq!(
+        error.as_native().map(JsNativeError::kind),
+        Some(JsNativeErrorKind::Range)
+    );
+    assert!(to_bigint(2.0).is_ok());
+
+    let error = JsStructuredError::from_error(
+        &JsNativeError::typ()
+            .with_message("outer")
+            .with_cause(JsValue::new("inner"))
+            .into(),
+    );
+    assert!(error.is_error());
+    assert_eq!(error.to_string(), "TypeError: outer");
+    assert_eq!(
+        error.cause().and_then(JsStructuredError::message),
+        Some("inner")
+    );
+}
+
+#[test]
+fn native_errors_use_intrinsic_prototypes() {
+    let mut context = Context::default();
+    let init = r#"
+        const OriginalTypeError = TypeError;
+        delete globalThis.TypeError;
+        let caught;
+        try {
+            null.property;
+        } catch (e) {
+            caught = e;
+        }
+    "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "caught instanceof OriginalTypeError"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "typeof globalThis.TypeError"),
+        "\"undefined\""
+    );
+
+    let error = JsNativeError::syntax().to_opaque(&mut context);
+    assert_eq!(
+        JsValue::from(error).to_string(&mut context).unwrap(),
+        "SyntaxError"
+    );
+}
+
+#[test]
+fn error_is_error() {
+    let mut context = Context::default();
+    let init = r"
+        class MyError extends TypeError {}
+        const fake = Object.create(Error.prototype);
+        const native = new RangeError('range');
+        Object.setPrototypeOf(native, null);
+    ";
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "Error.isError.length"), "1");
+    assert_eq!(forward(&mut context, "Error.isError(new Error())"), "true");
+    assert_eq!(
+        forward(&mut context, "Error.isError(new MyError())"),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "Error.isError(native)"), "true");
+    assert_eq!(forward(&mut context, "Error.isError(fake)"), "false");
+    assert_eq!(forward(&mut context, "Error.isError({})"), "false");
+    assert_eq!(forward(&mut context, "Error.isError('Error')"), "false");
+    assert_eq!(forward(&mut context, "Error.isError()"), "false");
+}
+
+#[test]
+fn structured_error() {
+    let mut context = Context::default();
+    let error = context
+        .eval(
+            r"
+            const inner = new RangeError('inner');
+            inner.stack = 'at inner';
+            throw new TypeError('outer', { cause: inner });
+            ",
+        )
+        .unwrap_err();
+
+    let error = JsStructuredError::from_error(&error);
+    assert!(error.is_error());
+    assert_eq!(error.name(), Some("TypeError"));
+    assert_eq!(error.message(), Some("outer"));
+    assert_eq!(error.stack(), None);
+    assert_eq!(error.to_string(), "TypeError: outer");
+
+    let cause = error.cause().expect("the error has a cause");
+    assert_eq!(cause.name(), Some("RangeError"));
+    assert_eq!(cause.message(), Some("inner"));
+    assert_eq!(cause.stack(), Some("at inner"));
+    assert!(cause.cause().is_none());
+}
+
+#[test]
+fn structured_error_does_not_run_script_code() {
+    let mut context = Context::default();
+    let error = context
+        .eval(
+            r"
+            var called = false;
+            const error = new Error('hidden');
+            Object.defineProperty(error, 'name', { get() { called = true; return 'Shadowed'; } });
+            Object.defineProperty(error, 'message', { get() { called = true; return 'shadowed'; } });
+            error.cause = new Proxy({}, { getOwnPropertyDescriptor() { called = true; } });
+            throw error;
+            ",
+        )
+        .unwrap_err();
+
+    let error = JsStructuredError::from_error(&error);
+    assert!(error.is_error());
+    assert_eq!(error.name(), None);
+    assert_eq!(error.message(), None);
+    let cause = error.cause().expect("the error has a cause");
+    assert!(!cause.is_error());
+    assert_eq!(cause.name(), None);
+    assert_eq!(forward(&mut context, "called"), "false");
+}
+
+#[test]
+fn structured_error_unusual_values() {
+    let mut context = Context::default();
+
+    let error = context.eval("throw 'plain string'").unwrap_err();
+    let error = JsStructuredError::from_error(&error);
+    assert!(!error.is_error());
+    assert_eq!(error.message(), Some("plain string"));
+    assert_eq!(error.to_string(), "plain string");
+
+    let error = context
+        .eval(
+            r"
+            const error = new Error();
+            error.message = { toString() { throw 'called'; } };
+            error.cause = error;
+            throw error;
+            ",
+        )
+        .unwrap_err();
+    let error = JsStructuredError::from_error(&error);
+    assert_eq!(error.name(), Some("Error"));
+    assert!(error.message().is_some());
+    assert!(error.cause().is_none());
+}
@@ -11,9 +11,10 @@
 
 use crate::{
     builtins::{BuiltIn, JsArgs},
+    context::declarations::GlobalDeclarations,
     object::FunctionBuilder,
     property::Attribute,
-    Context, JsValue,
+    Context, JsResult, JsValue,
 };
 use boa_profiler::Profiler;
 use rustc_hash::FxHashSet;
@@ -48,7 +49,7 @@ impl Eval {
     ///  - [ECMAScript reference][spec]
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-eval-x
-    fn eval(_: &JsValue, args: &[JsValue], context: &mut Context) -> Result<JsValue, JsValue> {
+    fn eval(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
         // 1. Return ? PerformEval(x, false, false).
         Self::perform_eval(args.get_or_undefined(0), false, false, context)
     }
@@ -64,7 +65,7 @@ impl Eval {
         direct: bool,
         strict: bool,
         context: &mut Context,
-    ) -> Result<JsValue, JsValue> {
+    ) -> JsResult<JsValue> {
         // 1. Assert: If direct is false, then strictCaller is also false.
         if !direct {
             debug_assert!(!strict);
@@ -77,13 +78,14 @@ impl Eval {
             return Ok(x.clone());
         };
 
+        // 3. Let evalRealm be the current Realm Record.
+        // 4. Perform ? HostEnsureCanCompileStrings(evalRealm).
+        context.host_hooks().ensure_can_compile_strings(context)?;
+
         // Because of implementation details the following code differs from the spec.
 
         // Parse the script body and handle early errors (6 - 11)
-        let body = match context.parse_eval(x.as_bytes(), direct, strict) {
-            Ok(body) => body,
-            Err(e) => return context.throw_syntax_error(e.to_string()),
-        };
+        let body = context.parse_eval(x.as_bytes(), direct, strict)?;
 
         // 12 - 13 are implicit in the call of `Context::compile_with_new_declarative`.
 
@@ -95,25 +97,38 @@ impl Eval {
             // Poison the current environment, because it may contain new declarations after/during eval.
             context.realm.environments.poison_current();
 
+            // Error if any var declaration in the eval code already exists as a let/const declaration in the current running environment.
+            // Strict eval code declares its variables in a new environment, so they cannot conflict.
+            let mut var_names = FxHashSet::default();
+            if !body.strict() {
+                let mut vars = FxHashSet::default();
+                body.var_declared_names_new(&mut vars);
+                if let Some(name) = context
+                    .realm
+                    .environments
+                    .has_lex_binding_until_function_environment(&vars)
+                {
+                    let name = context.interner().resolve_expect(name);
+                    let msg = format!("variable declaration {name} in eval function already exists as lexically declaration");
+                    return context.throw_syntax_error(msg);
+                }
+
+                if context.realm.environments.is_global_var_environment() {
+                    let declarations = GlobalDeclarations::new(&body, false);
+                    var_names = context.check_global_var_declarations(&declarations)?;
+                }
+            }
+
             // Set the compile time environment to the current running environment and save the number of current environments.
+            let compile_env = context.realm.compile_env.clone();
             context.realm.compile_env = context.realm.environments.current_compile_environment();
             let environments_len = context.realm.environments.len();
 
-            // Error if any var declaration in the eval code already exists as a let/const declaration in the current running environment.
-            let mut vars = FxHashSet::default();
-            body.var_declared_names_new(&mut vars);
-            if let Some(name) = context
-                .realm
-                .environments
-                .has_lex_binding_until_function_environment(&vars)
-            {
-                let name = context.interner().resolve_expect(name);
-                let msg = format!("variable declaration {name} in eval function already exists as lexically declaration");
-                return context.throw_syntax_error(msg);
-            }
-
             // Compile and execute the eval statement list.
-            let code_block = context.compile_with_new_declarative(&body, strict)?;
+            let code_block = context.compile_with_new_declarative(&body, strict);
+            context.realm.compile_env = compile_env;
+            let code_block = code_block?;
+            context.realm.global_var_names.extend(var_names);
             context
                 .realm
                 .environments
@@ -133,11 +148,25 @@ impl Eval {
             // Pop all environments before the eval execution.
             let environments = context.realm.environments.pop_to_global();
             let environments_len = context.realm.environments.len();
+            let compile_env = context.realm.compile_env.clone();
             context.realm.compile_env = context.realm.environments.current_compile_environment();
 
+            // Error if any var declaration in the eval code cannot be declared in the global environment.
+            let var_names = if body.strict() {
+                Ok(FxHashSet::default())
+            } else {
+                let declarations = GlobalDeclarations::new(&body, false);
+                context.check_global_var_declarations(&declarations)
+            };
+
             // Compile and execute the eval statement list.
-            let code_block = context.compile_with_new_declarative(&body, false)?;
-            let result = context.execute(code_block);
+            let code_block = var_names.and_then(|var_names| {
+                let code_block = context.compile_with_new_declarative(&body, false)?;
+                context.realm.global_var_names.extend(var_names);
+                Ok(code_block)
+            });
+            context.realm.compile_env = compile_env;
+            let result = code_block.and_then(|code_block| context.execute(code_block));
 
             // Restore all environments to the state from before the eval execution.
             context.realm.environments.truncate(environments_len);
@@ -1,10 +1,10 @@
 use crate::{
     environments::DeclarativeEnvironment,
-    object::{JsObject, ObjectData},
+    object::{internal_methods::check_property_count, JsObject, ObjectData},
     property::PropertyDescriptor,
     symbol::{self, WellKnownSymbols},
     syntax::ast::node::FormalParameterList,
-    Context, JsValue,
+    Context, JsResult, JsValue,
 };
 use boa_gc::{Finalize, Gc, Trace};
 use rustc_hash::FxHashMap;
@@ -63,6 +63,19 @@ pub enum Arguments {
 }
 
 impl Arguments {
+    /// Throws a `RangeError` if the arguments object of a call with the given arguments would
+    /// exceed the maximum property count of the [`ResourceLimits`][crate::context::ResourceLimits].
+    ///
+    /// The arguments object is created after the environment of the function is set up, where it
+    /// cannot fail, so this must be checked before calling the function.
+    pub(crate) fn check_property_count(
+        arguments_list: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<()> {
+        // The indices, "length", @@iterator and "callee".
+        check_property_count(arguments_list.len() + 3, context)
+    }
+
     /// Creates a new unmapped Arguments ordinary object.
     ///
     /// More information:
@@ -85,24 +98,28 @@ impl Arguments {
 
         // 4. Perform DefinePropertyOrThrow(obj, "length", PropertyDescriptor { [[Value]]: 𝔽(len),
         // [[Writable]]: true, [[Enumerable]]: false, [[Configurable]]: true }).
-        obj.define_property_or_throw(
+        obj.insert(
             "length",
             PropertyDescriptor::builder()
                 .value(len)
                 .writable(true)
                 .enumerable(false)
                 .configurable(true),
-            context,
-        )
-        .expect("Defining new own properties for a new ordinary object cannot fail");
+        );
 
         // 5. Let index be 0.
         // 6. Repeat, while index < len,
         for (index, value) in arguments_list.iter().cloned().enumerate() {
             // a. Let val be argumentsList[index].
             // b. Perform ! CreateDataPropertyOrThrow(obj, ! ToString(𝔽(index)), val).
-            obj.create_data_property_or_throw(index, value, context)
-                .expect("Defining new own properties for a new ordinary object cannot fail");
+            obj.insert(
+                index,
+                PropertyDescriptor::builder()
+                    .value(value)
+                    .writable(true)
+                    .enumerable(true)
+                    .configurable(true),
+            );
 
             // c. Set index to index + 1.
         }
@@ -111,32 +128,28 @@ impl Arguments {
         // [[Value]]: %Array.prototype.values%, [[Writable]]: true, [[Enumerable]]: false,
         // [[Configurable]]: true }).
         let values_function = context.intrinsics().objects().array_prototype_values();
-        obj.define_property_or_throw(
+        obj.insert(
             symbol::WellKnownSymbols::iterator(),
             PropertyDescriptor::builder()
                 .value(values_function)
                 .writable(true)
                 .enumerable(false)
                 .configurable(true),
-            context,
-        )
-        .expect("Defining new own properties for a new ordinary object cannot fail");
+        );
 
         let throw_type_error = context.intrinsics().objects().throw_type_error();
 
         // 8. Perform ! DefinePropertyOrThrow(obj, "callee", PropertyDescriptor {
         // [[Get]]: %ThrowTypeError%, [[Set]]: %ThrowTypeError%, [[Enumerable]]: false,
         // [[Configurable]]: false }).
-        obj.define_property_or_throw(
+        obj.insert(
             "callee",
             PropertyDescriptor::builder()
                 .get(throw_type_error.clone())
                 .set(throw_type_error)
                 .enumerable(false)
                 .configurable(false),
-            context,
-        )
-        .expect("Defining new own properties for a new ordinary object cannot fail");
+        );
 
         // 9. Return obj.
         obj
@@ -249,44 +262,38 @@ impl Arguments {
 
         // 16. Perform ! DefinePropertyOrThrow(obj, "length", PropertyDescriptor { [[Value]]: 𝔽(len),
         // [[Writable]]: true, [[Enumerable]]: false, [[Configurable]]: true }).
-        obj.define_property_or_throw(
+        obj.insert(
             "length",
             PropertyDescriptor::builder()
                 .value(len)
                 .writable(true)
                 .enumerable(false)
                 .configurable(true),
-            context,
-        )
-        .expect("Defining new own properties for a new ordinary object cannot fail");
+        );
 
         // 20. Perform ! DefinePropertyOrThrow(obj, @@iterator, PropertyDescriptor {
         // [[Value]]: %Array.prototype.values%, [[Writable]]: true, [[Enumerable]]: false,
         // [[Configurable]]: true }).
         let values_function = context.intrinsics().objects().array_prototype_values();
-        obj.define_property_or_throw(
+        obj.insert(
             WellKnownSymbols::iterator(),
             PropertyDescriptor::builder()
                 .value(values_function)
                 .writable(true)
                 .enumerable(false)
                 .configurable(true),
-            context,
-        )
-        .expect("Defining new own properties for a new ordinary object cannot fail");
+        );
 
         // 21. Perform ! DefinePropertyOrThrow(obj, "callee", PropertyDescriptor {
         // [[Value]]: func, [[Writable]]: true, [[Enumerable]]: false, [[Configurable]]: true }).
-        obj.define_property_or_throw(
+        obj.insert(
             "callee",
             PropertyDescriptor::builder()
                 .value(func.clone())
                 .writable(true)
                 .enumerable(false)
                 .configurable(true),
-            context,
-        )
-        .expect("Defining new own properties for a new ordinary object cannot fail");
+        );
 
         // 22. Return obj.
         obj
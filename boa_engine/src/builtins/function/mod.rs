@@ -14,7 +14,7 @@
 use crate::{
     builtins::{BuiltIn, JsArgs},
     bytecompiler::{FunctionCompiler, FunctionKind},
-    context::intrinsics::StandardConstructors,
+    context::{intrinsics::StandardConstructors, Intrinsic},
     environments::DeclarativeEnvironmentStack,
     object::{
         internal_methods::get_prototype_from_constructor, JsObject, NativeObject, Object,
@@ -23,12 +23,9 @@ use crate::{
     object::{ConstructorBuilder, FunctionBuilder, JsFunction, PrivateElement, Ref, RefMut},
     property::{Attribute, PropertyDescriptor, PropertyKey},
     symbol::WellKnownSymbols,
-    syntax::{
-        ast::node::{FormalParameterList, StatementList},
-        Parser,
-    },
+    syntax::ast::node::{FormalParameterList, StatementList},
     value::IntegerOrInfinity,
-    Context, JsResult, JsString, JsValue,
+    Context, JsNativeError, JsResult, JsString, JsValue,
 };
 use boa_gc::{self, custom_trace, Finalize, Gc, Trace};
 use boa_interner::Sym;
@@ -42,8 +39,6 @@ use std::{
 };
 use tap::{Conv, Pipe};
 
-use super::promise::PromiseCapability;
-
 pub(crate) mod arguments;
 #[cfg(test)]
 mod tests;
@@ -144,23 +139,10 @@ impl ConstructorKind {
 ///  - [ECMAScript specification][spec]
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-classfielddefinition-record-specification-type
-#[derive(Clone, Debug, Finalize)]
+#[derive(Clone, Debug, Trace, Finalize)]
 pub enum ClassFieldDefinition {
-    Public(PropertyKey, JsFunction),
-    Private(Sym, JsFunction),
-}
-
-unsafe impl Trace for ClassFieldDefinition {
-    custom_trace! {this, {
-        match this {
-            Self::Public(_key, func) => {
-                mark(func);
-            }
-            Self::Private(_, func) => {
-                mark(func);
-            }
-        }
-    }}
+    Public(#[unsafe_ignore_trace] PropertyKey, JsFunction),
+    Private(#[unsafe_ignore_trace] Sym, JsFunction),
 }
 
 /// Wrapper for `Gc<GcCell<dyn NativeObject>>` that allows passing additional
@@ -240,7 +222,6 @@ pub enum Function {
     Async {
         code: Gc<crate::vm::CodeBlock>,
         environments: DeclarativeEnvironmentStack,
-        promise_capability: PromiseCapability,
     },
     Generator {
         code: Gc<crate::vm::CodeBlock>,
@@ -266,12 +247,8 @@ unsafe impl Trace for Function {
                     mark(elem);
                 }
             }
-            Self::Async { code, environments, promise_capability } => {
-                mark(code);
-                mark(environments);
-                mark(promise_capability);
-            }
-            Self::Generator { code, environments }
+            Self::Async { code, environments }
+            | Self::Generator { code, environments }
             | Self::AsyncGenerator { code, environments } => {
                 mark(code);
                 mark(environments);
@@ -370,18 +347,6 @@ impl Function {
             private_methods.push((name, method));
         }
     }
-
-    /// Returns the promise capability if the function is an async function.
-    pub(crate) fn get_promise_capability(&self) -> Option<&PromiseCapability> {
-        if let Self::Async {
-            promise_capability, ..
-        } = self
-        {
-            Some(promise_capability)
-        } else {
-            None
-        }
-    }
 }
 
 /// Creates a new member function of a `Object` or `prototype`.
@@ -491,6 +456,13 @@ impl BuiltInFunctionObject {
             StandardConstructors::function
         };
 
+        // 1. Let currentRealm be the current Realm Record.
+        // 2. Perform ? HostEnsureCanCompileStrings(currentRealm).
+        context.host_hooks().ensure_can_compile_strings(context)?;
+        if !context.has_intrinsic(Intrinsic::FUNCTION) {
+            return context.throw_eval_error("dynamic function creation is disabled");
+        }
+
         let prototype = get_prototype_from_constructor(new_target, default, context)?;
         if let Some((body_arg, args)) = args.split_last() {
             let parameters = if args.is_empty() {
@@ -503,16 +475,17 @@ impl BuiltInFunctionObject {
                 let mut parameters = parameters.join(",");
                 parameters.push(')');
 
-                let parameters = match Parser::new(parameters.as_bytes()).parse_formal_parameters(
+                let mut parser = context.parser(parameters.as_bytes());
+                let parameters = match parser.parse_formal_parameters(
                     context.interner_mut(),
                     generator,
                     r#async,
                 ) {
                     Ok(parameters) => parameters,
                     Err(e) => {
-                        return context.throw_syntax_error(format!(
-                            "failed to parse function parameters: {e}"
-                        ))
+                        return Err(JsNativeError::syntax()
+                            .with_message(format!("failed to parse function parameters: {e}"))
+                            .into())
                     }
                 };
 
@@ -541,21 +514,23 @@ impl BuiltInFunctionObject {
 
             let body_arg = body_arg.to_string(context)?;
 
-            let body = match Parser::new(body_arg.as_bytes()).parse_function_body(
-                context.interner_mut(),
-                generator,
-                r#async,
-            ) {
+            let mut parser = context.parser(body_arg.as_bytes());
+            let body = match parser.parse_function_body(context.interner_mut(), generator, r#async)
+            {
                 Ok(statement_list) => statement_list,
                 Err(e) => {
-                    return context
-                        .throw_syntax_error(format!("failed to parse function body: {e}"))
+                    return Err(JsNativeError::syntax()
+                        .with_message(format!("failed to parse function body: {e}"))
+                        .into())
                 }
             };
 
+            // The body is also strict mode code if the context evaluates all code as strict.
+            let strict = body.strict() || context.strict();
+
             // Early Error: If BindingIdentifier is present and the source text matched by BindingIdentifier is strict mode code,
             // it is a Syntax Error if the StringValue of BindingIdentifier is "eval" or "arguments".
-            if body.strict() {
+            if strict {
                 for parameter in parameters.parameters.iter() {
                     for name in parameter.names() {
                         if name == Sym::ARGUMENTS || name == Sym::EVAL {
@@ -569,7 +544,7 @@ impl BuiltInFunctionObject {
 
             // Early Error: If the source code matching FormalParameters is strict mode code,
             // the Early Error rules for UniqueFormalParameters : FormalParameters are applied.
-            if (body.strict()) && parameters.has_duplicates() {
+            if strict && parameters.has_duplicates() {
                 return context
                     .throw_syntax_error("Duplicate parameter name not allowed in this context");
             }
@@ -602,12 +577,21 @@ impl BuiltInFunctionObject {
                 }
             }
 
+            // The function is always created in the global environment, even if the current
+            // compile time environment is the one of a function that called a direct `eval`.
+            let compile_env = std::mem::replace(
+                &mut context.realm.compile_env,
+                context.realm.environments.global_compile_environment(),
+            );
             let code = FunctionCompiler::new()
                 .name(Sym::ANONYMOUS)
                 .generator(generator)
                 .r#async(r#async)
                 .kind(FunctionKind::Expression)
-                .compile(&parameters, &body, context)?;
+                .strict(strict)
+                .compile(&parameters, &body, context);
+            context.realm.compile_env = compile_env;
+            let code = code?;
 
             let environments = context.realm.environments.pop_to_global();
 
@@ -671,7 +655,7 @@ impl BuiltInFunctionObject {
         // 1. Let func be the this value.
         // 2. If IsCallable(func) is false, throw a TypeError exception.
         let func = this.as_callable().ok_or_else(|| {
-            context.construct_type_error(format!("{} is not a function", this.display()))
+            JsNativeError::typ().with_message(format!("{} is not a function", this.display()))
         })?;
 
         let this_arg = args.get_or_undefined(0);
@@ -711,7 +695,8 @@ impl BuiltInFunctionObject {
         // 1. Let Target be the this value.
         // 2. If IsCallable(Target) is false, throw a TypeError exception.
         let target = this.as_callable().ok_or_else(|| {
-            context.construct_type_error("cannot bind `this` without a `[[Call]]` internal method")
+            JsNativeError::typ()
+                .with_message("cannot bind `this` without a `[[Call]]` internal method")
         })?;
 
         let this_arg = args.get_or_undefined(0).clone();
@@ -752,16 +737,16 @@ impl BuiltInFunctionObject {
         }
 
         // 7. Perform ! SetFunctionLength(F, L).
-        f.define_property_or_throw(
+        // NOTE: The property is inserted directly, since the `length` of a new function isn't
+        // counted against the maximum property count.
+        f.insert(
             "length",
             PropertyDescriptor::builder()
                 .value(l)
                 .writable(false)
                 .enumerable(false)
                 .configurable(true),
-            context,
-        )
-        .expect("defining the `length` property for a new object should not fail");
+        );
 
         // 8. Let targetName be ? Get(Target, "name").
         let target_name = target.get("name", context)?;
@@ -772,7 +757,7 @@ impl BuiltInFunctionObject {
             .map_or(JsString::new(""), Clone::clone);
 
         // 10. Perform SetFunctionName(F, targetName, "bound").
-        set_function_name(&f, &target_name.into(), Some("bound"), context);
+        set_function_name(&f, &target_name.into(), Some("bound"));
 
         // 11. Return F.
         Ok(f.into())
@@ -792,7 +777,7 @@ impl BuiltInFunctionObject {
         // 1. Let func be the this value.
         // 2. If IsCallable(func) is false, throw a TypeError exception.
         let func = this.as_callable().ok_or_else(|| {
-            context.construct_type_error(format!("{} is not a function", this.display()))
+            JsNativeError::typ().with_message(format!("{} is not a function", this.display()))
         })?;
         let this_arg = args.get_or_undefined(0);
 
@@ -809,7 +794,7 @@ impl BuiltInFunctionObject {
         let function = object
             .as_deref()
             .and_then(Object::as_function)
-            .ok_or_else(|| context.construct_type_error("Not a function"))?;
+            .ok_or_else(|| JsNativeError::typ().with_message("Not a function"))?;
 
         let name = {
             // Is there a case here where if there is no name field on a value
@@ -927,12 +912,7 @@ impl BuiltIn for BuiltInFunctionObject {
 ///  - [ECMAScript reference][spec]
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-setfunctionname
-fn set_function_name(
-    function: &JsObject,
-    name: &PropertyKey,
-    prefix: Option<&str>,
-    context: &mut Context,
-) {
+fn set_function_name(function: &JsObject, name: &PropertyKey, prefix: Option<&str>) {
     // 1. Assert: F is an extensible object that does not have a "name" own property.
     // 2. If Type(name) is Symbol, then
     let mut name = match name {
@@ -968,17 +948,16 @@ fn set_function_name(
 
     // 6. Return ! DefinePropertyOrThrow(F, "name", PropertyDescriptor { [[Value]]: name,
     // [[Writable]]: false, [[Enumerable]]: false, [[Configurable]]: true }).
-    function
-        .define_property_or_throw(
-            "name",
-            PropertyDescriptor::builder()
-                .value(name.into_owned())
-                .writable(false)
-                .enumerable(false)
-                .configurable(true),
-            context,
-        )
-        .expect("defining the `name` property must not fail per the spec");
+    // NOTE: The property is inserted directly, since the `name` of a new function isn't counted
+    // against the maximum property count.
+    function.insert(
+        "name",
+        PropertyDescriptor::builder()
+            .value(name.into_owned())
+            .writable(false)
+            .enumerable(false)
+            .configurable(true),
+    );
 }
 
 /// Binds a `Function Object` when `bind` is called.
@@ -2,7 +2,7 @@ use crate::{
     forward, forward_val,
     object::FunctionBuilder,
     property::{Attribute, PropertyDescriptor},
-    Context, JsString,
+    Context, JsNativeError, JsString,
 };
 
 #[allow(clippy::float_cmp)]
@@ -139,7 +139,9 @@ fn function_prototype_call_throw() {
         let call = Function.prototype.call;
         call(call)
         "#;
-    let value = forward_val(&mut context, throw).unwrap_err();
+    let value = forward_val(&mut context, throw)
+        .unwrap_err()
+        .to_opaque(&mut context);
     assert!(value.is_object());
     let string = value.to_string(&mut context).unwrap();
     assert!(string.starts_with("TypeError"));
@@ -247,7 +249,7 @@ fn closure_capture_clone() {
                     .__get_own_property__(&"key".into(), context)?
                     .and_then(|prop| prop.value().cloned())
                     .and_then(|val| val.as_string().cloned())
-                    .ok_or_else(|| context.construct_type_error("invalid `key` property"))?,
+                    .ok_or_else(|| JsNativeError::typ().with_message("invalid `key` property"))?,
             );
             Ok(hw.into())
         },
@@ -260,3 +262,53 @@ fn closure_capture_clone() {
 
     assert_eq!(forward(&mut context, "closure()"), "\"Hello world!\"");
 }
+
+#[test]
+fn builtin_functions_shape() {
+    let mut context = Context::default();
+
+    let lengths = [
+        ("Array.prototype.map", 1),
+        ("Array.prototype.reduce", 1),
+        ("Array.prototype.slice", 2),
+        ("Array.from", 1),
+        ("Object.defineProperty", 3),
+        ("String.prototype.replace", 2),
+        ("Math.max", 2),
+        ("JSON.stringify", 3),
+        ("Function.prototype.apply", 2),
+        ("Promise.prototype.then", 2),
+    ];
+    for (function, length) in lengths {
+        assert_eq!(
+            forward(&mut context, format!("{function}.length")),
+            length.to_string(),
+            "{function}"
+        );
+    }
+
+    for function in [
+        "Array.prototype.map",
+        "Math.max",
+        "JSON.parse",
+        "Object.keys",
+        "Promise.resolve",
+    ] {
+        assert_eq!(
+            forward(
+                &mut context,
+                format!("try {{ new {function}(); false }} catch (e) {{ e instanceof TypeError }}")
+            ),
+            "true",
+            "{function}"
+        );
+        assert_eq!(
+            forward(
+                &mut context,
+                format!("{function}.hasOwnProperty('prototype')")
+            ),
+            "false",
+            "{function}"
+        );
+    }
+}
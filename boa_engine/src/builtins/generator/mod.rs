@@ -10,14 +10,14 @@
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Generator
 
 use crate::{
-    builtins::{iterable::create_iter_result_object, BuiltIn, JsArgs},
+    builtins::{iterable::create_iter_result_object_or_throw, BuiltIn, JsArgs},
     environments::DeclarativeEnvironmentStack,
     object::{ConstructorBuilder, JsObject, ObjectData},
     property::{Attribute, PropertyDescriptor},
     symbol::WellKnownSymbols,
     value::JsValue,
     vm::{CallFrame, GeneratorResumeKind, ReturnType},
-    Context, JsResult,
+    Context, JsError, JsNativeError, JsResult,
 };
 use boa_gc::{Cell, Finalize, Gc, Trace};
 use boa_profiler::Profiler;
@@ -195,7 +195,11 @@ impl Generator {
         // 1. Let g be the this value.
         // 2. Let C be ThrowCompletion(exception).
         // 3. Return ? GeneratorResumeAbrupt(g, C, empty).
-        Self::generator_resume_abrupt(this, Err(args.get_or_undefined(0).clone()), context)
+        Self::generator_resume_abrupt(
+            this,
+            Err(JsError::from_opaque(args.get_or_undefined(0).clone())),
+            context,
+        )
     }
 
     /// `27.5.3.3 GeneratorResume ( generator, value, generatorBrand )`
@@ -212,21 +216,19 @@ impl Generator {
         // 1. Let state be ? GeneratorValidate(generator, generatorBrand).
         let mut generator_obj_mut = generator_obj.borrow_mut();
         let generator = generator_obj_mut.as_generator_mut().ok_or_else(|| {
-            context.construct_type_error("generator resumed on non generator object")
+            JsNativeError::typ().with_message("generator resumed on non generator object")
         })?;
         let state = generator.state;
 
         if state == GeneratorState::Executing {
-            return Err(context.construct_type_error("Generator should not be executing"));
+            return Err(JsNativeError::typ()
+                .with_message("Generator should not be executing")
+                .into());
         }
 
         // 2. If state is completed, return CreateIterResultObject(undefined, true).
         if state == GeneratorState::Completed {
-            return Ok(create_iter_result_object(
-                JsValue::undefined(),
-                true,
-                context,
-            ));
+            return create_iter_result_object_or_throw(JsValue::undefined(), true, context);
         }
 
         // 3. Assert: state is either suspendedStart or suspendedYield.
@@ -283,11 +285,11 @@ impl Generator {
                 generator.state = GeneratorState::SuspendedYield;
                 drop(generator_context);
                 generator.context = Some(generator_context_cell);
-                Ok(create_iter_result_object(value, false, context))
+                create_iter_result_object_or_throw(value, false, context)
             }
             Ok((value, _)) => {
                 generator.state = GeneratorState::Completed;
-                Ok(create_iter_result_object(value, true, context))
+                create_iter_result_object_or_throw(value, true, context)
             }
             Err(value) => {
                 generator.state = GeneratorState::Completed;
@@ -314,16 +316,18 @@ impl Generator {
     ) -> JsResult<JsValue> {
         // 1. Let state be ? GeneratorValidate(generator, generatorBrand).
         let generator_obj = this.as_object().ok_or_else(|| {
-            context.construct_type_error("generator resumed on non generator object")
+            JsNativeError::typ().with_message("generator resumed on non generator object")
         })?;
         let mut generator_obj_mut = generator_obj.borrow_mut();
         let generator = generator_obj_mut.as_generator_mut().ok_or_else(|| {
-            context.construct_type_error("generator resumed on non generator object")
+            JsNativeError::typ().with_message("generator resumed on non generator object")
         })?;
         let mut state = generator.state;
 
         if state == GeneratorState::Executing {
-            return Err(context.construct_type_error("Generator should not be executing"));
+            return Err(JsNativeError::typ()
+                .with_message("Generator should not be executing")
+                .into());
         }
 
         // 2. If state is suspendedStart, then
@@ -341,7 +345,7 @@ impl Generator {
             // a. If abruptCompletion.[[Type]] is return, then
             if let Ok(value) = abrupt_completion {
                 // i. Return CreateIterResultObject(abruptCompletion.[[Value]], true).
-                return Ok(create_iter_result_object(value, true, context));
+                return create_iter_result_object_or_throw(value, true, context);
             }
             // b. Return Completion(abruptCompletion).
             return abrupt_completion;
@@ -378,7 +382,8 @@ impl Generator {
                 context.vm.frame_mut().generator_resume_kind = GeneratorResumeKind::Return;
                 context.run()
             }
-            Err(value) => {
+            Err(error) => {
+                let value = error.to_opaque(context);
                 context.vm.push(value);
                 context.vm.frame_mut().generator_resume_kind = GeneratorResumeKind::Throw;
                 context.run()
@@ -404,11 +409,11 @@ impl Generator {
                 generator.state = GeneratorState::SuspendedYield;
                 drop(generator_context);
                 generator.context = Some(generator_context_cell);
-                Ok(create_iter_result_object(value, false, context))
+                create_iter_result_object_or_throw(value, false, context)
             }
             Ok((value, _)) => {
                 generator.state = GeneratorState::Completed;
-                Ok(create_iter_result_object(value, true, context))
+                create_iter_result_object_or_throw(value, true, context)
             }
             Err(value) => {
                 generator.state = GeneratorState::Completed;
@@ -8,6 +8,10 @@
 //! [spec]: https://tc39.es/ecma402/#datetimeformat-objects
 
 use crate::{
+    builtins::{
+        intl::{available_locales, canonicalize_locale_list, supported_locales},
+        JsArgs,
+    },
     context::intrinsics::StandardConstructors,
     object::{
         internal_methods::get_prototype_from_constructor, ConstructorBuilder, JsFunction, JsObject,
@@ -18,6 +22,9 @@ use crate::{
 
 use boa_gc::{Finalize, Trace};
 use boa_profiler::Profiler;
+use icu_datetime::provider::calendar::DateSymbolsV1Marker;
+use icu_locid::{unicode_ext_key, unicode_ext_value, Locale};
+use icu_provider::prelude::*;
 
 /// JavaScript `Intl.DateTimeFormat` object.
 #[derive(Debug, Clone, Trace, Finalize)]
@@ -52,6 +59,7 @@ impl DateTimeFormat {
         ConstructorBuilder::new(context, Self::constructor)
             .name(Self::NAME)
             .length(0)
+            .static_method(Self::supported_locales_of, "supportedLocalesOf", 1)
             .build()
     }
 }
@@ -113,6 +121,60 @@ impl DateTimeFormat {
         // 5. Return dateTimeFormat.
         Ok(date_time_format.into())
     }
+
+    /// `Intl.DateTimeFormat.supportedLocalesOf ( locales [ , options ] )`
+    ///
+    /// Returns the requested locales for which the data provider has date and time symbols.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.datetimeformat.supportedlocalesof
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DateTimeFormat/supportedLocalesOf
+    pub(crate) fn supported_locales_of(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let availableLocales be %DateTimeFormat%.[[AvailableLocales]].
+        // 2. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales: Vec<JsString> = canonicalize_locale_list(args, context)?
+            .into_iter()
+            .map(|locale| locale.to_string().into())
+            .collect();
+        let available = available_locales(&requested_locales, |locale| {
+            has_date_time_data(locale, context)
+        });
+
+        // 3. Return ? SupportedLocales(availableLocales, requestedLocales, options).
+        supported_locales(
+            &available,
+            &requested_locales,
+            args.get_or_undefined(1),
+            context,
+        )
+    }
+}
+
+/// Returns `true` if the data provider has date and time symbols for `locale`.
+///
+/// The data of the provider is split by calendar, so this looks for the symbols of the Gregorian
+/// calendar, which is the calendar used by `Intl.DateTimeFormat`.
+fn has_date_time_data(locale: &Locale, context: &Context) -> bool {
+    let mut locale = locale.clone();
+    locale
+        .extensions
+        .unicode
+        .keywords
+        .set(unicode_ext_key!("ca"), unicode_ext_value!("gregory"));
+    let request = DataRequest {
+        options: ResourceOptions::from(&locale),
+        metadata: Default::default(),
+    };
+    ResourceProvider::<DateSymbolsV1Marker>::load_resource(context.icu().provider(), &request)
+        .and_then(DataResponse::take_payload)
+        .is_ok()
 }
 
 /// Represents the `required` and `defaults` arguments in the abstract operation
@@ -8,15 +8,21 @@
 //! [spec]: https://tc39.es/ecma402/#intl-object
 
 use crate::{
-    builtins::intl::date_time_format::DateTimeFormat,
+    builtins::intl::{
+        date_time_format::DateTimeFormat, plural_rules::PluralRules,
+        relative_time_format::RelativeTimeFormat, segmenter::Segmenter,
+    },
     builtins::{Array, BuiltIn, JsArgs},
     object::{JsObject, ObjectInitializer},
     property::Attribute,
     symbol::WellKnownSymbols,
-    Context, JsResult, JsString, JsValue,
+    Context, JsNativeError, JsResult, JsString, JsValue,
 };
 
 pub mod date_time_format;
+pub mod plural_rules;
+pub mod relative_time_format;
+pub mod segmenter;
 #[cfg(test)]
 mod tests;
 
@@ -39,6 +45,9 @@ impl BuiltIn for Intl {
 
         let string_tag = WellKnownSymbols::to_string_tag();
         let date_time_format = DateTimeFormat::init(context);
+        let plural_rules = PluralRules::init(context);
+        let relative_time_format = RelativeTimeFormat::init(context);
+        let segmenter = Segmenter::init(context);
         ObjectInitializer::new(context)
             .function(Self::get_canonical_locales, "getCanonicalLocales", 1)
             .property(
@@ -51,6 +60,21 @@ impl BuiltIn for Intl {
                 date_time_format,
                 Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
             )
+            .property(
+                "PluralRules",
+                plural_rules,
+                Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .property(
+                "RelativeTimeFormat",
+                relative_time_format,
+                Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .property(
+                "Segmenter",
+                segmenter,
+                Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
             .build()
             .conv::<JsValue>()
             .pipe(Some)
@@ -80,7 +104,7 @@ impl Intl {
         Ok(JsValue::Object(Array::create_array_from_list(
             ll.into_iter().map(|loc| loc.to_string().into()),
             context,
-        )))
+        )?))
     }
 }
 
@@ -229,6 +253,99 @@ fn best_fit_matcher(
     lookup_matcher(available_locales, requested_locales, canonicalizer)
 }
 
+/// Abstract operation `LookupSupportedLocales ( availableLocales, requestedLocales )`
+///
+/// Returns the subset of the provided BCP 47 language priority list `requestedLocales` for which
+/// `availableLocales` has a matching locale when using the BCP 47 Lookup algorithm. Locales
+/// appear in the same order in the returned list as in `requestedLocales`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma402/#sec-lookupsupportedlocales
+fn lookup_supported_locales(
+    available_locales: &[JsString],
+    requested_locales: &[JsString],
+) -> Vec<JsString> {
+    // 1. Let subset be a new empty List.
+    // 2. For each element locale of requestedLocales, do
+    //    a. Let noExtensionsLocale be the String value that is locale with any Unicode locale
+    //       extension sequences removed.
+    //    b. Let availableLocale be ! BestAvailableLocale(availableLocales, noExtensionsLocale).
+    //    c. If availableLocale is not undefined, append locale to the end of subset.
+    // 3. Return subset.
+    requested_locales
+        .iter()
+        .filter(|locale| {
+            locale.parse::<Locale>().map_or(false, |parsed| {
+                let no_extensions_locale = JsString::new(parsed.id.to_string());
+                best_available_locale(available_locales, &no_extensions_locale).is_some()
+            })
+        })
+        .cloned()
+        .collect()
+}
+
+/// Abstract operation `BestFitSupportedLocales ( availableLocales, requestedLocales )`
+///
+/// Returns the subset of the provided BCP 47 language priority list `requestedLocales` for which
+/// `availableLocales` has a matching locale when using the Best Fit Matcher algorithm. Locales
+/// appear in the same order in the returned list as in `requestedLocales`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma402/#sec-bestfitsupportedlocales
+fn best_fit_supported_locales(
+    available_locales: &[JsString],
+    requested_locales: &[JsString],
+) -> Vec<JsString> {
+    lookup_supported_locales(available_locales, requested_locales)
+}
+
+/// Abstract operation `SupportedLocales ( availableLocales, requestedLocales, options )`
+///
+/// Returns the subset of the provided BCP 47 language priority list `requestedLocales` for which
+/// `availableLocales` has a matching locale.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma402/#sec-supportedlocales
+pub(crate) fn supported_locales(
+    available_locales: &[JsString],
+    requested_locales: &[JsString],
+    options: &JsValue,
+    context: &mut Context,
+) -> JsResult<JsValue> {
+    // 1. Set options to ? CoerceOptionsToObject(options).
+    let options = coerce_options_to_object(options, context)?;
+
+    // 2. Let matcher be ? GetOption(options, "localeMatcher", "string", « "lookup", "best fit" », "best fit").
+    let matcher = get_option(
+        &options,
+        "localeMatcher",
+        &GetOptionType::String,
+        &[JsString::new("lookup"), JsString::new("best fit")],
+        &JsValue::new("best fit"),
+        context,
+    )?
+    .to_string(context)?;
+
+    // 3. If matcher is "best fit", then
+    //    a. Let supportedLocales be BestFitSupportedLocales(availableLocales, requestedLocales).
+    // 4. Else,
+    //    a. Let supportedLocales be LookupSupportedLocales(availableLocales, requestedLocales).
+    let supported = if matcher.as_str() == "best fit" {
+        best_fit_supported_locales(available_locales, requested_locales)
+    } else {
+        lookup_supported_locales(available_locales, requested_locales)
+    };
+
+    // 5. Return CreateArrayFromList(supportedLocales).
+    Ok(Array::create_array_from_list(supported.into_iter().map(Into::into), context)?.into())
+}
+
 /// `Keyword` structure is a pair of keyword key and keyword value.
 #[derive(Debug)]
 struct Keyword {
@@ -437,7 +554,7 @@ fn canonicalize_locale_list(args: &[JsValue], context: &mut Context) -> JsResult
     // TODO: check if Type(locales) is object and handle the internal slots
     let o = if locales.is_string() {
         // a. Let O be CreateArrayFromList(« locales »).
-        Array::create_array_from_list([locales.clone()], context)
+        Array::create_array_from_list([locales.clone()], context)?
     } else {
         // 4. Else,
         // a. Let O be ? ToObject(locales).
@@ -469,7 +586,8 @@ fn canonicalize_locale_list(args: &[JsValue], context: &mut Context) -> JsResult
             let tag = k_value.to_string(context)?;
             // v. If IsStructurallyValidLanguageTag(tag) is false, throw a RangeError exception.
             let mut tag = tag.parse().map_err(|_| {
-                context.construct_range_error("locale is not a structurally valid language tag")
+                JsNativeError::range()
+                    .with_message("locale is not a structurally valid language tag")
             })?;
 
             // vi. Let canonicalizedTag be CanonicalizeUnicodeLocaleId(tag).
@@ -704,6 +822,54 @@ fn resolve_locale(
     result
 }
 
+/// Abstract operation `CoerceOptionsToObject ( options )`
+///
+/// Coerces `options` into an object for use by the abstract operation `GetOption`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma402/#sec-coerceoptionstoobject
+pub(crate) fn coerce_options_to_object(
+    options: &JsValue,
+    context: &mut Context,
+) -> JsResult<JsObject> {
+    // 1. If options is undefined, then
+    if options.is_undefined() {
+        // a. Return OrdinaryObjectCreate(null).
+        return Ok(JsObject::empty());
+    }
+
+    // 2. Return ? ToObject(options).
+    options.to_object(context)
+}
+
+/// Abstract operation `GetOptionsObject ( options )`
+///
+/// Returns `options` if it is an object, or a new object without a prototype if it is
+/// undefined, and throws a `TypeError` otherwise.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma402/#sec-getoptionsobject
+pub(crate) fn get_options_object(options: &JsValue, context: &mut Context) -> JsResult<JsObject> {
+    // 1. If options is undefined, then
+    if options.is_undefined() {
+        // a. Return OrdinaryObjectCreate(null).
+        return Ok(JsObject::empty());
+    }
+
+    // 2. If Type(options) is Object, then
+    if let Some(options) = options.as_object() {
+        // a. Return options.
+        return Ok(options.clone());
+    }
+
+    // 3. Throw a TypeError exception.
+    context.throw_type_error("options must be an object or undefined")
+}
+
 #[allow(unused)]
 pub(crate) enum GetOptionType {
     String,
@@ -822,6 +988,199 @@ pub(crate) fn default_number_option(
     Ok(Some(value.floor()))
 }
 
+/// The rounding of the digit options of a number formatting service.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum RoundingType {
+    FractionDigits { minimum: usize, maximum: usize },
+    SignificantDigits { minimum: usize, maximum: usize },
+}
+
+/// The digit options of a number formatting service, as set by
+/// [`set_number_format_digit_options`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct DigitOptions {
+    pub(crate) minimum_integer_digits: usize,
+    pub(crate) rounding: RoundingType,
+}
+
+impl DigitOptions {
+    /// Formats the absolute value of the finite number `x` with these digit options,
+    /// returning its integer digits and its fraction digits.
+    pub(crate) fn format_digits(self, x: f64) -> (String, String) {
+        let x = x.abs();
+        let (mut integer, fraction) = match self.rounding {
+            RoundingType::FractionDigits { minimum, maximum } => {
+                let formatted = format!("{x:.maximum$}");
+                let (integer, fraction) = formatted
+                    .split_once('.')
+                    .unwrap_or((formatted.as_str(), ""));
+                let mut fraction = fraction.to_string();
+                while fraction.len() > minimum && fraction.ends_with('0') {
+                    fraction.pop();
+                }
+                (integer.to_string(), fraction)
+            }
+            RoundingType::SignificantDigits { minimum, maximum } => {
+                if x == 0.0 {
+                    ("0".to_string(), "0".repeat(minimum - 1))
+                } else {
+                    let formatted = format!("{x:.precision$e}", precision = maximum - 1);
+                    let (mantissa, exponent) = formatted
+                        .split_once('e')
+                        .expect("exponential notation must contain an exponent");
+                    let exponent: i32 = exponent.parse().expect("exponent must be an integer");
+                    let mut digits = mantissa.replace('.', "");
+                    while digits.len() > minimum && digits.ends_with('0') {
+                        digits.pop();
+                    }
+                    if exponent < 0 {
+                        let zeros = "0".repeat(exponent.unsigned_abs() as usize - 1);
+                        ("0".to_string(), zeros + &digits)
+                    } else {
+                        let integer_digits = exponent as usize + 1;
+                        if digits.len() > integer_digits {
+                            let fraction = digits.split_off(integer_digits);
+                            (digits, fraction)
+                        } else {
+                            let zeros = "0".repeat(integer_digits - digits.len());
+                            (digits + &zeros, String::new())
+                        }
+                    }
+                }
+            }
+        };
+
+        if integer.len() < self.minimum_integer_digits {
+            integer = "0".repeat(self.minimum_integer_digits - integer.len()) + &integer;
+        }
+        (integer, fraction)
+    }
+}
+
+/// Abstract operation `SetNumberFormatDigitOptions ( intlObj, options, mnfdDefault, mxfdDefault, notation )`
+///
+/// Reads the digit options of a number formatting service from the provided `options`
+/// object, using the notation `"standard"`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma402/#sec-setnfdigitoptions
+#[allow(clippy::similar_names)]
+pub(crate) fn set_number_format_digit_options(
+    options: &JsObject,
+    mnfd_default: usize,
+    mxfd_default: usize,
+    context: &mut Context,
+) -> JsResult<DigitOptions> {
+    // 1. Let mnid be ? GetNumberOption(options, "minimumIntegerDigits,", 1, 21, 1).
+    let mnid = get_number_option(
+        options,
+        "minimumIntegerDigits",
+        1.0,
+        21.0,
+        Some(1.0),
+        context,
+    )?
+    .unwrap_or(1.0);
+    // 2. Let mnfd be ? Get(options, "minimumFractionDigits").
+    let mnfd = options.get("minimumFractionDigits", context)?;
+    // 3. Let mxfd be ? Get(options, "maximumFractionDigits").
+    let mxfd = options.get("maximumFractionDigits", context)?;
+    // 4. Let mnsd be ? Get(options, "minimumSignificantDigits").
+    let mnsd = options.get("minimumSignificantDigits", context)?;
+    // 5. Let mxsd be ? Get(options, "maximumSignificantDigits").
+    let mxsd = options.get("maximumSignificantDigits", context)?;
+
+    // 6. Set intlObj.[[MinimumIntegerDigits]] to mnid.
+    let minimum_integer_digits = mnid as usize;
+
+    // 7. If mnsd is not undefined or mxsd is not undefined, then
+    let rounding = if !mnsd.is_undefined() || !mxsd.is_undefined() {
+        // a. Set intlObj.[[RoundingType]] to significantDigits.
+        // b. Let mnsd be ? DefaultNumberOption(mnsd, 1, 21, 1).
+        let minimum = default_number_option(&mnsd, 1.0, 21.0, Some(1.0), context)?.unwrap_or(1.0);
+        // c. Let mxsd be ? DefaultNumberOption(mxsd, mnsd, 21, 21).
+        let maximum =
+            default_number_option(&mxsd, minimum, 21.0, Some(21.0), context)?.unwrap_or(21.0);
+        // d. Set intlObj.[[MinimumSignificantDigits]] to mnsd.
+        // e. Set intlObj.[[MaximumSignificantDigits]] to mxsd.
+        RoundingType::SignificantDigits {
+            minimum: minimum as usize,
+            maximum: maximum as usize,
+        }
+    } else {
+        // 8. Else if mnfd is not undefined or mxfd is not undefined, then
+        // a. Set intlObj.[[RoundingType]] to fractionDigits.
+        // b. Let mnfd be ? DefaultNumberOption(mnfd, 0, 20, undefined).
+        let mnfd = default_number_option(&mnfd, 0.0, 20.0, None, context)?;
+        // c. Let mxfd be ? DefaultNumberOption(mxfd, 0, 20, undefined).
+        let mxfd = default_number_option(&mxfd, 0.0, 20.0, None, context)?;
+        let (minimum, maximum) = match (mnfd, mxfd) {
+            // 9. Else,
+            // a. Set intlObj.[[RoundingType]] to fractionDigits.
+            // b. Set intlObj.[[MinimumFractionDigits]] to mnfdDefault.
+            // c. Set intlObj.[[MaximumFractionDigits]] to mxfdDefault.
+            (None, None) => (mnfd_default, mxfd_default),
+            // d. If mnfd is undefined, set mnfd to min(mnfdDefault, mxfd).
+            (None, Some(mxfd)) => (mnfd_default.min(mxfd as usize), mxfd as usize),
+            // e. Else if mxfd is undefined, set mxfd to max(mxfdDefault, mnfd).
+            (Some(mnfd), None) => (mnfd as usize, mxfd_default.max(mnfd as usize)),
+            // f. Else if mnfd is greater than mxfd, throw a RangeError exception.
+            (Some(mnfd), Some(mxfd)) if mnfd > mxfd => {
+                return context.throw_range_error(
+                    "minimumFractionDigits is greater than maximumFractionDigits",
+                );
+            }
+            (Some(mnfd), Some(mxfd)) => (mnfd as usize, mxfd as usize),
+        };
+        // g. Set intlObj.[[MinimumFractionDigits]] to mnfd.
+        // h. Set intlObj.[[MaximumFractionDigits]] to mxfd.
+        RoundingType::FractionDigits { minimum, maximum }
+    };
+
+    Ok(DigitOptions {
+        minimum_integer_digits,
+        rounding,
+    })
+}
+
+/// Returns the locales that `BestAvailableLocale` tries for each of the `requested_locales`,
+/// without their Unicode locale extensions, for which `has_data` returns `true`.
+///
+/// The data provider doesn't list the locales it has data for, so this is used to build the
+/// `availableLocales` of a service from the requested locales.
+fn available_locales<F>(requested_locales: &[JsString], has_data: F) -> Vec<JsString>
+where
+    F: Fn(&Locale) -> bool,
+{
+    let mut available = Vec::new();
+    for locale in requested_locales {
+        let mut candidate = match locale.parse::<Locale>() {
+            Ok(locale) => locale.id.to_string(),
+            Err(_) => continue,
+        };
+        loop {
+            if let Ok(locale) = candidate.parse::<Locale>() {
+                let candidate = JsString::new(&candidate);
+                if !available.contains(&candidate) && has_data(&locale) {
+                    available.push(candidate);
+                }
+            }
+
+            let mut pos = match candidate.rfind('-') {
+                Some(pos) => pos,
+                None => break,
+            };
+            if pos >= 2 && candidate.as_bytes()[pos - 2] == b'-' {
+                pos -= 2;
+            }
+            candidate.truncate(pos);
+        }
+    }
+    available
+}
+
 /// Abstract operation `CanonicalizeUnicodeLocaleId ( locale )`.
 ///
 /// This function differs slightly from the specification by modifying in-place
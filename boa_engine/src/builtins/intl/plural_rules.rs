@@ -0,0 +1,510 @@
+//! This module implements the global `Intl.PluralRules` object.
+//!
+//! `Intl.PluralRules` is a built-in object that enables plural-sensitive formatting and
+//! plural-related language rules.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma402/#pluralrules-objects
+
+use crate::{
+    builtins::{
+        intl::{
+            available_locales, canonicalize_locale_list, coerce_options_to_object, get_option,
+            resolve_locale, set_number_format_digit_options, supported_locales,
+            DateTimeFormatRecord, DigitOptions, GetOptionType, RoundingType,
+        },
+        Array, JsArgs, Number,
+    },
+    context::intrinsics::StandardConstructors,
+    object::{
+        internal_methods::get_prototype_from_constructor, ConstructorBuilder, JsFunction, JsObject,
+        ObjectData,
+    },
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    Context, JsResult, JsString, JsValue,
+};
+
+use boa_gc::{Finalize, Trace};
+use boa_profiler::Profiler;
+use icu_locid::Locale;
+use icu_plurals::{PluralCategory, PluralOperands, PluralRuleType};
+use rustc_hash::FxHashMap;
+
+/// JavaScript `Intl.PluralRules` object.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct PluralRules {
+    locale: JsString,
+    #[unsafe_ignore_trace]
+    data_locale: Locale,
+    #[unsafe_ignore_trace]
+    rule_type: PluralRuleType,
+    #[unsafe_ignore_trace]
+    digit_options: DigitOptions,
+}
+
+impl PluralRules {
+    const NAME: &'static str = "PluralRules";
+
+    pub(super) fn init(context: &mut Context) -> JsFunction {
+        let _timer = Profiler::global().start_event(Self::NAME, "init");
+
+        ConstructorBuilder::with_standard_constructor(
+            context,
+            Self::constructor,
+            context.intrinsics().constructors().plural_rules().clone(),
+        )
+        .name(Self::NAME)
+        .length(0)
+        .property(
+            WellKnownSymbols::to_string_tag(),
+            "Intl.PluralRules",
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
+        .static_method(Self::supported_locales_of, "supportedLocalesOf", 1)
+        .method(Self::select, "select", 1)
+        .method(Self::select_range, "selectRange", 2)
+        .method(Self::resolved_options, "resolvedOptions", 0)
+        .build()
+    }
+}
+
+impl PluralRules {
+    /// The `Intl.PluralRules` constructor is the `%PluralRules%` intrinsic object and a standard built-in property of the `Intl` object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.pluralrules
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/PluralRules/PluralRules
+    pub(crate) fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return context.throw_type_error(
+                "calling a builtin Intl.PluralRules constructor without new is forbidden",
+            );
+        }
+
+        // 2. Let pluralRules be ? OrdinaryCreateFromConstructor(NewTarget, "%PluralRules.prototype%",
+        // « [[InitializedPluralRules]], [[Locale]], [[Type]], [[MinimumIntegerDigits]],
+        // [[MinimumFractionDigits]], [[MaximumFractionDigits]], [[MinimumSignificantDigits]],
+        // [[MaximumSignificantDigits]], [[RoundingType]] »).
+        let prototype = get_prototype_from_constructor(
+            new_target,
+            StandardConstructors::plural_rules,
+            context,
+        )?;
+
+        // 3. Return ? InitializePluralRules(pluralRules, locales, options).
+        let plural_rules = Self::initialize(args, context)?;
+        Ok(JsObject::from_proto_and_data(
+            prototype,
+            ObjectData::plural_rules(Box::new(plural_rules)),
+        )
+        .into())
+    }
+
+    /// Abstract operation `InitializePluralRules ( pluralRules, locales, options )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-initializepluralrules
+    fn initialize(args: &[JsValue], context: &mut Context) -> JsResult<Self> {
+        // 1. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales: Vec<JsString> = canonicalize_locale_list(args, context)?
+            .into_iter()
+            .map(|locale| locale.to_string().into())
+            .collect();
+
+        // 2. Set options to ? CoerceOptionsToObject(options).
+        let options = coerce_options_to_object(args.get_or_undefined(1), context)?;
+
+        // 3. Let opt be a new Record.
+        // 4. Let matcher be ? GetOption(options, "localeMatcher", "string", « "lookup", "best fit" », "best fit").
+        // 5. Set opt.[[localeMatcher]] to matcher.
+        let locale_matcher = get_option(
+            &options,
+            "localeMatcher",
+            &GetOptionType::String,
+            &[JsString::new("lookup"), JsString::new("best fit")],
+            &JsValue::new("best fit"),
+            context,
+        )?
+        .to_string(context)?;
+
+        // 6. Let t be ? GetOption(options, "type", "string", « "cardinal", "ordinal" », "cardinal").
+        // 7. Set pluralRules.[[Type]] to t.
+        let rule_type = get_option(
+            &options,
+            "type",
+            &GetOptionType::String,
+            &[JsString::new("cardinal"), JsString::new("ordinal")],
+            &JsValue::new("cardinal"),
+            context,
+        )?;
+        let rule_type = if rule_type.as_string().map(JsString::as_str) == Some("ordinal") {
+            PluralRuleType::Ordinal
+        } else {
+            PluralRuleType::Cardinal
+        };
+
+        // 8. Perform ? SetNumberFormatDigitOptions(pluralRules, options, +0𝔽, 3𝔽, "standard").
+        let digit_options = set_number_format_digit_options(&options, 0, 3, context)?;
+
+        // 9. Let localeData be %PluralRules%.[[LocaleData]].
+        // 10. Let r be ResolveLocale(%PluralRules%.[[AvailableLocales]], requestedLocales, opt,
+        // %PluralRules%.[[RelevantExtensionKeys]], localeData).
+        let available = available_locales(&requested_locales, |locale| {
+            has_plural_data(locale, rule_type, context)
+        });
+        let r = resolve_locale(
+            &available,
+            &requested_locales,
+            &DateTimeFormatRecord {
+                locale_matcher,
+                properties: FxHashMap::default(),
+            },
+            &[],
+            &FxHashMap::default(),
+            context,
+        );
+
+        // 11. Set pluralRules.[[Locale]] to r.[[locale]].
+        // 12. Return pluralRules.
+        Ok(Self {
+            data_locale: plural_data_locale(&r.locale, rule_type, context),
+            locale: r.locale,
+            rule_type,
+            digit_options,
+        })
+    }
+
+    /// `Intl.PluralRules.supportedLocalesOf ( locales [ , options ] )`
+    ///
+    /// Returns the requested locales for which the data provider has plural rules.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.pluralrules.supportedlocalesof
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/PluralRules/supportedLocalesOf
+    pub(crate) fn supported_locales_of(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let availableLocales be %PluralRules%.[[AvailableLocales]].
+        // 2. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales: Vec<JsString> = canonicalize_locale_list(args, context)?
+            .into_iter()
+            .map(|locale| locale.to_string().into())
+            .collect();
+        let available = available_locales(&requested_locales, |locale| {
+            has_plural_data(locale, PluralRuleType::Cardinal, context)
+        });
+
+        // 3. Return ? SupportedLocales(availableLocales, requestedLocales, options).
+        supported_locales(
+            &available,
+            &requested_locales,
+            args.get_or_undefined(1),
+            context,
+        )
+    }
+
+    /// Returns a copy of the `[[InitializedPluralRules]]` object `this`, or throws a
+    /// `TypeError` if `this` is not an `Intl.PluralRules` object.
+    fn this_plural_rules(this: &JsValue, context: &mut Context) -> JsResult<Self> {
+        this.as_object()
+            .and_then(|object| object.borrow().as_plural_rules().cloned())
+            .map_or_else(
+                || context.throw_type_error("'this' is not an Intl.PluralRules object"),
+                Ok,
+            )
+    }
+
+    /// Abstract operation `ResolvePlural ( pluralRules, n )`
+    ///
+    /// Returns the plural category of `n` and the string `n` is formatted to with the digit
+    /// options of this object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-resolveplural
+    fn resolve_plural(&self, n: f64, context: &Context) -> (PluralCategory, String) {
+        // 1. Assert: Type(pluralRules) is Object.
+        // 2. Assert: pluralRules has an [[InitializedPluralRules]] internal slot.
+        // 3. Assert: Type(n) is Number.
+        // 4. If n is not a finite Number, then
+        if !n.is_finite() {
+            // a. Let s be ! ToString(n).
+            // b. Return the Record { [[PluralCategory]]: "other", [[FormattedString]]: s }.
+            return (PluralCategory::Other, Number::to_native_string(n));
+        }
+
+        // 5. Let res be FormatNumericToString(pluralRules, n).
+        // 6. Let s be res.[[FormattedString]].
+        let (integer, fraction) = self.digit_options.format_digits(n);
+        let digits = if fraction.is_empty() {
+            integer
+        } else {
+            format!("{integer}.{fraction}")
+        };
+
+        // 7. Let locale be pluralRules.[[Locale]].
+        // 8. Let type be pluralRules.[[Type]].
+        // 9. Let p be PluralRuleSelect(locale, type, n, s).
+        let category = plural_rule_select(&self.data_locale, self.rule_type, &digits, context);
+
+        // 10. Return the Record { [[PluralCategory]]: p, [[FormattedString]]: s }.
+        let formatted = if n.is_sign_negative() {
+            format!("-{digits}")
+        } else {
+            digits
+        };
+        (category, formatted)
+    }
+
+    /// `Intl.PluralRules.prototype.select ( value )`
+    ///
+    /// Returns the plural category of `value` in the locale of this object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.pluralrules.prototype.select
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/PluralRules/select
+    pub(crate) fn select(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let pr be the this value.
+        // 2. Perform ? RequireInternalSlot(pr, [[InitializedPluralRules]]).
+        let plural_rules = Self::this_plural_rules(this, context)?;
+
+        // 3. Let n be ? ToNumber(value).
+        let n = args.get_or_undefined(0).to_number(context)?;
+
+        // 4. Return ResolvePlural(pr, n).[[PluralCategory]].
+        let (category, _) = plural_rules.resolve_plural(n, context);
+        Ok(plural_category_name(category).into())
+    }
+
+    /// `Intl.PluralRules.prototype.selectRange ( start, end )`
+    ///
+    /// Returns the plural category of the range from `start` to `end` in the locale of this
+    /// object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.pluralrules.prototype.selectrange
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/PluralRules/selectRange
+    pub(crate) fn select_range(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let pr be the this value.
+        // 2. Perform ? RequireInternalSlot(pr, [[InitializedPluralRules]]).
+        let plural_rules = Self::this_plural_rules(this, context)?;
+
+        // 3. If start is undefined or end is undefined, throw a TypeError exception.
+        let start = args.get_or_undefined(0);
+        let end = args.get_or_undefined(1);
+        if start.is_undefined() || end.is_undefined() {
+            return context.throw_type_error("the start and end of a range must be defined");
+        }
+
+        // 4. Let x be ? ToNumber(start).
+        let x = start.to_number(context)?;
+
+        // 5. Let y be ? ToNumber(end).
+        let y = end.to_number(context)?;
+
+        // 6. Return ? ResolvePluralRange(pr, x, y).
+        // ResolvePluralRange ( pluralRules, x, y )
+        // 1. If x is NaN or y is NaN, throw a RangeError exception.
+        if x.is_nan() || y.is_nan() {
+            return context.throw_range_error("the start and end of a range must not be NaN");
+        }
+
+        // 2. Let xp be ResolvePlural(pluralRules, x).
+        let (x_category, x_formatted) = plural_rules.resolve_plural(x, context);
+
+        // 3. Let yp be ResolvePlural(pluralRules, y).
+        let (y_category, y_formatted) = plural_rules.resolve_plural(y, context);
+
+        // 4. If xp.[[FormattedString]] is yp.[[FormattedString]], then
+        if x_formatted == y_formatted {
+            // a. Return xp.[[PluralCategory]].
+            return Ok(plural_category_name(x_category).into());
+        }
+
+        // 5. Let locale be pluralRules.[[Locale]].
+        // 6. Let type be pluralRules.[[Type]].
+        // 7. Return PluralRuleSelectRange(locale, type, xp.[[PluralCategory]], yp.[[PluralCategory]]).
+        //
+        // The data provider has no plural ranges data, so this uses the category of the end of the
+        // range, which is the result of most of the plural ranges in the CLDR.
+        Ok(plural_category_name(y_category).into())
+    }
+
+    /// `Intl.PluralRules.prototype.resolvedOptions ( )`
+    ///
+    /// Returns a new object with properties reflecting the locale and options computed during
+    /// the initialization of this object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.pluralrules.prototype.resolvedoptions
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/PluralRules/resolvedOptions
+    pub(crate) fn resolved_options(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let pr be the this value.
+        // 2. Perform ? RequireInternalSlot(pr, [[InitializedPluralRules]]).
+        let plural_rules = Self::this_plural_rules(this, context)?;
+
+        // 3. Let options be OrdinaryObjectCreate(%Object.prototype%).
+        let options = context.construct_object();
+
+        // 4. For each row of Table 13, except the header row, in table order, do
+        //     a. Let p be the Property value of the current row.
+        //     b. Let v be the value of pr's internal slot whose name is the Internal Slot value of the current row.
+        //     c. If v is not undefined, then
+        //         i. Perform ! CreateDataPropertyOrThrow(options, p, v).
+        let rule_type = match plural_rules.rule_type {
+            PluralRuleType::Ordinal => "ordinal",
+            _ => "cardinal",
+        };
+        let mut properties: Vec<(&str, JsValue)> = vec![
+            ("locale", plural_rules.locale.clone().into()),
+            ("type", rule_type.into()),
+            (
+                "minimumIntegerDigits",
+                plural_rules.digit_options.minimum_integer_digits.into(),
+            ),
+        ];
+        match plural_rules.digit_options.rounding {
+            RoundingType::FractionDigits { minimum, maximum } => {
+                properties.push(("minimumFractionDigits", minimum.into()));
+                properties.push(("maximumFractionDigits", maximum.into()));
+            }
+            RoundingType::SignificantDigits { minimum, maximum } => {
+                properties.push(("minimumSignificantDigits", minimum.into()));
+                properties.push(("maximumSignificantDigits", maximum.into()));
+            }
+        }
+        for (property, value) in properties {
+            options.create_data_property_or_throw(property, value, context)?;
+        }
+
+        // 5. Let pluralCategories be a List of Strings containing all possible results of
+        // PluralRuleSelect for the selected locale pr.[[Locale]].
+        let categories =
+            load_plural_rules(&plural_rules.data_locale, plural_rules.rule_type, context)
+                .map_or_else(
+                    || vec!["other".into()],
+                    |rules| {
+                        rules
+                            .categories()
+                            .map(|category| plural_category_name(category).into())
+                            .collect::<Vec<JsValue>>()
+                    },
+                );
+
+        // 6. Perform ! CreateDataProperty(options, "pluralCategories", CreateArrayFromList(pluralCategories)).
+        let categories = Array::create_array_from_list(categories, context)?;
+        options.create_data_property_or_throw("pluralCategories", categories, context)?;
+
+        // 7. Return options.
+        Ok(options.into())
+    }
+}
+
+/// Loads the plural rules of type `rule_type` for `locale` from the data provider.
+fn load_plural_rules(
+    locale: &Locale,
+    rule_type: PluralRuleType,
+    context: &Context,
+) -> Option<icu_plurals::PluralRules> {
+    icu_plurals::PluralRules::try_new(locale.id.clone(), context.icu().provider(), rule_type).ok()
+}
+
+/// Returns `true` if the data provider has plural rules of type `rule_type` for `locale`.
+pub(crate) fn has_plural_data(
+    locale: &Locale,
+    rule_type: PluralRuleType,
+    context: &Context,
+) -> bool {
+    load_plural_rules(locale, rule_type, context).is_some()
+}
+
+/// Returns the locale whose plural rules of type `rule_type` are used for the resolved `locale`
+/// of a service.
+///
+/// This is the first fallback of `locale` that the data provider has data for, or the root
+/// locale if there is none. It can differ from the resolved locale if that is the default
+/// locale of the host.
+pub(crate) fn plural_data_locale(
+    locale: &JsString,
+    rule_type: PluralRuleType,
+    context: &Context,
+) -> Locale {
+    available_locales(std::slice::from_ref(locale), |locale| {
+        has_plural_data(locale, rule_type, context)
+    })
+    .first()
+    .and_then(|locale| locale.parse().ok())
+    .unwrap_or(Locale::UND)
+}
+
+/// Abstract operation `PluralRuleSelect ( locale, type, n, s )`
+///
+/// Returns the plural category of the number formatted to the decimal string `s`, which may be
+/// negative, in `locale`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma402/#sec-pluralruleselect
+pub(crate) fn plural_rule_select(
+    locale: &Locale,
+    rule_type: PluralRuleType,
+    s: &str,
+    context: &Context,
+) -> PluralCategory {
+    let operands = s.parse::<PluralOperands>().unwrap_or_default();
+    load_plural_rules(locale, rule_type, context)
+        .map_or(PluralCategory::Other, |rules| rules.select(operands))
+}
+
+/// Returns the name of a plural category, as returned by `Intl.PluralRules.prototype.select`.
+pub(crate) fn plural_category_name(category: PluralCategory) -> &'static str {
+    match category {
+        PluralCategory::Zero => "zero",
+        PluralCategory::One => "one",
+        PluralCategory::Two => "two",
+        PluralCategory::Few => "few",
+        PluralCategory::Many => "many",
+        PluralCategory::Other => "other",
+    }
+}
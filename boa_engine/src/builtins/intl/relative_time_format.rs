@@ -0,0 +1,859 @@
+//! This module implements the global `Intl.RelativeTimeFormat` object.
+//!
+//! `Intl.RelativeTimeFormat` is a built-in object that enables language-sensitive relative time
+//! formatting.
+//!
+//! The data provider has no relative time data, so the patterns of the `en` locale from the
+//! CLDR are built into this module, and every other locale resolves to `en`. The plural category
+//! used to pick a pattern does come from the plural rules of the data provider.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma402/#relativetimeformat-objects
+
+use crate::{
+    builtins::{
+        intl::{
+            available_locales, canonicalize_locale_list, coerce_options_to_object, get_option,
+            plural_rules::{plural_data_locale, plural_rule_select},
+            resolve_locale, supported_locales, DateTimeFormatRecord, DigitOptions, GetOptionType,
+            RoundingType,
+        },
+        Array, JsArgs, Number,
+    },
+    context::intrinsics::StandardConstructors,
+    object::{
+        internal_methods::get_prototype_from_constructor, ConstructorBuilder, JsFunction, JsObject,
+        ObjectData,
+    },
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    Context, JsResult, JsString, JsValue,
+};
+
+use boa_gc::{Finalize, Trace};
+use boa_profiler::Profiler;
+use icu_locid::{locale, Locale};
+use icu_plurals::{PluralCategory, PluralRuleType};
+use rustc_hash::FxHashMap;
+
+/// The `style` option of an `Intl.RelativeTimeFormat` object.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Style {
+    Long,
+    Short,
+    Narrow,
+}
+
+/// The `numeric` option of an `Intl.RelativeTimeFormat` object.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Numeric {
+    Always,
+    Auto,
+}
+
+/// JavaScript `Intl.RelativeTimeFormat` object.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct RelativeTimeFormat {
+    locale: JsString,
+    #[unsafe_ignore_trace]
+    plural_data_locale: Locale,
+    numbering_system: JsString,
+    #[unsafe_ignore_trace]
+    style: Style,
+    #[unsafe_ignore_trace]
+    numeric: Numeric,
+}
+
+impl RelativeTimeFormat {
+    const NAME: &'static str = "RelativeTimeFormat";
+
+    pub(super) fn init(context: &mut Context) -> JsFunction {
+        let _timer = Profiler::global().start_event(Self::NAME, "init");
+
+        ConstructorBuilder::with_standard_constructor(
+            context,
+            Self::constructor,
+            context
+                .intrinsics()
+                .constructors()
+                .relative_time_format()
+                .clone(),
+        )
+        .name(Self::NAME)
+        .length(0)
+        .property(
+            WellKnownSymbols::to_string_tag(),
+            "Intl.RelativeTimeFormat",
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
+        .static_method(Self::supported_locales_of, "supportedLocalesOf", 1)
+        .method(Self::format, "format", 2)
+        .method(Self::format_to_parts, "formatToParts", 2)
+        .method(Self::resolved_options, "resolvedOptions", 0)
+        .build()
+    }
+}
+
+impl RelativeTimeFormat {
+    /// The `Intl.RelativeTimeFormat` constructor is the `%RelativeTimeFormat%` intrinsic object and a standard built-in property of the `Intl` object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.relativetimeformat
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/RelativeTimeFormat/RelativeTimeFormat
+    pub(crate) fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return context.throw_type_error(
+                "calling a builtin Intl.RelativeTimeFormat constructor without new is forbidden",
+            );
+        }
+
+        // 2. Let relativeTimeFormat be ? OrdinaryCreateFromConstructor(NewTarget,
+        // "%RelativeTimeFormat.prototype%", « [[InitializedRelativeTimeFormat]], [[Locale]],
+        // [[DataLocale]], [[Style]], [[Numeric]], [[NumberFormat]], [[NumberingSystem]],
+        // [[PluralRules]] »).
+        let prototype = get_prototype_from_constructor(
+            new_target,
+            StandardConstructors::relative_time_format,
+            context,
+        )?;
+
+        // 3. Return ? InitializeRelativeTimeFormat(relativeTimeFormat, locales, options).
+        let relative_time_format = Self::initialize(args, context)?;
+        Ok(JsObject::from_proto_and_data(
+            prototype,
+            ObjectData::relative_time_format(Box::new(relative_time_format)),
+        )
+        .into())
+    }
+
+    /// Abstract operation `InitializeRelativeTimeFormat ( relativeTimeFormat, locales, options )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-InitializeRelativeTimeFormat
+    fn initialize(args: &[JsValue], context: &mut Context) -> JsResult<Self> {
+        // 1. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales: Vec<JsString> = canonicalize_locale_list(args, context)?
+            .into_iter()
+            .map(|locale| locale.to_string().into())
+            .collect();
+
+        // 2. Set options to ? CoerceOptionsToObject(options).
+        let options = coerce_options_to_object(args.get_or_undefined(1), context)?;
+
+        // 3. Let opt be a new Record.
+        // 4. Let matcher be ? GetOption(options, "localeMatcher", "string", « "lookup", "best fit" », "best fit").
+        // 5. Set opt.[[LocaleMatcher]] to matcher.
+        let locale_matcher = get_option(
+            &options,
+            "localeMatcher",
+            &GetOptionType::String,
+            &[JsString::new("lookup"), JsString::new("best fit")],
+            &JsValue::new("best fit"),
+            context,
+        )?
+        .to_string(context)?;
+
+        // 6. Let numberingSystem be ? GetOption(options, "numberingSystem", "string", undefined, undefined).
+        let numbering_system = get_option(
+            &options,
+            "numberingSystem",
+            &GetOptionType::String,
+            &[],
+            &JsValue::undefined(),
+            context,
+        )?;
+
+        // 7. If numberingSystem is not undefined, then
+        let mut properties = FxHashMap::default();
+        if let Some(numbering_system) = numbering_system.as_string() {
+            // a. If numberingSystem does not match the Unicode Locale Identifier type
+            // nonterminal, throw a RangeError exception.
+            let is_type = numbering_system.split('-').all(|subtag| {
+                (3..=8).contains(&subtag.len())
+                    && subtag.bytes().all(|byte| byte.is_ascii_alphanumeric())
+            });
+            if !is_type {
+                return context.throw_range_error("invalid numbering system");
+            }
+
+            // 8. Set opt.[[nu]] to numberingSystem.
+            properties.insert(JsString::new("nu"), numbering_system.clone().into());
+        }
+
+        // 9. Let localeData be %RelativeTimeFormat%.[[LocaleData]].
+        let mut locale_data = FxHashMap::default();
+        let mut en_data = FxHashMap::default();
+        en_data.insert(JsString::new("nu"), vec![JsString::new("latn")]);
+        locale_data.insert(JsString::new("en"), en_data);
+
+        // 10. Let r be ResolveLocale(%RelativeTimeFormat%.[[AvailableLocales]], requestedLocales, opt,
+        // %RelativeTimeFormat%.[[RelevantExtensionKeys]], localeData).
+        let available = available_locales(&requested_locales, has_relative_time_data);
+        let r = resolve_locale(
+            &available,
+            &requested_locales,
+            &DateTimeFormatRecord {
+                locale_matcher,
+                properties,
+            },
+            &[JsString::new("nu")],
+            &locale_data,
+            context,
+        );
+
+        // 11. Let locale be r.[[locale]].
+        // 12. Set relativeTimeFormat.[[Locale]] to locale.
+        // If no requested locale is available, `ResolveLocale` returns the default locale of the
+        // host, which has no patterns if it is not an English locale. Resolve to `en` instead, so
+        // that the resolved locale always matches the language of the formatted strings.
+        let locale = if available_locales(std::slice::from_ref(&r.locale), has_relative_time_data)
+            .is_empty()
+        {
+            JsString::new("en")
+        } else {
+            r.locale
+        };
+
+        // 13. Set relativeTimeFormat.[[DataLocale]] to r.[[dataLocale]].
+        // 14. Set relativeTimeFormat.[[NumberingSystem]] to r.[[nu]].
+        let numbering_system = r
+            .properties
+            .get(&JsString::new("nu"))
+            .and_then(JsValue::as_string)
+            .cloned()
+            .unwrap_or_else(|| JsString::new("latn"));
+
+        // 15. Let style be ? GetOption(options, "style", "string", « "long", "short", "narrow" », "long").
+        // 16. Set relativeTimeFormat.[[Style]] to style.
+        let style = get_option(
+            &options,
+            "style",
+            &GetOptionType::String,
+            &[
+                JsString::new("long"),
+                JsString::new("short"),
+                JsString::new("narrow"),
+            ],
+            &JsValue::new("long"),
+            context,
+        )?;
+        let style = match style.as_string().map(JsString::as_str) {
+            Some("short") => Style::Short,
+            Some("narrow") => Style::Narrow,
+            _ => Style::Long,
+        };
+
+        // 17. Let numeric be ? GetOption(options, "numeric", "string", « "always", "auto" », "always").
+        // 18. Set relativeTimeFormat.[[Numeric]] to numeric.
+        let numeric = get_option(
+            &options,
+            "numeric",
+            &GetOptionType::String,
+            &[JsString::new("always"), JsString::new("auto")],
+            &JsValue::new("always"),
+            context,
+        )?;
+        let numeric = if numeric.as_string().map(JsString::as_str) == Some("auto") {
+            Numeric::Auto
+        } else {
+            Numeric::Always
+        };
+
+        // 19. Let relativeTimeFormat.[[NumberFormat]] be ! Construct(%NumberFormat%, « locale »).
+        // 20. Let relativeTimeFormat.[[PluralRules]] be ! Construct(%PluralRules%, « locale »).
+        // 21. Return relativeTimeFormat.
+        Ok(Self {
+            plural_data_locale: plural_data_locale(&locale, PluralRuleType::Cardinal, context),
+            locale,
+            numbering_system,
+            style,
+            numeric,
+        })
+    }
+
+    /// `Intl.RelativeTimeFormat.supportedLocalesOf ( locales [ , options ] )`
+    ///
+    /// Returns the requested locales for which relative time patterns are available.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.RelativeTimeFormat.supportedLocalesOf
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/RelativeTimeFormat/supportedLocalesOf
+    pub(crate) fn supported_locales_of(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let availableLocales be %RelativeTimeFormat%.[[AvailableLocales]].
+        // 2. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales: Vec<JsString> = canonicalize_locale_list(args, context)?
+            .into_iter()
+            .map(|locale| locale.to_string().into())
+            .collect();
+        let available = available_locales(&requested_locales, has_relative_time_data);
+
+        // 3. Return ? SupportedLocales(availableLocales, requestedLocales, options).
+        supported_locales(
+            &available,
+            &requested_locales,
+            args.get_or_undefined(1),
+            context,
+        )
+    }
+
+    /// Returns a copy of the `[[InitializedRelativeTimeFormat]]` object `this`, or throws a
+    /// `TypeError` if `this` is not an `Intl.RelativeTimeFormat` object.
+    fn this_relative_time_format(this: &JsValue, context: &mut Context) -> JsResult<Self> {
+        this.as_object()
+            .and_then(|object| object.borrow().as_relative_time_format().cloned())
+            .map_or_else(
+                || context.throw_type_error("'this' is not an Intl.RelativeTimeFormat object"),
+                Ok,
+            )
+    }
+
+    /// Abstract operation `PartitionRelativeTimePattern ( relativeTimeFormat, value, unit )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-PartitionRelativeTimePattern
+    fn partition_relative_time_pattern(
+        &self,
+        value: f64,
+        unit: &JsString,
+        context: &mut Context,
+    ) -> JsResult<Vec<RelativeTimePart>> {
+        // 1. Assert: relativeTimeFormat has an [[InitializedRelativeTimeFormat]] internal slot.
+        // 2. Assert: Type(value) is Number.
+        // 3. Assert: Type(unit) is String.
+        // 4. If value is NaN, +∞𝔽, or -∞𝔽, throw a RangeError exception.
+        if !value.is_finite() {
+            return context.throw_range_error("relative time value must be finite");
+        }
+
+        // 5. Let unit be ? SingularRelativeTimeUnit(unit).
+        let unit = match singular_relative_time_unit(unit) {
+            Some(unit) => unit,
+            None => return context.throw_range_error(format!("invalid time unit: {unit}")),
+        };
+
+        // 6. Let localeData be %RelativeTimeFormat%.[[LocaleData]].
+        // 7. Let dataLocale be relativeTimeFormat.[[DataLocale]].
+        // 8. Let fields be localeData.[[<dataLocale>]].
+        // 9. Let style be relativeTimeFormat.[[Style]].
+        // 10. If style is equal to "short", then
+        //     a. Let entry be the string-concatenation of unit and "-short".
+        // 11. Else if style is equal to "narrow", then
+        //     a. Let entry be the string-concatenation of unit and "-narrow".
+        // 12. Else,
+        //     a. Let entry be unit.
+        // 13. If fields doesn't have a field [[<entry>]], then
+        //     a. Let entry be unit.
+        // 14. Let patterns be fields.[[<entry>]].
+        let patterns = unit_patterns(self.style, unit);
+
+        // 15. Let numeric be relativeTimeFormat.[[Numeric]].
+        // 16. If numeric is equal to "auto", then
+        if self.numeric == Numeric::Auto {
+            // a. Let valueString be ToString(value).
+            let value_string = Number::to_native_string(value);
+
+            // b. If patterns has a field [[<valueString>]], then
+            if let Some(&(_, result)) = patterns
+                .relative
+                .iter()
+                .find(|(offset, _)| *offset == value_string)
+            {
+                // i. Let result be patterns.[[<valueString>]].
+                // ii. Return a List containing the Record { [[Type]]: "literal", [[Value]]: result }.
+                return Ok(vec![RelativeTimePart {
+                    part_type: "literal",
+                    value: result.to_string(),
+                    unit: None,
+                }]);
+            }
+        }
+
+        // 17. If value is -0𝔽 or if value is less than -0𝔽, then
+        //     a. Let tl be "past".
+        //     b. Set value to -value.
+        // 18. Else,
+        //     a. Let tl be "future".
+        // 19. Let po be patterns.[[<tl>]].
+        let po = if value.is_sign_negative() {
+            patterns.past
+        } else {
+            patterns.future
+        };
+
+        // 20. Let fv be ! PartitionNumberPattern(relativeTimeFormat.[[NumberFormat]], value).
+        // `format_digits` formats the absolute value, which also takes care of step 17.b.
+        let digit_options = DigitOptions {
+            minimum_integer_digits: 1,
+            rounding: RoundingType::FractionDigits {
+                minimum: 0,
+                maximum: 3,
+            },
+        };
+        let (integer, fraction) = digit_options.format_digits(value);
+
+        // 21. Let pr be ! ResolvePlural(relativeTimeFormat.[[PluralRules]], value).[[PluralCategory]].
+        let digits = if fraction.is_empty() {
+            integer.clone()
+        } else {
+            format!("{integer}.{fraction}")
+        };
+        let pr = plural_rule_select(
+            &self.plural_data_locale,
+            PluralRuleType::Cardinal,
+            &digits,
+            context,
+        );
+
+        // 22. Let pattern be po.[[<pr>]].
+        let pattern = if pr == PluralCategory::One {
+            po[0]
+        } else {
+            po[1]
+        };
+
+        // 23. Return ! MakePartsList(pattern, unit, fv).
+        Ok(make_parts_list(pattern, unit, &integer, &fraction))
+    }
+
+    /// `Intl.RelativeTimeFormat.prototype.format ( value, unit )`
+    ///
+    /// Formats `value` as a relative time in the given `unit`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.RelativeTimeFormat.prototype.format
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/RelativeTimeFormat/format
+    pub(crate) fn format(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let relativeTimeFormat be the this value.
+        // 2. Perform ? RequireInternalSlot(relativeTimeFormat, [[InitializedRelativeTimeFormat]]).
+        let relative_time_format = Self::this_relative_time_format(this, context)?;
+
+        // 3. Let value be ? ToNumber(value).
+        let value = args.get_or_undefined(0).to_number(context)?;
+
+        // 4. Let unit be ? ToString(unit).
+        let unit = args.get_or_undefined(1).to_string(context)?;
+
+        // 5. Return ? FormatRelativeTime(relativeTimeFormat, value, unit).
+        // FormatRelativeTime ( relativeTimeFormat, value, unit )
+        // 1. Let parts be ? PartitionRelativeTimePattern(relativeTimeFormat, value, unit).
+        let parts = relative_time_format.partition_relative_time_pattern(value, &unit, context)?;
+
+        // 2. Let result be an empty String.
+        // 3. For each Record { [[Type]], [[Value]], [[Unit]] } part in parts, do
+        //     a. Set result to the string-concatenation of result and part.[[Value]].
+        let result: String = parts.into_iter().map(|part| part.value).collect();
+
+        // 4. Return result.
+        Ok(result.into())
+    }
+
+    /// `Intl.RelativeTimeFormat.prototype.formatToParts ( value, unit )`
+    ///
+    /// Formats `value` as a relative time in the given `unit`, returning the list of the parts
+    /// of the formatted string.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.RelativeTimeFormat.prototype.formatToParts
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/RelativeTimeFormat/formatToParts
+    pub(crate) fn format_to_parts(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let relativeTimeFormat be the this value.
+        // 2. Perform ? RequireInternalSlot(relativeTimeFormat, [[InitializedRelativeTimeFormat]]).
+        let relative_time_format = Self::this_relative_time_format(this, context)?;
+
+        // 3. Let value be ? ToNumber(value).
+        let value = args.get_or_undefined(0).to_number(context)?;
+
+        // 4. Let unit be ? ToString(unit).
+        let unit = args.get_or_undefined(1).to_string(context)?;
+
+        // 5. Return ? FormatRelativeTimeToParts(relativeTimeFormat, value, unit).
+        // FormatRelativeTimeToParts ( relativeTimeFormat, value, unit )
+        // 1. Let parts be ? PartitionRelativeTimePattern(relativeTimeFormat, value, unit).
+        let parts = relative_time_format.partition_relative_time_pattern(value, &unit, context)?;
+
+        // 2. Let result be ! ArrayCreate(0).
+        // 3. Let n be 0.
+        // 4. For each Record { [[Type]], [[Value]], [[Unit]] } part in parts, do
+        let mut result = Vec::with_capacity(parts.len());
+        for part in parts {
+            // a. Let O be OrdinaryObjectCreate(%Object.prototype%).
+            let o = context.construct_object();
+
+            // b. Perform ! CreateDataPropertyOrThrow(O, "type", part.[[Type]]).
+            o.create_data_property_or_throw("type", part.part_type, context)?;
+
+            // c. Perform ! CreateDataPropertyOrThrow(O, "value", part.[[Value]]).
+            o.create_data_property_or_throw("value", part.value, context)?;
+
+            // d. If part.[[Unit]] is not empty, then
+            if let Some(unit) = part.unit {
+                // i. Perform ! CreateDataPropertyOrThrow(O, "unit", part.[[Unit]]).
+                o.create_data_property_or_throw("unit", unit, context)?;
+            }
+
+            // e. Perform ! CreateDataPropertyOrThrow(result, ! ToString(n), O).
+            // f. Increment n by 1.
+            result.push(o.into());
+        }
+
+        // 5. Return result.
+        Ok(Array::create_array_from_list(result, context)?.into())
+    }
+
+    /// `Intl.RelativeTimeFormat.prototype.resolvedOptions ( )`
+    ///
+    /// Returns a new object with properties reflecting the locale and options computed during
+    /// the initialization of this object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.relativetimeformat.prototype.resolvedoptions
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/RelativeTimeFormat/resolvedOptions
+    pub(crate) fn resolved_options(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let relativeTimeFormat be the this value.
+        // 2. Perform ? RequireInternalSlot(relativeTimeFormat, [[InitializedRelativeTimeFormat]]).
+        let relative_time_format = Self::this_relative_time_format(this, context)?;
+
+        // 3. Let options be OrdinaryObjectCreate(%Object.prototype%).
+        let options = context.construct_object();
+
+        // 4. For each row of Table 15, except the header row, in table order, do
+        //     a. Let p be the Property value of the current row.
+        //     b. Let v be the value of relativeTimeFormat's internal slot whose name is the Internal Slot value of the current row.
+        //     c. Assert: v is not undefined.
+        //     d. Perform ! CreateDataPropertyOrThrow(options, p, v).
+        let style = match relative_time_format.style {
+            Style::Long => "long",
+            Style::Short => "short",
+            Style::Narrow => "narrow",
+        };
+        let numeric = match relative_time_format.numeric {
+            Numeric::Always => "always",
+            Numeric::Auto => "auto",
+        };
+        let properties: [(&str, JsValue); 4] = [
+            ("locale", relative_time_format.locale.clone().into()),
+            ("style", style.into()),
+            ("numeric", numeric.into()),
+            (
+                "numberingSystem",
+                relative_time_format.numbering_system.clone().into(),
+            ),
+        ];
+        for (property, value) in properties {
+            options.create_data_property_or_throw(property, value, context)?;
+        }
+
+        // 5. Return options.
+        Ok(options.into())
+    }
+}
+
+/// A part of a formatted relative time, as returned by `PartitionRelativeTimePattern`.
+#[derive(Debug)]
+struct RelativeTimePart {
+    part_type: &'static str,
+    value: String,
+    unit: Option<&'static str>,
+}
+
+/// Returns `true` if relative time patterns are available for `locale`.
+///
+/// Only the patterns of the `en` locale are built into this module.
+fn has_relative_time_data(locale: &Locale) -> bool {
+    *locale == locale!("en")
+}
+
+/// Abstract operation `SingularRelativeTimeUnit ( unit )`
+///
+/// Returns `None` if `unit` isn't a valid relative time unit.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma402/#sec-singularrelativetimeunit
+fn singular_relative_time_unit(unit: &str) -> Option<&'static str> {
+    // 1. Assert: Type(unit) is String.
+    // 2. If unit is "seconds", return "second".
+    // 3. If unit is "minutes", return "minute".
+    // 4. If unit is "hours", return "hour".
+    // 5. If unit is "days", return "day".
+    // 6. If unit is "weeks", return "week".
+    // 7. If unit is "months", return "month".
+    // 8. If unit is "quarters", return "quarter".
+    // 9. If unit is "years", return "year".
+    // 10. If unit is not one of "second", "minute", "hour", "day", "week", "month", "quarter",
+    // or "year", throw a RangeError exception.
+    // 11. Return unit.
+    match unit {
+        "second" | "seconds" => Some("second"),
+        "minute" | "minutes" => Some("minute"),
+        "hour" | "hours" => Some("hour"),
+        "day" | "days" => Some("day"),
+        "week" | "weeks" => Some("week"),
+        "month" | "months" => Some("month"),
+        "quarter" | "quarters" => Some("quarter"),
+        "year" | "years" => Some("year"),
+        _ => None,
+    }
+}
+
+/// Abstract operation `MakePartsList ( pattern, unit, parts )`
+///
+/// Replaces the `{0}` placeholder of `pattern` with the parts of the number formatted to the
+/// given integer and fraction digits, grouping the integer digits by thousands.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma402/#sec-makepartslist
+fn make_parts_list(
+    pattern: &str,
+    unit: &'static str,
+    integer: &str,
+    fraction: &str,
+) -> Vec<RelativeTimePart> {
+    let literal = |value: &str| RelativeTimePart {
+        part_type: "literal",
+        value: value.to_string(),
+        unit: None,
+    };
+    let number_part = |part_type, value: &str| RelativeTimePart {
+        part_type,
+        value: value.to_string(),
+        unit: Some(unit),
+    };
+
+    let (before, after) = pattern
+        .split_once("{0}")
+        .expect("relative time patterns must contain a placeholder");
+
+    let mut parts = Vec::new();
+    if !before.is_empty() {
+        parts.push(literal(before));
+    }
+
+    let first_group = match integer.len() % 3 {
+        0 => 3,
+        len => len,
+    };
+    parts.push(number_part("integer", &integer[..first_group]));
+    for start in (first_group..integer.len()).step_by(3) {
+        parts.push(number_part("group", ","));
+        parts.push(number_part("integer", &integer[start..start + 3]));
+    }
+    if !fraction.is_empty() {
+        parts.push(number_part("decimal", "."));
+        parts.push(number_part("fraction", fraction));
+    }
+
+    if !after.is_empty() {
+        parts.push(literal(after));
+    }
+    parts
+}
+
+/// The relative time patterns of a unit in a style.
+#[derive(Debug)]
+struct UnitPatterns {
+    /// The patterns of the past, for the plural categories `one` and `other`.
+    past: [&'static str; 2],
+    /// The patterns of the future, for the plural categories `one` and `other`.
+    future: [&'static str; 2],
+    /// The names of relative offsets of the unit, used with `numeric: "auto"`.
+    relative: &'static [(&'static str, &'static str)],
+}
+
+/// Returns the relative time patterns of the `en` locale for `unit` in `style`.
+fn unit_patterns(style: Style, unit: &str) -> &'static UnitPatterns {
+    const YEAR: UnitPatterns = UnitPatterns {
+        past: ["{0} year ago", "{0} years ago"],
+        future: ["in {0} year", "in {0} years"],
+        relative: &[("-1", "last year"), ("0", "this year"), ("1", "next year")],
+    };
+    const YEAR_SHORT: UnitPatterns = UnitPatterns {
+        past: ["{0} yr. ago", "{0} yr. ago"],
+        future: ["in {0} yr.", "in {0} yr."],
+        relative: &[("-1", "last yr."), ("0", "this yr."), ("1", "next yr.")],
+    };
+    const YEAR_NARROW: UnitPatterns = UnitPatterns {
+        past: ["{0}y ago", "{0}y ago"],
+        future: ["in {0}y", "in {0}y"],
+        relative: YEAR_SHORT.relative,
+    };
+    const QUARTER: UnitPatterns = UnitPatterns {
+        past: ["{0} quarter ago", "{0} quarters ago"],
+        future: ["in {0} quarter", "in {0} quarters"],
+        relative: &[
+            ("-1", "last quarter"),
+            ("0", "this quarter"),
+            ("1", "next quarter"),
+        ],
+    };
+    const QUARTER_SHORT: UnitPatterns = UnitPatterns {
+        past: ["{0} qtr. ago", "{0} qtrs. ago"],
+        future: ["in {0} qtr.", "in {0} qtrs."],
+        relative: &[("-1", "last qtr."), ("0", "this qtr."), ("1", "next qtr.")],
+    };
+    const QUARTER_NARROW: UnitPatterns = UnitPatterns {
+        past: ["{0}q ago", "{0}q ago"],
+        future: ["in {0}q", "in {0}q"],
+        relative: QUARTER_SHORT.relative,
+    };
+    const MONTH: UnitPatterns = UnitPatterns {
+        past: ["{0} month ago", "{0} months ago"],
+        future: ["in {0} month", "in {0} months"],
+        relative: &[
+            ("-1", "last month"),
+            ("0", "this month"),
+            ("1", "next month"),
+        ],
+    };
+    const MONTH_SHORT: UnitPatterns = UnitPatterns {
+        past: ["{0} mo. ago", "{0} mo. ago"],
+        future: ["in {0} mo.", "in {0} mo."],
+        relative: &[("-1", "last mo."), ("0", "this mo."), ("1", "next mo.")],
+    };
+    const MONTH_NARROW: UnitPatterns = UnitPatterns {
+        past: ["{0}mo ago", "{0}mo ago"],
+        future: ["in {0}mo", "in {0}mo"],
+        relative: MONTH_SHORT.relative,
+    };
+    const WEEK: UnitPatterns = UnitPatterns {
+        past: ["{0} week ago", "{0} weeks ago"],
+        future: ["in {0} week", "in {0} weeks"],
+        relative: &[("-1", "last week"), ("0", "this week"), ("1", "next week")],
+    };
+    const WEEK_SHORT: UnitPatterns = UnitPatterns {
+        past: ["{0} wk. ago", "{0} wk. ago"],
+        future: ["in {0} wk.", "in {0} wk."],
+        relative: &[("-1", "last wk."), ("0", "this wk."), ("1", "next wk.")],
+    };
+    const WEEK_NARROW: UnitPatterns = UnitPatterns {
+        past: ["{0}w ago", "{0}w ago"],
+        future: ["in {0}w", "in {0}w"],
+        relative: WEEK_SHORT.relative,
+    };
+    const DAY: UnitPatterns = UnitPatterns {
+        past: ["{0} day ago", "{0} days ago"],
+        future: ["in {0} day", "in {0} days"],
+        relative: &[("-1", "yesterday"), ("0", "today"), ("1", "tomorrow")],
+    };
+    const DAY_NARROW: UnitPatterns = UnitPatterns {
+        past: ["{0}d ago", "{0}d ago"],
+        future: ["in {0}d", "in {0}d"],
+        relative: DAY.relative,
+    };
+    const HOUR: UnitPatterns = UnitPatterns {
+        past: ["{0} hour ago", "{0} hours ago"],
+        future: ["in {0} hour", "in {0} hours"],
+        relative: &[("0", "this hour")],
+    };
+    const HOUR_SHORT: UnitPatterns = UnitPatterns {
+        past: ["{0} hr. ago", "{0} hr. ago"],
+        future: ["in {0} hr.", "in {0} hr."],
+        relative: HOUR.relative,
+    };
+    const HOUR_NARROW: UnitPatterns = UnitPatterns {
+        past: ["{0}h ago", "{0}h ago"],
+        future: ["in {0}h", "in {0}h"],
+        relative: HOUR.relative,
+    };
+    const MINUTE: UnitPatterns = UnitPatterns {
+        past: ["{0} minute ago", "{0} minutes ago"],
+        future: ["in {0} minute", "in {0} minutes"],
+        relative: &[("0", "this minute")],
+    };
+    const MINUTE_SHORT: UnitPatterns = UnitPatterns {
+        past: ["{0} min. ago", "{0} min. ago"],
+        future: ["in {0} min.", "in {0} min."],
+        relative: MINUTE.relative,
+    };
+    const MINUTE_NARROW: UnitPatterns = UnitPatterns {
+        past: ["{0}m ago", "{0}m ago"],
+        future: ["in {0}m", "in {0}m"],
+        relative: MINUTE.relative,
+    };
+    const SECOND: UnitPatterns = UnitPatterns {
+        past: ["{0} second ago", "{0} seconds ago"],
+        future: ["in {0} second", "in {0} seconds"],
+        relative: &[("0", "now")],
+    };
+    const SECOND_SHORT: UnitPatterns = UnitPatterns {
+        past: ["{0} sec. ago", "{0} sec. ago"],
+        future: ["in {0} sec.", "in {0} sec."],
+        relative: SECOND.relative,
+    };
+    const SECOND_NARROW: UnitPatterns = UnitPatterns {
+        past: ["{0}s ago", "{0}s ago"],
+        future: ["in {0}s", "in {0}s"],
+        relative: SECOND.relative,
+    };
+
+    match (unit, style) {
+        ("year", Style::Long) => &YEAR,
+        ("year", Style::Short) => &YEAR_SHORT,
+        ("year", Style::Narrow) => &YEAR_NARROW,
+        ("quarter", Style::Long) => &QUARTER,
+        ("quarter", Style::Short) => &QUARTER_SHORT,
+        ("quarter", Style::Narrow) => &QUARTER_NARROW,
+        ("month", Style::Long) => &MONTH,
+        ("month", Style::Short) => &MONTH_SHORT,
+        ("month", Style::Narrow) => &MONTH_NARROW,
+        ("week", Style::Long) => &WEEK,
+        ("week", Style::Short) => &WEEK_SHORT,
+        ("week", Style::Narrow) => &WEEK_NARROW,
+        ("day", Style::Long | Style::Short) => &DAY,
+        ("day", Style::Narrow) => &DAY_NARROW,
+        ("hour", Style::Long) => &HOUR,
+        ("hour", Style::Short) => &HOUR_SHORT,
+        ("hour", Style::Narrow) => &HOUR_NARROW,
+        ("minute", Style::Long) => &MINUTE,
+        ("minute", Style::Short) => &MINUTE_SHORT,
+        ("minute", Style::Narrow) => &MINUTE_NARROW,
+        (_, Style::Long) => &SECOND,
+        (_, Style::Short) => &SECOND_SHORT,
+        (_, Style::Narrow) => &SECOND_NARROW,
+    }
+}
@@ -0,0 +1,137 @@
+//! This module implements the `Segment Iterator` objects.
+//!
+//! A `Segment Iterator` is an object that represents a particular iteration over the segments
+//! of a `Segments` object. It yields the segment data object of each segment.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma402/#sec-segment-iterator-objects
+
+use crate::{
+    builtins::{
+        function::make_builtin_fn,
+        intl::segmenter::{create_segment_data_object, Granularity},
+        iterable::create_iter_result_object_or_throw,
+    },
+    object::{JsObject, ObjectData},
+    property::PropertyDescriptor,
+    symbol::WellKnownSymbols,
+    Context, JsNativeError, JsResult, JsString, JsValue,
+};
+use boa_gc::{Finalize, Trace};
+use boa_profiler::Profiler;
+
+#[derive(Debug, Clone, Finalize, Trace)]
+pub struct SegmentIterator {
+    #[unsafe_ignore_trace]
+    granularity: Granularity,
+    string: JsString,
+    /// Code unit index of the start of the next segment of `string`.
+    next_index: usize,
+}
+
+impl SegmentIterator {
+    /// Abstract operation `CreateSegmentIterator ( segmenter, string )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-createsegmentiterator
+    pub(crate) fn create(
+        granularity: Granularity,
+        string: JsString,
+        context: &mut Context,
+    ) -> JsObject {
+        // 1. Let internalSlotsList be « [[IteratingSegmenter]], [[IteratedString]], [[IteratedStringNextSegmentCodeUnitIndex]] ».
+        // 2. Let iterator be OrdinaryObjectCreate(%SegmentIteratorPrototype%, internalSlotsList).
+        // 3. Set iterator.[[IteratingSegmenter]] to segmenter.
+        // 4. Set iterator.[[IteratedString]] to string.
+        // 5. Set iterator.[[IteratedStringNextSegmentCodeUnitIndex]] to 0.
+        // 6. Return iterator.
+        JsObject::from_proto_and_data(
+            context
+                .intrinsics()
+                .objects()
+                .iterator_prototypes()
+                .segment_iterator(),
+            ObjectData::segment_iterator(Self {
+                granularity,
+                string,
+                next_index: 0,
+            }),
+        )
+    }
+
+    /// `%SegmentIteratorPrototype%.next ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-%segmentiteratorprototype%.next
+    pub(crate) fn next(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let iterator be the this value.
+        // 2. Perform ? RequireInternalSlot(iterator, [[IteratingSegmenter]]).
+        let mut iterator = this.as_object().map(JsObject::borrow_mut);
+        let iterator = iterator
+            .as_mut()
+            .and_then(|obj| obj.as_segment_iterator_mut())
+            .ok_or_else(|| JsNativeError::typ().with_message("`this` is not a SegmentIterator"))?;
+
+        // 3. Let segmenter be iterator.[[IteratingSegmenter]].
+        // 4. Let string be iterator.[[IteratedString]].
+        // 5. Let startIndex be iterator.[[IteratedStringNextSegmentCodeUnitIndex]].
+        // 6. Let len be the length of string.
+        // 7. If startIndex ≥ len, then
+        //     a. Return CreateIterResultObject(undefined, true).
+        // 8. Let endIndex be ! FindBoundary(segmenter, string, startIndex, after).
+        let start_index = iterator.next_index;
+        let segment = iterator
+            .granularity
+            .segments(&iterator.string)
+            .find(|(start, _, _)| *start == start_index);
+        let (end_index, segment) = match segment {
+            Some((_, end, segment)) => (end, segment.to_owned()),
+            None => return create_iter_result_object_or_throw(JsValue::undefined(), true, context),
+        };
+
+        // 9. Set iterator.[[IteratedStringNextSegmentCodeUnitIndex]] to endIndex.
+        iterator.next_index = end_index;
+
+        // 10. Let segmentData be ! CreateSegmentDataObject(segmenter, string, startIndex, endIndex).
+        let granularity = iterator.granularity;
+        let string = iterator.string.clone();
+        let segment_data =
+            create_segment_data_object(granularity, &string, start_index, &segment, context)?;
+
+        // 11. Return CreateIterResultObject(segmentData, false).
+        create_iter_result_object_or_throw(segment_data.into(), false, context)
+    }
+
+    /// Create the `%SegmentIteratorPrototype%` object
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-%segmentiteratorprototype%
+    pub(crate) fn create_prototype(
+        iterator_prototype: JsObject,
+        context: &mut Context,
+    ) -> JsObject {
+        let _timer = Profiler::global().start_event("Segment Iterator", "init");
+
+        // Create prototype
+        let segment_iterator =
+            JsObject::from_proto_and_data(iterator_prototype, ObjectData::ordinary());
+        make_builtin_fn(Self::next, "next", &segment_iterator, 0, context);
+
+        let to_string_tag = WellKnownSymbols::to_string_tag();
+        let to_string_tag_property = PropertyDescriptor::builder()
+            .value("Segmenter String Iterator")
+            .writable(false)
+            .enumerable(false)
+            .configurable(true);
+        segment_iterator.insert(to_string_tag, to_string_tag_property);
+        segment_iterator
+    }
+}
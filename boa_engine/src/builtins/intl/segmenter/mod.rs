@@ -0,0 +1,335 @@
+//! This module implements the global `Intl.Segmenter` object.
+//!
+//! `Intl.Segmenter` is a built-in object that enables locale-sensitive text segmentation,
+//! splitting a string into its graphemes, words or sentences.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma402/#segmenter-objects
+
+mod iterator;
+mod segments;
+
+pub use iterator::SegmentIterator;
+pub use segments::Segments;
+
+use crate::{
+    builtins::{
+        intl::{
+            available_locales, canonicalize_locale_list, get_option, get_options_object,
+            resolve_locale, DateTimeFormatRecord, GetOptionType,
+        },
+        JsArgs,
+    },
+    context::intrinsics::StandardConstructors,
+    object::{
+        internal_methods::get_prototype_from_constructor, ConstructorBuilder, JsFunction, JsObject,
+        ObjectData,
+    },
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    Context, JsResult, JsString, JsValue,
+};
+
+use boa_gc::{Finalize, Trace};
+use boa_profiler::Profiler;
+use rustc_hash::FxHashMap;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// The granularity of the segments of an `Intl.Segmenter`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Granularity {
+    Grapheme,
+    Word,
+    Sentence,
+}
+
+impl Granularity {
+    /// Returns the value of the `granularity` option for this granularity.
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Grapheme => "grapheme",
+            Self::Word => "word",
+            Self::Sentence => "sentence",
+        }
+    }
+
+    /// Splits `string` into its segments of this granularity, yielding each segment along with
+    /// the code unit indices of its start and end.
+    ///
+    /// Segment boundaries follow the default rules of [Unicode Standard Annex #29][uax29], so
+    /// grapheme segments are extended grapheme clusters, which keep combining marks and emoji
+    /// ZWJ sequences together.
+    ///
+    /// [uax29]: https://www.unicode.org/reports/tr29/
+    pub(crate) fn segments(self, string: &str) -> impl Iterator<Item = (usize, usize, &str)> {
+        let segments: Box<dyn Iterator<Item = &str>> = match self {
+            Self::Grapheme => Box::new(string.graphemes(true)),
+            Self::Word => Box::new(string.split_word_bounds()),
+            Self::Sentence => Box::new(string.split_sentence_bounds()),
+        };
+
+        segments.scan(0, |start, segment| {
+            let segment_start = *start;
+            *start += segment.encode_utf16().count();
+            Some((segment_start, *start, segment))
+        })
+    }
+}
+
+/// JavaScript `Intl.Segmenter` object.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct Segmenter {
+    locale: JsString,
+    #[unsafe_ignore_trace]
+    granularity: Granularity,
+}
+
+impl Segmenter {
+    const NAME: &'static str = "Segmenter";
+
+    pub(super) fn init(context: &mut Context) -> JsFunction {
+        let _timer = Profiler::global().start_event(Self::NAME, "init");
+
+        ConstructorBuilder::with_standard_constructor(
+            context,
+            Self::constructor,
+            context.intrinsics().constructors().segmenter().clone(),
+        )
+        .name(Self::NAME)
+        .length(0)
+        .property(
+            WellKnownSymbols::to_string_tag(),
+            "Intl.Segmenter",
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
+        .method(Self::segment, "segment", 1)
+        .method(Self::resolved_options, "resolvedOptions", 0)
+        .build()
+    }
+}
+
+impl Segmenter {
+    /// The `Intl.Segmenter` constructor is the `%Segmenter%` intrinsic object and a standard built-in property of the `Intl` object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.segmenter
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/Segmenter/Segmenter
+    pub(crate) fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return context.throw_type_error(
+                "calling a builtin Intl.Segmenter constructor without new is forbidden",
+            );
+        }
+
+        // 2. Let internalSlotsList be « [[InitializedSegmenter]], [[Locale]], [[SegmenterGranularity]] ».
+        // 3. Let segmenter be ? OrdinaryCreateFromConstructor(NewTarget, "%Segmenter.prototype%", internalSlotsList).
+        let prototype =
+            get_prototype_from_constructor(new_target, StandardConstructors::segmenter, context)?;
+
+        // 4. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales: Vec<JsString> = canonicalize_locale_list(args, context)?
+            .into_iter()
+            .map(|locale| locale.to_string().into())
+            .collect();
+
+        // 5. Set options to ? GetOptionsObject(options).
+        let options = get_options_object(args.get_or_undefined(1), context)?;
+
+        // 6. Let opt be a new Record.
+        // 7. Let matcher be ? GetOption(options, "localeMatcher", "string", « "lookup", "best fit" », "best fit").
+        // 8. Set opt.[[localeMatcher]] to matcher.
+        let locale_matcher = get_option(
+            &options,
+            "localeMatcher",
+            &GetOptionType::String,
+            &[JsString::new("lookup"), JsString::new("best fit")],
+            &JsValue::new("best fit"),
+            context,
+        )?
+        .to_string(context)?;
+
+        // 9. Let localeData be %Segmenter%.[[LocaleData]].
+        // 10. Let r be ResolveLocale(%Segmenter%.[[AvailableLocales]], requestedLocales, opt,
+        // %Segmenter%.[[RelevantExtensionKeys]], localeData).
+        //
+        // The segmentation rules don't depend on the locale, so every requested locale is available.
+        let available = available_locales(&requested_locales, |_| true);
+        let r = resolve_locale(
+            &available,
+            &requested_locales,
+            &DateTimeFormatRecord {
+                locale_matcher,
+                properties: FxHashMap::default(),
+            },
+            &[],
+            &FxHashMap::default(),
+            context,
+        );
+
+        // 11. Set segmenter.[[Locale]] to r.[[locale]].
+        // 12. Let granularity be ? GetOption(options, "granularity", "string", « "grapheme", "word", "sentence" », "grapheme").
+        // 13. Set segmenter.[[SegmenterGranularity]] to granularity.
+        let granularity = get_option(
+            &options,
+            "granularity",
+            &GetOptionType::String,
+            &[
+                JsString::new("grapheme"),
+                JsString::new("word"),
+                JsString::new("sentence"),
+            ],
+            &JsValue::new("grapheme"),
+            context,
+        )?;
+        let granularity = match granularity.as_string().map(JsString::as_str) {
+            Some("word") => Granularity::Word,
+            Some("sentence") => Granularity::Sentence,
+            _ => Granularity::Grapheme,
+        };
+
+        // 14. Return segmenter.
+        let segmenter = Self {
+            locale: r.locale,
+            granularity,
+        };
+        Ok(
+            JsObject::from_proto_and_data(prototype, ObjectData::segmenter(Box::new(segmenter)))
+                .into(),
+        )
+    }
+
+    /// Returns a copy of the `[[InitializedSegmenter]]` object `this`, or throws a `TypeError`
+    /// if `this` is not an `Intl.Segmenter` object.
+    fn this_segmenter(this: &JsValue, context: &mut Context) -> JsResult<Self> {
+        this.as_object()
+            .and_then(|object| object.borrow().as_segmenter().cloned())
+            .map_or_else(
+                || context.throw_type_error("'this' is not an Intl.Segmenter object"),
+                Ok,
+            )
+    }
+
+    /// `Intl.Segmenter.prototype.segment ( string )`
+    ///
+    /// Returns a `Segments` object containing the segments of `string`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.segmenter.prototype.segment
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/Segmenter/segment
+    pub(crate) fn segment(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let segmenter be the this value.
+        // 2. Perform ? RequireInternalSlot(segmenter, [[InitializedSegmenter]]).
+        let segmenter = Self::this_segmenter(this, context)?;
+
+        // 3. Let string be ? ToString(string).
+        let string = args.get_or_undefined(0).to_string(context)?;
+
+        // 4. Return ! CreateSegmentsObject(segmenter, string).
+        Ok(Segments::create(segmenter.granularity, string, context).into())
+    }
+
+    /// `Intl.Segmenter.prototype.resolvedOptions ( )`
+    ///
+    /// Returns a new object with properties reflecting the locale and granularity options computed
+    /// during the construction of the current `Intl.Segmenter` object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.segmenter.prototype.resolvedoptions
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/Segmenter/resolvedOptions
+    pub(crate) fn resolved_options(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let segmenter be the this value.
+        // 2. Perform ? RequireInternalSlot(segmenter, [[InitializedSegmenter]]).
+        let segmenter = Self::this_segmenter(this, context)?;
+
+        // 3. Let options be OrdinaryObjectCreate(%Object.prototype%).
+        let options = context.construct_object();
+
+        // 4. For each row of Table 18, except the header row, in table order, do
+        //     a. Let p be the Property value of the current row.
+        //     b. Let v be the value of segmenter's internal slot whose name is the Internal Slot value of the current row.
+        //     c. Assert: v is not undefined.
+        //     d. Perform ! CreateDataPropertyOrThrow(options, p, v).
+        let properties: [(&str, JsValue); 2] = [
+            ("locale", segmenter.locale.clone().into()),
+            ("granularity", segmenter.granularity.as_str().into()),
+        ];
+        for (property, value) in properties {
+            options.create_data_property_or_throw(property, value, context)?;
+        }
+
+        // 5. Return options.
+        Ok(options.into())
+    }
+}
+
+/// Abstract operation `CreateSegmentDataObject ( segmenter, string, startIndex, endIndex )`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma402/#sec-createsegmentdataobject
+fn create_segment_data_object(
+    granularity: Granularity,
+    string: &JsString,
+    start_index: usize,
+    segment: &str,
+    context: &mut Context,
+) -> JsResult<JsObject> {
+    // 1. Let len be the length of string.
+    // 2. Assert: startIndex ≥ 0.
+    // 3. Assert: endIndex ≤ len.
+    // 4. Assert: startIndex < endIndex.
+    // 5. Let result be OrdinaryObjectCreate(%Object.prototype%).
+    let result = context.construct_object();
+
+    // 6. Let segment be the substring of string from startIndex to endIndex.
+    // 7. Perform ! CreateDataPropertyOrThrow(result, "segment", segment).
+    result.create_data_property_or_throw("segment", segment, context)?;
+
+    // 8. Perform ! CreateDataPropertyOrThrow(result, "index", 𝔽(startIndex)).
+    result.create_data_property_or_throw("index", start_index, context)?;
+
+    // 9. Perform ! CreateDataPropertyOrThrow(result, "input", string).
+    result.create_data_property_or_throw("input", string.clone(), context)?;
+
+    // 10. Let granularity be segmenter.[[SegmenterGranularity]].
+    // 11. If granularity is "word", then
+    if granularity == Granularity::Word {
+        // a. Let isWordLike be a Boolean value indicating whether the segment in string is
+        // "word-like" according to locale segmenter.[[Locale]].
+        //
+        // A word is word-like if it contains letters, digits or ideographs, as opposed to
+        // whitespace and punctuation.
+        let is_word_like = segment.chars().any(char::is_alphanumeric);
+
+        // b. Perform ! CreateDataPropertyOrThrow(result, "isWordLike", isWordLike).
+        result.create_data_property_or_throw("isWordLike", is_word_like, context)?;
+    }
+
+    // 12. Return result.
+    Ok(result)
+}
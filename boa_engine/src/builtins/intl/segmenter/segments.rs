@@ -0,0 +1,165 @@
+//! This module implements the `Segments` objects returned by `Intl.Segmenter.prototype.segment`.
+//!
+//! A `Segments` object represents the segments of a specific string, subject to the locale and
+//! options of the `Intl.Segmenter` that created it.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma402/#sec-segments-objects
+
+use crate::{
+    builtins::{
+        function::make_builtin_fn,
+        intl::segmenter::{create_segment_data_object, Granularity, SegmentIterator},
+        JsArgs,
+    },
+    object::{FunctionBuilder, JsObject, ObjectData},
+    property::PropertyDescriptor,
+    symbol::WellKnownSymbols,
+    value::IntegerOrInfinity,
+    Context, JsNativeError, JsResult, JsString, JsValue,
+};
+use boa_gc::{Finalize, Trace};
+use boa_profiler::Profiler;
+
+#[derive(Debug, Clone, Finalize, Trace)]
+pub struct Segments {
+    #[unsafe_ignore_trace]
+    granularity: Granularity,
+    string: JsString,
+}
+
+impl Segments {
+    /// Abstract operation `CreateSegmentsObject ( segmenter, string )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-createsegmentsobject
+    pub(crate) fn create(
+        granularity: Granularity,
+        string: JsString,
+        context: &mut Context,
+    ) -> JsObject {
+        // 1. Let internalSlotsList be « [[SegmentsSegmenter]], [[SegmentsString]] ».
+        // 2. Let segments be OrdinaryObjectCreate(%SegmentsPrototype%, internalSlotsList).
+        // 3. Set segments.[[SegmentsSegmenter]] to segmenter.
+        // 4. Set segments.[[SegmentsString]] to string.
+        // 5. Return segments.
+        JsObject::from_proto_and_data(
+            context.intrinsics().objects().segments_prototype(),
+            ObjectData::segments(Self {
+                granularity,
+                string,
+            }),
+        )
+    }
+
+    /// `%SegmentsPrototype%.containing ( index )`
+    ///
+    /// Returns the segment data of the segment that contains the code unit at `index`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-%segmentsprototype%.containing
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/Segmenter/segment/Segments/containing
+    pub(crate) fn containing(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let segments be the this value.
+        // 2. Perform ? RequireInternalSlot(segments, [[SegmentsSegmenter]]).
+        let segments = this
+            .as_object()
+            .and_then(|object| object.borrow().as_segments().cloned())
+            .ok_or_else(|| JsNativeError::typ().with_message("`this` is not a Segments object"))?;
+
+        // 3. Let segmenter be segments.[[SegmentsSegmenter]].
+        // 4. Let string be segments.[[SegmentsString]].
+        // 5. Let len be the length of string.
+        // 6. Let n be ? ToIntegerOrInfinity(index).
+        // 7. If n < 0 or n ≥ len, return undefined.
+        let n = match args.get_or_undefined(0).to_integer_or_infinity(context)? {
+            IntegerOrInfinity::Integer(n) if n >= 0 => n as usize,
+            _ => return Ok(JsValue::undefined()),
+        };
+
+        // 8. Let startIndex be ! FindBoundary(segmenter, string, n, before).
+        // 9. Let endIndex be ! FindBoundary(segmenter, string, n, after).
+        // 10. Return ! CreateSegmentDataObject(segmenter, string, startIndex, endIndex).
+        let segment = segments
+            .granularity
+            .segments(&segments.string)
+            .find(|(_, end, _)| n < *end);
+        match segment {
+            Some((start, _, segment)) => Ok(create_segment_data_object(
+                segments.granularity,
+                &segments.string,
+                start,
+                segment,
+                context,
+            )?
+            .into()),
+            None => Ok(JsValue::undefined()),
+        }
+    }
+
+    /// `%SegmentsPrototype% [ @@iterator ] ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-%segmentsprototype%-@@iterator
+    pub(crate) fn iterator(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let segments be the this value.
+        // 2. Perform ? RequireInternalSlot(segments, [[SegmentsSegmenter]]).
+        let segments = this
+            .as_object()
+            .and_then(|object| object.borrow().as_segments().cloned())
+            .ok_or_else(|| JsNativeError::typ().with_message("`this` is not a Segments object"))?;
+
+        // 3. Let segmenter be segments.[[SegmentsSegmenter]].
+        // 4. Let string be segments.[[SegmentsString]].
+        // 5. Return ! CreateSegmentIterator(segmenter, string).
+        Ok(SegmentIterator::create(segments.granularity, segments.string.clone(), context).into())
+    }
+
+    /// Create the `%SegmentsPrototype%` object
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-%segmentsprototype%-object
+    pub(crate) fn create_prototype(context: &mut Context) -> JsObject {
+        let _timer = Profiler::global().start_event("Segments", "init");
+
+        let segments = JsObject::from_proto_and_data(
+            context.intrinsics().constructors().object().prototype(),
+            ObjectData::ordinary(),
+        );
+        make_builtin_fn(Self::containing, "containing", &segments, 1, context);
+
+        let iterator = FunctionBuilder::native(context, Self::iterator)
+            .name("[Symbol.iterator]")
+            .length(0)
+            .constructor(false)
+            .build();
+        segments.insert(
+            WellKnownSymbols::iterator(),
+            PropertyDescriptor::builder()
+                .value(iterator)
+                .writable(true)
+                .enumerable(false)
+                .configurable(true),
+        );
+        segments
+    }
+}
@@ -5,11 +5,13 @@ use crate::{
         get_number_option, get_option, insert_unicode_extension_and_canonicalize, lookup_matcher,
         resolve_locale, unicode_extension_components, DateTimeFormatRecord, GetOptionType,
     },
+    forward,
     object::JsObject,
     Context, JsString, JsValue,
 };
 
 use icu_locale_canonicalizer::LocaleCanonicalizer;
+use icu_provider::prelude::*;
 use rustc_hash::FxHashMap;
 
 #[test]
@@ -543,3 +545,428 @@ fn to_date_time_opts() {
         Ok(numeric_jsstring)
     );
 }
+
+#[test]
+fn plural_rules() {
+    let mut context = Context::default();
+    let init = r#"
+        var en = new Intl.PluralRules("en");
+        var enOrdinal = new Intl.PluralRules("en", { type: "ordinal" });
+        var ru = new Intl.PluralRules("ru");
+        var ar = new Intl.PluralRules("ar");
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "en.select(1)"), "\"one\"");
+    assert_eq!(forward(&mut context, "en.select(0)"), "\"other\"");
+    assert_eq!(forward(&mut context, "en.select(1.5)"), "\"other\"");
+    assert_eq!(forward(&mut context, "en.select(-1)"), "\"one\"");
+    assert_eq!(forward(&mut context, "en.select(Infinity)"), "\"other\"");
+    assert_eq!(forward(&mut context, "enOrdinal.select(22)"), "\"two\"");
+    assert_eq!(forward(&mut context, "enOrdinal.select(13)"), "\"other\"");
+    assert_eq!(forward(&mut context, "ru.select(3)"), "\"few\"");
+    assert_eq!(forward(&mut context, "ru.select(5)"), "\"many\"");
+    assert_eq!(forward(&mut context, "ar.select(0)"), "\"zero\"");
+    assert_eq!(
+        forward(
+            &mut context,
+            r#"new Intl.PluralRules("en", { minimumFractionDigits: 1 }).select(1)"#
+        ),
+        "\"other\""
+    );
+    assert_eq!(
+        forward(&mut context, "en.selectRange(1, 1.0004)"),
+        "\"one\""
+    );
+    assert_eq!(forward(&mut context, "en.selectRange(0, 2)"), "\"other\"");
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { en.selectRange(1) } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { en.selectRange(NaN, 1) } catch (e) { e instanceof RangeError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Intl.PluralRules() } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.prototype.toString.call(Intl.PluralRules.prototype)"
+        ),
+        "\"[object Intl.PluralRules]\""
+    );
+}
+
+#[test]
+fn plural_rules_resolved_options() {
+    let mut context = Context::default();
+    let init = r#"
+        var options = new Intl.PluralRules("ru", { type: "cardinal" }).resolvedOptions();
+        var significant = new Intl.PluralRules("en", { maximumSignificantDigits: 2 })
+            .resolvedOptions();
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "options.locale"), "\"ru\"");
+    assert_eq!(forward(&mut context, "options.type"), "\"cardinal\"");
+    assert_eq!(forward(&mut context, "options.minimumIntegerDigits"), "1");
+    assert_eq!(forward(&mut context, "options.minimumFractionDigits"), "0");
+    assert_eq!(forward(&mut context, "options.maximumFractionDigits"), "3");
+    assert_eq!(
+        forward(&mut context, "options.pluralCategories.join()"),
+        "\"one,few,many,other\""
+    );
+    assert_eq!(
+        forward(&mut context, "significant.minimumSignificantDigits"),
+        "1"
+    );
+    assert_eq!(
+        forward(&mut context, "significant.maximumSignificantDigits"),
+        "2"
+    );
+    assert_eq!(
+        forward(&mut context, "'minimumFractionDigits' in significant"),
+        "false"
+    );
+}
+
+#[test]
+fn relative_time_format() {
+    let mut context = Context::default();
+    let init = r#"
+        var long = new Intl.RelativeTimeFormat("en");
+        var auto = new Intl.RelativeTimeFormat("en", { numeric: "auto" });
+        var short = new Intl.RelativeTimeFormat("en", { style: "short" });
+        var narrow = new Intl.RelativeTimeFormat("en", { style: "narrow" });
+    "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "long.format(1, 'day')"),
+        "\"in 1 day\""
+    );
+    assert_eq!(
+        forward(&mut context, "long.format(-3, 'days')"),
+        "\"3 days ago\""
+    );
+    assert_eq!(
+        forward(&mut context, "long.format(-0, 'second')"),
+        "\"0 seconds ago\""
+    );
+    assert_eq!(
+        forward(&mut context, "long.format(1234.5, 'year')"),
+        "\"in 1,234.5 years\""
+    );
+    assert_eq!(
+        forward(&mut context, "auto.format(-1, 'day')"),
+        "\"yesterday\""
+    );
+    assert_eq!(forward(&mut context, "auto.format(0, 'second')"), "\"now\"");
+    assert_eq!(
+        forward(&mut context, "auto.format(2, 'day')"),
+        "\"in 2 days\""
+    );
+    assert_eq!(
+        forward(&mut context, "short.format(2, 'quarter')"),
+        "\"in 2 qtrs.\""
+    );
+    assert_eq!(
+        forward(&mut context, "narrow.format(-5, 'minutes')"),
+        "\"5m ago\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { long.format(1, 'decade') } catch (e) { e instanceof RangeError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { long.format(NaN, 'day') } catch (e) { e instanceof RangeError }"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn relative_time_format_to_parts() {
+    let mut context = Context::default();
+    let init = r#"
+        var rtf = new Intl.RelativeTimeFormat("en", { numeric: "auto" });
+        var parts = rtf.formatToParts(1000, "hour");
+        var literal = rtf.formatToParts(1, "day");
+        var options = rtf.resolvedOptions();
+    "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(
+            &mut context,
+            "parts.map((p) => `${p.type}:${p.value}:${p.unit}`).join('|')"
+        ),
+        "\"literal:in :undefined|integer:1:hour|group:,:hour|integer:000:hour|literal: hours:undefined\""
+    );
+    assert_eq!(
+        forward(&mut context, "JSON.stringify(literal)"),
+        r#""[{"type":"literal","value":"tomorrow"}]""#
+    );
+    assert_eq!(forward(&mut context, "options.locale"), "\"en\"");
+    assert_eq!(forward(&mut context, "options.style"), "\"long\"");
+    assert_eq!(forward(&mut context, "options.numeric"), "\"auto\"");
+    assert_eq!(forward(&mut context, "options.numberingSystem"), "\"latn\"");
+}
+
+#[test]
+fn relative_time_format_other_locales() {
+    let mut context = Context::default();
+    let init = r#"
+        var rtf = new Intl.RelativeTimeFormat(["fr", "ja"], { numeric: "auto" });
+        var locale = rtf.resolvedOptions().locale;
+    "#;
+    forward(&mut context, init);
+
+    // Only English patterns are available, so the resolved locale must be an English locale,
+    // even if the default locale of the host is not.
+    assert_eq!(
+        forward(&mut context, "locale === 'en' || locale.startsWith('en-')"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "rtf.format(-1, 'day')"),
+        "\"yesterday\""
+    );
+}
+
+#[test]
+fn segmenter_graphemes() {
+    let mut context = Context::default();
+    let init = r#"
+        var segmenter = new Intl.Segmenter("en");
+        var text = "e\u0301\u{1F468}\u200D\u{1F469}\u200D\u{1F467}a";
+        var segments = [...segmenter.segment(text)];
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "segments.length"), "3");
+    assert_eq!(
+        forward(&mut context, "segments.map((s) => s.index).join()"),
+        "\"0,2,10\""
+    );
+    assert_eq!(
+        forward(&mut context, "segments[1].segment === text.slice(2, 10)"),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "segments[2].input === text"), "true");
+    assert_eq!(
+        forward(&mut context, "'isWordLike' in segments[0]"),
+        "false"
+    );
+    assert_eq!(
+        forward(&mut context, "JSON.stringify(segmenter.resolvedOptions())"),
+        r#""{"locale":"en","granularity":"grapheme"}""#
+    );
+}
+
+#[test]
+fn segmenter_words() {
+    let mut context = Context::default();
+    let init = r#"
+        var segments = new Intl.Segmenter("en", { granularity: "word" }).segment("Hello, world!");
+    "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(
+            &mut context,
+            "Array.from(segments, (s) => `${s.segment}:${s.isWordLike}`).join('|')"
+        ),
+        "\"Hello:true|,:false| :false|world:true|!:false\""
+    );
+    assert_eq!(
+        forward(&mut context, "segments.containing(8).segment"),
+        "\"world\""
+    );
+    assert_eq!(forward(&mut context, "segments.containing(8).index"), "7");
+    assert_eq!(
+        forward(&mut context, "segments.containing(13)"),
+        "undefined"
+    );
+    assert_eq!(
+        forward(&mut context, "segments.containing(-1)"),
+        "undefined"
+    );
+}
+
+#[test]
+fn segmenter_sentences() {
+    let mut context = Context::default();
+    let init = r#"
+        var segments = new Intl.Segmenter("en", { granularity: "sentence" }).segment("One. Two? Three");
+    "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(
+            &mut context,
+            "Array.from(segments, (s) => s.segment).join('|')"
+        ),
+        "\"One. |Two? |Three\""
+    );
+}
+
+#[test]
+fn segmenter_prototypes() {
+    let mut context = Context::default();
+    let init = r#"
+        var segments = new Intl.Segmenter().segment("ab");
+        var iterator = segments[Symbol.iterator]();
+        var first = iterator.next();
+        iterator.next();
+        var last = iterator.next();
+    "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.prototype.toString.call(new Intl.Segmenter())"
+        ),
+        "\"[object Intl.Segmenter]\""
+    );
+    assert_eq!(
+        forward(&mut context, "Object.prototype.toString.call(iterator)"),
+        "\"[object Segmenter String Iterator]\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.getPrototypeOf(Object.getPrototypeOf(iterator)) === Object.getPrototypeOf(Object.getPrototypeOf([][Symbol.iterator]()))"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.getPrototypeOf(Object.getPrototypeOf(segments)) === Object.prototype"
+        ),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "first.value.segment"), "\"a\"");
+    assert_eq!(forward(&mut context, "last.done"), "true");
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Intl.Segmenter() } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new Intl.Segmenter('en', 'word') } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "try { new Intl.Segmenter('en', { granularity: 'line' }) } catch (e) { e instanceof RangeError }"),
+        "true"
+    );
+}
+
+#[test]
+fn supported_locales_of() {
+    let mut context = Context::default();
+
+    assert_eq!(
+        forward(
+            &mut context,
+            r#"Intl.PluralRules.supportedLocalesOf(["en-US", "de", "ru-u-nu-latn"]).join()"#
+        ),
+        "\"en-US,ru-u-nu-latn\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            r#"Intl.RelativeTimeFormat.supportedLocalesOf(["fr", "en-GB"]).join()"#
+        ),
+        "\"en-GB\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            r#"Intl.DateTimeFormat.supportedLocalesOf(["en-US", "de", "ja"]).join()"#
+        ),
+        "\"en-US,ja\""
+    );
+    assert_eq!(
+        forward(&mut context, "Intl.PluralRules.supportedLocalesOf().length"),
+        "0"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            r#"try { Intl.PluralRules.supportedLocalesOf("en", { localeMatcher: "x" }) } catch (e) { e instanceof RangeError }"#
+        ),
+        "true"
+    );
+}
+
+/// Data provider that only has the data of the `en` and root locales of the test data.
+struct EnglishOnlyProvider<P>(P);
+
+impl<M, P> ResourceProvider<M> for EnglishOnlyProvider<P>
+where
+    M: ResourceMarker,
+    P: ResourceProvider<M>,
+{
+    fn load_resource(&self, req: &DataRequest) -> Result<DataResponse<M>, DataError> {
+        let language = req.options.get_langid().language;
+        if language.is_empty() || language.as_str() == "en" {
+            self.0.load_resource(req)
+        } else {
+            Err(DataErrorKind::MissingLocale.with_req(M::KEY, req))
+        }
+    }
+}
+
+#[test]
+fn injected_provider() {
+    let mut context = Context::builder()
+        .icu_provider(Box::new(EnglishOnlyProvider(icu_testdata::get_provider())))
+        .expect("the provider has the data of the locale canonicalizer")
+        .build();
+
+    assert_eq!(
+        forward(
+            &mut context,
+            r#"Intl.PluralRules.supportedLocalesOf(["ru", "en-US", "ar"]).join()"#
+        ),
+        "\"en-US\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            r#"Intl.DateTimeFormat.supportedLocalesOf(["ja", "en-GB"]).join()"#
+        ),
+        "\"en-GB\""
+    );
+
+    // Locales without data fall back to the default locale, or to the root locale if the
+    // provider has no data for it either.
+    assert_eq!(
+        forward(
+            &mut context,
+            r#"
+            var ru = new Intl.PluralRules("ru");
+            ru.resolvedOptions().locale !== "ru" && ru.select(5)
+            "#
+        ),
+        "\"other\""
+    );
+}
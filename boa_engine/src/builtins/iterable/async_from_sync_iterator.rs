@@ -0,0 +1,347 @@
+//! This module implements the `%AsyncFromSyncIteratorPrototype%` object, used to iterate over a
+//! synchronous iterable where an asynchronous iterator is expected.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-%asyncfromsynciteratorprototype%-object
+
+use crate::{
+    builtins::{
+        function::make_builtin_fn,
+        iterable::{create_iter_result_object_or_throw, IteratorRecord, IteratorResult},
+        promise::{if_abrupt_reject_promise, PromiseCapability},
+        JsArgs, Promise,
+    },
+    object::{FunctionBuilder, JsObject, ObjectData},
+    Context, JsNativeError, JsResult, JsValue,
+};
+use boa_gc::{Finalize, Trace};
+use boa_profiler::Profiler;
+
+/// An asynchronous iterator wrapping a synchronous one.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-properties-of-async-from-sync-iterator-instances
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct AsyncFromSyncIterator {
+    // The [[SyncIteratorRecord]] internal slot.
+    sync_iterator_record: IteratorRecord,
+}
+
+impl AsyncFromSyncIterator {
+    pub(crate) const NAME: &'static str = "AsyncFromSyncIterator";
+
+    /// Creates the `%AsyncFromSyncIteratorPrototype%` object.
+    pub(crate) fn create_prototype(
+        async_iterator_prototype: JsObject,
+        context: &mut Context,
+    ) -> JsObject {
+        let _timer = Profiler::global().start_event(Self::NAME, "init");
+
+        let prototype =
+            JsObject::from_proto_and_data(async_iterator_prototype, ObjectData::ordinary());
+        make_builtin_fn(Self::next, "next", &prototype, 1, context);
+        make_builtin_fn(Self::r#return, "return", &prototype, 1, context);
+        make_builtin_fn(Self::throw, "throw", &prototype, 1, context);
+        prototype
+    }
+
+    /// `CreateAsyncFromSyncIterator ( syncIteratorRecord )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-createasyncfromsynciterator
+    pub(crate) fn create(
+        sync_iterator_record: IteratorRecord,
+        context: &mut Context,
+    ) -> IteratorRecord {
+        // 1. Let asyncIterator be OrdinaryObjectCreate(%AsyncFromSyncIteratorPrototype%, « [[SyncIteratorRecord]] »).
+        // 2. Set asyncIterator.[[SyncIteratorRecord]] to syncIteratorRecord.
+        let async_iterator = JsObject::from_proto_and_data(
+            context
+                .intrinsics()
+                .objects()
+                .iterator_prototypes()
+                .async_from_sync_iterator(),
+            ObjectData::async_from_sync_iterator(Self {
+                sync_iterator_record,
+            }),
+        );
+
+        // 3. Let nextMethod be ! Get(asyncIterator, "next").
+        let next_method = async_iterator
+            .get("next", context)
+            .expect("the prototype of an AsyncFromSyncIterator must have a `next` method");
+
+        // 4. Let iteratorRecord be the Iterator Record { [[Iterator]]: asyncIterator, [[NextMethod]]: nextMethod, [[Done]]: false }.
+        // 5. Return iteratorRecord.
+        IteratorRecord::new(async_iterator, next_method, false)
+    }
+
+    /// Gets the `[[SyncIteratorRecord]]` of the `this` value and a new promise capability.
+    fn this_sync_iterator_record(
+        this: &JsValue,
+        context: &mut Context,
+    ) -> JsResult<(IteratorRecord, PromiseCapability)> {
+        // 1. Let O be the this value.
+        // 2. Assert: O is an Object that has a [[SyncIteratorRecord]] internal slot.
+        let sync_iterator_record = this
+            .as_object()
+            .and_then(|o| {
+                o.borrow()
+                    .as_async_from_sync_iterator()
+                    .map(|iterator| iterator.sync_iterator_record.clone())
+            })
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message("`this` is not an AsyncFromSyncIterator")
+            })?;
+
+        // 3. Let promiseCapability be ! NewPromiseCapability(%Promise%).
+        let promise_capability = PromiseCapability::new(
+            &context
+                .intrinsics()
+                .constructors()
+                .promise()
+                .constructor()
+                .into(),
+            context,
+        )
+        .expect("cannot fail with the %Promise% constructor");
+
+        Ok((sync_iterator_record, promise_capability))
+    }
+
+    /// `%AsyncFromSyncIteratorPrototype%.next ( [ value ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%asyncfromsynciteratorprototype%.next
+    fn next(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1-3.
+        let (sync_iterator_record, promise_capability) =
+            Self::this_sync_iterator_record(this, context)?;
+
+        // 4. Let syncIteratorRecord be O.[[SyncIteratorRecord]].
+        // 5. If value is present, then
+        //     a. Let result be Completion(IteratorNext(syncIteratorRecord, value)).
+        // 6. Else,
+        //     a. Let result be Completion(IteratorNext(syncIteratorRecord)).
+        let result = sync_iterator_record.next(args.get(0).cloned(), context);
+
+        // 7. IfAbruptRejectPromise(result, promiseCapability).
+        if_abrupt_reject_promise!(result, promise_capability, context);
+
+        // 8. Return AsyncFromSyncIteratorContinuation(result, promiseCapability).
+        Self::continuation(&result, &promise_capability, context)
+    }
+
+    /// `%AsyncFromSyncIteratorPrototype%.return ( [ value ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%asyncfromsynciteratorprototype%.return
+    fn r#return(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1-3.
+        let (sync_iterator_record, promise_capability) =
+            Self::this_sync_iterator_record(this, context)?;
+
+        // 4. Let syncIterator be O.[[SyncIteratorRecord]].[[Iterator]].
+        let sync_iterator = sync_iterator_record.iterator().clone();
+
+        // 5. Let return be Completion(GetMethod(syncIterator, "return")).
+        let r#return = sync_iterator.get_method("return", context);
+
+        // 6. IfAbruptRejectPromise(return, promiseCapability).
+        if_abrupt_reject_promise!(r#return, promise_capability, context);
+
+        let result = match r#return {
+            // 7. If return is undefined, then
+            None => {
+                // a. Let iterResult be CreateIterResultObject(value, true).
+                let iter_result = create_iter_result_object_or_throw(
+                    args.get_or_undefined(0).clone(),
+                    true,
+                    context,
+                );
+                if_abrupt_reject_promise!(iter_result, promise_capability, context);
+
+                // b. Perform ! Call(promiseCapability.[[Resolve]], undefined, « iterResult »).
+                promise_capability
+                    .resolve()
+                    .call(&JsValue::undefined(), &[iter_result], context)
+                    .expect("cannot fail per spec");
+
+                // c. Return promiseCapability.[[Promise]].
+                return Ok(promise_capability.promise().clone().into());
+            }
+            // 8. If value is present, then
+            //     a. Let result be Completion(Call(return, syncIterator, « value »)).
+            // 9. Else,
+            //     a. Let result be Completion(Call(return, syncIterator)).
+            Some(r#return) => r#return.call(
+                &sync_iterator.into(),
+                args.get(0..1).unwrap_or(&[]),
+                context,
+            ),
+        };
+
+        // 10. IfAbruptRejectPromise(result, promiseCapability).
+        if_abrupt_reject_promise!(result, promise_capability, context);
+
+        // 11. If Type(result) is not Object, then
+        let result = if let Some(result) = result.as_object() {
+            IteratorResult::new(result.clone())
+        } else {
+            // a. Perform ! Call(promiseCapability.[[Reject]], undefined, « a newly created TypeError object »).
+            let error = context.construct_type_error("iterator result is not an object");
+            promise_capability
+                .reject()
+                .call(&JsValue::undefined(), &[error], context)
+                .expect("cannot fail per spec");
+
+            // b. Return promiseCapability.[[Promise]].
+            return Ok(promise_capability.promise().clone().into());
+        };
+
+        // 12. Return AsyncFromSyncIteratorContinuation(result, promiseCapability).
+        Self::continuation(&result, &promise_capability, context)
+    }
+
+    /// `%AsyncFromSyncIteratorPrototype%.throw ( [ value ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%asyncfromsynciteratorprototype%.throw
+    fn throw(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1-3.
+        let (sync_iterator_record, promise_capability) =
+            Self::this_sync_iterator_record(this, context)?;
+
+        // 4. Let syncIterator be O.[[SyncIteratorRecord]].[[Iterator]].
+        let sync_iterator = sync_iterator_record.iterator().clone();
+
+        // 5. Let throw be Completion(GetMethod(syncIterator, "throw")).
+        let throw = sync_iterator.get_method("throw", context);
+
+        // 6. IfAbruptRejectPromise(throw, promiseCapability).
+        if_abrupt_reject_promise!(throw, promise_capability, context);
+
+        let result = match throw {
+            // 7. If throw is undefined, then
+            None => {
+                // a. Perform ! Call(promiseCapability.[[Reject]], undefined, « value »).
+                promise_capability
+                    .reject()
+                    .call(
+                        &JsValue::undefined(),
+                        &[args.get_or_undefined(0).clone()],
+                        context,
+                    )
+                    .expect("cannot fail per spec");
+
+                // b. Return promiseCapability.[[Promise]].
+                return Ok(promise_capability.promise().clone().into());
+            }
+            // 8. If value is present, then
+            //     a. Let result be Completion(Call(throw, syncIterator, « value »)).
+            // 9. Else,
+            //     a. Let result be Completion(Call(throw, syncIterator)).
+            Some(throw) => throw.call(
+                &sync_iterator.into(),
+                args.get(0..1).unwrap_or(&[]),
+                context,
+            ),
+        };
+
+        // 10. IfAbruptRejectPromise(result, promiseCapability).
+        if_abrupt_reject_promise!(result, promise_capability, context);
+
+        // 11. If Type(result) is not Object, then
+        let result = if let Some(result) = result.as_object() {
+            IteratorResult::new(result.clone())
+        } else {
+            // a. Perform ! Call(promiseCapability.[[Reject]], undefined, « a newly created TypeError object »).
+            let error = context.construct_type_error("iterator result is not an object");
+            promise_capability
+                .reject()
+                .call(&JsValue::undefined(), &[error], context)
+                .expect("cannot fail per spec");
+
+            // b. Return promiseCapability.[[Promise]].
+            return Ok(promise_capability.promise().clone().into());
+        };
+
+        // 12. Return AsyncFromSyncIteratorContinuation(result, promiseCapability).
+        Self::continuation(&result, &promise_capability, context)
+    }
+
+    /// `AsyncFromSyncIteratorContinuation ( result, promiseCapability )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-asyncfromsynciteratorcontinuation
+    fn continuation(
+        result: &IteratorResult,
+        promise_capability: &PromiseCapability,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let done be Completion(IteratorComplete(result)).
+        let done = result.complete(context);
+
+        // 2. IfAbruptRejectPromise(done, promiseCapability).
+        if_abrupt_reject_promise!(done, promise_capability, context);
+
+        // 3. Let value be Completion(IteratorValue(result)).
+        let value = result.value(context);
+
+        // 4. IfAbruptRejectPromise(value, promiseCapability).
+        if_abrupt_reject_promise!(value, promise_capability, context);
+
+        // 5. Let valueWrapper be Completion(PromiseResolve(%Promise%, value)).
+        let value_wrapper = Promise::promise_resolve(
+            context.intrinsics().constructors().promise().constructor(),
+            value,
+            context,
+        );
+
+        // 6. IfAbruptRejectPromise(valueWrapper, promiseCapability).
+        if_abrupt_reject_promise!(value_wrapper, promise_capability, context);
+
+        // 7. Let unwrap be a new Abstract Closure with parameters (value) that captures done and performs the following steps when called:
+        //     a. Return CreateIterResultObject(value, done).
+        // 8. Let onFulfilled be CreateBuiltinFunction(unwrap, 1, "", « »).
+        let on_fulfilled = FunctionBuilder::closure_with_captures(
+            context,
+            |_this, args, done, context| {
+                create_iter_result_object_or_throw(args.get_or_undefined(0).clone(), *done, context)
+            },
+            done,
+        )
+        .name("")
+        .length(1)
+        .build();
+
+        // 9. NOTE: onFulfilled is used when processing the "value" property of an IteratorResult object in order to wait for its value if it is a promise and re-package the result in a new "unwrapped" IteratorResult object.
+        // 10. Perform PerformPromiseThen(valueWrapper, onFulfilled, undefined, promiseCapability).
+        Promise::perform_promise_then(
+            value_wrapper
+                .as_object()
+                .expect("PromiseResolve must return a promise"),
+            &on_fulfilled.into(),
+            &JsValue::undefined(),
+            Some(promise_capability.clone()),
+            context,
+        );
+
+        // 11. Return promiseCapability.[[Promise]].
+        Ok(promise_capability.promise().clone().into())
+    }
+}
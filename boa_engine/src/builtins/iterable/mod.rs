@@ -1,3 +1,9 @@
+mod async_from_sync_iterator;
+
+pub(crate) use async_from_sync_iterator::AsyncFromSyncIterator;
+
+#[cfg(feature = "intl")]
+use crate::builtins::intl::segmenter::SegmentIterator;
 use crate::{
     builtins::{
         regexp::regexp_string_iterator::RegExpStringIterator,
@@ -5,9 +11,11 @@ use crate::{
         SetIterator,
     },
     object::{JsObject, ObjectInitializer},
+    property::PropertyDescriptor,
     symbol::WellKnownSymbols,
-    Context, JsResult, JsValue,
+    Context, JsNativeError, JsResult, JsValue,
 };
+use boa_gc::{Finalize, Trace};
 use boa_profiler::Profiler;
 
 #[derive(Debug, Default)]
@@ -16,6 +24,8 @@ pub struct IteratorPrototypes {
     iterator_prototype: JsObject,
     /// %AsyncIteratorPrototype%
     async_iterator_prototype: JsObject,
+    /// `%AsyncFromSyncIteratorPrototype%`
+    async_from_sync_iterator: JsObject,
     /// %MapIteratorPrototype%
     array_iterator: JsObject,
     /// %SetIteratorPrototype%
@@ -28,6 +38,9 @@ pub struct IteratorPrototypes {
     map_iterator: JsObject,
     /// %ForInIteratorPrototype%
     for_in_iterator: JsObject,
+    /// `%SegmentIteratorPrototype%`
+    #[cfg(feature = "intl")]
+    segment_iterator: JsObject,
 }
 
 impl IteratorPrototypes {
@@ -46,6 +59,15 @@ impl IteratorPrototypes {
             ),
             map_iterator: MapIterator::create_prototype(iterator_prototype.clone(), context),
             for_in_iterator: ForInIterator::create_prototype(iterator_prototype.clone(), context),
+            #[cfg(feature = "intl")]
+            segment_iterator: SegmentIterator::create_prototype(
+                iterator_prototype.clone(),
+                context,
+            ),
+            async_from_sync_iterator: AsyncFromSyncIterator::create_prototype(
+                async_iterator_prototype.clone(),
+                context,
+            ),
             iterator_prototype,
             async_iterator_prototype,
         }
@@ -66,6 +88,11 @@ impl IteratorPrototypes {
         self.async_iterator_prototype.clone()
     }
 
+    #[inline]
+    pub fn async_from_sync_iterator(&self) -> JsObject {
+        self.async_from_sync_iterator.clone()
+    }
+
     #[inline]
     pub fn set_iterator(&self) -> JsObject {
         self.set_iterator.clone()
@@ -90,27 +117,66 @@ impl IteratorPrototypes {
     pub fn for_in_iterator(&self) -> JsObject {
         self.for_in_iterator.clone()
     }
+
+    #[inline]
+    #[cfg(feature = "intl")]
+    pub fn segment_iterator(&self) -> JsObject {
+        self.segment_iterator.clone()
+    }
 }
 
 /// `CreateIterResultObject( value, done )`
 ///
 /// Generates an object supporting the `IteratorResult` interface.
+///
+/// The object is created without checking the maximum property count of the context, use
+/// [`create_iter_result_object_or_throw`] for iterator results created by the engine.
 #[inline]
 pub fn create_iter_result_object(value: JsValue, done: bool, context: &mut Context) -> JsValue {
     let _timer = Profiler::global().start_event("create_iter_result_object", "init");
 
+    let obj = context.construct_object();
+    obj.insert(
+        "value",
+        PropertyDescriptor::builder()
+            .value(value)
+            .writable(true)
+            .enumerable(true)
+            .configurable(true),
+    );
+    obj.insert(
+        "done",
+        PropertyDescriptor::builder()
+            .value(done)
+            .writable(true)
+            .enumerable(true)
+            .configurable(true),
+    );
+    obj.into()
+}
+
+/// `CreateIterResultObject( value, done )`
+///
+/// Like [`create_iter_result_object`], but throws a `RangeError` if the object would exceed the
+/// maximum property count of the context.
+#[inline]
+pub(crate) fn create_iter_result_object_or_throw(
+    value: JsValue,
+    done: bool,
+    context: &mut Context,
+) -> JsResult<JsValue> {
+    let _timer = Profiler::global().start_event("create_iter_result_object", "init");
+
     // 1. Assert: Type(done) is Boolean.
     // 2. Let obj be ! OrdinaryObjectCreate(%Object.prototype%).
     let obj = context.construct_object();
 
     // 3. Perform ! CreateDataPropertyOrThrow(obj, "value", value).
-    obj.create_data_property_or_throw("value", value, context)
-        .expect("this CreateDataPropertyOrThrow call must not fail");
+    obj.create_data_property_or_throw("value", value, context)?;
     // 4. Perform ! CreateDataPropertyOrThrow(obj, "done", done).
-    obj.create_data_property_or_throw("done", done, context)
-        .expect("this CreateDataPropertyOrThrow call must not fail");
+    obj.create_data_property_or_throw("done", done, context)?;
     // 5. Return obj.
-    obj.into()
+    Ok(obj.into())
 }
 
 /// Iterator hint for `GetIterator`.
@@ -155,10 +221,10 @@ impl JsValue {
                         .get_method(WellKnownSymbols::iterator(), context)?
                         .map_or(Self::Undefined, Self::from);
                     // 2. Let syncIteratorRecord be ? GetIterator(obj, sync, syncMethod).
-                    let _sync_iterator_record =
-                        self.get_iterator(context, Some(IteratorHint::Sync), Some(sync_method));
+                    let sync_iterator_record =
+                        self.get_iterator(context, Some(IteratorHint::Sync), Some(sync_method))?;
                     // 3. Return ! CreateAsyncFromSyncIterator(syncIteratorRecord).
-                    todo!("CreateAsyncFromSyncIterator");
+                    return Ok(AsyncFromSyncIterator::create(sync_iterator_record, context));
                 }
             } else {
                 // b. Otherwise, set method to ? GetMethod(obj, @@iterator).
@@ -173,7 +239,7 @@ impl JsValue {
         // 4. If Type(iterator) is not Object, throw a TypeError exception.
         let iterator_obj = iterator
             .as_object()
-            .ok_or_else(|| context.construct_type_error("the iterator is not an object"))?;
+            .ok_or_else(|| JsNativeError::typ().with_message("the iterator is not an object"))?;
 
         // 5. Let nextMethod be ? GetV(iterator, "next").
         let next_method = iterator.get_v("next", context)?;
@@ -216,6 +282,12 @@ pub struct IteratorResult {
 }
 
 impl IteratorResult {
+    /// Creates a new `IteratorResult` from an iterator result object.
+    #[inline]
+    pub(crate) fn new(object: JsObject) -> Self {
+        Self { object }
+    }
+
     /// `IteratorComplete ( iterResult )`
     ///
     /// The abstract operation `IteratorComplete` takes argument `iterResult` (an `Object`) and
@@ -257,7 +329,7 @@ impl IteratorResult {
 ///  - [ECMA reference][spec]
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-iterator-records
-#[derive(Debug)]
+#[derive(Debug, Clone, Trace, Finalize)]
 pub struct IteratorRecord {
     /// `[[Iterator]]`
     ///
@@ -15,15 +15,12 @@
 
 use super::JsArgs;
 use crate::{
-    builtins::{
-        string::{is_leading_surrogate, is_trailing_surrogate},
-        BuiltIn,
-    },
+    builtins::BuiltIn,
     object::{JsObject, ObjectInitializer, RecursionLimiter},
     property::{Attribute, PropertyNameKind},
     symbol::WellKnownSymbols,
     value::IntegerOrInfinity,
-    Context, JsResult, JsString, JsValue,
+    Context, JsNativeError, JsResult, JsString, JsValue,
 };
 use boa_profiler::Profiler;
 use serde_json::{self, Value as JSONValue};
@@ -78,8 +75,9 @@ impl Json {
 
         // 2. Parse ! StringToCodePoints(jsonString) as a JSON text as specified in ECMA-404.
         //    Throw a SyntaxError exception if it is not a valid JSON text as defined in that specification.
-        if let Err(e) = serde_json::from_str::<JSONValue>(&json_string) {
-            return context.throw_syntax_error(e.to_string());
+        match serde_json::from_str::<JSONValue>(&json_string) {
+            Ok(json) => Self::check_resource_limits(&json, context)?,
+            Err(e) => return Err(JsNativeError::syntax().with_message(e.to_string()).into()),
         }
 
         // 3. Let scriptString be the string-concatenation of "(", jsonString, and ");".
@@ -101,11 +99,10 @@ impl Json {
 
             // b. Let rootName be the empty String.
             // c. Perform ! CreateDataPropertyOrThrow(root, rootName, unfiltered).
-            root.create_data_property_or_throw("", unfiltered, context)
-                .expect("CreateDataPropertyOrThrow should never throw here");
+            root.create_data_property_or_throw("", unfiltered, context)?;
 
             // d. Return ? InternalizeJSONProperty(root, rootName, reviver).
-            Self::internalize_json_property(&root, "".into(), obj, context)
+            Self::internalize_json_property(&root, "".into(), obj, 1, context)
         } else {
             // 12. Else,
             // a. Return unfiltered.
@@ -113,6 +110,56 @@ impl Json {
         }
     }
 
+    /// Checks that the parsed JSON text doesn't exceed the [`ResourceLimits`] of the context.
+    ///
+    /// [`ResourceLimits`]: crate::context::ResourceLimits
+    fn check_resource_limits(json: &JSONValue, context: &mut Context) -> JsResult<()> {
+        let limits = *context.resource_limits();
+        let mut pending = vec![(json, 1)];
+        while let Some((value, depth)) = pending.pop() {
+            match value {
+                JSONValue::String(string) => {
+                    if string.encode_utf16().count() > limits.max_string_length() {
+                        return context.throw_range_error(format!(
+                            "JSON string length exceeds the maximum string length ({})",
+                            limits.max_string_length()
+                        ));
+                    }
+                }
+                JSONValue::Array(array) => {
+                    limits.check_object_nesting(depth)?;
+                    if array.len() as u64 > limits.max_array_length() {
+                        return context.throw_range_error(format!(
+                            "JSON array length exceeds the maximum array length ({})",
+                            limits.max_array_length()
+                        ));
+                    }
+                    pending.extend(array.iter().map(|value| (value, depth + 1)));
+                }
+                JSONValue::Object(object) => {
+                    limits.check_object_nesting(depth)?;
+                    if object.len() > limits.max_property_count() {
+                        return context.throw_range_error(format!(
+                            "JSON object exceeds the maximum property count ({})",
+                            limits.max_property_count()
+                        ));
+                    }
+                    for (key, value) in object {
+                        if key.encode_utf16().count() > limits.max_string_length() {
+                            return context.throw_range_error(format!(
+                                "JSON string length exceeds the maximum string length ({})",
+                                limits.max_string_length()
+                            ));
+                        }
+                        pending.push((value, depth + 1));
+                    }
+                }
+                JSONValue::Null | JSONValue::Bool(_) | JSONValue::Number(_) => {}
+            }
+        }
+        Ok(())
+    }
+
     /// `25.5.1.1 InternalizeJSONProperty ( holder, name, reviver )`
     ///
     /// More information:
@@ -123,6 +170,7 @@ impl Json {
         holder: &JsObject,
         name: JsString,
         reviver: &JsObject,
+        depth: usize,
         context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let val be ? Get(holder, name).
@@ -130,9 +178,11 @@ impl Json {
 
         // 2. If Type(val) is Object, then
         if let Some(obj) = val.as_object() {
+            context.resource_limits().check_object_nesting(depth)?;
+
             // a. Let isArray be ? IsArray(val).
             // b. If isArray is true, then
-            if obj.is_array_abstract(context)? {
+            if obj.is_array_abstract()? {
                 // i. Let I be 0.
                 // ii. Let len be ? LengthOfArrayLike(val).
                 // iii. Repeat, while I < len,
@@ -144,6 +194,7 @@ impl Json {
                         obj,
                         i.to_string().into(),
                         reviver,
+                        depth + 1,
                         context,
                     )?;
 
@@ -172,8 +223,13 @@ impl Json {
                         .expect("EnumerableOwnPropertyNames only returns strings");
 
                     // 1. Let newElement be ? InternalizeJSONProperty(val, P, reviver).
-                    let new_element =
-                        Self::internalize_json_property(obj, p.clone(), reviver, context)?;
+                    let new_element = Self::internalize_json_property(
+                        obj,
+                        p.clone(),
+                        reviver,
+                        depth + 1,
+                        context,
+                    )?;
 
                     // 2. If newElement is undefined, then
                     if new_element.is_undefined() {
@@ -236,7 +292,7 @@ impl Json {
             } else {
                 // i. Let isArray be ? IsArray(replacer).
                 // ii. If isArray is true, then
-                if replacer_obj.is_array_abstract(context)? {
+                if replacer_obj.is_array_abstract()? {
                     // 1. Set PropertyList to a new empty List.
                     let mut property_set = indexmap::IndexSet::new();
 
@@ -320,7 +376,7 @@ impl Json {
         // 7. Else if Type(space) is String, then
         } else if let Some(s) = space.as_string() {
             // a. If the length of space is 10 or less, let gap be space; otherwise let gap be the substring of space from 0 to 10.
-            String::from_utf16_lossy(&s.encode_utf16().take(10).collect::<Vec<u16>>()).into()
+            JsString::from_utf16(&s.encode_utf16().take(10).collect::<Vec<u16>>())
         // 8. Else,
         } else {
             // a. Let gap be the empty String.
@@ -331,9 +387,7 @@ impl Json {
         let wrapper = context.construct_object();
 
         // 10. Perform ! CreateDataPropertyOrThrow(wrapper, the empty String, value).
-        wrapper
-            .create_data_property_or_throw("", args.get_or_undefined(0).clone(), context)
-            .expect("CreateDataPropertyOrThrow should never fail here");
+        wrapper.create_data_property_or_throw("", args.get_or_undefined(0).clone(), context)?;
 
         // 11. Let state be the Record { [[ReplacerFunction]]: ReplacerFunction, [[Stack]]: stack, [[Indent]]: indent, [[Gap]]: gap, [[PropertyList]]: PropertyList }.
         let mut state = StateRecord {
@@ -345,11 +399,12 @@ impl Json {
         };
 
         // 12. Return ? SerializeJSONProperty(state, the empty String, wrapper).
-        Ok(
-            Self::serialize_json_property(&mut state, JsString::new(""), &wrapper, context)?
-                .map(Into::into)
-                .unwrap_or_default(),
-        )
+        let result =
+            Self::serialize_json_property(&mut state, JsString::new(""), &wrapper, context)?;
+        if let Some(result) = &result {
+            context.resource_limits().check_string_length(&[result])?;
+        }
+        Ok(result.map(Into::into).unwrap_or_default())
     }
 
     /// `25.5.2.1 SerializeJSONProperty ( state, key, holder )`
@@ -457,7 +512,7 @@ impl Json {
                 // a. Let isArray be ? IsArray(value).
                 // b. If isArray is true, return ? SerializeJSONArray(state, value).
                 // c. Return ? SerializeJSONObject(state, value).
-                return if obj.is_array_abstract(context)? {
+                return if obj.is_array_abstract()? {
                     Ok(Some(Self::serialize_json_array(state, obj, context)?))
                 } else {
                     Ok(Some(Self::serialize_json_object(state, obj, context)?))
@@ -480,32 +535,30 @@ impl Json {
         let mut product = String::from('"');
 
         // 2. For each code point C of ! StringToCodePoints(value), do
-        for code_point in value.encode_utf16() {
+        for code_point in char::decode_utf16(value.encode_utf16()) {
             match code_point {
                 // a. If C is listed in the “Code Point” column of Table 73, then
                 // i. Set product to the string-concatenation of product and the escape sequence for C as specified in the “Escape Sequence” column of the corresponding row.
-                0x8 => product.push_str("\\b"),
-                0x9 => product.push_str("\\t"),
-                0xA => product.push_str("\\n"),
-                0xC => product.push_str("\\f"),
-                0xD => product.push_str("\\r"),
-                0x22 => product.push_str("\\\""),
-                0x5C => product.push_str("\\\\"),
+                Ok('\u{8}') => product.push_str("\\b"),
+                Ok('\t') => product.push_str("\\t"),
+                Ok('\n') => product.push_str("\\n"),
+                Ok('\u{C}') => product.push_str("\\f"),
+                Ok('\r') => product.push_str("\\r"),
+                Ok('"') => product.push_str("\\\""),
+                Ok('\\') => product.push_str("\\\\"),
                 // b. Else if C has a numeric value less than 0x0020 (SPACE), or if C has the same numeric value as a leading surrogate or trailing surrogate, then
-                code_point
-                    if is_leading_surrogate(code_point) || is_trailing_surrogate(code_point) =>
-                {
+                Ok(code_point) if code_point < '\u{20}' => {
                     // i. Let unit be the code unit whose numeric value is that of C.
                     // ii. Set product to the string-concatenation of product and UnicodeEscape(unit).
-                    product.push_str(&format!("\\\\uAA{code_point:x}"));
+                    product.push_str(&format!("\\u{:04x}", u32::from(code_point)));
+                }
+                Err(surrogate) => {
+                    product.push_str(&format!("\\u{:04x}", surrogate.unpaired_surrogate()));
                 }
                 // c. Else,
-                code_point => {
+                Ok(code_point) => {
                     // i. Set product to the string-concatenation of product and ! UTF16EncodeCodePoint(C).
-                    product.push(
-                        char::from_u32(u32::from(code_point))
-                            .expect("char from code point cannot fail here"),
-                    );
+                    product.push(code_point);
                 }
             }
         }
@@ -536,6 +589,9 @@ impl Json {
 
         // 2. Append value to state.[[Stack]].
         state.stack.push(value.clone().into());
+        context
+            .resource_limits()
+            .check_object_nesting(state.stack.len())?;
 
         // 3. Let stepback be state.[[Indent]].
         let stepback = state.indent.clone();
@@ -622,6 +678,8 @@ impl Json {
             }
         };
 
+        context.resource_limits().check_string_length(&[&r#final])?;
+
         // 11. Remove the last element of state.[[Stack]].
         state.stack.pop();
 
@@ -651,6 +709,9 @@ impl Json {
 
         // 2. Append value to state.[[Stack]].
         state.stack.push(value.clone().into());
+        context
+            .resource_limits()
+            .check_object_nesting(state.stack.len())?;
 
         // 3. Let stepback be state.[[Indent]].
         let stepback = state.indent.clone();
@@ -719,6 +780,8 @@ impl Json {
             }
         };
 
+        context.resource_limits().check_string_length(&[&r#final])?;
+
         // 11. Remove the last element of state.[[Stack]].
         state.stack.pop();
 
@@ -466,3 +466,27 @@ fn json_parse_with_no_args_throws_syntax_error() {
     let result = forward(&mut context, "JSON.parse();");
     assert!(result.contains("SyntaxError"));
 }
+
+#[test]
+fn json_stringify_surrogates_and_control_characters() {
+    let mut context = Context::default();
+
+    assert_eq!(
+        forward(&mut context, r#"JSON.stringify("\uD800") === '"\\ud800"'"#),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            r#"JSON.stringify("\uDE00\uD83D") === '"\\ude00\\ud83d"'"#
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            r#"JSON.stringify("😀\u0001") === '"😀\\u0001"'"#
+        ),
+        "true"
+    );
+}
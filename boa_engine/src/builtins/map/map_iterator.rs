@@ -1,10 +1,12 @@
 use super::ordered_map::MapLock;
 use crate::{
-    builtins::{function::make_builtin_fn, iterable::create_iter_result_object, Array, JsValue},
+    builtins::{
+        function::make_builtin_fn, iterable::create_iter_result_object_or_throw, Array, JsValue,
+    },
     object::{JsObject, ObjectData},
     property::{PropertyDescriptor, PropertyNameKind},
     symbol::WellKnownSymbols,
-    Context, JsResult,
+    Context, JsNativeError, JsResult,
 };
 use boa_gc::{Finalize, Trace};
 use boa_profiler::Profiler;
@@ -76,7 +78,7 @@ impl MapIterator {
         let map_iterator = map_iterator
             .as_mut()
             .and_then(|obj| obj.as_map_iterator_mut())
-            .ok_or_else(|| context.construct_type_error("`this` is not a MapIterator"))?;
+            .ok_or_else(|| JsNativeError::typ().with_message("`this` is not a MapIterator"))?;
 
         let item_kind = map_iterator.map_iteration_kind;
 
@@ -97,11 +99,15 @@ impl MapIterator {
             };
             if let Some((key, value)) = e {
                 let item = match item_kind {
-                    PropertyNameKind::Key => Ok(create_iter_result_object(key, false, context)),
-                    PropertyNameKind::Value => Ok(create_iter_result_object(value, false, context)),
+                    PropertyNameKind::Key => {
+                        create_iter_result_object_or_throw(key, false, context)
+                    }
+                    PropertyNameKind::Value => {
+                        create_iter_result_object_or_throw(value, false, context)
+                    }
                     PropertyNameKind::KeyAndValue => {
-                        let result = Array::create_array_from_list([key, value], context);
-                        Ok(create_iter_result_object(result.into(), false, context))
+                        let result = Array::create_array_from_list([key, value], context)?;
+                        create_iter_result_object_or_throw(result.into(), false, context)
                     }
                 };
                 map_iterator.iterated_map = Some(obj);
@@ -109,11 +115,7 @@ impl MapIterator {
             }
         }
 
-        Ok(create_iter_result_object(
-            JsValue::undefined(),
-            true,
-            context,
-        ))
+        create_iter_result_object_or_throw(JsValue::undefined(), true, context)
     }
 
     /// Create the `%MapIteratorPrototype%` object
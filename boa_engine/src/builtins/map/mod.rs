@@ -21,7 +21,7 @@ use crate::{
     },
     property::{Attribute, PropertyNameKind},
     symbol::WellKnownSymbols,
-    Context, JsResult, JsValue,
+    Context, JsNativeError, JsResult, JsValue,
 };
 use boa_profiler::Profiler;
 use num_traits::Zero;
@@ -445,12 +445,12 @@ impl Map {
         let map = this
             .as_object()
             .filter(|obj| obj.is_map())
-            .ok_or_else(|| context.construct_type_error("`this` is not a Map"))?;
+            .ok_or_else(|| JsNativeError::typ().with_message("`this` is not a Map"))?;
 
         // 3. If IsCallable(callbackfn) is false, throw a TypeError exception.
         let callback = args.get_or_undefined(0);
         let callback = callback.as_callable().ok_or_else(|| {
-            context.construct_type_error(format!("{} is not a function", callback.display()))
+            JsNativeError::typ().with_message(format!("{} is not a function", callback.display()))
         })?;
 
         let this_arg = args.get_or_undefined(1);
@@ -533,7 +533,7 @@ pub(crate) fn add_entries_from_iterable(
 ) -> JsResult<JsValue> {
     // 1. If IsCallable(adder) is false, throw a TypeError exception.
     let adder = adder.as_callable().ok_or_else(|| {
-        context.construct_type_error("property `set` of `NewTarget` is not callable")
+        JsNativeError::typ().with_message("property `set` of `NewTarget` is not callable")
     })?;
 
     // 2. Let iteratorRecord be ? GetIterator(iterable).
@@ -100,9 +100,8 @@ impl Math {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/abs
     pub(crate) fn abs(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
         Ok(args
-            .get_or_undefined(0)
             // 1. Let n be ? ToNumber(x).
-            .to_number(context)?
+            .to_number(0, context)?
             // 3. If n is -0𝔽, return +0𝔽.
             // 2. If n is NaN, return NaN.
             // 4. If n is -∞𝔽, return +∞𝔽.
@@ -122,9 +121,8 @@ impl Math {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/acos
     pub(crate) fn acos(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
         Ok(args
-            .get_or_undefined(0)
             // 1. Let n be ? ToNumber(x).
-            .to_number(context)?
+            .to_number(0, context)?
             // 2. If n is NaN, n > 1𝔽, or n < -1𝔽, return NaN.
             // 3. If n is 1𝔽, return +0𝔽.
             // 4. Return an implementation-approximated value representing the result of the inverse cosine of ℝ(n).
@@ -142,9 +140,8 @@ impl Math {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/acosh
     pub(crate) fn acosh(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
         Ok(args
-            .get_or_undefined(0)
             // 1. Let n be ? ToNumber(x).
-            .to_number(context)?
+            .to_number(0, context)?
             // 4. If n < 1𝔽, return NaN.
             // 2. If n is NaN or n is +∞𝔽, return n.
             // 3. If n is 1𝔽, return +0𝔽.
@@ -163,9 +160,8 @@ impl Math {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/asin
     pub(crate) fn asin(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
         Ok(args
-            .get_or_undefined(0)
             // 1. Let n be ? ToNumber(x).
-            .to_number(context)?
+            .to_number(0, context)?
             // 2. If n is NaN, n is +0𝔽, or n is -0𝔽, return n.
             // 3. If n > 1𝔽 or n < -1𝔽, return NaN.
             // 4. Return an implementation-approximated value representing the result of the inverse sine of ℝ(n).
@@ -183,9 +179,8 @@ impl Math {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/asinh
     pub(crate) fn asinh(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
         Ok(args
-            .get_or_undefined(0)
             // 1. Let n be ? ToNumber(x).
-            .to_number(context)?
+            .to_number(0, context)?
             // 2. If n is NaN, n is +0𝔽, n is -0𝔽, n is +∞𝔽, or n is -∞𝔽, return n.
             // 3. Return an implementation-approximated value representing the result of the inverse hyperbolic sine of ℝ(n).
             .asinh()
@@ -202,9 +197,8 @@ impl Math {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/atan
     pub(crate) fn atan(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
         Ok(args
-            .get_or_undefined(0)
             // 1. Let n be ? ToNumber(x).
-            .to_number(context)?
+            .to_number(0, context)?
             // 2. If n is NaN, n is +0𝔽, or n is -0𝔽, return n.
             // 3. If n is +∞𝔽, return an implementation-approximated value representing π / 2.
             // 4. If n is -∞𝔽, return an implementation-approximated value representing -π / 2.
@@ -223,9 +217,8 @@ impl Math {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/atanh
     pub(crate) fn atanh(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
         Ok(args
-            .get_or_undefined(0)
             // 1. Let n be ? ToNumber(x).
-            .to_number(context)?
+            .to_number(0, context)?
             // 2. If n is NaN, n is +0𝔽, or n is -0𝔽, return n.
             // 3. If n > 1𝔽 or n < -1𝔽, return NaN.
             // 4. If n is 1𝔽, return +∞𝔽.
@@ -245,10 +238,10 @@ impl Math {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/atan2
     pub(crate) fn atan2(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
         // 1. Let ny be ? ToNumber(y).
-        let y = args.get_or_undefined(0).to_number(context)?;
+        let y = args.to_number(0, context)?;
 
         // 2. Let nx be ? ToNumber(x).
-        let x = args.get_or_undefined(1).to_number(context)?;
+        let x = args.to_number(1, context)?;
 
         // 4. If ny is +∞𝔽, then
         // a. If nx is +∞𝔽, return an implementation-approximated value representing π / 4.
@@ -288,9 +281,8 @@ impl Math {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/cbrt
     pub(crate) fn cbrt(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
         Ok(args
-            .get_or_undefined(0)
             // 1. Let n be ? ToNumber(x).
-            .to_number(context)?
+            .to_number(0, context)?
             // 2. If n is NaN, n is +0𝔽, n is -0𝔽, n is +∞𝔽, or n is -∞𝔽, return n.
             // 3. Return an implementation-approximated value representing the result of the cube root of ℝ(n).
             .cbrt()
@@ -307,9 +299,8 @@ impl Math {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/ceil
     pub(crate) fn ceil(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
         Ok(args
-            .get_or_undefined(0)
             // 1. Let n be ? ToNumber(x).
-            .to_number(context)?
+            .to_number(0, context)?
             // 2. If n is NaN, n is +0𝔽, n is -0𝔽, n is +∞𝔽, or n is -∞𝔽, return n.
             // 3. If n < +0𝔽 and n > -1𝔽, return -0𝔽.
             // 4. If n is an integral Number, return n.
@@ -347,9 +338,8 @@ impl Math {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/cos
     pub(crate) fn cos(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
         Ok(args
-            .get_or_undefined(0)
             // 1. Let n be ? ToNumber(x).
-            .to_number(context)?
+            .to_number(0, context)?
             // 2. If n is NaN, n is +∞𝔽, or n is -∞𝔽, return NaN.
             // 3. If n is +0𝔽 or n is -0𝔽, return 1𝔽.
             // 4. Return an implementation-approximated value representing the result of the cosine of ℝ(n).
@@ -367,9 +357,8 @@ impl Math {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/cosh
     pub(crate) fn cosh(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
         Ok(args
-            .get_or_undefined(0)
             // 1. Let n be ? ToNumber(x).
-            .to_number(context)?
+            .to_number(0, context)?
             // 2. If n is NaN, return NaN.
             // 3. If n is +∞𝔽 or n is -∞𝔽, return +∞𝔽.
             // 4. If n is +0𝔽 or n is -0𝔽, return 1𝔽.
@@ -388,9 +377,8 @@ impl Math {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/exp
     pub(crate) fn exp(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
         Ok(args
-            .get_or_undefined(0)
             // 1. Let n be ? ToNumber(x).
-            .to_number(context)?
+            .to_number(0, context)?
             // 2. If n is NaN or n is +∞𝔽, return n.
             // 3. If n is +0𝔽 or n is -0𝔽, return 1𝔽.
             // 4. If n is -∞𝔽, return +0𝔽.
@@ -411,9 +399,8 @@ impl Math {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/expm1
     pub(crate) fn expm1(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
         Ok(args
-            .get_or_undefined(0)
             // 1. Let n be ? ToNumber(x).
-            .to_number(context)?
+            .to_number(0, context)?
             // 2. If n is NaN, n is +0𝔽, n is -0𝔽, or n is +∞𝔽, return n.
             // 3. If n is -∞𝔽, return -1𝔽.
             // 4. Return an implementation-approximated value representing the result of subtracting 1 from the exponential function of ℝ(n).
@@ -431,9 +418,8 @@ impl Math {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/floor
     pub(crate) fn floor(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
         Ok(args
-            .get_or_undefined(0)
             // 1. Let n be ? ToNumber(x).
-            .to_number(context)?
+            .to_number(0, context)?
             // 2. If n is NaN, n is +0𝔽, n is -0𝔽, n is +∞𝔽, or n is -∞𝔽, return n.
             // 3. If n < 1𝔽 and n > +0𝔽, return +0𝔽.
             // 4. If n is an integral Number, return n.
@@ -456,7 +442,7 @@ impl Math {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let n be ? ToNumber(x).
-        let x = args.get_or_undefined(0).to_number(context)?;
+        let x = args.to_number(0, context)?;
 
         // 2. If n is NaN, return NaN.
         // 3. If n is one of +0𝔽, -0𝔽, +∞𝔽, or -∞𝔽, return n.
@@ -526,9 +512,8 @@ impl Math {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/log
     pub(crate) fn log(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
         Ok(args
-            .get_or_undefined(0)
             // 1. Let n be ? ToNumber(x).
-            .to_number(context)?
+            .to_number(0, context)?
             // 2. If n is NaN or n is +∞𝔽, return n.
             // 3. If n is 1𝔽, return +0𝔽.
             // 4. If n is +0𝔽 or n is -0𝔽, return -∞𝔽.
@@ -548,9 +533,8 @@ impl Math {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/log1p
     pub(crate) fn log1p(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
         Ok(args
-            .get_or_undefined(0)
             // 1. Let n be ? ToNumber(x).
-            .to_number(context)?
+            .to_number(0, context)?
             // 2. If n is NaN, n is +0𝔽, n is -0𝔽, or n is +∞𝔽, return n.
             // 3. If n is -1𝔽, return -∞𝔽.
             // 4. If n < -1𝔽, return NaN.
@@ -569,9 +553,8 @@ impl Math {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/log10
     pub(crate) fn log10(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
         Ok(args
-            .get_or_undefined(0)
             // 1. Let n be ? ToNumber(x).
-            .to_number(context)?
+            .to_number(0, context)?
             // 2. If n is NaN or n is +∞𝔽, return n.
             // 3. If n is 1𝔽, return +0𝔽.
             // 4. If n is +0𝔽 or n is -0𝔽, return -∞𝔽.
@@ -591,9 +574,8 @@ impl Math {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/log2
     pub(crate) fn log2(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
         Ok(args
-            .get_or_undefined(0)
             // 1. Let n be ? ToNumber(x).
-            .to_number(context)?
+            .to_number(0, context)?
             // 2. If n is NaN or n is +∞𝔽, return n.
             // 3. If n is 1𝔽, return +0𝔽.
             // 4. If n is +0𝔽 or n is -0𝔽, return -∞𝔽.
@@ -690,10 +672,10 @@ impl Math {
     #[allow(clippy::float_cmp)]
     pub(crate) fn pow(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
         // 1. Set base to ? ToNumber(base).
-        let x = args.get_or_undefined(0).to_number(context)?;
+        let x = args.to_number(0, context)?;
 
         // 2. Set exponent to ? ToNumber(exponent).
-        let y = args.get_or_undefined(1).to_number(context)?;
+        let y = args.to_number(1, context)?;
 
         // 3. If |x| = 1 and the exponent is infinite, return NaN.
         if f64::abs(x) == 1f64 && y.is_infinite() {
@@ -713,9 +695,9 @@ impl Math {
     /// [spec]: https://tc39.es/ecma262/#sec-math.random
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/random
     #[allow(clippy::unnecessary_wraps)]
-    pub(crate) fn random(_: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+    pub(crate) fn random(_: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
         // NOTE: Each Math.random function created for distinct realms must produce a distinct sequence of values from successive calls.
-        Ok(rand::random::<f64>().into())
+        Ok(context.random().into())
     }
 
     /// Round a number to the nearest integer.
@@ -729,9 +711,8 @@ impl Math {
     #[allow(clippy::float_cmp)]
     pub(crate) fn round(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
         let num = args
-            .get_or_undefined(0)
             //1. Let n be ? ToNumber(x).
-            .to_number(context)?;
+            .to_number(0, context)?;
 
         //2. If n is NaN, +∞𝔽, -∞𝔽, or an integral Number, return n.
         //3. If n < 0.5𝔽 and n > +0𝔽, return +0𝔽.
@@ -755,7 +736,7 @@ impl Math {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/sign
     pub(crate) fn sign(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
         // 1. Let n be ? ToNumber(x).
-        let n = args.get_or_undefined(0).to_number(context)?;
+        let n = args.to_number(0, context)?;
 
         // 2. If n is NaN, n is +0𝔽, or n is -0𝔽, return n.
         if n == 0f64 {
@@ -776,9 +757,8 @@ impl Math {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/sin
     pub(crate) fn sin(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
         Ok(args
-            .get_or_undefined(0)
             // 1. Let n be ? ToNumber(x).
-            .to_number(context)?
+            .to_number(0, context)?
             // 2. If n is NaN, n is +0𝔽, or n is -0𝔽, return n.
             // 3. If n is +∞𝔽 or n is -∞𝔽, return NaN.
             // 4. Return an implementation-approximated value representing the result of the sine of ℝ(n).
@@ -796,9 +776,8 @@ impl Math {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/sinh
     pub(crate) fn sinh(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
         Ok(args
-            .get_or_undefined(0)
             // 1. Let n be ? ToNumber(x).
-            .to_number(context)?
+            .to_number(0, context)?
             // 2. If n is NaN, n is +0𝔽, n is -0𝔽, n is +∞𝔽, or n is -∞𝔽, return n.
             // 3. Return an implementation-approximated value representing the result of the hyperbolic sine of ℝ(n).
             .sinh()
@@ -815,9 +794,8 @@ impl Math {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/sqrt
     pub(crate) fn sqrt(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
         Ok(args
-            .get_or_undefined(0)
             // 1. Let n be ? ToNumber(x).
-            .to_number(context)?
+            .to_number(0, context)?
             // 2. If n is NaN, n is +0𝔽, n is -0𝔽, or n is +∞𝔽, return n.
             // 3. If n < +0𝔽, return NaN.
             // 4. Return an implementation-approximated value representing the result of the square root of ℝ(n).
@@ -835,9 +813,8 @@ impl Math {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/tan
     pub(crate) fn tan(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
         Ok(args
-            .get_or_undefined(0)
             // 1. Let n be ? ToNumber(x).
-            .to_number(context)?
+            .to_number(0, context)?
             // 2. If n is NaN, n is +0𝔽, or n is -0𝔽, return n.
             // 3. If n is +∞𝔽, or n is -∞𝔽, return NaN.
             // 4. Return an implementation-approximated value representing the result of the tangent of ℝ(n).
@@ -855,9 +832,8 @@ impl Math {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/tanh
     pub(crate) fn tanh(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
         Ok(args
-            .get_or_undefined(0)
             // 1. Let n be ? ToNumber(x).
-            .to_number(context)?
+            .to_number(0, context)?
             // 2. If n is NaN, n is +0𝔽, or n is -0𝔽, return n.
             // 3. If n is +∞𝔽, return 1𝔽.
             // 4. If n is -∞𝔽, return -1𝔽.
@@ -876,9 +852,8 @@ impl Math {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/trunc
     pub(crate) fn trunc(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
         Ok(args
-            .get_or_undefined(0)
             // 1. Let n be ? ToNumber(x).
-            .to_number(context)?
+            .to_number(0, context)?
             // 2. If n is NaN, n is +0𝔽, n is -0𝔽, n is +∞𝔽, or n is -∞𝔽, return n.
             // 3. If n < 1𝔽 and n > +0𝔽, return +0𝔽.
             // 4. If n < +0𝔽 and n > -1𝔽, return -0𝔽.
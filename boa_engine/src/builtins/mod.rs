@@ -23,6 +23,7 @@ pub mod math;
 pub mod nan;
 pub mod number;
 pub mod object;
+pub mod performance;
 pub mod promise;
 pub mod proxy;
 pub mod reflect;
@@ -62,6 +63,7 @@ pub(crate) use self::{
     number::Number,
     object::for_in_iterator::ForInIterator,
     object::Object as BuiltInObjectObject,
+    performance::Performance,
     promise::Promise,
     proxy::Proxy,
     reflect::Reflect,
@@ -83,8 +85,9 @@ use crate::{
         async_generator_function::AsyncGeneratorFunction, generator::Generator,
         generator_function::GeneratorFunction, typed_array::TypedArray,
     },
+    object::JsObject,
     property::{Attribute, PropertyDescriptor},
-    Context, JsValue,
+    Context, JsResult, JsValue,
 };
 
 /// Trait representing a global built-in object such as `Math`, `Object` or
@@ -120,7 +123,7 @@ pub(crate) trait BuiltIn {
 /// Utility function that checks if a type implements `BuiltIn` before
 /// initializing it as a global built-in.
 #[inline]
-fn init_builtin<B: BuiltIn>(context: &mut Context) {
+pub(crate) fn init_builtin<B: BuiltIn>(context: &mut Context) {
     if let Some(value) = B::init(context) {
         let property = PropertyDescriptor::builder()
             .value(value)
@@ -203,6 +206,23 @@ pub fn init(context: &mut Context) {
     init_builtin::<console::Console>(context);
 }
 
+/// Utility methods to extract the arguments of native functions.
+///
+/// The methods that convert an argument take the name of the native function, such as
+/// `"Array.prototype.map"`, to build the message of the `TypeError` they throw. The message is
+/// only created when the conversion fails.
+///
+/// # Examples
+///
+/// ```
+///# use boa_engine::prelude::*;
+///# use boa_engine::builtins::JsArgs;
+/// fn apply_twice(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+///     let function = args.to_callable(0, "applyTwice", context)?;
+///     let value = function.call(&JsValue::undefined(), &[args.get_or_undefined(1).clone()], context)?;
+///     function.call(&JsValue::undefined(), &[value], context)
+/// }
+/// ```
 pub trait JsArgs {
     /// Utility function to `get` a parameter from
     /// a `[JsValue]` or default to `JsValue::Undefined`
@@ -216,6 +236,27 @@ pub trait JsArgs {
     /// you only need to call methods of `JsValue`, so
     /// try to minimize calling `clone`.
     fn get_or_undefined(&self, index: usize) -> &JsValue;
+
+    /// Gets the argument at `index`, throwing a `TypeError` if it was not passed to the native
+    /// function `function`.
+    fn require(&self, index: usize, function: &str, context: &mut Context) -> JsResult<&JsValue>;
+
+    /// Converts the argument at `index` to a number with `ToNumber`, using `undefined` if it
+    /// was not passed.
+    fn to_number(&self, index: usize, context: &mut Context) -> JsResult<f64>;
+
+    /// Converts the argument at `index` to an object with `ToObject`, throwing a `TypeError`
+    /// if it is `undefined`, `null` or was not passed to the native function `function`.
+    fn to_object(&self, index: usize, function: &str, context: &mut Context) -> JsResult<JsObject>;
+
+    /// Gets the argument at `index` as a callable object, throwing a `TypeError` if it is not
+    /// callable or was not passed to the native function `function`.
+    fn to_callable(
+        &self,
+        index: usize,
+        function: &str,
+        context: &mut Context,
+    ) -> JsResult<&JsObject>;
 }
 
 impl JsArgs for [JsValue] {
@@ -223,4 +264,42 @@ impl JsArgs for [JsValue] {
         const UNDEFINED: &JsValue = &JsValue::Undefined;
         self.get(index).unwrap_or(UNDEFINED)
     }
+
+    fn require(&self, index: usize, function: &str, context: &mut Context) -> JsResult<&JsValue> {
+        match self.get(index) {
+            Some(value) => Ok(value),
+            None => {
+                context.throw_type_error(format!("{function}: argument {} is required", index + 1))
+            }
+        }
+    }
+
+    fn to_number(&self, index: usize, context: &mut Context) -> JsResult<f64> {
+        self.get_or_undefined(index).to_number(context)
+    }
+
+    fn to_object(&self, index: usize, function: &str, context: &mut Context) -> JsResult<JsObject> {
+        match self.get_or_undefined(index) {
+            JsValue::Undefined | JsValue::Null => context.throw_type_error(format!(
+                "{function}: argument {} cannot be converted to an object",
+                index + 1
+            )),
+            value => value.to_object(context),
+        }
+    }
+
+    fn to_callable(
+        &self,
+        index: usize,
+        function: &str,
+        context: &mut Context,
+    ) -> JsResult<&JsObject> {
+        match self.get_or_undefined(index).as_callable() {
+            Some(callable) => Ok(callable),
+            None => context.throw_type_error(format!(
+                "{function}: argument {} is not callable",
+                index + 1
+            )),
+        }
+    }
 }
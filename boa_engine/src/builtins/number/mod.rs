@@ -22,7 +22,7 @@ use crate::{
     },
     property::Attribute,
     value::{AbstractRelation, IntegerOrInfinity, JsValue},
-    Context, JsResult,
+    Context, JsNativeError, JsResult,
 };
 use boa_profiler::Profiler;
 use num_traits::{float::FloatCore, Num};
@@ -194,11 +194,15 @@ impl Number {
     ///  - [ECMAScript reference][spec]
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-thisnumbervalue
-    fn this_number_value(value: &JsValue, context: &mut Context) -> JsResult<f64> {
+    fn this_number_value(value: &JsValue) -> JsResult<f64> {
         value
             .as_number()
             .or_else(|| value.as_object().and_then(|obj| obj.borrow().as_number()))
-            .ok_or_else(|| context.construct_type_error("'this' is not a number"))
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("'this' is not a number")
+                    .into()
+            })
     }
 
     /// `Number.prototype.toExponential( [fractionDigits] )`
@@ -218,7 +222,7 @@ impl Number {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let x be ? thisNumberValue(this value).
-        let this_num = Self::this_number_value(this, context)?;
+        let this_num = Self::this_number_value(this)?;
         let precision = match args.get(0) {
             None | Some(JsValue::Undefined) => None,
             // 2. Let f be ? ToIntegerOrInfinity(fractionDigits).
@@ -262,7 +266,7 @@ impl Number {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let this_num be ? thisNumberValue(this value).
-        let this_num = Self::this_number_value(this, context)?;
+        let this_num = Self::this_number_value(this)?;
 
         // 2. Let f be ? ToIntegerOrInfinity(fractionDigits).
         // 3. Assert: If fractionDigits is undefined, then f is 0.
@@ -273,7 +277,8 @@ impl Number {
             .as_integer()
             .filter(|i| (0..=100).contains(i))
             .ok_or_else(|| {
-                context.construct_range_error("toFixed() digits argument must be between 0 and 100")
+                JsNativeError::range()
+                    .with_message("toFixed() digits argument must be between 0 and 100")
             })? as usize;
 
         // 6. If x is not finite, return ! Number::toString(x).
@@ -307,9 +312,9 @@ impl Number {
     pub(crate) fn to_locale_string(
         this: &JsValue,
         _: &[JsValue],
-        context: &mut Context,
+        _context: &mut Context,
     ) -> JsResult<JsValue> {
-        let this_num = Self::this_number_value(this, context)?;
+        let this_num = Self::this_number_value(this)?;
         let this_str_num = this_num.to_string();
         Ok(JsValue::new(this_str_num))
     }
@@ -422,7 +427,7 @@ impl Number {
         let precision = args.get_or_undefined(0);
 
         // 1 & 6
-        let mut this_num = Self::this_number_value(this, context)?;
+        let mut this_num = Self::this_number_value(this)?;
         // 2
         if precision.is_undefined() {
             return Self::to_string(this, &[], context);
@@ -600,7 +605,7 @@ impl Number {
                             let c: u8 = frac_buf[fraction_cursor];
                             // Reconstruct digit.
                             let digit_0 = (c as char)
-                                .to_digit(10)
+                                .to_digit(u32::from(radix))
                                 .expect("character was not a valid digit");
                             if digit_0 + 1 >= u32::from(radix) {
                                 continue;
@@ -672,7 +677,7 @@ impl Number {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let x be ? thisNumberValue(this value).
-        let x = Self::this_number_value(this, context)?;
+        let x = Self::this_number_value(this)?;
 
         let radix = args.get_or_undefined(0);
         let radix_number = if radix.is_undefined() {
@@ -686,9 +691,8 @@ impl Number {
                 // 4. If radixNumber < 2 or radixNumber > 36, throw a RangeError exception.
                 .filter(|i| (2..=36).contains(i))
                 .ok_or_else(|| {
-                    context.construct_range_error(
-                        "radix must be an integer at least 2 and no greater than 36",
-                    )
+                    JsNativeError::range()
+                        .with_message("radix must be an integer at least 2 and no greater than 36")
                 })?
         } as u8;
 
@@ -732,9 +736,9 @@ impl Number {
     pub(crate) fn value_of(
         this: &JsValue,
         _: &[JsValue],
-        context: &mut Context,
+        _context: &mut Context,
     ) -> JsResult<JsValue> {
-        Ok(JsValue::new(Self::this_number_value(this, context)?))
+        Ok(JsValue::new(Self::this_number_value(this)?))
     }
 
     /// Builtin javascript 'parseInt(str, radix)' function.
@@ -367,6 +367,51 @@ fn to_string() {
     );
 }
 
+#[test]
+fn to_string_radix() {
+    let mut context = Context::default();
+
+    assert_eq!("\"ff\"", &forward(&mut context, "(255).toString(16)"));
+    assert_eq!("\"0.1\"", &forward(&mut context, "(0.5).toString(2)"));
+    assert_eq!("\"-0.1\"", &forward(&mut context, "(-0.5).toString(2)"));
+    assert_eq!("\"0\"", &forward(&mut context, "(-0).toString(2)"));
+    assert_eq!("\"-ff.8\"", &forward(&mut context, "(-255.5).toString(16)"));
+    assert_eq!("\"z\"", &forward(&mut context, "(35).toString(36)"));
+    assert_eq!(
+        "\"0.0022002200220022002200220022002201\"",
+        &forward(&mut context, "(0.1).toString(3)")
+    );
+    assert_eq!(
+        "\"5v1j4f4ds7c000\"",
+        &forward(&mut context, "(1e21).toString(36)")
+    );
+
+    // Rounding the last fractional digit carries into a digit written as a letter.
+    assert_eq!(
+        "\"0.8a73b0ab147225b\"",
+        &forward(&mut context, "(0.7403512244280941).toString(12)")
+    );
+    assert_eq!(
+        "\"g0i.11798551g\"",
+        &forward(&mut context, "(7762.048218079553).toString(22)")
+    );
+    assert_eq!(
+        "\"-dach.84cfdiei\"",
+        &forward(&mut context, "(-163754.35642954556).toString(23)")
+    );
+
+    let expected =
+        "Uncaught \"RangeError\": \"radix must be an integer at least 2 and no greater than 36\"";
+    assert_eq!(forward(&mut context, "(1).toString(1)"), expected);
+    assert_eq!(forward(&mut context, "(1).toString(37)"), expected);
+    assert_eq!(forward(&mut context, "(1).toString(Infinity)"), expected);
+
+    assert_eq!(
+        forward(&mut context, "Number.prototype.toString.call(true)"),
+        "Uncaught \"TypeError\": \"'this' is not a number\""
+    );
+}
+
 #[test]
 fn num_to_string_exponential() {
     let mut context = Context::default();
@@ -884,3 +929,39 @@ fn number_is_safe_integer() {
         &forward(&mut context, "Number.isSafeInteger(new Number(5))")
     );
 }
+
+#[test]
+fn number_object_wrapper_identity() {
+    let mut context = Context::default();
+    let init = r#"
+        var n = new Number(5);
+        n.x = 1;
+        function attempt(f) {
+            try { return f(); } catch (e) { return e.constructor.name; }
+        }
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "typeof n"), "\"object\"");
+    assert_eq!(forward(&mut context, "typeof n.valueOf()"), "\"number\"");
+    assert_eq!(forward(&mut context, "n == 5"), "true");
+    assert_eq!(forward(&mut context, "n === 5"), "false");
+    assert_eq!(
+        forward(&mut context, "Object.getOwnPropertyNames(n).join()"),
+        "\"x\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "attempt(() => Number.prototype.valueOf.call('5'))"
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "attempt(() => Number.prototype.toString.call({}))"
+        ),
+        "\"TypeError\""
+    );
+}
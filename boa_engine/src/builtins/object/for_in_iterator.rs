@@ -1,10 +1,10 @@
 use crate::{
-    builtins::{function::make_builtin_fn, iterable::create_iter_result_object},
+    builtins::{function::make_builtin_fn, iterable::create_iter_result_object_or_throw},
     object::{JsObject, ObjectData},
     property::PropertyDescriptor,
     property::PropertyKey,
     symbol::WellKnownSymbols,
-    Context, JsResult, JsString, JsValue,
+    Context, JsNativeError, JsResult, JsString, JsValue,
 };
 use boa_gc::{Finalize, Trace};
 use boa_profiler::Profiler;
@@ -71,7 +71,7 @@ impl ForInIterator {
         let iterator = iterator
             .as_mut()
             .and_then(|obj| obj.as_for_in_iterator_mut())
-            .ok_or_else(|| context.construct_type_error("`this` is not a ForInIterator"))?;
+            .ok_or_else(|| JsNativeError::typ().with_message("`this` is not a ForInIterator"))?;
         let mut object = iterator.object.to_object(context)?;
         loop {
             if !iterator.object_was_visited {
@@ -96,11 +96,11 @@ impl ForInIterator {
                     {
                         iterator.visited_keys.insert(r.clone());
                         if desc.expect_enumerable() {
-                            return Ok(create_iter_result_object(
+                            return create_iter_result_object_or_throw(
                                 JsValue::new(r.to_string()),
                                 false,
                                 context,
-                            ));
+                            );
                         }
                     }
                 }
@@ -111,11 +111,7 @@ impl ForInIterator {
                     object = o;
                 }
                 _ => {
-                    return Ok(create_iter_result_object(
-                        JsValue::undefined(),
-                        true,
-                        context,
-                    ))
+                    return create_iter_result_object_or_throw(JsValue::undefined(), true, context)
                 }
             }
             iterator.object = JsValue::new(object.clone());
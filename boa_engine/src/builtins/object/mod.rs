@@ -435,7 +435,7 @@ impl Object {
         let desc = obj.__get_own_property__(&key, context)?;
 
         // 4. Return FromPropertyDescriptor(desc).
-        Ok(Self::from_property_descriptor(desc, context))
+        Self::from_property_descriptor(desc, context)
     }
 
     /// `Object.getOwnPropertyDescriptors( object )`
@@ -468,14 +468,12 @@ impl Object {
             let desc = obj.__get_own_property__(&key, context)?;
 
             // b. Let descriptor be FromPropertyDescriptor(desc).
-            let descriptor = Self::from_property_descriptor(desc, context);
+            let descriptor = Self::from_property_descriptor(desc, context)?;
 
             // c. If descriptor is not undefined,
             //    perform ! CreateDataPropertyOrThrow(descriptors, key, descriptor).
             if !descriptor.is_undefined() {
-                descriptors
-                    .create_data_property_or_throw(key, descriptor, context)
-                    .expect("should not fail according to spec");
+                descriptors.create_data_property_or_throw(key, descriptor, context)?;
             }
         }
 
@@ -491,10 +489,10 @@ impl Object {
     pub(crate) fn from_property_descriptor(
         desc: Option<PropertyDescriptor>,
         context: &mut Context,
-    ) -> JsValue {
+    ) -> JsResult<JsValue> {
         match desc {
             // 1. If Desc is undefined, return undefined.
-            None => JsValue::undefined(),
+            None => Ok(JsValue::undefined()),
             Some(desc) => {
                 // 2. Let obj be ! OrdinaryObjectCreate(%Object.prototype%).
                 // 3. Assert: obj is an extensible ordinary object with no own properties.
@@ -503,47 +501,41 @@ impl Object {
                 // 4. If Desc has a [[Value]] field, then
                 if let Some(value) = desc.value() {
                     // a. Perform ! CreateDataPropertyOrThrow(obj, "value", Desc.[[Value]]).
-                    obj.create_data_property_or_throw("value", value, context)
-                        .expect("CreateDataPropertyOrThrow cannot fail here");
+                    obj.create_data_property_or_throw("value", value, context)?;
                 }
 
                 // 5. If Desc has a [[Writable]] field, then
                 if let Some(writable) = desc.writable() {
                     // a. Perform ! CreateDataPropertyOrThrow(obj, "writable", Desc.[[Writable]]).
-                    obj.create_data_property_or_throw("writable", writable, context)
-                        .expect("CreateDataPropertyOrThrow cannot fail here");
+                    obj.create_data_property_or_throw("writable", writable, context)?;
                 }
 
                 // 6. If Desc has a [[Get]] field, then
                 if let Some(get) = desc.get() {
                     // a. Perform ! CreateDataPropertyOrThrow(obj, "get", Desc.[[Get]]).
-                    obj.create_data_property_or_throw("get", get, context)
-                        .expect("CreateDataPropertyOrThrow cannot fail here");
+                    obj.create_data_property_or_throw("get", get, context)?;
                 }
 
                 // 7. If Desc has a [[Set]] field, then
                 if let Some(set) = desc.set() {
                     // a. Perform ! CreateDataPropertyOrThrow(obj, "set", Desc.[[Set]]).
-                    obj.create_data_property_or_throw("set", set, context)
-                        .expect("CreateDataPropertyOrThrow cannot fail here");
+                    obj.create_data_property_or_throw("set", set, context)?;
                 }
 
                 // 8. If Desc has an [[Enumerable]] field, then
                 if let Some(enumerable) = desc.enumerable() {
                     // a. Perform ! CreateDataPropertyOrThrow(obj, "enumerable", Desc.[[Enumerable]]).
-                    obj.create_data_property_or_throw("enumerable", enumerable, context)
-                        .expect("CreateDataPropertyOrThrow cannot fail here");
+                    obj.create_data_property_or_throw("enumerable", enumerable, context)?;
                 }
 
                 // 9. If Desc has a [[Configurable]] field, then
                 if let Some(configurable) = desc.configurable() {
                     // a. Perform ! CreateDataPropertyOrThrow(obj, "configurable", Desc.[[Configurable]]).
-                    obj.create_data_property_or_throw("configurable", configurable, context)
-                        .expect("CreateDataPropertyOrThrow cannot fail here");
+                    obj.create_data_property_or_throw("configurable", configurable, context)?;
                 }
 
                 // 10. Return obj.
-                obj.into()
+                Ok(obj.into())
             }
         }
     }
@@ -747,7 +739,7 @@ impl Object {
 
         //  4. Let isArray be ? IsArray(O).
         //  5. If isArray is true, let builtinTag be "Array".
-        let builtin_tag = if JsValue::from(o.clone()).is_array(context)? {
+        let builtin_tag = if o.is_array_abstract()? {
             "Array"
         } else {
             // 6. Else if O has a [[ParameterMap]] internal slot, let builtinTag be "Arguments".
@@ -761,7 +753,6 @@ impl Object {
             // 14. Else, let builtinTag be "Object".
             let o = o.borrow();
             match o.kind() {
-                ObjectKind::Array => "Array",
                 ObjectKind::Arguments(_) => "Arguments",
                 ObjectKind::Function(_) => "Function",
                 ObjectKind::Error => "Error",
@@ -936,7 +927,7 @@ impl Object {
         let name_list = obj.enumerable_own_property_names(PropertyNameKind::Key, context)?;
 
         // 3. Return CreateArrayFromList(nameList).
-        let result = Array::create_array_from_list(name_list, context);
+        let result = Array::create_array_from_list(name_list, context)?;
 
         Ok(result.into())
     }
@@ -961,7 +952,7 @@ impl Object {
         let name_list = obj.enumerable_own_property_names(PropertyNameKind::Value, context)?;
 
         // 3. Return CreateArrayFromList(nameList).
-        let result = Array::create_array_from_list(name_list, context);
+        let result = Array::create_array_from_list(name_list, context)?;
 
         Ok(result.into())
     }
@@ -991,7 +982,7 @@ impl Object {
             obj.enumerable_own_property_names(PropertyNameKind::KeyAndValue, context)?;
 
         // 3. Return CreateArrayFromList(nameList).
-        let result = Array::create_array_from_list(name_list, context);
+        let result = Array::create_array_from_list(name_list, context)?;
 
         Ok(result.into())
     }
@@ -1326,5 +1317,5 @@ fn get_own_property_keys(
     });
 
     // 5. Return CreateArrayFromList(nameList).
-    Ok(Array::create_array_from_list(name_list, context).into())
+    Ok(Array::create_array_from_list(name_list, context)?.into())
 }
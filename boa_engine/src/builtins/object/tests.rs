@@ -420,3 +420,148 @@ fn object_from_entries() {
         TestAction::TestEq("map[5]", "4"),
     ]);
 }
+
+#[test]
+fn own_property_keys_order() {
+    let scenario = r#"
+        let obj = {};
+        obj.b = 1;
+        obj[2] = 2;
+        obj[Symbol.iterator] = 3;
+        obj.a = 4;
+        obj[1] = 5;
+        obj["-1"] = 6;
+        obj[Symbol.toStringTag] = 7;
+        obj[4294967295] = 8;
+        obj[4294967294] = 9;
+        obj["01"] = 10;
+    "#;
+
+    check_output(&[
+        TestAction::Execute(scenario),
+        TestAction::TestEq(
+            "Reflect.ownKeys(obj).map(String).join()",
+            r#""1,2,4294967294,b,a,-1,4294967295,01,Symbol(Symbol.iterator),Symbol(Symbol.toStringTag)""#,
+        ),
+        TestAction::TestEq(
+            "Object.getOwnPropertyNames(obj).join()",
+            r#""1,2,4294967294,b,a,-1,4294967295,01""#,
+        ),
+        TestAction::TestEq(
+            "Object.getOwnPropertySymbols(obj).map(String).join()",
+            r#""Symbol(Symbol.iterator),Symbol(Symbol.toStringTag)""#,
+        ),
+        TestAction::TestEq("obj[1]", "5"),
+        TestAction::TestEq("obj['+1']", "undefined"),
+        // JSON.stringify and Object.assign walk the keys in the same order.
+        TestAction::TestEq(
+            "JSON.stringify(obj)",
+            r#""{"1":5,"2":2,"4294967294":9,"b":1,"a":4,"-1":6,"4294967295":8,"01":10}""#,
+        ),
+        TestAction::TestEq(
+            r#"
+            let assigned = [];
+            Object.assign(new Proxy({}, {
+                set(target, key, value) {
+                    assigned.push(String(key));
+                    return true;
+                }
+            }), obj);
+            assigned.join()
+            "#,
+            r#""1,2,4294967294,b,a,-1,4294967295,01,Symbol(Symbol.iterator),Symbol(Symbol.toStringTag)""#,
+        ),
+    ]);
+}
+
+#[test]
+fn own_property_keys_of_exotic_objects() {
+    check_output(&[
+        TestAction::TestEq(
+            "Object.getOwnPropertyNames('abc').join()",
+            r#""0,1,2,length""#,
+        ),
+        TestAction::TestEq(
+            r#"
+            let str = new String("ab");
+            str.x = 1;
+            str[5] = 2;
+            Object.getOwnPropertyNames(str).join()
+            "#,
+            r#""0,1,5,length,x""#,
+        ),
+        TestAction::TestEq("Object.keys(new String('ab')).join()", r#""0,1""#),
+        TestAction::TestEq(
+            "Object.keys(new Uint8Array(3)).join()",
+            r#""0,1,2""#,
+        ),
+        TestAction::TestEq(
+            "let arr = [1, 2]; arr.x = 1; arr[5] = 1; Object.getOwnPropertyNames(arr).join()",
+            r#""0,1,5,length,x""#,
+        ),
+        TestAction::TestEq(
+            "(function () { arguments.x = 1; return Object.getOwnPropertyNames(arguments).join(); })(1, 2)",
+            r#""0,1,length,callee,x""#,
+        ),
+        TestAction::TestEq(
+            "Object.getOwnPropertyNames(function f(a) {}).join()",
+            r#""length,name,prototype""#,
+        ),
+        TestAction::TestEq(
+            "Object.getOwnPropertyNames(() => {}).join()",
+            r#""length,name""#,
+        ),
+    ]);
+}
+
+#[test]
+fn object_keys_values_entries_filter_enumerable() {
+    let scenario = r#"
+        let obj = { b: 1, 1: 2 };
+        Object.defineProperty(obj, "hidden", { value: 3, enumerable: false });
+        obj[Symbol()] = 4;
+        obj.a = 5;
+    "#;
+
+    check_output(&[
+        TestAction::Execute(scenario),
+        TestAction::TestEq("Object.keys(obj).join()", r#""1,b,a""#),
+        TestAction::TestEq("Object.values(obj).join()", r#""2,1,5""#),
+        TestAction::TestEq(
+            "JSON.stringify(Object.entries(obj))",
+            r#""[["1",2],["b",1],["a",5]]""#,
+        ),
+    ]);
+}
+
+#[test]
+fn proxy_own_keys_invariants() {
+    check_output(&[
+        TestAction::TestEq(
+            "Object.keys(new Proxy({ a: 1, b: 2 }, { ownKeys: () => ['b', 'a'] })).join()",
+            r#""b,a""#,
+        ),
+        TestAction::TestEq(
+            "try { Reflect.ownKeys(new Proxy({}, { ownKeys: () => ['a', 'a'] })) } catch (e) { e.name }",
+            r#""TypeError""#,
+        ),
+        TestAction::TestEq(
+            "try { Reflect.ownKeys(new Proxy({}, { ownKeys: () => [1] })) } catch (e) { e.name }",
+            r#""TypeError""#,
+        ),
+        TestAction::TestEq(
+            r#"
+            let sealed = Object.defineProperty({}, "x", { value: 1 });
+            try { Reflect.ownKeys(new Proxy(sealed, { ownKeys: () => [] })) } catch (e) { e.name }
+            "#,
+            r#""TypeError""#,
+        ),
+        TestAction::TestEq(
+            r#"
+            let fixed = Object.preventExtensions({ a: 1 });
+            try { Reflect.ownKeys(new Proxy(fixed, { ownKeys: () => ["a", "b"] })) } catch (e) { e.name }
+            "#,
+            r#""TypeError""#,
+        ),
+    ]);
+}
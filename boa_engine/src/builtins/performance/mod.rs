@@ -0,0 +1,100 @@
+//! This module implements the global `performance` object.
+//!
+//! The object is only added to the global object if the context is built with
+//! [`ContextBuilder::memory_measurement`][crate::context::ContextBuilder::memory_measurement],
+//! and only provides the `measureUserAgentSpecificMemory` method.
+//!
+//! More information:
+//!  - [Specification][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://wicg.github.io/performance-measure-memory/
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/Performance/measureUserAgentSpecificMemory
+
+use crate::{
+    builtins::{Array, BuiltIn, Promise},
+    object::ObjectInitializer,
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    Context, JsResult, JsValue,
+};
+use boa_profiler::Profiler;
+use tap::{Conv, Pipe};
+
+/// JavaScript `performance` object.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub(crate) struct Performance;
+
+impl BuiltIn for Performance {
+    const NAME: &'static str = "performance";
+
+    fn init(context: &mut Context) -> Option<JsValue> {
+        let _timer = Profiler::global().start_event(Self::NAME, "init");
+
+        let to_string_tag = WellKnownSymbols::to_string_tag();
+
+        ObjectInitializer::new(context)
+            .function(
+                Self::measure_user_agent_specific_memory,
+                "measureUserAgentSpecificMemory",
+                0,
+            )
+            .property(
+                to_string_tag,
+                "Performance",
+                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .build()
+            .conv::<JsValue>()
+            .pipe(Some)
+    }
+}
+
+impl Performance {
+    /// `performance.measureUserAgentSpecificMemory()`
+    ///
+    /// Returns a promise that resolves to the memory used by the engine, with a breakdown of the
+    /// memory used by each kind of allocation.
+    ///
+    /// More information:
+    ///  - [Specification][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://wicg.github.io/performance-measure-memory/#dom-performance-measureuseragentspecificmemory
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/Performance/measureUserAgentSpecificMemory
+    pub(crate) fn measure_user_agent_specific_memory(
+        _: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let usage = context.memory_usage();
+
+        let kinds = [
+            ("gc-heap", usage.gc_heap()),
+            ("interner", usage.interner()),
+            ("strings", usage.strings()),
+            ("code-blocks", usage.code_blocks()),
+            ("array-buffers", usage.array_buffers()),
+        ];
+        let mut breakdown = Vec::with_capacity(kinds.len());
+        for (kind, bytes) in kinds {
+            let attribution = Array::array_create(0, None, context)?;
+            let types = Array::create_array_from_list([kind.into()], context)?;
+            let entry = ObjectInitializer::new(context)
+                .property("bytes", bytes, Attribute::all())
+                .property("attribution", attribution, Attribute::all())
+                .property("types", types, Attribute::all())
+                .build();
+            breakdown.push(entry.into());
+        }
+        let breakdown = Array::create_array_from_list(breakdown, context)?;
+
+        let result = ObjectInitializer::new(context)
+            .property("bytes", usage.total(), Attribute::all())
+            .property("breakdown", breakdown, Attribute::all())
+            .build();
+
+        let promise = context.intrinsics().constructors().promise().constructor();
+        Promise::promise_resolve(promise, result.into(), context)
+    }
+}
@@ -9,7 +9,7 @@ use self::promise_job::PromiseJob;
 use super::{iterable::IteratorRecord, JsArgs};
 use crate::{
     builtins::{Array, BuiltIn},
-    context::intrinsics::StandardConstructors,
+    context::{intrinsics::StandardConstructors, OperationType},
     job::JobCallback,
     object::{
         internal_methods::get_prototype_from_constructor, ConstructorBuilder, FunctionBuilder,
@@ -18,7 +18,7 @@ use crate::{
     property::{Attribute, PropertyDescriptorBuilder},
     symbol::WellKnownSymbols,
     value::JsValue,
-    Context, JsResult,
+    Context, JsError, JsNativeError, JsResult,
 };
 use boa_gc::{Cell as GcCell, Finalize, Gc, Trace};
 use boa_profiler::Profiler;
@@ -37,7 +37,9 @@ macro_rules! if_abrupt_reject_promise {
     ($value:ident, $capability:expr, $context: expr) => {
         let $value = match $value {
             // 1. If value is an abrupt completion, then
-            Err(value) => {
+            Err(err) => {
+                let value: JsValue = err.to_opaque($context).into();
+
                 // a. Perform ? Call(capability.[[Reject]], undefined, « value.[[Value]] »).
                 $context.call(
                     &$capability.reject().clone().into(),
@@ -56,10 +58,16 @@ macro_rules! if_abrupt_reject_promise {
 
 pub(crate) use if_abrupt_reject_promise;
 
+/// The state of a promise.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum PromiseState {
+pub enum PromiseState {
+    /// The promise is neither fulfilled nor rejected yet.
     Pending,
+
+    /// The promise was fulfilled with a value.
     Fulfilled,
+
+    /// The promise was rejected with a reason.
     Rejected,
 }
 
@@ -173,8 +181,8 @@ impl PromiseCapability {
                     .cloned()
                     .and_then(JsFunction::from_object)
                     .ok_or_else(|| {
-                        context
-                            .construct_type_error("promiseCapability.[[Resolve]] is not callable")
+                        JsNativeError::typ()
+                            .with_message("promiseCapability.[[Resolve]] is not callable")
                     })?;
 
                 // 8. If IsCallable(promiseCapability.[[Reject]]) is false, throw a TypeError exception.
@@ -183,7 +191,8 @@ impl PromiseCapability {
                     .cloned()
                     .and_then(JsFunction::from_object)
                     .ok_or_else(|| {
-                        context.construct_type_error("promiseCapability.[[Reject]] is not callable")
+                        JsNativeError::typ()
+                            .with_message("promiseCapability.[[Reject]] is not callable")
                     })?;
 
                 // 9. Set promiseCapability.[[Promise]] to promise.
@@ -271,6 +280,20 @@ struct ResolvingFunctionsRecord {
 impl Promise {
     const LENGTH: usize = 1;
 
+    /// Gets the state of the promise.
+    #[inline]
+    pub fn state(&self) -> PromiseState {
+        self.promise_state
+    }
+
+    /// Gets the value the promise was fulfilled with, or the reason it was rejected with.
+    ///
+    /// This returns `None` while the promise is pending.
+    #[inline]
+    pub fn result(&self) -> Option<&JsValue> {
+        self.promise_result.as_ref()
+    }
+
     /// `Promise ( executor )`
     ///
     /// More information:
@@ -327,9 +350,10 @@ impl Promise {
         );
 
         // 10. If completion is an abrupt completion, then
-        if let Err(value) = completion {
+        if let Err(e) = completion {
+            let e = e.to_opaque(context);
             // a. Perform ? Call(resolvingFunctions.[[Reject]], undefined, « completion.[[Value]] »).
-            context.call(&resolving_functions.reject, &JsValue::Undefined, &[value])?;
+            context.call(&resolving_functions.reject, &JsValue::Undefined, &[e])?;
         }
 
         // 11. Return promise.
@@ -458,7 +482,7 @@ impl Promise {
                         let values_array = crate::builtins::Array::create_array_from_list(
                             values.borrow().iter().cloned(),
                             context,
-                        );
+                        )?;
 
                         // 2. Perform ? Call(resultCapability.[[Resolve]], undefined, « valuesArray »).
                         result_capability.resolve.call(
@@ -536,7 +560,7 @@ impl Promise {
                         let values_array = crate::builtins::Array::create_array_from_list(
                             captures.values.borrow().as_slice().iter().cloned(),
                             context,
-                        );
+                        )?;
 
                         // b. Return ? Call(promiseCapability.[[Resolve]], undefined, « valuesArray »).
                         return captures.capability_resolve.call(
@@ -699,7 +723,7 @@ impl Promise {
                         let values_array = crate::builtins::Array::create_array_from_list(
                             values.borrow().as_slice().iter().cloned(),
                             context,
-                        );
+                        )?;
 
                         // 2. Perform ? Call(resultCapability.[[Resolve]], undefined, « valuesArray »).
                         result_capability.resolve.call(
@@ -770,12 +794,10 @@ impl Promise {
                     let obj = context.construct_object();
 
                     // 10. Perform ! CreateDataPropertyOrThrow(obj, "status", "fulfilled").
-                    obj.create_data_property_or_throw("status", "fulfilled", context)
-                        .expect("cannot fail per spec");
+                    obj.create_data_property_or_throw("status", "fulfilled", context)?;
 
                     // 11. Perform ! CreateDataPropertyOrThrow(obj, "value", x).
-                    obj.create_data_property_or_throw("value", args.get_or_undefined(0), context)
-                        .expect("cannot fail per spec");
+                    obj.create_data_property_or_throw("value", args.get_or_undefined(0), context)?;
 
                     // 12. Set values[index] to obj.
                     captures.values.borrow_mut()[captures.index] = obj.into();
@@ -791,7 +813,7 @@ impl Promise {
                         let values_array = Array::create_array_from_list(
                             captures.values.borrow().as_slice().iter().cloned(),
                             context,
-                        );
+                        )?;
 
                         // b. Return ? Call(promiseCapability.[[Resolve]], undefined, « valuesArray »).
                         return captures.capability.call(
@@ -850,12 +872,10 @@ impl Promise {
                     let obj = context.construct_object();
 
                     // 10. Perform ! CreateDataPropertyOrThrow(obj, "status", "rejected").
-                    obj.create_data_property_or_throw("status", "rejected", context)
-                        .expect("cannot fail per spec");
+                    obj.create_data_property_or_throw("status", "rejected", context)?;
 
                     // 11. Perform ! CreateDataPropertyOrThrow(obj, "reason", x).
-                    obj.create_data_property_or_throw("reason", args.get_or_undefined(0), context)
-                        .expect("cannot fail per spec");
+                    obj.create_data_property_or_throw("reason", args.get_or_undefined(0), context)?;
 
                     // 12. Set values[index] to obj.
                     captures.values.borrow_mut()[captures.index] = obj.into();
@@ -871,7 +891,7 @@ impl Promise {
                         let values_array = Array::create_array_from_list(
                             captures.values.borrow().as_slice().iter().cloned(),
                             context,
-                        );
+                        )?;
 
                         // b. Return ? Call(promiseCapability.[[Resolve]], undefined, « valuesArray »).
                         return captures.capability.call(
@@ -1047,13 +1067,13 @@ impl Promise {
                                     .value(Array::create_array_from_list(
                                         errors.borrow().as_slice().iter().cloned(),
                                         context,
-                                    )),
+                                    )?),
                                 context,
                             )
                             .expect("cannot fail per spec");
 
                         // 3. Return ThrowCompletion(error).
-                        return Err(error.into());
+                        return Err(JsError::from_opaque(error.into()));
                     }
 
                     // iv. Return resultCapability.[[Promise]].
@@ -1142,7 +1162,7 @@ impl Promise {
                                     .value(Array::create_array_from_list(
                                         captures.errors.borrow().as_slice().iter().cloned(),
                                         context,
-                                    )),
+                                    )?),
                                 context,
                             )
                             .expect("cannot fail per spec");
@@ -1243,15 +1263,12 @@ impl Promise {
                 // 7. If SameValue(resolution, promise) is true, then
                 if JsValue::same_value(resolution, &promise.clone().into()) {
                     //   a. Let selfResolutionError be a newly created TypeError object.
-                    let self_resolution_error =
-                        context.construct_type_error("SameValue(resolution, promise) is true");
+                    let self_resolution_error = JsNativeError::typ()
+                        .with_message("SameValue(resolution, promise) is true")
+                        .to_opaque(context);
 
                     //   b. Perform RejectPromise(promise, selfResolutionError).
-                    promise
-                        .borrow_mut()
-                        .as_promise_mut()
-                        .expect("Expected promise to be a Promise")
-                        .reject_promise(&self_resolution_error, context);
+                    Promise::reject_promise(promise, &self_resolution_error.into(), context);
 
                     //   c. Return undefined.
                     return Ok(JsValue::Undefined);
@@ -1275,13 +1292,10 @@ impl Promise {
 
                 let then_action = match then {
                     // 10. If then is an abrupt completion, then
-                    Err(value) => {
+                    Err(e) => {
+                        let e = e.to_opaque(context);
                         //   a. Perform RejectPromise(promise, then.[[Value]]).
-                        promise
-                            .borrow_mut()
-                            .as_promise_mut()
-                            .expect("Expected promise to be a Promise")
-                            .reject_promise(&value, context);
+                        Promise::reject_promise(promise, &e, context);
 
                         //   b. Return undefined.
                         return Ok(JsValue::Undefined);
@@ -1307,7 +1321,8 @@ impl Promise {
                 };
 
                 // 13. Let thenJobCallback be HostMakeJobCallback(thenAction).
-                let then_job_callback = JobCallback::make_job_callback(then_action.clone());
+                let then_job_callback =
+                    JobCallback::make_job_callback(then_action.clone(), context);
 
                 // 14. Let job be NewPromiseResolveThenableJob(promise, resolution, thenJobCallback).
                 let job: JobCallback = PromiseJob::new_promise_resolve_thenable_job(
@@ -1364,11 +1379,7 @@ impl Promise {
 
                 // let reason = args.get_or_undefined(0);
                 // 7. Perform RejectPromise(promise, reason).
-                promise
-                    .borrow_mut()
-                    .as_promise_mut()
-                    .expect("Expected promise to be a Promise")
-                    .reject_promise(args.get_or_undefined(0), context);
+                Promise::reject_promise(promise, args.get_or_undefined(0), context);
 
                 // 8. Return undefined.
                 Ok(JsValue::Undefined)
@@ -1435,36 +1446,48 @@ impl Promise {
     ///  - [ECMAScript reference][spec]
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-rejectpromise
-    pub fn reject_promise(&mut self, reason: &JsValue, context: &mut Context) {
+    pub fn reject_promise(promise_obj: &JsObject, reason: &JsValue, context: &mut Context) {
+        let mut object = promise_obj.borrow_mut();
+        let promise = object
+            .as_promise_mut()
+            .expect("Expected promise to be a Promise");
+
         // 1. Assert: The value of promise.[[PromiseState]] is pending.
         assert_eq!(
-            self.promise_state,
+            promise.promise_state,
             PromiseState::Pending,
             "Expected promise.[[PromiseState]] to be pending"
         );
 
         // 2. Let reactions be promise.[[PromiseRejectReactions]].
-        let reactions = &self.promise_reject_reactions;
+        let reactions = &promise.promise_reject_reactions;
 
         // 8. Perform TriggerPromiseReactions(reactions, reason).
         Self::trigger_promise_reactions(reactions, reason, context);
         // reordering this statement does not affect the semantics
 
         // 3. Set promise.[[PromiseResult]] to reason.
-        self.promise_result = Some(reason.clone());
+        promise.promise_result = Some(reason.clone());
 
         // 4. Set promise.[[PromiseFulfillReactions]] to undefined.
-        self.promise_fulfill_reactions = Vec::new();
+        promise.promise_fulfill_reactions = Vec::new();
 
         // 5. Set promise.[[PromiseRejectReactions]] to undefined.
-        self.promise_reject_reactions = Vec::new();
+        promise.promise_reject_reactions = Vec::new();
 
         // 6. Set promise.[[PromiseState]] to rejected.
-        self.promise_state = PromiseState::Rejected;
+        promise.promise_state = PromiseState::Rejected;
 
         // 7. If promise.[[PromiseIsHandled]] is false, perform HostPromiseRejectionTracker(promise, "reject").
-        if !self.promise_is_handled {
-            // TODO
+        // The host hook is called once the promise is no longer borrowed, since it can access it.
+        let handled = promise.promise_is_handled;
+        drop(object);
+        if !handled {
+            context.host_hooks().promise_rejection_tracker(
+                promise_obj,
+                OperationType::Reject,
+                context,
+            );
         }
 
         // 9. Return unused.
@@ -1819,7 +1842,7 @@ impl Promise {
                         context,
                         |_this, _args, captures, _context| {
                             // 1. Return ThrowCompletion(reason).
-                            Err(captures.reason.clone())
+                            Err(JsError::from_opaque(captures.reason.clone()))
                         },
                         ThrowReasonCaptures {
                             reason: reason.clone(),
@@ -1881,12 +1904,14 @@ impl Promise {
         let on_rejected = args.get_or_undefined(1);
 
         // 5. Return PerformPromiseThen(promise, onFulfilled, onRejected, resultCapability).
-        promise_obj
-            .borrow_mut()
-            .as_promise_mut()
-            .expect("IsPromise(promise) is false")
-            .perform_promise_then(on_fulfilled, on_rejected, Some(result_capability), context)
-            .pipe(Ok)
+        Self::perform_promise_then(
+            promise_obj,
+            on_fulfilled,
+            on_rejected,
+            Some(result_capability),
+            context,
+        )
+        .pipe(Ok)
     }
 
     /// `PerformPromiseThen ( promise, onFulfilled, onRejected [ , resultCapability ] )`
@@ -1896,7 +1921,7 @@ impl Promise {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-performpromisethen
     pub(crate) fn perform_promise_then(
-        &mut self,
+        promise_obj: &JsObject,
         on_fulfilled: &JsValue,
         on_rejected: &JsValue,
         result_capability: Option<PromiseCapability>,
@@ -1910,9 +1935,9 @@ impl Promise {
         let on_fulfilled_job_callback = match on_fulfilled.as_object() {
             // 4. Else,
             //   a. Let onFulfilledJobCallback be HostMakeJobCallback(onFulfilled).
-            Some(on_fulfilled) if on_fulfilled.is_callable() => {
-                Some(JobCallback::make_job_callback(on_fulfilled.clone()))
-            }
+            Some(on_fulfilled) if on_fulfilled.is_callable() => Some(
+                JobCallback::make_job_callback(on_fulfilled.clone(), context),
+            ),
             // 3. If IsCallable(onFulfilled) is false, then
             //   a. Let onFulfilledJobCallback be empty.
             _ => None,
@@ -1922,7 +1947,7 @@ impl Promise {
             // 6. Else,
             //   a. Let onRejectedJobCallback be HostMakeJobCallback(onRejected).
             Some(on_rejected) if on_rejected.is_callable() => {
-                Some(JobCallback::make_job_callback(on_rejected.clone()))
+                Some(JobCallback::make_job_callback(on_rejected.clone(), context))
             }
             // 5. If IsCallable(onRejected) is false, then
             //   a. Let onRejectedJobCallback be empty.
@@ -1943,20 +1968,26 @@ impl Promise {
             handler: on_rejected_job_callback,
         };
 
-        match self.promise_state {
+        let mut object = promise_obj.borrow_mut();
+        let promise = object
+            .as_promise_mut()
+            .expect("IsPromise(promise) is false");
+
+        let mut handled = true;
+        match promise.promise_state {
             // 9. If promise.[[PromiseState]] is pending, then
             PromiseState::Pending => {
                 //   a. Append fulfillReaction as the last element of the List that is promise.[[PromiseFulfillReactions]].
-                self.promise_fulfill_reactions.push(fulfill_reaction);
+                promise.promise_fulfill_reactions.push(fulfill_reaction);
 
                 //   b. Append rejectReaction as the last element of the List that is promise.[[PromiseRejectReactions]].
-                self.promise_reject_reactions.push(reject_reaction);
+                promise.promise_reject_reactions.push(reject_reaction);
             }
 
             // 10. Else if promise.[[PromiseState]] is fulfilled, then
             PromiseState::Fulfilled => {
                 //   a. Let value be promise.[[PromiseResult]].
-                let value = self
+                let value = promise
                     .promise_result
                     .clone()
                     .expect("promise.[[PromiseResult]] cannot be empty");
@@ -1973,16 +2004,15 @@ impl Promise {
             //   a. Assert: The value of promise.[[PromiseState]] is rejected.
             PromiseState::Rejected => {
                 //   b. Let reason be promise.[[PromiseResult]].
-                let reason = self
+                let reason = promise
                     .promise_result
                     .clone()
                     .expect("promise.[[PromiseResult]] cannot be empty");
 
                 //   c. If promise.[[PromiseIsHandled]] is false, perform HostPromiseRejectionTracker(promise, "handle").
-                if !self.promise_is_handled {
-                    // HostPromiseRejectionTracker(promise, "handle")
-                    // TODO
-                }
+                //      The host hook is called once the promise is no longer borrowed, since it can
+                //      access it.
+                handled = promise.promise_is_handled;
 
                 //   d. Let rejectJob be NewPromiseReactionJob(rejectReaction, reason).
                 let reject_job =
@@ -1990,12 +2020,21 @@ impl Promise {
 
                 //   e. Perform HostEnqueuePromiseJob(rejectJob.[[Job]], rejectJob.[[Realm]]).
                 context.host_enqueue_promise_job(reject_job);
-
-                // 12. Set promise.[[PromiseIsHandled]] to true.
-                self.promise_is_handled = true;
             }
         }
 
+        // 12. Set promise.[[PromiseIsHandled]] to true.
+        promise.promise_is_handled = true;
+
+        drop(object);
+        if !handled {
+            context.host_hooks().promise_rejection_tracker(
+                promise_obj,
+                OperationType::Handle,
+                context,
+            );
+        }
+
         match result_capability {
             // 13. If resultCapability is undefined, then
             //   a. Return undefined.
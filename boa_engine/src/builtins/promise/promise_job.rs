@@ -3,7 +3,7 @@ use crate::{
     builtins::promise::{ReactionRecord, ReactionType},
     job::JobCallback,
     object::{FunctionBuilder, JsObject},
-    Context, JsValue,
+    Context, JsError, JsValue,
 };
 use boa_gc::{Finalize, Trace};
 
@@ -50,7 +50,7 @@ impl PromiseJob {
                         //   1. Assert: type is Reject.
                         ReactionType::Reject => {
                             // 2. Let handlerResult be ThrowCompletion(argument).
-                            Err(argument.clone())
+                            Err(JsError::from_opaque(argument.clone()))
                         }
                     },
                     //   e. Else, let handlerResult be Completion(HostCallJobCallback(handler, undefined, « argument »)).
@@ -81,9 +81,10 @@ impl PromiseJob {
 
                         match handler_result {
                             // h. If handlerResult is an abrupt completion, then
-                            Err(value) => {
+                            Err(e) => {
+                                let e = e.to_opaque(context);
                                 // i. Return ? Call(promiseCapability.[[Reject]], undefined, « handlerResult.[[Value]] »).
-                                context.call(&reject.clone().into(), &JsValue::Undefined, &[value])
+                                context.call(&reject.clone().into(), &JsValue::Undefined, &[e])
                             }
 
                             // i. Else,
@@ -107,7 +108,7 @@ impl PromiseJob {
         //   c. Else, set handlerRealm to the current Realm Record.
         //   d. NOTE: handlerRealm is never null unless the handler is undefined. When the handler is a revoked Proxy and no ECMAScript code runs, handlerRealm is used to create error objects.
         // 4. Return the Record { [[Job]]: job, [[Realm]]: handlerRealm }.
-        JobCallback::make_job_callback(job)
+        JobCallback::new(job, JsValue::undefined())
     }
 
     /// More information:
@@ -145,13 +146,10 @@ impl PromiseJob {
                 );
 
                 //    c. If thenCallResult is an abrupt completion, then
-                if let Err(value) = then_call_result {
+                if let Err(e) = then_call_result {
+                    let e = e.to_opaque(context);
                     //    i. Return ? Call(resolvingFunctions.[[Reject]], undefined, « thenCallResult.[[Value]] »).
-                    return context.call(
-                        &resolving_functions.reject,
-                        &JsValue::Undefined,
-                        &[value],
-                    );
+                    return context.call(&resolving_functions.reject, &JsValue::Undefined, &[e]);
                 }
 
                 //    d. Return ? thenCallResult.
@@ -166,7 +164,7 @@ impl PromiseJob {
         // 4. Else, let thenRealm be the current Realm Record.
         // 5. NOTE: thenRealm is never null. When then.[[Callback]] is a revoked Proxy and no code runs, thenRealm is used to create error objects.
         // 6. Return the Record { [[Job]]: job, [[Realm]]: thenRealm }.
-        JobCallback::make_job_callback(job.into())
+        JobCallback::new(job.into(), JsValue::undefined())
     }
 }
 
@@ -1,4 +1,5 @@
-use crate::{forward, Context};
+use super::PromiseState;
+use crate::{forward, Context, JsValue};
 
 #[test]
 fn promise() {
@@ -17,3 +18,29 @@ fn promise() {
     let after_completion = forward(&mut context, "count");
     assert_eq!(after_completion, String::from("3"));
 }
+
+#[test]
+fn promise_state() {
+    let mut context = Context::default();
+
+    let state = |value: JsValue| {
+        let object = value.as_object().unwrap().borrow();
+        let promise = object.as_promise().unwrap();
+        (promise.state(), promise.result().cloned())
+    };
+
+    let pending = context.eval("new Promise(() => {})").unwrap();
+    assert_eq!(state(pending), (PromiseState::Pending, None));
+
+    let fulfilled = context
+        .eval("Promise.resolve(1).then((x) => x + 1)")
+        .unwrap();
+    let (fulfilled_state, result) = state(fulfilled);
+    assert_eq!(fulfilled_state, PromiseState::Fulfilled);
+    assert_eq!(result.and_then(|x| x.as_number()), Some(2.0));
+
+    let rejected = context.eval("Promise.reject('reason')").unwrap();
+    let (rejected_state, reason) = state(rejected);
+    assert_eq!(rejected_state, PromiseState::Rejected);
+    assert_eq!(reason, Some(JsValue::from("reason")));
+}
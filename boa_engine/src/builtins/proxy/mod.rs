@@ -13,7 +13,7 @@
 use crate::{
     builtins::{BuiltIn, JsArgs},
     object::{ConstructorBuilder, FunctionBuilder, JsFunction, JsObject, ObjectData},
-    Context, JsResult, JsValue,
+    Context, JsNativeError, JsResult, JsValue,
 };
 use boa_gc::{Finalize, Trace};
 use boa_profiler::Profiler;
@@ -58,9 +58,11 @@ impl Proxy {
     /// This is an internal method only built for usage in the proxy internal methods.
     ///
     /// It returns the (target, handler) of the proxy.
-    pub(crate) fn try_data(&self, context: &mut Context) -> JsResult<(JsObject, JsObject)> {
+    pub(crate) fn try_data(&self) -> JsResult<(JsObject, JsObject)> {
         self.data.clone().ok_or_else(|| {
-            context.construct_type_error("Proxy object has empty handler and target")
+            JsNativeError::typ()
+                .with_message("Proxy object has empty handler and target")
+                .into()
         })
     }
 
@@ -97,12 +99,12 @@ impl Proxy {
     ) -> JsResult<JsObject> {
         // 1. If Type(target) is not Object, throw a TypeError exception.
         let target = target.as_object().ok_or_else(|| {
-            context.construct_type_error("Proxy constructor called with non-object target")
+            JsNativeError::typ().with_message("Proxy constructor called with non-object target")
         })?;
 
         // 2. If Type(handler) is not Object, throw a TypeError exception.
         let handler = handler.as_object().ok_or_else(|| {
-            context.construct_type_error("Proxy constructor called with non-object handler")
+            JsNativeError::typ().with_message("Proxy constructor called with non-object handler")
         })?;
 
         // 3. Let P be ! MakeBasicObject(« [[ProxyHandler]], [[ProxyTarget]] »).
@@ -171,14 +173,10 @@ impl Proxy {
         let result = context.construct_object();
 
         // 6. Perform ! CreateDataPropertyOrThrow(result, "proxy", p).
-        result
-            .create_data_property_or_throw("proxy", p, context)
-            .expect("CreateDataPropertyOrThrow cannot fail here");
+        result.create_data_property_or_throw("proxy", p, context)?;
 
         // 7. Perform ! CreateDataPropertyOrThrow(result, "revoke", revoker).
-        result
-            .create_data_property_or_throw("revoke", revoker, context)
-            .expect("CreateDataPropertyOrThrow cannot fail here");
+        result.create_data_property_or_throw("revoke", revoker, context)?;
 
         // 8. Return result.
         Ok(result.into())
@@ -16,7 +16,7 @@ use crate::{
     object::ObjectInitializer,
     property::Attribute,
     symbol::WellKnownSymbols,
-    Context, JsResult, JsValue,
+    Context, JsNativeError, JsResult, JsValue,
 };
 use boa_profiler::Profiler;
 use tap::{Conv, Pipe};
@@ -78,7 +78,7 @@ impl Reflect {
         let target = args
             .get(0)
             .and_then(JsValue::as_object)
-            .ok_or_else(|| context.construct_type_error("target must be a function"))?;
+            .ok_or_else(|| JsNativeError::typ().with_message("target must be a function"))?;
         let this_arg = args.get_or_undefined(1);
         let args_list = args.get_or_undefined(2);
 
@@ -106,7 +106,7 @@ impl Reflect {
         let target = args
             .get_or_undefined(0)
             .as_constructor()
-            .ok_or_else(|| context.construct_type_error("target must be a constructor"))?;
+            .ok_or_else(|| JsNativeError::typ().with_message("target must be a constructor"))?;
 
         let new_target = if let Some(new_target) = args.get(2) {
             // 3. Else if IsConstructor(newTarget) is false, throw a TypeError exception.
@@ -147,12 +147,14 @@ impl Reflect {
         let target = args
             .get(0)
             .and_then(JsValue::as_object)
-            .ok_or_else(|| context.construct_type_error("target must be an object"))?;
+            .ok_or_else(|| JsNativeError::typ().with_message("target must be an object"))?;
         let key = args.get_or_undefined(1).to_property_key(context)?;
         let prop_desc: JsValue = args
             .get(2)
             .and_then(|v| v.as_object().cloned())
-            .ok_or_else(|| context.construct_type_error("property descriptor must be an object"))?
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message("property descriptor must be an object")
+            })?
             .into();
 
         target
@@ -176,7 +178,7 @@ impl Reflect {
         let target = args
             .get(0)
             .and_then(JsValue::as_object)
-            .ok_or_else(|| context.construct_type_error("target must be an object"))?;
+            .ok_or_else(|| JsNativeError::typ().with_message("target must be an object"))?;
         let key = args.get_or_undefined(1).to_property_key(context)?;
 
         Ok(target.__delete__(&key, context)?.into())
@@ -195,7 +197,7 @@ impl Reflect {
         let target = args
             .get(0)
             .and_then(JsValue::as_object)
-            .ok_or_else(|| context.construct_type_error("target must be an object"))?;
+            .ok_or_else(|| JsNativeError::typ().with_message("target must be an object"))?;
         // 2. Let key be ? ToPropertyKey(propertyKey).
         let key = args.get_or_undefined(1).to_property_key(context)?;
         // 3. If receiver is not present, then
@@ -251,7 +253,7 @@ impl Reflect {
         let target = args
             .get(0)
             .and_then(JsValue::as_object)
-            .ok_or_else(|| context.construct_type_error("target must be an object"))?;
+            .ok_or_else(|| JsNativeError::typ().with_message("target must be an object"))?;
         Ok(target
             .__get_prototype_of__(context)?
             .map_or(JsValue::Null, JsValue::new))
@@ -269,7 +271,7 @@ impl Reflect {
         let target = args
             .get(0)
             .and_then(JsValue::as_object)
-            .ok_or_else(|| context.construct_type_error("target must be an object"))?;
+            .ok_or_else(|| JsNativeError::typ().with_message("target must be an object"))?;
         let key = args
             .get(1)
             .unwrap_or(&JsValue::undefined())
@@ -293,7 +295,7 @@ impl Reflect {
         let target = args
             .get(0)
             .and_then(JsValue::as_object)
-            .ok_or_else(|| context.construct_type_error("target must be an object"))?;
+            .ok_or_else(|| JsNativeError::typ().with_message("target must be an object"))?;
         Ok(target.__is_extensible__(context)?.into())
     }
 
@@ -313,7 +315,7 @@ impl Reflect {
         let target = args
             .get(0)
             .and_then(JsValue::as_object)
-            .ok_or_else(|| context.construct_type_error("target must be an object"))?;
+            .ok_or_else(|| JsNativeError::typ().with_message("target must be an object"))?;
 
         let keys: Vec<JsValue> = target
             .__own_property_keys__(context)?
@@ -321,7 +323,7 @@ impl Reflect {
             .map(Into::into)
             .collect();
 
-        Ok(Array::create_array_from_list(keys, context).into())
+        Ok(Array::create_array_from_list(keys, context)?.into())
     }
 
     /// Prevents new properties from ever being added to an object.
@@ -340,7 +342,7 @@ impl Reflect {
         let target = args
             .get(0)
             .and_then(JsValue::as_object)
-            .ok_or_else(|| context.construct_type_error("target must be an object"))?;
+            .ok_or_else(|| JsNativeError::typ().with_message("target must be an object"))?;
 
         Ok(target.__prevent_extensions__(context)?.into())
     }
@@ -357,7 +359,7 @@ impl Reflect {
         let target = args
             .get(0)
             .and_then(JsValue::as_object)
-            .ok_or_else(|| context.construct_type_error("target must be an object"))?;
+            .ok_or_else(|| JsNativeError::typ().with_message("target must be an object"))?;
         let key = args.get_or_undefined(1).to_property_key(context)?;
         let value = args.get_or_undefined(2);
         let receiver = if let Some(receiver) = args.get(3).cloned() {
@@ -386,7 +388,7 @@ impl Reflect {
         let target = args
             .get(0)
             .and_then(JsValue::as_object)
-            .ok_or_else(|| context.construct_type_error("target must be an object"))?;
+            .ok_or_else(|| JsNativeError::typ().with_message("target must be an object"))?;
         let proto = match args.get_or_undefined(1) {
             JsValue::Object(obj) => Some(obj.clone()),
             JsValue::Null => None,
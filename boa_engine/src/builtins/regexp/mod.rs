@@ -9,9 +9,13 @@
 //! [spec]: https://tc39.es/ecma262/#sec-regexp-constructor
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp
 
+mod named_groups;
 pub mod regexp_string_iterator;
 
-use self::regexp_string_iterator::RegExpStringIterator;
+use self::{
+    named_groups::{named_groups, NamedGroup},
+    regexp_string_iterator::RegExpStringIterator,
+};
 use super::JsArgs;
 use crate::{
     builtins::{array::Array, string, BuiltIn},
@@ -24,21 +28,36 @@ use crate::{
     symbol::WellKnownSymbols,
     syntax::lexer::regex::RegExpFlags,
     value::{IntegerOrInfinity, JsValue},
-    Context, JsResult, JsString,
+    Context, JsNativeError, JsResult, JsString,
 };
 use boa_profiler::Profiler;
-use regress::Regex;
-use std::str::FromStr;
+use regress::{Match, Regex};
+use std::{rc::Rc, str::FromStr};
 use tap::{Conv, Pipe};
 
 #[cfg(test)]
 mod tests;
 
+/// A compiled regular expression literal that can be sent to other threads.
+///
+/// This is used by [`CompiledScript`][crate::context::CompiledScript] to copy the regular
+/// expression literals of a script to other contexts without compiling them again.
+#[derive(Debug, Clone)]
+pub(crate) struct FrozenRegExp {
+    matcher: Regex,
+    named_groups: Vec<(String, Vec<usize>)>,
+    flags: RegExpFlags,
+    original_source: Vec<u16>,
+    original_flags: String,
+}
+
 /// The internal representation on a `RegExp` object.
 #[derive(Debug, Clone)]
 pub struct RegExp {
     /// Regex matcher.
-    matcher: Regex,
+    matcher: Rc<Regex>,
+    /// The capture group names of the pattern, in source order.
+    named_groups: Rc<[NamedGroup]>,
     flags: RegExpFlags,
     original_source: JsString,
     original_flags: JsString,
@@ -236,16 +255,16 @@ impl RegExp {
         let obj = JsObject::from_proto_and_data(proto, ObjectData::ordinary());
 
         // 2. Perform ! DefinePropertyOrThrow(obj, "lastIndex", PropertyDescriptor { [[Writable]]: true, [[Enumerable]]: false, [[Configurable]]: false }).
-        obj.define_property_or_throw(
+        // NOTE: The property is inserted directly, since the `lastIndex` of a new regular
+        // expression isn't counted against the maximum property count.
+        obj.insert(
             "lastIndex",
             PropertyDescriptorBuilder::new()
                 .writable(true)
                 .enumerable(false)
                 .configurable(false)
                 .build(),
-            context,
-        )
-        .expect("this cannot fail per spec");
+        );
 
         // 3. Return obj.
         Ok(obj)
@@ -281,13 +300,7 @@ impl RegExp {
 
         // 5. If F contains any code unit other than "g", "i", "m", "s", "u", or "y"
         //    or if it contains the same code unit more than once, throw a SyntaxError exception.
-        let flags = match RegExpFlags::from_str(&f) {
-            Err(msg) => return context.throw_syntax_error(msg),
-            Ok(result) => result,
-        };
-
         // TODO: Correct UTF-16 handling in 6. - 8.
-
         // 9. Let parseResult be ParsePattern(patternText, u).
         // 10. If parseResult is a non-empty List of SyntaxError objects, throw a SyntaxError exception.
         // 11. Assert: parseResult is a Pattern Parse Node.
@@ -295,18 +308,9 @@ impl RegExp {
         // 13. Set obj.[[OriginalFlags]] to F.
         // 14. NOTE: The definitions of DotAll, IgnoreCase, Multiline, and Unicode in 22.2.2.1 refer to this value of obj.[[OriginalFlags]].
         // 15. Set obj.[[RegExpMatcher]] to CompilePattern of parseResult.
-        let matcher = match Regex::with_flags(&p, f.as_ref()) {
-            Err(error) => {
-                return context
-                    .throw_syntax_error(format!("failed to create matcher: {}", error.text));
-            }
-            Ok(val) => val,
-        };
-        let regexp = Self {
-            matcher,
-            flags,
-            original_source: p,
-            original_flags: f,
+        let regexp = match Self::compile(p, f) {
+            Err(msg) => return context.throw_syntax_error(msg),
+            Ok(result) => result,
         };
         obj.borrow_mut().data = ObjectData::reg_exp(Box::new(regexp));
 
@@ -326,7 +330,12 @@ impl RegExp {
     pub(crate) fn create(p: &JsValue, f: &JsValue, context: &mut Context) -> JsResult<JsValue> {
         // 1. Let obj be ? RegExpAlloc(%RegExp%).
         let obj = Self::alloc(
-            &context.global_object().clone().get(Self::NAME, context)?,
+            &context
+                .intrinsics()
+                .constructors()
+                .regexp()
+                .constructor()
+                .into(),
             context,
         )?;
 
@@ -334,6 +343,82 @@ impl RegExp {
         Self::initialize(obj, p, f, context)
     }
 
+    /// Compiles the pattern `p` with the flags `f` into the internal representation of a
+    /// `RegExp` object, returning the message of the `SyntaxError` to throw if either is invalid.
+    ///
+    /// The compiled matcher is shared between clones, so the result can be cached and used to
+    /// initialize any number of `RegExp` objects, as is done for regular expression literals.
+    pub(crate) fn compile(p: JsString, f: JsString) -> Result<Self, String> {
+        let flags = RegExpFlags::from_str(&f)?;
+
+        let (named_groups, rewritten) =
+            named_groups(&p).map_err(|msg| format!("invalid regular expression: {msg}"))?;
+        let matcher = Regex::with_flags(rewritten.as_deref().unwrap_or(&p), f.as_ref())
+            .map_err(|error| format!("failed to create matcher: {}", error.text))?;
+
+        Ok(Self {
+            matcher: Rc::new(matcher),
+            named_groups: named_groups.into(),
+            flags,
+            original_source: p,
+            original_flags: f,
+        })
+    }
+
+    /// Creates a new `RegExp` object from an already compiled regular expression.
+    ///
+    /// This is equivalent to `RegExpCreate` with the source and flags of `regexp`, only without
+    /// compiling the pattern again. Each call returns a distinct object with its own `lastIndex`.
+    pub(crate) fn create_from_compiled(regexp: &Self, context: &mut Context) -> JsObject {
+        let obj = Self::alloc(
+            &context
+                .intrinsics()
+                .constructors()
+                .regexp()
+                .constructor()
+                .into(),
+            context,
+        )
+        .expect("allocating with %RegExp% cannot fail");
+        obj.borrow_mut().data = ObjectData::reg_exp(Box::new(regexp.clone()));
+        obj.set("lastIndex", 0, true, context)
+            .expect("setting lastIndex of a new RegExp object cannot fail");
+        obj
+    }
+
+    /// Copies the compiled regular expression, so it can be sent to other threads.
+    pub(crate) fn freeze(&self) -> FrozenRegExp {
+        FrozenRegExp {
+            matcher: (*self.matcher).clone(),
+            named_groups: self
+                .named_groups
+                .iter()
+                .map(|group| (group.name.to_string(), group.indices.clone()))
+                .collect(),
+            flags: self.flags,
+            original_source: self.original_source.encode_utf16().collect(),
+            original_flags: self.original_flags.to_string(),
+        }
+    }
+
+    /// Creates a compiled regular expression from a frozen one.
+    pub(crate) fn thaw(frozen: &FrozenRegExp) -> Self {
+        Self {
+            matcher: Rc::new(frozen.matcher.clone()),
+            named_groups: frozen
+                .named_groups
+                .iter()
+                .map(|(name, indices)| NamedGroup {
+                    name: name.as_str().into(),
+                    indices: indices.clone(),
+                })
+                .collect(),
+            flags: frozen.flags,
+            original_source: JsString::from_utf16(&frozen.original_source),
+            original_flags: frozen.original_flags.as_str().into(),
+        }
+    }
+
     /// `get RegExp [ @@species ]`
     ///
     /// The `RegExp [ @@species ]` accessor property returns the `RegExp` constructor.
@@ -683,8 +768,8 @@ impl RegExp {
         // 1. Let R be the this value.
         // 2. If Type(R) is not Object, throw a TypeError exception.
         let this = this.as_object().ok_or_else(|| {
-            context
-                .construct_type_error("RegExp.prototype.test method called on incompatible value")
+            JsNativeError::typ()
+                .with_message("RegExp.prototype.test method called on incompatible value")
         })?;
 
         // 3. Let string be ? ToString(S).
@@ -728,7 +813,7 @@ impl RegExp {
             .as_object()
             .filter(|obj| obj.is_regexp())
             .ok_or_else(|| {
-                context.construct_type_error("RegExp.prototype.exec called with invalid value")
+                JsNativeError::typ().with_message("RegExp.prototype.exec called with invalid value")
             })?;
 
         // 3. Let S be ? ToString(string).
@@ -931,60 +1016,47 @@ impl RegExp {
         let a = Array::array_create(n + 1, None, context)?;
 
         // 20. Perform ! CreateDataPropertyOrThrow(A, "index", 𝔽(lastIndex)).
-        a.create_data_property_or_throw("index", match_value.start(), context)
-            .expect("this CreateDataPropertyOrThrow call must not fail");
+        a.create_data_property_or_throw("index", match_value.start(), context)?;
 
         // 21. Perform ! CreateDataPropertyOrThrow(A, "input", S).
-        a.create_data_property_or_throw("input", input.clone(), context)
-            .expect("this CreateDataPropertyOrThrow call must not fail");
+        a.create_data_property_or_throw("input", input.clone(), context)?;
 
-        // 22. Let matchedSubstr be the substring of S from lastIndex to e.
+        // 22. Let match be the Match Record { [[StartIndex]]: lastIndex, [[EndIndex]]: e }.
+        // 23. Let indices be a new empty List.
+        // 24. Let groupNames be a new empty List.
+        // 25. Append match to indices.
+        // 26. Let matchedSubstr be GetMatchString(S, match).
         let matched_substr = if let Some(s) = input.get(match_value.range()) {
             s
         } else {
             ""
         };
 
-        // 23. Perform ! CreateDataPropertyOrThrow(A, "0", matchedSubstr).
-        a.create_data_property_or_throw(0, matched_substr, context)
-            .expect("this CreateDataPropertyOrThrow call must not fail");
-
-        // 24. If R contains any GroupName, then
-        // 25. Else,
-        let named_groups = match_value.named_groups();
-        let groups = if named_groups.clone().count() > 0 {
-            // a. Let groups be ! OrdinaryObjectCreate(null).
-            let groups = JsValue::from(JsObject::empty());
-
-            // Perform 27.f here
-            // f. If the ith capture of R was defined with a GroupName, then
-            // i. Let s be the CapturingGroupName of the corresponding RegExpIdentifierName.
-            // ii. Perform ! CreateDataPropertyOrThrow(groups, s, capturedValue).
-            for (name, range) in named_groups {
-                if let Some(range) = range {
-                    let value = if let Some(s) = input.get(range.clone()) {
-                        s
-                    } else {
-                        ""
-                    };
-
-                    groups
-                        .to_object(context)?
-                        .create_data_property_or_throw(name, value, context)
-                        .expect("this CreateDataPropertyOrThrow call must not fail");
-                }
-            }
-            groups
+        // 27. Perform ! CreateDataPropertyOrThrow(A, "0", matchedSubstr).
+        a.create_data_property_or_throw(0, matched_substr, context)?;
+
+        // 28. If R contains any GroupName, then
+        //     a. Let groups be OrdinaryObjectCreate(null).
+        //     b. Let hasGroups be true.
+        // 29. Else,
+        //     a. Let groups be undefined.
+        //     b. Let hasGroups be false.
+        let groups = if rx.named_groups.is_empty() {
+            None
         } else {
-            // a. Let groups be undefined.
-            JsValue::undefined()
+            Some(JsObject::empty())
         };
 
-        // 26. Perform ! CreateDataPropertyOrThrow(A, "groups", groups).
-        a.create_data_property_or_throw("groups", groups, context)
-            .expect("this CreateDataPropertyOrThrow call must not fail");
+        // 30. Perform ! CreateDataPropertyOrThrow(A, "groups", groups).
+        a.create_data_property_or_throw(
+            "groups",
+            groups
+                .clone()
+                .map_or_else(JsValue::undefined, JsValue::from),
+            context,
+        )?;
 
-        // 27. For each integer i such that i ≥ 1 and i ≤ n, in ascending order, do
+        // 31. For each integer i such that 1 ≤ i ≤ n, in ascending order, do
         for i in 1..=n {
             // a. Let captureI be ith element of r's captures List.
             let capture = match_value.group(i as usize);
@@ -1004,14 +1076,142 @@ impl RegExp {
             };
 
             // e. Perform ! CreateDataPropertyOrThrow(A, ! ToString(𝔽(i)), capturedValue).
-            a.create_data_property_or_throw(i, captured_value, context)
-                .expect("this CreateDataPropertyOrThrow call must not fail");
+            a.create_data_property_or_throw(i, captured_value, context)?;
+        }
+
+        // Perform 31.f here, creating the properties of groups in the same order.
+        // f. If the ith capture of R was defined with a GroupName, then
+        //     i. Let s be the CapturingGroupName of that GroupName.
+        //     ii. If matchedGroupNames contains s, then
+        //         1. Assert: capturedValue is undefined.
+        //         2. Append undefined to groupNames.
+        //     iii. Else,
+        //         1. If capturedValue is not undefined, append s to matchedGroupNames.
+        //         2. Perform ! CreateDataPropertyOrThrow(groups, s, capturedValue).
+        //         3. Append s to groupNames.
+        if let Some(groups) = &groups {
+            for group in rx.named_groups.iter() {
+                let captured_value = group
+                    .indices
+                    .iter()
+                    .find_map(|&i| match_value.group(i))
+                    .map_or_else(JsValue::undefined, |range| {
+                        input.get(range).unwrap_or_default().into()
+                    });
+                groups.create_data_property_or_throw(
+                    group.name.clone(),
+                    captured_value,
+                    context,
+                )?;
+            }
+        }
+
+        // 32. If hasIndices is true, then
+        if flags.contains('d') {
+            // a. Let indicesArray be MakeMatchIndicesIndexPairArray(S, indices, groupNames, hasGroups).
+            let indices_array = Self::make_match_indices_index_pair_array(
+                input,
+                &match_value,
+                &rx.named_groups,
+                context,
+            )?;
+
+            // b. Perform ! CreateDataPropertyOrThrow(A, "indices", indicesArray).
+            a.create_data_property_or_throw("indices", indices_array, context)?;
         }
 
-        // 28. Return A.
+        // 33. Return A.
         Ok(Some(a))
     }
 
+    /// `22.2.7.8 MakeMatchIndicesIndexPairArray ( S, indices, groupNames, hasGroups )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-makematchindicesindexpairarray
+    fn make_match_indices_index_pair_array(
+        input: &str,
+        match_value: &Match,
+        named_groups: &[NamedGroup],
+        context: &mut Context,
+    ) -> JsResult<JsObject> {
+        // 1. Let n be the number of elements in indices.
+        let n = match_value.captures.len() + 1;
+
+        // 2. Assert: n < 2^32 - 1.
+        // 3. Assert: groupNames has n - 1 elements.
+        // 4. NOTE: The groupNames List contains elements aligned with the indices List starting at indices[1].
+        // 5. Let A be ! ArrayCreate(n).
+        let a = Array::array_create(n as u64, None, context)?;
+
+        // 6. If hasGroups is true, then
+        //     a. Let groups be OrdinaryObjectCreate(null).
+        // 7. Else,
+        //     a. Let groups be undefined.
+        let groups = if named_groups.is_empty() {
+            None
+        } else {
+            Some(JsObject::empty())
+        };
+
+        // 8. Perform ! CreateDataPropertyOrThrow(A, "groups", groups).
+        a.create_data_property_or_throw(
+            "groups",
+            groups
+                .clone()
+                .map_or_else(JsValue::undefined, JsValue::from),
+            context,
+        )?;
+
+        // 9. For each integer i such that 0 ≤ i < n, in ascending order, do
+        let mut match_index_pairs = Vec::with_capacity(n);
+        for (i, match_indices) in match_value.groups().enumerate() {
+            // a. Let matchIndices be indices[i].
+            // b. If matchIndices is not undefined, then
+            //     i. Let matchIndexPair be GetMatchIndexPair(S, matchIndices).
+            // c. Else,
+            //     i. Let matchIndexPair be undefined.
+            let match_index_pair = match match_indices {
+                // GetMatchIndexPair ( S, match )
+                // The indices are UTF-16 code unit offsets into S.
+                Some(range) => {
+                    let start = input[..range.start].encode_utf16().count();
+                    let end = start + input[range].encode_utf16().count();
+                    Array::create_array_from_list([start.into(), end.into()], context)?.into()
+                }
+                None => JsValue::undefined(),
+            };
+
+            // d. Perform ! CreateDataPropertyOrThrow(A, ! ToString(𝔽(i)), matchIndexPair).
+            a.create_data_property_or_throw(i, match_index_pair.clone(), context)?;
+            match_index_pairs.push(match_index_pair);
+        }
+
+        // e. If i > 0 and groupNames[i - 1] is not undefined, then
+        //     i. Assert: groups is not undefined.
+        //     ii. Perform ! CreateDataPropertyOrThrow(groups, groupNames[i - 1], matchIndexPair).
+        if let Some(groups) = &groups {
+            for group in named_groups {
+                let match_index_pair = group
+                    .indices
+                    .iter()
+                    .map(|&i| &match_index_pairs[i])
+                    .find(|pair| !pair.is_undefined())
+                    .cloned()
+                    .unwrap_or_default();
+                groups.create_data_property_or_throw(
+                    group.name.clone(),
+                    match_index_pair,
+                    context,
+                )?;
+            }
+        }
+
+        // 10. Return A.
+        Ok(a)
+    }
+
     /// `RegExp.prototype[ @@match ]( string )`
     ///
     /// This method retrieves the matches when matching a string against a regular expression.
@@ -1084,8 +1284,7 @@ impl RegExp {
                     let match_str = result.get("0", context)?.to_string(context)?;
 
                     // 2. Perform ! CreateDataPropertyOrThrow(A, ! ToString(𝔽(n)), matchStr).
-                    a.create_data_property_or_throw(n, match_str.clone(), context)
-                        .expect("this CreateDataPropertyOrThrow call must not fail");
+                    a.create_data_property_or_throw(n, match_str.clone(), context)?;
 
                     // 3. If matchStr is the empty String, then
                     if match_str.is_empty() {
@@ -1132,7 +1331,7 @@ impl RegExp {
         let (body, flags) = if let Some(object) = this.as_object() {
             let object = object.borrow();
             let regex = object.as_regexp().ok_or_else(|| {
-                context.construct_type_error(format!(
+                JsNativeError::typ().with_message(format!(
                     "Method RegExp.prototype.toString called on incompatible receiver {}",
                     this.display()
                 ))
@@ -1165,9 +1364,8 @@ impl RegExp {
         // 1. Let R be the this value.
         // 2. If Type(R) is not Object, throw a TypeError exception.
         let regexp = this.as_object().ok_or_else(|| {
-            context.construct_type_error(
-                "RegExp.prototype.match_all method called on incompatible value",
-            )
+            JsNativeError::typ()
+                .with_message("RegExp.prototype.match_all method called on incompatible value")
         })?;
 
         // 3. Let S be ? ToString(string).
@@ -1409,8 +1607,8 @@ impl RegExp {
 
                 // ii. Let replacement be ? GetSubstitution(matched, S, position, captures, namedCaptures, replaceValue).
                 replacement = string::get_substitution(
-                    matched.as_str(),
-                    arg_str.as_str(),
+                    &matched,
+                    &arg_str,
                     position,
                     &captures,
                     &named_captures,
@@ -1427,13 +1625,15 @@ impl RegExp {
                 //    In such cases, the corresponding substitution is ignored.
                 // ii. Set accumulatedResult to the string-concatenation of accumulatedResult,
                 //     the substring of S from nextSourcePosition to position, and replacement.
-                accumulated_result = format!(
-                    "{accumulated_result}{}{replacement}",
-                    arg_str
-                        .get(next_source_position..position)
-                        .expect("index of a regexp match cannot be greater than the input string"),
-                )
-                .into();
+                let preserved = arg_str
+                    .get(next_source_position..position)
+                    .expect("index of a regexp match cannot be greater than the input string");
+                context.resource_limits().check_string_length(&[
+                    &accumulated_result,
+                    preserved,
+                    &replacement,
+                ])?;
+                accumulated_result = format!("{accumulated_result}{preserved}{replacement}").into();
 
                 // iii. Set nextSourcePosition to position + matchLength.
                 next_source_position = position + match_length;
@@ -1446,14 +1646,13 @@ impl RegExp {
         }
 
         // 16. Return the string-concatenation of accumulatedResult and the substring of S from nextSourcePosition.
-        Ok(format!(
-            "{}{}",
-            accumulated_result,
-            arg_str
-                .get(next_source_position..)
-                .expect("next_source_position cannot be greater than the input string")
-        )
-        .into())
+        let rest = arg_str
+            .get(next_source_position..)
+            .expect("next_source_position cannot be greater than the input string");
+        context
+            .resource_limits()
+            .check_string_length(&[&accumulated_result, rest])?;
+        Ok(format!("{accumulated_result}{rest}").into())
     }
 
     /// `RegExp.prototype[ @@search ]( string )`
@@ -1607,8 +1806,7 @@ impl RegExp {
             }
 
             // c. Perform ! CreateDataPropertyOrThrow(A, "0", S).
-            a.create_data_property_or_throw(0, arg_str, context)
-                .expect("this CreateDataPropertyOrThrow call must not fail");
+            a.create_data_property_or_throw(0, arg_str, context)?;
 
             // d. Return A.
             return Ok(a.into());
@@ -1651,8 +1849,7 @@ impl RegExp {
                     );
 
                     // 2. Perform ! CreateDataPropertyOrThrow(A, ! ToString(𝔽(lengthA)), T).
-                    a.create_data_property_or_throw(length_a, arg_str_substring, context)
-                        .expect("this CreateDataPropertyOrThrow call must not fail");
+                    a.create_data_property_or_throw(length_a, arg_str_substring, context)?;
 
                     // 3. Set lengthA to lengthA + 1.
                     length_a += 1;
@@ -1682,8 +1879,7 @@ impl RegExp {
                         let next_capture = result.get(i.to_string(), context)?;
 
                         // b. Perform ! CreateDataPropertyOrThrow(A, ! ToString(𝔽(lengthA)), nextCapture).
-                        a.create_data_property_or_throw(length_a, next_capture, context)
-                            .expect("this CreateDataPropertyOrThrow call must not fail");
+                        a.create_data_property_or_throw(length_a, next_capture, context)?;
 
                         // d. Set lengthA to lengthA + 1.
                         length_a += 1;
@@ -1712,8 +1908,7 @@ impl RegExp {
         );
 
         // 21. Perform ! CreateDataPropertyOrThrow(A, ! ToString(𝔽(lengthA)), T).
-        a.create_data_property_or_throw(length_a, arg_str_substring, context)
-            .expect("this CreateDataPropertyOrThrow call must not fail");
+        a.create_data_property_or_throw(length_a, arg_str_substring, context)?;
 
         // 22. Return A.
         Ok(a.into())
@@ -0,0 +1,210 @@
+//! This module implements the table of capture group names of a `RegExp` pattern.
+//!
+//! The regex backend only keeps a single capture index per group name and doesn't preserve the
+//! order in which group names appear in the pattern, so the names are collected here from the
+//! pattern source instead. The table is used to build the `groups` objects of match results,
+//! and to resolve `\k<name>` backreferences when a name is shared by groups in different
+//! alternatives.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-patterns-static-semantics-early-errors
+
+use crate::JsString;
+use std::{iter::Peekable, ops::Range, str::CharIndices};
+
+/// A capture group name, with the indices of all the capture groups defined with it.
+#[derive(Debug, Clone)]
+pub(super) struct NamedGroup {
+    pub(super) name: JsString,
+    pub(super) indices: Vec<usize>,
+}
+
+/// The location of a capture group in the nested disjunctions of a pattern, as a list of
+/// `(disjunction, alternative)` pairs ordered from the outermost disjunction inwards.
+type Location = Vec<(usize, usize)>;
+
+/// Collects the named capture groups of `pattern`, in the order in which they are defined.
+///
+/// If a name is shared by several groups, the pattern is also rewritten so that every
+/// `\k<name>` backreference to that name refers to all the groups that use it. Only one of
+/// those groups can participate in a match, and backreferences to the other ones match the
+/// empty string.
+pub(super) fn named_groups(
+    pattern: &str,
+) -> Result<(Vec<NamedGroup>, Option<String>), &'static str> {
+    let mut chars = pattern.char_indices().peekable();
+    let mut groups: Vec<(String, Vec<(usize, Location)>)> = Vec::new();
+    let mut backreferences = Vec::new();
+    let mut location = vec![(0, 0)];
+    let mut disjunctions = 1;
+    let mut captures = 0;
+
+    while let Some((start, c)) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                Some((_, 'k')) if next_is(&mut chars, '<') => {
+                    if let Some(name) = group_name(&mut chars) {
+                        let end = chars.peek().map_or(pattern.len(), |&(i, _)| i);
+                        backreferences.push((start..end, name));
+                    }
+                }
+                _ => {}
+            },
+            '[' => loop {
+                match chars.next() {
+                    Some((_, '\\')) => {
+                        chars.next();
+                    }
+                    Some((_, ']')) | None => break,
+                    _ => {}
+                }
+            },
+            '(' => {
+                if !next_is(&mut chars, '?') {
+                    captures += 1;
+                } else if next_is(&mut chars, '<') && !matches!(chars.peek(), Some((_, '=' | '!')))
+                {
+                    captures += 1;
+                    if let Some(name) = group_name(&mut chars) {
+                        if let Some((_, defined)) = groups.iter_mut().find(|(n, _)| *n == name) {
+                            // It is a Syntax Error if Pattern contains two distinct GroupSpecifiers
+                            // x and y such that the CapturingGroupName of x is the CapturingGroupName
+                            // of y and such that MightBothParticipate(x, y) is true.
+                            if defined
+                                .iter()
+                                .any(|(_, other)| might_both_participate(other, &location))
+                            {
+                                return Err("duplicate capture group name");
+                            }
+                            defined.push((captures, location.clone()));
+                        } else {
+                            groups.push((name, vec![(captures, location.clone())]));
+                        }
+                    }
+                }
+                location.push((disjunctions, 0));
+                disjunctions += 1;
+            }
+            ')' if location.len() > 1 => {
+                location.pop();
+            }
+            '|' => {
+                if let Some((_, alternative)) = location.last_mut() {
+                    *alternative += 1;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let groups: Vec<_> = groups
+        .into_iter()
+        .map(|(name, defined)| NamedGroup {
+            name: name.into(),
+            indices: defined.into_iter().map(|(index, _)| index).collect(),
+        })
+        .collect();
+
+    let rewritten = rewrite_backreferences(pattern, &groups, &backreferences);
+    Ok((groups, rewritten))
+}
+
+/// Replaces the backreferences to names shared by several groups with numbered backreferences
+/// to each of those groups, returning `None` if no backreference needs to be replaced.
+fn rewrite_backreferences(
+    pattern: &str,
+    groups: &[NamedGroup],
+    backreferences: &[(Range<usize>, String)],
+) -> Option<String> {
+    let mut rewritten = String::new();
+    let mut last = 0;
+    for (range, name) in backreferences {
+        let indices = match groups.iter().find(|group| group.name == name.as_str()) {
+            Some(group) if group.indices.len() > 1 => &group.indices,
+            _ => continue,
+        };
+        rewritten.push_str(&pattern[last..range.start]);
+        rewritten.push_str("(?:");
+        for index in indices {
+            rewritten.push('\\');
+            rewritten.push_str(&index.to_string());
+        }
+        rewritten.push(')');
+        last = range.end;
+    }
+
+    if last == 0 {
+        return None;
+    }
+    rewritten.push_str(&pattern[last..]);
+    Some(rewritten)
+}
+
+/// Abstract operation `MightBothParticipate ( x, y )`
+///
+/// Two groups can't both participate in a match if they are in different alternatives of the
+/// same disjunction.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-mightbothparticipate
+fn might_both_participate(x: &Location, y: &Location) -> bool {
+    x.iter()
+        .zip(y)
+        .find(|(x, y)| x != y)
+        .map_or(true, |((x, _), (y, _))| x != y)
+}
+
+/// Consumes the next character if it is `expected`.
+fn next_is(chars: &mut Peekable<CharIndices<'_>>, expected: char) -> bool {
+    if matches!(chars.peek(), Some(&(_, c)) if c == expected) {
+        chars.next();
+        true
+    } else {
+        false
+    }
+}
+
+/// Parses the `RegExpIdentifierName` of a group, up to and including the closing `>`,
+/// resolving any unicode escape sequences in it.
+///
+/// Returns `None` if the name isn't terminated or contains an invalid escape. Those patterns
+/// are rejected by the regex backend.
+fn group_name(chars: &mut Peekable<CharIndices<'_>>) -> Option<String> {
+    let mut units = Vec::new();
+    loop {
+        match chars.next()?.1 {
+            '>' => return String::from_utf16(&units).ok(),
+            '\\' => {
+                if chars.next()?.1 != 'u' {
+                    return None;
+                }
+                let code_point = if next_is(chars, '{') {
+                    let mut digits = String::new();
+                    loop {
+                        match chars.next()?.1 {
+                            '}' => break,
+                            c => digits.push(c),
+                        }
+                    }
+                    u32::from_str_radix(&digits, 16).ok()?
+                } else {
+                    let digits: String = chars.by_ref().take(4).map(|(_, c)| c).collect();
+                    if digits.len() != 4 {
+                        return None;
+                    }
+                    u32::from_str_radix(&digits, 16).ok()?
+                };
+                if let Ok(unit) = u16::try_from(code_point) {
+                    units.push(unit);
+                } else {
+                    units.extend(char::from_u32(code_point)?.encode_utf16(&mut [0; 2]).iter());
+                }
+            }
+            c => units.extend(c.encode_utf16(&mut [0; 2]).iter()),
+        }
+    }
+}
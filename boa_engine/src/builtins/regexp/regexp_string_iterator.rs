@@ -11,11 +11,11 @@
 //! [spec]: https://tc39.es/ecma262/#sec-regexp-string-iterator-objects
 
 use crate::{
-    builtins::{function::make_builtin_fn, iterable::create_iter_result_object, regexp},
+    builtins::{function::make_builtin_fn, iterable::create_iter_result_object_or_throw, regexp},
     object::{JsObject, ObjectData},
     property::PropertyDescriptor,
     symbol::WellKnownSymbols,
-    Context, JsResult, JsString, JsValue,
+    Context, JsNativeError, JsResult, JsString, JsValue,
 };
 use boa_gc::{Finalize, Trace};
 use boa_profiler::Profiler;
@@ -85,13 +85,11 @@ impl RegExpStringIterator {
         let iterator = iterator
             .as_mut()
             .and_then(|obj| obj.as_regexp_string_iterator_mut())
-            .ok_or_else(|| context.construct_type_error("`this` is not a RegExpStringIterator"))?;
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message("`this` is not a RegExpStringIterator")
+            })?;
         if iterator.completed {
-            return Ok(create_iter_result_object(
-                JsValue::undefined(),
-                true,
-                context,
-            ));
+            return create_iter_result_object_or_throw(JsValue::undefined(), true, context);
         }
 
         // TODO: This is the code that should be created as a closure in create_regexp_string_iterator.
@@ -105,7 +103,7 @@ impl RegExpStringIterator {
                 // 1. Perform ? Yield(match).
                 // 2. Return undefined.
                 iterator.completed = true;
-                return Ok(create_iter_result_object(m.into(), false, context));
+                return create_iter_result_object_or_throw(m.into(), false, context);
             }
 
             // iv. Let matchStr be ? ToString(? Get(match, "0")).
@@ -130,15 +128,11 @@ impl RegExpStringIterator {
             }
 
             // vi. Perform ? Yield(match).
-            Ok(create_iter_result_object(m.into(), false, context))
+            create_iter_result_object_or_throw(m.into(), false, context)
         } else {
             // ii. If match is null, return undefined.
             iterator.completed = true;
-            Ok(create_iter_result_object(
-                JsValue::undefined(),
-                true,
-                context,
-            ))
+            create_iter_result_object_or_throw(JsValue::undefined(), true, context)
         }
     }
 
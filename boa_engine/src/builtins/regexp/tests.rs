@@ -124,6 +124,105 @@ fn exec() {
     );
 }
 
+#[test]
+fn named_groups() {
+    let mut context = Context::default();
+    let init = r"
+        var re = /(?<year>\d{4})-(?<month>\d{2})(?<day>-\d{2})?/;
+        var result = re.exec('2020-12');
+        ";
+
+    eprintln!("{}", forward(&mut context, init));
+    assert_eq!(forward(&mut context, "result.groups.year"), "\"2020\"");
+    assert_eq!(forward(&mut context, "result.groups.month"), "\"12\"");
+    assert_eq!(forward(&mut context, "'day' in result.groups"), "true");
+    assert_eq!(forward(&mut context, "result.groups.day"), "undefined");
+    assert_eq!(
+        forward(&mut context, "Object.keys(result.groups).join()"),
+        "\"year,month,day\""
+    );
+    assert_eq!(
+        forward(&mut context, "Object.getPrototypeOf(result.groups)"),
+        "null"
+    );
+    assert_eq!(forward(&mut context, "/(a)/.exec('a').groups"), "undefined");
+    assert_eq!(forward(&mut context, r"/(?<a>.)\k<a>/.test('bb')"), "true");
+    assert_eq!(forward(&mut context, r"/(?<a>.)\k<a>/.test('bc')"), "false");
+    assert_eq!(
+        forward(
+            &mut context,
+            "'2020-12'.replace(re, '$<month>/$<year>$<day>')"
+        ),
+        "\"12/2020\""
+    );
+}
+
+#[test]
+fn duplicate_named_groups() {
+    let mut context = Context::default();
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new RegExp('(?<a>x)(?<a>y)') } catch (e) { e.name }"
+        ),
+        "\"SyntaxError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new RegExp('(?:(?<a>x)|(?<b>y))(?<a>z)') } catch (e) { e.name }"
+        ),
+        "\"SyntaxError\""
+    );
+    assert_eq!(
+        forward(&mut context, "/(?<a>x)|(?<a>y)/.exec('y').groups.a"),
+        "\"y\""
+    );
+    assert_eq!(
+        forward(&mut context, "/(?<a>x)|(?<a>y)/.exec('x').groups.a"),
+        "\"x\""
+    );
+    assert_eq!(
+        forward(&mut context, r"/(?:(?<a>x)|(?<a>y))\k<a>/.test('yy')"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, r"/(?:(?<a>x)|(?<a>y))\k<a>/.test('xy')"),
+        "false"
+    );
+}
+
+#[test]
+fn match_indices() {
+    let mut context = Context::default();
+    let init = r"
+        var result = /(?<first>é)(?<second>z)?b/d.exec('ébc');
+        ";
+
+    eprintln!("{}", forward(&mut context, init));
+    assert_eq!(
+        forward(&mut context, "JSON.stringify(result.indices)"),
+        "\"[[0,2],[0,1],null]\""
+    );
+    assert_eq!(
+        forward(&mut context, "result.indices.groups.first.join()"),
+        "\"0,1\""
+    );
+    assert_eq!(
+        forward(&mut context, "'second' in result.indices.groups"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "/b/d.exec('abc').indices.groups"),
+        "undefined"
+    );
+    assert_eq!(
+        forward(&mut context, "'indices' in /b/.exec('abc')"),
+        "false"
+    );
+}
+
 #[test]
 fn to_string() {
     let mut context = Context::default();
@@ -13,7 +13,7 @@
 use self::{ordered_set::OrderedSet, set_iterator::SetIterator};
 use super::JsArgs;
 use crate::{
-    builtins::BuiltIn,
+    builtins::{iterable::IteratorRecord, BuiltIn},
     context::intrinsics::StandardConstructors,
     object::{
         internal_methods::get_prototype_from_constructor, ConstructorBuilder, FunctionBuilder,
@@ -21,9 +21,11 @@ use crate::{
     },
     property::{Attribute, PropertyNameKind},
     symbol::WellKnownSymbols,
-    Context, JsResult, JsValue,
+    value::IntegerOrInfinity,
+    Context, JsError, JsNativeError, JsResult, JsValue,
 };
 use boa_profiler::Profiler;
+use std::cmp::Ordering;
 use tap::{Conv, Pipe};
 
 pub mod ordered_set;
@@ -76,9 +78,16 @@ impl BuiltIn for Set {
         .method(Self::add, "add", 1)
         .method(Self::clear, "clear", 0)
         .method(Self::delete, "delete", 1)
+        .method(Self::difference, "difference", 1)
         .method(Self::entries, "entries", 0)
         .method(Self::for_each, "forEach", 1)
         .method(Self::has, "has", 1)
+        .method(Self::intersection, "intersection", 1)
+        .method(Self::is_disjoint_from, "isDisjointFrom", 1)
+        .method(Self::is_subset_of, "isSubsetOf", 1)
+        .method(Self::is_superset_of, "isSupersetOf", 1)
+        .method(Self::symmetric_difference, "symmetricDifference", 1)
+        .method(Self::union, "union", 1)
         .property(
             "keys",
             values_function.clone(),
@@ -138,7 +147,7 @@ impl Set {
 
         // 6. If IsCallable(adder) is false, throw a TypeError exception.
         let adder = adder.as_callable().ok_or_else(|| {
-            context.construct_type_error("'add' of 'newTarget' is not a function")
+            JsNativeError::typ().with_message("'add' of 'newTarget' is not a function")
         })?;
 
         // 7. Let iteratorRecord be ? GetIterator(iterable).
@@ -342,7 +351,9 @@ impl Set {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         if args.is_empty() {
-            return Err(JsValue::new("Missing argument for Set.prototype.forEach"));
+            return Err(JsError::from_opaque(JsValue::new(
+                "Missing argument for Set.prototype.forEach",
+            )));
         }
 
         let callback_arg = &args[0];
@@ -357,7 +368,7 @@ impl Set {
 
         let mut index = 0;
 
-        while index < Self::get_size(this, context)? {
+        while index < Self::get_size(this)? {
             let arguments = this
                 .as_object()
                 .and_then(|obj| {
@@ -366,7 +377,7 @@ impl Set {
                             .map(|value| [value.clone(), value.clone(), this.clone()])
                     })
                 })
-                .ok_or_else(|| context.construct_type_error("'this' is not a Set"))?;
+                .ok_or_else(|| JsNativeError::typ().with_message("'this' is not a Set"))?;
 
             if let Some(arguments) = arguments {
                 context.call(callback_arg, &this_arg, &arguments)?;
@@ -391,7 +402,7 @@ impl Set {
     pub(crate) fn has(
         this: &JsValue,
         args: &[JsValue],
-        context: &mut Context,
+        _context: &mut Context,
     ) -> JsResult<JsValue> {
         let value = args.get_or_undefined(0);
 
@@ -401,7 +412,11 @@ impl Set {
                     .as_set_ref()
                     .map(|set| set.contains(value).into())
             })
-            .ok_or_else(|| context.construct_type_error("'this' is not a Set"))
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("'this' is not a Set")
+                    .into()
+            })
     }
 
     /// `Set.prototype.values( )`
@@ -438,14 +453,654 @@ impl Set {
         ))
     }
 
-    fn size_getter(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
-        Self::get_size(this, context).map(JsValue::from)
+    /// `Set.prototype.union ( other )`
+    ///
+    /// This method returns a new set with the elements of the set followed by the elements of
+    /// the set-like `other` that are not in it.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-set-methods/#sec-set.prototype.union
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/union
+    pub(crate) fn union(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[SetData]]).
+        // 5. Let resultSetData be a copy of O.[[SetData]].
+        let mut result_set_data = Self::this_set_data(this)?;
+
+        // 3. Let otherRec be ? GetSetRecord(other).
+        let other_rec = SetRecord::new(args.get_or_undefined(0), context)?;
+
+        // 4. Let keysIter be ? GetKeysIterator(otherRec).
+        let keys_iter = other_rec.keys_iterator(context)?;
+
+        // 6. Let next be true.
+        // 7. Repeat, while next is not false,
+        //     a. Set next to ? IteratorStep(keysIter).
+        while let Some(next) = keys_iter.step(context)? {
+            // b. If next is not false, then
+            //     i. Let nextValue be ? IteratorValue(next).
+            //     ii. If nextValue is -0𝔽, set nextValue to +0𝔽.
+            let next_value = Self::canonicalize_keyed_collection_key(next.value(context)?);
+
+            //     iii. If SetDataHas(resultSetData, nextValue) is false, then
+            //         1. Append nextValue to resultSetData.
+            result_set_data.add(next_value);
+        }
+
+        // 8. Let result be OrdinaryObjectCreate(%Set.prototype%, « [[SetData]] »).
+        // 9. Set result.[[SetData]] to resultSetData.
+        // 10. Return result.
+        Ok(Self::create_set_from_data(result_set_data, context).into())
+    }
+
+    /// `Set.prototype.intersection ( other )`
+    ///
+    /// This method returns a new set with the elements that are both in the set and in the
+    /// set-like `other`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-set-methods/#sec-set.prototype.intersection
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/intersection
+    pub(crate) fn intersection(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[SetData]]).
+        let this_size = Self::get_size(this)?;
+
+        // 3. Let otherRec be ? GetSetRecord(other).
+        let other_rec = SetRecord::new(args.get_or_undefined(0), context)?;
+
+        // 4. Let resultSetData be a new empty List.
+        let mut result_set_data = OrderedSet::new();
+
+        // 5. If SetDataSize(O.[[SetData]]) ≤ otherRec.[[Size]], then
+        if other_rec.cmp_size(this_size).is_le() {
+            // a. Let thisSize be the number of elements in O.[[SetData]].
+            // b. Let index be 0.
+            // c. Repeat, while index < thisSize,
+            //     i. Let e be O.[[SetData]][index].
+            //     ii. Set index to index + 1.
+            //     iii. If e is not empty, then
+            //         ...
+            //         4. Set thisSize to the number of elements in O.[[SetData]].
+            let mut index = 0;
+            while let Some(e) = Self::get_set_element(this, index)? {
+                index += 1;
+
+                // 1. Let inOther be ToBoolean(? Call(otherRec.[[Has]], otherRec.[[SetObject]], « e »)).
+                // 2. If inOther is true, then
+                if other_rec.has(&e, context)? {
+                    // a. NOTE: It is possible for earlier calls to otherRec.[[Has]] to remove and re-add an element of O.[[SetData]], which can cause elements to be visited more than once during this iteration.
+                    // b. If SetDataHas(resultSetData, e) is false, then
+                    //     i. Append e to resultSetData.
+                    result_set_data.add(e);
+                }
+
+                // 3. NOTE: The number of elements in O.[[SetData]] may have increased during execution of otherRec.[[Has]].
+            }
+        } else {
+            // 6. Else,
+            //     a. Let keysIter be ? GetKeysIterator(otherRec).
+            let keys_iter = other_rec.keys_iterator(context)?;
+
+            //     b. Let next be true.
+            //     c. Repeat, while next is not false,
+            //         i. Set next to ? IteratorStep(keysIter).
+            while let Some(next) = keys_iter.step(context)? {
+                // ii. If next is not false, then
+                //     1. Let nextValue be ? IteratorValue(next).
+                //     2. If nextValue is -0𝔽, set nextValue to +0𝔽.
+                let next_value = Self::canonicalize_keyed_collection_key(next.value(context)?);
+
+                //     3. NOTE: Because other is an arbitrary object, it is possible for its "keys" iterator to produce the same value more than once.
+                //     4. Let alreadyInResult be SetDataHas(resultSetData, nextValue).
+                //     5. Let inThis be SetDataHas(O.[[SetData]], nextValue).
+                //     6. If alreadyInResult is false and inThis is true, then
+                //         a. Append nextValue to resultSetData.
+                if Self::set_data_has(this, &next_value)? {
+                    result_set_data.add(next_value);
+                }
+            }
+        }
+
+        // 7. Let result be OrdinaryObjectCreate(%Set.prototype%, « [[SetData]] »).
+        // 8. Set result.[[SetData]] to resultSetData.
+        // 9. Return result.
+        Ok(Self::create_set_from_data(result_set_data, context).into())
+    }
+
+    /// `Set.prototype.difference ( other )`
+    ///
+    /// This method returns a new set with the elements of the set that are not in the set-like
+    /// `other`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-set-methods/#sec-set.prototype.difference
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/difference
+    pub(crate) fn difference(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[SetData]]).
+        // 4. Let resultSetData be a copy of O.[[SetData]].
+        let this_set_data = Self::this_set_data(this)?;
+
+        // 3. Let otherRec be ? GetSetRecord(other).
+        let other_rec = SetRecord::new(args.get_or_undefined(0), context)?;
+
+        let mut result_set_data = this_set_data.clone();
+
+        // 5. If SetDataSize(O.[[SetData]]) ≤ otherRec.[[Size]], then
+        if other_rec.cmp_size(this_set_data.size()).is_le() {
+            // a. Let thisSize be the number of elements in O.[[SetData]].
+            // b. Let index be 0.
+            // c. Repeat, while index < thisSize,
+            //     i. Let e be resultSetData[index].
+            //     ii. If e is not empty, then
+            for e in &this_set_data {
+                // 1. Let inOther be ToBoolean(? Call(otherRec.[[Has]], otherRec.[[SetObject]], « e »)).
+                // 2. If inOther is true, then
+                if other_rec.has(e, context)? {
+                    // a. Set resultSetData[index] to empty.
+                    result_set_data.delete(e);
+                }
+
+                // iii. Set index to index + 1.
+            }
+        } else {
+            // 6. Else,
+            //     a. Let keysIter be ? GetKeysIterator(otherRec).
+            let keys_iter = other_rec.keys_iterator(context)?;
+
+            //     b. Let next be true.
+            //     c. Repeat, while next is not false,
+            //         i. Set next to ? IteratorStep(keysIter).
+            while let Some(next) = keys_iter.step(context)? {
+                // ii. If next is not false, then
+                //     1. Let nextValue be ? IteratorValue(next).
+                //     2. If nextValue is -0𝔽, set nextValue to +0𝔽.
+                let next_value = Self::canonicalize_keyed_collection_key(next.value(context)?);
+
+                //     3. If SetDataHas(resultSetData, nextValue) is true, then
+                //         a. Remove nextValue from resultSetData.
+                result_set_data.delete(&next_value);
+            }
+        }
+
+        // 7. Let result be OrdinaryObjectCreate(%Set.prototype%, « [[SetData]] »).
+        // 8. Set result.[[SetData]] to resultSetData.
+        // 9. Return result.
+        Ok(Self::create_set_from_data(result_set_data, context).into())
+    }
+
+    /// `Set.prototype.symmetricDifference ( other )`
+    ///
+    /// This method returns a new set with the elements that are either in the set or in the
+    /// set-like `other`, but not in both.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-set-methods/#sec-set.prototype.symmetricdifference
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/symmetricDifference
+    pub(crate) fn symmetric_difference(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[SetData]]).
+        // 5. Let resultSetData be a copy of O.[[SetData]].
+        let mut result_set_data = Self::this_set_data(this)?;
+
+        // 3. Let otherRec be ? GetSetRecord(other).
+        let other_rec = SetRecord::new(args.get_or_undefined(0), context)?;
+
+        // 4. Let keysIter be ? GetKeysIterator(otherRec).
+        let keys_iter = other_rec.keys_iterator(context)?;
+
+        // 6. Let next be true.
+        // 7. Repeat, while next is not false,
+        //     a. Set next to ? IteratorStep(keysIter).
+        while let Some(next) = keys_iter.step(context)? {
+            // b. If next is not false, then
+            //     i. Let nextValue be ? IteratorValue(next).
+            //     ii. If nextValue is -0𝔽, set nextValue to +0𝔽.
+            let next_value = Self::canonicalize_keyed_collection_key(next.value(context)?);
+
+            //     iii. Let resultIndex be SetDataIndex(resultSetData, nextValue).
+            //     iv. If resultIndex is not-found, let alreadyInResult be false. Otherwise let alreadyInResult be true.
+            let already_in_result = result_set_data.contains(&next_value);
+
+            //     v. If SetDataHas(O.[[SetData]], nextValue) is true, then
+            if Self::set_data_has(this, &next_value)? {
+                // 1. If alreadyInResult is true, set resultSetData[resultIndex] to empty.
+                if already_in_result {
+                    result_set_data.delete(&next_value);
+                }
+            }
+            //     vi. Else,
+            //         1. If alreadyInResult is false, append nextValue to resultSetData.
+            else if !already_in_result {
+                result_set_data.add(next_value);
+            }
+        }
+
+        // 8. Let result be OrdinaryObjectCreate(%Set.prototype%, « [[SetData]] »).
+        // 9. Set result.[[SetData]] to resultSetData.
+        // 10. Return result.
+        Ok(Self::create_set_from_data(result_set_data, context).into())
+    }
+
+    /// `Set.prototype.isSubsetOf ( other )`
+    ///
+    /// This method returns `true` if all the elements of the set are in the set-like `other`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-set-methods/#sec-set.prototype.issubsetof
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/isSubsetOf
+    pub(crate) fn is_subset_of(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[SetData]]).
+        let this_size = Self::get_size(this)?;
+
+        // 3. Let otherRec be ? GetSetRecord(other).
+        let other_rec = SetRecord::new(args.get_or_undefined(0), context)?;
+
+        // 4. If SetDataSize(O.[[SetData]]) > otherRec.[[Size]], return false.
+        if other_rec.cmp_size(this_size).is_gt() {
+            return Ok(false.into());
+        }
+
+        // 5. Let thisSize be the number of elements in O.[[SetData]].
+        // 6. Let index be 0.
+        // 7. Repeat, while index < thisSize,
+        //     a. Let e be O.[[SetData]][index].
+        //     b. Set index to index + 1.
+        //     c. If e is not empty, then
+        //         ...
+        //         iii. Set thisSize to the number of elements in O.[[SetData]].
+        let mut index = 0;
+        while let Some(e) = Self::get_set_element(this, index)? {
+            index += 1;
+
+            // i. Let inOther be ToBoolean(? Call(otherRec.[[Has]], otherRec.[[SetObject]], « e »)).
+            // ii. If inOther is false, return false.
+            if !other_rec.has(&e, context)? {
+                return Ok(false.into());
+            }
+        }
+
+        // 8. Return true.
+        Ok(true.into())
+    }
+
+    /// `Set.prototype.isSupersetOf ( other )`
+    ///
+    /// This method returns `true` if all the elements of the set-like `other` are in the set.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-set-methods/#sec-set.prototype.issupersetof
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/isSupersetOf
+    pub(crate) fn is_superset_of(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[SetData]]).
+        let this_size = Self::get_size(this)?;
+
+        // 3. Let otherRec be ? GetSetRecord(other).
+        let other_rec = SetRecord::new(args.get_or_undefined(0), context)?;
+
+        // 4. If SetDataSize(O.[[SetData]]) < otherRec.[[Size]], return false.
+        if other_rec.cmp_size(this_size).is_lt() {
+            return Ok(false.into());
+        }
+
+        // 5. Let keysIter be ? GetKeysIterator(otherRec).
+        let keys_iter = other_rec.keys_iterator(context)?;
+
+        // 6. Let next be true.
+        // 7. Repeat, while next is not false,
+        //     a. Set next to ? IteratorStep(keysIter).
+        while let Some(next) = keys_iter.step(context)? {
+            // b. If next is not false, then
+            //     i. Let nextValue be ? IteratorValue(next).
+            let next_value = next.value(context)?;
+
+            //     ii. If SetDataHas(O.[[SetData]], nextValue) is false, then
+            if !Self::set_data_has(this, &next_value)? {
+                // 1. Perform ? IteratorClose(keysIter, NormalCompletion(unused)).
+                keys_iter.close(Ok(JsValue::undefined()), context)?;
+
+                // 2. Return false.
+                return Ok(false.into());
+            }
+        }
+
+        // 8. Return true.
+        Ok(true.into())
+    }
+
+    /// `Set.prototype.isDisjointFrom ( other )`
+    ///
+    /// This method returns `true` if none of the elements of the set are in the set-like
+    /// `other`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-set-methods/#sec-set.prototype.isdisjointfrom
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/isDisjointFrom
+    pub(crate) fn is_disjoint_from(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[SetData]]).
+        let this_size = Self::get_size(this)?;
+
+        // 3. Let otherRec be ? GetSetRecord(other).
+        let other_rec = SetRecord::new(args.get_or_undefined(0), context)?;
+
+        // 4. If SetDataSize(O.[[SetData]]) ≤ otherRec.[[Size]], then
+        if other_rec.cmp_size(this_size).is_le() {
+            // a. Let thisSize be the number of elements in O.[[SetData]].
+            // b. Let index be 0.
+            // c. Repeat, while index < thisSize,
+            //     i. Let e be O.[[SetData]][index].
+            //     ii. Set index to index + 1.
+            //     iii. If e is not empty, then
+            //         ...
+            //         4. Set thisSize to the number of elements in O.[[SetData]].
+            let mut index = 0;
+            while let Some(e) = Self::get_set_element(this, index)? {
+                index += 1;
+
+                // 1. Let inOther be ToBoolean(? Call(otherRec.[[Has]], otherRec.[[SetObject]], « e »)).
+                // 2. If inOther is true, return false.
+                if other_rec.has(&e, context)? {
+                    return Ok(false.into());
+                }
+            }
+        } else {
+            // 5. Else,
+            //     a. Let keysIter be ? GetKeysIterator(otherRec).
+            let keys_iter = other_rec.keys_iterator(context)?;
+
+            //     b. Let next be true.
+            //     c. Repeat, while next is not false,
+            //         i. Set next to ? IteratorStep(keysIter).
+            while let Some(next) = keys_iter.step(context)? {
+                // ii. If next is not false, then
+                //     1. Let nextValue be ? IteratorValue(next).
+                let next_value = next.value(context)?;
+
+                //     2. If SetDataHas(O.[[SetData]], nextValue) is true, then
+                if Self::set_data_has(this, &next_value)? {
+                    // a. Perform ? IteratorClose(keysIter, NormalCompletion(unused)).
+                    keys_iter.close(Ok(JsValue::undefined()), context)?;
+
+                    // b. Return false.
+                    return Ok(false.into());
+                }
+            }
+        }
+
+        // 6. Return true.
+        Ok(true.into())
+    }
+
+    fn size_getter(this: &JsValue, _: &[JsValue], _context: &mut Context) -> JsResult<JsValue> {
+        Self::get_size(this).map(JsValue::from)
     }
 
     /// Helper function to get the size of the `Set` object.
-    pub(crate) fn get_size(set: &JsValue, context: &mut Context) -> JsResult<usize> {
+    pub(crate) fn get_size(set: &JsValue) -> JsResult<usize> {
         set.as_object()
             .and_then(|obj| obj.borrow().as_set_ref().map(OrderedSet::size))
-            .ok_or_else(|| context.construct_type_error("'this' is not a Set"))
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("'this' is not a Set")
+                    .into()
+            })
+    }
+
+    /// Helper function to get a copy of the `[[SetData]]` of the `Set` object.
+    fn this_set_data(set: &JsValue) -> JsResult<OrderedSet<JsValue>> {
+        set.as_object()
+            .and_then(|obj| obj.borrow().as_set_ref().cloned())
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("'this' is not a Set")
+                    .into()
+            })
+    }
+
+    /// Helper function to get the element at `index` of the `[[SetData]]` of the `Set` object.
+    fn get_set_element(set: &JsValue, index: usize) -> JsResult<Option<JsValue>> {
+        set.as_object()
+            .and_then(|obj| {
+                obj.borrow()
+                    .as_set_ref()
+                    .map(|set| set.get_index(index).cloned())
+            })
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("'this' is not a Set")
+                    .into()
+            })
+    }
+
+    /// `SetDataHas ( setData, value )`, for the `[[SetData]]` of the `Set` object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-set-methods/#sec-setdatahas
+    fn set_data_has(set: &JsValue, value: &JsValue) -> JsResult<bool> {
+        set.as_object()
+            .and_then(|obj| obj.borrow().as_set_ref().map(|set| set.contains(value)))
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("'this' is not a Set")
+                    .into()
+            })
+    }
+
+    /// `CanonicalizeKeyedCollectionKey ( key )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-canonicalizekeyedcollectionkey
+    fn canonicalize_keyed_collection_key(key: JsValue) -> JsValue {
+        // 1. If key is -0𝔽, return +0𝔽.
+        if key.as_number().map_or(false, |n| n == -0f64) {
+            return JsValue::Integer(0);
+        }
+
+        // 2. Return key.
+        key
+    }
+
+    /// Creates a new `Set` object with `%Set.prototype%` as its prototype and `set_data` as its
+    /// `[[SetData]]`.
+    fn create_set_from_data(set_data: OrderedSet<JsValue>, context: &mut Context) -> JsObject {
+        JsObject::from_proto_and_data(
+            context.intrinsics().constructors().set().prototype(),
+            ObjectData::set(set_data),
+        )
+    }
+}
+
+/// A `Set Record`, with the parts of a set-like object used by the methods of `Set.prototype`
+/// that take one.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/proposal-set-methods/#sec-set-records
+#[derive(Debug)]
+struct SetRecord {
+    /// `[[SetObject]]`
+    set_object: JsObject,
+
+    /// `[[Size]]`
+    size: IntegerOrInfinity,
+
+    /// `[[Has]]`
+    has: JsObject,
+
+    /// `[[Keys]]`
+    keys: JsObject,
+}
+
+impl SetRecord {
+    /// `GetSetRecord ( obj )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-set-methods/#sec-getsetrecord
+    fn new(obj: &JsValue, context: &mut Context) -> JsResult<Self> {
+        // 1. If obj is not an Object, throw a TypeError exception.
+        let obj = obj.as_object().ok_or_else(|| {
+            JsNativeError::typ().with_message("set-like argument is not an object")
+        })?;
+
+        // 2. Let rawSize be ? Get(obj, "size").
+        let raw_size = obj.get("size", context)?;
+
+        // 3. Let numSize be ? ToNumber(rawSize).
+        // 4. NOTE: If rawSize is undefined, then numSize will be NaN.
+        let num_size = raw_size.to_number(context)?;
+
+        // 5. If numSize is NaN, throw a TypeError exception.
+        if num_size.is_nan() {
+            return context.throw_type_error("'size' of set-like argument is not a number");
+        }
+
+        // 6. Let intSize be ! ToIntegerOrInfinity(numSize).
+        let int_size = JsValue::new(num_size)
+            .to_integer_or_infinity(context)
+            .expect("ToIntegerOrInfinity cannot fail for a number");
+
+        // 7. If intSize < 0, throw a RangeError exception.
+        if int_size < 0 {
+            return context.throw_range_error("'size' of set-like argument is negative");
+        }
+
+        // 8. Let has be ? Get(obj, "has").
+        // 9. If IsCallable(has) is false, throw a TypeError exception.
+        let has = obj
+            .get("has", context)?
+            .as_callable()
+            .cloned()
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message("'has' of set-like argument is not a function")
+            })?;
+
+        // 10. Let keys be ? Get(obj, "keys").
+        // 11. If IsCallable(keys) is false, throw a TypeError exception.
+        let keys = obj
+            .get("keys", context)?
+            .as_callable()
+            .cloned()
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message("'keys' of set-like argument is not a function")
+            })?;
+
+        // 12. Return a new Set Record { [[SetObject]]: obj, [[Size]]: intSize, [[Has]]: has, [[Keys]]: keys }.
+        Ok(Self {
+            set_object: obj.clone(),
+            size: int_size,
+            has,
+            keys,
+        })
+    }
+
+    /// Compares `size` with the `[[Size]]` of the set-like object.
+    fn cmp_size(&self, size: usize) -> Ordering {
+        match self.size {
+            IntegerOrInfinity::Integer(other) => {
+                size.cmp(&usize::try_from(other).unwrap_or(usize::MAX))
+            }
+            IntegerOrInfinity::PositiveInfinity => Ordering::Less,
+            IntegerOrInfinity::NegativeInfinity => Ordering::Greater,
+        }
+    }
+
+    /// Calls the `[[Has]]` method of the set-like object with `value`.
+    fn has(&self, value: &JsValue, context: &mut Context) -> JsResult<bool> {
+        self.has
+            .call(
+                &self.set_object.clone().into(),
+                std::slice::from_ref(value),
+                context,
+            )
+            .map(|result| result.to_boolean())
+    }
+
+    /// `GetKeysIterator ( setRec )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-set-methods/#sec-getkeysiterator
+    fn keys_iterator(&self, context: &mut Context) -> JsResult<IteratorRecord> {
+        // 1. Let keysIter be ? Call(setRec.[[Keys]], setRec.[[SetObject]]).
+        let keys_iter = self
+            .keys
+            .call(&self.set_object.clone().into(), &[], context)?;
+
+        // 2. If keysIter is not an Object, throw a TypeError exception.
+        let keys_iter = keys_iter.as_object().cloned().ok_or_else(|| {
+            JsNativeError::typ().with_message("'keys' of set-like argument returned a non-object")
+        })?;
+
+        // 3. Let nextMethod be ? Get(keysIter, "next").
+        let next_method = keys_iter.get("next", context)?;
+
+        // 4. If IsCallable(nextMethod) is false, throw a TypeError exception.
+        if !next_method.is_callable() {
+            return context.throw_type_error("'next' of the keys iterator is not a function");
+        }
+
+        // 5. Return a new Iterator Record { [[Iterator]]: keysIter, [[NextMethod]]: nextMethod, [[Done]]: false }.
+        Ok(IteratorRecord::new(keys_iter, next_method, false))
     }
 }
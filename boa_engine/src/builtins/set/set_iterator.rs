@@ -1,9 +1,11 @@
 use crate::{
-    builtins::{function::make_builtin_fn, iterable::create_iter_result_object, Array, JsValue},
+    builtins::{
+        function::make_builtin_fn, iterable::create_iter_result_object_or_throw, Array, JsValue,
+    },
     object::{JsObject, ObjectData},
     property::{PropertyDescriptor, PropertyNameKind},
     symbol::WellKnownSymbols,
-    Context, JsResult,
+    Context, JsNativeError, JsResult,
 };
 use boa_gc::{Finalize, Trace};
 use boa_profiler::Profiler;
@@ -72,25 +74,21 @@ impl SetIterator {
         let set_iterator = set_iterator
             .as_mut()
             .and_then(|obj| obj.as_set_iterator_mut())
-            .ok_or_else(|| context.construct_type_error("`this` is not an SetIterator"))?;
+            .ok_or_else(|| JsNativeError::typ().with_message("`this` is not an SetIterator"))?;
         {
             let m = &set_iterator.iterated_set;
             let mut index = set_iterator.next_index;
             let item_kind = &set_iterator.iteration_kind;
 
             if set_iterator.iterated_set.is_undefined() {
-                return Ok(create_iter_result_object(
-                    JsValue::undefined(),
-                    true,
-                    context,
-                ));
+                return create_iter_result_object_or_throw(JsValue::undefined(), true, context);
             }
 
             let entries = m.as_object().map(JsObject::borrow);
             let entries = entries
                 .as_ref()
                 .and_then(|obj| obj.as_set_ref())
-                .ok_or_else(|| context.construct_type_error("'this' is not a Set"))?;
+                .ok_or_else(|| JsNativeError::typ().with_message("'this' is not a Set"))?;
 
             let num_entries = entries.size();
             while index < num_entries {
@@ -100,14 +98,22 @@ impl SetIterator {
                 if let Some(value) = e {
                     match item_kind {
                         PropertyNameKind::Value => {
-                            return Ok(create_iter_result_object(value.clone(), false, context));
+                            return create_iter_result_object_or_throw(
+                                value.clone(),
+                                false,
+                                context,
+                            );
                         }
                         PropertyNameKind::KeyAndValue => {
                             let result = Array::create_array_from_list(
                                 [value.clone(), value.clone()],
                                 context,
+                            )?;
+                            return create_iter_result_object_or_throw(
+                                result.into(),
+                                false,
+                                context,
                             );
-                            return Ok(create_iter_result_object(result.into(), false, context));
                         }
                         PropertyNameKind::Key => {
                             panic!("tried to collect only keys of Set")
@@ -118,11 +124,7 @@ impl SetIterator {
         }
 
         set_iterator.iterated_set = JsValue::undefined();
-        Ok(create_iter_result_object(
-            JsValue::undefined(),
-            true,
-            context,
-        ))
+        create_iter_result_object_or_throw(JsValue::undefined(), true, context)
     }
 
     /// Create the `%SetIteratorPrototype%` object
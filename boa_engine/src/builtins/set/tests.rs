@@ -246,3 +246,179 @@ fn not_a_function() {
         "\"TypeError: calling a builtin Set constructor without new is forbidden\""
     );
 }
+
+#[test]
+fn set_methods() {
+    let mut context = Context::default();
+    let init = r"
+        var join = (set) => [...set].join();
+        var a = new Set([1, 2, 3, 4]);
+        var b = new Set([3, 4, 5, -0]);
+    ";
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "join(a.union(b))"), "\"1,2,3,4,5,0\"");
+    assert_eq!(forward(&mut context, "join(a.intersection(b))"), "\"3,4\"");
+    assert_eq!(forward(&mut context, "join(a.difference(b))"), "\"1,2\"");
+    assert_eq!(
+        forward(&mut context, "join(a.symmetricDifference(b))"),
+        "\"1,2,5,0\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.is([...new Set([1]).union(new Set([-0]))][1], 0)"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "new Set([1, 2]).isSubsetOf(a)"),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "a.isSubsetOf(new Set([1]))"), "false");
+    assert_eq!(
+        forward(&mut context, "a.isSupersetOf(new Set([1, 2]))"),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "a.isDisjointFrom(b)"), "false");
+    assert_eq!(
+        forward(&mut context, "a.isDisjointFrom(new Set([9]))"),
+        "true"
+    );
+}
+
+#[test]
+fn set_methods_set_like() {
+    let mut context = Context::default();
+    let init = r#"
+        var join = (set) => [...set].join();
+        var a = new Set([1, 2, 3, 4]);
+        var map = new Map([[2, "x"], [7, "y"]]);
+        var mapKeys = { size: map.size, has: (k) => map.has(k), keys: () => map.keys() };
+    "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "join(a.union(mapKeys))"),
+        "\"1,2,3,4,7\""
+    );
+    assert_eq!(
+        forward(&mut context, "join(a.intersection(mapKeys))"),
+        "\"2\""
+    );
+    assert_eq!(
+        forward(&mut context, "join(a.difference(mapKeys))"),
+        "\"1,3,4\""
+    );
+    assert_eq!(
+        forward(&mut context, "join(a.symmetricDifference(mapKeys))"),
+        "\"1,3,4,7\""
+    );
+    assert_eq!(
+        forward(&mut context, "new Set([2]).isSubsetOf(mapKeys)"),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "a.isSupersetOf(mapKeys)"), "false");
+    assert_eq!(forward(&mut context, "a.isDisjointFrom(mapKeys)"), "false");
+
+    // The receiver's own `add` and `has` are never called.
+    let init = r#"
+        var c = new Set([1, 2]);
+        c.add = () => { throw "add"; };
+        c.has = () => { throw "has"; };
+    "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "join(c.union(new Set([3])))"),
+        "\"1,2,3\""
+    );
+    assert_eq!(
+        forward(&mut context, "join(c.intersection(new Set([2, 5, 6])))"),
+        "\"2\""
+    );
+}
+
+#[test]
+fn set_methods_iterate_smaller_side() {
+    let mut context = Context::default();
+    let init = r#"
+        var a = new Set([1, 2, 3, 4]);
+        var log = [];
+        var big = {
+            size: 100,
+            has(v) { log.push("has " + v); return v % 2 == 0; },
+            keys() { log.push("keys"); return [].values(); }
+        };
+        var small = {
+            size: 1,
+            has(v) { log.push("has " + v); return true; },
+            keys() { log.push("keys"); return [4, 1, 4, 9].values(); }
+        };
+        var infinite = {
+            size: 1,
+            has() {},
+            keys() {
+                return {
+                    next() { log.push("next"); return { value: 9, done: false }; },
+                    return() { log.push("return"); return {}; }
+                };
+            }
+        };
+    "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(
+            &mut context,
+            "[...a.intersection(big)].join() + ': ' + log.join()"
+        ),
+        "\"2,4: has 1,has 2,has 3,has 4\""
+    );
+    forward(&mut context, "log = []");
+    assert_eq!(
+        forward(
+            &mut context,
+            "[...a.intersection(small)].join() + ': ' + log.join()"
+        ),
+        "\"4,1: keys\""
+    );
+    forward(&mut context, "log = []");
+    assert_eq!(
+        forward(&mut context, "a.isSupersetOf(infinite) + ': ' + log.join()"),
+        "\"false: next,return\""
+    );
+}
+
+#[test]
+fn set_methods_validate_set_like() {
+    let mut context = Context::default();
+    let init = r"
+        function error(other) {
+            try {
+                new Set().union(other);
+            } catch (e) {
+                return e.constructor.name;
+            }
+        }
+    ";
+    forward(&mut context, init);
+    for (other, error) in [
+        ("1", "TypeError"),
+        ("{}", "TypeError"),
+        ("{ size: NaN, has() {}, keys() {} }", "TypeError"),
+        ("{ size: undefined, has() {}, keys() {} }", "TypeError"),
+        ("{ size: -1, has() {}, keys() {} }", "RangeError"),
+        ("{ size: 1, has: 1, keys() {} }", "TypeError"),
+        ("{ size: 1, has() {}, keys: 1 }", "TypeError"),
+        ("{ size: 1, has() {}, keys() { return 1; } }", "TypeError"),
+    ] {
+        assert_eq!(
+            forward(&mut context, format!("error({other})")),
+            format!("\"{error}\"")
+        );
+    }
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Set.prototype.union.call({}, new Set()) } catch (e) { e.constructor.name }"
+        ),
+        "\"TypeError\""
+    );
+}
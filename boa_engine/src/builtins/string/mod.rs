@@ -23,7 +23,7 @@ use crate::{
     property::{Attribute, PropertyDescriptor},
     symbol::WellKnownSymbols,
     value::IntegerOrInfinity,
-    Context, JsResult, JsString, JsValue,
+    Context, JsNativeError, JsResult, JsString, JsValue,
 };
 use boa_profiler::Profiler;
 use std::{
@@ -195,7 +195,7 @@ impl String {
 
         let prototype =
             get_prototype_from_constructor(new_target, StandardConstructors::string, context)?;
-        Ok(Self::string_create(string, prototype, context).into())
+        Ok(Self::string_create(string, prototype).into())
     }
 
     /// Abstract function `StringCreate( value, prototype )`.
@@ -206,7 +206,7 @@ impl String {
     ///  - [ECMAScript reference][spec]
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-stringcreate
-    fn string_create(value: JsString, prototype: JsObject, context: &mut Context) -> JsObject {
+    fn string_create(value: JsString, prototype: JsObject) -> JsObject {
         // 7. Let length be the number of code unit elements in value.
         let len = value.encode_utf16().count();
 
@@ -220,16 +220,16 @@ impl String {
 
         // 8. Perform ! DefinePropertyOrThrow(S, "length", PropertyDescriptor { [[Value]]: 𝔽(length),
         // [[Writable]]: false, [[Enumerable]]: false, [[Configurable]]: false }).
-        s.define_property_or_throw(
+        // NOTE: The property is inserted directly, since the `length` of a new string object isn't
+        // counted against the maximum property count.
+        s.insert(
             "length",
             PropertyDescriptor::builder()
                 .value(len)
                 .writable(false)
                 .enumerable(false)
                 .configurable(false),
-            context,
-        )
-        .expect("length definition for a new string must not fail");
+        );
 
         // 9. Return S.
         s
@@ -241,7 +241,7 @@ impl String {
     /// - [ECMAScript reference][spec]
     ///
     /// [spec]: https://tc39.es/ecma262/#thisstringvalue
-    fn this_string_value(this: &JsValue, context: &mut Context) -> JsResult<JsString> {
+    fn this_string_value(this: &JsValue) -> JsResult<JsString> {
         // 1. If Type(value) is String, return value.
         this.as_string()
             .cloned()
@@ -251,7 +251,11 @@ impl String {
             //     c. Return s.
             .or_else(|| this.as_object().and_then(|obj| obj.borrow().as_string()))
             // 3. Throw a TypeError exception.
-            .ok_or_else(|| context.construct_type_error("'this' is not a string"))
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("'this' is not a string")
+                    .into()
+            })
     }
 
     /// `String.fromCodePoint(num1[, ...[, numN]])`
@@ -270,7 +274,7 @@ impl String {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let result be the empty String.
-        let mut result = StdString::new();
+        let mut result = Vec::new();
 
         // 2. For each element next of codePoints, do
         for arg in args.iter() {
@@ -279,19 +283,31 @@ impl String {
 
             // b. If ! IsIntegralNumber(nextCP) is false, throw a RangeError exception.
             if !Number::is_float_integer(nextcp) {
-                return Err(context.construct_range_error(format!("invalid code point: {nextcp}")));
+                return Err(JsNativeError::range()
+                    .with_message(format!("invalid code point: {nextcp}"))
+                    .into());
             }
 
             // c. If ℝ(nextCP) < 0 or ℝ(nextCP) > 0x10FFFF, throw a RangeError exception.
             if nextcp < 0.0 || nextcp > f64::from(0x10FFFF) {
-                return Err(context.construct_range_error(format!("invalid code point: {nextcp}")));
+                return Err(JsNativeError::range()
+                    .with_message(format!("invalid code point: {nextcp}"))
+                    .into());
             }
 
-            // TODO: Full UTF-16 support
             // d. Set result to the string-concatenation of result and ! UTF16EncodeCodePoint(ℝ(nextCP)).
-            result.push(char::try_from(nextcp as u32).unwrap_or('\u{FFFD}' /* replacement char */));
+            let nextcp = nextcp as u32;
+            if let Some(c) = from_u32(nextcp) {
+                result.extend_from_slice(c.encode_utf16(&mut [0; 2]));
+            } else {
+                // Surrogate code points aren't `char`s, but they are a single code unit.
+                result.push(nextcp as u16);
+            }
         }
 
+        let result = JsString::from_utf16(&result);
+        context.resource_limits().check_string_length(&[&result])?;
+
         // 3. Assert: If codePoints is empty, then result is the empty String.
         // 4. Return result.
         Ok(result.into())
@@ -312,7 +328,7 @@ impl String {
         let number_of_substitutions = substitutions.len() as u64;
 
         // 2. Let cooked be ? ToObject(template).
-        let cooked = args.get_or_undefined(0).to_object(context)?;
+        let cooked = args.to_object(0, "String.raw", context)?;
 
         // 3. Let raw be ? ToObject(? Get(cooked, "raw")).
         let raw = cooked.get("raw", context)?.to_object(context)?;
@@ -342,11 +358,18 @@ impl String {
             // c. Append the code unit elements of nextSeg to the end of stringElements.
             string_elements.extend(next_seg.encode_utf16());
 
+            let max_length = context.resource_limits().max_string_length();
+            if string_elements.len() > max_length {
+                return context.throw_range_error(format!(
+                    "string length exceeds the maximum string length ({max_length})"
+                ));
+            }
+
             // d. If nextIndex + 1 = literalSegments, then
             if next_index + 1 == literal_segments {
                 // i. Return the String value whose code units are the elements in the List stringElements.
                 //    If stringElements has no elements, the empty String is returned.
-                return Ok(StdString::from_utf16_lossy(&string_elements).into());
+                return Ok(JsString::from_utf16(&string_elements).into());
             }
 
             // e. If nextIndex < numberOfSubstitutions, let next be substitutions[nextIndex].
@@ -394,8 +417,9 @@ impl String {
         // 4. Return the String value whose code units are the elements in the List elements.
         //    If codeUnits is empty, the empty String is returned.
 
-        let s = std::string::String::from_utf16_lossy(elements.as_slice());
-        Ok(JsValue::String(JsString::new(s)))
+        let s = JsString::from_utf16(&elements);
+        context.resource_limits().check_string_length(&[&s])?;
+        Ok(JsValue::String(s))
     }
 
     /// `String.prototype.toString ( )`
@@ -409,10 +433,10 @@ impl String {
     pub(crate) fn to_string(
         this: &JsValue,
         _: &[JsValue],
-        context: &mut Context,
+        _context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Return ? thisStringValue(this value).
-        Ok(Self::this_string_value(this, context)?.into())
+        Ok(Self::this_string_value(this)?.into())
     }
 
     /// `String.prototype.charAt( index )`
@@ -454,9 +478,7 @@ impl String {
                     .nth(position as usize)
                     .expect("Already checked bounds above");
 
-                Ok(char::try_from(u32::from(char))
-                    .unwrap_or('\u{FFFD}' /* replacement char */)
-                    .into())
+                Ok(JsString::from_utf16(&[char]).into())
             }
             _ => {
                 // 5. If position < 0 or position ≥ size, return the empty String.
@@ -501,9 +523,7 @@ impl String {
 
         // 8. Return the substring of S from k to k + 1.
         if let Some(utf16_val) = s.encode_utf16().nth(k) {
-            Ok(JsValue::new(
-                from_u32(u32::from(utf16_val)).expect("invalid utf-16 character"),
-            ))
+            Ok(JsString::from_utf16(&[utf16_val]).into())
         } else {
             Ok(JsValue::undefined())
         }
@@ -621,14 +641,18 @@ impl String {
         let this = this.require_object_coercible(context)?;
 
         // 2. Let S be ? ToString(O).
-        let mut string = this.to_string(context)?.to_string();
+        let mut string = this.to_string(context)?;
 
         // 3. Let R be S.
         // 4. For each element next of args, do
         for arg in args {
             // a. Let nextString be ? ToString(next).
+            let next_string = arg.to_string(context)?;
             // b. Set R to the string-concatenation of R and nextString.
-            string.push_str(&arg.to_string(context)?);
+            context
+                .resource_limits()
+                .check_string_length(&[&string, &next_string])?;
+            string = JsString::concat_code_units(&[&string, &next_string]);
         }
 
         // 5. Return R.
@@ -659,15 +683,23 @@ impl String {
 
         let len = string.encode_utf16().count();
 
+        let max_length = context.resource_limits().max_string_length();
+
         // 3. Let n be ? ToIntegerOrInfinity(count).
         match args.get_or_undefined(0).to_integer_or_infinity(context)? {
             IntegerOrInfinity::Integer(n)
-                if n > 0 && (n as usize) * len <= Self::MAX_STRING_LENGTH =>
+                if n > 0 && (n as usize).saturating_mul(len) <= max_length =>
             {
                 if string.is_empty() {
                     return Ok("".into());
                 }
                 let n = n as usize;
+                if string.has_lone_surrogates() {
+                    // 6. Return the String value that is made from n copies of S appended together.
+                    let units = string.encode_utf16().collect::<Vec<_>>();
+                    return Ok(JsString::from_utf16(&units.repeat(n)).into());
+                }
+
                 let mut result = std::string::String::with_capacity(n * len);
 
                 std::iter::repeat(&string[..])
@@ -750,8 +782,7 @@ impl String {
             // 13. Return the substring of S from from to to.
             let span = to - from;
             let substring_utf16: Vec<u16> = string.encode_utf16().skip(from).take(span).collect();
-            let substring_lossy = StdString::from_utf16_lossy(&substring_utf16);
-            Ok(substring_lossy.into())
+            Ok(JsString::from_utf16(&substring_utf16).into())
         }
     }
 
@@ -999,10 +1030,15 @@ impl String {
             .unwrap_or_default();
 
         // 6. If functionalReplace is false, then
-        // a. Set replaceValue to ? ToString(replaceValue).
+        let replace_value_string = if functional_replace {
+            None
+        } else {
+            // a. Set replaceValue to ? ToString(replaceValue).
+            Some(replace_value.to_string(context)?)
+        };
 
         // 7. Let searchLength be the length of searchString.
-        let search_length = search_str.len();
+        let search_length = search_str.encode_utf16().count();
 
         // 8. Let position be ! StringIndexOf(string, searchString, 0).
         // 9. If position is -1, return string.
@@ -1013,49 +1049,49 @@ impl String {
         };
 
         // 10. Let preserved be the substring of string from 0 to position.
-        let preserved = StdString::from_utf16_lossy(
-            &this_str.encode_utf16().take(position).collect::<Vec<u16>>(),
-        );
+        let preserved =
+            JsString::from_utf16(&this_str.encode_utf16().take(position).collect::<Vec<u16>>());
 
-        // 11. If functionalReplace is true, then
         // 12. Else,
-        let replacement = if functional_replace {
-            // a. Let replacement be ? ToString(? Call(replaceValue, undefined, « searchString, 𝔽(position), string »)).
-            context
-                .call(
-                    replace_value,
-                    &JsValue::undefined(),
-                    &[search_str.into(), position.into(), this_str.clone().into()],
-                )?
-                .to_string(context)?
-        } else {
+        let replacement = if let Some(ref replace_value) = replace_value_string {
             // a. Assert: Type(replaceValue) is String.
             // b. Let captures be a new empty List.
-            let captures = Vec::new();
-
             // c. Let replacement be ! GetSubstitution(searchString, string, position, captures, undefined, replaceValue).
             get_substitution(
-                search_str.as_str(),
-                this_str.as_str(),
+                &search_str,
+                &this_str,
                 position,
-                &captures,
+                &[],
                 &JsValue::undefined(),
-                &replace_value.to_string(context)?,
+                replace_value,
                 context,
             )?
+        }
+        // 11. If functionalReplace is true, then
+        else {
+            // a. Let replacement be ? ToString(? Call(replaceValue, undefined, « searchString, 𝔽(position), string »)).
+            context
+                .call(
+                    replace_value,
+                    &JsValue::undefined(),
+                    &[search_str.into(), position.into(), this_str.clone().into()],
+                )?
+                .to_string(context)?
         };
 
         // 13. Return the string-concatenation of preserved, replacement, and the substring of string from position + searchLength.
-        Ok(format!(
-            "{preserved}{replacement}{}",
-            StdString::from_utf16_lossy(
-                &this_str
-                    .encode_utf16()
-                    .skip(position + search_length)
-                    .collect::<Vec<u16>>()
-            )
-        )
-        .into())
+        let rest = JsString::from_utf16(
+            &this_str
+                .encode_utf16()
+                .skip(position + search_length)
+                .collect::<Vec<u16>>(),
+        );
+        context.resource_limits().check_string_length(&[
+            preserved.as_str(),
+            replacement.as_str(),
+            rest.as_str(),
+        ])?;
+        Ok(JsString::concat_code_units(&[&preserved, &replacement, &rest]).into())
     }
 
     /// `22.1.3.18 String.prototype.replaceAll ( searchValue, replaceValue )`
@@ -1166,9 +1202,8 @@ impl String {
         // 14. For each element p of matchPositions, do
         for p in match_positions {
             // a. Let preserved be the substring of string from endOfLastMatch to p.
-            let preserved = StdString::from_utf16_lossy(
+            let preserved = JsString::from_utf16(
                 &string
-                    .clone()
                     .encode_utf16()
                     .skip(end_of_last_match)
                     .take(p - end_of_last_match)
@@ -1188,8 +1223,7 @@ impl String {
                     &JsValue::undefined(),
                     replace_value,
                     context,
-                )
-                .expect("GetSubstitution should never fail here.")
+                )?
             }
             // b. If functionalReplace is true, then
             else {
@@ -1208,7 +1242,12 @@ impl String {
             };
 
             // d. Set result to the string-concatenation of result, preserved, and replacement.
-            result = JsString::new(format!("{}{preserved}{replacement}", result.as_str()));
+            context.resource_limits().check_string_length(&[
+                result.as_str(),
+                preserved.as_str(),
+                replacement.as_str(),
+            ])?;
+            result = JsString::concat_code_units(&[&result, &preserved, &replacement]);
 
             // e. Set endOfLastMatch to p + searchLength.
             end_of_last_match = p + search_length;
@@ -1217,16 +1256,16 @@ impl String {
         // 15. If endOfLastMatch < the length of string, then
         if end_of_last_match < string.encode_utf16().count() {
             // a. Set result to the string-concatenation of result and the substring of string from endOfLastMatch.
-            result = JsString::new(format!(
-                "{}{}",
-                result.as_str(),
-                &StdString::from_utf16_lossy(
-                    &string
-                        .encode_utf16()
-                        .skip(end_of_last_match)
-                        .collect::<Vec<u16>>()
-                )
-            ));
+            let rest = JsString::from_utf16(
+                &string
+                    .encode_utf16()
+                    .skip(end_of_last_match)
+                    .collect::<Vec<u16>>(),
+            );
+            context
+                .resource_limits()
+                .check_string_length(&[result.as_str(), rest.as_str()])?;
+            result = JsString::concat_code_units(&[&result, &rest]);
         }
 
         // 16. Return result.
@@ -1306,7 +1345,7 @@ impl String {
 
         // 4. Let numPos be ? ToNumber(position).
         // 5. Assert: If position is undefined, then numPos is NaN.
-        let num_pos = args.get_or_undefined(1).to_number(context)?;
+        let num_pos = args.to_number(1, context)?;
 
         // 6. If numPos is NaN, let pos be +∞; otherwise, let pos be ! ToIntegerOrInfinity(numPos).
         let pos = if num_pos.is_nan() {
@@ -1331,14 +1370,16 @@ impl String {
         let search_len = search_str.encode_utf16().count();
 
         // 11. For each non-negative integer i starting with start such that i ≤ len - searchLen, in descending order, do
-        // a. Let candidate be the substring of S from i to i + searchLen.
-        let substring_utf16: Vec<u16> = string.encode_utf16().take(start + search_len).collect();
-        let substring_lossy = StdString::from_utf16_lossy(&substring_utf16);
-        if let Some(position) = substring_lossy.rfind(search_str.as_str()) {
-            // b. If candidate is the same sequence of code units as searchStr, return 𝔽(i).
-            return Ok(JsValue::new(
-                substring_lossy[..position].encode_utf16().count(),
-            ));
+        if let Some(last) = len.checked_sub(search_len) {
+            let string = string.encode_utf16().collect::<Vec<u16>>();
+            let search_str = search_str.encode_utf16().collect::<Vec<u16>>();
+            for i in (0..=min(start, last)).rev() {
+                // a. Let candidate be the substring of S from i to i + searchLen.
+                // b. If candidate is the same sequence of code units as searchStr, return 𝔽(i).
+                if string[i..i + search_len] == search_str[..] {
+                    return Ok(JsValue::new(i));
+                }
+            }
         }
 
         // 12. Return -1𝔽.
@@ -1415,6 +1456,13 @@ impl String {
             return Ok(string.into());
         }
 
+        let max_string_length = context.resource_limits().max_string_length();
+        if int_max_length > max_string_length as u64 {
+            return context.throw_range_error(format!(
+                "padded string length must not exceed the maximum string length ({max_string_length})"
+            ));
+        }
+
         // 5. If fillString is undefined, let filler be the String value consisting solely of the code unit 0x0020 (SPACE).
         let filler = if fill_string.is_undefined() {
             "\u{0020}".into()
@@ -1430,34 +1478,22 @@ impl String {
 
         // 8. Let fillLen be intMaxLength - stringLength.
         let fill_len = int_max_length - string_length;
-        let filler_len = filler.encode_utf16().count() as u64;
 
         // 9. Let truncatedStringFiller be the String value consisting of repeated
         // concatenations of filler truncated to length fillLen.
-        let repetitions = {
-            let q = fill_len / filler_len;
-            let r = fill_len % filler_len;
-            if r == 0 {
-                q
-            } else {
-                q + 1
-            }
-        };
-
         let truncated_string_filler = filler
-            .repeat(repetitions as usize)
             .encode_utf16()
+            .cycle()
             .take(fill_len as usize)
             .collect::<Vec<_>>();
-        let truncated_string_filler =
-            std::string::String::from_utf16_lossy(truncated_string_filler.as_slice());
+        let truncated_string_filler = JsString::from_utf16(&truncated_string_filler);
 
         // 10. If placement is start, return the string-concatenation of truncatedStringFiller and S.
         if placement == Placement::Start {
-            Ok(format!("{truncated_string_filler}{string}").into())
+            Ok(JsString::concat_code_units(&[&truncated_string_filler, &string]).into())
         } else {
             // 11. Else, return the string-concatenation of S and truncatedStringFiller.
-            Ok(format!("{string}{truncated_string_filler}").into())
+            Ok(JsString::concat_code_units(&[&string, &truncated_string_filler]).into())
         }
     }
 
@@ -1606,9 +1642,14 @@ impl String {
         // 3. Let sText be ! StringToCodePoints(S).
         // 4. Let lowerText be the result of toLowercase(sText), according to
         // the Unicode Default Case Conversion algorithm.
+        let lower_text = string.to_lowercase();
+        context
+            .resource_limits()
+            .check_string_length(&[&lower_text])?;
+
         // 5. Let L be ! CodePointsToString(lowerText).
         // 6. Return L.
-        Ok(JsValue::new(string.to_lowercase()))
+        Ok(JsValue::new(lower_text))
     }
 
     /// `String.prototype.toUpperCase()`
@@ -1643,9 +1684,14 @@ impl String {
         // 3. Let sText be ! StringToCodePoints(S).
         // 4. Let upperText be the result of toUppercase(sText), according to
         // the Unicode Default Case Conversion algorithm.
+        let upper_text = string.to_uppercase();
+        context
+            .resource_limits()
+            .check_string_length(&[&upper_text])?;
+
         // 5. Let L be ! CodePointsToString(upperText).
         // 6. Return L.
-        Ok(JsValue::new(string.to_uppercase()))
+        Ok(JsValue::new(upper_text))
     }
 
     /// `String.prototype.substring( indexStart[, indexEnd] )`
@@ -1696,11 +1742,9 @@ impl String {
         // 10. Return the substring of S from from to to.
         // Extract the part of the string contained between the from index and the to index
         // where from is guaranteed to be smaller or equal to to
-        // TODO: Full UTF-16 support
         let substring_utf16: Vec<u16> = string.encode_utf16().skip(from).take(to - from).collect();
-        let substring = StdString::from_utf16_lossy(&substring_utf16);
 
-        Ok(substring.into())
+        Ok(JsString::from_utf16(&substring_utf16).into())
     }
 
     /// `String.prototype.substr( start[, length] )`
@@ -1764,9 +1808,8 @@ impl String {
             .skip(int_start)
             .take(int_end - int_start)
             .collect();
-        let substring = StdString::from_utf16_lossy(&substring_utf16);
 
-        Ok(substring.into())
+        Ok(JsString::from_utf16(&substring_utf16).into())
     }
 
     /// `String.prototype.split ( separator, limit )`
@@ -1829,8 +1872,7 @@ impl String {
         // 9. If separator is undefined, then
         if separator.is_undefined() {
             // a. Perform ! CreateDataPropertyOrThrow(A, "0", S).
-            a.create_data_property_or_throw(0, this_str, context)
-                .expect("this CreateDataPropertyOrThrow call must not fail");
+            a.create_data_property_or_throw(0, this_str, context)?;
 
             // b. Return A.
             return Ok(a.into());
@@ -1844,8 +1886,7 @@ impl String {
             // a. If R is not the empty String, then
             if !separator_str.is_empty() {
                 // i. Perform ! CreateDataPropertyOrThrow(A, "0", S).
-                a.create_data_property_or_throw(0, this_str, context)
-                    .expect("this CreateDataPropertyOrThrow call must not fail");
+                a.create_data_property_or_throw(0, this_str, context)?;
             }
 
             // b. Return A.
@@ -1874,7 +1915,7 @@ impl String {
                         q += 1;
                     } else {
                         // 1. Let T be the substring of S from p to q.
-                        let this_str_substring = StdString::from_utf16_lossy(
+                        let this_str_substring = JsString::from_utf16(
                             &this_str
                                 .encode_utf16()
                                 .skip(p)
@@ -1883,8 +1924,8 @@ impl String {
                         );
 
                         // 2. Perform ! CreateDataPropertyOrThrow(A, ! ToString(𝔽(lengthA)), T).
-                        a.create_data_property_or_throw(length_a, this_str_substring, context)
-                            .expect("this CreateDataPropertyOrThrow call must not fail");
+                        // This can only fail if the resource limits of the context are exceeded.
+                        a.create_data_property_or_throw(length_a, this_str_substring, context)?;
 
                         // 3. Set lengthA to lengthA + 1.
                         length_a += 1;
@@ -1905,7 +1946,7 @@ impl String {
         }
 
         // 15. Let T be the substring of S from p to s.
-        let this_str_substring = StdString::from_utf16_lossy(
+        let this_str_substring = JsString::from_utf16(
             &this_str
                 .encode_utf16()
                 .skip(p)
@@ -1914,8 +1955,8 @@ impl String {
         );
 
         // 16. Perform ! CreateDataPropertyOrThrow(A, ! ToString(𝔽(lengthA)), T).
-        a.create_data_property_or_throw(length_a, this_str_substring, context)
-            .expect("this CreateDataPropertyOrThrow call must not fail");
+        // This can only fail if the resource limits of the context are exceeded.
+        a.create_data_property_or_throw(length_a, this_str_substring, context)?;
 
         // 17. Return A.
         Ok(a.into())
@@ -1934,10 +1975,10 @@ impl String {
     pub(crate) fn value_of(
         this: &JsValue,
         _: &[JsValue],
-        context: &mut Context,
+        _context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Return ? thisStringValue(this value).
-        Self::this_string_value(this, context).map(JsValue::from)
+        Self::this_string_value(this).map(JsValue::from)
     }
 
     /// `String.prototype.matchAll( regexp )`
@@ -2034,16 +2075,22 @@ impl String {
         // 6. Let ns be the String value that is the result of normalizing S
         // into the normalization form named by f as specified in
         // https://unicode.org/reports/tr15/.
-        // 7. Return ns.
-        match f {
-            "NFC" => Ok(JsValue::new(s.nfc().collect::<StdString>())),
-            "NFD" => Ok(JsValue::new(s.nfd().collect::<StdString>())),
-            "NFKC" => Ok(JsValue::new(s.nfkc().collect::<StdString>())),
-            "NFKD" => Ok(JsValue::new(s.nfkd().collect::<StdString>())),
+        let ns = match f {
+            "NFC" => s.nfc().collect::<StdString>(),
+            "NFD" => s.nfd().collect::<StdString>(),
+            "NFKC" => s.nfkc().collect::<StdString>(),
+            "NFKD" => s.nfkd().collect::<StdString>(),
             // 5. If f is not one of "NFC", "NFD", "NFKC", or "NFKD", throw a RangeError exception.
-            _ => context
-                .throw_range_error("The normalization form should be one of NFC, NFD, NFKC, NFKD."),
-        }
+            _ => {
+                return context.throw_range_error(
+                    "The normalization form should be one of NFC, NFD, NFKC, NFKD.",
+                )
+            }
+        };
+        context.resource_limits().check_string_length(&[&ns])?;
+
+        // 7. Return ns.
+        Ok(ns.into())
     }
 
     /// `String.prototype.search( regexp )`
@@ -2086,12 +2133,30 @@ impl String {
         rx.invoke(WellKnownSymbols::search(), &[JsValue::new(string)], context)
     }
 
+    /// `String.prototype[ @@iterator ]( )`
+    ///
+    /// Returns an iterator over the code points of the string.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-string.prototype-@@iterator
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/@@iterator
     pub(crate) fn iterator(
         this: &JsValue,
         _: &[JsValue],
         context: &mut Context,
     ) -> JsResult<JsValue> {
-        StringIterator::create_string_iterator(this.clone(), context)
+        // 1. Let O be ? RequireObjectCoercible(this value).
+        let o = this.require_object_coercible(context)?;
+
+        // 2. Let s be ? ToString(O).
+        let s = o.to_string(context)?;
+
+        // 3. Let closure be a new Abstract Closure with no parameters that captures s and performs the following steps when called:
+        // 4. Return CreateIteratorFromClosure(closure, "%StringIteratorPrototype%", %StringIteratorPrototype%).
+        Ok(StringIterator::create_string_iterator(s, context))
     }
 }
 
@@ -2102,8 +2167,8 @@ impl String {
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-getsubstitution
 pub(crate) fn get_substitution(
-    matched: &str,
-    str: &str,
+    matched: &JsString,
+    str: &JsString,
     position: usize,
     captures: &[JsValue],
     named_captures: &JsValue,
@@ -2134,97 +2199,85 @@ pub(crate) fn get_substitution(
     //     from replacement to result while performing replacements as specified in Table 58.
     //     These $ replacements are done left-to-right, and, once such a replacement is performed,
     //     the new replacement text is not subject to further replacements.
-    let mut result = StdString::new();
-    let mut chars = replacement.chars().peekable();
-
-    while let Some(first) = chars.next() {
-        if first == '$' {
-            let second = chars.next();
-            let second_is_digit = second.as_ref().map_or(false, char::is_ascii_digit);
+    let digit = |unit: u16| {
+        from_u32(u32::from(unit))
+            .and_then(|c| c.to_digit(10))
+            .map(|digit| digit as usize)
+    };
+    let max_length = context.resource_limits().max_string_length();
+    let mut result = Vec::new();
+    let mut units = replacement.encode_utf16().peekable();
+
+    while let Some(first) = units.next() {
+        if first == u16::from(b'$') {
+            let second = units.next();
+            let second_digit = second.and_then(digit);
             // we use peek so that it is still in the iterator if not used
-            let third = if second_is_digit { chars.peek() } else { None };
-            let third_is_digit = third.map_or(false, char::is_ascii_digit);
+            let third_digit = if second_digit.is_some() {
+                units.peek().copied().and_then(digit)
+            } else {
+                None
+            };
 
-            match (second, third) {
+            match (second, second_digit, third_digit) {
                 // $$
-                (Some('$'), _) => {
+                (Some(0x0024 /* $ */), _, _) => {
                     // $
-                    result.push('$');
+                    result.push(first);
                 }
                 // $&
-                (Some('&'), _) => {
+                (Some(0x0026 /* & */), _, _) => {
                     // matched
-                    result.push_str(matched);
+                    result.extend(matched.encode_utf16());
                 }
                 // $`
-                (Some('`'), _) => {
+                (Some(0x0060 /* ` */), _, _) => {
                     // The replacement is the substring of str from 0 to position.
-                    result.push_str(&StdString::from_utf16_lossy(
-                        &str.encode_utf16().take(position).collect::<Vec<u16>>(),
-                    ));
+                    result.extend(str.encode_utf16().take(position));
                 }
                 // $'
-                (Some('\''), _) => {
+                (Some(0x0027 /* ' */), _, _) => {
                     // If tailPos ≥ stringLength, the replacement is the empty String.
                     // Otherwise the replacement is the substring of str from tailPos.
                     if tail_pos < str_length {
-                        result.push_str(&StdString::from_utf16_lossy(
-                            &str.encode_utf16().skip(tail_pos).collect::<Vec<u16>>(),
-                        ));
+                        result.extend(str.encode_utf16().skip(tail_pos));
                     }
                 }
                 // $nn
-                (Some(second), Some(third)) if second_is_digit && third_is_digit => {
+                (Some(second), Some(tens), Some(units_digit)) => {
                     // The nnth element of captures, where nn is a two-digit decimal number in the range 01 to 99.
-                    let tens = second
-                        .to_digit(10)
-                        .expect("could not convert character to digit after checking it")
-                        as usize;
-                    let units = third
-                        .to_digit(10)
-                        .expect("could not convert character to digit after checking it")
-                        as usize;
-                    let nn = 10 * tens + units;
+                    let nn = 10 * tens + units_digit;
+                    let third = units.next().expect("already peeked the third code unit");
 
                     // If nn ≤ m and the nnth element of captures is undefined, use the empty String instead.
                     // If nn is 00 or nn > m, no replacement is done.
                     if nn == 0 || nn > m {
-                        result.push('$');
-                        result.push(second);
-                        result.push(*third);
+                        result.extend([first, second, third]);
                     } else if let Some(capture) = captures.get(nn - 1) {
                         if let Some(s) = capture.as_string() {
-                            result.push_str(s);
+                            result.extend(s.encode_utf16());
                         }
                     }
-
-                    chars.next();
                 }
                 // $n
-                (Some(second), _) if second_is_digit => {
+                (Some(second), Some(n), None) => {
                     // The nth element of captures, where n is a single digit in the range 1 to 9.
-                    let n = second
-                        .to_digit(10)
-                        .expect("could not convert character to digit after checking it")
-                        as usize;
-
                     // If n ≤ m and the nth element of captures is undefined, use the empty String instead.
                     // If n > m, no replacement is done.
                     if n == 0 || n > m {
-                        result.push('$');
-                        result.push(second);
+                        result.extend([first, second]);
                     } else if let Some(capture) = captures.get(n - 1) {
                         if let Some(s) = capture.as_string() {
-                            result.push_str(s);
+                            result.extend(s.encode_utf16());
                         }
                     }
                 }
                 // $<
-                (Some('<'), _) => {
+                (Some(0x003C /* < */), _, _) => {
                     // 1. If namedCaptures is undefined, the replacement text is the String "$<".
                     // 2. Else,
                     if named_captures.is_undefined() {
-                        result.push_str("$<");
+                        result.extend("$<".encode_utf16());
                     } else {
                         // a. Assert: Type(namedCaptures) is Object.
                         let named_captures = named_captures
@@ -2232,15 +2285,15 @@ pub(crate) fn get_substitution(
                             .expect("should be an object according to spec");
 
                         // b. Scan until the next > U+003E (GREATER-THAN SIGN).
-                        let mut group_name = StdString::new();
+                        let mut group_name = Vec::new();
                         let mut found = false;
                         loop {
-                            match chars.next() {
-                                Some('>') => {
+                            match units.next() {
+                                Some(0x003E /* > */) => {
                                     found = true;
                                     break;
                                 }
-                                Some(c) => group_name.push(c),
+                                Some(unit) => group_name.push(unit),
                                 None => break,
                             }
                         }
@@ -2248,25 +2301,26 @@ pub(crate) fn get_substitution(
                         // c. If none is found, the replacement text is the String "$<".
                         #[allow(clippy::if_not_else)]
                         if !found {
-                            result.push_str("$<");
-                            result.push_str(&group_name);
+                            result.extend("$<".encode_utf16());
+                            result.extend(group_name);
                         // d. Else,
                         } else {
                             // i. Let groupName be the enclosed substring.
                             // ii. Let capture be ? Get(namedCaptures, groupName).
-                            let capture = named_captures.get(group_name, context)?;
+                            let capture =
+                                named_captures.get(JsString::from_utf16(&group_name), context)?;
 
                             // iii. If capture is undefined, replace the text through > with the empty String.
                             // iv. Otherwise, replace the text through > with ? ToString(capture).
                             if !capture.is_undefined() {
-                                result.push_str(capture.to_string(context)?.as_str());
+                                result.extend(capture.to_string(context)?.encode_utf16());
                             }
                         }
                     }
                 }
                 // $?, ? is none of the above
                 _ => {
-                    result.push('$');
+                    result.push(first);
                     if let Some(second) = second {
                         result.push(second);
                     }
@@ -2275,10 +2329,16 @@ pub(crate) fn get_substitution(
         } else {
             result.push(first);
         }
+
+        if result.len() > max_length {
+            return context.throw_range_error(format!(
+                "string length exceeds the maximum string length ({max_length})"
+            ));
+        }
     }
 
     // 11. Return result.
-    Ok(result.into())
+    Ok(JsString::from_utf16(&result))
 }
 
 /// `22.1.3.21.1 SplitMatch ( S, q, R )`
@@ -2287,7 +2347,7 @@ pub(crate) fn get_substitution(
 ///  - [ECMAScript reference][spec]
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-splitmatch
-fn split_match(s_str: &str, q: usize, r_str: &str) -> Option<usize> {
+fn split_match(s_str: &JsString, q: usize, r_str: &JsString) -> Option<usize> {
     // 1. Let r be the number of code units in R.
     let r = r_str.encode_utf16().count();
 
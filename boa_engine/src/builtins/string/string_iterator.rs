@@ -1,30 +1,46 @@
+//! This module implements the `String Iterator` object.
+//!
+//! A `String` Iterator is an object that represents a specific iteration over some specific
+//! `String` instance object. It yields the code points of the string, so a surrogate pair is
+//! yielded as a single value.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-string-iterator-objects
+
 use crate::{
     builtins::{
-        function::make_builtin_fn, iterable::create_iter_result_object, string::code_point_at,
+        function::make_builtin_fn, iterable::create_iter_result_object_or_throw,
+        string::code_point_at,
     },
     object::{JsObject, ObjectData},
     property::PropertyDescriptor,
     symbol::WellKnownSymbols,
-    Context, JsResult, JsValue,
+    Context, JsNativeError, JsResult, JsString, JsValue,
 };
 use boa_gc::{Finalize, Trace};
 use boa_profiler::Profiler;
 
 #[derive(Debug, Clone, Finalize, Trace)]
 pub struct StringIterator {
-    string: JsValue,
-    next_index: i32,
+    string: JsString,
+    /// Offset of the next code point in `string`, or `None` once the iterator is done.
+    ///
+    /// This is a byte offset, or a code unit offset if `string` has unpaired surrogates.
+    next_index: Option<usize>,
 }
 
 impl StringIterator {
-    fn new(string: JsValue) -> Self {
+    fn new(string: JsString) -> Self {
         Self {
             string,
-            next_index: 0,
+            next_index: Some(0),
         }
     }
 
-    pub fn create_string_iterator(string: JsValue, context: &mut Context) -> JsResult<JsValue> {
+    /// Creates a `String` iterator over the code points of `string`.
+    pub fn create_string_iterator(string: JsString, context: &mut Context) -> JsValue {
         let string_iterator = JsObject::from_proto_and_data(
             context
                 .intrinsics()
@@ -33,50 +49,75 @@ impl StringIterator {
                 .string_iterator(),
             ObjectData::string_iterator(Self::new(string)),
         );
-        Ok(string_iterator.into())
+        string_iterator.into()
     }
 
+    /// `%StringIteratorPrototype%.next( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%stringiteratorprototype%.next
     pub fn next(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
         let mut string_iterator = this.as_object().map(JsObject::borrow_mut);
         let string_iterator = string_iterator
             .as_mut()
             .and_then(|obj| obj.as_string_iterator_mut())
-            .ok_or_else(|| context.construct_type_error("`this` is not an ArrayIterator"))?;
+            .ok_or_else(|| JsNativeError::typ().with_message("`this` is not a StringIterator"))?;
 
-        if string_iterator.string.is_undefined() {
-            return Ok(create_iter_result_object(
-                JsValue::undefined(),
-                true,
-                context,
-            ));
-        }
-        let native_string = string_iterator.string.to_string(context)?;
-        let len = native_string.encode_utf16().count() as i32;
-        let position = string_iterator.next_index;
-        if position >= len {
-            string_iterator.string = JsValue::undefined();
-            return Ok(create_iter_result_object(
-                JsValue::undefined(),
-                true,
-                context,
-            ));
+        // a. Let len be the length of s.
+        // b. Let position be 0.
+        // c. Repeat, while position < len,
+        //     i. Let cp be CodePointAt(s, position).
+        //     ii. Let nextIndex be position + cp.[[CodeUnitCount]].
+        //     iii. Let resultString be the substring of s from position to nextIndex.
+        //     iv. Set position to nextIndex.
+        //     v. Perform ? GeneratorYield(CreateIterResultObject(resultString, false)).
+        // d. Return undefined.
+        //
+        // Every `char` of the string is a whole code point, so the iterator can step through its
+        // characters directly, unless the string has unpaired surrogates.
+        let string = &string_iterator.string;
+        let next = string_iterator.next_index.and_then(|position| {
+            if string.has_lone_surrogates() {
+                if position >= string.encode_utf16().count() {
+                    return None;
+                }
+                let (_, count, _) = code_point_at(string, position as u64);
+                let next_index = position + usize::from(count);
+                let units = string
+                    .encode_utf16()
+                    .skip(position)
+                    .take(usize::from(count));
+                Some((
+                    JsString::from_utf16(&units.collect::<Vec<u16>>()),
+                    next_index,
+                ))
+            } else {
+                string[position..].chars().next().map(|cp| {
+                    (
+                        string[position..position + cp.len_utf8()].into(),
+                        position + cp.len_utf8(),
+                    )
+                })
+            }
+        });
+
+        if let Some((result_string, next_index)) = next {
+            string_iterator.next_index = Some(next_index);
+            create_iter_result_object_or_throw(result_string.into(), false, context)
+        } else {
+            string_iterator.next_index = None;
+            create_iter_result_object_or_throw(JsValue::undefined(), true, context)
         }
-        let (_, code_unit_count, _) = code_point_at(&native_string, position as u64);
-        string_iterator.next_index += i32::from(code_unit_count);
-        let result_string = crate::builtins::string::String::substring(
-            &string_iterator.string,
-            &[position.into(), string_iterator.next_index.into()],
-            context,
-        )?;
-        Ok(create_iter_result_object(result_string, false, context))
     }
 
-    /// Create the `%ArrayIteratorPrototype%` object
+    /// Create the `%StringIteratorPrototype%` object
     ///
     /// More information:
     ///  - [ECMA reference][spec]
     ///
-    /// [spec]: https://tc39.es/ecma262/#sec-%arrayiteratorprototype%-object
+    /// [spec]: https://tc39.es/ecma262/#sec-%stringiteratorprototype%-object
     pub(crate) fn create_prototype(
         iterator_prototype: JsObject,
         context: &mut Context,
@@ -84,9 +125,9 @@ impl StringIterator {
         let _timer = Profiler::global().start_event("String Iterator", "init");
 
         // Create prototype
-        let array_iterator =
+        let string_iterator =
             JsObject::from_proto_and_data(iterator_prototype, ObjectData::ordinary());
-        make_builtin_fn(Self::next, "next", &array_iterator, 0, context);
+        make_builtin_fn(Self::next, "next", &string_iterator, 0, context);
 
         let to_string_tag = WellKnownSymbols::to_string_tag();
         let to_string_tag_property = PropertyDescriptor::builder()
@@ -94,7 +135,7 @@ impl StringIterator {
             .writable(false)
             .enumerable(false)
             .configurable(true);
-        array_iterator.insert(to_string_tag, to_string_tag_property);
-        array_iterator
+        string_iterator.insert(to_string_tag, to_string_tag_property);
+        string_iterator
     }
 }
@@ -312,6 +312,76 @@ fn replace_with_function() {
     assert_eq!(forward(&mut context, "length"), "14");
 }
 
+#[test]
+fn replace_string_pattern_substitutions() {
+    let mut context = Context::default();
+
+    assert_eq!(
+        forward(&mut context, r#""abc".replace("b", "[$&]")"#),
+        "\"a[b]c\""
+    );
+    assert_eq!(
+        forward(&mut context, r#""abc".replace("b", "[$$]")"#),
+        "\"a[$]c\""
+    );
+    assert_eq!(
+        forward(&mut context, r#""abc".replace("b", "[$`]")"#),
+        "\"a[a]c\""
+    );
+    assert_eq!(
+        forward(&mut context, r#""abc".replace("b", "[$']")"#),
+        "\"a[c]c\""
+    );
+    assert_eq!(
+        forward(&mut context, r#""abc".replace("c", "[$']")"#),
+        "\"ab[]\""
+    );
+    assert_eq!(
+        forward(&mut context, r#""a.b.c".replace(".", "$1")"#),
+        "\"a$1b.c\""
+    );
+    assert_eq!(
+        forward(&mut context, r#""a.b.c".replace(".", "$<x>")"#),
+        "\"a$<x>b.c\""
+    );
+    assert_eq!(
+        forward(&mut context, r#""ébé".replace("é", "[$']")"#),
+        "\"[bé]bé\""
+    );
+}
+
+#[test]
+fn replace_string_pattern_with_function() {
+    let mut context = Context::default();
+    let init = r#"
+        var args;
+        var a = "a+b+c".replace("+", function () {
+            args = Array.prototype.slice.call(arguments);
+            return "-";
+        });
+        "#;
+
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "a"), "\"a-b+c\"");
+    assert_eq!(forward(&mut context, "args.length"), "3");
+    assert_eq!(forward(&mut context, "args.join()"), "\"+,1,a+b+c\"");
+}
+
+#[test]
+fn replace_string_pattern_converts_replace_value_without_match() {
+    let mut context = Context::default();
+    let init = r#"
+        var calls = 0;
+        var a = "abc".replace("x", { toString() { calls++; return "y"; } });
+        "#;
+
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "a"), "\"abc\"");
+    assert_eq!(forward(&mut context, "calls"), "1");
+}
+
 #[test]
 fn starts_with() {
     let mut context = Context::default();
@@ -1130,6 +1200,59 @@ fn unicode_iter() {
     assert_eq!(forward(&mut context, "next.done"), "true");
 }
 
+#[test]
+fn code_point_iteration() {
+    let mut context = Context::default();
+    assert_eq!(forward(&mut context, "[...'a😀b'].length"), "3");
+    assert_eq!(forward(&mut context, "[...'a😀b'][1] === '😀'"), "true");
+    assert_eq!(forward(&mut context, "Array.from('😀😀').length"), "2");
+    assert_eq!(
+        forward(
+            &mut context,
+            "var [first, second] = '😀z'; first === '😀' && second"
+        ),
+        "\"z\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "var calls = 0; [...String.prototype[Symbol.iterator].call({ toString() { calls++; return 'ab'; } })].join() + calls"
+        ),
+        "\"a,b1\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { String.prototype[Symbol.iterator].call(null) } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn from_code_point() {
+    let mut context = Context::default();
+    assert_eq!(
+        forward(&mut context, "String.fromCodePoint(0x1F600) === '😀'"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "String.fromCodePoint(97, 0x1F600, 98)"),
+        "\"a😀b\""
+    );
+    assert_eq!(forward(&mut context, "String.fromCodePoint()"), "\"\"");
+    assert_eq!(forward(&mut context, "String.fromCodePoint('65')"), "\"A\"");
+    for code_point in ["-1", "0x110000", "1.5", "NaN", "Infinity"] {
+        assert_eq!(
+            forward(
+                &mut context,
+                format!("try {{ String.fromCodePoint({code_point}) }} catch (e) {{ e.name }}")
+            ),
+            "\"RangeError\""
+        );
+    }
+}
+
 #[test]
 fn string_get_property() {
     let mut context = Context::default();
@@ -1150,3 +1273,184 @@ fn search() {
     assert_eq!(forward(&mut context, "'aa'.search(/a/g)"), "0");
     assert_eq!(forward(&mut context, "'ba'.search(/a/)"), "1");
 }
+
+#[test]
+fn string_object_own_property_keys() {
+    let mut context = Context::default();
+    let init = r#"
+        var s = new String('ab');
+        s.x = 1;
+        s[5] = 2;
+        s[3] = 3;
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(
+        forward(&mut context, "Object.getOwnPropertyNames(s).join()"),
+        "\"0,1,3,5,length,x\""
+    );
+    assert_eq!(
+        forward(&mut context, "Object.keys(s).join()"),
+        "\"0,1,3,5,x\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.keys(new String('\\uD83D\\uDE00')).join()"
+        ),
+        "\"0,1\""
+    );
+}
+
+#[test]
+fn string_object_index_properties() {
+    let mut context = Context::default();
+    let init = r#"
+        var s = new String('ab');
+        var desc = Object.getOwnPropertyDescriptor(s, '1');
+        var length = Object.getOwnPropertyDescriptor(s, 'length');
+        function attempt(f) {
+            try { return f(); } catch (e) { return e.constructor.name; }
+        }
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "desc.value"), "\"b\"");
+    assert_eq!(forward(&mut context, "desc.writable"), "false");
+    assert_eq!(forward(&mut context, "desc.enumerable"), "true");
+    assert_eq!(forward(&mut context, "desc.configurable"), "false");
+    assert_eq!(forward(&mut context, "length.value"), "2");
+    assert_eq!(forward(&mut context, "length.enumerable"), "false");
+    assert_eq!(
+        forward(&mut context, "Object.getOwnPropertyDescriptor(s, '2')"),
+        "undefined"
+    );
+    assert_eq!(forward(&mut context, "delete s[0]"), "false");
+    assert_eq!(forward(&mut context, "s[0] = 'z'; s[0]"), "\"a\"");
+    assert_eq!(
+        forward(&mut context, "attempt(() => { 'use strict'; s[0] = 'z'; })"),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "attempt(() => { 'use strict'; delete s[1]; })"
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.defineProperty(s, '0', { value: 'a' }) === s"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "attempt(() => Object.defineProperty(s, '0', { value: 'z' }))"
+        ),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn string_object_wrapper_identity() {
+    let mut context = Context::default();
+    let init = r#"
+        var s = new String('ab');
+        function attempt(f) {
+            try { return f(); } catch (e) { return e.constructor.name; }
+        }
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "typeof s"), "\"object\"");
+    assert_eq!(forward(&mut context, "typeof s.valueOf()"), "\"string\"");
+    assert_eq!(forward(&mut context, "s == 'ab'"), "true");
+    assert_eq!(forward(&mut context, "s === 'ab'"), "false");
+    assert_eq!(forward(&mut context, "s == new String('ab')"), "false");
+    assert_eq!(
+        forward(
+            &mut context,
+            "attempt(() => String.prototype.valueOf.call({}))"
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "attempt(() => String.prototype.toString.call(1))"
+        ),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn raw_requires_template_object() {
+    let mut context = Context::default();
+
+    assert_eq!(
+        forward(&mut context, "String.raw({ raw: ['a', 'c'] }, 'b')"),
+        "\"abc\""
+    );
+    assert_eq!(
+        forward(&mut context, "String.raw()"),
+        "Uncaught \"TypeError\": \"String.raw: argument 1 cannot be converted to an object\""
+    );
+    assert_eq!(
+        forward(&mut context, "String.raw(null)"),
+        "Uncaught \"TypeError\": \"String.raw: argument 1 cannot be converted to an object\""
+    );
+}
+
+#[test]
+fn lone_surrogates() {
+    let mut context = Context::default();
+
+    let init = r#"
+        var high = "\uD83D";
+        var low = "\uDE00";
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "high + low === '😀'"), "true");
+    assert_eq!(forward(&mut context, "`${high}${low}` === '😀'"), "true");
+    assert_eq!(forward(&mut context, "'😀'.charAt(0) === high"), "true");
+    assert_eq!(forward(&mut context, "'😀'.slice(1) === low"), "true");
+    assert_eq!(forward(&mut context, "'😀'.at(-1).charCodeAt(0)"), "56832");
+    assert_eq!(forward(&mut context, "'\\uD800' === '\\uD801'"), "false");
+    assert_eq!(forward(&mut context, "'\\uD800' < '\\uD801'"), "true");
+    assert_eq!(forward(&mut context, "'\\uD800' === '\\uFFFD'"), "false");
+    assert_eq!(
+        forward(&mut context, "String.fromCharCode(0xD800).charCodeAt(0)"),
+        "55296"
+    );
+    assert_eq!(
+        forward(&mut context, "String.fromCodePoint(0xDC00).charCodeAt(0)"),
+        "56320"
+    );
+    assert_eq!(forward(&mut context, "high.repeat(3).length"), "3");
+    assert_eq!(
+        forward(
+            &mut context,
+            "'a\\uD800b'.split('').map(c => c.charCodeAt(0)).join()"
+        ),
+        "\"97,55296,98\""
+    );
+    assert_eq!(
+        forward(&mut context, "[high, low].join('') === '😀'"),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "'a\\uD800'.indexOf('\\uD800')"), "1");
+    assert_eq!(forward(&mut context, "'a\\uD800'.indexOf('\\uFFFD')"), "-1");
+    assert_eq!(
+        forward(&mut context, "[...'a\\uD800'][1] === '\\uD800'"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "({ '\\uD800': 1 })[high] === undefined"),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "({ '\\uD800': 1 })['\\uD800']"), "1");
+}
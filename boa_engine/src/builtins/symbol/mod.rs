@@ -25,7 +25,7 @@ use crate::{
     property::Attribute,
     symbol::{JsSymbol, WellKnownSymbols},
     value::JsValue,
-    Context, JsResult, JsString,
+    Context, JsNativeError, JsResult, JsString,
 };
 use boa_profiler::Profiler;
 use rustc_hash::FxHashMap;
@@ -189,11 +189,15 @@ impl Symbol {
         Ok(JsSymbol::new(description).into())
     }
 
-    fn this_symbol_value(value: &JsValue, context: &mut Context) -> JsResult<JsSymbol> {
+    fn this_symbol_value(value: &JsValue) -> JsResult<JsSymbol> {
         value
             .as_symbol()
             .or_else(|| value.as_object().and_then(|obj| obj.borrow().as_symbol()))
-            .ok_or_else(|| context.construct_type_error("'this' is not a Symbol"))
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("'this' is not a Symbol")
+                    .into()
+            })
     }
 
     /// `Symbol.prototype.toString()`
@@ -210,10 +214,10 @@ impl Symbol {
     pub(crate) fn to_string(
         this: &JsValue,
         _: &[JsValue],
-        context: &mut Context,
+        _context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let sym be ? thisSymbolValue(this value).
-        let symbol = Self::this_symbol_value(this, context)?;
+        let symbol = Self::this_symbol_value(this)?;
 
         // 2. Return SymbolDescriptiveString(sym).
         Ok(symbol.descriptive_string().into())
@@ -232,10 +236,10 @@ impl Symbol {
     pub(crate) fn value_of(
         this: &JsValue,
         _: &[JsValue],
-        context: &mut Context,
+        _context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Return ? thisSymbolValue(this value).
-        let symbol = Self::this_symbol_value(this, context)?;
+        let symbol = Self::this_symbol_value(this)?;
         Ok(JsValue::Symbol(symbol))
     }
 
@@ -252,9 +256,9 @@ impl Symbol {
     pub(crate) fn get_description(
         this: &JsValue,
         _: &[JsValue],
-        context: &mut Context,
+        _context: &mut Context,
     ) -> JsResult<JsValue> {
-        let symbol = Self::this_symbol_value(this, context)?;
+        let symbol = Self::this_symbol_value(this)?;
         if let Some(ref description) = symbol.description() {
             Ok(description.clone().into())
         } else {
@@ -337,9 +341,9 @@ impl Symbol {
     pub(crate) fn to_primitive(
         this: &JsValue,
         _: &[JsValue],
-        context: &mut Context,
+        _context: &mut Context,
     ) -> JsResult<JsValue> {
-        let sym = Self::this_symbol_value(this, context)?;
+        let sym = Self::this_symbol_value(this)?;
         // 1. Return ? thisSymbolValue(this value).
         Ok(sym.into())
     }
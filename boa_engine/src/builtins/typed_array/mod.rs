@@ -27,7 +27,7 @@ use crate::{
     property::{Attribute, PropertyNameKind},
     symbol::WellKnownSymbols,
     value::{IntegerOrInfinity, JsValue},
-    Context, JsResult, JsString,
+    Context, JsNativeError, JsResult, JsString,
 };
 use boa_profiler::Profiler;
 use num_traits::{Signed, Zero};
@@ -37,6 +37,9 @@ use tap::{Conv, Pipe};
 
 pub mod integer_indexed_object;
 
+#[cfg(test)]
+mod tests;
+
 macro_rules! typed_array {
     ($ty:ident, $variant:ident, $name:literal, $global_object_name:ident) => {
         #[doc = concat!("JavaScript `", $name, "` built-in implementation.")]
@@ -360,7 +363,10 @@ impl BuiltIn for TypedArray {
         .method(Self::some, "some", 1)
         .method(Self::sort, "sort", 1)
         .method(Self::subarray, "subarray", 2)
+        .method(Self::to_reversed, "toReversed", 0)
+        .method(Self::to_sorted, "toSorted", 1)
         .method(Self::values, "values", 0)
+        .method(Self::with, "with", 2)
         // 23.2.3.29 %TypedArray%.prototype.toString ( )
         // The initial value of the %TypedArray%.prototype.toString data property is the same
         // built-in function object as the Array.prototype.toString method defined in 23.1.3.30.
@@ -551,13 +557,13 @@ impl TypedArray {
     pub(crate) fn at(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
         // 1. Let O be the this value.
         // 2. Perform ? ValidateTypedArray(O).
-        let obj = this
-            .as_object()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        let obj = this.as_object().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
         let obj_borrow = obj.borrow();
-        let o = obj_borrow
-            .as_typed_array()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        let o = obj_borrow.as_typed_array().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
         if o.is_detached() {
             return context.throw_type_error("Buffer of the typed array is detached");
         }
@@ -596,17 +602,17 @@ impl TypedArray {
     ///  - [ECMAScript reference][spec]
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-get-%typedarray%.prototype.buffer
-    fn buffer(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+    fn buffer(this: &JsValue, _: &[JsValue], _context: &mut Context) -> JsResult<JsValue> {
         // 1. Let O be the this value.
         // 2. Perform ? RequireInternalSlot(O, [[TypedArrayName]]).
         // 3. Assert: O has a [[ViewedArrayBuffer]] internal slot.
-        let obj = this
-            .as_object()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        let obj = this.as_object().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
         let obj_borrow = obj.borrow();
-        let typed_array = obj_borrow
-            .as_typed_array()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        let typed_array = obj_borrow.as_typed_array().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
 
         // 4. Let buffer be O.[[ViewedArrayBuffer]].
         // 5. Return buffer.
@@ -624,18 +630,18 @@ impl TypedArray {
     pub(crate) fn byte_length(
         this: &JsValue,
         _: &[JsValue],
-        context: &mut Context,
+        _context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let O be the this value.
         // 2. Perform ? RequireInternalSlot(O, [[TypedArrayName]]).
         // 3. Assert: O has a [[ViewedArrayBuffer]] internal slot.
-        let obj = this
-            .as_object()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        let obj = this.as_object().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
         let obj_borrow = obj.borrow();
-        let typed_array = obj_borrow
-            .as_typed_array()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        let typed_array = obj_borrow.as_typed_array().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
 
         // 4. Let buffer be O.[[ViewedArrayBuffer]].
         // 5. If IsDetachedBuffer(buffer) is true, return +0𝔽.
@@ -657,18 +663,18 @@ impl TypedArray {
     pub(crate) fn byte_offset(
         this: &JsValue,
         _: &[JsValue],
-        context: &mut Context,
+        _context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let O be the this value.
         // 2. Perform ? RequireInternalSlot(O, [[TypedArrayName]]).
         // 3. Assert: O has a [[ViewedArrayBuffer]] internal slot.
-        let obj = this
-            .as_object()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        let obj = this.as_object().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
         let obj_borrow = obj.borrow();
-        let typed_array = obj_borrow
-            .as_typed_array()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        let typed_array = obj_borrow.as_typed_array().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
 
         // 4. Let buffer be O.[[ViewedArrayBuffer]].
         // 5. If IsDetachedBuffer(buffer) is true, return +0𝔽.
@@ -689,15 +695,15 @@ impl TypedArray {
     /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.copywithin
     fn copy_within(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
         // 1. Let O be the this value.
-        let obj = this
-            .as_object()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        let obj = this.as_object().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
 
         let len = {
             let obj_borrow = obj.borrow();
-            let o = obj_borrow
-                .as_typed_array()
-                .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+            let o = obj_borrow.as_typed_array().ok_or_else(|| {
+                JsNativeError::typ().with_message("Value is not a typed array object")
+            })?;
 
             // 2. Perform ? ValidateTypedArray(O).
             if o.is_detached() {
@@ -756,9 +762,9 @@ impl TypedArray {
         let count = std::cmp::min(r#final - from, len - to);
 
         let obj_borrow = obj.borrow();
-        let o = obj_borrow
-            .as_typed_array()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        let o = obj_borrow.as_typed_array().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
 
         // 17. If count > 0, then
         if count > 0 {
@@ -859,12 +865,12 @@ impl TypedArray {
     fn entries(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
         // 1. Let O be the this value.
         // 2. Perform ? ValidateTypedArray(O).
-        let o = this
-            .as_object()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        let o = this.as_object().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
         if o.borrow()
             .as_typed_array()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?
+            .ok_or_else(|| JsNativeError::typ().with_message("Value is not a typed array object"))?
             .is_detached()
         {
             return context.throw_type_error("Buffer of the typed array is detached");
@@ -891,13 +897,13 @@ impl TypedArray {
     ) -> JsResult<JsValue> {
         // 1. Let O be the this value.
         // 2. Perform ? ValidateTypedArray(O).
-        let obj = this
-            .as_object()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        let obj = this.as_object().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
         let obj_borrow = obj.borrow();
-        let o = obj_borrow
-            .as_typed_array()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        let o = obj_borrow.as_typed_array().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
         if o.is_detached() {
             return context.throw_type_error("Buffer of the typed array is detached");
         }
@@ -954,13 +960,13 @@ impl TypedArray {
     ) -> JsResult<JsValue> {
         // 1. Let O be the this value.
         // 2. Perform ? ValidateTypedArray(O).
-        let obj = this
-            .as_object()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        let obj = this.as_object().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
         let obj_borrow = obj.borrow();
-        let o = obj_borrow
-            .as_typed_array()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        let o = obj_borrow.as_typed_array().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
         if o.is_detached() {
             return context.throw_type_error("Buffer of the typed array is detached");
         }
@@ -1038,13 +1044,13 @@ impl TypedArray {
     ) -> JsResult<JsValue> {
         // 1. Let O be the this value.
         // 2. Perform ? ValidateTypedArray(O).
-        let obj = this
-            .as_object()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        let obj = this.as_object().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
         let obj_borrow = obj.borrow();
-        let o = obj_borrow
-            .as_typed_array()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        let o = obj_borrow.as_typed_array().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
         if o.is_detached() {
             return context.throw_type_error("Buffer of the typed array is detached");
         }
@@ -1123,13 +1129,13 @@ impl TypedArray {
     ) -> JsResult<JsValue> {
         // 1. Let O be the this value.
         // 2. Perform ? ValidateTypedArray(O).
-        let obj = this
-            .as_object()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        let obj = this.as_object().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
         let obj_borrow = obj.borrow();
-        let o = obj_borrow
-            .as_typed_array()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        let o = obj_borrow.as_typed_array().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
         if o.is_detached() {
             return context.throw_type_error("Buffer of the typed array is detached");
         }
@@ -1185,13 +1191,13 @@ impl TypedArray {
     ) -> JsResult<JsValue> {
         // 1. Let O be the this value.
         // 2. Perform ? ValidateTypedArray(O).
-        let obj = this
-            .as_object()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        let obj = this.as_object().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
         let obj_borrow = obj.borrow();
-        let o = obj_borrow
-            .as_typed_array()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        let o = obj_borrow.as_typed_array().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
         if o.is_detached() {
             return context.throw_type_error("Buffer of the typed array is detached");
         }
@@ -1246,13 +1252,13 @@ impl TypedArray {
     ) -> JsResult<JsValue> {
         // 1. Let O be the this value.
         // 2. Perform ? ValidateTypedArray(O).
-        let obj = this
-            .as_object()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        let obj = this.as_object().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
         let obj_borrow = obj.borrow();
-        let o = obj_borrow
-            .as_typed_array()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        let o = obj_borrow.as_typed_array().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
         if o.is_detached() {
             return context.throw_type_error("Buffer of the typed array is detached");
         }
@@ -1302,13 +1308,13 @@ impl TypedArray {
     ) -> JsResult<JsValue> {
         // 1. Let O be the this value.
         // 2. Perform ? ValidateTypedArray(O).
-        let obj = this
-            .as_object()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        let obj = this.as_object().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
         let obj_borrow = obj.borrow();
-        let o = obj_borrow
-            .as_typed_array()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        let o = obj_borrow.as_typed_array().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
         if o.is_detached() {
             return context.throw_type_error("Buffer of the typed array is detached");
         }
@@ -1379,13 +1385,13 @@ impl TypedArray {
     ) -> JsResult<JsValue> {
         // 1. Let O be the this value.
         // 2. Perform ? ValidateTypedArray(O).
-        let obj = this
-            .as_object()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        let obj = this.as_object().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
         let obj_borrow = obj.borrow();
-        let o = obj_borrow
-            .as_typed_array()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        let o = obj_borrow.as_typed_array().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
         if o.is_detached() {
             return context.throw_type_error("Buffer of the typed array is detached");
         }
@@ -1465,13 +1471,13 @@ impl TypedArray {
     ) -> JsResult<JsValue> {
         // 1. Let O be the this value.
         // 2. Perform ? ValidateTypedArray(O).
-        let obj = this
-            .as_object()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        let obj = this.as_object().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
         let obj_borrow = obj.borrow();
-        let o = obj_borrow
-            .as_typed_array()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        let o = obj_borrow.as_typed_array().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
         if o.is_detached() {
             return context.throw_type_error("Buffer of the typed array is detached");
         }
@@ -1496,6 +1502,7 @@ impl TypedArray {
         for k in 0..len {
             // a. If k > 0, set R to the string-concatenation of R and sep.
             if k > 0 {
+                context.resource_limits().check_string_length(&[&r, &sep])?;
                 r = JsString::concat(r, sep.clone());
             }
 
@@ -1505,7 +1512,11 @@ impl TypedArray {
             // c. If element is undefined, let next be the empty String; otherwise, let next be ! ToString(element).
             // d. Set R to the string-concatenation of R and next.
             if !element.is_undefined() {
-                r = JsString::concat(r, element.to_string(context)?);
+                let next = element.to_string(context)?;
+                context
+                    .resource_limits()
+                    .check_string_length(&[&r, &next])?;
+                r = JsString::concat(r, next);
             }
         }
 
@@ -1522,12 +1533,12 @@ impl TypedArray {
     pub(crate) fn keys(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
         // 1. Let O be the this value.
         // 2. Perform ? ValidateTypedArray(O).
-        let o = this
-            .as_object()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        let o = this.as_object().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
         if o.borrow()
             .as_typed_array()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?
+            .ok_or_else(|| JsNativeError::typ().with_message("Value is not a typed array object"))?
             .is_detached()
         {
             return context.throw_type_error("Buffer of the typed array is detached");
@@ -1554,19 +1565,21 @@ impl TypedArray {
     ) -> JsResult<JsValue> {
         // 1. Let O be the this value.
         // 2. Perform ? ValidateTypedArray(O).
-        let obj = this
-            .as_object()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
-        let obj_borrow = obj.borrow();
-        let o = obj_borrow
-            .as_typed_array()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
-        if o.is_detached() {
-            return context.throw_type_error("Buffer of the typed array is detached");
-        }
+        let obj = this.as_object().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
+        let len = {
+            let obj_borrow = obj.borrow();
+            let o = obj_borrow.as_typed_array().ok_or_else(|| {
+                JsNativeError::typ().with_message("Value is not a typed array object")
+            })?;
+            if o.is_detached() {
+                return context.throw_type_error("Buffer of the typed array is detached");
+            }
 
-        // 3. Let len be O.[[ArrayLength]].
-        let len = o.array_length() as i64;
+            // 3. Let len be O.[[ArrayLength]].
+            o.array_length() as i64
+        };
 
         // 4. If len is 0, return -1𝔽.
         if len == 0 {
@@ -1628,18 +1641,18 @@ impl TypedArray {
     pub(crate) fn length(
         this: &JsValue,
         _: &[JsValue],
-        context: &mut Context,
+        _context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let O be the this value.
         // 2. Perform ? RequireInternalSlot(O, [[TypedArrayName]]).
         // 3. Assert: O has [[ViewedArrayBuffer]] and [[ArrayLength]] internal slots.
-        let obj = this
-            .as_object()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        let obj = this.as_object().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
         let obj_borrow = obj.borrow();
-        let typed_array = obj_borrow
-            .as_typed_array()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        let typed_array = obj_borrow.as_typed_array().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
 
         // 4. Let buffer be O.[[ViewedArrayBuffer]].
         // 5. If IsDetachedBuffer(buffer) is true, return +0𝔽.
@@ -1665,13 +1678,13 @@ impl TypedArray {
     ) -> JsResult<JsValue> {
         // 1. Let O be the this value.
         // 2. Perform ? ValidateTypedArray(O).
-        let obj = this
-            .as_object()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        let obj = this.as_object().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
         let obj_borrow = obj.borrow();
-        let o = obj_borrow
-            .as_typed_array()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        let o = obj_borrow.as_typed_array().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
         if o.is_detached() {
             return context.throw_type_error("Buffer of the typed array is detached");
         }
@@ -1727,13 +1740,13 @@ impl TypedArray {
     ) -> JsResult<JsValue> {
         // 1. Let O be the this value.
         // 2. Perform ? ValidateTypedArray(O).
-        let obj = this
-            .as_object()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        let obj = this.as_object().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
         let obj_borrow = obj.borrow();
-        let o = obj_borrow
-            .as_typed_array()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        let o = obj_borrow.as_typed_array().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
         if o.is_detached() {
             return context.throw_type_error("Buffer of the typed array is detached");
         }
@@ -1808,13 +1821,13 @@ impl TypedArray {
     ) -> JsResult<JsValue> {
         // 1. Let O be the this value.
         // 2. Perform ? ValidateTypedArray(O).
-        let obj = this
-            .as_object()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        let obj = this.as_object().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
         let obj_borrow = obj.borrow();
-        let o = obj_borrow
-            .as_typed_array()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        let o = obj_borrow.as_typed_array().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
         if o.is_detached() {
             return context.throw_type_error("Buffer of the typed array is detached");
         }
@@ -1892,13 +1905,13 @@ impl TypedArray {
     ) -> JsResult<JsValue> {
         // 1. Let O be the this value.
         // 2. Perform ? ValidateTypedArray(O).
-        let obj = this
-            .as_object()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        let obj = this.as_object().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
         let obj_borrow = obj.borrow();
-        let o = obj_borrow
-            .as_typed_array()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        let o = obj_borrow.as_typed_array().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
         if o.is_detached() {
             return context.throw_type_error("Buffer of the typed array is detached");
         }
@@ -1938,6 +1951,55 @@ impl TypedArray {
         Ok(this.clone())
     }
 
+    /// `%TypedArray%.prototype.toReversed ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.toreversed
+    pub(crate) fn to_reversed(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? ValidateTypedArray(O).
+        let obj = this.as_object().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
+        let (name, len) = {
+            let obj_borrow = obj.borrow();
+            let o = obj_borrow.as_typed_array().ok_or_else(|| {
+                JsNativeError::typ().with_message("Value is not a typed array object")
+            })?;
+            if o.is_detached() {
+                return context.throw_type_error("Buffer of the typed array is detached");
+            }
+
+            // 3. Let length be O.[[ArrayLength]].
+            (o.typed_array_name(), o.array_length())
+        };
+
+        // 4. Let A be ? TypedArrayCreateSameType(O, « 𝔽(length) »).
+        let a = Self::create_same_type(name, len, context)?;
+
+        // 5. Let k be 0.
+        // 6. Repeat, while k < length,
+        for k in 0..len {
+            // a. Let from be ! ToString(𝔽(length - k - 1)).
+            // b. Let Pk be ! ToString(𝔽(k)).
+            // c. Let fromValue be ! Get(O, from).
+            let from_value = obj.get(len - k - 1, context).expect("Get cannot fail here");
+            // d. Perform ! Set(A, Pk, fromValue, true).
+            a.set(k, from_value, true, context)
+                .expect("Set cannot fail here");
+            // e. Set k to k + 1.
+        }
+
+        // 7. Return A.
+        Ok(a.into())
+    }
+
     /// `23.2.3.24 %TypedArray%.prototype.set ( source [ , offset ] )`
     ///
     /// More information:
@@ -1953,7 +2015,7 @@ impl TypedArray {
         // 2. Perform ? RequireInternalSlot(target, [[TypedArrayName]]).
         // 3. Assert: target has a [[ViewedArrayBuffer]] internal slot.
         let target = this.as_object().ok_or_else(|| {
-            context.construct_type_error("TypedArray.set must be called on typed array object")
+            JsNativeError::typ().with_message("TypedArray.set must be called on typed array object")
         })?;
         if !target.is_typed_array() {
             return context.throw_type_error("TypedArray.set must be called on typed array object");
@@ -2333,13 +2395,13 @@ impl TypedArray {
     ) -> JsResult<JsValue> {
         // 1. Let O be the this value.
         // 2. Perform ? ValidateTypedArray(O).
-        let obj = this
-            .as_object()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        let obj = this.as_object().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
         let obj_borrow = obj.borrow();
-        let o = obj_borrow
-            .as_typed_array()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        let o = obj_borrow.as_typed_array().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
         if o.is_detached() {
             return context.throw_type_error("Buffer of the typed array is detached");
         }
@@ -2502,13 +2564,13 @@ impl TypedArray {
     ) -> JsResult<JsValue> {
         // 1. Let O be the this value.
         // 2. Perform ? ValidateTypedArray(O).
-        let obj = this
-            .as_object()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        let obj = this.as_object().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
         let obj_borrow = obj.borrow();
-        let o = obj_borrow
-            .as_typed_array()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        let o = obj_borrow.as_typed_array().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
         if o.is_detached() {
             return context.throw_type_error("Buffer of the typed array is detached");
         }
@@ -2574,7 +2636,8 @@ impl TypedArray {
 
         // 2. Let obj be the this value.
         let obj = this.as_object().ok_or_else(|| {
-            context.construct_type_error("TypedArray.sort must be called on typed array object")
+            JsNativeError::typ()
+                .with_message("TypedArray.sort must be called on typed array object")
         })?;
 
         // 4. Let buffer be obj.[[ViewedArrayBuffer]].
@@ -2583,7 +2646,8 @@ impl TypedArray {
             // 3. Perform ? ValidateTypedArray(obj).
             let obj_borrow = obj.borrow();
             let o = obj_borrow.as_typed_array().ok_or_else(|| {
-                context.construct_type_error("TypedArray.sort must be called on typed array object")
+                JsNativeError::typ()
+                    .with_message("TypedArray.sort must be called on typed array object")
             })?;
             if o.is_detached() {
                 return context.throw_type_error(
@@ -2599,168 +2663,220 @@ impl TypedArray {
             )
         };
 
-        // 4. Let items be a new empty List.
-        let mut items = Vec::with_capacity(len as usize);
+        // 6. NOTE: The following closure performs a numeric comparison rather than the string comparison used in Array.prototype.sort.
+        // 7. Let SortCompare be a new Abstract Closure with parameters (x, y) that captures comparefn and performs the following steps when called:
+        //     a. Return ? CompareTypedArrayElements(x, y, comparefn).
+        // 8. Let sortedList be ? SortIndexedProperties(obj, len, SortCompare, read-through-holes).
+        let sorted_list = Array::sort_indexed_properties(
+            obj,
+            len,
+            |x, y, context| Self::compare_typed_array_elements(x, y, compare_fn, &buffer, context),
+            false,
+            context,
+        )?;
 
-        // 5. Let k be 0.
-        // 6. Repeat, while k < len,
-        for k in 0..len {
-            // a. Let Pk be ! ToString(𝔽(k)).
-            // b. Let kPresent be ? HasProperty(obj, Pk).
-            // c. If kPresent is true, then
-            if obj.has_property(k, context)? {
-                // i. Let kValue be ? Get(obj, Pk).
-                let k_val = obj.get(k, context)?;
-                // ii. Append kValue to items.
-                items.push(k_val);
-            }
-            // d. Set k to k + 1.
+        // 9. Let j be 0.
+        // 10. Repeat, while j < len,
+        for (j, item) in sorted_list.into_iter().enumerate() {
+            // a. Perform ! Set(obj, ! ToString(𝔽(j)), sortedList[j], true).
+            obj.set(j, item, true, context)?;
+            // b. Set j to j + 1.
         }
 
-        // 7. Let itemCount be the number of elements in items.
-        let item_count = items.len();
-
-        let sort_compare = |x: &JsValue,
-                            y: &JsValue,
-                            compare_fn: Option<&JsObject>,
-                            context: &mut Context|
-         -> JsResult<Ordering> {
-            // 1. Assert: Both Type(x) and Type(y) are Number or both are BigInt.
-            // 2. If comparefn is not undefined, then
-            if let Some(obj) = compare_fn {
-                // a. Let v be ? ToNumber(? Call(comparefn, undefined, « x, y »)).
-                let v = obj
-                    .call(&JsValue::undefined(), &[x.clone(), y.clone()], context)?
-                    .to_number(context)?;
-
-                // b. If IsDetachedBuffer(buffer) is true, throw a TypeError exception.
-                if buffer
-                    .borrow()
-                    .as_array_buffer()
-                    .expect("Must be array buffer")
-                    .is_detached_buffer()
-                {
-                    return context
-                        .throw_type_error("Cannot sort typed array with detached buffer");
-                }
+        // 11. Return obj.
+        Ok(obj.clone().into())
+    }
 
-                // c. If v is NaN, return +0𝔽.
-                // d. Return v.
-                return Ok(v.partial_cmp(&0.0).unwrap_or(Ordering::Equal));
+    /// `%TypedArray%.prototype.toSorted ( comparefn )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.tosorted
+    pub(crate) fn to_sorted(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If comparefn is not undefined and IsCallable(comparefn) is false, throw a TypeError exception.
+        let compare_fn = match args.get(0) {
+            None | Some(JsValue::Undefined) => None,
+            Some(JsValue::Object(obj)) if obj.is_callable() => Some(obj),
+            _ => {
+                return context
+                    .throw_type_error("TypedArray.toSorted called with non-callable comparefn")
             }
+        };
 
-            if let (JsValue::BigInt(x), JsValue::BigInt(y)) = (x, y) {
-                // 6. If x < y, return -1𝔽.
-                if x < y {
-                    return Ok(Ordering::Less);
-                }
+        // 2. Let O be the this value.
+        let obj = this.as_object().ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("TypedArray.toSorted must be called on typed array object")
+        })?;
 
-                // 7. If x > y, return 1𝔽.
-                if x > y {
-                    return Ok(Ordering::Greater);
-                }
+        // 4. Let len be O.[[ArrayLength]].
+        let (name, buffer, len) = {
+            // 3. Perform ? ValidateTypedArray(O).
+            let obj_borrow = obj.borrow();
+            let o = obj_borrow.as_typed_array().ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("TypedArray.toSorted must be called on typed array object")
+            })?;
+            if o.is_detached() {
+                return context.throw_type_error(
+                    "TypedArray.toSorted called on typed array object with detached array buffer",
+                );
+            }
 
-                // 8. If x is -0𝔽 and y is +0𝔽, return -1𝔽.
-                if x.is_zero()
-                    && y.is_zero()
-                    && x.as_inner().is_negative()
-                    && y.as_inner().is_positive()
-                {
-                    return Ok(Ordering::Less);
-                }
+            (
+                o.typed_array_name(),
+                o.viewed_array_buffer()
+                    .expect("Already checked for detached buffer")
+                    .clone(),
+                o.array_length(),
+            )
+        };
 
-                // 9. If x is +0𝔽 and y is -0𝔽, return 1𝔽.
-                if x.is_zero()
-                    && y.is_zero()
-                    && x.as_inner().is_positive()
-                    && y.as_inner().is_negative()
-                {
-                    return Ok(Ordering::Greater);
-                }
+        // 5. Let A be ? TypedArrayCreateSameType(O, « 𝔽(len) »).
+        let a = Self::create_same_type(name, len, context)?;
 
-                // 10. Return +0𝔽.
-                Ok(Ordering::Equal)
-            } else {
-                let x = x
-                    .as_number()
-                    .expect("Typed array can only contain number or bigint");
-                let y = y
-                    .as_number()
-                    .expect("Typed array can only contain number or bigint");
-
-                // 3. If x and y are both NaN, return +0𝔽.
-                if x.is_nan() && y.is_nan() {
-                    return Ok(Ordering::Equal);
-                }
+        // 6. NOTE: The following closure performs a numeric comparison rather than the string comparison used in Array.prototype.toSorted.
+        // 7. Let SortCompare be a new Abstract Closure with parameters (x, y) that captures comparefn and performs the following steps when called:
+        //     a. Return ? CompareTypedArrayElements(x, y, comparefn).
+        // 8. Let sortedList be ? SortIndexedProperties(O, len, SortCompare, read-through-holes).
+        let sorted_list = Array::sort_indexed_properties(
+            obj,
+            len,
+            |x, y, context| Self::compare_typed_array_elements(x, y, compare_fn, &buffer, context),
+            false,
+            context,
+        )?;
 
-                // 4. If x is NaN, return 1𝔽.
-                if x.is_nan() {
-                    return Ok(Ordering::Greater);
-                }
+        // 9. Let j be 0.
+        // 10. Repeat, while j < len,
+        for (j, item) in sorted_list.into_iter().enumerate() {
+            // a. Perform ! Set(A, ! ToString(𝔽(j)), sortedList[j], true).
+            a.set(j, item, true, context).expect("Set cannot fail here");
+            // b. Set j to j + 1.
+        }
 
-                // 5. If y is NaN, return -1𝔽.
-                if y.is_nan() {
-                    return Ok(Ordering::Less);
-                }
+        // 11. Return A.
+        Ok(a.into())
+    }
 
-                // 6. If x < y, return -1𝔽.
-                if x < y {
-                    return Ok(Ordering::Less);
-                }
+    /// `CompareTypedArrayElements ( x, y, comparefn )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-comparetypedarrayelements
+    fn compare_typed_array_elements(
+        x: &JsValue,
+        y: &JsValue,
+        compare_fn: Option<&JsObject>,
+        buffer: &JsObject,
+        context: &mut Context,
+    ) -> JsResult<Ordering> {
+        // 1. Assert: Both Type(x) and Type(y) are Number or both are BigInt.
+        // 2. If comparefn is not undefined, then
+        if let Some(obj) = compare_fn {
+            // a. Let v be ? ToNumber(? Call(comparefn, undefined, « x, y »)).
+            let v = obj
+                .call(&JsValue::undefined(), &[x.clone(), y.clone()], context)?
+                .to_number(context)?;
+
+            // b. If IsDetachedBuffer(buffer) is true, throw a TypeError exception.
+            if buffer
+                .borrow()
+                .as_array_buffer()
+                .expect("Must be array buffer")
+                .is_detached_buffer()
+            {
+                return context.throw_type_error("Cannot sort typed array with detached buffer");
+            }
 
-                // 7. If x > y, return 1𝔽.
-                if x > y {
-                    return Ok(Ordering::Greater);
-                }
+            // c. If v is NaN, return +0𝔽.
+            // d. Return v.
+            return Ok(v.partial_cmp(&0.0).unwrap_or(Ordering::Equal));
+        }
 
-                // 8. If x is -0𝔽 and y is +0𝔽, return -1𝔽.
-                if x.is_zero() && y.is_zero() && x.is_sign_negative() && y.is_sign_positive() {
-                    return Ok(Ordering::Less);
-                }
+        if let (JsValue::BigInt(x), JsValue::BigInt(y)) = (x, y) {
+            // 6. If x < y, return -1𝔽.
+            if x < y {
+                return Ok(Ordering::Less);
+            }
 
-                // 9. If x is +0𝔽 and y is -0𝔽, return 1𝔽.
-                if x.is_zero() && y.is_zero() && x.is_sign_positive() && y.is_sign_negative() {
-                    return Ok(Ordering::Greater);
-                }
+            // 7. If x > y, return 1𝔽.
+            if x > y {
+                return Ok(Ordering::Greater);
+            }
 
-                // 10. Return +0𝔽.
-                Ok(Ordering::Equal)
+            // 8. If x is -0𝔽 and y is +0𝔽, return -1𝔽.
+            if x.is_zero()
+                && y.is_zero()
+                && x.as_inner().is_negative()
+                && y.as_inner().is_positive()
+            {
+                return Ok(Ordering::Less);
             }
-        };
 
-        // 8. Sort items using an implementation-defined sequence of calls to SortCompare.
-        // If any such call returns an abrupt completion, stop before performing any further
-        // calls to SortCompare or steps in this algorithm and return that completion.
-        let mut sort_err = Ok(());
-        items.sort_by(|x, y| {
-            if sort_err.is_ok() {
-                sort_compare(x, y, compare_fn, context).unwrap_or_else(|err| {
-                    sort_err = Err(err);
-                    Ordering::Equal
-                })
-            } else {
-                Ordering::Equal
+            // 9. If x is +0𝔽 and y is -0𝔽, return 1𝔽.
+            if x.is_zero()
+                && y.is_zero()
+                && x.as_inner().is_positive()
+                && y.as_inner().is_negative()
+            {
+                return Ok(Ordering::Greater);
             }
-        });
-        sort_err?;
 
-        // 9. Let j be 0.
-        // 10. Repeat, while j < itemCount,
-        for (j, item) in items.into_iter().enumerate() {
-            // a. Perform ? Set(obj, ! ToString(𝔽(j)), items[j], true).
-            obj.set(j, item, true, context)?;
-            // b. Set j to j + 1.
-        }
+            // 10. Return +0𝔽.
+            Ok(Ordering::Equal)
+        } else {
+            let x = x
+                .as_number()
+                .expect("Typed array can only contain number or bigint");
+            let y = y
+                .as_number()
+                .expect("Typed array can only contain number or bigint");
+
+            // 3. If x and y are both NaN, return +0𝔽.
+            if x.is_nan() && y.is_nan() {
+                return Ok(Ordering::Equal);
+            }
 
-        // 11. Repeat, while j < len,
-        for j in item_count..(len as usize) {
-            // a. Perform ? DeletePropertyOrThrow(obj, ! ToString(𝔽(j))).
-            obj.delete_property_or_throw(j, context)?;
-            // b. Set j to j + 1.
-        }
+            // 4. If x is NaN, return 1𝔽.
+            if x.is_nan() {
+                return Ok(Ordering::Greater);
+            }
 
-        // 12. Return obj.
-        Ok(obj.clone().into())
+            // 5. If y is NaN, return -1𝔽.
+            if y.is_nan() {
+                return Ok(Ordering::Less);
+            }
+
+            // 6. If x < y, return -1𝔽.
+            if x < y {
+                return Ok(Ordering::Less);
+            }
+
+            // 7. If x > y, return 1𝔽.
+            if x > y {
+                return Ok(Ordering::Greater);
+            }
+
+            // 8. If x is -0𝔽 and y is +0𝔽, return -1𝔽.
+            if x.is_zero() && y.is_zero() && x.is_sign_negative() && y.is_sign_positive() {
+                return Ok(Ordering::Less);
+            }
+
+            // 9. If x is +0𝔽 and y is -0𝔽, return 1𝔽.
+            if x.is_zero() && y.is_zero() && x.is_sign_positive() && y.is_sign_negative() {
+                return Ok(Ordering::Greater);
+            }
+
+            // 10. Return +0𝔽.
+            Ok(Ordering::Equal)
+        }
     }
 
     /// `23.2.3.28 %TypedArray%.prototype.subarray ( begin, end )`
@@ -2777,13 +2893,13 @@ impl TypedArray {
         // 1. Let O be the this value.
         // 2. Perform ? RequireInternalSlot(O, [[TypedArrayName]]).
         // 3. Assert: O has a [[ViewedArrayBuffer]] internal slot.
-        let obj = this
-            .as_object()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        let obj = this.as_object().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
         let obj_borrow = obj.borrow();
-        let o = obj_borrow
-            .as_typed_array()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        let o = obj_borrow.as_typed_array().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
 
         // 4. Let buffer be O.[[ViewedArrayBuffer]].
         let buffer = o
@@ -2852,6 +2968,94 @@ impl TypedArray {
 
     // TODO: 23.2.3.29 %TypedArray%.prototype.toLocaleString ( [ reserved1 [ , reserved2 ] ] )
 
+    /// `%TypedArray%.prototype.with ( index, value )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.with
+    pub(crate) fn with(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? ValidateTypedArray(O).
+        let obj = this.as_object().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
+        let (name, len) = {
+            let obj_borrow = obj.borrow();
+            let o = obj_borrow.as_typed_array().ok_or_else(|| {
+                JsNativeError::typ().with_message("Value is not a typed array object")
+            })?;
+            if o.is_detached() {
+                return context.throw_type_error("Buffer of the typed array is detached");
+            }
+
+            // 3. Let len be O.[[ArrayLength]].
+            (o.typed_array_name(), o.array_length())
+        };
+
+        // 4. Let relativeIndex be ? ToIntegerOrInfinity(index).
+        let relative_index = args.get_or_undefined(0).to_integer_or_infinity(context)?;
+
+        let actual_index = match relative_index {
+            // 5. If relativeIndex ≥ 0, let actualIndex be relativeIndex.
+            IntegerOrInfinity::Integer(i) if i >= 0 => Some(i as u64),
+            // 6. Else, let actualIndex be len + relativeIndex.
+            IntegerOrInfinity::Integer(i) => len.checked_sub(i.unsigned_abs()),
+            IntegerOrInfinity::PositiveInfinity | IntegerOrInfinity::NegativeInfinity => None,
+        };
+
+        let value = args.get_or_undefined(1);
+        // 7. If O.[[ContentType]] is BigInt, let numericValue be ? ToBigInt(value).
+        let numeric_value: JsValue = if name.content_type() == ContentType::BigInt {
+            value.to_bigint(context)?.into()
+        // 8. Else, let numericValue be ? ToNumber(value).
+        } else {
+            value.to_number(context)?.into()
+        };
+
+        // 9. If IsValidIntegerIndex(O, 𝔽(actualIndex)) is false, throw a RangeError exception.
+        let actual_index = match actual_index {
+            Some(i) => {
+                let obj_borrow = obj.borrow();
+                let o = obj_borrow
+                    .as_typed_array()
+                    .expect("Already checked for typed array");
+                if o.is_detached() || i >= o.array_length() {
+                    return context.throw_range_error("TypedArray.with: index out of range");
+                }
+                i
+            }
+            None => return context.throw_range_error("TypedArray.with: index out of range"),
+        };
+
+        // 10. Let A be ? TypedArrayCreateSameType(O, « 𝔽(len) »).
+        let a = Self::create_same_type(name, len, context)?;
+
+        // 11. Let k be 0.
+        // 12. Repeat, while k < len,
+        for k in 0..len {
+            // a. Let Pk be ! ToString(𝔽(k)).
+            // b. If k is actualIndex, let fromValue be numericValue.
+            // c. Else, let fromValue be ! Get(O, Pk).
+            let from_value = if k == actual_index {
+                numeric_value.clone()
+            } else {
+                obj.get(k, context).expect("Get cannot fail here")
+            };
+            // d. Perform ! Set(A, Pk, fromValue, true).
+            a.set(k, from_value, true, context)
+                .expect("Set cannot fail here");
+            // e. Set k to k + 1.
+        }
+
+        // 13. Return A.
+        Ok(a.into())
+    }
+
     /// `23.2.3.31 %TypedArray%.prototype.values ( )`
     ///
     /// More information:
@@ -2861,12 +3065,12 @@ impl TypedArray {
     fn values(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
         // 1. Let O be the this value.
         // 2. Perform ? ValidateTypedArray(O).
-        let o = this
-            .as_object()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        let o = this.as_object().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
         if o.borrow()
             .as_typed_array()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?
+            .ok_or_else(|| JsNativeError::typ().with_message("Value is not a typed array object"))?
             .is_detached()
         {
             return context.throw_type_error("Buffer of the typed array is detached");
@@ -2917,19 +3121,7 @@ impl TypedArray {
         context: &mut Context,
     ) -> JsResult<JsObject> {
         // 1. Let defaultConstructor be the intrinsic object listed in column one of Table 73 for exemplar.[[TypedArrayName]].
-        let default_constructor = match typed_array_name {
-            TypedArrayKind::Int8 => StandardConstructors::typed_int8_array,
-            TypedArrayKind::Uint8 => StandardConstructors::typed_uint8_array,
-            TypedArrayKind::Uint8Clamped => StandardConstructors::typed_uint8clamped_array,
-            TypedArrayKind::Int16 => StandardConstructors::typed_int16_array,
-            TypedArrayKind::Uint16 => StandardConstructors::typed_uint16_array,
-            TypedArrayKind::Int32 => StandardConstructors::typed_int32_array,
-            TypedArrayKind::Uint32 => StandardConstructors::typed_uint32_array,
-            TypedArrayKind::BigInt64 => StandardConstructors::typed_bigint64_array,
-            TypedArrayKind::BigUint64 => StandardConstructors::typed_biguint64_array,
-            TypedArrayKind::Float32 => StandardConstructors::typed_float32_array,
-            TypedArrayKind::Float64 => StandardConstructors::typed_float64_array,
-        };
+        let default_constructor = typed_array_name.standard_constructor();
 
         // 2. Let constructor be ? SpeciesConstructor(exemplar, defaultConstructor).
         let constructor = exemplar.species_constructor(default_constructor, context)?;
@@ -2971,9 +3163,9 @@ impl TypedArray {
 
         let obj_borrow = new_typed_array.borrow();
         // 2. Perform ? ValidateTypedArray(newTypedArray).
-        let o = obj_borrow
-            .as_typed_array()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        let o = obj_borrow.as_typed_array().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
         if o.is_detached() {
             return context.throw_type_error("Buffer of the typed array is detached");
         }
@@ -2993,6 +3185,32 @@ impl TypedArray {
         Ok(new_typed_array.clone())
     }
 
+    /// `TypedArrayCreateSameType ( exemplar, argumentList )`
+    ///
+    /// Unlike `TypedArraySpeciesCreate`, this always uses the intrinsic constructor of the
+    /// exemplar, and only supports the `« length »` argument list.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-typedarray-create-same-type
+    fn create_same_type(
+        typed_array_name: TypedArrayKind,
+        length: u64,
+        context: &mut Context,
+    ) -> JsResult<JsObject> {
+        // 1. Let constructor be the intrinsic object listed in column one of Table 73 for exemplar.[[TypedArrayName]].
+        let constructor =
+            typed_array_name.standard_constructor()(context.intrinsics().constructors())
+                .constructor();
+
+        // 2. Let result be ? TypedArrayCreate(constructor, argumentList).
+        // 3. Assert: result has [[TypedArrayName]] and [[ContentType]] internal slots.
+        // 4. Assert: result.[[ContentType]] is exemplar.[[ContentType]].
+        // 5. Return result.
+        Self::create(&constructor, &[length.into()], context)
+    }
+
     /// <https://tc39.es/ecma262/#sec-allocatetypedarraybuffer>
     fn allocate_buffer(
         indexed: &mut IntegerIndexed,
@@ -3416,6 +3634,26 @@ impl TypedArrayKind {
         }
     }
 
+    /// Gets the standard constructor of this typed array name, as listed in the [spec].
+    ///
+    /// [spec]: https://tc39.es/ecma262/#table-the-typedarray-constructors
+    #[inline]
+    pub(crate) fn standard_constructor(self) -> fn(&StandardConstructors) -> &StandardConstructor {
+        match self {
+            Self::Int8 => StandardConstructors::typed_int8_array,
+            Self::Uint8 => StandardConstructors::typed_uint8_array,
+            Self::Uint8Clamped => StandardConstructors::typed_uint8clamped_array,
+            Self::Int16 => StandardConstructors::typed_int16_array,
+            Self::Uint16 => StandardConstructors::typed_uint16_array,
+            Self::Int32 => StandardConstructors::typed_int32_array,
+            Self::Uint32 => StandardConstructors::typed_uint32_array,
+            Self::BigInt64 => StandardConstructors::typed_bigint64_array,
+            Self::BigUint64 => StandardConstructors::typed_biguint64_array,
+            Self::Float32 => StandardConstructors::typed_float32_array,
+            Self::Float64 => StandardConstructors::typed_float64_array,
+        }
+    }
+
     /// Gets the content type of this typed array name.
     #[inline]
     pub(crate) const fn content_type(self) -> ContentType {
@@ -0,0 +1,113 @@
+use crate::{forward, Context};
+
+#[test]
+fn typed_array_to_reversed() {
+    let mut context = Context::default();
+    forward(&mut context, "let ta = new Int8Array([1, 2, 3]);");
+    assert_eq!(forward(&mut context, "ta.toReversed().join()"), "\"3,2,1\"");
+    assert_eq!(forward(&mut context, "ta.join()"), "\"1,2,3\"");
+    assert_eq!(
+        forward(&mut context, "ta.toReversed() instanceof Int8Array"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "new Float64Array(0).toReversed().length"),
+        "0"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "new BigInt64Array([1n, 2n]).toReversed().join()"
+        ),
+        "\"2,1\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Int8Array.prototype.toReversed.call([1, 2]) } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn typed_array_to_sorted() {
+    let mut context = Context::default();
+    forward(&mut context, "let ta = new Int16Array([10, -3, 2, 1]);");
+    assert_eq!(
+        forward(&mut context, "ta.toSorted().join()"),
+        "\"-3,1,2,10\""
+    );
+    assert_eq!(
+        forward(&mut context, "ta.toSorted((a, b) => b - a).join()"),
+        "\"10,2,1,-3\""
+    );
+    assert_eq!(forward(&mut context, "ta.join()"), "\"10,-3,2,1\"");
+    assert_eq!(
+        forward(&mut context, "ta.toSorted() instanceof Int16Array"),
+        "true"
+    );
+
+    // The default comparison is numeric, with NaN last and -0 before +0.
+    assert_eq!(
+        forward(
+            &mut context,
+            "Array.from(new Float64Array([NaN, 0, -0, -Infinity]).toSorted(), x => Object.is(x, -0) ? '-0' : String(x)).join()"
+        ),
+        "\"-Infinity,-0,0,NaN\""
+    );
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { ta.toSorted(1) } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn typed_array_with() {
+    let mut context = Context::default();
+    forward(&mut context, "let ta = new Uint8Array([1, 2, 3]);");
+    assert_eq!(forward(&mut context, "ta.with(1, 7).join()"), "\"1,7,3\"");
+    assert_eq!(forward(&mut context, "ta.with(-1, 7).join()"), "\"1,2,7\"");
+    assert_eq!(forward(&mut context, "ta.join()"), "\"1,2,3\"");
+    assert_eq!(forward(&mut context, "ta.with(0, 256).join()"), "\"0,2,3\"");
+    assert_eq!(
+        forward(&mut context, "ta.with(0, 1) instanceof Uint8Array"),
+        "true"
+    );
+
+    for index in ["3", "-4", "Infinity"] {
+        assert_eq!(
+            forward(
+                &mut context,
+                format!("try {{ ta.with({index}, 0) }} catch (e) {{ e instanceof RangeError }}")
+            ),
+            "true"
+        );
+    }
+
+    // The value is converted before the index is checked.
+    forward(
+        &mut context,
+        "var converted = false; try { ta.with(5, { valueOf() { converted = true; return 0; } }) } catch (e) {}",
+    );
+    assert_eq!(forward(&mut context, "converted"), "true");
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "new BigInt64Array([1n, 2n]).with(0, 5n).join()"
+        ),
+        "\"5,2\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new BigInt64Array(1).with(0, 1) } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+}
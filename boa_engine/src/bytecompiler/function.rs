@@ -7,7 +7,7 @@ use crate::{
 };
 use boa_gc::Gc;
 use boa_interner::Sym;
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 
 /// `FunctionCompiler` is used to compile AST functions to bytecode.
 #[derive(Debug, Clone, Copy)]
@@ -145,6 +145,26 @@ impl FunctionCompiler {
             compiler.emit_opcode(Opcode::RestParameterPop);
         }
 
+        // If the parameters have their own environment, variables of the body with the same
+        // name as a parameter start with the value of the parameter.
+        let mut parameter_vars = Vec::new();
+        if parameters.has_expressions() {
+            let mut var_names = FxHashSet::default();
+            body.var_declared_names_new(&mut var_names);
+            for parameter in parameters.parameters.iter() {
+                match parameter.declaration() {
+                    Declaration::Identifier { ident, .. } => parameter_vars.push(ident.sym()),
+                    Declaration::Pattern(pattern) => parameter_vars.extend(pattern.idents()),
+                }
+            }
+            parameter_vars.retain(|name| var_names.contains(name));
+            for name in &parameter_vars {
+                let binding = compiler.context.get_binding_value(*name);
+                let index = compiler.get_or_insert_binding(binding);
+                compiler.emit(Opcode::GetName, &[index]);
+            }
+        }
+
         let env_label = if parameters.has_expressions() {
             compiler.code_block.num_bindings = compiler.context.get_binding_number();
             compiler.context.push_compile_time_environment(true);
@@ -155,6 +175,11 @@ impl FunctionCompiler {
             None
         };
 
+        for name in parameter_vars.into_iter().rev() {
+            compiler.context.create_mutable_binding(name, true);
+            compiler.emit_binding(BindingOpcode::InitVar, name);
+        }
+
         // When a generator object is created from a generator function, the generator executes until here to init parameters.
         if self.generator {
             compiler.emit_opcode(Opcode::PushUndefined);
@@ -1,9 +1,11 @@
 mod function;
 
 use crate::{
+    builtins::RegExp,
     environments::{BindingLocator, CompileTimeEnvironment},
     syntax::ast::{
         node::{
+            self,
             declaration::{
                 class_decl::ClassElement, BindingPatternTypeArray, BindingPatternTypeObject,
                 DeclarationPattern,
@@ -12,17 +14,17 @@ use crate::{
             object::{MethodDefinition, PropertyDefinition, PropertyName},
             operator::assign::AssignTarget,
             template::TemplateElement,
-            Class, Declaration, GetConstField, GetField, GetSuperField,
+            Call, Class, Declaration, GetConstField, GetField, GetSuperField, Object,
         },
         op::{AssignOp, BinOp, BitOp, CompOp, LogOp, NumOp, UnaryOp},
         Const, Node,
     },
     vm::{BindingOpcode, CodeBlock, Opcode},
-    Context, JsBigInt, JsResult, JsString, JsValue,
+    Context, JsBigInt, JsNativeError, JsResult, JsString, JsValue,
 };
 use boa_gc::Gc;
 use boa_interner::{Interner, Sym};
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 use std::mem::size_of;
 
 pub(crate) use function::FunctionCompiler;
@@ -59,6 +61,13 @@ enum JumpControlInfoKind {
     Try,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum CallKind {
+    CallEval,
+    Call,
+    New,
+}
+
 #[derive(Debug, Clone, Copy)]
 enum Access<'a> {
     Variable { name: Sym },
@@ -128,6 +137,18 @@ impl<'b> ByteCompiler<'b> {
         index
     }
 
+    /// Returns `true` if the object literal only defines data properties with literal names,
+    /// which allows creating it with precomputed property keys.
+    fn is_data_object_literal(object: &Object) -> bool {
+        !object.properties().is_empty()
+            && object.properties().iter().all(|property| {
+                matches!(
+                    property,
+                    PropertyDefinition::Property(PropertyName::Literal(_), _)
+                )
+            })
+    }
+
     #[inline]
     fn get_or_insert_name(&mut self, name: Sym) -> u32 {
         if let Some(index) = self.names_map.get(&name) {
@@ -567,17 +588,214 @@ impl<'b> ByteCompiler<'b> {
         Ok(())
     }
 
+    /// Gets the number of values that [`Self::access_get_reference`] pushes on the stack for the
+    /// reference of an access.
     #[inline]
-    pub fn compile_statement_list(&mut self, list: &[Node], use_expr: bool) -> JsResult<()> {
-        if let Some((last, items)) = list.split_last() {
-            for node in items {
-                self.compile_stmt(node, false)?;
+    fn reference_len(access: Access<'_>) -> u8 {
+        match access {
+            Access::Variable { .. } | Access::This => 0,
+            Access::ByName { .. } => 1,
+            Access::ByValue { .. } => 2,
+        }
+    }
+
+    /// Evaluates the reference of an access once, and pushes it followed by its value.
+    ///
+    /// The reference of a property access is its object, followed by its key if the key is an
+    /// expression, so that [`Self::access_set_reference`] can write to the same property without
+    /// evaluating them again.
+    ///
+    /// Stack: **=>** reference, value
+    #[inline]
+    fn access_get_reference(&mut self, access: Access<'_>) -> JsResult<()> {
+        match access {
+            Access::Variable { .. } | Access::This => self.access_get(access, true)?,
+            Access::ByName { node } => {
+                let index = self.get_or_insert_name(node.field());
+                self.compile_expr(node.obj(), true)?;
+                self.emit_opcode(Opcode::Dup);
+                self.emit(Opcode::GetPropertyByName, &[index]);
+            }
+            Access::ByValue { node } => {
+                self.compile_expr(node.obj(), true)?;
+                self.emit_opcode(Opcode::Dup);
+                self.compile_expr(node.field(), true)?;
+                self.emit_opcode(Opcode::Swap);
+                self.emit_opcode(Opcode::GetPropertyByValuePush);
+            }
+        }
+        Ok(())
+    }
+
+    /// Assigns the value on top of the stack to a reference pushed by
+    /// [`Self::access_get_reference`].
+    ///
+    /// Stack: reference, value **=>** value (if `use_expr` is `true`)
+    #[inline]
+    fn access_set_reference(&mut self, access: Access<'_>, use_expr: bool) {
+        if use_expr {
+            self.emit_opcode(Opcode::Dup);
+            let reference_len = Self::reference_len(access);
+            if reference_len > 0 {
+                self.emit_rotate_right(reference_len + 2);
+            }
+        }
+
+        match access {
+            Access::Variable { name } => {
+                let binding = self.context.set_mutable_binding(name);
+                let index = self.get_or_insert_binding(binding);
+                self.emit(Opcode::SetName, &[index]);
+            }
+            Access::ByName { node } => {
+                self.emit_opcode(Opcode::Swap);
+                let index = self.get_or_insert_name(node.field());
+                self.emit(Opcode::SetPropertyByName, &[index]);
+            }
+            Access::ByValue { .. } => {
+                self.emit_rotate_right(3);
+                self.emit_opcode(Opcode::Swap);
+                self.emit_opcode(Opcode::SetPropertyByValue);
+            }
+            Access::This => {
+                unreachable!("the parser rejects `this` as an assignment or update target")
+            }
+        }
+    }
+
+    /// Moves the value on top of the stack below the `n - 1` values under it.
+    #[inline]
+    fn emit_rotate_right(&mut self, n: u8) {
+        if n == 2 {
+            self.emit_opcode(Opcode::Swap);
+        } else {
+            self.emit_opcode(Opcode::RotateRight);
+            self.emit_u8(n);
+        }
+    }
+
+    /// Compiles a compound assignment like `a += b`, or a logical assignment like `a ??= b`.
+    ///
+    /// The reference of the target is evaluated once, before the right-hand side. A logical
+    /// assignment that short-circuits doesn't evaluate the right-hand side, and doesn't assign
+    /// anything.
+    #[inline]
+    fn compile_compound_assign(
+        &mut self,
+        access: Access<'_>,
+        op: AssignOp,
+        rhs: &Node,
+        use_expr: bool,
+    ) -> JsResult<()> {
+        let opcode = match op {
+            AssignOp::Add => Opcode::Add,
+            AssignOp::Sub => Opcode::Sub,
+            AssignOp::Mul => Opcode::Mul,
+            AssignOp::Div => Opcode::Div,
+            AssignOp::Mod => Opcode::Mod,
+            AssignOp::Exp => Opcode::Pow,
+            AssignOp::And => Opcode::BitAnd,
+            AssignOp::Or => Opcode::BitOr,
+            AssignOp::Xor => Opcode::BitXor,
+            AssignOp::Shl => Opcode::ShiftLeft,
+            AssignOp::Shr => Opcode::ShiftRight,
+            AssignOp::Ushr => Opcode::UnsignedShiftRight,
+            AssignOp::BoolAnd => Opcode::LogicalAnd,
+            AssignOp::BoolOr => Opcode::LogicalOr,
+            AssignOp::Coalesce => Opcode::Coalesce,
+        };
+
+        self.access_get_reference(access)?;
+
+        if !matches!(
+            op,
+            AssignOp::BoolAnd | AssignOp::BoolOr | AssignOp::Coalesce
+        ) {
+            self.compile_expr(rhs, true)?;
+            self.emit_opcode(opcode);
+            self.access_set_reference(access, use_expr);
+            return Ok(());
+        }
+
+        // The short-circuiting jump keeps the value of the target on the stack, and otherwise
+        // pops it.
+        let short_circuit = self.emit_opcode_with_operand(opcode);
+        self.compile_expr(rhs, true)?;
+        self.access_set_reference(access, use_expr);
+        let exit = self.jump();
+
+        self.patch_jump(short_circuit);
+        let reference_len = Self::reference_len(access);
+        if use_expr {
+            self.emit_rotate_right(reference_len + 1);
+        } else {
+            self.emit_opcode(Opcode::Pop);
+        }
+        for _ in 0..reference_len {
+            self.emit_opcode(Opcode::Pop);
+        }
+        self.patch_jump(exit);
+
+        Ok(())
+    }
+
+    /// Compiles an increment or decrement expression like `a++` or `--a`, pushing its value.
+    ///
+    /// The reference of the target is evaluated once. The value of a postfix expression is the
+    /// value of the target converted to a numeric value, before it is updated.
+    #[inline]
+    fn compile_update(&mut self, access: Access<'_>, op: UnaryOp) -> JsResult<()> {
+        let opcode = match op {
+            UnaryOp::IncrementPre => Opcode::Inc,
+            UnaryOp::DecrementPre => Opcode::Dec,
+            UnaryOp::IncrementPost => Opcode::IncPost,
+            UnaryOp::DecrementPost => Opcode::DecPost,
+            _ => unreachable!("not an update expression"),
+        };
+
+        self.access_get_reference(access)?;
+        self.emit_opcode(opcode);
+
+        if matches!(op, UnaryOp::IncrementPost | UnaryOp::DecrementPost) {
+            // Stack: reference, old value, new value => old value, reference, new value
+            let reference_len = Self::reference_len(access);
+            if reference_len > 0 {
+                self.emit_opcode(Opcode::Swap);
+                self.emit_rotate_right(reference_len + 2);
             }
-            self.compile_stmt(last, use_expr)?;
+            self.access_set_reference(access, false);
+        } else {
+            self.access_set_reference(access, true);
         }
         Ok(())
     }
 
+    /// Compile a statement list.
+    ///
+    /// If `use_expr` is `true`, the statements of the list update the completion value of the
+    /// script or `eval` code, which ends up being the value of the last statement that produces
+    /// one.
+    #[inline]
+    pub fn compile_statement_list(&mut self, list: &[Node], use_expr: bool) -> JsResult<()> {
+        for node in list {
+            self.compile_stmt(node, use_expr)?;
+        }
+        Ok(())
+    }
+
+    /// Sets the completion value of the script or `eval` code to `undefined`.
+    ///
+    /// This is emitted at the start of the statements whose completion value is `undefined` if
+    /// none of their inner statements produce a value, like an `if` statement whose condition
+    /// doesn't hold or a loop that never runs its body.
+    #[inline]
+    fn emit_undefined_completion_value(&mut self, use_expr: bool) {
+        if use_expr {
+            self.emit_opcode(Opcode::PushUndefined);
+            self.emit_opcode(Opcode::SetCompletionValue);
+        }
+    }
+
     /// Compile a statement list in a new declarative environment.
     #[inline]
     pub(crate) fn compile_statement_list_with_new_declarative(
@@ -590,13 +808,7 @@ impl<'b> ByteCompiler<'b> {
         let push_env = self.emit_opcode_with_two_operands(Opcode::PushDeclarativeEnvironment);
 
         self.create_declarations(list)?;
-
-        if let Some((last, items)) = list.split_last() {
-            for node in items {
-                self.compile_stmt(node, false)?;
-            }
-            self.compile_stmt(last, use_expr)?;
-        }
+        self.compile_statement_list(list, use_expr)?;
 
         let (num_bindings, compile_environment) = self.context.pop_compile_time_environment();
         let index_compile_environment = self.push_compile_environment(compile_environment);
@@ -608,13 +820,191 @@ impl<'b> ByteCompiler<'b> {
     }
 
     #[inline]
+    /// Compiles a binary operation that isn't an assignment.
+    ///
+    /// The left operand of a chain of operations, like `a + b + c`, is the rest of the chain. The
+    /// chain is compiled in a loop instead of recursively, so that long chains don't overflow the
+    /// stack.
+    fn compile_binary(&mut self, binary: &node::BinOp, use_expr: bool) -> JsResult<()> {
+        let mut chain = vec![binary];
+        let mut lhs = binary.lhs();
+        while let Node::BinOp(binary) = lhs {
+            if let BinOp::Assign(_) = binary.op() {
+                break;
+            }
+            chain.push(binary);
+            lhs = binary.lhs();
+        }
+        self.compile_expr(lhs, true)?;
+
+        for binary in chain.into_iter().rev() {
+            match binary.op() {
+                BinOp::Num(op) => {
+                    self.compile_expr(binary.rhs(), true)?;
+                    match op {
+                        NumOp::Add => self.emit_opcode(Opcode::Add),
+                        NumOp::Sub => self.emit_opcode(Opcode::Sub),
+                        NumOp::Div => self.emit_opcode(Opcode::Div),
+                        NumOp::Mul => self.emit_opcode(Opcode::Mul),
+                        NumOp::Exp => self.emit_opcode(Opcode::Pow),
+                        NumOp::Mod => self.emit_opcode(Opcode::Mod),
+                    }
+                }
+                BinOp::Bit(op) => {
+                    self.compile_expr(binary.rhs(), true)?;
+                    match op {
+                        BitOp::And => self.emit_opcode(Opcode::BitAnd),
+                        BitOp::Or => self.emit_opcode(Opcode::BitOr),
+                        BitOp::Xor => self.emit_opcode(Opcode::BitXor),
+                        BitOp::Shl => self.emit_opcode(Opcode::ShiftLeft),
+                        BitOp::Shr => self.emit_opcode(Opcode::ShiftRight),
+                        BitOp::UShr => self.emit_opcode(Opcode::UnsignedShiftRight),
+                    }
+                }
+                BinOp::Comp(op) => {
+                    self.compile_expr(binary.rhs(), true)?;
+                    match op {
+                        CompOp::Equal => self.emit_opcode(Opcode::Eq),
+                        CompOp::NotEqual => self.emit_opcode(Opcode::NotEq),
+                        CompOp::StrictEqual => self.emit_opcode(Opcode::StrictEq),
+                        CompOp::StrictNotEqual => self.emit_opcode(Opcode::StrictNotEq),
+                        CompOp::GreaterThan => self.emit_opcode(Opcode::GreaterThan),
+                        CompOp::GreaterThanOrEqual => self.emit_opcode(Opcode::GreaterThanOrEq),
+                        CompOp::LessThan => self.emit_opcode(Opcode::LessThan),
+                        CompOp::LessThanOrEqual => self.emit_opcode(Opcode::LessThanOrEq),
+                        CompOp::In => self.emit_opcode(Opcode::In),
+                        CompOp::InstanceOf => self.emit_opcode(Opcode::InstanceOf),
+                    }
+                }
+                BinOp::Log(op) => {
+                    let exit = match op {
+                        LogOp::And => self.emit_opcode_with_operand(Opcode::LogicalAnd),
+                        LogOp::Or => self.emit_opcode_with_operand(Opcode::LogicalOr),
+                        LogOp::Coalesce => self.emit_opcode_with_operand(Opcode::Coalesce),
+                    };
+                    self.compile_expr(binary.rhs(), true)?;
+                    self.patch_jump(exit);
+                }
+                BinOp::Comma => {
+                    self.emit(Opcode::Pop, &[]);
+                    self.compile_expr(binary.rhs(), true)?;
+                }
+                BinOp::Assign(_) => unreachable!("assignments are not part of a chain"),
+            }
+        }
+
+        if !use_expr {
+            self.emit(Opcode::Pop, &[]);
+        }
+        Ok(())
+    }
+
+    /// Compiles a chain of property accesses and calls, like `a.b[c]().d`.
+    ///
+    /// Every link of the chain is nested in the next one. Like chains of binary operations, the
+    /// chain is compiled in a loop instead of recursively.
+    fn compile_chain(&mut self, expr: &Node, use_expr: bool) -> JsResult<()> {
+        enum Link<'a> {
+            Access(Access<'a>),
+            Call {
+                call: &'a Call,
+                method: Option<Access<'a>>,
+            },
+        }
+
+        let mut links = Vec::new();
+        let mut root = expr;
+        loop {
+            match root {
+                Node::GetConstField(node) => {
+                    links.push(Link::Access(Access::ByName { node }));
+                    root = node.obj();
+                }
+                Node::GetField(node) => {
+                    links.push(Link::Access(Access::ByValue { node }));
+                    root = node.obj();
+                }
+                Node::Call(call) => match call.expr() {
+                    Node::GetConstField(node) => {
+                        let method = Some(Access::ByName { node });
+                        links.push(Link::Call { call, method });
+                        root = node.obj();
+                    }
+                    Node::GetField(node) => {
+                        let method = Some(Access::ByValue { node });
+                        links.push(Link::Call { call, method });
+                        root = node.obj();
+                    }
+                    Node::Identifier(ident) if ident.sym() == Sym::EVAL => break,
+                    Node::GetSuperField(_) | Node::GetPrivateField(_) => break,
+                    callee => {
+                        links.push(Link::Call { call, method: None });
+                        root = callee;
+                    }
+                },
+                _ => break,
+            }
+        }
+
+        if let Node::Call(_) = root {
+            self.call(root, true)?;
+        } else {
+            self.compile_expr(root, true)?;
+        }
+
+        for link in links.into_iter().rev() {
+            match link {
+                Link::Access(access) => self.emit_chain_access(access)?,
+                Link::Call {
+                    call,
+                    method: Some(access),
+                } => {
+                    self.emit_opcode(Opcode::Dup);
+                    self.emit_chain_access(access)?;
+                    self.call_arguments(call, CallKind::Call)?;
+                }
+                Link::Call { call, method: None } => {
+                    self.emit_opcode(Opcode::PushUndefined);
+                    self.emit_opcode(Opcode::Swap);
+                    self.call_arguments(call, CallKind::Call)?;
+                }
+            }
+        }
+
+        if !use_expr {
+            self.emit(Opcode::Pop, &[]);
+        }
+        Ok(())
+    }
+
+    /// Gets the property of a property access from the object on the top of the stack.
+    ///
+    /// Stack: object **=>** value
+    fn emit_chain_access(&mut self, access: Access<'_>) -> JsResult<()> {
+        match access {
+            Access::ByName { node } => {
+                let index = self.get_or_insert_name(node.field());
+                self.emit(Opcode::GetPropertyByName, &[index]);
+            }
+            Access::ByValue { node } => {
+                self.compile_expr(node.field(), true)?;
+                self.emit_opcode(Opcode::Swap);
+                self.emit_opcode(Opcode::GetPropertyByValue);
+            }
+            Access::Variable { .. } | Access::This => {
+                unreachable!("only property accesses are part of a chain")
+            }
+        }
+        Ok(())
+    }
+
     pub fn compile_expr(&mut self, expr: &Node, use_expr: bool) -> JsResult<()> {
         match expr {
             Node::Const(c) => {
                 match c {
-                    Const::String(v) => self.emit_push_literal(Literal::String(
-                        self.interner().resolve_expect(*v).into(),
-                    )),
+                    Const::String(v) => {
+                        self.emit_push_literal(Literal::String(self.context.interned_string(*v)));
+                    }
                     Const::Int(v) => self.emit_push_integer(*v),
                     Const::Num(v) => self.emit_push_rational(*v),
                     Const::BigInt(v) => self.emit_push_literal(Literal::BigInt(v.clone().into())),
@@ -630,50 +1020,20 @@ impl<'b> ByteCompiler<'b> {
             }
             Node::UnaryOp(unary) => {
                 let opcode = match unary.op() {
-                    UnaryOp::IncrementPre => {
-                        self.compile_expr(unary.target(), true)?;
-                        self.emit(Opcode::Inc, &[]);
-
+                    UnaryOp::IncrementPre
+                    | UnaryOp::DecrementPre
+                    | UnaryOp::IncrementPost
+                    | UnaryOp::DecrementPost => {
                         let access = Self::compile_access(unary.target()).ok_or_else(|| {
-                            self.context
-                                .construct_syntax_error("Invalid increment operand")
-                        })?;
-                        self.access_set(access, None, true)?;
-                        None
-                    }
-                    UnaryOp::DecrementPre => {
-                        self.compile_expr(unary.target(), true)?;
-                        self.emit(Opcode::Dec, &[]);
-
-                        let access = Self::compile_access(unary.target()).ok_or_else(|| {
-                            self.context
-                                .construct_syntax_error("Invalid decrement operand")
-                        })?;
-                        self.access_set(access, None, true)?;
-                        None
-                    }
-                    UnaryOp::IncrementPost => {
-                        self.compile_expr(unary.target(), true)?;
-                        self.emit(Opcode::IncPost, &[]);
-
-                        let access = Self::compile_access(unary.target()).ok_or_else(|| {
-                            self.context
-                                .construct_syntax_error("Invalid increment operand")
-                        })?;
-                        self.access_set(access, None, false)?;
-
-                        None
-                    }
-                    UnaryOp::DecrementPost => {
-                        self.compile_expr(unary.target(), true)?;
-                        self.emit(Opcode::DecPost, &[]);
-
-                        let access = Self::compile_access(unary.target()).ok_or_else(|| {
-                            self.context
-                                .construct_syntax_error("Invalid decrement operand")
+                            let message = match unary.op() {
+                                UnaryOp::IncrementPre | UnaryOp::IncrementPost => {
+                                    "Invalid increment operand"
+                                }
+                                _ => "Invalid decrement operand",
+                            };
+                            JsNativeError::syntax().with_message(message)
                         })?;
-                        self.access_set(access, None, false)?;
-
+                        self.compile_update(access, unary.op())?;
                         None
                     }
                     UnaryOp::Delete => match unary.target() {
@@ -728,153 +1088,35 @@ impl<'b> ByteCompiler<'b> {
                 }
             }
             Node::BinOp(binary) => {
-                self.compile_expr(binary.lhs(), true)?;
-                match binary.op() {
-                    BinOp::Num(op) => {
-                        self.compile_expr(binary.rhs(), true)?;
-                        match op {
-                            NumOp::Add => self.emit_opcode(Opcode::Add),
-                            NumOp::Sub => self.emit_opcode(Opcode::Sub),
-                            NumOp::Div => self.emit_opcode(Opcode::Div),
-                            NumOp::Mul => self.emit_opcode(Opcode::Mul),
-                            NumOp::Exp => self.emit_opcode(Opcode::Pow),
-                            NumOp::Mod => self.emit_opcode(Opcode::Mod),
-                        }
-
-                        if !use_expr {
-                            self.emit(Opcode::Pop, &[]);
-                        }
-                    }
-                    BinOp::Bit(op) => {
-                        self.compile_expr(binary.rhs(), true)?;
-                        match op {
-                            BitOp::And => self.emit_opcode(Opcode::BitAnd),
-                            BitOp::Or => self.emit_opcode(Opcode::BitOr),
-                            BitOp::Xor => self.emit_opcode(Opcode::BitXor),
-                            BitOp::Shl => self.emit_opcode(Opcode::ShiftLeft),
-                            BitOp::Shr => self.emit_opcode(Opcode::ShiftRight),
-                            BitOp::UShr => self.emit_opcode(Opcode::UnsignedShiftRight),
-                        }
-
-                        if !use_expr {
-                            self.emit(Opcode::Pop, &[]);
-                        }
-                    }
-                    BinOp::Comp(op) => {
-                        self.compile_expr(binary.rhs(), true)?;
-                        match op {
-                            CompOp::Equal => self.emit_opcode(Opcode::Eq),
-                            CompOp::NotEqual => self.emit_opcode(Opcode::NotEq),
-                            CompOp::StrictEqual => self.emit_opcode(Opcode::StrictEq),
-                            CompOp::StrictNotEqual => self.emit_opcode(Opcode::StrictNotEq),
-                            CompOp::GreaterThan => self.emit_opcode(Opcode::GreaterThan),
-                            CompOp::GreaterThanOrEqual => self.emit_opcode(Opcode::GreaterThanOrEq),
-                            CompOp::LessThan => self.emit_opcode(Opcode::LessThan),
-                            CompOp::LessThanOrEqual => self.emit_opcode(Opcode::LessThanOrEq),
-                            CompOp::In => self.emit_opcode(Opcode::In),
-                            CompOp::InstanceOf => self.emit_opcode(Opcode::InstanceOf),
-                        }
-
-                        if !use_expr {
-                            self.emit(Opcode::Pop, &[]);
-                        }
-                    }
-                    BinOp::Log(op) => {
-                        match op {
-                            LogOp::And => {
-                                let exit = self.emit_opcode_with_operand(Opcode::LogicalAnd);
-                                self.compile_expr(binary.rhs(), true)?;
-                                self.patch_jump(exit);
-                            }
-                            LogOp::Or => {
-                                let exit = self.emit_opcode_with_operand(Opcode::LogicalOr);
-                                self.compile_expr(binary.rhs(), true)?;
-                                self.patch_jump(exit);
-                            }
-                            LogOp::Coalesce => {
-                                let exit = self.emit_opcode_with_operand(Opcode::Coalesce);
-                                self.compile_expr(binary.rhs(), true)?;
-                                self.patch_jump(exit);
-                            }
-                        };
-
-                        if !use_expr {
-                            self.emit(Opcode::Pop, &[]);
-                        }
-                    }
-                    BinOp::Assign(op) => {
-                        let opcode = match op {
-                            AssignOp::Add => Some(Opcode::Add),
-                            AssignOp::Sub => Some(Opcode::Sub),
-                            AssignOp::Mul => Some(Opcode::Mul),
-                            AssignOp::Div => Some(Opcode::Div),
-                            AssignOp::Mod => Some(Opcode::Mod),
-                            AssignOp::Exp => Some(Opcode::Pow),
-                            AssignOp::And => Some(Opcode::BitAnd),
-                            AssignOp::Or => Some(Opcode::BitOr),
-                            AssignOp::Xor => Some(Opcode::BitXor),
-                            AssignOp::Shl => Some(Opcode::ShiftLeft),
-                            AssignOp::Shr => Some(Opcode::ShiftRight),
-                            AssignOp::Ushr => Some(Opcode::UnsignedShiftRight),
-                            AssignOp::BoolAnd => {
-                                let exit = self.emit_opcode_with_operand(Opcode::LogicalAnd);
-                                self.compile_expr(binary.rhs(), true)?;
-                                let access =
-                                    Self::compile_access(binary.lhs()).ok_or_else(|| {
-                                        self.context.construct_syntax_error(
-                                            "Invalid left-hand side in assignment",
-                                        )
-                                    })?;
-                                self.access_set(access, None, use_expr)?;
-                                self.patch_jump(exit);
-                                None
-                            }
-                            AssignOp::BoolOr => {
-                                let exit = self.emit_opcode_with_operand(Opcode::LogicalOr);
-                                self.compile_expr(binary.rhs(), true)?;
-                                let access =
-                                    Self::compile_access(binary.lhs()).ok_or_else(|| {
-                                        self.context.construct_syntax_error(
-                                            "Invalid left-hand side in assignment",
-                                        )
-                                    })?;
-                                self.access_set(access, None, use_expr)?;
-                                self.patch_jump(exit);
-                                None
-                            }
-                            AssignOp::Coalesce => {
-                                let exit = self.emit_opcode_with_operand(Opcode::Coalesce);
-                                self.compile_expr(binary.rhs(), true)?;
-                                let access =
-                                    Self::compile_access(binary.lhs()).ok_or_else(|| {
-                                        self.context.construct_syntax_error(
-                                            "Invalid left-hand side in assignment",
-                                        )
-                                    })?;
-                                self.access_set(access, None, use_expr)?;
-                                self.patch_jump(exit);
-                                None
-                            }
-                        };
-
-                        if let Some(opcode) = opcode {
-                            self.compile_expr(binary.rhs(), true)?;
-                            self.emit(opcode, &[]);
-                            let access = Self::compile_access(binary.lhs()).ok_or_else(|| {
-                                self.context
-                                    .construct_syntax_error("Invalid left-hand side in assignment")
-                            })?;
-                            self.access_set(access, None, use_expr)?;
-                        }
+                if let BinOp::Assign(op) = binary.op() {
+                    let access = Self::compile_access(binary.lhs()).ok_or_else(|| {
+                        JsNativeError::syntax().with_message("Invalid left-hand side in assignment")
+                    })?;
+                    self.compile_compound_assign(access, op, binary.rhs(), use_expr)?;
+                } else {
+                    self.compile_binary(binary, use_expr)?;
+                }
+            }
+            Node::Object(object) if Self::is_data_object_literal(object) => {
+                let mut keys = Vec::with_capacity(object.properties().len());
+                for property in object.properties() {
+                    if let PropertyDefinition::Property(PropertyName::Literal(name), node) =
+                        property
+                    {
+                        self.compile_expr(node, true)?;
+                        self.emit_opcode(Opcode::PopOnReturnAdd);
+                        keys.push(self.context.interned_string(*name).into());
                     }
-                    BinOp::Comma => {
-                        self.emit(Opcode::Pop, &[]);
-                        self.compile_expr(binary.rhs(), true)?;
+                }
 
-                        if !use_expr {
-                            self.emit(Opcode::Pop, &[]);
-                        }
-                    }
+                let index = self.code_block.object_literals.len() as u32;
+                self.code_block
+                    .object_literals
+                    .push(keys.into_boxed_slice());
+                self.emit(Opcode::PushObjectLiteral, &[index]);
+
+                if !use_expr {
+                    self.emit(Opcode::Pop, &[]);
                 }
             }
             Node::Object(object) => {
@@ -888,15 +1130,15 @@ impl<'b> ByteCompiler<'b> {
                         }
                         PropertyDefinition::Property(name, node) => match name {
                             PropertyName::Literal(name) => {
-                                self.compile_stmt(node, true)?;
+                                self.compile_expr(node, true)?;
                                 self.emit_opcode(Opcode::Swap);
                                 let index = self.get_or_insert_name(*name);
                                 self.emit(Opcode::DefineOwnPropertyByName, &[index]);
                             }
                             PropertyName::Computed(name_node) => {
-                                self.compile_stmt(name_node, true)?;
+                                self.compile_expr(name_node, true)?;
                                 self.emit_opcode(Opcode::ToPropertyKey);
-                                self.compile_stmt(node, true)?;
+                                self.compile_expr(node, true)?;
                                 self.emit_opcode(Opcode::DefineOwnPropertyByValue);
                             }
                         },
@@ -909,7 +1151,7 @@ impl<'b> ByteCompiler<'b> {
                                     self.emit(Opcode::SetPropertyGetterByName, &[index]);
                                 }
                                 PropertyName::Computed(name_node) => {
-                                    self.compile_stmt(name_node, true)?;
+                                    self.compile_expr(name_node, true)?;
                                     self.emit_opcode(Opcode::ToPropertyKey);
                                     self.function(&expr.clone().into(), true)?;
                                     self.emit_opcode(Opcode::SetPropertyGetterByValue);
@@ -923,7 +1165,7 @@ impl<'b> ByteCompiler<'b> {
                                     self.emit(Opcode::SetPropertySetterByName, &[index]);
                                 }
                                 PropertyName::Computed(name_node) => {
-                                    self.compile_stmt(name_node, true)?;
+                                    self.compile_expr(name_node, true)?;
                                     self.emit_opcode(Opcode::ToPropertyKey);
                                     self.function(&expr.clone().into(), true)?;
                                     self.emit_opcode(Opcode::SetPropertySetterByValue);
@@ -937,7 +1179,7 @@ impl<'b> ByteCompiler<'b> {
                                     self.emit(Opcode::DefineOwnPropertyByName, &[index]);
                                 }
                                 PropertyName::Computed(name_node) => {
-                                    self.compile_stmt(name_node, true)?;
+                                    self.compile_expr(name_node, true)?;
                                     self.emit_opcode(Opcode::ToPropertyKey);
                                     self.function(&expr.clone().into(), true)?;
                                     self.emit_opcode(Opcode::DefineOwnPropertyByValue);
@@ -951,7 +1193,7 @@ impl<'b> ByteCompiler<'b> {
                                     self.emit(Opcode::DefineOwnPropertyByName, &[index]);
                                 }
                                 PropertyName::Computed(name_node) => {
-                                    self.compile_stmt(name_node, true)?;
+                                    self.compile_expr(name_node, true)?;
                                     self.emit_opcode(Opcode::ToPropertyKey);
                                     self.function(&expr.clone().into(), true)?;
                                     self.emit_opcode(Opcode::DefineOwnPropertyByValue);
@@ -965,7 +1207,7 @@ impl<'b> ByteCompiler<'b> {
                                     self.emit(Opcode::DefineOwnPropertyByName, &[index]);
                                 }
                                 PropertyName::Computed(name_node) => {
-                                    self.compile_stmt(name_node, true)?;
+                                    self.compile_expr(name_node, true)?;
                                     self.emit_opcode(Opcode::ToPropertyKey);
                                     self.function(&expr.clone().into(), true)?;
                                     self.emit_opcode(Opcode::DefineOwnPropertyByValue);
@@ -979,7 +1221,7 @@ impl<'b> ByteCompiler<'b> {
                                     self.emit(Opcode::DefineOwnPropertyByName, &[index]);
                                 }
                                 PropertyName::Computed(name_node) => {
-                                    self.compile_stmt(name_node, true)?;
+                                    self.compile_expr(name_node, true)?;
                                     self.emit_opcode(Opcode::ToPropertyKey);
                                     self.function(&expr.clone().into(), true)?;
                                     self.emit_opcode(Opcode::DefineOwnPropertyByValue);
@@ -1038,32 +1280,26 @@ impl<'b> ByteCompiler<'b> {
                     self.compile_declaration_pattern(pattern, BindingOpcode::SetName)?;
                 }
             },
-            Node::GetConstField(node) => {
-                let access = Access::ByName { node };
-                self.access_get(access, use_expr)?;
-            }
             Node::GetPrivateField(node) => {
                 let index = self.get_or_insert_name(node.field());
                 self.compile_expr(node.obj(), true)?;
                 self.emit(Opcode::GetPrivateField, &[index]);
             }
-            Node::GetField(node) => {
-                let access = Access::ByValue { node };
-                self.access_get(access, use_expr)?;
-            }
             Node::GetSuperField(get_super_field) => match get_super_field {
                 GetSuperField::Const(field) => {
                     let index = self.get_or_insert_name(*field);
+                    self.emit_opcode(Opcode::This);
                     self.emit_opcode(Opcode::Super);
-                    self.emit(Opcode::GetPropertyByName, &[index]);
+                    self.emit(Opcode::GetSuperPropertyByName, &[index]);
                     if !use_expr {
                         self.emit_opcode(Opcode::Pop);
                     }
                 }
                 GetSuperField::Expr(expr) => {
+                    self.emit_opcode(Opcode::This);
                     self.compile_expr(expr, true)?;
                     self.emit_opcode(Opcode::Super);
-                    self.emit_opcode(Opcode::GetPropertyByValue);
+                    self.emit_opcode(Opcode::GetSuperPropertyByValue);
                     if !use_expr {
                         self.emit_opcode(Opcode::Pop);
                     }
@@ -1109,16 +1345,38 @@ impl<'b> ByteCompiler<'b> {
             Node::This => {
                 self.access_get(Access::This, use_expr)?;
             }
+            Node::RegExpLiteral(regexp) => {
+                let pattern = self.interner().resolve_expect(regexp.pattern()).into();
+                let flags = self.interner().resolve_expect(regexp.flags()).into();
+                let regexp = match RegExp::compile(pattern, flags) {
+                    Ok(regexp) => regexp,
+                    Err(msg) => {
+                        return self.context.throw_syntax_error(format!(
+                            "invalid regular expression literal: {msg}"
+                        ))
+                    }
+                };
+
+                let index = self.code_block.regexps.len() as u32;
+                self.code_block.regexps.push(regexp);
+                self.emit(Opcode::PushRegExp, &[index]);
+
+                if !use_expr {
+                    self.emit(Opcode::Pop, &[]);
+                }
+            }
             Node::Spread(spread) => self.compile_expr(spread.val(), true)?,
             Node::FunctionExpr(_function) => self.function(expr, use_expr)?,
             Node::ArrowFunctionDecl(_function) => self.function(expr, use_expr)?,
-            Node::Call(_) | Node::New(_) => self.call(expr, use_expr)?,
+            Node::GetConstField(_) | Node::GetField(_) | Node::Call(_) => {
+                self.compile_chain(expr, use_expr)?;
+            }
+            Node::New(_) => self.call(expr, use_expr)?,
             Node::TemplateLit(template_literal) => {
                 for element in template_literal.elements() {
                     match element {
-                        TemplateElement::String(s) => self.emit_push_literal(Literal::String(
-                            self.interner().resolve_expect(*s).into(),
-                        )),
+                        TemplateElement::String(s) => self
+                            .emit_push_literal(Literal::String(self.context.interned_string(*s))),
                         TemplateElement::Expr(expr) => {
                             self.compile_expr(expr, true)?;
                         }
@@ -1205,7 +1463,7 @@ impl<'b> ByteCompiler<'b> {
                 for cooked in template.cookeds() {
                     if let Some(cooked) = cooked {
                         self.emit_push_literal(Literal::String(
-                            self.interner().resolve_expect(*cooked).into(),
+                            self.context.interned_string(*cooked),
                         ));
                     } else {
                         self.emit_opcode(Opcode::PushUndefined);
@@ -1216,9 +1474,7 @@ impl<'b> ByteCompiler<'b> {
 
                 self.emit_opcode(Opcode::PushNewArray);
                 for raw in template.raws() {
-                    self.emit_push_literal(Literal::String(
-                        self.interner().resolve_expect(*raw).into(),
-                    ));
+                    self.emit_push_literal(Literal::String(self.context.interned_string(*raw)));
                     self.emit_opcode(Opcode::PushValueToArray);
                 }
 
@@ -1344,6 +1600,7 @@ impl<'b> ByteCompiler<'b> {
                 }
             }
             Node::If(node) => {
+                self.emit_undefined_completion_value(use_expr);
                 self.compile_expr(node.cond(), true)?;
                 let jelse = self.jump_if_false();
 
@@ -1351,7 +1608,7 @@ impl<'b> ByteCompiler<'b> {
                     self.create_decls_from_stmt(node.body())?;
                 }
 
-                self.compile_stmt(node.body(), false)?;
+                self.compile_stmt(node.body(), use_expr)?;
 
                 match node.else_node() {
                     None => {
@@ -1363,20 +1620,25 @@ impl<'b> ByteCompiler<'b> {
                         if !matches!(else_body, Node::Block(_)) {
                             self.create_decls_from_stmt(else_body)?;
                         }
-                        self.compile_stmt(else_body, false)?;
+                        self.compile_stmt(else_body, use_expr)?;
                         self.patch_jump(exit);
                     }
                 }
             }
             Node::ForLoop(for_loop) => {
+                self.emit_undefined_completion_value(use_expr);
                 self.context.push_compile_time_environment(false);
                 let push_env =
                     self.emit_opcode_with_two_operands(Opcode::PushDeclarativeEnvironment);
 
+                let per_iteration_lets = matches!(for_loop.init(), Some(Node::LetDeclList(_)));
                 if let Some(init) = for_loop.init() {
                     self.create_decls_from_stmt(init)?;
                     self.compile_stmt(init, false)?;
                 }
+                if per_iteration_lets {
+                    self.emit_opcode(Opcode::CopyDeclarativeEnvironment);
+                }
 
                 self.emit_opcode(Opcode::LoopStart);
                 let initial_jump = self.jump();
@@ -1385,6 +1647,9 @@ impl<'b> ByteCompiler<'b> {
                 self.push_loop_control_info(for_loop.label(), start_address);
 
                 self.emit_opcode(Opcode::LoopContinue);
+                if per_iteration_lets {
+                    self.emit_opcode(Opcode::CopyDeclarativeEnvironment);
+                }
                 if let Some(final_expr) = for_loop.final_expr() {
                     self.compile_expr(final_expr, false)?;
                 }
@@ -1401,7 +1666,7 @@ impl<'b> ByteCompiler<'b> {
                 if !matches!(for_loop.body(), Node::Block(_)) {
                     self.create_decls_from_stmt(for_loop.body())?;
                 }
-                self.compile_stmt(for_loop.body(), false)?;
+                self.compile_stmt(for_loop.body(), use_expr)?;
 
                 self.emit(Opcode::Jump, &[start_address]);
 
@@ -1417,6 +1682,7 @@ impl<'b> ByteCompiler<'b> {
                 self.emit_opcode(Opcode::PopEnvironment);
             }
             Node::ForInLoop(for_in_loop) => {
+                self.emit_undefined_completion_value(use_expr);
                 let init_bound_names = for_in_loop.init().bound_names();
                 if init_bound_names.is_empty() {
                     self.compile_expr(for_in_loop.expr(), true)?;
@@ -1502,7 +1768,7 @@ impl<'b> ByteCompiler<'b> {
                     }
                 }
 
-                self.compile_stmt(for_in_loop.body(), false)?;
+                self.compile_stmt(for_in_loop.body(), use_expr)?;
 
                 let (num_bindings, compile_environment) =
                     self.context.pop_compile_time_environment();
@@ -1521,6 +1787,7 @@ impl<'b> ByteCompiler<'b> {
                 self.patch_jump(early_exit);
             }
             Node::ForOfLoop(for_of_loop) => {
+                self.emit_undefined_completion_value(use_expr);
                 let init_bound_names = for_of_loop.init().bound_names();
                 if init_bound_names.is_empty() {
                     self.compile_expr(for_of_loop.iterable(), true)?;
@@ -1606,7 +1873,7 @@ impl<'b> ByteCompiler<'b> {
                     }
                 }
 
-                self.compile_stmt(for_of_loop.body(), false)?;
+                self.compile_stmt(for_of_loop.body(), use_expr)?;
 
                 let (num_bindings, compile_environment) =
                     self.context.pop_compile_time_environment();
@@ -1623,6 +1890,7 @@ impl<'b> ByteCompiler<'b> {
                 self.emit_opcode(Opcode::IteratorClose);
             }
             Node::WhileLoop(while_) => {
+                self.emit_undefined_completion_value(use_expr);
                 self.emit_opcode(Opcode::LoopStart);
                 let start_address = self.next_opcode_location();
                 self.push_loop_control_info(while_.label(), start_address);
@@ -1630,7 +1898,7 @@ impl<'b> ByteCompiler<'b> {
 
                 self.compile_expr(while_.cond(), true)?;
                 let exit = self.jump_if_false();
-                self.compile_stmt(while_.body(), false)?;
+                self.compile_stmt(while_.body(), use_expr)?;
                 self.emit(Opcode::Jump, &[start_address]);
                 self.patch_jump(exit);
 
@@ -1638,6 +1906,7 @@ impl<'b> ByteCompiler<'b> {
                 self.emit_opcode(Opcode::LoopEnd);
             }
             Node::DoWhileLoop(do_while) => {
+                self.emit_undefined_completion_value(use_expr);
                 self.emit_opcode(Opcode::LoopStart);
                 let initial_label = self.jump();
 
@@ -1651,7 +1920,7 @@ impl<'b> ByteCompiler<'b> {
 
                 self.patch_jump(initial_label);
 
-                self.compile_stmt(do_while.body(), false)?;
+                self.compile_stmt(do_while.body(), use_expr)?;
                 self.emit(Opcode::Jump, &[condition_label_address]);
                 self.patch_jump(exit);
 
@@ -1711,7 +1980,7 @@ impl<'b> ByteCompiler<'b> {
                         }
                         let address = address_info
                             .ok_or_else(|| {
-                                self.context.construct_syntax_error(format!(
+                                JsNativeError::syntax().with_message(format!(
                                     "Cannot use the undeclared label '{}'",
                                     self.context.interner().resolve_expect(label_name)
                                 ))
@@ -1730,8 +1999,7 @@ impl<'b> ByteCompiler<'b> {
                         items
                             .next()
                             .ok_or_else(|| {
-                                self.context
-                                    .construct_syntax_error("continue must be inside loop")
+                                JsNativeError::syntax().with_message("continue must be inside loop")
                             })?
                             .start_address
                     };
@@ -1784,9 +2052,8 @@ impl<'b> ByteCompiler<'b> {
                     self.jump_info
                         .last_mut()
                         .ok_or_else(|| {
-                            self.context.construct_syntax_error(
-                                "unlabeled break must be inside loop or switch",
-                            )
+                            JsNativeError::syntax()
+                                .with_message("unlabeled break must be inside loop or switch")
                         })?
                         .breaks
                         .push(label);
@@ -1796,7 +2063,7 @@ impl<'b> ByteCompiler<'b> {
                 self.context.push_compile_time_environment(false);
                 let push_env =
                     self.emit_opcode_with_two_operands(Opcode::PushDeclarativeEnvironment);
-                self.create_declarations(block.items())?;
+                self.create_block_declarations(block.items())?;
                 self.compile_statement_list(block.items(), use_expr)?;
                 let (num_bindings, compile_environment) =
                     self.context.pop_compile_time_environment();
@@ -1810,11 +2077,15 @@ impl<'b> ByteCompiler<'b> {
                 self.emit(Opcode::Throw, &[]);
             }
             Node::Switch(switch) => {
+                self.emit_undefined_completion_value(use_expr);
                 self.context.push_compile_time_environment(false);
                 let push_env =
                     self.emit_opcode_with_two_operands(Opcode::PushDeclarativeEnvironment);
                 for case in switch.cases() {
-                    self.create_declarations(case.body().items())?;
+                    self.create_block_declarations(case.body().items())?;
+                }
+                if let Some(body) = switch.default() {
+                    self.create_block_declarations(body)?;
                 }
                 self.emit_opcode(Opcode::LoopStart);
 
@@ -1832,13 +2103,13 @@ impl<'b> ByteCompiler<'b> {
 
                 for (label, case) in labels.into_iter().zip(switch.cases()) {
                     self.patch_jump(label);
-                    self.compile_statement_list(case.body().items(), false)?;
+                    self.compile_statement_list(case.body().items(), use_expr)?;
                 }
 
                 self.patch_jump(exit);
                 if let Some(body) = switch.default() {
                     self.create_declarations(body)?;
-                    self.compile_statement_list(body, false)?;
+                    self.compile_statement_list(body, use_expr)?;
                 }
 
                 self.pop_switch_control_info();
@@ -1862,6 +2133,7 @@ impl<'b> ByteCompiler<'b> {
                 self.emit(Opcode::Return, &[]);
             }
             Node::Try(t) => {
+                self.emit_undefined_completion_value(use_expr);
                 self.push_try_control_info(t.finally().is_some());
                 let try_start = self.next_opcode_location();
                 self.emit(Opcode::TryStart, &[Self::DUMMY_ADDRESS, 0]);
@@ -1869,7 +2141,7 @@ impl<'b> ByteCompiler<'b> {
                 let push_env =
                     self.emit_opcode_with_two_operands(Opcode::PushDeclarativeEnvironment);
 
-                self.create_declarations(t.block().items())?;
+                self.create_block_declarations(t.block().items())?;
                 self.compile_statement_list(t.block().items(), use_expr)?;
 
                 let (num_bindings, compile_environment) =
@@ -1910,7 +2182,8 @@ impl<'b> ByteCompiler<'b> {
                         self.emit_opcode(Opcode::Pop);
                     }
 
-                    self.create_declarations(catch.block().items())?;
+                    self.create_block_declarations(catch.block().items())?;
+                    self.emit_undefined_completion_value(use_expr);
                     self.compile_statement_list(catch.block().items(), use_expr)?;
 
                     let (num_bindings, compile_environment) =
@@ -1947,7 +2220,7 @@ impl<'b> ByteCompiler<'b> {
                     let push_env =
                         self.emit_opcode_with_two_operands(Opcode::PushDeclarativeEnvironment);
 
-                    self.create_declarations(finally.items())?;
+                    self.create_block_declarations(finally.items())?;
                     self.compile_statement_list(finally.items(), false)?;
 
                     let (num_bindings, compile_environment) =
@@ -1969,7 +2242,12 @@ impl<'b> ByteCompiler<'b> {
             }
             Node::ClassDecl(class) => self.class(class, false)?,
             Node::Empty => {}
-            expr => self.compile_expr(expr, use_expr)?,
+            expr => {
+                self.compile_expr(expr, use_expr)?;
+                if use_expr {
+                    self.emit_opcode(Opcode::SetCompletionValue);
+                }
+            }
         }
         Ok(())
     }
@@ -2075,10 +2353,30 @@ impl<'b> ByteCompiler<'b> {
 
         match kind {
             FunctionKind::Declaration => {
-                self.emit_binding(
-                    BindingOpcode::InitVar,
-                    name.expect("function declaration must have a name"),
-                );
+                let name = name.expect("function declaration must have a name");
+                if self.context.has_block_lex_binding(name) {
+                    // In sloppy mode, a function declaration in a block also sets its var
+                    // binding when it is evaluated, unless that binding would conflict with a
+                    // lexical declaration or a parameter.
+                    if !self.code_block.strict
+                        && !generator
+                        && !r#async
+                        && !self
+                            .context
+                            .has_outer_lex_binding_until_function_environment(name)
+                    {
+                        self.emit_opcode(Opcode::Dup);
+                        self.emit_binding(BindingOpcode::InitLet, name);
+                        self.context.create_mutable_binding(name, true);
+                        let binding = self.context.initialize_mutable_binding(name, true);
+                        let index = self.get_or_insert_binding(binding);
+                        self.emit(Opcode::DefInitVar, &[index]);
+                    } else {
+                        self.emit_binding(BindingOpcode::InitLet, name);
+                    }
+                } else {
+                    self.emit_binding(BindingOpcode::InitVar, name);
+                }
             }
             FunctionKind::Expression | FunctionKind::Arrow => {
                 if !use_expr {
@@ -2091,13 +2389,6 @@ impl<'b> ByteCompiler<'b> {
     }
 
     pub(crate) fn call(&mut self, node: &Node, use_expr: bool) -> JsResult<()> {
-        #[derive(PartialEq)]
-        enum CallKind {
-            CallEval,
-            Call,
-            New,
-        }
-
         let (call, kind) = match node {
             Node::Call(call) => match call.expr() {
                 Node::Identifier(ident) if ident.sym() == Sym::EVAL => (call, CallKind::CallEval),
@@ -2126,19 +2417,20 @@ impl<'b> ByteCompiler<'b> {
                 self.emit(Opcode::GetPropertyByValue, &[]);
             }
             Node::GetSuperField(get_super_field) => {
+                self.emit_opcode(Opcode::This);
                 if kind == CallKind::Call {
-                    self.emit_opcode(Opcode::This);
+                    self.emit_opcode(Opcode::Dup);
                 }
-                self.emit_opcode(Opcode::Super);
                 match get_super_field {
                     GetSuperField::Const(field) => {
                         let index = self.get_or_insert_name(*field);
-                        self.emit(Opcode::GetPropertyByName, &[index]);
+                        self.emit_opcode(Opcode::Super);
+                        self.emit(Opcode::GetSuperPropertyByName, &[index]);
                     }
                     GetSuperField::Expr(expr) => {
                         self.compile_expr(expr, true)?;
-                        self.emit_opcode(Opcode::Swap);
-                        self.emit_opcode(Opcode::GetPropertyByValue);
+                        self.emit_opcode(Opcode::Super);
+                        self.emit_opcode(Opcode::GetSuperPropertyByValue);
                     }
                 }
             }
@@ -2159,6 +2451,18 @@ impl<'b> ByteCompiler<'b> {
             }
         }
 
+        self.call_arguments(call, kind)?;
+
+        if !use_expr {
+            self.emit(Opcode::Pop, &[]);
+        }
+        Ok(())
+    }
+
+    /// Compiles the arguments of a call, and calls the function below them on the stack.
+    ///
+    /// Stack: this, function **=>** result
+    fn call_arguments(&mut self, call: &Call, kind: CallKind) -> JsResult<()> {
         let contains_spread = call.args().iter().any(|arg| matches!(arg, Node::Spread(_)));
 
         if contains_spread {
@@ -2186,15 +2490,13 @@ impl<'b> ByteCompiler<'b> {
             CallKind::New if contains_spread => self.emit_opcode(Opcode::NewSpread),
             CallKind::New => self.emit(Opcode::New, &[call.args().len() as u32]),
         }
-
-        if !use_expr {
-            self.emit(Opcode::Pop, &[]);
-        }
         Ok(())
     }
 
     #[inline]
-    pub fn finish(self) -> CodeBlock {
+    pub fn finish(mut self) -> CodeBlock {
+        let bytes = self.code_block.allocated_bytes();
+        self.code_block.allocation.resize(bytes);
         self.code_block
     }
 
@@ -2274,7 +2576,7 @@ impl<'b> ByteCompiler<'b> {
 
                             for key in excluded_keys {
                                 self.emit_push_literal(Literal::String(
-                                    self.interner().resolve_expect(*key).into(),
+                                    self.context.interned_string(*key),
                                 ));
                             }
 
@@ -2292,7 +2594,7 @@ impl<'b> ByteCompiler<'b> {
                             self.emit_opcode(Opcode::PushEmptyObject);
                             for key in excluded_keys {
                                 self.emit_push_literal(Literal::String(
-                                    self.interner().resolve_expect(*key).into(),
+                                    self.context.interned_string(*key),
                                 ));
                             }
                             self.emit(Opcode::CopyDataProperties, &[excluded_keys.len() as u32, 0]);
@@ -2511,6 +2813,58 @@ impl<'b> ByteCompiler<'b> {
         for node in stmt_list {
             self.create_decls_from_stmt(node)?;
         }
+
+        // Variables declared in nested blocks, loops and other statements are hoisted to the
+        // function or script too, and are initialized with `undefined` when it starts, before
+        // any of them can be referenced. Bindings that are already initialized, such as
+        // parameters, keep their value.
+        let mut var_names = FxHashSet::default();
+        for node in stmt_list {
+            node.var_declared_names(&mut var_names);
+        }
+        for name in var_names {
+            self.context.create_mutable_binding(name, true);
+            self.emit_binding(BindingOpcode::Var, name);
+        }
+
+        // Under the web compatibility semantics, function declarations in blocks also get a
+        // var binding in sloppy mode, which is initialized with `undefined` until the block runs.
+        if !self.code_block.strict {
+            let mut names = FxHashSet::default();
+            for node in stmt_list {
+                node.annex_b_function_names(&mut Vec::new(), &mut names);
+            }
+            for name in names {
+                if !self
+                    .context
+                    .has_lex_binding_until_function_environment(name)
+                {
+                    self.context.create_mutable_binding(name, true);
+                    self.emit_binding(BindingOpcode::Var, name);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Creates the bindings declared by the statements of a block.
+    ///
+    /// Unlike at the top level of a function or script, function declarations in a block are
+    /// lexically scoped to it.
+    pub(crate) fn create_block_declarations(&mut self, stmt_list: &[Node]) -> JsResult<()> {
+        for node in stmt_list {
+            let name = match node {
+                Node::FunctionDecl(decl) => decl.name(),
+                Node::GeneratorDecl(decl) => decl.name(),
+                Node::AsyncFunctionDecl(decl) => decl.name(),
+                Node::AsyncGeneratorDecl(decl) => decl.name(),
+                _ => {
+                    self.create_decls_from_stmt(node)?;
+                    continue;
+                }
+            };
+            self.context.create_mutable_binding(name, false);
+        }
         Ok(())
     }
 
@@ -2632,6 +2986,47 @@ impl<'b> ByteCompiler<'b> {
         Ok(has_identifier_argument)
     }
 
+    /// Compiles the initializer of a static field as a method of the class and calls it.
+    ///
+    /// The initializer is evaluated with the class as `this` and as its home object, so `super`
+    /// property accesses resolve through the parent class.
+    ///
+    /// Stack: class **=>** value
+    fn static_field_initializer(&mut self, field: Option<&Node>) -> JsResult<()> {
+        let field_code = CodeBlock::new(Sym::EMPTY_STRING, 0, true);
+        let mut field_compiler = ByteCompiler {
+            code_block: field_code,
+            literals_map: FxHashMap::default(),
+            names_map: FxHashMap::default(),
+            bindings_map: FxHashMap::default(),
+            jump_info: Vec::new(),
+            in_async_generator: false,
+            context: self.context,
+        };
+        field_compiler.context.push_compile_time_environment(true);
+        if let Some(node) = field {
+            field_compiler.compile_expr(node, true)?;
+        } else {
+            field_compiler.emit_opcode(Opcode::PushUndefined);
+        }
+        let (num_bindings, compile_environment) =
+            field_compiler.context.pop_compile_time_environment();
+        field_compiler
+            .code_block
+            .compile_environments
+            .push(compile_environment);
+        field_compiler.code_block.num_bindings = num_bindings;
+        field_compiler.emit_opcode(Opcode::Return);
+
+        let code = Gc::new(field_compiler.finish());
+        let index = self.code_block.functions.len() as u32;
+        self.code_block.functions.push(code);
+        self.emit(Opcode::GetFunction, &[index]);
+        self.emit_opcode(Opcode::SetHomeObject);
+        self.emit(Opcode::Call, &[0]);
+        Ok(())
+    }
+
     /// This function compiles a class declaration or expression.
     ///
     /// The compilation of a class declaration and expression is mostly equal.
@@ -2763,7 +3158,7 @@ impl<'b> ByteCompiler<'b> {
                                 self.emit(Opcode::DefineClassGetterByName, &[index]);
                             }
                             PropertyName::Computed(name_node) => {
-                                self.compile_stmt(name_node, true)?;
+                                self.compile_expr(name_node, true)?;
                                 self.emit_opcode(Opcode::ToPropertyKey);
                                 self.function(&expr.clone().into(), true)?;
                                 self.emit_opcode(Opcode::DefineClassGetterByValue);
@@ -2777,7 +3172,7 @@ impl<'b> ByteCompiler<'b> {
                                 self.emit(Opcode::DefineClassSetterByName, &[index]);
                             }
                             PropertyName::Computed(name_node) => {
-                                self.compile_stmt(name_node, true)?;
+                                self.compile_expr(name_node, true)?;
                                 self.emit_opcode(Opcode::ToPropertyKey);
                                 self.function(&expr.clone().into(), true)?;
                                 self.emit_opcode(Opcode::DefineClassSetterByValue);
@@ -2791,7 +3186,7 @@ impl<'b> ByteCompiler<'b> {
                                 self.emit(Opcode::DefineClassMethodByName, &[index]);
                             }
                             PropertyName::Computed(name_node) => {
-                                self.compile_stmt(name_node, true)?;
+                                self.compile_expr(name_node, true)?;
                                 self.emit_opcode(Opcode::ToPropertyKey);
                                 self.function(&expr.clone().into(), true)?;
                                 self.emit_opcode(Opcode::DefineClassMethodByValue);
@@ -2805,7 +3200,7 @@ impl<'b> ByteCompiler<'b> {
                                 self.emit(Opcode::DefineClassMethodByName, &[index]);
                             }
                             PropertyName::Computed(name_node) => {
-                                self.compile_stmt(name_node, true)?;
+                                self.compile_expr(name_node, true)?;
                                 self.emit_opcode(Opcode::ToPropertyKey);
                                 self.function(&expr.clone().into(), true)?;
                                 self.emit_opcode(Opcode::DefineClassMethodByValue);
@@ -2819,7 +3214,7 @@ impl<'b> ByteCompiler<'b> {
                                 self.emit(Opcode::DefineClassMethodByName, &[index]);
                             }
                             PropertyName::Computed(name_node) => {
-                                self.compile_stmt(name_node, true)?;
+                                self.compile_expr(name_node, true)?;
                                 self.emit_opcode(Opcode::ToPropertyKey);
                                 self.function(&expr.clone().into(), true)?;
                                 self.emit_opcode(Opcode::DefineClassMethodByValue);
@@ -2833,7 +3228,7 @@ impl<'b> ByteCompiler<'b> {
                                 self.emit(Opcode::DefineClassMethodByName, &[index]);
                             }
                             PropertyName::Computed(name_node) => {
-                                self.compile_stmt(name_node, true)?;
+                                self.compile_expr(name_node, true)?;
                                 self.emit_opcode(Opcode::ToPropertyKey);
                                 self.function(&expr.clone().into(), true)?;
                                 self.emit_opcode(Opcode::DefineClassMethodByValue);
@@ -2846,31 +3241,37 @@ impl<'b> ByteCompiler<'b> {
                     match method_definition {
                         MethodDefinition::Get(expr) => {
                             self.function(&expr.clone().into(), true)?;
+                            self.emit_opcode(Opcode::SetHomeObject);
                             let index = self.get_or_insert_name(*name);
                             self.emit(Opcode::SetPrivateGetter, &[index]);
                         }
                         MethodDefinition::Set(expr) => {
                             self.function(&expr.clone().into(), true)?;
+                            self.emit_opcode(Opcode::SetHomeObject);
                             let index = self.get_or_insert_name(*name);
                             self.emit(Opcode::SetPrivateSetter, &[index]);
                         }
                         MethodDefinition::Ordinary(expr) => {
                             self.function(&expr.clone().into(), true)?;
+                            self.emit_opcode(Opcode::SetHomeObject);
                             let index = self.get_or_insert_name(*name);
                             self.emit(Opcode::SetPrivateMethod, &[index]);
                         }
                         MethodDefinition::Async(expr) => {
                             self.function(&expr.clone().into(), true)?;
+                            self.emit_opcode(Opcode::SetHomeObject);
                             let index = self.get_or_insert_name(*name);
                             self.emit(Opcode::SetPrivateMethod, &[index]);
                         }
                         MethodDefinition::Generator(expr) => {
                             self.function(&expr.clone().into(), true)?;
+                            self.emit_opcode(Opcode::SetHomeObject);
                             let index = self.get_or_insert_name(*name);
                             self.emit(Opcode::SetPrivateMethod, &[index]);
                         }
                         MethodDefinition::AsyncGenerator(expr) => {
                             self.function(&expr.clone().into(), true)?;
+                            self.emit_opcode(Opcode::SetHomeObject);
                             let index = self.get_or_insert_name(*name);
                             self.emit(Opcode::SetPrivateMethod, &[index]);
                         }
@@ -2881,7 +3282,7 @@ impl<'b> ByteCompiler<'b> {
                     match name {
                         PropertyName::Literal(name) => {
                             self.emit_push_literal(Literal::String(
-                                self.interner().resolve_expect(*name).into(),
+                                self.context.interned_string(*name),
                             ));
                         }
                         PropertyName::Computed(name) => {
@@ -2900,7 +3301,7 @@ impl<'b> ByteCompiler<'b> {
                     };
                     field_compiler.context.push_compile_time_environment(true);
                     if let Some(node) = field {
-                        field_compiler.compile_stmt(node, true)?;
+                        field_compiler.compile_expr(node, true)?;
                     } else {
                         field_compiler.emit_opcode(Opcode::PushUndefined);
                     }
@@ -2934,7 +3335,7 @@ impl<'b> ByteCompiler<'b> {
                     };
                     field_compiler.context.push_compile_time_environment(true);
                     if let Some(node) = field {
-                        field_compiler.compile_stmt(node, true)?;
+                        field_compiler.compile_expr(node, true)?;
                     } else {
                         field_compiler.emit_opcode(Opcode::PushUndefined);
                     }
@@ -2957,34 +3358,26 @@ impl<'b> ByteCompiler<'b> {
                     self.emit_opcode(Opcode::Dup);
                     match name {
                         PropertyName::Literal(name) => {
-                            if let Some(node) = field {
-                                self.compile_stmt(node, true)?;
-                            } else {
-                                self.emit_opcode(Opcode::PushUndefined);
-                            }
+                            self.emit_opcode(Opcode::Dup);
+                            self.static_field_initializer(field.as_ref())?;
                             self.emit_opcode(Opcode::Swap);
                             let index = self.get_or_insert_name(*name);
                             self.emit(Opcode::DefineOwnPropertyByName, &[index]);
                         }
                         PropertyName::Computed(name_node) => {
-                            self.compile_stmt(name_node, true)?;
+                            self.emit_opcode(Opcode::Dup);
+                            self.compile_expr(name_node, true)?;
                             self.emit_opcode(Opcode::ToPropertyKey);
-                            if let Some(node) = field {
-                                self.compile_stmt(node, true)?;
-                            } else {
-                                self.emit_opcode(Opcode::PushUndefined);
-                            }
+                            self.emit_opcode(Opcode::Swap);
+                            self.static_field_initializer(field.as_ref())?;
                             self.emit_opcode(Opcode::DefineOwnPropertyByValue);
                         }
                     }
                 }
                 ClassElement::PrivateStaticFieldDefinition(name, field) => {
                     self.emit_opcode(Opcode::Dup);
-                    if let Some(node) = field {
-                        self.compile_stmt(node, true)?;
-                    } else {
-                        self.emit_opcode(Opcode::PushUndefined);
-                    }
+                    self.emit_opcode(Opcode::Dup);
+                    self.static_field_initializer(field.as_ref())?;
                     let index = self.get_or_insert_name(*name);
                     self.emit(Opcode::SetPrivateField, &[index]);
                 }
@@ -3064,7 +3457,7 @@ impl<'b> ByteCompiler<'b> {
                                 self.emit(Opcode::DefineClassGetterByName, &[index]);
                             }
                             PropertyName::Computed(name_node) => {
-                                self.compile_stmt(name_node, true)?;
+                                self.compile_expr(name_node, true)?;
                                 self.emit_opcode(Opcode::ToPropertyKey);
                                 self.function(&expr.clone().into(), true)?;
                                 self.emit_opcode(Opcode::DefineClassGetterByValue);
@@ -3078,7 +3471,7 @@ impl<'b> ByteCompiler<'b> {
                                 self.emit(Opcode::DefineClassSetterByName, &[index]);
                             }
                             PropertyName::Computed(name_node) => {
-                                self.compile_stmt(name_node, true)?;
+                                self.compile_expr(name_node, true)?;
                                 self.emit_opcode(Opcode::ToPropertyKey);
                                 self.function(&expr.clone().into(), true)?;
                                 self.emit_opcode(Opcode::DefineClassSetterByValue);
@@ -3092,7 +3485,7 @@ impl<'b> ByteCompiler<'b> {
                                 self.emit(Opcode::DefineClassMethodByName, &[index]);
                             }
                             PropertyName::Computed(name_node) => {
-                                self.compile_stmt(name_node, true)?;
+                                self.compile_expr(name_node, true)?;
                                 self.emit_opcode(Opcode::ToPropertyKey);
                                 self.function(&expr.clone().into(), true)?;
                                 self.emit_opcode(Opcode::DefineClassMethodByValue);
@@ -3106,7 +3499,7 @@ impl<'b> ByteCompiler<'b> {
                                 self.emit(Opcode::DefineClassMethodByName, &[index]);
                             }
                             PropertyName::Computed(name_node) => {
-                                self.compile_stmt(name_node, true)?;
+                                self.compile_expr(name_node, true)?;
                                 self.emit_opcode(Opcode::ToPropertyKey);
                                 self.function(&expr.clone().into(), true)?;
                                 self.emit_opcode(Opcode::DefineClassMethodByValue);
@@ -3120,7 +3513,7 @@ impl<'b> ByteCompiler<'b> {
                                 self.emit(Opcode::DefineClassMethodByName, &[index]);
                             }
                             PropertyName::Computed(name_node) => {
-                                self.compile_stmt(name_node, true)?;
+                                self.compile_expr(name_node, true)?;
                                 self.emit_opcode(Opcode::ToPropertyKey);
                                 self.function(&expr.clone().into(), true)?;
                                 self.emit_opcode(Opcode::DefineClassMethodByValue);
@@ -3134,7 +3527,7 @@ impl<'b> ByteCompiler<'b> {
                                 self.emit(Opcode::DefineClassMethodByName, &[index]);
                             }
                             PropertyName::Computed(name_node) => {
-                                self.compile_stmt(name_node, true)?;
+                                self.compile_expr(name_node, true)?;
                                 self.emit_opcode(Opcode::ToPropertyKey);
                                 self.function(&expr.clone().into(), true)?;
                                 self.emit_opcode(Opcode::DefineClassMethodByValue);
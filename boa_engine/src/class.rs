@@ -63,10 +63,12 @@
 
 use crate::{
     builtins::function::NativeFunctionSignature,
+    context::intrinsics::StandardConstructor,
     object::{ConstructorBuilder, JsFunction, JsObject, NativeObject, ObjectData, PROTOTYPE},
     property::{Attribute, PropertyDescriptor, PropertyKey},
     Context, JsResult, JsValue,
 };
+use std::any::TypeId;
 
 /// Native class.
 pub trait Class: NativeObject + Sized {
@@ -110,25 +112,6 @@ impl<T: Class> ClassConstructor for T {
             ));
         }
 
-        let class_constructor = context.global_object().clone().get(T::NAME, context)?;
-        let class_constructor = if let JsValue::Object(ref obj) = class_constructor {
-            obj
-        } else {
-            return context.throw_type_error(format!(
-                "invalid constructor for native class `{}` ",
-                T::NAME
-            ));
-        };
-        let class_prototype =
-            if let JsValue::Object(ref obj) = class_constructor.get(PROTOTYPE, context)? {
-                obj.clone()
-            } else {
-                return context.throw_type_error(format!(
-                    "invalid default prototype for native class `{}`",
-                    T::NAME
-                ));
-            };
-
         let prototype = this
             .as_object()
             .cloned()
@@ -137,8 +120,18 @@ impl<T: Class> ClassConstructor for T {
                     .map(|val| val.as_object().cloned())
             })
             .transpose()?
-            .flatten()
-            .unwrap_or(class_prototype);
+            .flatten();
+
+        // The default prototype comes from the class registered in the realm, not from the global
+        // object, so that scripts cannot replace it by modifying the global binding.
+        let prototype = if let Some(prototype) = prototype {
+            prototype
+        } else if let Some(class) = context.intrinsics().class::<T>() {
+            class.prototype()
+        } else {
+            return context
+                .throw_type_error(format!("native class `{}` is not registered", T::NAME));
+        };
 
         let native_instance = Self::constructor(this, args, context)?;
         let object_instance = JsObject::from_proto_and_data(
@@ -153,6 +146,8 @@ impl<T: Class> ClassConstructor for T {
 #[derive(Debug)]
 pub struct ClassBuilder<'context> {
     builder: ConstructorBuilder<'context>,
+    type_id: TypeId,
+    class: StandardConstructor,
 }
 
 impl<'context> ClassBuilder<'context> {
@@ -161,15 +156,28 @@ impl<'context> ClassBuilder<'context> {
     where
         T: ClassConstructor,
     {
-        let mut builder = ConstructorBuilder::new(context, T::raw_constructor);
+        let class = StandardConstructor::default();
+        let mut builder = ConstructorBuilder::with_standard_constructor(
+            context,
+            T::raw_constructor,
+            class.clone(),
+        );
         builder.name(T::NAME);
         builder.length(T::LENGTH);
-        Self { builder }
+        Self {
+            builder,
+            type_id: TypeId::of::<T>(),
+            class,
+        }
     }
 
     #[inline]
     pub(crate) fn build(mut self) -> JsFunction {
-        JsFunction::from_object_unchecked(self.builder.build().into())
+        let constructor = self.builder.build();
+        self.builder
+            .context()
+            .register_class(self.type_id, self.class);
+        JsFunction::from_object_unchecked(constructor.into())
     }
 
     /// Add a method to the class.
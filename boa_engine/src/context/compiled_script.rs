@@ -0,0 +1,475 @@
+//! Scripts compiled ahead of time, which can be shared between threads.
+//!
+//! A [`Context`] can't be sent to other threads, since its values are managed by a garbage
+//! collector that is local to the thread that created them. A [`CompiledScript`] keeps a frozen
+//! copy of the bytecode of a script instead, which only holds plain data, and creates the code
+//! blocks of the script again in every context that executes it.
+
+use std::sync::Arc;
+
+use boa_gc::{Cell, Gc};
+use boa_interner::Sym;
+use rustc_hash::FxHashMap;
+
+use super::declarations::GlobalDeclarations;
+use crate::{
+    bigint::RawBigInt,
+    builtins::{function::ThisMode, regexp::FrozenRegExp, RegExp},
+    environments::{BindingLocator, CompileTimeEnvironment, FrozenCompileTimeEnvironment},
+    property::PropertyKey,
+    syntax::ast::node::{FormalParameterList, FrozenFormalParameterList},
+    vm::CodeBlock,
+    Context, JsBigInt, JsResult, JsString, JsValue,
+};
+
+/// A script compiled ahead of time, which can be sent to other threads and executed in any
+/// number of contexts.
+///
+/// Compiling a script once and executing it in the contexts of several threads saves parsing and
+/// compiling it again in each of them. Cloning a `CompiledScript` is cheap, since the compiled
+/// code is shared.
+///
+/// The compiled code refers to the `let`, `const` and `class` declarations of the global scope
+/// by their position, which depends on the scripts that ran before in the context. If a context
+/// already has a global lexical declaration with a name used by the script, the script is
+/// compiled again from its source in that context, so it always behaves like
+/// [`Context::eval`].
+///
+/// # Examples
+///
+/// ```
+/// use boa_engine::{context::CompiledScript, Context};
+/// use std::thread;
+///
+/// let script = CompiledScript::compile("let answer = 40; answer + 2").unwrap();
+///
+/// let handles: Vec<_> = (0..4)
+///     .map(|_| {
+///         let script = script.clone();
+///         thread::spawn(move || {
+///             let mut context = Context::default();
+///             script.execute(&mut context).unwrap().as_number()
+///         })
+///     })
+///     .collect();
+///
+/// for handle in handles {
+///     assert_eq!(handle.join().unwrap(), Some(42.0));
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct CompiledScript {
+    inner: Arc<FrozenScript>,
+}
+
+/// The frozen compiled code of a script, and everything needed to run it in another context.
+#[derive(Debug)]
+struct FrozenScript {
+    /// The source of the script, to compile it again if the frozen code can't be used.
+    source: Box<[u8]>,
+
+    /// The code block of the script.
+    code: FrozenCodeBlock,
+
+    /// The compile time environments of the script, ordered so that outer environments come
+    /// before the environments nested in them.
+    environments: Vec<FrozenEnvironment>,
+
+    /// The names declared by the script in the global scope.
+    declarations: GlobalDeclarations,
+
+    /// The lexical bindings that the script creates in the global environment.
+    global_bindings: FrozenCompileTimeEnvironment,
+
+    /// The properties that the script creates in the global object for its `var` and function
+    /// declarations, in creation order.
+    global_properties: Vec<String>,
+
+    /// The names that the code looks up in the global object.
+    global_names: Vec<Sym>,
+
+    /// The strings of all the symbols used by the code.
+    strings: Vec<(Sym, Box<[u16]>)>,
+}
+
+/// A frozen compile time environment.
+#[derive(Debug)]
+struct FrozenEnvironment {
+    /// The position of the outer environment, or `None` if it is the global environment.
+    outer: Option<usize>,
+    bindings: FrozenCompileTimeEnvironment,
+}
+
+/// A frozen [`CodeBlock`].
+#[derive(Debug)]
+struct FrozenCodeBlock {
+    name: Sym,
+    length: u32,
+    strict: bool,
+    this_mode: ThisMode,
+    params: FrozenFormalParameterList,
+    code: Vec<u8>,
+    literals: Vec<FrozenLiteral>,
+    names: Vec<Sym>,
+    regexps: Vec<FrozenRegExp>,
+    object_literals: Vec<Box<[FrozenPropertyKey]>>,
+    bindings: Vec<BindingLocator>,
+    num_bindings: usize,
+    functions: Vec<FrozenCodeBlock>,
+    arguments_binding: Option<BindingLocator>,
+
+    /// The positions of the compile time environments, or `None` for the global environment.
+    compile_environments: Vec<Option<usize>>,
+
+    is_class_constructor: bool,
+    function_environment_push_location: u32,
+}
+
+/// A frozen literal of a [`CodeBlock`].
+#[derive(Debug)]
+enum FrozenLiteral {
+    String(Box<[u16]>),
+    BigInt(RawBigInt),
+}
+
+/// A frozen key of an object literal of a [`CodeBlock`].
+#[derive(Debug)]
+enum FrozenPropertyKey {
+    String(Box<[u16]>),
+    Index(u32),
+}
+
+impl CompiledScript {
+    /// Parses and compiles the given source.
+    ///
+    /// The script is compiled in a new [`Context`], as non-strict code, and with the default
+    /// resource limits.
+    pub fn compile<S>(src: S) -> JsResult<Self>
+    where
+        S: AsRef<[u8]>,
+    {
+        let src = src.as_ref();
+        let mut context = Context::default();
+        let global = context.realm.environments.global_compile_environment();
+        debug_assert_eq!(global.borrow().num_bindings(), 0);
+        let num_global_properties = context
+            .realm
+            .global_property_map
+            .string_property_map()
+            .len();
+
+        let statement_list = context.parse(src)?;
+        let declarations = GlobalDeclarations::new(&statement_list, statement_list.strict());
+        let code = context.compile(&statement_list)?;
+
+        let mut freezer = Freezer {
+            context: &context,
+            global: global.clone(),
+            environments: Vec::new(),
+            environment_ids: FxHashMap::default(),
+            global_names: Vec::new(),
+            strings: FxHashMap::default(),
+        };
+        let code = freezer.code_block(&code);
+        for name in declarations.names() {
+            freezer.symbol(name);
+        }
+        let global_bindings = global.borrow().freeze();
+        for name in global_bindings.names() {
+            freezer.symbol(name);
+        }
+        let Freezer {
+            environments,
+            mut global_names,
+            strings,
+            ..
+        } = freezer;
+        global_names.sort_unstable();
+        global_names.dedup();
+
+        let global_properties = context
+            .realm
+            .global_property_map
+            .string_property_map()
+            .keys()
+            .skip(num_global_properties)
+            .map(ToString::to_string)
+            .collect();
+
+        Ok(Self {
+            inner: Arc::new(FrozenScript {
+                source: src.into(),
+                code,
+                environments,
+                declarations,
+                global_bindings,
+                global_properties,
+                global_names,
+                strings: strings.into_iter().collect(),
+            }),
+        })
+    }
+
+    /// Executes the script in the given context, and returns the value of its last statement.
+    pub fn execute(&self, context: &mut Context) -> JsResult<JsValue> {
+        match self.instantiate(context)? {
+            Some(code) => context.execute(code),
+            None => context.eval(&self.inner.source),
+        }
+    }
+
+    /// Creates the code block of the script in the given context, and the global bindings of
+    /// its declarations.
+    ///
+    /// Returns `None` if the frozen code can't be used in the context, and the script has to be
+    /// compiled again.
+    fn instantiate(&self, context: &mut Context) -> JsResult<Option<Gc<CodeBlock>>> {
+        let script = &*self.inner;
+        if context.strict && !script.code.strict {
+            return Ok(None);
+        }
+
+        let symbols: FxHashMap<Sym, Sym> = script
+            .strings
+            .iter()
+            .map(|(sym, string)| (*sym, context.interner_mut().get_or_intern_utf16(string)))
+            .collect();
+        let rename = |sym: Sym| symbols[&sym];
+
+        let global = context.realm.environments.global_compile_environment();
+        if script
+            .global_names
+            .iter()
+            .any(|name| global.borrow().has_lex_binding(rename(*name)))
+        {
+            return Ok(None);
+        }
+
+        // These are the steps of `Context::compile_script` that change the global environment.
+        let declarations = script.declarations.renamed(rename);
+        let var_names = context.check_global_declarations(&declarations)?;
+        let global_offset = global.borrow().num_bindings();
+        global
+            .borrow_mut()
+            .extend_frozen(&script.global_bindings, rename);
+        for name in &script.global_properties {
+            context.create_global_var_property(name.as_str().into());
+        }
+        context.realm.global_var_names.extend(var_names);
+
+        let mut thawer = Thawer {
+            symbols: &symbols,
+            global,
+            global_offset,
+            environments: Vec::with_capacity(script.environments.len()),
+        };
+        for environment in &script.environments {
+            let outer = thawer.environment(environment.outer);
+            let environment =
+                CompileTimeEnvironment::thaw(&environment.bindings, Some(outer), rename);
+            thawer.environments.push(Gc::new(Cell::new(environment)));
+        }
+
+        Ok(Some(thawer.code_block(&script.code)))
+    }
+}
+
+/// Freezes the code blocks compiled in a context.
+struct Freezer<'a> {
+    context: &'a Context,
+    global: Gc<Cell<CompileTimeEnvironment>>,
+    environments: Vec<FrozenEnvironment>,
+    environment_ids: FxHashMap<*const Cell<CompileTimeEnvironment>, usize>,
+    global_names: Vec<Sym>,
+    strings: FxHashMap<Sym, Box<[u16]>>,
+}
+
+impl Freezer<'_> {
+    /// Records the string of a symbol.
+    fn symbol(&mut self, sym: Sym) -> Sym {
+        let interner = self.context.interner();
+        self.strings.entry(sym).or_insert_with(|| {
+            interner.resolve_utf16(sym).map_or_else(
+                || interner.resolve_expect(sym).encode_utf16().collect(),
+                Into::into,
+            )
+        });
+        sym
+    }
+
+    /// Records the name of a binding, and whether it is looked up in the global object.
+    fn binding(&mut self, binding: BindingLocator) -> BindingLocator {
+        self.symbol(binding.name());
+        if binding.is_global() {
+            self.global_names.push(binding.name());
+        }
+        binding
+    }
+
+    /// Freezes a compile time environment and its outer environments, and returns its position,
+    /// or `None` if it is the global environment.
+    fn environment(&mut self, environment: &Gc<Cell<CompileTimeEnvironment>>) -> Option<usize> {
+        if Gc::ptr_eq(environment, &self.global) {
+            return None;
+        }
+        let key: *const Cell<CompileTimeEnvironment> = &**environment;
+        if let Some(id) = self.environment_ids.get(&key) {
+            return Some(*id);
+        }
+
+        let outer = environment
+            .borrow()
+            .outer()
+            .expect("only the global environment has no outer environment");
+        let outer = self.environment(&outer);
+        let bindings = environment.borrow().freeze();
+        for name in bindings.names() {
+            self.symbol(name);
+        }
+
+        let id = self.environments.len();
+        self.environments
+            .push(FrozenEnvironment { outer, bindings });
+        self.environment_ids.insert(key, id);
+        Some(id)
+    }
+
+    /// Freezes a code block and the code blocks of its inner functions.
+    fn code_block(&mut self, code: &CodeBlock) -> FrozenCodeBlock {
+        let params = code.params.freeze();
+        for name in params.names() {
+            self.symbol(name);
+        }
+
+        FrozenCodeBlock {
+            name: self.symbol(code.name),
+            length: code.length,
+            strict: code.strict,
+            this_mode: code.this_mode.clone(),
+            params,
+            code: code.code.clone(),
+            literals: code
+                .literals
+                .iter()
+                .map(|literal| match literal {
+                    JsValue::String(string) => {
+                        FrozenLiteral::String(string.encode_utf16().collect())
+                    }
+                    JsValue::BigInt(bigint) => FrozenLiteral::BigInt(bigint.as_inner().clone()),
+                    _ => unreachable!("the literals of a code block are strings and bigints"),
+                })
+                .collect(),
+            names: code.names.iter().map(|name| self.symbol(*name)).collect(),
+            regexps: code.regexps.iter().map(RegExp::freeze).collect(),
+            object_literals: code
+                .object_literals
+                .iter()
+                .map(|keys| {
+                    keys.iter()
+                        .map(|key| match key {
+                            PropertyKey::String(string) => {
+                                FrozenPropertyKey::String(string.encode_utf16().collect())
+                            }
+                            PropertyKey::Index(index) => FrozenPropertyKey::Index(*index),
+                            PropertyKey::Symbol(_) => {
+                                unreachable!("the keys of object literals are literal names")
+                            }
+                        })
+                        .collect()
+                })
+                .collect(),
+            bindings: code
+                .bindings
+                .iter()
+                .map(|binding| self.binding(*binding))
+                .collect(),
+            num_bindings: code.num_bindings,
+            functions: code
+                .functions
+                .iter()
+                .map(|function| self.code_block(function))
+                .collect(),
+            arguments_binding: code.arguments_binding.map(|binding| self.binding(binding)),
+            compile_environments: code
+                .compile_environments
+                .iter()
+                .map(|environment| self.environment(environment))
+                .collect(),
+            is_class_constructor: code.is_class_constructor,
+            function_environment_push_location: code.function_environment_push_location,
+        }
+    }
+}
+
+/// Creates the code blocks of a frozen script in a context.
+struct Thawer<'a> {
+    symbols: &'a FxHashMap<Sym, Sym>,
+    global: Gc<Cell<CompileTimeEnvironment>>,
+    global_offset: usize,
+    environments: Vec<Gc<Cell<CompileTimeEnvironment>>>,
+}
+
+impl Thawer<'_> {
+    /// Gets the symbol of the context for a symbol of the frozen script.
+    fn symbol(&self, sym: Sym) -> Sym {
+        self.symbols[&sym]
+    }
+
+    /// Gets the compile time environment at the given position.
+    fn environment(&self, id: Option<usize>) -> Gc<Cell<CompileTimeEnvironment>> {
+        id.map_or_else(|| self.global.clone(), |id| self.environments[id].clone())
+    }
+
+    /// Creates a code block and the code blocks of its inner functions.
+    fn code_block(&self, frozen: &FrozenCodeBlock) -> Gc<CodeBlock> {
+        let binding = |binding: &BindingLocator| {
+            binding.relocate(self.symbol(binding.name()), self.global_offset)
+        };
+
+        let mut code = CodeBlock::new(self.symbol(frozen.name), frozen.length, frozen.strict);
+        code.this_mode = frozen.this_mode.clone();
+        code.params = FormalParameterList::thaw(&frozen.params, |name| self.symbol(name));
+        code.code.clone_from(&frozen.code);
+        code.literals = frozen
+            .literals
+            .iter()
+            .map(|literal| match literal {
+                FrozenLiteral::String(string) => JsString::from_utf16(string).into(),
+                FrozenLiteral::BigInt(bigint) => JsBigInt::from(bigint.clone()).into(),
+            })
+            .collect();
+        code.names = frozen.names.iter().map(|name| self.symbol(*name)).collect();
+        code.regexps = frozen.regexps.iter().map(RegExp::thaw).collect();
+        code.object_literals = frozen
+            .object_literals
+            .iter()
+            .map(|keys| {
+                keys.iter()
+                    .map(|key| match key {
+                        FrozenPropertyKey::String(string) => JsString::from_utf16(string).into(),
+                        FrozenPropertyKey::Index(index) => PropertyKey::Index(*index),
+                    })
+                    .collect()
+            })
+            .collect();
+        code.bindings = frozen.bindings.iter().map(binding).collect();
+        code.num_bindings = frozen.num_bindings;
+        code.functions = frozen
+            .functions
+            .iter()
+            .map(|function| self.code_block(function))
+            .collect();
+        code.arguments_binding = frozen.arguments_binding.as_ref().map(binding);
+        code.compile_environments = frozen
+            .compile_environments
+            .iter()
+            .map(|id| self.environment(*id))
+            .collect();
+        code.is_class_constructor = frozen.is_class_constructor;
+        code.function_environment_push_location = frozen.function_environment_push_location;
+
+        let bytes = code.allocated_bytes();
+        code.allocation.resize(bytes);
+        Gc::new(code)
+    }
+}
@@ -0,0 +1,63 @@
+//! The completion of the evaluation of a script by a [`Context`][crate::Context].
+
+use crate::{JsError, JsResult, JsValue};
+
+/// The way the evaluation of a script completed.
+///
+/// Unlike the [`JsResult`] returned by [`Context::eval`][crate::Context::eval], this tells
+/// apart scripts whose statements don't produce any value, like `var x = 5`, from scripts whose
+/// last value is `undefined`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// # Examples
+///
+/// ```
+/// use boa_engine::{context::Completion, Context, JsValue};
+///
+/// let mut context = Context::default();
+///
+/// assert_eq!(
+///     context.eval_with_completion("1; ;"),
+///     Completion::Normal(Some(JsValue::new(1)))
+/// );
+/// assert_eq!(context.eval_with_completion("var x = 5"), Completion::Normal(None));
+/// assert!(matches!(
+///     context.eval_with_completion("throw x"),
+///     Completion::Throw(_)
+/// ));
+/// ```
+///
+/// [spec]: https://tc39.es/ecma262/#sec-completion-record-specification-type
+#[derive(Debug, Clone, PartialEq)]
+pub enum Completion {
+    /// The script completed normally, with the value of the last statement that produced one,
+    /// or `None` if no statement produced a value.
+    Normal(Option<JsValue>),
+
+    /// The script threw the contained error.
+    Throw(JsError),
+}
+
+impl Completion {
+    /// Converts the completion into the result of the evaluation, where a script that didn't
+    /// produce any value evaluates to `undefined`.
+    #[inline]
+    pub fn into_result(self) -> JsResult<JsValue> {
+        match self {
+            Self::Normal(value) => Ok(value.unwrap_or_default()),
+            Self::Throw(error) => Err(error),
+        }
+    }
+}
+
+impl From<JsResult<Option<JsValue>>> for Completion {
+    #[inline]
+    fn from(result: JsResult<Option<JsValue>>) -> Self {
+        match result {
+            Ok(value) => Self::Normal(value),
+            Err(error) => Self::Throw(error),
+        }
+    }
+}
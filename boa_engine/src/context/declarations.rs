@@ -0,0 +1,204 @@
+//! Checks of the declarations of scripts and `eval` code against the global environment.
+//!
+//! The bindings of a script are created when it is compiled, so the steps of
+//! `GlobalDeclarationInstantiation` that can throw run before the compilation, and must not
+//! depend on anything but the declared names, which are collected in [`GlobalDeclarations`].
+
+use crate::{
+    syntax::ast::node::{Node, StatementList},
+    Context, JsResult, JsString,
+};
+use boa_interner::Sym;
+use rustc_hash::FxHashSet;
+
+/// The names declared by a script, or by an `eval` code whose variable environment is the
+/// global environment.
+#[derive(Debug, Clone)]
+pub(crate) struct GlobalDeclarations {
+    /// The names declared by `let`, `const` and class declarations.
+    lexical: Vec<Sym>,
+
+    /// The names declared by `var` declarations.
+    vars: FxHashSet<Sym>,
+
+    /// The names of the function declarations that are not nested in a block.
+    functions: Vec<Sym>,
+
+    /// The names of the function declarations in blocks that get a var binding under the web
+    /// compatibility semantics, unless a lexical binding of the global environment prevents it.
+    annex_b_functions: Vec<Sym>,
+}
+
+impl GlobalDeclarations {
+    /// Collects the names declared by the given code.
+    pub(crate) fn new(code: &StatementList, strict: bool) -> Self {
+        // 1. Let lexNames be the LexicallyDeclaredNames of script.
+        let lex_names = code.lexically_declared_names_top_level();
+
+        // 2. Let varNames be the VarDeclaredNames of script.
+        let mut var_names = FxHashSet::default();
+        code.var_declared_names_new(&mut var_names);
+        let function_names = code
+            .items()
+            .iter()
+            .filter_map(|node| match node {
+                Node::FunctionDecl(decl) => Some(decl.name()),
+                Node::GeneratorDecl(decl) => Some(decl.name()),
+                Node::AsyncFunctionDecl(decl) => Some(decl.name()),
+                Node::AsyncGeneratorDecl(decl) => Some(decl.name()),
+                _ => None,
+            })
+            .collect();
+
+        let mut annex_b_function_names = FxHashSet::default();
+        if !strict {
+            for node in code.items() {
+                node.annex_b_function_names(&mut Vec::new(), &mut annex_b_function_names);
+            }
+        }
+        let annex_b_function_names = annex_b_function_names
+            .into_iter()
+            .filter(|name| !lex_names.contains(name))
+            .collect();
+
+        Self {
+            lexical: lex_names,
+            vars: var_names,
+            functions: function_names,
+            annex_b_functions: annex_b_function_names,
+        }
+    }
+
+    /// Gets all the declared names.
+    pub(crate) fn names(&self) -> impl Iterator<Item = Sym> + '_ {
+        self.lexical
+            .iter()
+            .chain(&self.vars)
+            .chain(&self.functions)
+            .chain(&self.annex_b_functions)
+            .copied()
+    }
+
+    /// Renames all the declared names with `rename`.
+    pub(crate) fn renamed(&self, rename: impl Fn(Sym) -> Sym) -> Self {
+        Self {
+            lexical: self.lexical.iter().map(|name| rename(*name)).collect(),
+            vars: self.vars.iter().map(|name| rename(*name)).collect(),
+            functions: self.functions.iter().map(|name| rename(*name)).collect(),
+            annex_b_functions: self
+                .annex_b_functions
+                .iter()
+                .map(|name| rename(*name))
+                .collect(),
+        }
+    }
+}
+
+impl Context {
+    /// Checks that the declarations of a script can be instantiated in the global environment,
+    /// and returns the names that it binds with `var` and function declarations.
+    ///
+    /// These are the steps of `GlobalDeclarationInstantiation` that can throw, which all run
+    /// before any binding of the script is created.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-globaldeclarationinstantiation
+    pub(crate) fn check_global_declarations(
+        &mut self,
+        declarations: &GlobalDeclarations,
+    ) -> JsResult<FxHashSet<Sym>> {
+        // 3. For each element name of lexNames, do
+        for &name in &declarations.lexical {
+            // a. If env.HasVarDeclaration(name) is true, throw a SyntaxError exception.
+            // b. If env.HasLexicalDeclaration(name) is true, throw a SyntaxError exception.
+            let name_str = JsString::from(self.interner().resolve_expect(name));
+            if self.realm.has_var_declaration(name) || self.realm.has_lexical_declaration(name) {
+                return self.throw_syntax_error(format!(
+                    "Identifier '{name_str}' has already been declared"
+                ));
+            }
+
+            // c. Let hasRestrictedGlobal be ? env.HasRestrictedGlobalProperty(name).
+            // d. If hasRestrictedGlobal is true, throw a SyntaxError exception.
+            if self.realm.has_restricted_global_property(&name_str) {
+                return self.throw_syntax_error(format!(
+                    "cannot redeclare non-configurable global property '{name_str}'"
+                ));
+            }
+        }
+
+        self.check_global_var_declarations(declarations)
+    }
+
+    /// Checks that the `var` and function declarations of a script or of an `eval` code whose
+    /// variable environment is the global environment can be instantiated, and returns the
+    /// names that they bind.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-globaldeclarationinstantiation
+    pub(crate) fn check_global_var_declarations(
+        &mut self,
+        declarations: &GlobalDeclarations,
+    ) -> JsResult<FxHashSet<Sym>> {
+        let var_names = &declarations.vars;
+        let function_names = &declarations.functions;
+
+        // 4. For each element name of varNames, do
+        for &name in var_names.iter().chain(function_names) {
+            // a. If env.HasLexicalDeclaration(name) is true, throw a SyntaxError exception.
+            if self.realm.has_lexical_declaration(name) {
+                let name = self.interner().resolve_expect(name);
+                return self
+                    .throw_syntax_error(format!("Identifier '{name}' has already been declared"));
+            }
+        }
+
+        // 8. For each element d of varDeclarations, do
+        //     a. If d is either a FunctionDeclaration, a GeneratorDeclaration, an
+        //        AsyncFunctionDeclaration, or an AsyncGeneratorDeclaration, then
+        //         iii. If declaredFunctionNames does not contain fn, then
+        //             1. Let fnDefinable be ? env.CanDeclareGlobalFunction(fn).
+        //             2. If fnDefinable is false, throw a TypeError exception.
+        for &name in function_names {
+            let name_str = JsString::from(self.interner().resolve_expect(name));
+            if !self.realm.can_declare_global_function(&name_str) {
+                return self
+                    .throw_type_error(format!("cannot declare global function '{name_str}'"));
+            }
+        }
+
+        // 10. For each element d of varDeclarations, do
+        //     a. If d is either a VariableDeclaration, a ForBinding, or a BindingIdentifier, then
+        //         i. For each String vn of the BoundNames of d, do
+        //             1. If declaredFunctionNames does not contain vn, then
+        //                 a. Let vnDefinable be ? env.CanDeclareGlobalVar(vn).
+        //                 b. If vnDefinable is false, throw a TypeError exception.
+        for &name in var_names {
+            if function_names.contains(&name) {
+                continue;
+            }
+            let name_str = JsString::from(self.interner().resolve_expect(name));
+            if !self.realm.can_declare_global_var(&name_str) {
+                return self
+                    .throw_type_error(format!("cannot declare global variable '{name_str}'"));
+            }
+        }
+
+        let mut declared_names = var_names.clone();
+        declared_names.extend(function_names);
+
+        // Under the web compatibility semantics, the function declarations in blocks that get a
+        // var binding are also var names of the global environment.
+        for &name in &declarations.annex_b_functions {
+            if !self.realm.has_lexical_declaration(name) {
+                declared_names.insert(name);
+            }
+        }
+
+        Ok(declared_names)
+    }
+}
@@ -0,0 +1,159 @@
+//! An opt-in cache of the code compiled by [`Context::eval`][crate::Context::eval].
+//!
+//! Embedders that evaluate the same short snippets over and over can enable the cache with
+//! [`Context::set_eval_cache`][crate::Context::set_eval_cache], so the source of a snippet is
+//! only parsed and compiled the first time it is evaluated.
+
+use std::{
+    hash::{BuildHasherDefault, Hash, Hasher},
+    rc::Rc,
+};
+
+use boa_gc::Gc;
+use indexmap::IndexMap;
+use rustc_hash::FxHasher;
+
+use super::declarations::GlobalDeclarations;
+use crate::vm::CodeBlock;
+
+/// The configuration of the cache of compiled code of a [`Context`][crate::Context].
+///
+/// The cache is disabled by default.
+///
+/// # Examples
+///
+/// ```
+/// use boa_engine::{context::EvalCacheConfig, Context};
+///
+/// let mut context = Context::default();
+/// context.set_eval_cache(EvalCacheConfig {
+///     max_entries: 64,
+///     max_source_len: 1024,
+/// });
+///
+/// context.eval("var total = 0").unwrap();
+/// for _ in 0..10 {
+///     // Only the first evaluation parses and compiles the snippet.
+///     context.eval("total += 2").unwrap();
+/// }
+///
+/// assert_eq!(context.eval("total").unwrap(), 20.into());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct EvalCacheConfig {
+    /// The maximum number of snippets in the cache, `0` disables the cache.
+    ///
+    /// When the cache is full, the snippet that was cached first is evicted.
+    pub max_entries: usize,
+
+    /// The maximum length (in bytes) of the source of a cached snippet.
+    ///
+    /// Longer sources are compiled every time they are evaluated.
+    pub max_source_len: usize,
+}
+
+/// A snippet in the cache.
+#[derive(Debug)]
+struct Entry {
+    /// The source of the snippet, to tell apart snippets with the same hash.
+    source: Box<[u8]>,
+
+    /// Whether the snippet was compiled in a strict mode context.
+    strict: bool,
+
+    /// The compiled code of the snippet.
+    code: Gc<CodeBlock>,
+
+    /// The declarations of the snippet, which are checked again every time it is evaluated.
+    declarations: Rc<GlobalDeclarations>,
+
+    /// The number of bindings of the global compile time environment after the snippet was
+    /// compiled.
+    ///
+    /// The compiled code refers to the global lexical bindings by index, and to everything else
+    /// in the global scope by name, so the code has to be compiled again if a global lexical
+    /// binding was declared since.
+    global_bindings: usize,
+}
+
+/// The cache of compiled code of a [`Context`][crate::Context], keyed by the hash of the source
+/// of a snippet and of the flags that change how it is compiled.
+#[derive(Debug, Default)]
+pub(crate) struct EvalCache {
+    config: EvalCacheConfig,
+    entries: IndexMap<u64, Entry, BuildHasherDefault<FxHasher>>,
+}
+
+impl EvalCache {
+    /// Replaces the configuration of the cache, and removes all the cached snippets.
+    pub(crate) fn set_config(&mut self, config: EvalCacheConfig) {
+        self.config = config;
+        self.clear();
+    }
+
+    /// Removes all the cached snippets.
+    pub(crate) fn clear(&mut self) {
+        self.entries = IndexMap::default();
+    }
+
+    /// Whether the given source can be cached.
+    pub(crate) fn accepts(&self, source: &[u8]) -> bool {
+        self.config.max_entries > 0 && source.len() <= self.config.max_source_len
+    }
+
+    /// Gets the compiled code and the declarations of the given snippet, if it is cached and
+    /// the code is still valid with the given number of global lexical bindings.
+    pub(crate) fn get(
+        &self,
+        source: &[u8],
+        strict: bool,
+        global_bindings: usize,
+    ) -> Option<(Gc<CodeBlock>, Rc<GlobalDeclarations>)> {
+        let entry = self.entries.get(&Self::key(source, strict))?;
+        if entry.strict != strict
+            || *entry.source != *source
+            || entry.global_bindings != global_bindings
+        {
+            return None;
+        }
+        Some((entry.code.clone(), entry.declarations.clone()))
+    }
+
+    /// Caches the compiled code of a snippet, evicting the oldest snippet if the cache is full.
+    pub(crate) fn insert(
+        &mut self,
+        source: &[u8],
+        strict: bool,
+        code: Gc<CodeBlock>,
+        declarations: Rc<GlobalDeclarations>,
+        global_bindings: usize,
+    ) {
+        let key = Self::key(source, strict);
+        if !self.entries.contains_key(&key) && self.entries.len() >= self.config.max_entries {
+            self.entries.shift_remove_index(0);
+        }
+        self.entries.insert(
+            key,
+            Entry {
+                source: source.into(),
+                strict,
+                code,
+                declarations,
+                global_bindings,
+            },
+        );
+    }
+
+    /// Gets the number of cached snippets.
+    #[cfg(test)]
+    pub(crate) fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    fn key(source: &[u8], strict: bool) -> u64 {
+        let mut hasher = FxHasher::default();
+        source.hash(&mut hasher);
+        strict.hash(&mut hasher);
+        hasher.finish()
+    }
+}
@@ -0,0 +1,133 @@
+//! Host hooks that allow embedders to customize the behaviour of the engine.
+//!
+//! The specification defines some abstract operations as host-defined, so that the environment
+//! running the engine can adapt them to its needs. A [`Context`] calls these operations on the
+//! [`HostHooks`] installed with [`ContextBuilder::host_hooks`][crate::context::ContextBuilder::host_hooks].
+
+use crate::{job::JobCallback, object::JsObject, Context, JsResult, JsValue};
+use std::fmt;
+
+/// The operations passed to [`HostHooks::promise_rejection_tracker`].
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-host-promise-rejection-tracker
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OperationType {
+    /// A promise was rejected without any handlers.
+    Reject,
+
+    /// A handler was added to a rejected promise for the first time.
+    Handle,
+}
+
+/// The host-defined abstract operations of the specification.
+///
+/// All the hooks have default implementations that match the behaviour required by the
+/// specification for hosts that don't customize them, so implementors only need to override
+/// the hooks they care about.
+///
+/// # Examples
+///
+/// ```
+/// use boa_engine::{context::HostHooks, Context, JsResult};
+///
+/// struct NoDynamicCode;
+///
+/// impl HostHooks for NoDynamicCode {
+///     fn ensure_can_compile_strings(&self, context: &mut Context) -> JsResult<()> {
+///         context.throw_eval_error("dynamic code evaluation is disabled")
+///     }
+/// }
+///
+/// let mut context = Context::builder().host_hooks(NoDynamicCode).build();
+///
+/// assert!(context.eval("eval('1 + 1')").is_err());
+/// ```
+pub trait HostHooks {
+    /// `HostMakeJobCallback ( callback )`
+    ///
+    /// Creates the [`JobCallback`] record of a function that will be called by a job, such as
+    /// the handlers of a promise. Hosts can store data in the record using
+    /// [`JobCallback::new`], and retrieve it in [`HostHooks::call_job_callback`].
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-hostmakejobcallback
+    fn make_job_callback(&self, callback: JsObject, _context: &mut Context) -> JobCallback {
+        // 1. Return the JobCallback Record { [[Callback]]: callback, [[HostDefined]]: empty }.
+        JobCallback::new(callback, JsValue::undefined())
+    }
+
+    /// `HostCallJobCallback ( jobCallback, V, argumentsList )`
+    ///
+    /// Calls the function of a [`JobCallback`] record. Implementations must call the function
+    /// of the record with the given `this` value and arguments, but can restore any state of the
+    /// host around the call.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-hostcalljobcallback
+    fn call_job_callback(
+        &self,
+        job: &JobCallback,
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Assert: IsCallable(jobCallback.[[Callback]]) is true.
+        assert!(
+            job.callback().is_callable(),
+            "the callback of the job callback was not callable"
+        );
+
+        // 2. Return ? Call(jobCallback.[[Callback]], V, argumentsList).
+        job.callback().__call__(this, args, context)
+    }
+
+    /// `HostPromiseRejectionTracker ( promise, operation )`
+    ///
+    /// Called when a promise is rejected without any handlers, and when a handler is added to
+    /// a rejected promise for the first time. Hosts can use this to report unhandled rejections.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-host-promise-rejection-tracker
+    fn promise_rejection_tracker(
+        &self,
+        _promise: &JsObject,
+        _operation: OperationType,
+        _context: &mut Context,
+    ) {
+    }
+
+    /// `HostEnsureCanCompileStrings ( calleeRealm )`
+    ///
+    /// Called before compiling code from a string with `eval` or the `Function` constructors.
+    /// Returning an error prevents the code from being compiled, and the error is thrown to the
+    /// caller.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-hostensurecancompilestrings
+    fn ensure_can_compile_strings(&self, _context: &mut Context) -> JsResult<()> {
+        Ok(())
+    }
+}
+
+impl fmt::Debug for dyn HostHooks {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("HostHooks")
+    }
+}
+
+/// The hooks used by a [`Context`] if the host doesn't provide any.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct DefaultHooks;
+
+impl HostHooks for DefaultHooks {}
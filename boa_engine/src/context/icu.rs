@@ -6,7 +6,7 @@ use icu_locale_canonicalizer::{
     provider::{AliasesV1Marker, LikelySubtagsV1Marker},
     LocaleCanonicalizer,
 };
-use icu_plurals::provider::OrdinalV1Marker;
+use icu_plurals::provider::{CardinalV1Marker, OrdinalV1Marker};
 use icu_provider::prelude::*;
 
 /// Trait encompassing all the required implementations that define
@@ -17,6 +17,7 @@ pub trait BoaProvider:
     + ResourceProvider<DateSymbolsV1Marker>
     + ResourceProvider<DatePatternsV1Marker>
     + ResourceProvider<DateSkeletonPatternsV1Marker>
+    + ResourceProvider<CardinalV1Marker>
     + ResourceProvider<OrdinalV1Marker>
     + ResourceProvider<WeekDataV1Marker>
 {
@@ -28,6 +29,7 @@ impl<T> BoaProvider for T where
         + ResourceProvider<DateSymbolsV1Marker>
         + ResourceProvider<DatePatternsV1Marker>
         + ResourceProvider<DateSkeletonPatternsV1Marker>
+        + ResourceProvider<CardinalV1Marker>
         + ResourceProvider<OrdinalV1Marker>
         + ResourceProvider<WeekDataV1Marker>
         + ?Sized
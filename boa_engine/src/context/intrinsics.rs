@@ -1,18 +1,36 @@
+#[cfg(feature = "intl")]
+use crate::builtins::intl::segmenter::Segments;
 use crate::{
     builtins::{
         array::Array, error::r#type::create_throw_type_error, iterable::IteratorPrototypes,
     },
+    class::Class,
     object::{JsObject, ObjectData},
     property::PropertyDescriptorBuilder,
     Context,
 };
-
+use rustc_hash::FxHashMap;
+use std::any::TypeId;
+
+/// The intrinsic objects of a realm, as defined by the specification.
+///
+/// These are the objects the engine itself uses, so they are not affected by scripts that modify
+/// or delete the corresponding properties of the global object. The well-known symbols, like
+/// `@@iterator`, are shared by all realms and can be obtained from
+/// [`WellKnownSymbols`][crate::symbol::WellKnownSymbols].
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-well-known-intrinsic-objects
 #[derive(Debug, Default)]
 pub struct Intrinsics {
     /// Cached standard constructors
     pub(super) constructors: StandardConstructors,
     /// Cached intrinsic objects
     pub(super) objects: IntrinsicObjects,
+    /// Constructors of the native classes registered in the realm
+    pub(super) classes: FxHashMap<TypeId, StandardConstructor>,
 }
 
 impl Intrinsics {
@@ -27,6 +45,13 @@ impl Intrinsics {
     pub fn constructors(&self) -> &StandardConstructors {
         &self.constructors
     }
+
+    /// Return the constructor and prototype of the native class `T`, if it was registered with
+    /// [`Context::register_global_class`] or as part of a namespace.
+    #[inline]
+    pub fn class<T: Class>(&self) -> Option<&StandardConstructor> {
+        self.classes.get(&TypeId::of::<T>())
+    }
 }
 
 /// Store a builtin constructor (such as `Object`) and its corresponding prototype.
@@ -115,6 +140,9 @@ pub struct StandardConstructors {
     array_buffer: StandardConstructor,
     data_view: StandardConstructor,
     date_time_format: StandardConstructor,
+    plural_rules: StandardConstructor,
+    relative_time_format: StandardConstructor,
+    segmenter: StandardConstructor,
     promise: StandardConstructor,
 }
 
@@ -174,6 +202,9 @@ impl Default for StandardConstructors {
             array_buffer: StandardConstructor::default(),
             data_view: StandardConstructor::default(),
             date_time_format: StandardConstructor::default(),
+            plural_rules: StandardConstructor::default(),
+            relative_time_format: StandardConstructor::default(),
+            segmenter: StandardConstructor::default(),
             promise: StandardConstructor::default(),
         };
 
@@ -398,6 +429,21 @@ impl StandardConstructors {
         &self.date_time_format
     }
 
+    #[inline]
+    pub fn plural_rules(&self) -> &StandardConstructor {
+        &self.plural_rules
+    }
+
+    #[inline]
+    pub fn relative_time_format(&self) -> &StandardConstructor {
+        &self.relative_time_format
+    }
+
+    #[inline]
+    pub fn segmenter(&self) -> &StandardConstructor {
+        &self.segmenter
+    }
+
     #[inline]
     pub fn promise(&self) -> &StandardConstructor {
         &self.promise
@@ -415,6 +461,10 @@ pub struct IntrinsicObjects {
 
     /// Cached iterator prototypes.
     iterator_prototypes: IteratorPrototypes,
+
+    /// `%SegmentsPrototype%` intrinsic object
+    #[cfg(feature = "intl")]
+    segments_prototype: JsObject,
 }
 
 impl IntrinsicObjects {
@@ -424,6 +474,8 @@ impl IntrinsicObjects {
             throw_type_error: create_throw_type_error(context),
             array_prototype_values: Array::create_array_prototype_values(context).into(),
             iterator_prototypes: IteratorPrototypes::init(context),
+            #[cfg(feature = "intl")]
+            segments_prototype: Segments::create_prototype(context),
         }
     }
 
@@ -444,4 +496,11 @@ impl IntrinsicObjects {
     pub fn iterator_prototypes(&self) -> &IteratorPrototypes {
         &self.iterator_prototypes
     }
+
+    /// Get the `%SegmentsPrototype%` intrinsic object.
+    #[inline]
+    #[cfg(feature = "intl")]
+    pub fn segments_prototype(&self) -> JsObject {
+        self.segments_prototype.clone()
+    }
 }
@@ -0,0 +1,196 @@
+//! Configurable resource limits of a [`Context`][crate::Context].
+//!
+//! These limits allow embedders running untrusted code to bound the amount of memory that a
+//! single allocation inside the engine can request, and the time that a single evaluation can
+//! take. Every limit defaults to the maximum the specification allows, so a default
+//! [`Context`][crate::Context] behaves as if there were no limits at all.
+
+use crate::{builtins::String, JsNativeError, JsResult};
+
+/// The maximum length of an array, as defined by the specification (2^32 - 1).
+const DEFAULT_MAX_ARRAY_LENGTH: u64 = u32::MAX as u64;
+
+/// The maximum byte length of an array buffer, as defined by the specification (2^53 - 1).
+const DEFAULT_MAX_ARRAY_BUFFER_BYTE_LENGTH: u64 = (1 << 53) - 1;
+
+/// Resource limits enforced by a [`Context`][crate::Context].
+///
+/// Exceeding any of the limits throws a catchable `RangeError`.
+///
+/// # Examples
+///
+/// ```
+/// use boa_engine::{context::ResourceLimits, Context};
+///
+/// let mut limits = ResourceLimits::default();
+/// limits.set_max_string_length(1024);
+///
+/// let mut context = Context::builder().resource_limits(limits).build();
+///
+/// assert!(context.eval("'x'.repeat(2048)").is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ResourceLimits {
+    max_string_length: usize,
+    max_array_length: u64,
+    max_array_buffer_byte_length: u64,
+    max_property_count: usize,
+    max_object_nesting: usize,
+    max_instructions: u64,
+}
+
+impl Default for ResourceLimits {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            max_string_length: String::MAX_STRING_LENGTH,
+            max_array_length: DEFAULT_MAX_ARRAY_LENGTH,
+            max_array_buffer_byte_length: DEFAULT_MAX_ARRAY_BUFFER_BYTE_LENGTH,
+            max_property_count: usize::MAX,
+            max_object_nesting: usize::MAX,
+            max_instructions: u64::MAX,
+        }
+    }
+}
+
+impl ResourceLimits {
+    /// Gets the maximum length (in UTF-16 code units) of a string created by the engine.
+    #[inline]
+    pub fn max_string_length(&self) -> usize {
+        self.max_string_length
+    }
+
+    /// Sets the maximum length (in UTF-16 code units) of a string created by the engine.
+    ///
+    /// This is enforced by string concatenation (the `+` operator and template literals), by the
+    /// methods of `String` and `String.prototype` that build a new string (like `repeat`,
+    /// `padStart`, `concat`, `replace` or `toUpperCase`), by `RegExp.prototype[@@replace]`, by
+    /// the `join` methods of arrays and typed arrays, and by `JSON.parse` and `JSON.stringify`.
+    #[inline]
+    pub fn set_max_string_length(&mut self, value: usize) {
+        self.max_string_length = value;
+    }
+
+    /// Gets the maximum number of elements that can be eagerly allocated.
+    #[inline]
+    pub fn max_array_length(&self) -> u64 {
+        self.max_array_length
+    }
+
+    /// Sets the maximum number of elements that can be eagerly allocated.
+    ///
+    /// This is enforced when creating arrays, when filling them with `Array.prototype.fill`,
+    /// and by `JSON.parse`.
+    #[inline]
+    pub fn set_max_array_length(&mut self, value: u64) {
+        self.max_array_length = value;
+    }
+
+    /// Gets the maximum number of bytes of the data of an array buffer.
+    #[inline]
+    pub fn max_array_buffer_byte_length(&self) -> u64 {
+        self.max_array_buffer_byte_length
+    }
+
+    /// Sets the maximum number of bytes of the data of an array buffer.
+    ///
+    /// This is enforced when allocating the data of array buffers, including the ones created
+    /// for typed arrays, so it bounds the byte length of a typed array instead of its number of
+    /// elements.
+    #[inline]
+    pub fn set_max_array_buffer_byte_length(&mut self, value: u64) {
+        self.max_array_buffer_byte_length = value;
+    }
+
+    /// Gets the maximum number of own properties of a single object.
+    #[inline]
+    pub fn max_property_count(&self) -> usize {
+        self.max_property_count
+    }
+
+    /// Sets the maximum number of own properties of a single object.
+    ///
+    /// This is enforced every time a new property is defined on an ordinary object, including
+    /// the objects that the engine creates with a fixed set of properties, like arguments
+    /// objects, iterator results and property descriptors. The properties that the engine
+    /// defines on the objects it creates, like the `length` of arrays, strings and functions,
+    /// the `name` of functions or the `lastIndex` of regular expressions, are not checked. The
+    /// objects built by the host, like the handler of a [`JsProxy`][crate::object::JsProxy] or
+    /// the result of [`create_iter_result_object`][crate::builtins::iterable::create_iter_result_object],
+    /// are not checked either.
+    #[inline]
+    pub fn set_max_property_count(&mut self, value: usize) {
+        self.max_property_count = value;
+    }
+
+    /// Gets the maximum depth of nested objects that the engine traverses.
+    #[inline]
+    pub fn max_object_nesting(&self) -> usize {
+        self.max_object_nesting
+    }
+
+    /// Sets the maximum depth of nested objects that the engine traverses.
+    ///
+    /// This is enforced by the operations that recursively walk through nested objects and
+    /// arrays: `JSON.parse` (including its reviver), `JSON.stringify`, and
+    /// `Array.prototype.flat` and `Array.prototype.flatMap`. An object that doesn't contain any
+    /// other object has a depth of 1.
+    #[inline]
+    pub fn set_max_object_nesting(&mut self, value: usize) {
+        self.max_object_nesting = value;
+    }
+
+    /// Gets the maximum number of instructions executed by a single evaluation.
+    #[inline]
+    pub fn max_instructions(&self) -> u64 {
+        self.max_instructions
+    }
+
+    /// Sets the maximum number of instructions executed by a single evaluation.
+    ///
+    /// This counts all the instructions executed by [`Context::eval`][crate::Context::eval]
+    /// and [`Context::execute`][crate::Context::execute], including the ones of the promise
+    /// jobs that they run. Once the limit is exceeded, every following instruction throws, so
+    /// the error propagates out of any `catch` or `finally` block.
+    #[inline]
+    pub fn set_max_instructions(&mut self, value: u64) {
+        self.max_instructions = value;
+    }
+
+    /// Throws a `RangeError` if the concatenation of `strings` exceeds the maximum string length.
+    pub(crate) fn check_string_length(&self, strings: &[&str]) -> JsResult<()> {
+        // The UTF-8 length of a string is never smaller than its UTF-16 length, so the code units
+        // only need to be counted if the strings could exceed the limit.
+        let byte_len = strings
+            .iter()
+            .fold(0usize, |len, string| len.saturating_add(string.len()));
+        if byte_len <= self.max_string_length {
+            return Ok(());
+        }
+        let len = strings.iter().fold(0usize, |len, string| {
+            len.saturating_add(string.encode_utf16().count())
+        });
+        if len > self.max_string_length {
+            return Err(JsNativeError::range()
+                .with_message(format!(
+                    "string length exceeds the maximum string length ({})",
+                    self.max_string_length
+                ))
+                .into());
+        }
+        Ok(())
+    }
+
+    /// Throws a `RangeError` if an object at the given `depth` exceeds the maximum object nesting.
+    pub(crate) fn check_object_nesting(&self, depth: usize) -> JsResult<()> {
+        if depth > self.max_object_nesting {
+            return Err(JsNativeError::range()
+                .with_message(format!(
+                    "object nesting exceeds the maximum object nesting ({})",
+                    self.max_object_nesting
+                ))
+                .into());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,234 @@
+//! Measurement of the memory used by the engine.
+//!
+//! The memory is accounted by counters that are updated every time an object, string, code block
+//! or array buffer is allocated or freed, so measuring it doesn't need to walk the heap.
+//!
+//! Every context owns its own counters. The garbage collector is shared by all the contexts of a
+//! thread, so each allocation is accounted to the context that was running when it was made, and
+//! keeps a reference to its counters to account itself as freed when it's dropped.
+
+use boa_gc::{unsafe_empty_trace, Finalize, Trace};
+use std::{
+    cell::{Cell, RefCell},
+    rc::{Rc, Weak},
+};
+
+thread_local! {
+    static ACTIVE: RefCell<ActiveCounters> = RefCell::default();
+}
+
+/// The counters of the context that is running in the current thread.
+#[derive(Debug, Default)]
+struct ActiveCounters {
+    counters: Weak<MemoryCounters>,
+    depth: usize,
+}
+
+/// Gets the counters of the context that is running in the current thread, if any.
+#[inline]
+fn active_counters() -> Option<Rc<MemoryCounters>> {
+    // The counters may already be destroyed if this is called while the thread exits.
+    ACTIVE
+        .try_with(|active| active.borrow().counters.upgrade())
+        .ok()
+        .flatten()
+}
+
+/// The number of bytes currently allocated by a context for each [`MemoryKind`].
+#[derive(Debug, Default)]
+pub(crate) struct MemoryCounters {
+    gc_heap: Cell<usize>,
+    strings: Cell<usize>,
+    code_blocks: Cell<usize>,
+    array_buffers: Cell<usize>,
+}
+
+impl MemoryCounters {
+    fn counter(&self, kind: MemoryKind) -> &Cell<usize> {
+        match kind {
+            MemoryKind::GcHeap => &self.gc_heap,
+            MemoryKind::Strings => &self.strings,
+            MemoryKind::CodeBlocks => &self.code_blocks,
+            MemoryKind::ArrayBuffers => &self.array_buffers,
+        }
+    }
+
+    fn allocated(&self, kind: MemoryKind, bytes: usize) {
+        let counter = self.counter(kind);
+        counter.set(counter.get().saturating_add(bytes));
+    }
+
+    fn freed(&self, kind: MemoryKind, bytes: usize) {
+        let counter = self.counter(kind);
+        counter.set(counter.get().saturating_sub(bytes));
+    }
+
+    /// Makes these the counters of the running context, until the returned guard is dropped.
+    ///
+    /// When the outermost guard is dropped the counters stay active, so the memory allocated
+    /// through the Rust API between two executions is accounted to the last context that ran.
+    pub(crate) fn enter(self: &Rc<Self>) -> ActiveContext {
+        let previous = ACTIVE
+            .try_with(|active| {
+                let mut active = active.borrow_mut();
+                active.depth += 1;
+                std::mem::replace(&mut active.counters, Rc::downgrade(self))
+            })
+            .unwrap_or_default();
+        ActiveContext { previous }
+    }
+
+    /// Measures the memory currently used by the context that owns these counters.
+    pub(crate) fn usage(&self, interner: usize) -> MemoryUsage {
+        MemoryUsage {
+            gc_heap: self.gc_heap.get(),
+            interner,
+            strings: self.strings.get(),
+            code_blocks: self.code_blocks.get(),
+            array_buffers: self.array_buffers.get(),
+        }
+    }
+}
+
+/// Restores the counters that were active before [`MemoryCounters::enter`] when dropped.
+#[derive(Debug)]
+pub(crate) struct ActiveContext {
+    previous: Weak<MemoryCounters>,
+}
+
+impl Drop for ActiveContext {
+    #[inline]
+    fn drop(&mut self) {
+        let previous = std::mem::take(&mut self.previous);
+        let _ = ACTIVE.try_with(|active| {
+            let mut active = active.borrow_mut();
+            active.depth = active.depth.saturating_sub(1);
+            if active.depth > 0 {
+                active.counters = previous;
+            }
+        });
+    }
+}
+
+/// The kinds of memory accounted by the engine.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum MemoryKind {
+    GcHeap,
+    Strings,
+    CodeBlocks,
+    ArrayBuffers,
+}
+
+/// Accounts some memory as allocated for as long as it lives.
+///
+/// The memory is accounted to the context that is running when the allocation is created. This
+/// can be embedded in the values that own the memory, so that it is accounted as freed when they
+/// are dropped. Cloning it accounts the memory again, for the clone.
+#[derive(Debug)]
+pub(crate) struct Allocation {
+    counters: Option<Rc<MemoryCounters>>,
+    kind: MemoryKind,
+    bytes: usize,
+}
+
+impl Allocation {
+    /// Accounts `bytes` of memory of the given kind as allocated.
+    #[inline]
+    pub(crate) fn new(kind: MemoryKind, bytes: usize) -> Self {
+        let counters = active_counters();
+        if let Some(counters) = &counters {
+            counters.allocated(kind, bytes);
+        }
+        Self {
+            counters,
+            kind,
+            bytes,
+        }
+    }
+
+    /// Changes the amount of memory accounted by this allocation.
+    #[inline]
+    pub(crate) fn resize(&mut self, bytes: usize) {
+        if let Some(counters) = &self.counters {
+            if bytes > self.bytes {
+                counters.allocated(self.kind, bytes - self.bytes);
+            } else {
+                counters.freed(self.kind, self.bytes - bytes);
+            }
+        }
+        self.bytes = bytes;
+    }
+}
+
+impl Clone for Allocation {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self::new(self.kind, self.bytes)
+    }
+}
+
+impl Drop for Allocation {
+    #[inline]
+    fn drop(&mut self) {
+        if let Some(counters) = &self.counters {
+            counters.freed(self.kind, self.bytes);
+        }
+    }
+}
+
+impl Finalize for Allocation {}
+
+// SAFETY: `Allocation` doesn't contain any traceable types.
+unsafe impl Trace for Allocation {
+    unsafe_empty_trace!();
+}
+
+/// The memory used by the engine, in bytes.
+///
+/// This is returned by [`Context::memory_usage`][crate::Context::memory_usage].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MemoryUsage {
+    pub(crate) gc_heap: usize,
+    pub(crate) interner: usize,
+    pub(crate) strings: usize,
+    pub(crate) code_blocks: usize,
+    pub(crate) array_buffers: usize,
+}
+
+impl MemoryUsage {
+    /// Gets the memory used by the objects of the garbage collected heap.
+    #[inline]
+    pub fn gc_heap(&self) -> usize {
+        self.gc_heap
+    }
+
+    /// Gets the memory used by the strings interned by the context.
+    #[inline]
+    pub fn interner(&self) -> usize {
+        self.interner
+    }
+
+    /// Gets the memory used by the strings created at runtime.
+    #[inline]
+    pub fn strings(&self) -> usize {
+        self.strings
+    }
+
+    /// Gets the memory used by the bytecode of compiled scripts and functions.
+    #[inline]
+    pub fn code_blocks(&self) -> usize {
+        self.code_blocks
+    }
+
+    /// Gets the memory used by the data of array buffers.
+    #[inline]
+    pub fn array_buffers(&self) -> usize {
+        self.array_buffers
+    }
+
+    /// Gets the total memory used.
+    #[inline]
+    pub fn total(&self) -> usize {
+        self.gc_heap + self.interner + self.strings + self.code_blocks + self.array_buffers
+    }
+}
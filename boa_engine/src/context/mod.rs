@@ -1,27 +1,58 @@
 //! Javascript context.
 
+mod compiled_script;
+mod completion;
+pub(crate) mod declarations;
+mod eval_cache;
+mod hooks;
 pub mod intrinsics;
+mod limits;
+pub(crate) mod memory;
+mod namespace;
+mod sandbox;
 
 #[cfg(feature = "intl")]
 mod icu;
 
-use std::collections::VecDeque;
+#[cfg(test)]
+mod tests;
 
-use intrinsics::{IntrinsicObjects, Intrinsics};
+use std::{any::TypeId, collections::VecDeque, rc::Rc};
+
+use declarations::GlobalDeclarations;
+use eval_cache::EvalCache;
+use hooks::DefaultHooks;
+use intrinsics::{IntrinsicObjects, Intrinsics, StandardConstructor};
+use memory::{ActiveContext, MemoryCounters};
+use sandbox::HostSources;
+
+pub use compiled_script::CompiledScript;
+pub use completion::Completion;
+pub use eval_cache::EvalCacheConfig;
+pub use hooks::{HostHooks, OperationType};
+pub use limits::ResourceLimits;
+pub use memory::MemoryUsage;
+pub use namespace::NamespaceBuilder;
+pub use sandbox::Intrinsic;
 
 #[cfg(feature = "console")]
 use crate::builtins::console::Console;
 use crate::{
-    builtins::{self, function::NativeFunctionSignature},
+    builtins::{self, function::NativeFunctionSignature, Performance},
     bytecompiler::ByteCompiler,
     class::{Class, ClassBuilder},
+    error::JsNativeError,
     job::JobCallback,
-    object::{FunctionBuilder, GlobalPropertyMap, JsObject, ObjectData},
+    object::{FunctionBuilder, GlobalPropertyMap, JsFunction, JsObject, ObjectData},
     property::{Attribute, PropertyDescriptor, PropertyKey},
     realm::Realm,
-    syntax::{ast::node::StatementList, parser::ParseError, Parser},
+    syntax::{
+        ast::node::StatementList,
+        parser::{ParseError, DEFAULT_RECURSION_LIMIT},
+        Parser,
+    },
     vm::{CallFrame, CodeBlock, FinallyReturn, GeneratorResumeKind, Vm},
-    JsResult, JsValue,
+    JsResult, JsString, JsValue,
 };
 
 use boa_gc::Gc;
@@ -41,6 +72,12 @@ pub use icu::BoaProvider;
 /// is possible to share objects from one context to another context, but they
 /// have to be in the same thread.
 ///
+/// A `Context` is not [`Send`], since its objects are managed by a garbage collector that is
+/// local to the thread that created them. To run scripts in multiple threads, create one
+/// `Context` in each thread and only share `Send` data between them. Scripts can be compiled once
+/// into a [`CompiledScript`], which is `Send + Sync` and can be executed in any of those contexts.
+/// The `context_pool` example shows how to do this with a pool of worker threads.
+///
 /// # Examples
 ///
 /// ## Execute Function of Script File
@@ -102,6 +139,30 @@ pub struct Context {
     pub(crate) vm: Vm,
 
     pub(crate) promise_job_queue: VecDeque<JobCallback>,
+
+    /// Resource limits enforced by the engine.
+    limits: ResourceLimits,
+
+    /// Host-provided sources of time and randomness.
+    host_sources: HostSources,
+
+    /// Builtins removed by the host.
+    removed_intrinsics: Intrinsic,
+
+    /// Whether all code evaluated by the context is strict mode code.
+    strict: bool,
+
+    /// The maximum nesting depth of the parsed code.
+    recursion_limit: usize,
+
+    /// Host-defined abstract operations.
+    host_hooks: Rc<dyn HostHooks>,
+
+    /// Cache of the code compiled by `eval`.
+    eval_cache: EvalCache,
+
+    /// The memory allocated by the context.
+    memory: Rc<MemoryCounters>,
 }
 
 impl Default for Context {
@@ -128,6 +189,16 @@ impl Context {
         &mut self.interner
     }
 
+    /// Gets the string of an interned symbol as a [`JsString`], keeping the unpaired surrogates
+    /// of string literals.
+    #[inline]
+    pub(crate) fn interned_string(&self, sym: Sym) -> JsString {
+        self.interner.resolve_utf16(sym).map_or_else(
+            || self.interner.resolve_expect(sym).into(),
+            JsString::from_utf16,
+        )
+    }
+
     /// A helper function for getting an immutable reference to the `console` object.
     #[cfg(feature = "console")]
     pub(crate) fn console(&self) -> &Console {
@@ -149,6 +220,41 @@ impl Context {
         builtins::init(self);
     }
 
+    /// Removes the given builtins from the global object.
+    fn remove_intrinsics(&mut self, intrinsics: Intrinsic) {
+        self.removed_intrinsics = intrinsics;
+
+        if intrinsics.contains(Intrinsic::DATE_NOW) {
+            self.intrinsics()
+                .constructors()
+                .date()
+                .constructor()
+                .borrow_mut()
+                .remove(&"now".into());
+        }
+        if intrinsics.contains(Intrinsic::MATH_RANDOM) {
+            if let Some(math) = self
+                .global_bindings_mut()
+                .get("Math")
+                .and_then(PropertyDescriptor::value)
+                .and_then(JsValue::as_object)
+            {
+                math.borrow_mut().remove(&"random".into());
+            }
+        }
+
+        let globals = [
+            (Intrinsic::EVAL, "eval"),
+            (Intrinsic::FUNCTION, "Function"),
+            (Intrinsic::DATE, "Date"),
+        ];
+        for (intrinsic, name) in globals {
+            if intrinsics.contains(intrinsic) {
+                self.global_bindings_mut().shift_remove(name);
+            }
+        }
+    }
+
     /// Constructs an object with the `%Object.prototype%` prototype.
     #[inline]
     pub fn construct_object(&self) -> JsObject {
@@ -163,8 +269,18 @@ impl Context {
     where
         S: AsRef<[u8]>,
     {
-        let mut parser = Parser::new(src.as_ref());
-        parser.parse_all(self)
+        self.parser(src.as_ref()).parse_all(self)
+    }
+
+    /// Creates a parser for the given source text, with the strictness and the recursion limit of
+    /// the context.
+    pub(crate) fn parser<'s>(&self, src: &'s [u8]) -> Parser<&'s [u8]> {
+        let mut parser = Parser::new(src);
+        if self.strict {
+            parser.set_strict();
+        }
+        parser.set_recursion_limit(self.recursion_limit);
+        parser
     }
 
     /// Parse the given source text with eval specific handling.
@@ -177,7 +293,7 @@ impl Context {
     where
         S: AsRef<[u8]>,
     {
-        let mut parser = Parser::new(src.as_ref());
+        let mut parser = self.parser(src.as_ref());
         if strict {
             parser.set_strict();
         }
@@ -210,7 +326,11 @@ impl Context {
         // 2. If IsCallable(F) is false, throw a TypeError exception.
         // 3. Return ? F.[[Call]](V, argumentsList).
         f.as_callable()
-            .ok_or_else(|| self.construct_type_error("Value is not callable"))
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("Value is not callable")
+                    .into()
+            })
             .and_then(|f| f.call(v, arguments_list, self))
     }
 
@@ -232,17 +352,10 @@ impl Context {
     where
         M: Into<Box<str>>,
     {
-        crate::builtins::error::Error::constructor(
-            &self
-                .intrinsics()
-                .constructors()
-                .error()
-                .constructor()
-                .into(),
-            &[message.into().into()],
-            self,
-        )
-        .expect("Into<String> used as message")
+        JsNativeError::error()
+            .with_message(message)
+            .to_opaque(self)
+            .into()
     }
 
     /// Throws a `Error` with the specified message.
@@ -251,7 +364,7 @@ impl Context {
     where
         M: Into<Box<str>>,
     {
-        Err(self.construct_error(message))
+        Err(JsNativeError::error().with_message(message).into())
     }
 
     /// Constructs a `RangeError` with the specified message.
@@ -260,17 +373,10 @@ impl Context {
     where
         M: Into<Box<str>>,
     {
-        crate::builtins::error::RangeError::constructor(
-            &self
-                .intrinsics()
-                .constructors()
-                .range_error()
-                .constructor()
-                .into(),
-            &[message.into().into()],
-            self,
-        )
-        .expect("Into<String> used as message")
+        JsNativeError::range()
+            .with_message(message)
+            .to_opaque(self)
+            .into()
     }
 
     /// Throws a `RangeError` with the specified message.
@@ -279,7 +385,7 @@ impl Context {
     where
         M: Into<Box<str>>,
     {
-        Err(self.construct_range_error(message))
+        Err(JsNativeError::range().with_message(message).into())
     }
 
     /// Constructs a `TypeError` with the specified message.
@@ -288,17 +394,10 @@ impl Context {
     where
         M: Into<Box<str>>,
     {
-        crate::builtins::error::TypeError::constructor(
-            &self
-                .intrinsics()
-                .constructors()
-                .type_error()
-                .constructor()
-                .into(),
-            &[message.into().into()],
-            self,
-        )
-        .expect("Into<String> used as message")
+        JsNativeError::typ()
+            .with_message(message)
+            .to_opaque(self)
+            .into()
     }
 
     /// Throws a `TypeError` with the specified message.
@@ -307,7 +406,7 @@ impl Context {
     where
         M: Into<Box<str>>,
     {
-        Err(self.construct_type_error(message))
+        Err(JsNativeError::typ().with_message(message).into())
     }
 
     /// Constructs a `ReferenceError` with the specified message.
@@ -316,17 +415,10 @@ impl Context {
     where
         M: Into<Box<str>>,
     {
-        crate::builtins::error::ReferenceError::constructor(
-            &self
-                .intrinsics()
-                .constructors()
-                .reference_error()
-                .constructor()
-                .into(),
-            &[message.into().into()],
-            self,
-        )
-        .expect("Into<String> used as message")
+        JsNativeError::reference()
+            .with_message(message)
+            .to_opaque(self)
+            .into()
     }
 
     /// Throws a `ReferenceError` with the specified message.
@@ -335,7 +427,7 @@ impl Context {
     where
         M: Into<Box<str>>,
     {
-        Err(self.construct_reference_error(message))
+        Err(JsNativeError::reference().with_message(message).into())
     }
 
     /// Constructs a `SyntaxError` with the specified message.
@@ -344,17 +436,10 @@ impl Context {
     where
         M: Into<Box<str>>,
     {
-        crate::builtins::error::SyntaxError::constructor(
-            &self
-                .intrinsics()
-                .constructors()
-                .syntax_error()
-                .constructor()
-                .into(),
-            &[message.into().into()],
-            self,
-        )
-        .expect("Into<String> used as message")
+        JsNativeError::syntax()
+            .with_message(message)
+            .to_opaque(self)
+            .into()
     }
 
     /// Throws a `SyntaxError` with the specified message.
@@ -363,7 +448,7 @@ impl Context {
     where
         M: Into<Box<str>>,
     {
-        Err(self.construct_syntax_error(message))
+        Err(JsNativeError::syntax().with_message(message).into())
     }
 
     /// Constructs a `EvalError` with the specified message.
@@ -371,17 +456,10 @@ impl Context {
     where
         M: Into<Box<str>>,
     {
-        crate::builtins::error::EvalError::constructor(
-            &self
-                .intrinsics()
-                .constructors()
-                .eval_error()
-                .constructor()
-                .into(),
-            &[message.into().into()],
-            self,
-        )
-        .expect("Into<String> used as message")
+        JsNativeError::eval()
+            .with_message(message)
+            .to_opaque(self)
+            .into()
     }
 
     /// Constructs a `URIError` with the specified message.
@@ -389,17 +467,10 @@ impl Context {
     where
         M: Into<Box<str>>,
     {
-        crate::builtins::error::UriError::constructor(
-            &self
-                .intrinsics()
-                .constructors()
-                .uri_error()
-                .constructor()
-                .into(),
-            &[message.into().into()],
-            self,
-        )
-        .expect("Into<String> used as message")
+        JsNativeError::uri()
+            .with_message(message)
+            .to_opaque(self)
+            .into()
     }
 
     /// Throws a `EvalError` with the specified message.
@@ -407,7 +478,7 @@ impl Context {
     where
         M: Into<Box<str>>,
     {
-        Err(self.construct_eval_error(message))
+        Err(JsNativeError::eval().with_message(message).into())
     }
 
     /// Throws a `URIError` with the specified message.
@@ -415,7 +486,7 @@ impl Context {
     where
         M: Into<Box<str>>,
     {
-        Err(self.construct_uri_error(message))
+        Err(JsNativeError::uri().with_message(message).into())
     }
 
     /// Register a global native function.
@@ -585,6 +656,33 @@ impl Context {
         Ok(())
     }
 
+    /// Register a namespace of native functions, classes and values as a global property.
+    ///
+    /// The namespace object is frozen, and the global property is not writable nor configurable,
+    /// so scripts cannot replace the exports of the namespace. See [`NamespaceBuilder`] for an
+    /// example.
+    ///
+    /// # Errors
+    ///
+    /// This returns the error thrown while initializing one of the exported classes.
+    pub fn register_global_namespace(
+        &mut self,
+        name: &str,
+        namespace: NamespaceBuilder,
+    ) -> JsResult<JsObject> {
+        let namespace = namespace.build(self)?;
+        self.global_bindings_mut().insert(
+            name.into(),
+            PropertyDescriptor::builder()
+                .value(namespace.clone())
+                .writable(false)
+                .enumerable(false)
+                .configurable(false)
+                .build(),
+        );
+        Ok(namespace)
+    }
+
     /// Register a global property.
     ///
     /// # Example
@@ -638,6 +736,46 @@ impl Context {
         );
     }
 
+    /// Register a global accessor property.
+    ///
+    /// The `writable` field of `attribute` is ignored, since accessor properties
+    /// cannot be writable.
+    ///
+    /// # Example
+    /// ```
+    /// use boa_engine::{object::FunctionBuilder, property::Attribute, Context};
+    ///
+    /// let mut context = Context::default();
+    ///
+    /// let getter = FunctionBuilder::native(&mut context, |_, _, _| Ok(42.into()))
+    ///     .name("get answer")
+    ///     .build();
+    ///
+    /// context.register_global_accessor("answer", Some(getter), None, Attribute::all());
+    ///
+    /// assert_eq!(context.eval("answer").unwrap().as_number(), Some(42.0));
+    /// ```
+    #[inline]
+    pub fn register_global_accessor<K>(
+        &mut self,
+        key: K,
+        get: Option<JsFunction>,
+        set: Option<JsFunction>,
+        attribute: Attribute,
+    ) where
+        K: Into<PropertyKey>,
+    {
+        self.realm.global_property_map.insert(
+            &key.into(),
+            PropertyDescriptor::builder()
+                .maybe_get(get)
+                .maybe_set(set)
+                .enumerable(attribute.enumerable())
+                .configurable(attribute.configurable())
+                .build(),
+        );
+    }
+
     /// Evaluates the given code by compiling down to bytecode, then interpreting the bytecode into a value
     ///
     /// # Examples
@@ -650,24 +788,92 @@ impl Context {
     /// assert!(value.is_number());
     /// assert_eq!(value.as_number().unwrap(), 4.0);
     /// ```
-    #[allow(clippy::unit_arg, clippy::drop_copy)]
+    #[inline]
     pub fn eval<S>(&mut self, src: S) -> JsResult<JsValue>
     where
         S: AsRef<[u8]>,
     {
-        let main_timer = Profiler::global().start_event("Evaluation", "Main");
+        self.eval_with_completion(src).into_result()
+    }
 
-        let parsing_result = Parser::new(src.as_ref())
-            .parse_all(self)
-            .map_err(|e| e.to_string());
+    /// Evaluates the given code like [`Self::eval()`], but returns its [`Completion`], which
+    /// tells apart code whose statements don't produce any value from code whose last value is
+    /// `undefined`.
+    ///
+    /// # Examples
+    /// ```
+    ///# use boa_engine::{context::Completion, Context, JsValue};
+    /// let mut context = Context::default();
+    ///
+    /// assert_eq!(
+    ///     context.eval_with_completion("let x = 3; x * 2;"),
+    ///     Completion::Normal(Some(JsValue::new(6)))
+    /// );
+    /// assert_eq!(context.eval_with_completion("let y = 3"), Completion::Normal(None));
+    /// ```
+    #[allow(clippy::unit_arg, clippy::drop_copy)]
+    pub fn eval_with_completion<S>(&mut self, src: S) -> Completion
+    where
+        S: AsRef<[u8]>,
+    {
+        let main_timer = Profiler::global().start_event("Evaluation", "Main");
 
-        let statement_list = match parsing_result {
-            Ok(statement_list) => statement_list,
-            Err(e) => return self.throw_syntax_error(e),
+        let result = match self.compile_source(src.as_ref()) {
+            Ok(code_block) => self.execute_with_completion(code_block),
+            Err(e) => Completion::Throw(e),
         };
 
-        let code_block = self.compile(&statement_list)?;
-        let result = self.execute(code_block);
+        // The main_timer needs to be dropped before the Profiler is.
+        drop(main_timer);
+        Profiler::global().drop();
+
+        result
+    }
+
+    /// Evaluates the given code like [`Self::eval()`], in a temporary scope that contains the
+    /// given bindings and whose outer scope is the global scope.
+    ///
+    /// The bindings shadow any global variable with the same name, and assignments to them only
+    /// change the temporary scope. Every declaration in the code, including `var` and function
+    /// declarations and the `var` declarations of direct `eval` calls, is also created in the
+    /// temporary scope, as if the code was the body of a function called with the bindings as
+    /// its parameters, so nothing declared by the code outlives the evaluation. For the same
+    /// reason, redeclaring a binding with `let`, `const` or `class` throws a `SyntaxError`.
+    /// Assignments to undeclared names still create global properties in sloppy mode, and throw
+    /// a `ReferenceError` in strict mode.
+    ///
+    /// # Examples
+    /// ```
+    ///# use boa_engine::{Context, JsValue};
+    /// let mut context = Context::default();
+    ///
+    /// let total = context
+    ///     .eval_with_bindings(
+    ///         "var discount = 0.5; price * qty * discount",
+    ///         &[("price", JsValue::new(4)), ("qty", JsValue::new(3))],
+    ///     )
+    ///     .unwrap();
+    ///
+    /// assert_eq!(total, JsValue::new(6));
+    /// assert_eq!(context.eval("typeof discount").unwrap(), "undefined".into());
+    /// ```
+    pub fn eval_with_bindings<S>(
+        &mut self,
+        src: S,
+        bindings: &[(&str, JsValue)],
+    ) -> JsResult<JsValue>
+    where
+        S: AsRef<[u8]>,
+    {
+        let main_timer = Profiler::global().start_event("Evaluation", "Main");
+
+        let result = match self.parse(src) {
+            Ok(statement_list) => self.execute_with_bindings(&statement_list, bindings),
+            Err(e) => {
+                let e = e.to_string();
+                self.throw_syntax_error(e)
+            }
+        };
 
         // The main_timer needs to be dropped before the Profiler is.
         drop(main_timer);
@@ -676,14 +882,178 @@ impl Context {
         result
     }
 
+    /// Compile and execute the statement list in a new scope with the given bindings on top of
+    /// the global scope.
+    fn execute_with_bindings(
+        &mut self,
+        statement_list: &StatementList,
+        bindings: &[(&str, JsValue)],
+    ) -> JsResult<JsValue> {
+        let names: Vec<_> = bindings
+            .iter()
+            .map(|(name, _)| self.interner_mut().get_or_intern(name))
+            .collect();
+
+        // Like the parameters of a function, the bindings cannot be redeclared by the lexical
+        // declarations of the code.
+        let lexically_declared_names = statement_list.lexically_declared_names_top_level();
+        if let Some(name) = names
+            .iter()
+            .find(|name| lexically_declared_names.contains(name))
+        {
+            let name = self.interner().resolve_expect(*name);
+            return self
+                .throw_syntax_error(format!("Identifier '{name}' has already been declared"));
+        }
+
+        // The code may be evaluated during the execution of other code, so the scope is created
+        // directly above the global environment.
+        let environments = self.realm.environments.pop_to_global();
+        let environments_len = self.realm.environments.len();
+        let compile_env = self.realm.compile_env.clone();
+        self.realm.compile_env = self.realm.environments.current_compile_environment();
+
+        // The scope is a function scope at compile time, so that it receives the `var`
+        // declarations of the code.
+        self.push_compile_time_environment(true);
+        for name in &names {
+            self.realm
+                .compile_env
+                .borrow_mut()
+                .create_mutable_binding(*name, true);
+        }
+
+        let timer = Profiler::global().start_event("Compilation", "Main");
+        let strict = statement_list.strict() || self.strict;
+        let mut compiler = ByteCompiler::new(Sym::MAIN, strict, self);
+        let result = compiler
+            .create_declarations(statement_list.items())
+            .and_then(|()| compiler.compile_statement_list(statement_list.items(), true))
+            .map(|()| Gc::new(compiler.finish()));
+        drop(timer);
+
+        let result = result.and_then(|code_block| {
+            let (num_bindings, scope) = self.pop_compile_time_environment();
+            self.realm
+                .environments
+                .push_declarative(num_bindings, scope.clone());
+            for (name, (_, value)) in names.into_iter().zip(bindings) {
+                let binding = scope
+                    .borrow()
+                    .get_binding(name)
+                    .expect("binding must have been created before compiling the code");
+                self.realm.environments.put_value(
+                    binding.environment_index(),
+                    binding.binding_index(),
+                    value.clone(),
+                );
+            }
+            self.execute(code_block)
+        });
+
+        // Restore the environments from before the evaluation.
+        self.realm.compile_env = compile_env;
+        self.realm.environments.truncate(environments_len);
+        self.realm.environments.extend(environments);
+
+        result
+    }
+
     /// Compile the AST into a `CodeBlock` ready to be executed by the VM.
+    ///
+    /// The declarations of the script are instantiated in the global environment of the context,
+    /// where they persist for the code compiled after it. Compiling a script that redeclares a
+    /// lexical name of a previous script, or that declares a lexical name that is already bound
+    /// by a `var` or function declaration, fails with a `SyntaxError`. If the compilation fails,
+    /// none of the declarations of the script are instantiated.
     #[inline]
     pub fn compile(&mut self, statement_list: &StatementList) -> JsResult<Gc<CodeBlock>> {
+        let _active = self.enter();
+        let strict = statement_list.strict() || self.strict;
+        let declarations = GlobalDeclarations::new(statement_list, strict);
+        self.compile_script(statement_list, strict, &declarations)
+    }
+
+    /// Parses and compiles the given source, or gets its compiled code from the eval cache.
+    fn compile_source(&mut self, src: &[u8]) -> JsResult<Gc<CodeBlock>> {
+        let _active = self.enter();
+        let cached = self.eval_cache.accepts(src);
+        if cached {
+            let global_bindings = self.global_lexical_binding_count();
+            if let Some((code_block, declarations)) =
+                self.eval_cache.get(src, self.strict, global_bindings)
+            {
+                let var_names = self.check_global_declarations(&declarations)?;
+                self.realm.global_var_names.extend(var_names);
+                return Ok(code_block);
+            }
+        }
+
+        let statement_list = self.parse(src)?;
+        let strict = statement_list.strict() || self.strict;
+        let declarations = GlobalDeclarations::new(&statement_list, strict);
+        let code_block = self.compile_script(&statement_list, strict, &declarations)?;
+
+        if cached {
+            let global_bindings = self.global_lexical_binding_count();
+            self.eval_cache.insert(
+                src,
+                self.strict,
+                code_block.clone(),
+                Rc::new(declarations),
+                global_bindings,
+            );
+        }
+        Ok(code_block)
+    }
+
+    /// Gets the number of lexical bindings of the global environment.
+    fn global_lexical_binding_count(&self) -> usize {
+        self.realm
+            .environments
+            .global_compile_environment()
+            .borrow()
+            .num_bindings()
+    }
+
+    /// Compiles a script whose declarations have already been collected.
+    fn compile_script(
+        &mut self,
+        statement_list: &StatementList,
+        strict: bool,
+        declarations: &GlobalDeclarations,
+    ) -> JsResult<Gc<CodeBlock>> {
         let _timer = Profiler::global().start_event("Compilation", "Main");
-        let mut compiler = ByteCompiler::new(Sym::MAIN, statement_list.strict(), self);
-        compiler.create_declarations(statement_list.items())?;
-        compiler.compile_statement_list(statement_list.items(), true)?;
-        Ok(Gc::new(compiler.finish()))
+        let var_names = self.check_global_declarations(declarations)?;
+
+        let compile_env = self.realm.compile_env.clone();
+        let num_bindings = compile_env.borrow().num_bindings();
+        let num_global_properties = self.realm.global_property_map.string_property_map().len();
+        let mut compiler = ByteCompiler::new(Sym::MAIN, strict, self);
+        let result = compiler
+            .create_declarations(statement_list.items())
+            .and_then(|()| compiler.compile_statement_list(statement_list.items(), true));
+        match result {
+            Ok(()) => {
+                let code_block = Gc::new(compiler.finish());
+                self.realm.global_var_names.extend(var_names);
+                Ok(code_block)
+            }
+            Err(e) => {
+                // An early error can be thrown with nested compile time environments pushed.
+                self.realm.compile_env = compile_env;
+
+                // The declarations are created before the statements are compiled, so the
+                // bindings and global properties that were added for them have to be removed.
+                // Properties of the global object are only added while compiling, so the new
+                // ones are the last ones of the map.
+                self.realm.compile_env.borrow_mut().truncate(num_bindings);
+                self.realm
+                    .global_bindings_mut()
+                    .truncate(num_global_properties);
+                Err(e)
+            }
+        }
     }
 
     /// Compile the AST into a `CodeBlock` with an additional declarative environment.
@@ -694,13 +1064,21 @@ impl Context {
         strict: bool,
     ) -> JsResult<Gc<CodeBlock>> {
         let _timer = Profiler::global().start_event("Compilation", "Main");
-        let mut compiler = ByteCompiler::new(Sym::MAIN, statement_list.strict(), self);
-        compiler.compile_statement_list_with_new_declarative(
+        let strict = strict || statement_list.strict();
+        let compile_env = self.realm.compile_env.clone();
+        let mut compiler = ByteCompiler::new(Sym::MAIN, strict, self);
+        let result = compiler.compile_statement_list_with_new_declarative(
             statement_list.items(),
             true,
-            strict || statement_list.strict(),
-        )?;
-        Ok(Gc::new(compiler.finish()))
+            strict,
+        );
+        match result {
+            Ok(()) => Ok(Gc::new(compiler.finish())),
+            Err(e) => {
+                self.realm.compile_env = compile_env;
+                Err(e)
+            }
+        }
     }
 
     /// Call the VM with a `CodeBlock` and return the result.
@@ -711,8 +1089,18 @@ impl Context {
     /// `Gc<CodeBlock>` returned by the [`Self::compile()`] function.
     #[inline]
     pub fn execute(&mut self, code_block: Gc<CodeBlock>) -> JsResult<JsValue> {
+        self.execute_with_completion(code_block).into_result()
+    }
+
+    /// Call the VM with a `CodeBlock` like [`Self::execute()`], but return its [`Completion`].
+    pub fn execute_with_completion(&mut self, code_block: Gc<CodeBlock>) -> Completion {
         let _timer = Profiler::global().start_event("Execution", "Main");
 
+        // The instructions are counted from the start of the outermost execution.
+        if self.vm.frames.is_empty() {
+            self.vm.instruction_count = 0;
+        }
+
         self.vm.push_frame(CallFrame {
             code: code_block,
             pc: 0,
@@ -730,35 +1118,235 @@ impl Context {
             generator_resume_kind: GeneratorResumeKind::Normal,
             thrown: false,
             async_generator: None,
+            promise_capability: None,
+            completion_value: None,
         });
 
         self.realm.set_global_binding_number();
+        let environments_len = self.realm.environments.len();
         let result = self.run();
+        let completion_value = self.vm.frame_mut().completion_value.take();
         self.vm.pop_frame();
-        self.run_queued_jobs()?;
-        let (result, _) = result?;
-        Ok(result)
+
+        // The environments pushed by the code are not popped if it throws, so they have to be
+        // removed before running any other code.
+        if result.is_err() {
+            self.realm.environments.truncate(environments_len);
+        }
+        if let Err(e) = self.run_jobs() {
+            return Completion::Throw(e);
+        }
+        result.map(|_| completion_value).into()
     }
 
-    /// Runs all the jobs in the job queue.
-    fn run_queued_jobs(&mut self) -> JsResult<()> {
+    /// Runs all the jobs in the job queue, such as the reactions of settled promises.
+    ///
+    /// The queue is drained at the end of every evaluation, but embedders that call functions
+    /// of the script directly have to drain it themselves, so that the promises settled by these
+    /// functions run their reactions.
+    #[inline]
+    pub fn run_jobs(&mut self) -> JsResult<()> {
         while let Some(job) = self.promise_job_queue.pop_front() {
-            job.call_job_callback(&JsValue::Undefined, &[], self)?;
+            job.callback().__call__(&JsValue::Undefined, &[], self)?;
         }
         Ok(())
     }
 
+    /// Returns the [`HostHooks`] called by the context.
+    #[inline]
+    pub fn host_hooks(&self) -> Rc<dyn HostHooks> {
+        self.host_hooks.clone()
+    }
+
     /// Return the intrinsic constructors and objects.
+    ///
+    /// Embedders should use these instead of looking up the builtins in the global object,
+    /// since scripts can replace or remove the properties of the global object.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use boa_engine::{object::JsObject, Context};
+    ///
+    /// let mut context = Context::default();
+    /// context.eval("delete globalThis.Array").unwrap();
+    ///
+    /// let array_prototype = context.intrinsics().constructors().array().prototype();
+    /// let array = context.eval("[]").unwrap();
+    /// let prototype = array.as_object().unwrap().prototype().clone().unwrap();
+    /// assert!(JsObject::equals(&prototype, &array_prototype));
+    /// ```
     #[inline]
     pub fn intrinsics(&self) -> &Intrinsics {
         &self.intrinsics
     }
 
+    /// Registers the constructor and prototype of a native class in the current realm.
+    pub(crate) fn register_class(&mut self, type_id: TypeId, class: StandardConstructor) {
+        self.intrinsics.classes.insert(type_id, class);
+    }
+
     /// Set the value of trace on the context
     pub fn set_trace(&mut self, trace: bool) {
         self.vm.trace = trace;
     }
 
+    /// Enables or disables the recording of profiler events.
+    ///
+    /// The profiler is shared by all the contexts of the process, and the recorded events can be
+    /// retrieved with [`Profiler::report`] and [`Profiler::write_chrome_trace`]. This allows
+    /// profiling a single evaluation in a long-running process.
+    #[inline]
+    pub fn set_profiling(&mut self, enabled: bool) {
+        Profiler::global().set_enabled(enabled);
+    }
+
+    /// Measures the memory used by the context.
+    ///
+    /// Objects, strings, code blocks and array buffers are accounted to the context that was
+    /// running when they were allocated. Values allocated through the Rust API while no context
+    /// is running are accounted to the context that ran last in the current thread.
+    ///
+    /// Memory that is no longer reachable is only accounted as freed after it's collected.
+    #[inline]
+    pub fn memory_usage(&self) -> MemoryUsage {
+        self.memory.usage(self.interner.allocated_bytes())
+    }
+
+    /// Accounts the memory allocated until the returned guard is dropped to this context.
+    #[inline]
+    pub(crate) fn enter(&self) -> ActiveContext {
+        self.memory.enter()
+    }
+
+    /// Gets the resource limits of the context.
+    #[inline]
+    pub fn resource_limits(&self) -> &ResourceLimits {
+        &self.limits
+    }
+
+    /// Gets a mutable reference to the resource limits of the context.
+    #[inline]
+    pub fn resource_limits_mut(&mut self) -> &mut ResourceLimits {
+        &mut self.limits
+    }
+
+    /// Configures the cache of the code compiled by [`Self::eval()`] and
+    /// [`Self::eval_with_completion()`], removing all the cached snippets.
+    ///
+    /// A cached snippet is only parsed and compiled the first time it is evaluated. Every
+    /// evaluation still executes the code from the start, so it creates new functions and
+    /// scopes, and the declarations of the snippet are checked against the global scope as if
+    /// it was compiled again. The code is compiled again if a global lexical declaration was
+    /// added since it was cached, and whether the context is strict is part of the key of a
+    /// snippet.
+    ///
+    /// See [`EvalCacheConfig`] for an example.
+    #[inline]
+    pub fn set_eval_cache(&mut self, config: EvalCacheConfig) {
+        self.eval_cache.set_config(config);
+    }
+
+    /// Removes all the snippets in the cache of compiled code, keeping its configuration.
+    #[inline]
+    pub fn clear_eval_cache(&mut self) {
+        self.eval_cache.clear();
+    }
+
+    /// Returns `true` if all code evaluated by the context is treated as strict mode code.
+    #[inline]
+    pub fn strict(&self) -> bool {
+        self.strict
+    }
+
+    /// Sets whether all code evaluated by the context is treated as strict mode code.
+    ///
+    /// When enabled, every script passed to [`Context::eval`] or [`Context::parse`], every
+    /// `eval` call and every function body created with the `Function` constructor is parsed
+    /// and executed as if it began with a `"use strict"` directive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use boa_engine::Context;
+    ///
+    /// let mut context = Context::default();
+    /// context.set_strict(true);
+    ///
+    /// assert!(context.eval("undeclared = 1").is_err());
+    /// ```
+    #[inline]
+    pub fn set_strict(&mut self, strict: bool) {
+        self.strict = strict;
+    }
+
+    /// Replaces the clock used by `Date.now`, `Date()` and `new Date()`.
+    ///
+    /// The clock must return the current time in milliseconds since the Unix epoch.
+    /// By default, the system clock is used, unless it was removed with [`Intrinsic::DATE_NOW`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use boa_engine::Context;
+    ///
+    /// let mut context = Context::default();
+    /// context.set_clock(|| 86_400_000.0);
+    ///
+    /// let now = context.eval("Date.now()").unwrap();
+    /// assert_eq!(now.as_number(), Some(86_400_000.0));
+    /// ```
+    #[inline]
+    pub fn set_clock<F>(&mut self, clock: F)
+    where
+        F: Fn() -> f64 + 'static,
+    {
+        self.host_sources.clock = Some(Box::new(clock));
+    }
+
+    /// Replaces the source of randomness used by `Math.random`.
+    ///
+    /// The source must return numbers in the range `[0, 1)`.
+    /// By default, a thread-local random number generator is used.
+    #[inline]
+    pub fn set_random_source<F>(&mut self, source: F)
+    where
+        F: FnMut() -> f64 + 'static,
+    {
+        self.host_sources.random = Some(Box::new(source));
+    }
+
+    /// Checks if the given builtins are available to scripts, i.e. if they weren't removed with
+    /// [`ContextBuilder::without`].
+    #[inline]
+    pub(crate) fn has_intrinsic(&self, intrinsic: Intrinsic) -> bool {
+        !self.removed_intrinsics.intersects(intrinsic)
+    }
+
+    /// Gets the current time, in milliseconds since the Unix epoch.
+    ///
+    /// Returns `NaN` if the system clock was removed with [`Intrinsic::DATE_NOW`] and the host
+    /// didn't provide a clock.
+    #[inline]
+    pub(crate) fn current_time_millis(&self) -> f64 {
+        match &self.host_sources.clock {
+            Some(clock) => clock(),
+            None if self.has_intrinsic(Intrinsic::DATE_NOW) => {
+                chrono::Utc::now().timestamp_millis() as f64
+            }
+            None => f64::NAN,
+        }
+    }
+
+    /// Gets a random number in the range `[0, 1)`.
+    #[inline]
+    pub(crate) fn random(&mut self) -> f64 {
+        self.host_sources
+            .random
+            .as_mut()
+            .map_or_else(rand::random::<f64>, |random| random())
+    }
+
     #[cfg(feature = "intl")]
     #[inline]
     /// Get the ICU related utilities
@@ -795,6 +1383,12 @@ pub struct ContextBuilder {
     interner: Option<Interner>,
     #[cfg(feature = "intl")]
     icu: Option<icu::Icu>,
+    limits: ResourceLimits,
+    without: Intrinsic,
+    strict: bool,
+    recursion_limit: Option<usize>,
+    memory_measurement: bool,
+    host_hooks: Option<Rc<dyn HostHooks>>,
 }
 
 impl ContextBuilder {
@@ -808,9 +1402,75 @@ impl ContextBuilder {
         self
     }
 
+    /// Sets the [`ResourceLimits`] enforced by the [`Context`].
+    ///
+    /// By default, all limits are set to the maximum values allowed by the specification.
+    #[must_use]
+    pub fn resource_limits(mut self, limits: ResourceLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Makes the [`Context`] treat all evaluated code as strict mode code.
+    ///
+    /// See [`Context::set_strict`] for more information.
+    #[must_use]
+    pub fn strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// Sets the maximum nesting depth of the code parsed by the [`Context`].
+    ///
+    /// See [`Parser::set_recursion_limit`] for more information.
+    #[must_use]
+    pub fn recursion_limit(mut self, limit: usize) -> Self {
+        self.recursion_limit = Some(limit);
+        self
+    }
+
+    /// Sets the [`HostHooks`] called by the [`Context`].
+    ///
+    /// By default, the [`Context`] uses hooks that implement the default behaviour described by
+    /// the specification.
+    #[must_use]
+    pub fn host_hooks<H: HostHooks + 'static>(mut self, hooks: H) -> Self {
+        self.host_hooks = Some(Rc::new(hooks));
+        self
+    }
+
+    /// Adds the global `performance.measureUserAgentSpecificMemory` function to the [`Context`].
+    ///
+    /// The function returns a promise that resolves to the [`Context::memory_usage`] of the
+    /// context, broken down by the kind of memory.
+    #[must_use]
+    pub fn memory_measurement(mut self, enabled: bool) -> Self {
+        self.memory_measurement = enabled;
+        self
+    }
+
+    /// Removes the given builtins from the global object of the [`Context`].
+    ///
+    /// The engine does not depend on the removed builtins being reachable from the global
+    /// object, so scripts can still use every other builtin.
+    #[must_use]
+    pub fn without(mut self, intrinsics: Intrinsic) -> Self {
+        self.without |= intrinsics;
+        self
+    }
+
     /// Provides an icu data provider to the [`Context`].
     ///
     /// This function is only available if the `intl` feature is enabled.
+    ///
+    /// If no provider is set, the [`Context`] uses the ICU test data compiled into the engine.
+    /// Custom providers can be used to reduce the data shipped with an application, since only
+    /// the locales supported by the provider need to be included.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the provider does not contain the data required to initialize
+    /// the locale canonicalizer.
     #[cfg(any(feature = "intl", docs))]
     pub fn icu_provider(mut self, provider: Box<dyn icu::BoaProvider>) -> Result<Self, DataError> {
         self.icu = Some(icu::Icu::new(provider)?);
@@ -826,6 +1486,10 @@ impl ContextBuilder {
     /// Builds a new [`Context`] with the provided parameters, and defaults
     /// all missing parameters to their default values.
     pub fn build(self) -> Context {
+        // The builtins of the context are accounted to it.
+        let memory = Rc::new(MemoryCounters::default());
+        let _active = memory.enter();
+
         let mut context = Context {
             realm: Realm::create(),
             interner: self.interner.unwrap_or_default(),
@@ -837,6 +1501,7 @@ impl ContextBuilder {
                 stack: Vec::with_capacity(1024),
                 trace: false,
                 stack_size_limit: 1024,
+                instruction_count: 0,
             },
             #[cfg(feature = "intl")]
             icu: self.icu.unwrap_or_else(|| {
@@ -845,6 +1510,14 @@ impl ContextBuilder {
                     .expect("Failed to initialize default icu data.")
             }),
             promise_job_queue: VecDeque::new(),
+            limits: ResourceLimits::default(),
+            host_sources: HostSources::default(),
+            removed_intrinsics: Intrinsic::empty(),
+            strict: false,
+            recursion_limit: self.recursion_limit.unwrap_or(DEFAULT_RECURSION_LIMIT),
+            host_hooks: self.host_hooks.unwrap_or_else(|| Rc::new(DefaultHooks)),
+            eval_cache: EvalCache::default(),
+            memory,
         };
 
         // Add new builtIns to Context Realm
@@ -852,6 +1525,15 @@ impl ContextBuilder {
         // but for now we almost always want these default builtins
         context.intrinsics.objects = IntrinsicObjects::init(&mut context);
         context.create_intrinsics();
+        context.remove_intrinsics(self.without);
+        if self.memory_measurement {
+            builtins::init_builtin::<Performance>(&mut context);
+        }
+
+        // The limits are only applied after the builtins are initialized, since those should
+        // never fail to be created.
+        context.limits = self.limits;
+        context.strict = self.strict;
         context
     }
 }
@@ -0,0 +1,168 @@
+//! Namespaces of native functions and values provided by the host.
+//!
+//! A namespace is a frozen object, like the namespace objects of ECMAScript modules, so scripts
+//! cannot replace the functions of the host that it contains.
+
+use crate::{
+    builtins::function::NativeFunctionSignature,
+    class::{Class, ClassBuilder},
+    object::{FunctionBuilder, JsObject, ObjectData},
+    property::PropertyDescriptor,
+    symbol::WellKnownSymbols,
+    Context, JsResult, JsString, JsValue,
+};
+use std::fmt;
+
+/// An export of a namespace, which is only created once the namespace is built.
+enum Export {
+    Function {
+        body: NativeFunctionSignature,
+        length: usize,
+    },
+    Value(JsValue),
+    Class(fn(&mut Context) -> JsResult<JsValue>),
+    Namespace(NamespaceBuilder),
+}
+
+/// Builder of a namespace object, whose exports are native functions, classes and values.
+///
+/// The namespace object is created with the semantics of a module namespace object: it has a
+/// `null` prototype, its exports are sorted by name, it has a `Symbol.toStringTag` property with
+/// the value `"Module"`, and it is frozen.
+///
+/// # Examples
+///
+/// ```
+/// use boa_engine::{context::NamespaceBuilder, Context, JsValue};
+///
+/// let mut context = Context::default();
+///
+/// let fs = NamespaceBuilder::new()
+///     .function("readFile", 1, |_, _, _| Ok(JsValue::from("contents")));
+/// let host = NamespaceBuilder::new()
+///     .value("version", 1)
+///     .namespace("fs", fs);
+/// context.register_global_namespace("host", host).unwrap();
+///
+/// let value = context.eval("host.fs.readFile('file.txt')").unwrap();
+/// assert_eq!(value.as_string().unwrap(), "contents");
+/// ```
+#[derive(Default)]
+pub struct NamespaceBuilder {
+    exports: Vec<(JsString, Export)>,
+}
+
+impl NamespaceBuilder {
+    /// Creates a new, empty namespace builder.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Exports a native function, which is not a constructor.
+    #[must_use]
+    pub fn function<N>(self, name: N, length: usize, body: NativeFunctionSignature) -> Self
+    where
+        N: Into<JsString>,
+    {
+        self.export(name.into(), Export::Function { body, length })
+    }
+
+    /// Exports a value.
+    #[must_use]
+    pub fn value<N, V>(self, name: N, value: V) -> Self
+    where
+        N: Into<JsString>,
+        V: Into<JsValue>,
+    {
+        self.export(name.into(), Export::Value(value.into()))
+    }
+
+    /// Exports the class `T`, with its name as the name of the export.
+    #[must_use]
+    pub fn class<T: Class>(self) -> Self {
+        self.export(T::NAME.into(), Export::Class(build_class::<T>))
+    }
+
+    /// Exports a nested namespace.
+    #[must_use]
+    pub fn namespace<N>(self, name: N, namespace: Self) -> Self
+    where
+        N: Into<JsString>,
+    {
+        self.export(name.into(), Export::Namespace(namespace))
+    }
+
+    /// Adds an export, replacing the previous export with the same name.
+    fn export(mut self, name: JsString, export: Export) -> Self {
+        self.exports.retain(|(export_name, _)| export_name != &name);
+        self.exports.push((name, export));
+        self
+    }
+
+    /// Creates the namespace object.
+    ///
+    /// # Errors
+    ///
+    /// This returns the error thrown while initializing one of the exported classes.
+    pub fn build(self, context: &mut Context) -> JsResult<JsObject> {
+        let mut exports = self.exports;
+        exports.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+        let namespace = JsObject::from_proto_and_data(None, ObjectData::ordinary());
+        for (name, export) in exports {
+            let value = match export {
+                Export::Function { body, length } => FunctionBuilder::native(context, body)
+                    .name(&name)
+                    .length(length)
+                    .constructor(false)
+                    .build()
+                    .into(),
+                Export::Value(value) => value,
+                Export::Class(build) => build(context)?,
+                Export::Namespace(namespace) => namespace.build(context)?.into(),
+            };
+            namespace.insert_property(
+                name,
+                PropertyDescriptor::builder()
+                    .value(value)
+                    .writable(false)
+                    .enumerable(true)
+                    .configurable(false),
+            );
+        }
+        namespace.insert_property(
+            WellKnownSymbols::to_string_tag(),
+            PropertyDescriptor::builder()
+                .value("Module")
+                .writable(false)
+                .enumerable(false)
+                .configurable(false),
+        );
+        namespace.__prevent_extensions__(context)?;
+
+        Ok(namespace)
+    }
+}
+
+impl fmt::Debug for NamespaceBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NamespaceBuilder")
+            .field(
+                "exports",
+                &self
+                    .exports
+                    .iter()
+                    .map(|(name, _)| name)
+                    .collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+/// Creates the constructor of the class `T`.
+fn build_class<T: Class>(context: &mut Context) -> JsResult<JsValue> {
+    let mut class_builder = ClassBuilder::new::<T>(context);
+    T::init(&mut class_builder)?;
+    Ok(class_builder.build().into())
+}
@@ -0,0 +1,60 @@
+//! Facilities to sandbox the scripts run by a [`Context`][crate::Context].
+//!
+//! Embedders can remove builtins that give scripts access to code generation or to the ambient
+//! environment, and can replace the ambient clock and entropy source with host-controlled ones.
+
+use bitflags::bitflags;
+use std::fmt;
+
+bitflags! {
+    /// Builtins that can be removed from a [`Context`][crate::Context] using
+    /// [`ContextBuilder::without`][crate::context::ContextBuilder::without].
+    ///
+    /// Removed builtins are not present in the global object, so scripts observe them as
+    /// `undefined`. Removing a builtin also disables the capability that it gives access to,
+    /// even when it is reachable through other objects.
+    #[derive(Default)]
+    pub struct Intrinsic: u8 {
+        /// The global `eval` function.
+        const EVAL = 0b0000_0001;
+
+        /// The global `Function` constructor.
+        ///
+        /// Creating functions from strings throws an `EvalError`, including through the
+        /// `constructor` of any function, generator function, async function or async
+        /// generator function.
+        const FUNCTION = 0b0000_0010;
+
+        /// The global `Date` constructor.
+        const DATE = 0b0000_0100;
+
+        /// The `Date.now` function.
+        ///
+        /// The system clock is not read anymore, so `Date()` and `new Date()` return an invalid
+        /// date, unless the host provides a clock with
+        /// [`Context::set_clock`][crate::Context::set_clock].
+        const DATE_NOW = 0b0000_1000;
+
+        /// The `Math.random` function.
+        const MATH_RANDOM = 0b0001_0000;
+    }
+}
+
+/// Host-provided sources of time and randomness.
+#[derive(Default)]
+pub(crate) struct HostSources {
+    /// Returns the current time, in milliseconds since the Unix epoch.
+    pub(crate) clock: Option<Box<dyn Fn() -> f64>>,
+
+    /// Returns a random number in the range `[0, 1)`.
+    pub(crate) random: Option<Box<dyn FnMut() -> f64>>,
+}
+
+impl fmt::Debug for HostSources {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HostSources")
+            .field("clock", &self.clock.is_some())
+            .field("random", &self.random.is_some())
+            .finish()
+    }
+}
@@ -0,0 +1,1548 @@
+use crate::{
+    builtins::JsArgs,
+    class::{Class, ClassBuilder},
+    context::{
+        CompiledScript, Completion, EvalCacheConfig, HostHooks, Intrinsic, NamespaceBuilder,
+        OperationType, ResourceLimits,
+    },
+    forward,
+    job::JobCallback,
+    object::{FunctionBuilder, JsObject, JsProxy},
+    property::{Attribute, PropertyDescriptor},
+    Context, JsError, JsResult, JsValue,
+};
+use boa_gc::{Finalize, Trace};
+use boa_profiler::Profiler;
+use std::{
+    cell::{Cell, RefCell},
+    mem::size_of,
+    rc::Rc,
+};
+
+fn context_with_limits(limits: ResourceLimits) -> Context {
+    Context::builder().resource_limits(limits).build()
+}
+
+#[test]
+fn default_limits_are_unlimited() {
+    let mut context = Context::default();
+    assert_eq!(forward(&mut context, "'x'.repeat(1000).length"), "1000");
+    assert_eq!(
+        forward(&mut context, "new Array(1000).fill(0).length"),
+        "1000"
+    );
+    assert_eq!(
+        forward(&mut context, "new ArrayBuffer(1000).byteLength"),
+        "1000"
+    );
+}
+
+#[test]
+fn max_string_length() {
+    let mut limits = ResourceLimits::default();
+    limits.set_max_string_length(16);
+    let mut context = context_with_limits(limits);
+
+    assert_eq!(forward(&mut context, "'x'.repeat(16).length"), "16");
+
+    let scenarios = [
+        "'x'.repeat(17)",
+        "'x'.padStart(17)",
+        "'x'.padEnd(17, 'y')",
+        "new Array(17).fill('a').join('')",
+        "['0123456789', '0123456789'].join()",
+        "JSON.parse('\"01234567890123456\"')",
+        "'0123456789' + '0123456789'",
+        "var s = 'x'; for (var i = 0; i < 5; i++) { s += s; }",
+        "`${'0123456789'}${'0123456789'}`",
+        "'0123456789'.concat('0123456789')",
+        "'0123456789'.replace('0', '0123456789')",
+        "'0123456789'.replaceAll('0', '0123456789')",
+        "'0123456789'.replace('0', '$&$&$&$&$&$&$&$&')",
+        "'0123456789'.replace(/0/g, '0123456789')",
+        "'ßßßßßßßßßß'.toUpperCase()",
+        "String.fromCharCode(...new Array(17).fill(48))",
+        "String.raw({ raw: ['0123456789', '0123456789'] }, 0)",
+        "JSON.stringify('0123456789abcdefgh')",
+        "JSON.stringify(['0123456789', '0123456789'])",
+        "new Uint8Array(10).join()",
+    ];
+    for scenario in scenarios {
+        let scenario = format!("try {{ {scenario} }} catch (e) {{ e instanceof RangeError }}");
+        assert_eq!(forward(&mut context, &scenario), "true");
+    }
+}
+
+#[test]
+fn max_array_length() {
+    let mut limits = ResourceLimits::default();
+    limits.set_max_array_length(64);
+    let mut context = context_with_limits(limits);
+
+    assert_eq!(forward(&mut context, "new Array(64).fill(0).length"), "64");
+
+    let scenarios = [
+        "new Array(65)",
+        "new Array(2 ** 32 - 1)",
+        "[].fill.call({ length: 2 ** 32 - 1 }, 0)",
+        "JSON.parse(JSON.stringify(new Array(65).fill(0)))",
+    ];
+    for scenario in scenarios {
+        let scenario = format!("try {{ {scenario} }} catch (e) {{ e instanceof RangeError }}");
+        assert_eq!(forward(&mut context, &scenario), "true");
+    }
+
+    // The data of array buffers is limited by its size in bytes instead.
+    assert_eq!(forward(&mut context, "new Float64Array(65).length"), "65");
+}
+
+#[test]
+fn max_array_buffer_byte_length() {
+    let mut limits = ResourceLimits::default();
+    limits.set_max_array_buffer_byte_length(64);
+    let mut context = context_with_limits(limits);
+
+    assert_eq!(
+        forward(&mut context, "new ArrayBuffer(64).byteLength"),
+        "64"
+    );
+    assert_eq!(
+        forward(&mut context, "new Float64Array(8).byteLength"),
+        "64"
+    );
+    assert_eq!(forward(&mut context, "new Array(65).length"), "65");
+
+    let scenarios = [
+        "new ArrayBuffer(65)",
+        "new Uint8Array(65)",
+        "new Float64Array(9)",
+    ];
+    for scenario in scenarios {
+        let scenario = format!("try {{ {scenario} }} catch (e) {{ e instanceof RangeError }}");
+        assert_eq!(forward(&mut context, &scenario), "true");
+    }
+}
+
+#[test]
+fn max_property_count() {
+    let mut limits = ResourceLimits::default();
+    limits.set_max_property_count(4);
+    let mut context = context_with_limits(limits);
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.keys({ a: 1, b: 2, c: 3, d: 4 }).length"
+        ),
+        "4"
+    );
+
+    let scenarios = [
+        "var o = {}; for (var i = 0; i < 5; i++) { o['p' + i] = i; }",
+        "({ a: 1, b: 2, c: 3, d: 4, e: 5 })",
+        "Object.defineProperty({ a: 1, b: 2, c: 3, d: 4 }, 'e', { value: 5 })",
+        "JSON.parse('{\"a\":1,\"b\":2,\"c\":3,\"d\":4,\"e\":5}')",
+    ];
+    for scenario in scenarios {
+        let scenario = format!("try {{ {scenario} }} catch (e) {{ e instanceof RangeError }}");
+        assert_eq!(forward(&mut context, &scenario), "true");
+    }
+
+    // Redefining existing properties doesn't count against the limit.
+    assert_eq!(
+        forward(
+            &mut context,
+            "var o = { a: 1, b: 2, c: 3, d: 4 }; o.a = 5; o.a"
+        ),
+        "5"
+    );
+}
+
+#[test]
+fn max_property_count_in_builtins() {
+    let mut limits = ResourceLimits::default();
+    limits.set_max_property_count(2);
+    let mut context = context_with_limits(limits);
+
+    // Builtins that create objects with more properties than the limit throw, instead of
+    // assuming that defining the properties of a new object cannot fail.
+    let scenarios = [
+        "/(a)(b)(c)/.exec('abc')",
+        "'a,b,c'.split(',')",
+        "JSON.parse('[1, 2, 3]')",
+        "Object.getOwnPropertyDescriptors({ a: 1, b: 2, c: 3 })",
+        "Object.keys('abcdefghijklmnop')",
+        "Reflect.ownKeys(new String('abc'))",
+        "Array.prototype.toReversed.call({ length: 20 })",
+        "Array.prototype.toSorted.call({ length: 20 })",
+        "Array.prototype.toSpliced.call({ length: 20 }, 1, 1)",
+        "Array.prototype.with.call({ length: 20 }, 1, 1)",
+    ];
+    for scenario in scenarios {
+        let scenario = format!("try {{ {scenario} }} catch (e) {{ e instanceof RangeError }}");
+        assert_eq!(forward(&mut context, &scenario), "true");
+    }
+
+    // The `groups` property is the last one defined on the result of `exec`.
+    let mut limits = ResourceLimits::default();
+    limits.set_max_property_count(4);
+    let mut context = context_with_limits(limits);
+    for scenario in ["/(?<a>x)/.exec('x')", "/(?<a>x)/d.exec('x')"] {
+        let scenario = format!("try {{ {scenario} }} catch (e) {{ e instanceof RangeError }}");
+        assert_eq!(forward(&mut context, &scenario), "true");
+    }
+}
+
+#[test]
+fn max_object_nesting() {
+    let mut limits = ResourceLimits::default();
+    limits.set_max_object_nesting(4);
+    let mut context = context_with_limits(limits);
+
+    assert_eq!(
+        forward(&mut context, "JSON.stringify(JSON.parse('[[[[1]]]]'))"),
+        "\"[[[[1]]]]\""
+    );
+    assert_eq!(
+        forward(&mut context, "[[[[1]]]].flat(Infinity).length"),
+        "1"
+    );
+
+    let scenarios = [
+        "JSON.parse('[[[[[1]]]]]')",
+        "JSON.parse('{\"a\":{\"b\":{\"c\":{\"d\":{}}}}}')",
+        "JSON.parse('[1, 2]', function (k, v) { if (k === '0') { this[1] = [[[[1]]]]; } return v; })",
+        "JSON.stringify([[[[[1]]]]])",
+        "JSON.stringify({ a: { b: { c: { d: {} } } } })",
+        "var a = [1]; for (var i = 0; i < 100; i++) { a = [a]; } JSON.stringify(a)",
+        "[[[[[1]]]]].flat(Infinity)",
+    ];
+    for scenario in scenarios {
+        let scenario = format!("try {{ {scenario} }} catch (e) {{ e instanceof RangeError }}");
+        assert_eq!(forward(&mut context, &scenario), "true");
+    }
+}
+
+#[test]
+fn max_property_count_in_fixed_objects() {
+    #[allow(clippy::unnecessary_wraps)]
+    fn trap(_: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        Ok(JsValue::new(1))
+    }
+
+    let mut limits = ResourceLimits::default();
+    limits.set_max_property_count(3);
+    let mut context = context_with_limits(limits);
+
+    // The objects that the engine creates with a fixed set of properties are checked as well.
+    let scenarios = [
+        "(function (a) { return arguments; })(1)",
+        "(function () { 'use strict'; return arguments; })(1)",
+        "(function* (a) { arguments; })(1)",
+        "Object.getOwnPropertyDescriptor({ a: 1 }, 'a')",
+        r#"
+            Object.defineProperty(
+                new Proxy({}, { defineProperty() { return true; } }),
+                'a',
+                Object.setPrototypeOf({ value: 1, writable: true }, { enumerable: true, configurable: true })
+            )
+        "#,
+    ];
+    for scenario in scenarios {
+        let scenario = format!("try {{ {scenario} }} catch (e) {{ e instanceof RangeError }}");
+        assert_eq!(forward(&mut context, &scenario), "true");
+    }
+
+    // Objects within the limit can still be created after a call failed.
+    assert_eq!(
+        forward(
+            &mut context,
+            r#"
+            function f() { return arguments.length; }
+            var result = f();
+            for (var value of [1, 2]) { result += value; }
+            result
+        "#
+        ),
+        "3"
+    );
+
+    // The handlers of proxies built by the host aren't counted against the limit.
+    let target = context.construct_object();
+    let proxy = JsProxy::builder(target)
+        .get(trap)
+        .set(trap)
+        .has(trap)
+        .own_keys(trap)
+        .build(&mut context);
+    assert_eq!(proxy.get("a", &mut context).unwrap(), JsValue::new(1));
+}
+
+#[test]
+fn max_instructions() {
+    let mut limits = ResourceLimits::default();
+    limits.set_max_instructions(10_000);
+    let mut context = context_with_limits(limits);
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "let n = 0; for (let i = 0; i < 10; i++) { n += i; } n"
+        ),
+        "45"
+    );
+
+    let scenarios = [
+        "while (true) {}",
+        "try { while (true) {} } catch (e) { 'caught' }",
+        "try { while (true) {} } finally { 'finally' }",
+        "for (;;) { try { for (;;) {} } catch {} }",
+    ];
+    for scenario in scenarios {
+        let error = context.eval(scenario).unwrap_err().to_opaque(&mut context);
+        assert_eq!(
+            error.to_string(&mut context).unwrap(),
+            "RangeError: Maximum number of instructions exceeded"
+        );
+    }
+
+    // A loop in a promise job also stops, rejecting the promise of the job.
+    let promise = context
+        .eval("Promise.resolve().then(function loop() { while (true) {} })")
+        .unwrap();
+    assert!(promise.as_object().unwrap().is_promise());
+
+    // The instructions are counted separately for each evaluation.
+    assert_eq!(forward(&mut context, "1 + 1"), "2");
+}
+
+#[test]
+fn global_accessor() {
+    let mut context = Context::default();
+
+    let getter = FunctionBuilder::native(&mut context, |_, _, _| Ok(42.into()))
+        .name("get answer")
+        .build();
+    context.register_global_accessor(
+        "answer",
+        Some(getter),
+        None,
+        Attribute::NON_ENUMERABLE | Attribute::PERMANENT,
+    );
+
+    assert_eq!(forward(&mut context, "answer"), "42");
+    assert_eq!(forward(&mut context, "answer = 1; answer"), "42");
+    assert_eq!(
+        forward(
+            &mut context,
+            "typeof Object.getOwnPropertyDescriptor(globalThis, 'answer').get"
+        ),
+        "\"function\""
+    );
+    assert_eq!(forward(&mut context, "delete globalThis.answer"), "false");
+}
+
+#[test]
+fn without_intrinsics() {
+    let mut context = Context::builder()
+        .without(Intrinsic::EVAL | Intrinsic::FUNCTION | Intrinsic::DATE)
+        .build();
+
+    assert_eq!(forward(&mut context, "typeof eval"), "\"undefined\"");
+    assert_eq!(forward(&mut context, "typeof Function"), "\"undefined\"");
+    assert_eq!(forward(&mut context, "typeof Date"), "\"undefined\"");
+    assert_eq!(
+        forward(&mut context, "'eval' in globalThis || 'Date' in globalThis"),
+        "false"
+    );
+    assert_eq!(
+        forward(&mut context, "try { eval('1') } catch (e) { e.name }"),
+        "\"ReferenceError\""
+    );
+
+    // Functions can't be created from strings through the constructor of any kind of function.
+    let scenarios = [
+        "(function () {}).constructor('return 40 + 2')()",
+        "(() => {}).constructor('return 40 + 2')()",
+        "(function* () {}).constructor('yield 40 + 2')().next().value",
+        "(async function () {}).constructor('return 40 + 2')",
+        "(async function* () {}).constructor('yield 40 + 2')",
+        "new (function () {}).constructor('return 40 + 2')",
+        "Reflect.construct((function () {}).constructor, ['return 40 + 2'])",
+    ];
+    for scenario in scenarios {
+        let scenario = format!("try {{ {scenario} }} catch (e) {{ e.name }}");
+        assert_eq!(forward(&mut context, &scenario), "\"EvalError\"");
+    }
+
+    // The rest of the engine keeps working without the removed globals.
+    assert_eq!(
+        forward(
+            &mut context,
+            r#"
+            function* gen() { yield 1; yield 2; }
+            class A { get x() { return [...gen()].length; } }
+            new A().x + JSON.parse("[1]").length
+            "#
+        ),
+        "3"
+    );
+}
+
+#[test]
+fn without_ambient_time_and_entropy() {
+    let mut context = Context::builder()
+        .without(Intrinsic::DATE_NOW | Intrinsic::MATH_RANDOM)
+        .build();
+
+    assert_eq!(forward(&mut context, "typeof Date"), "\"function\"");
+    assert_eq!(forward(&mut context, "typeof Date.now"), "\"undefined\"");
+    assert_eq!(forward(&mut context, "typeof Math.random"), "\"undefined\"");
+    assert_eq!(forward(&mut context, "typeof Math.floor"), "\"function\"");
+
+    // The system clock can't be read through the `Date` constructor either.
+    assert_eq!(forward(&mut context, "new Date().getTime()"), "NaN");
+    assert_eq!(forward(&mut context, "Date()"), "\"Invalid Date\"");
+    assert_eq!(forward(&mut context, "new Date(0).getTime()"), "0");
+
+    // Unless the host provides a clock.
+    context.set_clock(|| 86_400_000.0);
+    assert_eq!(forward(&mut context, "new Date().getTime()"), "86400000");
+}
+
+#[test]
+fn injected_clock() {
+    let time = Rc::new(Cell::new(1_000_000.0));
+
+    let mut context = Context::default();
+    let clock = time.clone();
+    context.set_clock(move || clock.get());
+
+    assert_eq!(forward(&mut context, "Date.now()"), "1000000");
+    assert_eq!(forward(&mut context, "new Date().getTime()"), "1000000");
+
+    time.set(86_400_000.5);
+    assert_eq!(forward(&mut context, "Date.now()"), "86400000");
+    assert_eq!(forward(&mut context, "new Date().getTime()"), "86400000");
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Date(Date()).getTime() === new Date().getTime()"
+        ),
+        "true"
+    );
+
+    time.set(8.64e15 + 1.0);
+    assert_eq!(forward(&mut context, "Date.now()"), "NaN");
+}
+
+#[test]
+fn injected_random_source_replays() {
+    fn run() -> String {
+        let mut seed = 42_u64;
+        let mut context = Context::default();
+        context.set_random_source(move || {
+            seed = seed
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(1_442_695_040_888_963_407);
+            (seed >> 11) as f64 / (1_u64 << 53) as f64
+        });
+        forward(
+            &mut context,
+            "[Math.random(), Math.random(), Math.random()].join()",
+        )
+    }
+
+    let first = run();
+    assert_eq!(first, run());
+
+    let values: Vec<f64> = first
+        .trim_matches('"')
+        .split(',')
+        .map(|value| value.parse().expect("should be a number"))
+        .collect();
+    assert_eq!(values.len(), 3);
+    assert!(values.iter().all(|value| (0.0..1.0).contains(value)));
+}
+
+#[test]
+fn strict_by_default() {
+    let mut context = Context::builder().strict(true).build();
+
+    let error = context
+        .eval("undeclared = 1")
+        .expect_err("assignment to an undeclared variable should throw")
+        .to_opaque(&mut context);
+    assert_eq!(
+        error
+            .as_object()
+            .expect("should be an error object")
+            .get("name", &mut context)
+            .expect("should not fail"),
+        "ReferenceError".into()
+    );
+}
+
+#[test]
+fn strict_by_default_nested_code() {
+    let mut context = Context::default();
+    context.set_strict(true);
+
+    forward(
+        &mut context,
+        r#"
+        function run(f) {
+            try { f(); return "no error"; } catch (e) { return e.name; }
+        }
+        "#,
+    );
+    assert_eq!(
+        forward(&mut context, "run(new Function('undeclaredFunction = 1'))"),
+        "\"ReferenceError\""
+    );
+    assert_eq!(
+        forward(&mut context, "run(() => { Object.freeze([1])[0] = 2; })"),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(&mut context, "run(() => new Function('a', 'a', ''))"),
+        "\"SyntaxError\""
+    );
+    assert_eq!(
+        forward(&mut context, "new Function('a = 1', 'return a')()"),
+        "1"
+    );
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "run(() => (0, eval)('undeclaredIndirect = 1'))"
+        ),
+        "\"ReferenceError\""
+    );
+    assert_eq!(
+        forward(&mut context, "run(() => eval('undeclaredDirect = 1'))"),
+        "\"ReferenceError\""
+    );
+    assert_eq!(
+        forward(&mut context, "run(new Function('undeclaredAfterEval = 1'))"),
+        "\"ReferenceError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "(function (a) { return eval(\"new Function('return typeof a')()\"); })(1)"
+        ),
+        "\"undefined\""
+    );
+
+    context.set_strict(false);
+    assert_eq!(
+        forward(&mut context, "sloppy = 1; typeof sloppy"),
+        "\"number\""
+    );
+}
+
+#[test]
+fn recursion_limit() {
+    let mut context = Context::builder().recursion_limit(8).build();
+
+    let nested = format!("{}1{}", "(".repeat(10), ")".repeat(10));
+    assert!(forward(&mut context, &nested)
+        .starts_with("Uncaught \"SyntaxError\": \"maximum nesting depth exceeded"));
+    assert_eq!(forward(&mut context, "((1))"), "1");
+    assert_eq!(
+        forward(&mut context, format!("1{}", " + 1".repeat(100))),
+        "101"
+    );
+
+    forward(
+        &mut context,
+        r"
+        function run(f) {
+            try { return f(); } catch (e) { return e.name; }
+        }
+        ",
+    );
+    assert_eq!(
+        forward(&mut context, format!("run(() => eval('{nested}'))")),
+        "\"SyntaxError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            format!("run(() => Function('return {nested}'))")
+        ),
+        "\"SyntaxError\""
+    );
+}
+
+#[test]
+fn runtime_profiling() {
+    let profiler = Profiler::global();
+    let mut context = Context::default();
+
+    context.set_profiling(true);
+    profiler.clear();
+    forward(&mut context, "let x = [1, 2, 3].map(n => n * 2); x.length");
+    context.set_profiling(false);
+
+    let report = profiler.report();
+    assert!(report
+        .entries()
+        .iter()
+        .any(|entry| entry.category() == "Parsing" && entry.count() > 0));
+    assert!(report
+        .entries()
+        .iter()
+        .any(|entry| entry.category() == "vm" && entry.label() == "INST - Call"));
+    assert!(report.category_time("Main") >= report.category_time("Parsing"));
+
+    let mut trace = Vec::new();
+    profiler
+        .write_chrome_trace(&mut trace)
+        .expect("writing to a vector should not fail");
+    let trace = String::from_utf8(trace).expect("trace should be UTF-8");
+    assert!(trace.starts_with(r#"{"traceEvents":[{"name":"#));
+    assert_eq!(
+        trace.matches(r#""ph":"B""#).count(),
+        trace.matches(r#""ph":"E""#).count()
+    );
+
+    // The trace only keeps the most recent events, but the report includes all of them.
+    context.set_profiling(true);
+    profiler.clear();
+    for _ in 0..600_000 {
+        let _timer = profiler.start_event("event", "bounded");
+    }
+    context.set_profiling(false);
+    assert_eq!(profiler.report().entries()[0].count(), 600_000);
+    let mut trace = Vec::new();
+    profiler
+        .write_chrome_trace(&mut trace)
+        .expect("writing to a vector should not fail");
+    let trace = String::from_utf8(trace).expect("trace should be UTF-8");
+    let ends = trace.matches(r#""ph":"E""#).count();
+    assert!(ends > 0 && ends < 600_000);
+
+    // Events are not recorded while profiling is disabled.
+    profiler.clear();
+    forward(&mut context, "1 + 1");
+    assert!(profiler.report().is_empty());
+}
+
+#[test]
+fn contexts_in_concurrent_threads() {
+    let script = r#"
+        class Counter {
+            #count = 0;
+            increment() { this.#count = this.#count + 1; return this.#count; }
+        }
+        const counter = new Counter();
+        const words = [];
+        for (let i = 0; i < 200; i++) {
+            counter.increment();
+            words.push(Symbol.for("word" + (i % 10)).description);
+        }
+        JSON.stringify({ count: counter.increment(), words: new Set(words).size })
+    "#;
+
+    let handles: Vec<_> = (0..8)
+        .map(|_| {
+            std::thread::spawn(move || {
+                (0..10)
+                    .map(|_| {
+                        let mut context = Context::default();
+                        forward(&mut context, script)
+                    })
+                    .collect::<Vec<_>>()
+            })
+        })
+        .collect();
+
+    for handle in handles {
+        for result in handle.join().expect("thread should not panic") {
+            assert_eq!(result, r#""{"count":201,"words":10}""#);
+        }
+    }
+}
+
+#[test]
+fn compiled_script_in_concurrent_threads() {
+    let script = CompiledScript::compile(
+        r#"
+        class Counter {
+            #count = 0;
+            increment() { this.#count = this.#count + 1; return this.#count; }
+        }
+        function sum(first, second = first, ...rest) {
+            arguments[0] = 0;
+            return first + second + rest.length;
+        }
+        function mapped(a, b) {
+            arguments[0] = 10;
+            return a + b;
+        }
+        const counter = new Counter();
+        const words = [];
+        for (let i = 0; i < 200; i++) {
+            counter.increment();
+            words.push(Symbol.for("word" + (i % 10)).description);
+        }
+        const { "\uD800": lone, 1: one } = { "\uD800": /a(?<b>b)/.exec("ab").groups.b, 1: 10n };
+        JSON.stringify({
+            count: counter.increment(),
+            words: new Set(words).size,
+            sum: sum(1, 2, 3, 4),
+            mapped: mapped(1, 2),
+            lone,
+            one: typeof one,
+        })
+    "#,
+    )
+    .expect("script should compile");
+    let expected = r#"{"count":201,"words":10,"sum":5,"mapped":12,"lone":"b","one":"bigint"}"#;
+
+    let handles: Vec<_> = (0..8)
+        .map(|_| {
+            let script = script.clone();
+            std::thread::spawn(move || {
+                (0..10)
+                    .map(|_| {
+                        let mut context = Context::default();
+                        let result = script
+                            .execute(&mut context)
+                            .expect("script should not throw");
+                        result
+                            .as_string()
+                            .expect("script should return a string")
+                            .to_string()
+                    })
+                    .collect::<Vec<_>>()
+            })
+        })
+        .collect();
+
+    for handle in handles {
+        for result in handle.join().expect("thread should not panic") {
+            assert_eq!(result, expected);
+        }
+    }
+}
+
+#[test]
+fn compiled_script_global_declarations() {
+    let declare = CompiledScript::compile(
+        "let a = 1; const b = 2; var c = 3; function d() { return a + b + c; }",
+    )
+    .expect("script should compile");
+    let read = CompiledScript::compile("d() + c").expect("script should compile");
+
+    let mut context = Context::default();
+    forward(&mut context, "let x = 'x'; var y = 'y';");
+    declare
+        .execute(&mut context)
+        .expect("declarations should not throw");
+
+    assert_eq!(
+        forward(&mut context, "[x, y, a, b, c, d()].join()"),
+        "\"x,y,1,2,3,6\""
+    );
+    assert_eq!(forward(&mut context, "a = 5; d()"), "10");
+    assert_eq!(
+        read.execute(&mut context).expect("script should not throw"),
+        JsValue::new(13)
+    );
+
+    // The declarations are checked against the global scope of the context.
+    let error = declare
+        .execute(&mut context)
+        .expect_err("redeclaring a global lexical binding should throw");
+    assert!(error.to_string().contains("SyntaxError"));
+}
+
+#[test]
+fn compiled_script_recompiles_over_global_lexical_bindings() {
+    // `total` is a global object property when the script is compiled, but a lexical binding of
+    // the context that runs it.
+    let script = CompiledScript::compile("total += 1; total").expect("script should compile");
+
+    let mut context = Context::default();
+    forward(&mut context, "let total = 10;");
+    assert_eq!(
+        script
+            .execute(&mut context)
+            .expect("script should not throw"),
+        JsValue::new(11)
+    );
+    assert_eq!(forward(&mut context, "total"), "11");
+    assert_eq!(forward(&mut context, "globalThis.total"), "undefined");
+
+    // Strict contexts run non-strict scripts as strict code.
+    let script = CompiledScript::compile("undeclared = 1").expect("script should compile");
+    let mut context = Context::builder().strict(true).build();
+    assert!(script.execute(&mut context).is_err());
+}
+
+#[test]
+fn memory_usage_is_released_on_collection() {
+    let mut context = Context::default();
+    forward(&mut context, "var buffer, string;");
+    let baseline = context.memory_usage();
+
+    forward(
+        &mut context,
+        "buffer = new ArrayBuffer(1 << 20); string = 'x'.repeat(1 << 20);",
+    );
+    let allocated = context.memory_usage();
+    assert!(allocated.array_buffers() >= baseline.array_buffers() + (1 << 20));
+    assert!(allocated.strings() >= baseline.strings() + (1 << 20));
+    assert!(allocated.total() > baseline.total());
+
+    forward(&mut context, "buffer = undefined; string = undefined;");
+    boa_gc::force_collect();
+    let released = context.memory_usage();
+    assert!(released.array_buffers() < baseline.array_buffers() + (1 << 20));
+    assert!(released.strings() < baseline.strings() + (1 << 20));
+}
+
+#[test]
+fn memory_usage_is_accounted_per_context() {
+    let mut first = Context::default();
+    let second = Context::default();
+    let baseline = first.memory_usage();
+    let other = second.memory_usage();
+
+    forward(
+        &mut first,
+        "var buffer = new ArrayBuffer(1 << 20); var string = 'x'.repeat(1 << 20);",
+    );
+    let allocated = first.memory_usage();
+    assert!(allocated.array_buffers() >= baseline.array_buffers() + (1 << 20));
+    assert!(allocated.strings() >= baseline.strings() + (1 << 20));
+
+    // The garbage of the second context may be collected meanwhile, but it never grows.
+    let unchanged = second.memory_usage();
+    assert!(unchanged.array_buffers() <= other.array_buffers());
+    assert!(unchanged.strings() <= other.strings());
+    assert!(unchanged.gc_heap() <= other.gc_heap());
+}
+
+#[test]
+fn memory_usage_includes_property_tables() {
+    let mut context = Context::default();
+    forward(&mut context, "var object = {};");
+    let baseline = context.memory_usage();
+
+    forward(
+        &mut context,
+        "for (let i = 0; i < 10000; i++) { object['key' + i] = i; }",
+    );
+    let allocated = context.memory_usage();
+    assert!(allocated.gc_heap() >= baseline.gc_heap() + 10000 * size_of::<PropertyDescriptor>());
+}
+
+#[test]
+fn memory_measurement_builtin() {
+    let mut context = Context::default();
+    assert_eq!(forward(&mut context, "typeof performance"), "\"undefined\"");
+
+    let mut context = Context::builder().memory_measurement(true).build();
+    forward(
+        &mut context,
+        r#"
+        var result;
+        performance.measureUserAgentSpecificMemory().then(r => { result = r; });
+        "#,
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "result.breakdown.map(entry => entry.types[0]).join()"
+        ),
+        "\"gc-heap,interner,strings,code-blocks,array-buffers\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "result.bytes === result.breakdown.reduce((sum, entry) => sum + entry.bytes, 0)"
+        ),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "result.bytes > 0"), "true");
+}
+
+#[derive(Default)]
+struct LoggingHooks {
+    log: Rc<RefCell<Vec<String>>>,
+}
+
+impl HostHooks for LoggingHooks {
+    fn make_job_callback(&self, callback: JsObject, _context: &mut Context) -> JobCallback {
+        let id = self.log.borrow().len();
+        self.log.borrow_mut().push(format!("make {id}"));
+        JobCallback::new(callback, id.into())
+    }
+
+    fn call_job_callback(
+        &self,
+        job: &JobCallback,
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let id = job.host_defined().display().to_string();
+        self.log.borrow_mut().push(format!("call {id}"));
+        job.callback().__call__(this, args, context)
+    }
+
+    fn promise_rejection_tracker(
+        &self,
+        _promise: &JsObject,
+        operation: OperationType,
+        _context: &mut Context,
+    ) {
+        self.log.borrow_mut().push(format!("{operation:?}"));
+    }
+
+    fn ensure_can_compile_strings(&self, context: &mut Context) -> JsResult<()> {
+        context.throw_eval_error("dynamic code is disabled by the host")
+    }
+}
+
+#[test]
+fn host_hooks_block_dynamic_code() {
+    let mut context = Context::builder()
+        .host_hooks(LoggingHooks::default())
+        .build();
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { eval('1 + 1') } catch (e) { `${e.name}: ${e.message}` }"
+        ),
+        "\"EvalError: dynamic code is disabled by the host\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new Function('return 1') } catch (e) { `${e.name}: ${e.message}` }"
+        ),
+        "\"EvalError: dynamic code is disabled by the host\""
+    );
+    assert_eq!(forward(&mut context, "eval(1)"), "1");
+}
+
+#[test]
+fn host_hooks_job_callbacks() {
+    let hooks = LoggingHooks::default();
+    let log = hooks.log.clone();
+    let mut context = Context::builder().host_hooks(hooks).build();
+
+    forward(
+        &mut context,
+        r#"
+        var result = [];
+        Promise.resolve(1).then(value => result.push(value));
+        "#,
+    );
+    assert_eq!(forward(&mut context, "result.join()"), "\"1\"");
+    assert_eq!(*log.borrow(), ["make 0", "call 0"]);
+
+    log.borrow_mut().clear();
+    forward(&mut context, "var rejected = Promise.reject(2);");
+    assert_eq!(*log.borrow(), ["Reject"]);
+    forward(
+        &mut context,
+        "rejected.catch(reason => result.push(reason));",
+    );
+    assert_eq!(*log.borrow(), ["Reject", "make 1", "Handle", "call 1"]);
+    assert_eq!(forward(&mut context, "result.join()"), "\"1,2\"");
+}
+
+#[test]
+fn intrinsics_are_not_affected_by_the_global_object() {
+    let mut context = Context::default();
+    let type_error = context
+        .intrinsics()
+        .constructors()
+        .type_error()
+        .constructor();
+    context.register_global_property("savedTypeError", type_error, Attribute::all());
+
+    forward(
+        &mut context,
+        r#"
+        delete globalThis.TypeError;
+        delete globalThis.Array;
+        globalThis.RegExp = undefined;
+        globalThis.Promise = function () { throw "clobbered"; };
+        "#,
+    );
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { null.x } catch (e) { e instanceof savedTypeError && e.name }"
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(forward(&mut context, "typeof TypeError"), "\"undefined\"");
+    assert_eq!(
+        forward(&mut context, "[1, 2].map(x => x * 2).join()"),
+        "\"2,4\""
+    );
+    assert_eq!(forward(&mut context, "'abc'.match('b')[0]"), "\"b\"");
+    forward(
+        &mut context,
+        "var result; async function f() { return 1; } f().then(value => { result = value; });",
+    );
+    assert_eq!(forward(&mut context, "result"), "1");
+}
+
+#[derive(Debug, Trace, Finalize)]
+struct Point {
+    x: f64,
+}
+
+impl Class for Point {
+    const NAME: &'static str = "Point";
+    const LENGTH: usize = 1;
+
+    fn constructor(_this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<Self> {
+        let x = args.get_or_undefined(0).to_number(context)?;
+        Ok(Self { x })
+    }
+
+    fn init(class: &mut ClassBuilder<'_>) -> JsResult<()> {
+        class.method("getX", 0, |this, _, context| {
+            let x = this
+                .as_object()
+                .and_then(|object| object.downcast_ref::<Self>().map(|point| point.x));
+            match x {
+                Some(x) => Ok(x.into()),
+                None => context.throw_type_error("not a Point"),
+            }
+        });
+        Ok(())
+    }
+}
+
+#[test]
+fn class_prototype_is_not_affected_by_the_global_object() {
+    let mut context = Context::default();
+    context.register_global_class::<Point>().unwrap();
+
+    let prototype = context
+        .intrinsics()
+        .class::<Point>()
+        .expect("the class should be registered")
+        .prototype();
+    context.register_global_property("pointPrototype", prototype, Attribute::all());
+
+    forward(
+        &mut context,
+        r#"
+        const SavedPoint = Point;
+        delete globalThis.Point;
+        globalThis.Point = function () {};
+        function Target() {}
+        Target.prototype = 1;
+        "#,
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "const p = Reflect.construct(SavedPoint, [3], Target); \
+             Object.getPrototypeOf(p) === pointPrototype && p.getX()"
+        ),
+        "3"
+    );
+}
+
+#[test]
+fn global_namespace() {
+    let mut context = Context::default();
+
+    let fs = NamespaceBuilder::new().function("readFile", 1, |_, args, context| {
+        let path = args.get_or_undefined(0).to_string(context)?;
+        Ok(format!("contents of {path}").into())
+    });
+    let host = NamespaceBuilder::new()
+        .value("version", 2)
+        .namespace("fs", fs)
+        .class::<Point>();
+    context.register_global_namespace("host", host).unwrap();
+
+    assert_eq!(
+        forward(&mut context, "host.fs.readFile('a.txt')"),
+        "\"contents of a.txt\""
+    );
+    assert_eq!(forward(&mut context, "new host.Point(3).getX()"), "3");
+    assert_eq!(forward(&mut context, "typeof Point"), "\"undefined\"");
+    assert_eq!(
+        forward(&mut context, "Object.keys(host).join()"),
+        "\"Point,fs,version\""
+    );
+    assert_eq!(
+        forward(&mut context, "Object.prototype.toString.call(host.fs)"),
+        "\"[object Module]\""
+    );
+    assert_eq!(forward(&mut context, "Object.getPrototypeOf(host)"), "null");
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.isFrozen(host) && Object.isFrozen(host.fs)"
+        ),
+        "true"
+    );
+
+    forward(
+        &mut context,
+        r#"
+        host.version = 3;
+        host.fs.readFile = () => "clobbered";
+        host = {};
+        delete globalThis.host;
+        "#,
+    );
+    assert_eq!(forward(&mut context, "host.version"), "2");
+    assert_eq!(
+        forward(&mut context, "host.fs.readFile('b.txt')"),
+        "\"contents of b.txt\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "'use strict'; try { host.version = 3 } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn run_jobs_from_the_host() {
+    let mut context = Context::default();
+    let resolve = context
+        .eval(
+            r#"
+            var settled = false;
+            var resolve;
+            new Promise((r) => { resolve = r; }).then((value) => { settled = value; });
+            resolve
+            "#,
+        )
+        .unwrap();
+
+    // Calling a function of the script doesn't run the queued reactions on its own.
+    resolve
+        .as_callable()
+        .unwrap()
+        .call(&JsValue::undefined(), &[true.into()], &mut context)
+        .unwrap();
+    let settled = context.global_object().clone().get("settled", &mut context);
+    assert_eq!(settled.unwrap(), false.into());
+
+    context.run_jobs().unwrap();
+    let settled = context.global_object().clone().get("settled", &mut context);
+    assert_eq!(settled.unwrap(), true.into());
+}
+
+#[test]
+fn eval_with_completion() {
+    let mut context = Context::default();
+
+    let cases = [
+        ("1; ;", Some(JsValue::new(1))),
+        ("var x = 5", None),
+        ("let y = 3", None),
+        ("2; var z = 5;", Some(JsValue::new(2))),
+        ("3; function f() {}", Some(JsValue::new(3))),
+        ("4; {}", Some(JsValue::new(4))),
+        ("5; if (false) 6;", Some(JsValue::undefined())),
+        ("if (true) { 7; } else { 8; }", Some(JsValue::new(7))),
+        ("9; while (false) {}", Some(JsValue::undefined())),
+        ("var i = 0; while (i < 3) { i++; }", Some(JsValue::new(2))),
+        ("do { 10; break; } while (true)", Some(JsValue::new(10))),
+        ("for (var j of [1, 2]) { j * 11; }", Some(JsValue::new(22))),
+        ("switch (1) { case 1: 12; }", Some(JsValue::new(12))),
+        ("try { 13; } finally { 14; }", Some(JsValue::new(13))),
+        (
+            "try { 15; throw 0; } catch (e) {}",
+            Some(JsValue::undefined()),
+        ),
+        ("try { throw 0; } catch (e) { 16; }", Some(JsValue::new(16))),
+        ("", None),
+    ];
+    for (source, value) in cases {
+        assert_eq!(
+            context.eval_with_completion(source),
+            Completion::Normal(value),
+            "{source}"
+        );
+    }
+
+    assert_eq!(
+        context.eval_with_completion("throw 17"),
+        Completion::Throw(JsError::from_opaque(JsValue::new(17)))
+    );
+    assert!(matches!(
+        context.eval_with_completion("let"),
+        Completion::Throw(error) if error.to_string().starts_with("SyntaxError")
+    ));
+    assert_eq!(context.eval("var w = 5").unwrap(), JsValue::undefined());
+}
+
+#[test]
+fn eval_returns_completion_value() {
+    let mut context = Context::default();
+    assert_eq!(forward(&mut context, "eval('1; ;')"), "1");
+    assert_eq!(forward(&mut context, "eval('var x = 5')"), "undefined");
+    assert_eq!(forward(&mut context, "eval('2; var y = 5')"), "2");
+    assert_eq!(
+        forward(&mut context, "eval('for (var i = 0; i < 3; i++) i * 2')"),
+        "4"
+    );
+}
+
+#[test]
+fn eval_with_bindings() {
+    let mut context = Context::default();
+    context.eval("var price = 'global'; var total;").unwrap();
+
+    let bindings = [("price", JsValue::new(4)), ("qty", JsValue::new(3))];
+    assert_eq!(
+        context
+            .eval_with_bindings("price * qty", &bindings)
+            .unwrap(),
+        JsValue::new(12)
+    );
+
+    // The bindings shadow the global scope, and assignments to them don't leave the
+    // temporary scope.
+    assert_eq!(
+        context
+            .eval_with_bindings("price = price + 1; qty = 0; price", &bindings)
+            .unwrap(),
+        JsValue::new(5)
+    );
+    assert_eq!(forward(&mut context, "price"), "\"global\"");
+    assert_eq!(forward(&mut context, "typeof qty"), "\"undefined\"");
+
+    // Global bindings that are not shadowed are still visible and writable.
+    assert_eq!(
+        context
+            .eval_with_bindings("total = price * qty; total", &bindings)
+            .unwrap(),
+        JsValue::new(12)
+    );
+    assert_eq!(forward(&mut context, "total"), "12");
+
+    // Declarations stay in the temporary scope.
+    assert_eq!(
+        context
+            .eval_with_bindings(
+                "var unit = 'EUR'; let tax = 2; const fee = 1; function f() { return price + tax + fee }
+                [unit, f(), this === globalThis].join()",
+                &bindings,
+            )
+            .unwrap(),
+        JsValue::new("EUR,7,true")
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "[typeof unit, typeof tax, typeof fee, typeof f].join()"
+        ),
+        "\"undefined,undefined,undefined,undefined\""
+    );
+
+    // Redeclaring a binding with `var` keeps its value, like a function parameter.
+    assert_eq!(
+        context
+            .eval_with_bindings("var price; price", &bindings)
+            .unwrap(),
+        JsValue::new(4)
+    );
+
+    // Closures created by the code keep seeing the temporary scope.
+    let counter = context
+        .eval_with_bindings("() => ++qty", &bindings)
+        .unwrap();
+    context.register_global_property("counter", counter, Attribute::all());
+    assert_eq!(forward(&mut context, "counter(); counter()"), "5");
+    assert_eq!(forward(&mut context, "typeof qty"), "\"undefined\"");
+
+    // The code is evaluated over the global scope even when called by other code.
+    let inner = FunctionBuilder::native(&mut context, |_, args, context| {
+        context.eval_with_bindings("local * 2", &[("local", args.get_or_undefined(0).clone())])
+    })
+    .build();
+    context.register_global_property("inner", inner, Attribute::all());
+    assert_eq!(
+        forward(
+            &mut context,
+            "function outer() { let local = 100; return inner(21) + local } outer()"
+        ),
+        "142"
+    );
+
+    // Errors leave the context usable.
+    assert!(context.eval_with_bindings("price +", &bindings).is_err());
+    assert!(context
+        .eval_with_bindings("throw price", &bindings)
+        .is_err());
+    assert!(context
+        .eval_with_bindings("let a; let a;", &bindings)
+        .is_err());
+    assert_eq!(forward(&mut context, "price"), "\"global\"");
+    assert_eq!(
+        context.eval_with_bindings("qty", &bindings).unwrap(),
+        JsValue::new(3)
+    );
+}
+
+#[test]
+fn eval_with_bindings_direct_eval() {
+    let mut context = Context::default();
+    let bindings = [("price", JsValue::new(4))];
+
+    // Variables declared by a direct `eval` are created in the temporary scope, like in the body
+    // of a function.
+    assert_eq!(
+        context
+            .eval_with_bindings("eval('var e1 = 1'); e1", &bindings)
+            .unwrap(),
+        JsValue::new(1)
+    );
+    assert_eq!(
+        context
+            .eval_with_bindings(
+                "eval('var e1 = price * 2; var e2 = e1 + 1'); [price, e1, e2].join()",
+                &bindings
+            )
+            .unwrap(),
+        JsValue::new("4,8,9")
+    );
+    assert_eq!(
+        context
+            .eval_with_bindings("{ eval('var e1 = 1'); } e1 + price", &bindings)
+            .unwrap(),
+        JsValue::new(5)
+    );
+    assert_eq!(
+        context
+            .eval_with_bindings("eval('price = 10'); price", &bindings)
+            .unwrap(),
+        JsValue::new(10)
+    );
+    assert_eq!(forward(&mut context, "typeof e1"), "\"undefined\"");
+    assert_eq!(forward(&mut context, "typeof price"), "\"undefined\"");
+}
+
+#[test]
+fn eval_with_bindings_lexical_redeclaration() {
+    let mut context = Context::default();
+    let bindings = [("qty", JsValue::new(3))];
+
+    for code in [
+        "let qty = 1",
+        "const qty = 1",
+        "class qty {}",
+        "let [qty] = [1]",
+    ] {
+        let error = context
+            .eval_with_bindings(code, &bindings)
+            .unwrap_err()
+            .to_opaque(&mut context);
+        let error = error.as_object().unwrap();
+        assert_eq!(
+            error.get("name", &mut context).unwrap(),
+            JsValue::new("SyntaxError")
+        );
+    }
+
+    // Nested scopes and function declarations can still shadow the bindings.
+    assert_eq!(
+        context
+            .eval_with_bindings("{ let qty = 1; } qty", &bindings)
+            .unwrap(),
+        JsValue::new(3)
+    );
+    assert_eq!(
+        context
+            .eval_with_bindings("function qty() {} typeof qty", &bindings)
+            .unwrap(),
+        JsValue::new("function")
+    );
+}
+
+#[test]
+fn eval_with_bindings_undeclared_assignment() {
+    let mut context = Context::default();
+    let bindings = [("x", JsValue::new(1))];
+
+    // Sloppy mode code creates global properties for undeclared names.
+    context.eval_with_bindings("y = x", &bindings).unwrap();
+    assert_eq!(forward(&mut context, "y"), "1");
+
+    // Strict mode code can assign the bindings, but not undeclared names.
+    assert_eq!(
+        context
+            .eval_with_bindings("'use strict'; x = 2; x", &bindings)
+            .unwrap(),
+        JsValue::new(2)
+    );
+    let error = context
+        .eval_with_bindings("'use strict'; z = x", &bindings)
+        .unwrap_err()
+        .to_opaque(&mut context);
+    assert!(error.as_object().map_or(false, |error| error.is_error()));
+    assert_eq!(forward(&mut context, "typeof z"), "\"undefined\"");
+
+    let mut context = Context::builder().strict(true).build();
+    assert!(context.eval_with_bindings("z = x", &bindings).is_err());
+    assert_eq!(
+        context
+            .eval_with_bindings("var w = x; w", &bindings)
+            .unwrap(),
+        JsValue::new(1)
+    );
+    assert_eq!(forward(&mut context, "typeof w"), "\"undefined\"");
+}
+
+#[test]
+fn native_function_argument_helpers() {
+    fn call_with(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let function = args.to_callable(0, "callWith", context)?;
+        let value = args.require(1, "callWith", context)?.clone();
+        function.call(&JsValue::undefined(), &[value], context)
+    }
+    fn object_of(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        Ok(args.to_object(0, "objectOf", context)?.into())
+    }
+    fn number_of(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        Ok(args.to_number(0, context)?.into())
+    }
+
+    let mut context = Context::default();
+    context.register_global_function("callWith", 2, call_with);
+    context.register_global_function("objectOf", 1, object_of);
+    context.register_global_function("numberOf", 1, number_of);
+
+    assert_eq!(forward(&mut context, "callWith(x => x * 2, 21)"), "42");
+    assert_eq!(
+        forward(&mut context, "callWith(x => x, undefined)"),
+        "undefined"
+    );
+    assert_eq!(
+        forward(&mut context, "callWith({}, 1)"),
+        "Uncaught \"TypeError\": \"callWith: argument 1 is not callable\""
+    );
+    assert_eq!(
+        forward(&mut context, "callWith(x => x)"),
+        "Uncaught \"TypeError\": \"callWith: argument 2 is required\""
+    );
+
+    assert_eq!(forward(&mut context, "typeof objectOf('a')"), "\"object\"");
+    assert_eq!(
+        forward(&mut context, "objectOf(null)"),
+        "Uncaught \"TypeError\": \"objectOf: argument 1 cannot be converted to an object\""
+    );
+
+    assert_eq!(forward(&mut context, "numberOf('12')"), "12");
+    assert_eq!(forward(&mut context, "numberOf()"), "NaN");
+}
+
+fn context_with_eval_cache() -> Context {
+    let mut context = Context::default();
+    context.set_eval_cache(EvalCacheConfig {
+        max_entries: 4,
+        max_source_len: 64,
+    });
+    context
+}
+
+#[test]
+fn eval_cache_reuses_compiled_code() {
+    let mut context = context_with_eval_cache();
+
+    forward(&mut context, "var calls = 0; var fns = [];");
+    let snippet = "calls += 1; fns.push(function () {}); calls * 10";
+    assert_eq!(forward(&mut context, snippet), "10");
+    assert_eq!(context.eval_cache.len(), 2);
+    assert_eq!(forward(&mut context, snippet), "20");
+    assert_eq!(forward(&mut context, snippet), "30");
+    assert_eq!(context.eval_cache.len(), 2);
+
+    // Every evaluation creates new functions.
+    assert_eq!(forward(&mut context, "fns[0] === fns[1]"), "false");
+    assert_eq!(forward(&mut context, "fns[1] === fns[2]"), "false");
+}
+
+#[test]
+fn eval_cache_checks_declarations() {
+    let mut context = context_with_eval_cache();
+
+    assert_eq!(forward(&mut context, "let x = 1; x"), "1");
+    assert_eq!(
+        forward(&mut context, "let x = 1; x"),
+        "Uncaught \"SyntaxError\": \"Identifier 'x' has already been declared\""
+    );
+
+    assert_eq!(forward(&mut context, "var y = 2; y"), "2");
+    assert_eq!(forward(&mut context, "var y = 2; y"), "2");
+}
+
+#[test]
+fn eval_cache_sees_new_global_lexical_declarations() {
+    let mut context = context_with_eval_cache();
+
+    assert_eq!(forward(&mut context, "typeof z"), "\"undefined\"");
+    forward(&mut context, "let z = 'lexical'");
+    assert_eq!(forward(&mut context, "typeof z"), "\"string\"");
+    assert_eq!(forward(&mut context, "z = 'assigned'; z"), "\"assigned\"");
+    assert_eq!(forward(&mut context, "typeof z"), "\"string\"");
+    assert_eq!(forward(&mut context, "globalThis.z"), "undefined");
+}
+
+#[test]
+fn eval_cache_depends_on_strict_mode() {
+    let mut context = context_with_eval_cache();
+
+    assert_eq!(forward(&mut context, "undeclared = 1"), "1");
+    context.set_strict(true);
+    assert_eq!(
+        forward(&mut context, "undeclared2 = 1"),
+        "Uncaught \"ReferenceError\": \"assignment to undeclared variable undeclared2\""
+    );
+    context.set_strict(false);
+    assert_eq!(forward(&mut context, "undeclared2 = 1"), "1");
+}
+
+#[test]
+fn eval_cache_limits() {
+    let mut context = context_with_eval_cache();
+
+    forward(&mut context, "1 + 1;".repeat(20));
+    assert_eq!(context.eval_cache.len(), 0);
+
+    for i in 0..6 {
+        forward(&mut context, format!("{i}"));
+    }
+    assert_eq!(context.eval_cache.len(), 4);
+
+    // Syntax errors are not cached.
+    forward(&mut context, "(");
+    assert_eq!(context.eval_cache.len(), 4);
+
+    context.clear_eval_cache();
+    assert_eq!(context.eval_cache.len(), 0);
+    forward(&mut context, "1");
+    assert_eq!(context.eval_cache.len(), 1);
+
+    context.set_eval_cache(EvalCacheConfig::default());
+    assert_eq!(context.eval_cache.len(), 0);
+    forward(&mut context, "1");
+    assert_eq!(context.eval_cache.len(), 0);
+}
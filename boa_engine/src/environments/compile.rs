@@ -8,13 +8,32 @@ use rustc_hash::FxHashMap;
 /// A compile time binding represents a binding at bytecode compile time in a [`CompileTimeEnvironment`].
 ///
 /// It contains the binding index and a flag to indicate if this is a mutable binding or not.
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 struct CompileTimeBinding {
     index: usize,
     mutable: bool,
     lex: bool,
 }
 
+/// The bindings of a [`CompileTimeEnvironment`] without its outer environment, which can be sent
+/// to other threads.
+///
+/// This is used by [`CompiledScript`][crate::context::CompiledScript] to create copies of the
+/// compile time environments of a script in other contexts.
+#[derive(Debug, Clone)]
+pub(crate) struct FrozenCompileTimeEnvironment {
+    environment_index: usize,
+    bindings: Vec<(Sym, CompileTimeBinding)>,
+    function_scope: bool,
+}
+
+impl FrozenCompileTimeEnvironment {
+    /// Gets the names of the bindings.
+    pub(crate) fn names(&self) -> impl Iterator<Item = Sym> + '_ {
+        self.bindings.iter().map(|(name, _)| *name)
+    }
+}
+
 /// A compile time environment maps bound identifiers to their binding positions.
 ///
 /// A compile time environment also indicates, if it is a function environment.
@@ -39,6 +58,65 @@ impl CompileTimeEnvironment {
         }
     }
 
+    /// Creates an environment with the frozen bindings of another one, renaming them with
+    /// `rename`.
+    pub(crate) fn thaw(
+        frozen: &FrozenCompileTimeEnvironment,
+        outer: Option<Gc<Cell<Self>>>,
+        rename: impl Fn(Sym) -> Sym,
+    ) -> Self {
+        Self {
+            outer,
+            environment_index: frozen.environment_index,
+            bindings: frozen
+                .bindings
+                .iter()
+                .map(|(name, binding)| (rename(*name), *binding))
+                .collect(),
+            function_scope: frozen.function_scope,
+        }
+    }
+
+    /// Copies the bindings of this environment, ordered by their index.
+    pub(crate) fn freeze(&self) -> FrozenCompileTimeEnvironment {
+        let mut bindings: Vec<_> = self
+            .bindings
+            .iter()
+            .map(|(name, binding)| (*name, *binding))
+            .collect();
+        bindings.sort_unstable_by_key(|(_, binding)| binding.index);
+        FrozenCompileTimeEnvironment {
+            environment_index: self.environment_index,
+            bindings,
+            function_scope: self.function_scope,
+        }
+    }
+
+    /// Adds the frozen bindings of another environment after the bindings of this one, renaming
+    /// them with `rename`.
+    pub(crate) fn extend_frozen(
+        &mut self,
+        frozen: &FrozenCompileTimeEnvironment,
+        rename: impl Fn(Sym) -> Sym,
+    ) {
+        let offset = self.bindings.len();
+        for (name, binding) in &frozen.bindings {
+            self.bindings.insert(
+                rename(*name),
+                CompileTimeBinding {
+                    index: binding.index + offset,
+                    ..*binding
+                },
+            );
+        }
+    }
+
+    /// Gets the outer environment of this environment.
+    #[inline]
+    pub(crate) fn outer(&self) -> Option<Gc<Cell<Self>>> {
+        self.outer.clone()
+    }
+
     /// Check if environment has a lexical binding with the given name.
     #[inline]
     pub(crate) fn has_lex_binding(&self, name: Sym) -> bool {
@@ -47,12 +125,37 @@ impl CompileTimeEnvironment {
             .map_or(false, |binding| binding.lex)
     }
 
+    /// Check if this environment or any outer environment, up to and including the next function
+    /// environment, has a lexical binding with the given name.
+    #[inline]
+    pub(crate) fn has_lex_binding_until_function_environment(&self, name: Sym) -> bool {
+        if self.has_lex_binding(name) {
+            true
+        } else if self.function_scope {
+            false
+        } else if let Some(outer) = &self.outer {
+            outer
+                .borrow()
+                .has_lex_binding_until_function_environment(name)
+        } else {
+            false
+        }
+    }
+
     /// Returns the number of bindings in this environment.
     #[inline]
     pub(crate) fn num_bindings(&self) -> usize {
         self.bindings.len()
     }
 
+    /// Remove the bindings that were created after the environment had the given number of
+    /// bindings.
+    #[inline]
+    pub(crate) fn truncate(&mut self, num_bindings: usize) {
+        self.bindings
+            .retain(|_, binding| binding.index < num_bindings);
+    }
+
     /// Check if the environment is a function environment.
     #[inline]
     pub(crate) fn is_function(&self) -> bool {
@@ -274,6 +377,43 @@ impl Context {
         self.realm.compile_env.borrow().has_binding_recursive(name)
     }
 
+    /// Return if the current environment is a declarative environment, such as the environment
+    /// of a block, with a lexical binding of the given name.
+    ///
+    /// Note: This function only works at bytecode compile time!
+    #[inline]
+    pub(crate) fn has_block_lex_binding(&self, name: Sym) -> bool {
+        let env = self.realm.compile_env.borrow();
+        !env.is_function() && env.has_lex_binding(name)
+    }
+
+    /// Return if a lexical binding with the given name exists in the current environment or its
+    /// outer environments, up to and including the next function environment.
+    ///
+    /// Note: This function only works at bytecode compile time!
+    #[inline]
+    pub(crate) fn has_lex_binding_until_function_environment(&self, name: Sym) -> bool {
+        self.realm
+            .compile_env
+            .borrow()
+            .has_lex_binding_until_function_environment(name)
+    }
+
+    /// Return if a lexical binding with the given name exists in the outer environments of the
+    /// current environment, up to and including the next function environment.
+    ///
+    /// Note: This function only works at bytecode compile time!
+    #[inline]
+    pub(crate) fn has_outer_lex_binding_until_function_environment(&self, name: Sym) -> bool {
+        let env = self.realm.compile_env.borrow();
+        !env.is_function()
+            && env.outer.as_ref().map_or(false, |outer| {
+                outer
+                    .borrow()
+                    .has_lex_binding_until_function_environment(name)
+            })
+    }
+
     /// Create a mutable binding at bytecode compile time.
     /// This function returns a syntax error, if the binding is a redeclaration.
     ///
@@ -291,22 +431,29 @@ impl Context {
             .create_mutable_binding(name, function_scope)
         {
             let name_str = JsString::from(self.interner().resolve_expect(name));
-            let desc = self
-                .realm
-                .global_property_map
-                .string_property_map()
-                .get(&name_str);
-            if desc.is_none() {
-                self.global_bindings_mut().insert(
-                    name_str,
-                    PropertyDescriptor::builder()
-                        .value(JsValue::Undefined)
-                        .writable(true)
-                        .enumerable(true)
-                        .configurable(true)
-                        .build(),
-                );
-            }
+            self.create_global_var_property(name_str);
+        }
+    }
+
+    /// Create the property of the global object for a `var` or function declaration of a
+    /// script, unless it already exists.
+    #[inline]
+    pub(crate) fn create_global_var_property(&mut self, name: JsString) {
+        let desc = self
+            .realm
+            .global_property_map
+            .string_property_map()
+            .get(&name);
+        if desc.is_none() {
+            self.global_bindings_mut().insert(
+                name,
+                PropertyDescriptor::builder()
+                    .value(JsValue::Undefined)
+                    .writable(true)
+                    .enumerable(true)
+                    .configurable(true)
+                    .build(),
+            );
         }
     }
 
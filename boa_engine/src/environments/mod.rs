@@ -28,7 +28,7 @@ mod compile;
 mod runtime;
 
 pub(crate) use {
-    compile::CompileTimeEnvironment,
+    compile::{CompileTimeEnvironment, FrozenCompileTimeEnvironment},
     runtime::{
         BindingLocator, DeclarativeEnvironment, DeclarativeEnvironmentStack, EnvironmentSlots,
     },
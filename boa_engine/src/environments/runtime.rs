@@ -235,13 +235,29 @@ impl DeclarativeEnvironmentStack {
         }
     }
 
+    /// Get the compile time environment of the global environment.
+    #[inline]
+    pub(crate) fn global_compile_environment(&self) -> Gc<Cell<CompileTimeEnvironment>> {
+        self.stack
+            .first()
+            .expect("global environment must always exist")
+            .compile
+            .clone()
+    }
+
     /// Extends the length of the next outer function environment to the number of compiled bindings.
     ///
     /// This is only useful when compiled bindings are added after the initial compilation (eval).
     pub(crate) fn extend_outer_function_environment(&mut self) {
         for env in self.stack.iter().rev() {
-            if let Some(EnvironmentSlots::Function(_)) = env.slots {
-                let compile_bindings_number = env.compile.borrow().num_bindings();
+            // The scope created by `Context::eval_with_bindings` isn't a function environment at
+            // runtime, but it receives the `var` declarations of the code like one.
+            let compile = env.compile.borrow();
+            if compile.is_function() {
+                if let Some(EnvironmentSlots::Global) = env.slots {
+                    break;
+                }
+                let compile_bindings_number = compile.num_bindings();
                 let mut bindings_mut = env.bindings.borrow_mut();
 
                 if compile_bindings_number > bindings_mut.len() {
@@ -275,6 +291,18 @@ impl DeclarativeEnvironmentStack {
         None
     }
 
+    /// Check if the next outer function environment of the current environment is the global
+    /// environment, which receives the `var` declarations of sloppy direct `eval` code.
+    pub(crate) fn is_global_var_environment(&self) -> bool {
+        self.stack
+            .iter()
+            .rev()
+            .find(|env| env.compile.borrow().is_function())
+            .map_or(true, |env| {
+                matches!(env.slots, Some(EnvironmentSlots::Global))
+            })
+    }
+
     /// Pop all current environments except the global environment.
     pub(crate) fn pop_to_global(&mut self) -> Vec<Gc<DeclarativeEnvironment>> {
         self.stack.split_off(1)
@@ -444,6 +472,30 @@ impl DeclarativeEnvironmentStack {
         }));
     }
 
+    /// Replace the most outer environment with a copy of it.
+    ///
+    /// This implements `CreatePerIterationEnvironment`, so that closures created in an
+    /// iteration of a `for` loop keep the bindings of that iteration.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no environment exists on the stack.
+    #[inline]
+    pub(crate) fn copy_current(&mut self) {
+        let last = self
+            .stack
+            .last_mut()
+            .expect("global environment must always exist");
+
+        let copy = DeclarativeEnvironment {
+            bindings: Cell::new(last.bindings.borrow().clone()),
+            compile: last.compile.clone(),
+            poisoned: Cell::new(*last.poisoned.borrow()),
+            slots: last.slots.clone(),
+        };
+        *last = Gc::new(copy);
+    }
+
     /// Pop environment from the environments stack.
     #[inline]
     pub(crate) fn pop(&mut self) -> Gc<DeclarativeEnvironment> {
@@ -481,18 +533,16 @@ impl DeclarativeEnvironmentStack {
 
     /// Mark that there may be added bindings in the current environment.
     ///
-    /// # Panics
-    ///
-    /// Panics if no environment exists on the stack.
+    /// The `var` declarations of the added bindings are created in the nearest function
+    /// environment, so all environments up to it are marked as well.
     #[inline]
     pub(crate) fn poison_current(&mut self) {
-        let mut poisoned = self
-            .stack
-            .last()
-            .expect("global environment must always exist")
-            .poisoned
-            .borrow_mut();
-        *poisoned = true;
+        for env in self.stack.iter().rev() {
+            *env.poisoned.borrow_mut() = true;
+            if env.compile.borrow().is_function() {
+                break;
+            }
+        }
     }
 
     /// Mark that there may be added binding in all environments.
@@ -791,6 +841,26 @@ impl BindingLocator {
         self.binding_index
     }
 
+    /// Renames the binding, and moves it `global_offset` positions forward if it is a lexical
+    /// binding of the global environment.
+    ///
+    /// This adapts the binding to a context where the global environment has `global_offset`
+    /// more lexical bindings than the one where it was compiled.
+    #[inline]
+    pub(crate) fn relocate(self, name: Sym, global_offset: usize) -> Self {
+        let binding_index = if self.environment_index == 0 && !self.global && !self.mutate_immutable
+        {
+            self.binding_index + global_offset
+        } else {
+            self.binding_index
+        };
+        Self {
+            name,
+            binding_index,
+            ..self
+        }
+    }
+
     /// Helper method to throws an error if the binding access is illegal.
     #[inline]
     pub(crate) fn throw_mutate_immutable(&self, context: &mut Context) -> JsResult<()> {
@@ -1,4 +1,4 @@
-use crate::exec;
+use crate::{exec, forward, Context};
 
 #[test]
 fn let_is_block_scoped() {
@@ -90,3 +90,170 @@ fn set_outer_let_in_block_scope() {
 
     assert_eq!(&exec(scenario), "true");
 }
+
+#[test]
+fn global_lexical_redeclaration_across_scripts() {
+    let mut context = Context::default();
+
+    assert_eq!(forward(&mut context, "let a = 1;"), "undefined");
+    assert_eq!(
+        forward(&mut context, "let a = 2;"),
+        "Uncaught \"SyntaxError\": \"Identifier 'a' has already been declared\""
+    );
+    assert_eq!(
+        forward(&mut context, "var a = 3;"),
+        "Uncaught \"SyntaxError\": \"Identifier 'a' has already been declared\""
+    );
+    assert_eq!(
+        forward(&mut context, "function a() {}"),
+        "Uncaught \"SyntaxError\": \"Identifier 'a' has already been declared\""
+    );
+    assert_eq!(
+        forward(&mut context, "class a {}"),
+        "Uncaught \"SyntaxError\": \"Identifier 'a' has already been declared\""
+    );
+    assert_eq!(forward(&mut context, "a"), "1");
+
+    assert_eq!(forward(&mut context, "var b = 1;"), "undefined");
+    assert_eq!(
+        forward(&mut context, "let b = 2;"),
+        "Uncaught \"SyntaxError\": \"Identifier 'b' has already been declared\""
+    );
+    assert_eq!(forward(&mut context, "var b = 3; b"), "3");
+
+    // Properties created by assignments are not var declarations, so they can be shadowed.
+    assert_eq!(forward(&mut context, "c = 1;"), "1");
+    assert_eq!(forward(&mut context, "let c = 2; c"), "2");
+    assert_eq!(forward(&mut context, "globalThis.c"), "1");
+}
+
+#[test]
+fn global_function_redeclaration_across_scripts() {
+    let mut context = Context::default();
+
+    assert_eq!(
+        forward(&mut context, "function f() { return 1; }"),
+        "undefined"
+    );
+    assert_eq!(
+        forward(&mut context, "function f() { return 2; }"),
+        "undefined"
+    );
+    assert_eq!(forward(&mut context, "f()"), "2");
+    assert_eq!(
+        forward(&mut context, "let f = 3;"),
+        "Uncaught \"SyntaxError\": \"Identifier 'f' has already been declared\""
+    );
+
+    assert_eq!(forward(&mut context, "{ function g() {} }"), "undefined");
+    assert_eq!(
+        forward(&mut context, "let g = 1;"),
+        "Uncaught \"SyntaxError\": \"Identifier 'g' has already been declared\""
+    );
+}
+
+#[test]
+fn global_declarations_on_restricted_properties() {
+    let mut context = Context::default();
+
+    assert_eq!(
+        forward(&mut context, "let NaN = 1;"),
+        "Uncaught \"SyntaxError\": \"cannot redeclare non-configurable global property 'NaN'\""
+    );
+    assert_eq!(
+        forward(&mut context, "function NaN() {}"),
+        "Uncaught \"TypeError\": \"cannot declare global function 'NaN'\""
+    );
+    assert_eq!(forward(&mut context, "var NaN; typeof NaN"), "\"number\"");
+    assert_eq!(forward(&mut context, "function Array() {}"), "undefined");
+
+    assert_eq!(
+        forward(&mut context, "Object.preventExtensions(globalThis); 0"),
+        "0"
+    );
+    assert_eq!(
+        forward(&mut context, "var v;"),
+        "Uncaught \"TypeError\": \"cannot declare global variable 'v'\""
+    );
+    assert_eq!(
+        forward(&mut context, "function h() {}"),
+        "Uncaught \"TypeError\": \"cannot declare global function 'h'\""
+    );
+    assert_eq!(forward(&mut context, "let l = 1; l"), "1");
+}
+
+#[test]
+fn failed_global_declaration_instantiation_creates_no_bindings() {
+    let mut context = Context::default();
+
+    assert_eq!(forward(&mut context, "let taken = 1;"), "undefined");
+    assert_eq!(
+        forward(
+            &mut context,
+            "let x = 1; var y; function z() {} let taken = 2;"
+        ),
+        "Uncaught \"SyntaxError\": \"Identifier 'taken' has already been declared\""
+    );
+    assert_eq!(
+        forward(&mut context, "let x = 1; var y; function NaN() {}"),
+        "Uncaught \"TypeError\": \"cannot declare global function 'NaN'\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "let x = 1; var y; function z() {} while (true) { continue undeclared; }"
+        ),
+        "Uncaught \"SyntaxError\": \"Cannot use the undeclared label 'undeclared'\""
+    );
+    assert_eq!(
+        forward(&mut context, "[typeof x, typeof y, typeof z].join()"),
+        "\"undefined,undefined,undefined\""
+    );
+    assert_eq!(
+        forward(&mut context, "let x = 2; let y = 3; let z = 4; x + y + z"),
+        "9"
+    );
+}
+
+#[test]
+fn global_declarations_of_eval_code() {
+    let mut context = Context::default();
+
+    assert_eq!(forward(&mut context, "let a = 1;"), "undefined");
+    assert_eq!(forward(&mut context, "eval('let a = 2; a')"), "2");
+    assert_eq!(forward(&mut context, "(0, eval)('let a = 3; a')"), "3");
+    assert_eq!(
+        forward(&mut context, "(0, eval)('var a = 4;')"),
+        "Uncaught \"SyntaxError\": \"Identifier 'a' has already been declared\""
+    );
+    assert_eq!(
+        forward(&mut context, "(0, eval)('\"use strict\"; var a = 5; a')"),
+        "5"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "(function() { eval('var a = 6'); return a; })()"
+        ),
+        "6"
+    );
+    assert_eq!(forward(&mut context, "a"), "1");
+
+    assert_eq!(
+        forward(&mut context, "(0, eval)('var b = 1');"),
+        "undefined"
+    );
+    assert_eq!(
+        forward(&mut context, "let b = 2;"),
+        "Uncaught \"SyntaxError\": \"Identifier 'b' has already been declared\""
+    );
+
+    // Running direct `eval` in a function must not change where the next script declares its
+    // bindings.
+    assert_eq!(
+        forward(&mut context, "function f() { let c = 1; eval(''); } f();"),
+        "undefined"
+    );
+    assert_eq!(forward(&mut context, "let d = 7;"), "undefined");
+    assert_eq!(forward(&mut context, "(function() { return d; })()"), "7");
+}
@@ -0,0 +1,535 @@
+//! Error types of the engine.
+//!
+//! A [`JsError`] is the error of a [`JsResult`][crate::JsResult]. It is either a value thrown by
+//! JavaScript code, or a [`JsNativeError`].
+//!
+//! A [`JsNativeError`] describes one of the error types of the specification, and can be created
+//! without a [`Context`]. It is converted into an error object of the right type, with the
+//! intrinsic prototype of its constructor, only once it has to be observed by JavaScript code.
+//!
+//! A [`JsStructuredError`] goes the other way: it describes a value thrown by JavaScript code,
+//! so that it can be logged or passed to a host without running any more code of the script.
+
+use crate::{
+    bigint::TryFromF64Error,
+    context::intrinsics::{StandardConstructor, StandardConstructors},
+    object::{JsObject, ObjectData},
+    property::PropertyKey,
+    syntax::parser::ParseError,
+    Context, JsValue,
+};
+use boa_gc::{Finalize, Trace};
+#[cfg(feature = "deser")]
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// The error of a [`JsResult`][crate::JsResult].
+///
+/// This is either an opaque value thrown by JavaScript code, which can be any value, or a
+/// [`JsNativeError`] created by the engine, which is only turned into an error object when it
+/// is caught by JavaScript code or converted with [`JsError::to_opaque`].
+///
+/// Native errors, and errors that convert into them, like parse errors, can be returned with the
+/// `?` operator.
+///
+/// # Examples
+///
+/// ```
+/// use boa_engine::{Context, JsError, JsNativeError, JsValue};
+///
+/// let mut context = Context::default();
+///
+/// let error: JsError = JsNativeError::typ().with_message("not a function").into();
+/// assert_eq!(error.as_native().unwrap().message(), "not a function");
+/// assert_eq!(error.to_string(), "TypeError: not a function");
+///
+/// let error = context.eval("throw 5").unwrap_err();
+/// assert_eq!(error.as_opaque(), Some(&JsValue::new(5)));
+/// ```
+#[derive(Debug, Clone, PartialEq, Trace, Finalize)]
+pub struct JsError {
+    inner: Repr,
+}
+
+/// The internal representation of a [`JsError`].
+#[derive(Debug, Clone, PartialEq, Trace, Finalize)]
+enum Repr {
+    Native(JsNativeError),
+    Opaque(JsValue),
+}
+
+impl JsError {
+    /// Creates an error from a native error.
+    #[inline]
+    pub fn from_native(error: JsNativeError) -> Self {
+        Self {
+            inner: Repr::Native(error),
+        }
+    }
+
+    /// Creates an error from a value thrown by JavaScript code.
+    #[inline]
+    pub fn from_opaque(value: JsValue) -> Self {
+        Self {
+            inner: Repr::Opaque(value),
+        }
+    }
+
+    /// Converts the error into the value that is thrown to JavaScript code.
+    ///
+    /// A native error is turned into a new error object every time this is called, so it should
+    /// be called once, when the error is caught.
+    pub fn to_opaque(&self, context: &mut Context) -> JsValue {
+        match &self.inner {
+            Repr::Native(error) => error.to_opaque(context).into(),
+            Repr::Opaque(value) => value.clone(),
+        }
+    }
+
+    /// Gets the thrown value, if this error was thrown by JavaScript code or already converted
+    /// into a value.
+    #[inline]
+    pub fn as_opaque(&self) -> Option<&JsValue> {
+        match &self.inner {
+            Repr::Native(_) => None,
+            Repr::Opaque(value) => Some(value),
+        }
+    }
+
+    /// Gets the native error, if this error was created by the engine.
+    #[inline]
+    pub fn as_native(&self) -> Option<&JsNativeError> {
+        match &self.inner {
+            Repr::Native(error) => Some(error),
+            Repr::Opaque(_) => None,
+        }
+    }
+}
+
+impl fmt::Display for JsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.inner {
+            Repr::Native(error) => error.fmt(f),
+            Repr::Opaque(value) => value.display().fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for JsError {}
+
+impl<E> From<E> for JsError
+where
+    E: Into<JsNativeError>,
+{
+    #[inline]
+    fn from(error: E) -> Self {
+        Self::from_native(error.into())
+    }
+}
+
+/// The types of native errors.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-native-error-types-used-in-this-standard
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JsNativeErrorKind {
+    /// An `Error`.
+    Error,
+
+    /// An `EvalError`.
+    Eval,
+
+    /// A `RangeError`.
+    Range,
+
+    /// A `ReferenceError`.
+    Reference,
+
+    /// A `SyntaxError`.
+    Syntax,
+
+    /// A `TypeError`.
+    Type,
+
+    /// A `URIError`.
+    Uri,
+}
+
+impl JsNativeErrorKind {
+    /// Gets the standard constructor of this error type.
+    fn constructor(self) -> fn(&StandardConstructors) -> &StandardConstructor {
+        match self {
+            Self::Error => StandardConstructors::error,
+            Self::Eval => StandardConstructors::eval_error,
+            Self::Range => StandardConstructors::range_error,
+            Self::Reference => StandardConstructors::reference_error,
+            Self::Syntax => StandardConstructors::syntax_error,
+            Self::Type => StandardConstructors::type_error,
+            Self::Uri => StandardConstructors::uri_error,
+        }
+    }
+}
+
+impl fmt::Display for JsNativeErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Error => "Error",
+            Self::Eval => "EvalError",
+            Self::Range => "RangeError",
+            Self::Reference => "ReferenceError",
+            Self::Syntax => "SyntaxError",
+            Self::Type => "TypeError",
+            Self::Uri => "URIError",
+        })
+    }
+}
+
+/// A native error, which is thrown as an error object of its type.
+///
+/// # Examples
+///
+/// ```
+/// use boa_engine::{Context, JsNativeError, JsResult, JsValue};
+///
+/// fn check_positive(value: f64) -> JsResult<JsValue> {
+///     if value < 0.0 {
+///         return Err(JsNativeError::range()
+///             .with_message("the value must be positive")
+///             .into());
+///     }
+///     Ok(value.into())
+/// }
+///
+/// let mut context = Context::default();
+/// let error = check_positive(-1.0).unwrap_err();
+/// assert_eq!(
+///     error.to_opaque(&mut context).to_string(&mut context).unwrap(),
+///     "RangeError: the value must be positive"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Trace, Finalize)]
+pub struct JsNativeError {
+    #[unsafe_ignore_trace]
+    kind: JsNativeErrorKind,
+    #[unsafe_ignore_trace]
+    message: Box<str>,
+    cause: Option<JsValue>,
+}
+
+impl JsNativeError {
+    /// Creates a new error of the given type, without a message.
+    #[inline]
+    pub fn new(kind: JsNativeErrorKind) -> Self {
+        Self {
+            kind,
+            message: "".into(),
+            cause: None,
+        }
+    }
+
+    /// Creates a new `Error`.
+    #[inline]
+    pub fn error() -> Self {
+        Self::new(JsNativeErrorKind::Error)
+    }
+
+    /// Creates a new `EvalError`.
+    #[inline]
+    pub fn eval() -> Self {
+        Self::new(JsNativeErrorKind::Eval)
+    }
+
+    /// Creates a new `RangeError`.
+    #[inline]
+    pub fn range() -> Self {
+        Self::new(JsNativeErrorKind::Range)
+    }
+
+    /// Creates a new `ReferenceError`.
+    #[inline]
+    pub fn reference() -> Self {
+        Self::new(JsNativeErrorKind::Reference)
+    }
+
+    /// Creates a new `SyntaxError`.
+    #[inline]
+    pub fn syntax() -> Self {
+        Self::new(JsNativeErrorKind::Syntax)
+    }
+
+    /// Creates a new `TypeError`.
+    #[inline]
+    pub fn typ() -> Self {
+        Self::new(JsNativeErrorKind::Type)
+    }
+
+    /// Creates a new `URIError`.
+    #[inline]
+    pub fn uri() -> Self {
+        Self::new(JsNativeErrorKind::Uri)
+    }
+
+    /// Sets the message of the error.
+    #[must_use]
+    #[inline]
+    pub fn with_message<M>(mut self, message: M) -> Self
+    where
+        M: Into<Box<str>>,
+    {
+        self.message = message.into();
+        self
+    }
+
+    /// Sets the `cause` of the error, which is usually the error that caused it to be thrown.
+    #[must_use]
+    #[inline]
+    pub fn with_cause<V>(mut self, cause: V) -> Self
+    where
+        V: Into<JsValue>,
+    {
+        self.cause = Some(cause.into());
+        self
+    }
+
+    /// Gets the type of the error.
+    #[inline]
+    pub fn kind(&self) -> JsNativeErrorKind {
+        self.kind
+    }
+
+    /// Gets the message of the error.
+    #[inline]
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Gets the `cause` of the error.
+    #[inline]
+    pub fn cause(&self) -> Option<&JsValue> {
+        self.cause.as_ref()
+    }
+
+    /// Creates the error object for this error.
+    ///
+    /// The error object uses the intrinsic prototype of its constructor, so it is not affected by
+    /// scripts that modify the global constructors.
+    pub fn to_opaque(&self, context: &mut Context) -> JsObject {
+        let prototype = (self.kind.constructor())(context.intrinsics().constructors()).prototype();
+        let object = JsObject::from_proto_and_data(prototype, ObjectData::error());
+
+        object.create_non_enumerable_data_property_or_throw("message", &*self.message);
+        if let Some(cause) = &self.cause {
+            object.create_non_enumerable_data_property_or_throw("cause", cause.clone());
+        }
+        object
+    }
+}
+
+impl fmt::Display for JsNativeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.message.is_empty() {
+            write!(f, "{}", self.kind)
+        } else {
+            write!(f, "{}: {}", self.kind, self.message)
+        }
+    }
+}
+
+impl std::error::Error for JsNativeError {}
+
+impl From<ParseError> for JsNativeError {
+    #[inline]
+    fn from(error: ParseError) -> Self {
+        Self::syntax().with_message(error.to_string())
+    }
+}
+
+impl From<TryFromF64Error> for JsNativeError {
+    #[inline]
+    fn from(error: TryFromF64Error) -> Self {
+        Self::range().with_message(error.to_string())
+    }
+}
+
+/// A description of a thrown value, extracted without running any JavaScript code.
+///
+/// The `name`, `message` and `stack` of an error are read from data properties of the error or
+/// its prototypes, and its `cause` from an own data property. Accessor properties are ignored
+/// instead of being called, and proxies are never inspected through their traps, so the
+/// extraction can't be observed or interfered with by the script that threw the value.
+///
+/// # Examples
+///
+/// ```
+/// use boa_engine::{error::JsStructuredError, Context};
+///
+/// let mut context = Context::default();
+/// let error = context
+///     .eval("throw new TypeError('outer', { cause: new RangeError('inner') })")
+///     .unwrap_err();
+///
+/// let error = JsStructuredError::from_error(&error);
+/// assert!(error.is_error());
+/// assert_eq!(error.to_string(), "TypeError: outer");
+/// assert_eq!(error.cause().unwrap().to_string(), "RangeError: inner");
+/// ```
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct JsStructuredError {
+    is_error: bool,
+    name: Option<String>,
+    message: Option<String>,
+    stack: Option<String>,
+    cause: Option<Box<Self>>,
+}
+
+impl JsStructuredError {
+    /// Extracts the description of an error.
+    ///
+    /// Native errors are described from their kind, message and cause, without creating an
+    /// error object for them.
+    pub fn from_error(error: &JsError) -> Self {
+        match &error.inner {
+            Repr::Native(error) => Self {
+                is_error: true,
+                name: Some(error.kind().to_string()),
+                message: Some(error.message().to_owned()),
+                stack: None,
+                cause: error
+                    .cause()
+                    .map(|cause| Box::new(Self::from_thrown(cause))),
+            },
+            Repr::Opaque(value) => Self::from_thrown(value),
+        }
+    }
+
+    /// Extracts the description of a thrown value.
+    ///
+    /// Values that aren't objects are described by their `message` only. The extraction stops
+    /// at the first `cause` that was already visited, so cyclic causes are tolerated.
+    pub fn from_thrown(value: &JsValue) -> Self {
+        Self::from_value(value, &mut Vec::new())
+    }
+
+    /// Extracts the description of `value`, keeping track of the objects already visited.
+    fn from_value(value: &JsValue, visited: &mut Vec<JsObject>) -> Self {
+        let object = if let Some(object) = value.as_object() {
+            object
+        } else {
+            return Self {
+                message: Some(value_to_string(value)),
+                ..Self::default()
+            };
+        };
+        visited.push(object.clone());
+
+        let cause = own_data_property(object, "cause")
+            .filter(|cause| {
+                cause.as_object().map_or(true, |cause| {
+                    !visited.iter().any(|v| JsObject::equals(v, cause))
+                })
+            })
+            .map(|cause| Box::new(Self::from_value(&cause, visited)));
+
+        Self {
+            is_error: object.try_borrow().map_or(false, |o| o.is_error()),
+            name: data_property(object, "name").map(|name| value_to_string(&name)),
+            message: data_property(object, "message").map(|message| value_to_string(&message)),
+            stack: data_property(object, "stack").map(|stack| value_to_string(&stack)),
+            cause,
+        }
+    }
+
+    /// Returns `true` if the thrown value was an error object, with an `[[ErrorData]]` internal
+    /// slot.
+    #[inline]
+    pub fn is_error(&self) -> bool {
+        self.is_error
+    }
+
+    /// Gets the `name` of the error, if it was found.
+    #[inline]
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Gets the `message` of the error, if it was found.
+    ///
+    /// If the thrown value wasn't an object, this is the value itself.
+    #[inline]
+    pub fn message(&self) -> Option<&str> {
+        self.message.as_deref()
+    }
+
+    /// Gets the `stack` of the error, if it was found.
+    #[inline]
+    pub fn stack(&self) -> Option<&str> {
+        self.stack.as_deref()
+    }
+
+    /// Gets the description of the `cause` of the error, if it has one.
+    #[inline]
+    pub fn cause(&self) -> Option<&Self> {
+        self.cause.as_deref()
+    }
+}
+
+impl fmt::Display for JsStructuredError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.name(), self.message()) {
+            (Some(name), Some(message)) if !name.is_empty() && !message.is_empty() => {
+                write!(f, "{name}: {message}")
+            }
+            (Some(name), Some("") | None) => f.write_str(name),
+            (_, Some(message)) => f.write_str(message),
+            (None, None) => f.write_str("Error"),
+        }
+    }
+}
+
+/// Reads the value of an own data property of `object`, without calling any getter or trap.
+fn own_data_property<K>(object: &JsObject, key: K) -> Option<JsValue>
+where
+    K: Into<PropertyKey>,
+{
+    let object = object.try_borrow().ok()?;
+    let descriptor = object.properties().get(&key.into())?;
+    descriptor.value().cloned()
+}
+
+/// Reads the value of a data property of `object` or its prototypes, without calling any getter
+/// or trap.
+///
+/// Returns `None` if the property is an accessor, or if it isn't found before the end of the
+/// prototype chain or a proxy.
+fn data_property<K>(object: &JsObject, key: K) -> Option<JsValue>
+where
+    K: Into<PropertyKey>,
+{
+    let key = key.into();
+    let mut object = object.clone();
+    loop {
+        let prototype = {
+            let borrowed = object.try_borrow().ok()?;
+            if borrowed.is_proxy() {
+                return None;
+            }
+            if let Some(descriptor) = borrowed.properties().get(&key) {
+                return descriptor.value().cloned();
+            }
+            borrowed.prototype().clone()?
+        };
+        object = prototype;
+    }
+}
+
+/// Converts a property value to a string without calling any of its methods.
+///
+/// Strings are used as they are, and other values, including objects, use their display form.
+fn value_to_string(value: &JsValue) -> String {
+    value
+        .as_string()
+        .map_or_else(|| value.display().to_string(), ToString::to_string)
+}
@@ -10,21 +10,42 @@ use gc::{Finalize, Trace};
 #[derive(Debug, Clone, Trace, Finalize)]
 pub struct JobCallback {
     callback: JsObject,
+    host_defined: JsValue,
 }
 
 impl JobCallback {
+    /// Creates a new `JobCallback` record with the given `[[Callback]]` and `[[HostDefined]]`
+    /// fields.
+    pub fn new(callback: JsObject, host_defined: JsValue) -> Self {
+        Self {
+            callback,
+            host_defined,
+        }
+    }
+
+    /// Gets the `[[Callback]]` field of the record.
+    pub fn callback(&self) -> &JsObject {
+        &self.callback
+    }
+
+    /// Gets the `[[HostDefined]]` field of the record.
+    pub fn host_defined(&self) -> &JsValue {
+        &self.host_defined
+    }
+
     /// `HostMakeJobCallback ( callback )`
     ///
     /// The host-defined abstract operation `HostMakeJobCallback` takes argument `callback` (a
     /// function object) and returns a `JobCallback` Record.
     ///
+    /// This calls [`HostHooks::make_job_callback`][crate::context::HostHooks::make_job_callback].
+    ///
     /// More information:
     ///  - [ECMAScript reference][spec]
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-hostmakejobcallback
-    pub fn make_job_callback(callback: JsObject) -> Self {
-        // 1. Return the JobCallback Record { [[Callback]]: callback, [[HostDefined]]: empty }.
-        Self { callback }
+    pub fn make_job_callback(callback: JsObject, context: &mut Context) -> Self {
+        context.host_hooks().make_job_callback(callback, context)
     }
 
     /// `HostCallJobCallback ( jobCallback, V, argumentsList )`
@@ -34,6 +55,8 @@ impl JobCallback {
     /// ECMAScript language values) and returns either a normal completion containing an ECMAScript
     /// language value or a throw completion.
     ///
+    /// This calls [`HostHooks::call_job_callback`][crate::context::HostHooks::call_job_callback].
+    ///
     /// More information:
     ///  - [ECMAScript reference][spec]
     ///
@@ -44,15 +67,8 @@ impl JobCallback {
         arguments_list: &[JsValue],
         context: &mut Context,
     ) -> JsResult<JsValue> {
-        // It must perform and return the result of Call(jobCallback.[[Callback]], V, argumentsList).
-
-        // 1. Assert: IsCallable(jobCallback.[[Callback]]) is true.
-        assert!(
-            self.callback.is_callable(),
-            "the callback of the job callback was not callable"
-        );
-
-        // 2. Return ? Call(jobCallback.[[Callback]], V, argumentsList).
-        self.callback.__call__(v, arguments_list, context)
+        context
+            .host_hooks()
+            .call_job_callback(self, v, arguments_list, context)
     }
 }
@@ -81,6 +81,7 @@ pub mod bytecompiler;
 pub mod class;
 pub mod context;
 pub mod environments;
+pub mod error;
 pub mod job;
 pub mod object;
 pub mod property;
@@ -96,7 +97,9 @@ mod tests;
 
 /// A convenience module that re-exports the most commonly-used Boa APIs
 pub mod prelude {
-    pub use crate::{object::JsObject, Context, JsBigInt, JsResult, JsString, JsValue};
+    pub use crate::{
+        builtins::JsArgs, object::JsObject, Context, JsBigInt, JsResult, JsString, JsValue,
+    };
 }
 
 use std::result::Result as StdResult;
@@ -104,11 +107,16 @@ use std::result::Result as StdResult;
 // Export things to root level
 #[doc(inline)]
 pub use crate::{
-    bigint::JsBigInt, context::Context, string::JsString, symbol::JsSymbol, value::JsValue,
+    bigint::JsBigInt,
+    context::Context,
+    error::{JsError, JsNativeError},
+    string::JsString,
+    symbol::JsSymbol,
+    value::JsValue,
 };
 
 /// The result of a Javascript expression is represented like this so it can succeed (`Ok`) or fail (`Err`)
-pub type JsResult<T> = StdResult<T, JsValue>;
+pub type JsResult<T> = StdResult<T, JsError>;
 
 /// Execute the code using an existing `Context`.
 ///
@@ -118,10 +126,10 @@ pub(crate) fn forward<S>(context: &mut Context, src: S) -> String
 where
     S: AsRef<[u8]>,
 {
-    context.eval(src.as_ref()).map_or_else(
-        |e| format!("Uncaught {}", e.display()),
-        |v| v.display().to_string(),
-    )
+    match context.eval(src.as_ref()) {
+        Ok(value) => value.display().to_string(),
+        Err(error) => format!("Uncaught {}", error.to_opaque(context).display()),
+    }
 }
 
 /// Execute the code using an existing Context.
@@ -150,9 +158,10 @@ pub(crate) fn forward_val<T: AsRef<[u8]>>(context: &mut Context, src: T) -> JsRe
 pub(crate) fn exec<T: AsRef<[u8]>>(src: T) -> String {
     let src_bytes: &[u8] = src.as_ref();
 
-    match Context::default().eval(src_bytes) {
+    let mut context = Context::default();
+    match context.eval(src_bytes) {
         Ok(value) => value.display().to_string(),
-        Err(error) => error.display().to_string(),
+        Err(error) => error.to_opaque(&mut context).display().to_string(),
     }
 }
 
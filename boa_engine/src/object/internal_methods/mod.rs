@@ -488,6 +488,11 @@ pub(crate) fn ordinary_define_own_property(
     // 2. Let extensible be ? IsExtensible(O).
     let extensible = obj.__is_extensible__(context)?;
 
+    if current.is_none() && extensible {
+        let count = obj.borrow().properties().len() + 1;
+        check_property_count(count, context)?;
+    }
+
     // 3. Return ValidateAndApplyPropertyDescriptor(O, P, extensible, Desc, current).
     Ok(validate_and_apply_property_descriptor(
         Some((obj, key)),
@@ -497,6 +502,19 @@ pub(crate) fn ordinary_define_own_property(
     ))
 }
 
+/// Throws a `RangeError` if an object with `count` own properties exceeds the maximum property
+/// count of the [`ResourceLimits`][crate::context::ResourceLimits].
+#[inline]
+pub(crate) fn check_property_count(count: usize, context: &mut Context) -> JsResult<()> {
+    let max_count = context.resource_limits().max_property_count();
+    if count > max_count {
+        return context.throw_range_error(format!(
+            "object exceeds the maximum property count ({max_count})"
+        ));
+    }
+    Ok(())
+}
+
 /// Abstract operation `OrdinaryHasProperty`.
 ///
 /// More information:
@@ -807,8 +825,8 @@ pub(crate) fn validate_and_apply_property_descriptor(
         // b. Assert: extensible is true.
 
         if let Some((obj, key)) = obj_and_key {
-            obj.borrow_mut().properties.insert(
-                &key,
+            obj.borrow_mut().insert(
+                key,
                 // c. If IsGenericDescriptor(Desc) is true or IsDataDescriptor(Desc) is true, then
                 if desc.is_generic_descriptor() || desc.is_data_descriptor() {
                     // i. If O is not undefined, create an own data property named P of
@@ -925,7 +943,7 @@ pub(crate) fn validate_and_apply_property_descriptor(
         // a. For each field of Desc that is present, set the corresponding attribute of the
         // property named P of object O to the value of the field.
         current.fill_with(&desc);
-        obj.borrow_mut().properties.insert(&key, current);
+        obj.borrow_mut().insert(key, current);
     }
 
     // 10. Return true.
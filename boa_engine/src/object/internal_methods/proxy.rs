@@ -3,7 +3,7 @@ use crate::{
     object::{InternalObjectMethods, JsObject, JsPrototype},
     property::{PropertyDescriptor, PropertyKey},
     value::Type,
-    Context, JsResult, JsValue,
+    Context, JsNativeError, JsResult, JsValue,
 };
 use rustc_hash::FxHashSet;
 
@@ -61,7 +61,7 @@ pub(crate) fn proxy_exotic_get_prototype_of(
         .borrow()
         .as_proxy()
         .expect("Proxy object internal internal method called on non-proxy object")
-        .try_data(context)?;
+        .try_data()?;
 
     // 5. Let trap be ? GetMethod(handler, "getPrototypeOf").
     let trap = if let Some(trap) = handler.get_method("getPrototypeOf", context)? {
@@ -120,7 +120,7 @@ pub(crate) fn proxy_exotic_set_prototype_of(
         .borrow()
         .as_proxy()
         .expect("Proxy object internal internal method called on non-proxy object")
-        .try_data(context)?;
+        .try_data()?;
 
     // 5. Let trap be ? GetMethod(handler, "setPrototypeOf").
     let trap = if let Some(trap) = handler.get_method("setPrototypeOf", context)? {
@@ -181,7 +181,7 @@ pub(crate) fn proxy_exotic_is_extensible(obj: &JsObject, context: &mut Context)
         .borrow()
         .as_proxy()
         .expect("Proxy object internal internal method called on non-proxy object")
-        .try_data(context)?;
+        .try_data()?;
 
     // 5. Let trap be ? GetMethod(handler, "isExtensible").
     let trap = if let Some(trap) = handler.get_method("isExtensible", context)? {
@@ -228,7 +228,7 @@ pub(crate) fn proxy_exotic_prevent_extensions(
         .borrow()
         .as_proxy()
         .expect("Proxy object internal internal method called on non-proxy object")
-        .try_data(context)?;
+        .try_data()?;
 
     // 5. Let trap be ? GetMethod(handler, "preventExtensions").
     let trap = if let Some(trap) = handler.get_method("preventExtensions", context)? {
@@ -275,7 +275,7 @@ pub(crate) fn proxy_exotic_get_own_property(
         .borrow()
         .as_proxy()
         .expect("Proxy object internal internal method called on non-proxy object")
-        .try_data(context)?;
+        .try_data()?;
 
     // 5. Let trap be ? GetMethod(handler, "getOwnPropertyDescriptor").
     let trap = if let Some(trap) = handler.get_method("getOwnPropertyDescriptor", context)? {
@@ -394,7 +394,7 @@ pub(crate) fn proxy_exotic_define_own_property(
         .borrow()
         .as_proxy()
         .expect("Proxy object internal internal method called on non-proxy object")
-        .try_data(context)?;
+        .try_data()?;
 
     // 5. Let trap be ? GetMethod(handler, "defineProperty").
     let trap = if let Some(trap) = handler.get_method("defineProperty", context)? {
@@ -406,7 +406,7 @@ pub(crate) fn proxy_exotic_define_own_property(
     };
 
     // 7. Let descObj be FromPropertyDescriptor(Desc).
-    let desc_obj = Object::from_property_descriptor(Some(desc.clone()), context);
+    let desc_obj = Object::from_property_descriptor(Some(desc.clone()), context)?;
 
     // 8. Let booleanTrapResult be ! ToBoolean(? Call(trap, handler, « target, P, descObj »)).
     // 9. If booleanTrapResult is false, return false.
@@ -502,7 +502,7 @@ pub(crate) fn proxy_exotic_has_property(
         .borrow()
         .as_proxy()
         .expect("Proxy object internal internal method called on non-proxy object")
-        .try_data(context)?;
+        .try_data()?;
 
     // 5. Let trap be ? GetMethod(handler, "has").
     let trap = if let Some(trap) = handler.get_method("has", context)? {
@@ -567,7 +567,7 @@ pub(crate) fn proxy_exotic_get(
         .borrow()
         .as_proxy()
         .expect("Proxy object internal internal method called on non-proxy object")
-        .try_data(context)?;
+        .try_data()?;
 
     // 5. Let trap be ? GetMethod(handler, "get").
     let trap = if let Some(trap) = handler.get_method("get", context)? {
@@ -637,7 +637,7 @@ pub(crate) fn proxy_exotic_set(
         .borrow()
         .as_proxy()
         .expect("Proxy object internal internal method called on non-proxy object")
-        .try_data(context)?;
+        .try_data()?;
 
     // 5. Let trap be ? GetMethod(handler, "set").
     let trap = if let Some(trap) = handler.get_method("set", context)? {
@@ -653,7 +653,12 @@ pub(crate) fn proxy_exotic_set(
     if !trap
         .call(
             &handler.into(),
-            &[target.clone().into(), value.clone(), receiver],
+            &[
+                target.clone().into(),
+                key.clone().into(),
+                value.clone(),
+                receiver,
+            ],
             context,
         )?
         .to_boolean()
@@ -713,7 +718,7 @@ pub(crate) fn proxy_exotic_delete(
         .borrow()
         .as_proxy()
         .expect("Proxy object internal internal method called on non-proxy object")
-        .try_data(context)?;
+        .try_data()?;
 
     // 5. Let trap be ? GetMethod(handler, "deleteProperty").
     let trap = if let Some(trap) = handler.get_method("deleteProperty", context)? {
@@ -778,7 +783,7 @@ pub(crate) fn proxy_exotic_own_property_keys(
         .borrow()
         .as_proxy()
         .expect("Proxy object internal internal method called on non-proxy object")
-        .try_data(context)?;
+        .try_data()?;
 
     // 5. Let trap be ? GetMethod(handler, "ownKeys").
     let trap = if let Some(trap) = handler.get_method("ownKeys", context)? {
@@ -913,7 +918,7 @@ fn proxy_exotic_call(
         .borrow()
         .as_proxy()
         .expect("Proxy object internal internal method called on non-proxy object")
-        .try_data(context)?;
+        .try_data()?;
 
     // 5. Let trap be ? GetMethod(handler, "apply").
     let trap = if let Some(trap) = handler.get_method("apply", context)? {
@@ -925,7 +930,7 @@ fn proxy_exotic_call(
     };
 
     // 7. Let argArray be ! CreateArrayFromList(argumentsList).
-    let arg_array = array::Array::create_array_from_list(args.to_vec(), context);
+    let arg_array = array::Array::create_array_from_list(args.to_vec(), context)?;
 
     // 8. Return ? Call(trap, handler, « target, thisArgument, argArray »).
     trap.call(
@@ -955,7 +960,7 @@ fn proxy_exotic_construct(
         .borrow()
         .as_proxy()
         .expect("Proxy object internal internal method called on non-proxy object")
-        .try_data(context)?;
+        .try_data()?;
 
     // 5. Assert: IsConstructor(target) is true.
     assert!(target.is_constructor());
@@ -970,7 +975,7 @@ fn proxy_exotic_construct(
     };
 
     // 8. Let argArray be ! CreateArrayFromList(argumentsList).
-    let arg_array = array::Array::create_array_from_list(args.to_vec(), context);
+    let arg_array = array::Array::create_array_from_list(args.to_vec(), context)?;
 
     // 9. Let newObj be ? Call(trap, handler, « target, argArray, newTarget »).
     let new_obj = trap.call(
@@ -985,7 +990,7 @@ fn proxy_exotic_construct(
 
     // 10. If Type(newObj) is not Object, throw a TypeError exception.
     let new_obj = new_obj.as_object().cloned().ok_or_else(|| {
-        context.construct_type_error("Proxy trap constructor returned non-object value")
+        JsNativeError::typ().with_message("Proxy trap constructor returned non-object value")
     })?;
 
     // 11. Return newObj.
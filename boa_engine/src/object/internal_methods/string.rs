@@ -1,7 +1,7 @@
 use crate::{
     object::JsObject,
     property::{PropertyDescriptor, PropertyKey},
-    Context, JsResult, JsValue,
+    Context, JsResult, JsString, JsValue,
 };
 
 use super::{InternalObjectMethods, ORDINARY_INTERNAL_METHODS};
@@ -175,7 +175,7 @@ fn string_get_own_property(obj: &JsObject, key: &PropertyKey) -> Option<Property
     let result_str = string
         .encode_utf16()
         .nth(pos)
-        .map(|c| JsValue::from(String::from_utf16_lossy(&[c])))?;
+        .map(|c| JsValue::from(JsString::from_utf16(&[c])))?;
 
     // 13. Return the PropertyDescriptor { [[Value]]: resultStr, [[Writable]]: false, [[Enumerable]]: true, [[Configurable]]: false }.
     let desc = PropertyDescriptor::builder()
@@ -30,7 +30,7 @@ impl JsArray {
         I: IntoIterator<Item = JsValue>,
     {
         Self {
-            inner: Array::create_array_from_list(elements, context),
+            inner: Array::create_array_from_vec(elements.into_iter().collect(), context),
         }
     }
 
@@ -1,6 +1,9 @@
 use crate::{
     builtins::array_buffer::ArrayBuffer,
-    context::intrinsics::StandardConstructors,
+    context::{
+        intrinsics::StandardConstructors,
+        memory::{Allocation, MemoryKind},
+    },
     object::{
         internal_methods::get_prototype_from_constructor, JsObject, JsObjectType, ObjectData,
     },
@@ -67,6 +70,7 @@ impl JsArrayBuffer {
         // 3. Set obj.[[ArrayBufferData]] to block.
         // 4. Set obj.[[ArrayBufferByteLength]] to byteLength.
         obj.borrow_mut().data = ObjectData::array_buffer(ArrayBuffer {
+            allocation: Allocation::new(MemoryKind::ArrayBuffers, block.capacity()),
             array_buffer_data: Some(block),
             array_buffer_byte_length: byte_length as u64,
             array_buffer_detach_key: JsValue::Undefined,
@@ -1,6 +1,6 @@
 use crate::{
     object::{JsObject, JsObjectType},
-    JsValue,
+    Context, JsResult, JsValue,
 };
 use boa_gc::{Finalize, Trace};
 use std::ops::Deref;
@@ -26,6 +26,54 @@ impl JsFunction {
             .is_callable()
             .then(|| Self::from_object_unchecked(object))
     }
+
+    /// Calls this function with the given `this` value and arguments.
+    ///
+    /// Any error thrown by the function is returned as the `Err` variant.
+    #[inline]
+    pub fn call(
+        &self,
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        self.inner.call(this, args, context)
+    }
+
+    /// Calls this function with the given `this` value and the arguments yielded by `args`.
+    ///
+    /// This is the equivalent of `Function.prototype.apply`.
+    #[inline]
+    pub fn apply<I>(&self, this: &JsValue, args: I, context: &mut Context) -> JsResult<JsValue>
+    where
+        I: IntoIterator<Item = JsValue>,
+    {
+        let args: Vec<_> = args.into_iter().collect();
+        self.inner.call(this, &args, context)
+    }
+
+    /// Constructs an instance of this function with the given arguments.
+    ///
+    /// If `new_target` is `None`, the function itself is used as `new.target`.
+    ///
+    /// Throws a `TypeError` if this function is not a constructor.
+    #[inline]
+    pub fn construct(
+        &self,
+        args: &[JsValue],
+        new_target: Option<&JsObject>,
+        context: &mut Context,
+    ) -> JsResult<JsObject> {
+        if !self.inner.is_constructor() {
+            return context.throw_type_error("not a constructor");
+        }
+        if let Some(new_target) = new_target {
+            if !new_target.is_constructor() {
+                return context.throw_type_error("new target is not a constructor");
+            }
+        }
+        self.inner.construct(args, new_target, context)
+    }
 }
 
 impl From<JsFunction> for JsObject {
@@ -0,0 +1,288 @@
+//! This module implements a wrapper for the iterator protocol.
+use std::{fmt, ops::Deref};
+
+use boa_gc::{Finalize, Gc, Trace};
+
+use crate::{
+    builtins::iterable::{create_iter_result_object_or_throw, IteratorRecord},
+    builtins::JsArgs,
+    object::{FunctionBuilder, JsObject, JsObjectType, NativeObject, ObjectData},
+    property::PropertyDescriptor,
+    Context, JsResult, JsValue,
+};
+
+/// The `next` function of an iterator backed by Rust code, which receives its captures.
+type NextFn = Box<dyn FnMut(&mut dyn NativeObject, &mut Context) -> JsResult<Option<JsValue>>>;
+
+/// JavaScript iterator rust object.
+///
+/// A `JsIterator` can wrap any object implementing the iterator protocol, using
+/// [`JsIterator::from_object`], or expose a Rust iterator to JavaScript, using
+/// [`JsIterator::from_rust_iterator`] or [`JsIterator::from_fn`].
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct JsIterator {
+    record: IteratorRecord,
+}
+
+impl JsIterator {
+    /// Gets an iterator over the values of an iterable object, like an `Array`, a `Map` or a
+    /// generator.
+    ///
+    /// This throws a `TypeError` if the object is not iterable.
+    #[inline]
+    pub fn from_object(object: JsObject, context: &mut Context) -> JsResult<Self> {
+        let record = JsValue::from(object).get_iterator(context, None, None)?;
+        Ok(Self { record })
+    }
+
+    /// Creates a JavaScript iterator that yields the values of a Rust iterator.
+    ///
+    /// The returned object implements the iterator protocol, so it can be used by `for...of`,
+    /// the spread syntax, `Array.from` and any other consumer of iterables. The Rust iterator is
+    /// dropped once it is exhausted, once it returns an error, or when the consumer of the
+    /// iterator exits early and calls its `return` method.
+    ///
+    /// The iterator must be `Send`, which guarantees that it doesn't hold garbage collected values,
+    /// since the collector can't trace them. To iterate over such values, use
+    /// [`JsIterator::from_fn_with_captures`].
+    #[inline]
+    pub fn from_rust_iterator<I>(iterator: I, context: &mut Context) -> Self
+    where
+        I: Iterator<Item = JsResult<JsValue>> + Send + 'static,
+    {
+        let mut iterator = iterator;
+        Self::from_native(
+            Box::new(move |_, _| iterator.next().transpose()),
+            Box::new(()),
+            context,
+        )
+    }
+
+    /// Creates a JavaScript iterator that yields the values returned by the `next` closure.
+    ///
+    /// The iterator is done when `next` returns `Ok(None)`. See
+    /// [`JsIterator::from_rust_iterator`] for more information.
+    ///
+    /// The closure must be `Copy`, so it can't capture garbage collected values. To capture them,
+    /// use [`JsIterator::from_fn_with_captures`].
+    #[inline]
+    pub fn from_fn<F>(next: F, context: &mut Context) -> Self
+    where
+        F: FnMut(&mut Context) -> JsResult<Option<JsValue>> + Copy + 'static,
+    {
+        let mut next = next;
+        Self::from_native(
+            Box::new(move |_, context| next(context)),
+            Box::new(()),
+            context,
+        )
+    }
+
+    /// Creates a JavaScript iterator that yields the values returned by the `next` closure,
+    /// which receives a mutable reference to `captures`.
+    ///
+    /// The captures are traced by the garbage collector, so they can hold objects and other
+    /// garbage collected values. See [`JsIterator::from_fn`] for more information.
+    #[inline]
+    pub fn from_fn_with_captures<F, C>(next: F, captures: C, context: &mut Context) -> Self
+    where
+        F: Fn(&mut C, &mut Context) -> JsResult<Option<JsValue>> + Copy + 'static,
+        C: NativeObject,
+    {
+        Self::from_native(
+            Box::new(move |captures, context| {
+                let captures = captures
+                    .as_mut_any()
+                    .downcast_mut::<C>()
+                    .expect("the captures of the iterator must have the type of the closure");
+                next(captures, context)
+            }),
+            Box::new(captures),
+            context,
+        )
+    }
+
+    /// Creates a JavaScript iterator backed by the given `next` function and its captures.
+    fn from_native(next: NextFn, captures: Box<dyn NativeObject>, context: &mut Context) -> Self {
+        let state = NativeIteratorState(Gc::new(boa_gc::Cell::new(Some(NativeIterator {
+            next,
+            captures,
+        }))));
+
+        let next = FunctionBuilder::closure_with_captures(
+            context,
+            |_, _, state, context| {
+                // The closure is taken out of the state while it runs, so that a reentrant call
+                // to `next` or `return` doesn't try to borrow it again.
+                let iterator = state.0.borrow_mut().take();
+                let mut iterator = match iterator {
+                    Some(iterator) => iterator,
+                    None => {
+                        return create_iter_result_object_or_throw(
+                            JsValue::undefined(),
+                            true,
+                            context,
+                        )
+                    }
+                };
+                match (iterator.next)(&mut *iterator.captures, context)? {
+                    Some(value) => {
+                        *state.0.borrow_mut() = Some(iterator);
+                        create_iter_result_object_or_throw(value, false, context)
+                    }
+                    None => create_iter_result_object_or_throw(JsValue::undefined(), true, context),
+                }
+            },
+            state.clone(),
+        )
+        .name("next")
+        .length(0)
+        .build();
+
+        let r#return = FunctionBuilder::closure_with_captures(
+            context,
+            |_, args, state, context| {
+                let iterator = state.0.borrow_mut().take();
+                drop(iterator);
+                create_iter_result_object_or_throw(args.get_or_undefined(0).clone(), true, context)
+            },
+            state,
+        )
+        .name("return")
+        .length(0)
+        .build();
+
+        let prototype = context
+            .intrinsics()
+            .objects()
+            .iterator_prototypes()
+            .iterator_prototype();
+        let iterator = JsObject::from_proto_and_data(prototype, ObjectData::ordinary());
+        for (key, function) in [("next", next.clone()), ("return", r#return)] {
+            iterator.insert_property(
+                key,
+                PropertyDescriptor::builder()
+                    .value(function)
+                    .writable(true)
+                    .enumerable(false)
+                    .configurable(true),
+            );
+        }
+
+        Self {
+            record: IteratorRecord::new(iterator, next.into(), false),
+        }
+    }
+
+    /// Advances the iterator, returning its next value or `None` if it is done.
+    ///
+    /// Once the iterator is done or throws an error, it is not advanced again.
+    pub fn next(&mut self, context: &mut Context) -> JsResult<Option<JsValue>> {
+        if self.record.done() {
+            return Ok(None);
+        }
+
+        // The iterator is considered done if any of the steps throws.
+        self.record.set_done(true);
+        let result = match self.record.step(context)? {
+            Some(result) => result,
+            None => return Ok(None),
+        };
+        let value = result.value(context)?;
+        self.record.set_done(false);
+        Ok(Some(value))
+    }
+
+    /// Closes the iterator before it is done, calling its `return` method.
+    ///
+    /// This does nothing if the iterator is already done.
+    pub fn close(&mut self, context: &mut Context) -> JsResult<()> {
+        if self.record.done() {
+            return Ok(());
+        }
+        self.record.set_done(true);
+        self.record.close(Ok(JsValue::undefined()), context)?;
+        Ok(())
+    }
+
+    /// Returns a Rust iterator over the values of this iterator.
+    ///
+    /// The Rust iterator stops after yielding the first error.
+    #[inline]
+    pub fn iter<'a>(&'a mut self, context: &'a mut Context) -> JsIteratorIter<'a> {
+        JsIteratorIter {
+            iterator: self,
+            context,
+        }
+    }
+}
+
+impl From<JsIterator> for JsObject {
+    #[inline]
+    fn from(o: JsIterator) -> Self {
+        o.record.iterator().clone()
+    }
+}
+
+impl From<JsIterator> for JsValue {
+    #[inline]
+    fn from(o: JsIterator) -> Self {
+        o.record.iterator().clone().into()
+    }
+}
+
+impl Deref for JsIterator {
+    type Target = JsObject;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.record.iterator()
+    }
+}
+
+impl JsObjectType for JsIterator {}
+
+/// Rust iterator over the values of a [`JsIterator`].
+///
+/// This is created by [`JsIterator::iter`].
+#[derive(Debug)]
+pub struct JsIteratorIter<'a> {
+    iterator: &'a mut JsIterator,
+    context: &'a mut Context,
+}
+
+impl Iterator for JsIteratorIter<'_> {
+    type Item = JsResult<JsValue>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iterator.next(self.context).transpose()
+    }
+}
+
+/// A Rust `next` function along with the garbage collected values that it captures.
+#[derive(Finalize)]
+struct NativeIterator {
+    next: NextFn,
+    captures: Box<dyn NativeObject>,
+}
+
+// SAFETY: The `next` function can't capture garbage collected values, since it's either `Copy` or
+// `Send`, so only the captures need to be traced.
+unsafe impl Trace for NativeIterator {
+    boa_gc::custom_trace!(this, {
+        mark(&this.captures);
+    });
+}
+
+/// The state shared by the `next` and `return` functions of an iterator backed by Rust code.
+#[derive(Clone, Trace, Finalize)]
+struct NativeIteratorState(Gc<boa_gc::Cell<Option<NativeIterator>>>);
+
+impl fmt::Debug for NativeIteratorState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NativeIteratorState")
+            .field("done", &self.0.borrow().is_none())
+            .finish()
+    }
+}
@@ -26,11 +26,36 @@ pub type Ref<'a, T> = boa_gc::Ref<'a, T>;
 pub type RefMut<'a, T, U> = boa_gc::RefMut<'a, T, U>;
 
 /// Garbage collected `Object`.
-#[derive(Trace, Finalize, Clone, Default)]
+#[derive(Finalize, Clone, Default)]
 pub struct JsObject {
     inner: Gc<boa_gc::Cell<Object>>,
 }
 
+unsafe impl Trace for JsObject {
+    #[inline]
+    unsafe fn trace(&self) {
+        // Objects can form long chains of references, like prototype chains or proxies of
+        // proxies, so they are traced without recursing for every object.
+        boa_gc::trace_with_explicit_stack(&self.inner);
+    }
+
+    #[inline]
+    unsafe fn root(&self) {
+        self.inner.root();
+    }
+
+    #[inline]
+    unsafe fn unroot(&self) {
+        self.inner.unroot();
+    }
+
+    #[inline]
+    fn finalize_glue(&self) {
+        Finalize::finalize(self);
+        self.inner.finalize_glue();
+    }
+}
+
 impl JsObject {
     /// Create a new `JsObject` from an internal `Object`.
     #[inline]
@@ -58,9 +83,43 @@ impl JsObject {
             extensible: true,
             properties: PropertyMap::default(),
             private_elements: FxHashMap::default(),
+            allocation: Object::allocation(),
         })
     }
 
+    /// Create a new `JsObject` that holds the native Rust value `data`.
+    ///
+    /// If `prototype` is `None`, the prototype of the object will be `%Object.prototype%`.
+    ///
+    /// The value can later be recovered with [`JsObject::downcast_ref`] and
+    /// [`JsObject::downcast_mut`], and it is dropped when the object is garbage collected.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use boa_engine::{object::JsObject, Context};
+    /// use boa_gc::{Finalize, Trace};
+    ///
+    /// #[derive(Debug, Trace, Finalize)]
+    /// struct Counter(u32);
+    ///
+    /// let context = Context::default();
+    /// let object = JsObject::from_native_data(Counter(0), None, &context);
+    ///
+    /// object.downcast_mut::<Counter>().unwrap().0 += 1;
+    /// assert_eq!(object.downcast_ref::<Counter>().unwrap().0, 1);
+    /// assert!(!object.is::<String>());
+    /// ```
+    #[inline]
+    pub fn from_native_data<T>(data: T, prototype: Option<Self>, context: &Context) -> Self
+    where
+        T: NativeObject,
+    {
+        let prototype =
+            prototype.unwrap_or_else(|| context.intrinsics().constructors().object().prototype());
+        Self::from_proto_and_data(prototype, ObjectData::native_object(Box::new(data)))
+    }
+
     /// Immutably borrows the `Object`.
     ///
     /// The borrow lasts until the returned `Ref` exits scope.
@@ -233,7 +292,7 @@ impl JsObject {
     /// Panics if the object is currently borrowed.
     #[inline]
     #[track_caller]
-    pub fn downcast_mut<T>(&mut self) -> Option<RefMut<'_, Object, T>>
+    pub fn downcast_mut<T>(&self) -> Option<RefMut<'_, Object, T>>
     where
         T: NativeObject,
     {
@@ -642,10 +701,8 @@ Cannot both specify accessors and a value or writable attribute",
                             let prop_value = from.__get__(&key, from.clone().into(), context)?;
 
                             // 2. Perform ! CreateDataPropertyOrThrow(target, nextKey, propValue).
-                            self.create_data_property_or_throw(key, prop_value, context)
-                                .expect(
-                                    "CreateDataPropertyOrThrow should never complete abruptly here",
-                                );
+                            // This can only fail if the resource limits of the context are exceeded.
+                            self.create_data_property_or_throw(key, prop_value, context)?;
                         }
                     }
                 }
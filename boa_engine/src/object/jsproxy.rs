@@ -2,6 +2,7 @@ use boa_gc::{Finalize, Trace};
 
 use crate::{
     builtins::{function::NativeFunctionSignature, Proxy},
+    property::PropertyDescriptor,
     Context, JsResult, JsValue,
 };
 
@@ -353,101 +354,84 @@ impl JsProxyBuilder {
     /// inside Rust code.
     #[must_use]
     pub fn build(self, context: &mut Context) -> JsProxy {
+        // NOTE: The handler is created by the host, so its traps are inserted directly instead
+        // of being counted against the maximum property count of the context.
         let handler = context.construct_object();
+        let trap = |f: JsFunction| {
+            PropertyDescriptor::builder()
+                .value(f)
+                .writable(true)
+                .enumerable(true)
+                .configurable(true)
+        };
 
         if let Some(apply) = self.apply {
             let f = FunctionBuilder::native(context, apply).length(3).build();
-            handler
-                .create_data_property_or_throw("apply", f, context)
-                .expect("new object should be writable");
+            handler.insert("apply", trap(f));
         }
         if let Some(construct) = self.construct {
             let f = FunctionBuilder::native(context, construct)
                 .length(3)
                 .build();
-            handler
-                .create_data_property_or_throw("construct", f, context)
-                .expect("new object should be writable");
+            handler.insert("construct", trap(f));
         }
         if let Some(define_property) = self.define_property {
             let f = FunctionBuilder::native(context, define_property)
                 .length(3)
                 .build();
-            handler
-                .create_data_property_or_throw("defineProperty", f, context)
-                .expect("new object should be writable");
+            handler.insert("defineProperty", trap(f));
         }
         if let Some(delete_property) = self.delete_property {
             let f = FunctionBuilder::native(context, delete_property)
                 .length(2)
                 .build();
-            handler
-                .create_data_property_or_throw("deleteProperty", f, context)
-                .expect("new object should be writable");
+            handler.insert("deleteProperty", trap(f));
         }
         if let Some(get) = self.get {
             let f = FunctionBuilder::native(context, get).length(3).build();
-            handler
-                .create_data_property_or_throw("get", f, context)
-                .expect("new object should be writable");
+            handler.insert("get", trap(f));
         }
         if let Some(get_own_property_descriptor) = self.get_own_property_descriptor {
             let f = FunctionBuilder::native(context, get_own_property_descriptor)
                 .length(2)
                 .build();
-            handler
-                .create_data_property_or_throw("getOwnPropertyDescriptor", f, context)
-                .expect("new object should be writable");
+            handler.insert("getOwnPropertyDescriptor", trap(f));
         }
         if let Some(get_prototype_of) = self.get_prototype_of {
             let f = FunctionBuilder::native(context, get_prototype_of)
                 .length(1)
                 .build();
-            handler
-                .create_data_property_or_throw("getPrototypeOf", f, context)
-                .expect("new object should be writable");
+            handler.insert("getPrototypeOf", trap(f));
         }
         if let Some(has) = self.has {
             let f = FunctionBuilder::native(context, has).length(2).build();
-            handler
-                .create_data_property_or_throw("has", f, context)
-                .expect("new object should be writable");
+            handler.insert("has", trap(f));
         }
         if let Some(is_extensible) = self.is_extensible {
             let f = FunctionBuilder::native(context, is_extensible)
                 .length(1)
                 .build();
-            handler
-                .create_data_property_or_throw("isExtensible", f, context)
-                .expect("new object should be writable");
+            handler.insert("isExtensible", trap(f));
         }
         if let Some(own_keys) = self.own_keys {
             let f = FunctionBuilder::native(context, own_keys).length(1).build();
-            handler
-                .create_data_property_or_throw("ownKeys", f, context)
-                .expect("new object should be writable");
+            handler.insert("ownKeys", trap(f));
         }
         if let Some(prevent_extensions) = self.prevent_extensions {
             let f = FunctionBuilder::native(context, prevent_extensions)
                 .length(1)
                 .build();
-            handler
-                .create_data_property_or_throw("preventExtensions", f, context)
-                .expect("new object should be writable");
+            handler.insert("preventExtensions", trap(f));
         }
         if let Some(set) = self.set {
             let f = FunctionBuilder::native(context, set).length(4).build();
-            handler
-                .create_data_property_or_throw("set", f, context)
-                .expect("new object should be writable");
+            handler.insert("set", trap(f));
         }
         if let Some(set_prototype_of) = self.set_prototype_of {
             let f = FunctionBuilder::native(context, set_prototype_of)
                 .length(2)
                 .build();
-            handler
-                .create_data_property_or_throw("setPrototypeOf", f, context)
-                .expect("new object should be writable");
+            handler.insert("setPrototypeOf", trap(f));
         }
 
         let callable = self.target.is_callable();
@@ -5,7 +5,7 @@ use boa_gc::{Finalize, Trace};
 use crate::{
     builtins::Set,
     object::{JsFunction, JsObject, JsObjectType, JsSetIterator},
-    Context, JsResult, JsValue,
+    Context, JsError, JsResult, JsValue,
 };
 
 // This is an wrapper for `JsSet`
@@ -30,8 +30,8 @@ impl JsSet {
     ///
     /// Same as JavaScript's `set.size`.
     #[inline]
-    pub fn size(&self, context: &mut Context) -> JsResult<usize> {
-        Set::get_size(&self.inner.clone().into(), context)
+    pub fn size(&self, _context: &mut Context) -> JsResult<usize> {
+        Set::get_size(&self.inner.clone().into())
     }
 
     /// Appends value to the Set object.
@@ -76,7 +76,7 @@ impl JsSet {
     {
         match Set::delete(&self.inner.clone().into(), &[value.into()], context)? {
             JsValue::Boolean(bool) => Ok(bool),
-            _ => Err(JsValue::Undefined),
+            _ => Err(JsError::from_opaque(JsValue::Undefined)),
         }
     }
 
@@ -91,7 +91,7 @@ impl JsSet {
     {
         match Set::has(&self.inner.clone().into(), &[value.into()], context)? {
             JsValue::Boolean(bool) => Ok(bool),
-            _ => Err(JsValue::Undefined),
+            _ => Err(JsError::from_opaque(JsValue::Undefined)),
         }
     }
 
@@ -21,7 +21,12 @@ use self::internal_methods::{
     InternalObjectMethods, ORDINARY_INTERNAL_METHODS,
 };
 #[cfg(feature = "intl")]
-use crate::builtins::intl::date_time_format::DateTimeFormat;
+use crate::builtins::intl::{
+    date_time_format::DateTimeFormat,
+    plural_rules::PluralRules,
+    relative_time_format::RelativeTimeFormat,
+    segmenter::{SegmentIterator, Segmenter, Segments},
+};
 use crate::{
     builtins::{
         array::array_iterator::ArrayIterator,
@@ -33,6 +38,7 @@ use crate::{
             NativeFunctionSignature,
         },
         generator::Generator,
+        iterable::AsyncFromSyncIterator,
         map::map_iterator::MapIterator,
         map::ordered_map::OrderedMap,
         object::for_in_iterator::ForInIterator,
@@ -44,17 +50,21 @@ use crate::{
         typed_array::integer_indexed_object::IntegerIndexed,
         DataView, Date, Promise, RegExp,
     },
-    context::intrinsics::StandardConstructor,
+    context::{
+        intrinsics::StandardConstructor,
+        memory::{Allocation, MemoryKind},
+    },
     property::{Attribute, PropertyDescriptor, PropertyKey},
-    Context, JsBigInt, JsResult, JsString, JsSymbol, JsValue,
+    Context, JsBigInt, JsNativeError, JsResult, JsString, JsSymbol, JsValue,
 };
 
-use boa_gc::{custom_trace, Finalize, Trace};
+use boa_gc::{Finalize, Trace};
 use boa_interner::Sym;
 use rustc_hash::FxHashMap;
 use std::{
     any::Any,
     fmt::{self, Debug, Display},
+    mem::size_of,
     ops::{Deref, DerefMut},
 };
 
@@ -65,6 +75,7 @@ pub(crate) mod internal_methods;
 mod jsarray;
 mod jsarraybuffer;
 mod jsfunction;
+mod jsiterator;
 mod jsmap;
 mod jsmap_iterator;
 mod jsobject;
@@ -78,6 +89,7 @@ mod property_map;
 pub use jsarray::*;
 pub use jsarraybuffer::*;
 pub use jsfunction::*;
+pub use jsiterator::*;
 pub use jsmap::*;
 pub use jsmap_iterator::*;
 pub use jsproxy::*;
@@ -131,6 +143,9 @@ pub struct Object {
     extensible: bool,
     /// The `[[PrivateElements]]` internal slot.
     private_elements: FxHashMap<Sym, PrivateElement>,
+    /// The memory accounted for this object, which is released when the object is dropped.
+    #[allow(dead_code)]
+    allocation: Allocation,
 }
 
 unsafe impl Trace for Object {
@@ -163,8 +178,9 @@ pub struct ObjectData {
 }
 
 /// Defines the different types of objects.
-#[derive(Debug, Finalize)]
+#[derive(Debug, Trace, Finalize)]
 pub enum ObjectKind {
+    AsyncFromSyncIterator(AsyncFromSyncIterator),
     AsyncGenerator(AsyncGenerator),
     AsyncGeneratorFunction(Function),
     Array,
@@ -172,9 +188,9 @@ pub enum ObjectKind {
     ArrayBuffer(ArrayBuffer),
     Map(OrderedMap<JsValue>),
     MapIterator(MapIterator),
-    RegExp(Box<RegExp>),
+    RegExp(#[unsafe_ignore_trace] Box<RegExp>),
     RegExpStringIterator(RegExpStringIterator),
-    BigInt(JsBigInt),
+    BigInt(#[unsafe_ignore_trace] JsBigInt),
     Boolean(bool),
     DataView(DataView),
     ForInIterator(ForInIterator),
@@ -191,56 +207,35 @@ pub enum ObjectKind {
     Error,
     Ordinary,
     Proxy(Proxy),
-    Date(Date),
+    Date(#[unsafe_ignore_trace] Date),
     Global,
     Arguments(Arguments),
     NativeObject(Box<dyn NativeObject>),
     IntegerIndexed(IntegerIndexed),
     #[cfg(feature = "intl")]
     DateTimeFormat(Box<DateTimeFormat>),
+    #[cfg(feature = "intl")]
+    PluralRules(Box<PluralRules>),
+    #[cfg(feature = "intl")]
+    RelativeTimeFormat(Box<RelativeTimeFormat>),
+    #[cfg(feature = "intl")]
+    Segmenter(Box<Segmenter>),
+    #[cfg(feature = "intl")]
+    Segments(Segments),
+    #[cfg(feature = "intl")]
+    SegmentIterator(SegmentIterator),
     Promise(Promise),
 }
 
-unsafe impl Trace for ObjectKind {
-    custom_trace! {this, {
-        match this {
-            Self::ArrayIterator(i) => mark(i),
-            Self::ArrayBuffer(b) => mark(b),
-            Self::Map(m) => mark(m),
-            Self::MapIterator(i) => mark(i),
-            Self::RegExpStringIterator(i) => mark(i),
-            Self::DataView(v) => mark(v),
-            Self::ForInIterator(i) => mark(i),
-            Self::Function(f) | Self::GeneratorFunction(f) | Self::AsyncGeneratorFunction(f) => mark(f),
-            Self::BoundFunction(f) => mark(f),
-            Self::Generator(g) => mark(g),
-            Self::Set(s) => mark(s),
-            Self::SetIterator(i) => mark(i),
-            Self::StringIterator(i) => mark(i),
-            Self::Proxy(p) => mark(p),
-            Self::Arguments(a) => mark(a),
-            Self::NativeObject(o) => mark(o),
-            Self::IntegerIndexed(i) => mark(i),
-            #[cfg(feature = "intl")]
-            Self::DateTimeFormat(f) => mark(f),
-            Self::Promise(p) => mark(p),
-            Self::AsyncGenerator(g) => mark(g),
-            Self::RegExp(_)
-            | Self::BigInt(_)
-            | Self::Boolean(_)
-            | Self::String(_)
-            | Self::Date(_)
-            | Self::Array
-            | Self::Error
-            | Self::Ordinary
-            | Self::Global
-            | Self::Number(_)
-            | Self::Symbol(_) => {}
-        }
-    }}
-}
-
 impl ObjectData {
+    /// Create the `AsyncFromSyncIterator` object data
+    pub fn async_from_sync_iterator(async_from_sync_iterator: AsyncFromSyncIterator) -> Self {
+        Self {
+            kind: ObjectKind::AsyncFromSyncIterator(async_from_sync_iterator),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
     /// Create the `AsyncGenerator` object data
     pub fn async_generator(async_generator: AsyncGenerator) -> Self {
         Self {
@@ -531,11 +526,57 @@ impl ObjectData {
             internal_methods: &ORDINARY_INTERNAL_METHODS,
         }
     }
+
+    /// Create the `PluralRules` object data
+    #[cfg(feature = "intl")]
+    pub fn plural_rules(plural_rules: Box<PluralRules>) -> Self {
+        Self {
+            kind: ObjectKind::PluralRules(plural_rules),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
+    /// Create the `RelativeTimeFormat` object data
+    #[cfg(feature = "intl")]
+    pub fn relative_time_format(relative_time_format: Box<RelativeTimeFormat>) -> Self {
+        Self {
+            kind: ObjectKind::RelativeTimeFormat(relative_time_format),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
+    /// Create the `Segmenter` object data
+    #[cfg(feature = "intl")]
+    pub fn segmenter(segmenter: Box<Segmenter>) -> Self {
+        Self {
+            kind: ObjectKind::Segmenter(segmenter),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
+    /// Create the `Segments` object data
+    #[cfg(feature = "intl")]
+    pub fn segments(segments: Segments) -> Self {
+        Self {
+            kind: ObjectKind::Segments(segments),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
+    /// Create the `SegmentIterator` object data
+    #[cfg(feature = "intl")]
+    pub fn segment_iterator(segment_iterator: SegmentIterator) -> Self {
+        Self {
+            kind: ObjectKind::SegmentIterator(segment_iterator),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
 }
 
 impl Display for ObjectKind {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.write_str(match self {
+            Self::AsyncFromSyncIterator(_) => "AsyncFromSyncIterator",
             Self::AsyncGenerator(_) => "AsyncGenerator",
             Self::AsyncGeneratorFunction(_) => "AsyncGeneratorFunction",
             Self::Array => "Array",
@@ -569,6 +610,16 @@ impl Display for ObjectKind {
             Self::DataView(_) => "DataView",
             #[cfg(feature = "intl")]
             Self::DateTimeFormat(_) => "DateTimeFormat",
+            #[cfg(feature = "intl")]
+            Self::PluralRules(_) => "PluralRules",
+            #[cfg(feature = "intl")]
+            Self::RelativeTimeFormat(_) => "RelativeTimeFormat",
+            #[cfg(feature = "intl")]
+            Self::Segmenter(_) => "Segmenter",
+            #[cfg(feature = "intl")]
+            Self::Segments(_) => "Segments",
+            #[cfg(feature = "intl")]
+            Self::SegmentIterator(_) => "SegmentIterator",
             Self::Promise(_) => "Promise",
         })
     }
@@ -593,11 +644,36 @@ impl Default for Object {
             prototype: None,
             extensible: true,
             private_elements: FxHashMap::default(),
+            allocation: Self::allocation(),
         }
     }
 }
 
 impl Object {
+    /// Accounts the memory of a new object.
+    #[inline]
+    pub(crate) fn allocation() -> Allocation {
+        Allocation::new(
+            MemoryKind::GcHeap,
+            boa_gc::allocation_size::<boa_gc::Cell<Self>>(),
+        )
+    }
+
+    /// Updates the memory accounted for this object after its properties change.
+    ///
+    /// This is the size of the allocation of the object in the garbage collected heap, plus the
+    /// tables of its properties and private elements.
+    #[inline]
+    fn update_allocation(&mut self) {
+        let private_elements =
+            self.private_elements.capacity() * (size_of::<(Sym, PrivateElement)>() + 1);
+        self.allocation.resize(
+            boa_gc::allocation_size::<boa_gc::Cell<Self>>()
+                + self.properties.allocated_bytes()
+                + private_elements,
+        );
+    }
+
     #[inline]
     pub fn kind(&self) -> &ObjectKind {
         &self.data.kind
@@ -758,6 +834,17 @@ impl Object {
         }
     }
 
+    #[inline]
+    pub fn as_async_from_sync_iterator(&self) -> Option<&AsyncFromSyncIterator> {
+        match &self.data {
+            ObjectData {
+                kind: ObjectKind::AsyncFromSyncIterator(iter),
+                ..
+            } => Some(iter),
+            _ => None,
+        }
+    }
+
     #[inline]
     pub fn as_for_in_iterator(&self) -> Option<&ForInIterator> {
         match &self.data {
@@ -1383,6 +1470,71 @@ impl Object {
         }
     }
 
+    /// Gets the plural rules data if the object is an `Intl.PluralRules`.
+    #[inline]
+    #[cfg(feature = "intl")]
+    pub fn as_plural_rules(&self) -> Option<&PluralRules> {
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::PluralRules(ref plural_rules),
+                ..
+            } => Some(plural_rules),
+            _ => None,
+        }
+    }
+
+    /// Gets the relative time format data if the object is an `Intl.RelativeTimeFormat`.
+    #[inline]
+    #[cfg(feature = "intl")]
+    pub fn as_relative_time_format(&self) -> Option<&RelativeTimeFormat> {
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::RelativeTimeFormat(ref relative_time_format),
+                ..
+            } => Some(relative_time_format),
+            _ => None,
+        }
+    }
+
+    /// Gets the segmenter data if the object is an `Intl.Segmenter`.
+    #[inline]
+    #[cfg(feature = "intl")]
+    pub fn as_segmenter(&self) -> Option<&Segmenter> {
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::Segmenter(ref segmenter),
+                ..
+            } => Some(segmenter),
+            _ => None,
+        }
+    }
+
+    /// Gets the segments data if the object is a `Segments` object.
+    #[inline]
+    #[cfg(feature = "intl")]
+    pub fn as_segments(&self) -> Option<&Segments> {
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::Segments(ref segments),
+                ..
+            } => Some(segments),
+            _ => None,
+        }
+    }
+
+    /// Gets a mutable reference to the segment iterator data if the object is a `SegmentIterator`.
+    #[inline]
+    #[cfg(feature = "intl")]
+    pub fn as_segment_iterator_mut(&mut self) -> Option<&mut SegmentIterator> {
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::SegmentIterator(ref mut segment_iterator),
+                ..
+            } => Some(segment_iterator),
+            _ => None,
+        }
+    }
+
     /// Return `true` if it is a native object and the native type is `T`.
     #[inline]
     pub fn is<T>(&self) -> bool
@@ -1435,9 +1587,11 @@ impl Object {
         &self.properties
     }
 
+    /// Overrides all the indexed properties of the object, setting them to dense storage.
     #[inline]
-    pub(crate) fn properties_mut(&mut self) -> &mut PropertyMap {
-        &mut self.properties
+    pub(crate) fn override_indexed_properties(&mut self, properties: Vec<JsValue>) {
+        self.properties.override_indexed_properties(properties);
+        self.update_allocation();
     }
 
     /// Inserts a field in the object `properties` without checking if it's writable.
@@ -1450,13 +1604,17 @@ impl Object {
         K: Into<PropertyKey>,
         P: Into<PropertyDescriptor>,
     {
-        self.properties.insert(&key.into(), property.into())
+        let previous = self.properties.insert(&key.into(), property.into());
+        self.update_allocation();
+        previous
     }
 
     /// Helper function for property removal.
     #[inline]
     pub(crate) fn remove(&mut self, key: &PropertyKey) -> Option<PropertyDescriptor> {
-        self.properties.remove(key)
+        let removed = self.properties.remove(key);
+        self.update_allocation();
+        removed
     }
 
     /// Get a private element.
@@ -1469,6 +1627,7 @@ impl Object {
     #[inline]
     pub(crate) fn set_private_element(&mut self, name: Sym, value: PrivateElement) {
         self.private_elements.insert(name, value);
+        self.update_allocation();
     }
 
     /// Set a private setter.
@@ -1491,6 +1650,7 @@ impl Object {
                 );
             }
         }
+        self.update_allocation();
     }
 
     /// Set a private getter.
@@ -1513,6 +1673,7 @@ impl Object {
                 );
             }
         }
+        self.update_allocation();
     }
 }
 
@@ -1590,6 +1751,7 @@ pub struct FunctionBuilder<'context> {
     function: Function,
     name: JsString,
     length: usize,
+    prototype: Option<JsObject>,
 }
 
 impl<'context> FunctionBuilder<'context> {
@@ -1604,6 +1766,7 @@ impl<'context> FunctionBuilder<'context> {
             },
             name: JsString::default(),
             length: 0,
+            prototype: None,
         }
     }
 
@@ -1622,6 +1785,7 @@ impl<'context> FunctionBuilder<'context> {
             },
             name: JsString::default(),
             length: 0,
+            prototype: None,
         }
     }
 
@@ -1647,7 +1811,8 @@ impl<'context> FunctionBuilder<'context> {
                 function: Box::new(move |this, args, captures: Captures, context| {
                     let mut captures = captures.as_mut_any();
                     let captures = captures.downcast_mut::<C>().ok_or_else(|| {
-                        context.construct_type_error("cannot downcast `Captures` to given type")
+                        JsNativeError::typ()
+                            .with_message("cannot downcast `Captures` to given type")
                     })?;
                     function(this, args, captures, context)
                 }),
@@ -1656,6 +1821,7 @@ impl<'context> FunctionBuilder<'context> {
             },
             name: JsString::default(),
             length: 0,
+            prototype: None,
         }
     }
 
@@ -1686,6 +1852,9 @@ impl<'context> FunctionBuilder<'context> {
 
     /// Specify whether the object function object can be called with `new` keyword.
     ///
+    /// Functions that are not constructors throw a `TypeError` when called with `new`, and
+    /// don't have a `prototype` property, like the methods of the builtin objects.
+    ///
     /// The default is `false`.
     #[inline]
     #[must_use]
@@ -1711,9 +1880,23 @@ impl<'context> FunctionBuilder<'context> {
         self
     }
 
+    /// Specify the object used as the `prototype` property of a constructor function.
+    ///
+    /// This is ignored if the function is not a constructor.
+    ///
+    /// The default is a new ordinary object with a `constructor` property pointing back
+    /// to the function.
+    #[inline]
+    #[must_use]
+    pub fn prototype(mut self, prototype: JsObject) -> Self {
+        self.prototype = Some(prototype);
+        self
+    }
+
     /// Build the function object.
     #[inline]
     pub fn build(self) -> JsFunction {
+        let is_constructor = self.function.is_constructor();
         let function = JsObject::from_proto_and_data(
             self.context
                 .intrinsics()
@@ -1729,6 +1912,29 @@ impl<'context> FunctionBuilder<'context> {
         function.insert_property("length", property.clone().value(self.length));
         function.insert_property("name", property.value(self.name));
 
+        if is_constructor {
+            let prototype = self.prototype.unwrap_or_else(|| {
+                let prototype = self.context.construct_object();
+                prototype.insert_property(
+                    "constructor",
+                    PropertyDescriptor::builder()
+                        .value(function.clone())
+                        .writable(true)
+                        .enumerable(false)
+                        .configurable(true),
+                );
+                prototype
+            });
+            function.insert_property(
+                PROTOTYPE,
+                PropertyDescriptor::builder()
+                    .value(prototype)
+                    .writable(true)
+                    .enumerable(false)
+                    .configurable(false),
+            );
+        }
+
         JsFunction::from_object_unchecked(function)
     }
 
@@ -1842,6 +2048,30 @@ impl<'context> ObjectInitializer<'context> {
         self
     }
 
+    /// Add new accessor property to the object.
+    ///
+    /// The `writable` field of `attribute` is ignored, since accessor properties
+    /// cannot be writable.
+    #[inline]
+    pub fn accessor<K>(
+        &mut self,
+        key: K,
+        get: Option<JsFunction>,
+        set: Option<JsFunction>,
+        attribute: Attribute,
+    ) -> &mut Self
+    where
+        K: Into<PropertyKey>,
+    {
+        let property = PropertyDescriptor::builder()
+            .maybe_get(get)
+            .maybe_set(set)
+            .enumerable(attribute.enumerable())
+            .configurable(attribute.configurable());
+        self.object.borrow_mut().insert(key, property);
+        self
+    }
+
     /// Build the object.
     #[inline]
     pub fn build(&mut self) -> JsObject {
@@ -5,7 +5,7 @@ use crate::{
     property::{PropertyDescriptor, PropertyDescriptorBuilder, PropertyKey, PropertyNameKind},
     symbol::WellKnownSymbols,
     value::Type,
-    Context, JsResult, JsValue,
+    Context, JsNativeError, JsResult, JsString, JsValue,
 };
 
 /// Object integrity level.
@@ -162,12 +162,8 @@ impl JsObject {
     ///  - [ECMAScript reference][spec]
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-createnonenumerabledatapropertyinfallibly
-    pub(crate) fn create_non_enumerable_data_property_or_throw<K, V>(
-        &self,
-        key: K,
-        value: V,
-        context: &mut Context,
-    ) where
+    pub(crate) fn create_non_enumerable_data_property_or_throw<K, V>(&self, key: K, value: V)
+    where
         K: Into<PropertyKey>,
         V: Into<JsValue>,
     {
@@ -187,8 +183,9 @@ impl JsObject {
             .build();
 
         // 3. Perform ! DefinePropertyOrThrow(O, P, newDesc).
-        self.define_property_or_throw(key, new_desc, context)
-            .expect("should not fail according to spec");
+        // O is an ordinary extensible object whose properties are all configurable, so this
+        // cannot fail, and it doesn't need to be checked against the resource limits.
+        self.insert(key, new_desc);
 
         // 4. Return unused.
     }
@@ -285,6 +282,62 @@ impl JsObject {
         Ok(desc.is_some())
     }
 
+    /// Get the own property keys of this object, in the order given by its `[[OwnPropertyKeys]]`
+    /// internal method.
+    ///
+    /// This respects exotic objects, so calling it on a `Proxy` invokes its `ownKeys` trap.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-ordinary-object-internal-methods-and-internal-slots-ownpropertykeys
+    #[inline]
+    pub fn own_property_keys(&self, context: &mut Context) -> JsResult<Vec<PropertyKey>> {
+        self.__own_property_keys__(context)
+    }
+
+    /// Get the own property descriptors of this object, including the ones with symbol keys.
+    ///
+    /// This is the Rust equivalent of `Object.getOwnPropertyDescriptors`, and calls the
+    /// `[[OwnPropertyKeys]]` and `[[GetOwnProperty]]` internal methods of the object.
+    #[inline]
+    pub fn own_property_descriptors(
+        &self,
+        context: &mut Context,
+    ) -> JsResult<Vec<(PropertyKey, PropertyDescriptor)>> {
+        let mut descriptors = Vec::new();
+        for key in self.__own_property_keys__(context)? {
+            if let Some(desc) = self.__get_own_property__(&key, context)? {
+                descriptors.push((key, desc));
+            }
+        }
+        Ok(descriptors)
+    }
+
+    /// Get the key/value pairs of the enumerable own properties of this object with string keys.
+    ///
+    /// This is the Rust equivalent of `Object.entries`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-enumerableownpropertynames
+    pub fn entries(&self, context: &mut Context) -> JsResult<Vec<(PropertyKey, JsValue)>> {
+        let mut entries = Vec::new();
+        for key in self.__own_property_keys__(context)? {
+            if let PropertyKey::Symbol(_) = key {
+                continue;
+            }
+            if let Some(desc) = self.__get_own_property__(&key, context)? {
+                if desc.expect_enumerable() {
+                    let value = self.get(key.clone(), context)?;
+                    entries.push((key, value));
+                }
+            }
+        }
+        Ok(entries)
+    }
+
     /// Call this object.
     ///
     /// # Panics
@@ -336,6 +389,28 @@ impl JsObject {
         self.__construct__(args, new_target, context)
     }
 
+    /// Gets the method stored in the property `key` of this object and calls it, using the object
+    /// as the `this` value.
+    ///
+    /// Throws a `TypeError` if the property is not callable.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-invoke
+    #[inline]
+    pub fn invoke_method<K>(
+        &self,
+        key: K,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue>
+    where
+        K: Into<PropertyKey>,
+    {
+        JsValue::from(self.clone()).invoke(key, args, context)
+    }
+
     /// Make the object [`sealed`][IntegrityLevel::Sealed] or [`frozen`][IntegrityLevel::Frozen].
     ///
     /// More information:
@@ -564,7 +639,7 @@ impl JsObject {
                                 Array::create_array_from_list(
                                     [key_str.into(), self.get(key.clone(), context)?],
                                     context,
-                                )
+                                )?
                                 .into(),
                             ),
                         }
@@ -614,28 +689,31 @@ impl JsObject {
     ///  - [ECMAScript reference][spec]
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-isarray
-    pub(crate) fn is_array_abstract(&self, context: &mut Context) -> JsResult<bool> {
+    pub(crate) fn is_array_abstract(&self) -> JsResult<bool> {
         // Note: The spec specifies this function for JsValue.
         // It is implemented for JsObject for convenience.
 
-        // 2. If argument is an Array exotic object, return true.
-        if self.is_array() {
-            return Ok(true);
-        }
+        // The recursion of step 3.c is done iteratively, so that long chains of proxies cannot
+        // overflow the native stack.
+        let mut object = self.clone();
+        loop {
+            // 2. If argument is an Array exotic object, return true.
+            if object.is_array() {
+                return Ok(true);
+            }
 
-        // 3. If argument is a Proxy exotic object, then
-        let object = self.borrow();
-        if let Some(proxy) = object.as_proxy() {
-            // a. If argument.[[ProxyHandler]] is null, throw a TypeError exception.
-            // b. Let target be argument.[[ProxyTarget]].
-            let (target, _) = proxy.try_data(context)?;
+            // 3. If argument is a Proxy exotic object, then
+            let target = match object.borrow().as_proxy() {
+                // a. If argument.[[ProxyHandler]] is null, throw a TypeError exception.
+                // b. Let target be argument.[[ProxyTarget]].
+                Some(proxy) => proxy.try_data()?.0,
+                // 4. Return false.
+                None => return Ok(false),
+            };
 
             // c. Return ? IsArray(target).
-            return target.is_array_abstract(context);
+            object = target;
         }
-
-        // 4. Return false.
-        Ok(false)
     }
 
     // todo: GetFunctionRealm
@@ -673,11 +751,46 @@ impl JsValue {
     where
         K: Into<PropertyKey>,
     {
-        // 1. Let O be ? ToObject(V).
-        let o = self.to_object(context)?;
+        let key = key.into();
 
+        // 1. Let O be ? ToObject(V).
         // 2. Return ? O.[[Get]](P, V).
-        o.__get__(&key.into(), self.clone(), context)
+        //
+        // The wrapper object of a primitive is not observable from its [[Get]], so the property is
+        // looked up directly on the prototype the wrapper would have, with the primitive value as
+        // the receiver. The own properties of `String` wrappers are resolved from the string.
+        let prototype = match self {
+            Self::Object(object) => return object.__get__(&key, self.clone(), context),
+            Self::Undefined | Self::Null => {
+                return context.throw_type_error("cannot convert 'null' or 'undefined' to object")
+            }
+            Self::Boolean(_) => context.intrinsics().constructors().boolean().prototype(),
+            Self::Integer(_) | Self::Rational(_) => {
+                context.intrinsics().constructors().number().prototype()
+            }
+            Self::String(string) => {
+                match key {
+                    PropertyKey::String(ref name) if name.as_str() == "length" => {
+                        return Ok(string.encode_utf16().count().into());
+                    }
+                    PropertyKey::Index(index) => {
+                        if let Some(unit) = string.encode_utf16().nth(index as usize) {
+                            return Ok(JsString::from_utf16(&[unit]).into());
+                        }
+                    }
+                    _ => {}
+                }
+                context.intrinsics().constructors().string().prototype()
+            }
+            Self::Symbol(_) => context.intrinsics().constructors().symbol().prototype(),
+            Self::BigInt(_) => context
+                .intrinsics()
+                .constructors()
+                .bigint_object()
+                .prototype(),
+        };
+
+        prototype.__get__(&key, self.clone(), context)
     }
 
     /// Abstract operation `GetMethod ( V, P )`
@@ -693,9 +806,18 @@ impl JsValue {
     where
         K: Into<PropertyKey>,
     {
-        // Note: The spec specifies this function for JsValue.
-        // The main part of the function is implemented for JsObject.
-        self.to_object(context)?.get_method(key, context)
+        // 1. Assert: IsPropertyKey(P) is true.
+        // 2. Let func be ? GetV(V, P).
+        match &self.get_v(key, context)? {
+            // 3. If func is either undefined or null, return undefined.
+            Self::Undefined | Self::Null => Ok(None),
+            // 5. Return func.
+            Self::Object(obj) if obj.is_callable() => Ok(Some(obj.clone())),
+            // 4. If IsCallable(func) is false, throw a TypeError exception.
+            _ => {
+                context.throw_type_error("value returned for property of object is not a function")
+            }
+        }
     }
 
     /// It is used to create List value whose elements are provided by the indexed properties of
@@ -727,9 +849,9 @@ impl JsValue {
         };
 
         // 2. If Type(obj) is not Object, throw a TypeError exception.
-        let obj = self
-            .as_object()
-            .ok_or_else(|| context.construct_type_error("cannot create list from a primitive"))?;
+        let obj = self.as_object().ok_or_else(|| {
+            JsNativeError::typ().with_message("cannot create list from a primitive")
+        })?;
 
         // 3. Let len be ? LengthOfArrayLike(obj).
         let len = obj.length_of_array_like(context)?;
@@ -3,7 +3,7 @@ use crate::{property::PropertyDescriptorBuilder, JsString, JsSymbol, JsValue};
 use boa_gc::{custom_trace, Finalize, Trace};
 use indexmap::IndexMap;
 use rustc_hash::{FxHashMap, FxHasher};
-use std::{collections::hash_map, hash::BuildHasherDefault, iter::FusedIterator};
+use std::{collections::hash_map, hash::BuildHasherDefault, iter::FusedIterator, mem::size_of};
 
 /// Type alias to make it easier to work with the string properties on the global object.
 pub(crate) type GlobalPropertyMap =
@@ -19,6 +19,16 @@ impl<K: Trace> Default for OrderedHashMap<K> {
     }
 }
 
+impl<K: Trace> OrderedHashMap<K> {
+    /// Returns the number of bytes allocated by the map.
+    ///
+    /// Every entry stores its hash along with the key and value, and the hash table stores the
+    /// index of the entry and a control byte.
+    fn allocated_bytes(&self) -> usize {
+        self.0.capacity() * (size_of::<(usize, K, PropertyDescriptor)>() + size_of::<usize>() + 1)
+    }
+}
+
 unsafe impl<K: Trace> Trace for OrderedHashMap<K> {
     custom_trace!(this, {
         for (k, v) in this.0.iter() {
@@ -64,6 +74,15 @@ impl Default for IndexedProperties {
 impl IndexedProperties {
     /// Get a property descriptor if it exists.
     #[inline]
+    /// Returns the number of bytes allocated by the storage.
+    fn allocated_bytes(&self) -> usize {
+        match self {
+            Self::Dense(vec) => vec.capacity() * size_of::<JsValue>(),
+            // Every bucket of the hash table also has a control byte.
+            Self::Sparse(map) => map.capacity() * (size_of::<(u32, PropertyDescriptor)>() + 1),
+        }
+    }
+
     fn get(&self, key: u32) -> Option<PropertyDescriptor> {
         match self {
             Self::Sparse(ref map) => map.get(&key).cloned(),
@@ -191,6 +210,15 @@ impl IndexedProperties {
         old_property
     }
 
+    /// Returns the number of indexed properties.
+    #[inline]
+    fn len(&self) -> usize {
+        match self {
+            Self::Sparse(map) => map.len(),
+            Self::Dense(vec) => vec.len(),
+        }
+    }
+
     /// Check if we contain the key to a property descriptor.
     fn contains_key(&self, key: u32) -> bool {
         match self {
@@ -267,6 +295,28 @@ impl PropertyMap {
         }
     }
 
+    /// Returns the number of properties in the map.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.indexed_properties.len()
+            + self.string_properties.0.len()
+            + self.symbol_properties.0.len()
+    }
+
+    /// Returns `true` if the map contains no properties.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the number of bytes allocated by the tables of the map.
+    #[inline]
+    pub(crate) fn allocated_bytes(&self) -> usize {
+        self.indexed_properties.allocated_bytes()
+            + self.string_properties.allocated_bytes()
+            + self.symbol_properties.allocated_bytes()
+    }
+
     /// Overrides all the indexed properties, setting it to dense storage.
     pub(crate) fn override_indexed_properties(&mut self, properties: Vec<JsValue>) {
         self.indexed_properties = IndexedProperties::Dense(properties);
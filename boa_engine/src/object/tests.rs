@@ -1,4 +1,18 @@
-use crate::{check_output, exec, TestAction};
+use crate::{
+    check_output, exec, forward, forward_val,
+    object::{FunctionBuilder, JsFunction, JsIterator, JsObject, ObjectData, ObjectInitializer},
+    property::{Attribute, PropertyKey},
+    Context, JsValue, TestAction,
+};
+use boa_gc::{unsafe_empty_trace, Finalize, Trace};
+use std::{
+    cell::Cell,
+    rc::Rc,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
 
 #[test]
 fn ordinary_has_instance_nonobject_prototype() {
@@ -44,3 +58,670 @@ fn object_properties_return_order() {
         ),
     ]);
 }
+
+#[derive(Debug, Trace, Finalize)]
+struct Counter {
+    count: u32,
+    values: Vec<JsValue>,
+}
+
+#[derive(Debug)]
+struct Resource {
+    dropped: Rc<Cell<bool>>,
+}
+
+impl Finalize for Resource {}
+
+unsafe impl Trace for Resource {
+    unsafe_empty_trace!();
+}
+
+impl Drop for Resource {
+    fn drop(&mut self) {
+        self.dropped.set(true);
+    }
+}
+
+#[test]
+fn native_data_downcast() {
+    let context = Context::default();
+    let object = JsObject::from_native_data(
+        Counter {
+            count: 0,
+            values: Vec::new(),
+        },
+        None,
+        &context,
+    );
+
+    assert!(object.is::<Counter>());
+    assert!(!object.is::<Resource>());
+    assert!(object.downcast_ref::<Resource>().is_none());
+    assert!(JsObject::equals(
+        &object.prototype().clone().expect("should have a prototype"),
+        &context.intrinsics().constructors().object().prototype()
+    ));
+
+    {
+        let mut counter = object
+            .downcast_mut::<Counter>()
+            .expect("should be a counter");
+        counter.count += 1;
+        counter.values.push(JsObject::empty().into());
+    }
+
+    let counter = object
+        .downcast_ref::<Counter>()
+        .expect("should be a counter");
+    assert_eq!(counter.count, 1);
+    assert!(counter.values[0].is_object());
+}
+
+#[test]
+fn native_data_is_dropped_on_collection() {
+    let dropped = Rc::new(Cell::new(false));
+    {
+        let context = Context::default();
+        let object = JsObject::from_native_data(
+            Resource {
+                dropped: dropped.clone(),
+            },
+            None,
+            &context,
+        );
+        assert!(object.is::<Resource>());
+        drop(object);
+        boa_gc::force_collect();
+        assert!(dropped.get());
+    }
+}
+
+#[test]
+fn collect_long_object_chains() {
+    let dropped = Rc::new(Cell::new(false));
+    let mut context = Context::default();
+    let tail = JsObject::from_native_data(
+        Resource {
+            dropped: dropped.clone(),
+        },
+        None,
+        &context,
+    );
+
+    // A prototype chain and a linked list through properties, both too long to be traced
+    // recursively.
+    let mut prototype = tail.clone();
+    let mut list = tail;
+    for _ in 0..100_000 {
+        prototype = JsObject::from_proto_and_data(prototype, ObjectData::ordinary());
+        let node = context.construct_object();
+        node.create_data_property_or_throw("next", list, &mut context)
+            .expect("should define the property");
+        list = node;
+    }
+
+    boa_gc::force_collect();
+    assert!(!dropped.get());
+
+    drop(prototype);
+    drop(list);
+    boa_gc::force_collect();
+    assert!(dropped.get());
+}
+
+#[test]
+fn collect_live_objects_on_thread_exit() {
+    // The collector runs again when the thread exits, after the thread locals used to trace
+    // long chains may have been destroyed.
+    std::thread::spawn(|| {
+        let mut context = Context::default();
+        let mut list = context.construct_object();
+        for _ in 0..1_000 {
+            let node = context.construct_object();
+            node.create_data_property_or_throw("next", list, &mut context)
+                .expect("should define the property");
+            list = node;
+        }
+        boa_gc::force_collect();
+
+        // Keep the objects rooted until the thread exits.
+        std::mem::forget(list);
+        std::mem::forget(context);
+    })
+    .join()
+    .expect("the thread should exit without panicking");
+}
+
+#[test]
+fn native_data_from_this() {
+    let mut context = Context::default();
+
+    let increment = FunctionBuilder::native(&mut context, |this, _, context| {
+        let mut counter = this.downcast_native_mut::<Counter>(context)?;
+        counter.count += 1;
+        Ok(counter.count.into())
+    })
+    .name("increment")
+    .build();
+
+    let counter = JsObject::from_native_data(
+        Counter {
+            count: 0,
+            values: Vec::new(),
+        },
+        None,
+        &context,
+    );
+    counter.insert_property(
+        "increment",
+        crate::property::PropertyDescriptor::builder()
+            .value(increment.clone())
+            .writable(true)
+            .enumerable(false)
+            .configurable(true),
+    );
+    context.register_global_property("counter", counter, Attribute::all());
+    context.register_global_property("increment", increment, Attribute::all());
+
+    assert_eq!(forward(&mut context, "counter.increment()"), "1");
+    assert_eq!(forward(&mut context, "counter.increment()"), "2");
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { increment.call({}) } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn object_initializer_accessor() {
+    let mut context = Context::default();
+
+    let getter = FunctionBuilder::native(&mut context, |this, _, context| {
+        this.as_object()
+            .map_or(Ok(JsValue::undefined()), |this| this.get("_value", context))
+    })
+    .name("get value")
+    .build();
+    let setter = FunctionBuilder::native(&mut context, |this, args, context| {
+        let value = args
+            .get(0)
+            .cloned()
+            .unwrap_or_default()
+            .to_number(context)?;
+        if value < 0.0 {
+            return context.throw_range_error("value must be positive");
+        }
+        if let Some(this) = this.as_object() {
+            this.set("_value", value, true, context)?;
+        }
+        Ok(JsValue::undefined())
+    })
+    .name("set value")
+    .length(1)
+    .build();
+
+    let object = ObjectInitializer::new(&mut context)
+        .property("_value", 1, Attribute::all())
+        .accessor(
+            "value",
+            Some(getter),
+            Some(setter),
+            Attribute::ENUMERABLE | Attribute::PERMANENT,
+        )
+        .accessor("readonly", None, None, Attribute::CONFIGURABLE)
+        .build();
+    context.register_global_property("obj", object, Attribute::all());
+    forward(
+        &mut context,
+        "var desc = Object.getOwnPropertyDescriptor(obj, 'value');",
+    );
+
+    let cases = [
+        ("obj.value", "1"),
+        ("obj.value = 5; obj.value", "5"),
+        (
+            "try { obj.value = -1 } catch (e) { e instanceof RangeError }",
+            "true",
+        ),
+        ("typeof desc.get", "\"function\""),
+        ("desc.get.name", "\"get value\""),
+        ("desc.set.length", "1"),
+        ("desc.enumerable", "true"),
+        ("desc.configurable", "false"),
+        ("'writable' in desc", "false"),
+        ("delete obj.value", "false"),
+        (
+            "try { Object.defineProperty(obj, 'value', { value: 1 }) } catch (e) { e instanceof TypeError }",
+            "true",
+        ),
+        ("delete obj.readonly", "true"),
+        ("obj.readonly", "undefined"),
+    ];
+    for (case, expected) in cases {
+        assert_eq!(forward(&mut context, case), expected, "{case}");
+    }
+}
+
+#[test]
+fn function_builder_properties() {
+    let mut context = Context::default();
+
+    let method = FunctionBuilder::native(&mut context, |_, _, _| Ok(JsValue::undefined()))
+        .name("method")
+        .length(2)
+        .build();
+    let constructor = FunctionBuilder::native(&mut context, |_, _, _| Ok(JsValue::undefined()))
+        .name("Ctor")
+        .length(1)
+        .constructor(true)
+        .build();
+    let prototype = ObjectInitializer::new(&mut context)
+        .property("answer", 42, Attribute::all())
+        .build();
+    let custom = FunctionBuilder::native(&mut context, |_, _, _| Ok(JsValue::undefined()))
+        .name("Custom")
+        .constructor(true)
+        .prototype(prototype)
+        .build();
+
+    context.register_global_property("method", method, Attribute::all());
+    context.register_global_property("Ctor", constructor, Attribute::all());
+    context.register_global_property("Custom", custom, Attribute::all());
+
+    let cases = [
+        ("method.name", "\"method\""),
+        ("method.length", "2"),
+        ("method.hasOwnProperty('prototype')", "false"),
+        (
+            "try { new method() } catch (e) { e instanceof TypeError }",
+            "true",
+        ),
+        ("Ctor.name", "\"Ctor\""),
+        ("Ctor.length", "1"),
+        ("Ctor.prototype.constructor === Ctor", "true"),
+        (
+            "Object.getPrototypeOf(new Ctor()) === Ctor.prototype",
+            "true",
+        ),
+        ("new Custom().answer", "42"),
+        ("Custom.prototype.hasOwnProperty('constructor')", "false"),
+    ];
+    for (case, expected) in cases {
+        assert_eq!(forward(&mut context, case), expected, "{case}");
+    }
+}
+
+#[test]
+fn host_property_enumeration() {
+    let mut context = Context::default();
+
+    let object = context
+        .eval(
+            r#"
+            var sym = Symbol("sym");
+            var o = { b: 1, a: 2, [sym]: 3 };
+            o[1] = "one";
+            Object.defineProperty(o, "hidden", { value: 4, enumerable: false });
+            o
+            "#,
+        )
+        .expect("script should not fail")
+        .as_object()
+        .cloned()
+        .expect("should be an object");
+
+    let keys = object
+        .own_property_keys(&mut context)
+        .expect("should not fail");
+    assert_eq!(keys.len(), 5);
+    assert_eq!(keys[0], PropertyKey::Index(1));
+    assert_eq!(keys[1], "b");
+    assert_eq!(keys[2], "a");
+    assert_eq!(keys[3], "hidden");
+    assert!(matches!(keys[4], PropertyKey::Symbol(_)));
+
+    let descriptors = object
+        .own_property_descriptors(&mut context)
+        .expect("should not fail");
+    assert_eq!(descriptors.len(), 5);
+    assert!(!descriptors[3].1.expect_enumerable());
+
+    let entries = object.entries(&mut context).expect("should not fail");
+    let entries: Vec<_> = entries
+        .into_iter()
+        .map(|(key, value)| (key.to_string(), value.display().to_string()))
+        .collect();
+    assert_eq!(
+        entries,
+        [
+            ("1".to_owned(), "\"one\"".to_owned()),
+            ("b".to_owned(), "1".to_owned()),
+            ("a".to_owned(), "2".to_owned()),
+        ]
+    );
+
+    assert!(object
+        .has_own_property("hidden", &mut context)
+        .expect("should not fail"));
+    assert!(!object
+        .has_own_property("toString", &mut context)
+        .expect("should not fail"));
+}
+
+#[test]
+fn host_property_enumeration_of_proxy() {
+    let mut context = Context::default();
+
+    let proxy = context
+        .eval(
+            r#"
+            var trapped = [];
+            new Proxy({ x: 1, y: 2 }, {
+                ownKeys(target) {
+                    trapped.push("ownKeys");
+                    return ["y", "x"];
+                },
+                getOwnPropertyDescriptor(target, key) {
+                    trapped.push("getOwnPropertyDescriptor:" + key);
+                    return Reflect.getOwnPropertyDescriptor(target, key);
+                },
+            })
+            "#,
+        )
+        .expect("script should not fail")
+        .as_object()
+        .cloned()
+        .expect("should be an object");
+
+    let keys = proxy
+        .own_property_keys(&mut context)
+        .expect("should not fail");
+    assert_eq!(keys, [PropertyKey::from("y"), PropertyKey::from("x")]);
+    assert_eq!(forward(&mut context, "trapped.join()"), "\"ownKeys\"");
+
+    let entries = proxy.entries(&mut context).expect("should not fail");
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0].0, "y");
+    assert_eq!(
+        forward(&mut context, "trapped.join()"),
+        "\"ownKeys,ownKeys,getOwnPropertyDescriptor:y,getOwnPropertyDescriptor:x\""
+    );
+}
+
+#[test]
+fn host_call_round_trip() {
+    let mut context = Context::default();
+
+    let double = FunctionBuilder::native(&mut context, |_, args, context| {
+        let value = args
+            .get(0)
+            .cloned()
+            .unwrap_or_default()
+            .to_number(context)?;
+        Ok((value * 2.0).into())
+    })
+    .name("double")
+    .length(1)
+    .build();
+    context.register_global_property("double", double, Attribute::all());
+
+    let callback = context
+        .eval("(function (x) { return double(x) + this.offset; })")
+        .expect("script should not fail")
+        .as_object()
+        .cloned()
+        .and_then(JsFunction::from_object)
+        .expect("should be a function");
+
+    let this = ObjectInitializer::new(&mut context)
+        .property("offset", 1, Attribute::all())
+        .build();
+    let result = callback
+        .call(&this.clone().into(), &[20.into()], &mut context)
+        .expect("should not fail");
+    assert_eq!(result, JsValue::new(41));
+
+    let result = callback
+        .apply(&this.into(), [JsValue::new(5)], &mut context)
+        .expect("should not fail");
+    assert_eq!(result, JsValue::new(11));
+
+    let thrower = context
+        .eval("(function () { throw new RangeError('boom'); })")
+        .expect("script should not fail")
+        .as_object()
+        .cloned()
+        .and_then(JsFunction::from_object)
+        .expect("should be a function");
+    let error = thrower
+        .call(&JsValue::undefined(), &[], &mut context)
+        .expect_err("should throw");
+    assert_eq!(
+        error
+            .to_opaque(&mut context)
+            .to_string(&mut context)
+            .expect("should not fail"),
+        "RangeError: boom"
+    );
+}
+
+#[test]
+fn host_construct_and_invoke_method() {
+    let mut context = Context::default();
+
+    let point = context
+        .eval(
+            r#"
+            (class Point {
+                constructor(x, y) { this.x = x; this.y = y; }
+                sum() { return this.x + this.y; }
+            })
+            "#,
+        )
+        .expect("script should not fail")
+        .as_object()
+        .cloned()
+        .and_then(JsFunction::from_object)
+        .expect("should be a function");
+
+    let instance = point
+        .construct(&[1.into(), 2.into()], None, &mut context)
+        .expect("should not fail");
+    let sum = instance
+        .invoke_method("sum", &[], &mut context)
+        .expect("should not fail");
+    assert_eq!(sum, JsValue::new(3));
+
+    let not_a_method = instance.invoke_method("x", &[], &mut context);
+    assert!(not_a_method.is_err());
+
+    let arrow = context
+        .eval("() => {}")
+        .expect("script should not fail")
+        .as_object()
+        .cloned()
+        .and_then(JsFunction::from_object)
+        .expect("should be a function");
+    let error = arrow
+        .construct(&[], None, &mut context)
+        .expect_err("should throw");
+    assert_eq!(
+        error
+            .to_opaque(&mut context)
+            .to_string(&mut context)
+            .expect("should not fail"),
+        "TypeError: not a constructor"
+    );
+}
+
+/// Sets a flag when dropped, to check when Rust iterators are released.
+///
+/// Rust iterators must be `Send`, so the flag is atomic.
+struct DropFlag(Arc<AtomicBool>);
+
+impl Drop for DropFlag {
+    fn drop(&mut self) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+}
+
+#[test]
+fn rust_iterator_round_trip() {
+    let mut context = Context::default();
+
+    let iterator =
+        JsIterator::from_rust_iterator((1..=3).map(|i| Ok(JsValue::new(i))), &mut context);
+    context.register_global_property("numbers", iterator, Attribute::all());
+    assert_eq!(
+        forward(&mut context, "Array.from(numbers).join()"),
+        "\"1,2,3\""
+    );
+    assert_eq!(forward(&mut context, "[...numbers].length"), "0");
+
+    let iterator =
+        JsIterator::from_rust_iterator((1..=3).map(|i| Ok(JsValue::new(i))), &mut context);
+    context.register_global_property("spread", iterator, Attribute::all());
+    assert_eq!(forward(&mut context, "Math.max(...spread)"), "3");
+
+    let mut iterator = JsIterator::from_object(
+        forward_val(&mut context, "new Set(['a', 'b'])")
+            .unwrap()
+            .as_object()
+            .unwrap()
+            .clone(),
+        &mut context,
+    )
+    .unwrap();
+    let values: Vec<_> = iterator
+        .iter(&mut context)
+        .map(|value| value.unwrap().as_string().unwrap().to_string())
+        .collect();
+    assert_eq!(values, ["a", "b"]);
+    assert!(iterator.next(&mut context).unwrap().is_none());
+}
+
+#[test]
+fn rust_iterator_early_exit() {
+    let mut context = Context::default();
+    let dropped = Arc::new(AtomicBool::new(false));
+
+    let flag = DropFlag(dropped.clone());
+    let iterator = JsIterator::from_rust_iterator(
+        (0..).map(move |i| {
+            let _ = &flag;
+            Ok(JsValue::new(i))
+        }),
+        &mut context,
+    );
+    context.register_global_property("naturals", iterator, Attribute::all());
+
+    assert_eq!(
+        forward(
+            &mut context,
+            r#"
+            var sum = 0;
+            for (const n of naturals) {
+                if (n > 4) break;
+                sum += n;
+            }
+            sum
+            "#
+        ),
+        "10"
+    );
+    assert!(dropped.load(Ordering::SeqCst));
+    assert_eq!(forward(&mut context, "naturals.next().done"), "true");
+}
+
+/// The captures of a Rust iterator, which hold a garbage collected object.
+#[derive(Debug, Trace, Finalize)]
+struct IteratorCaptures {
+    object: JsObject,
+    resource: Resource,
+}
+
+#[test]
+fn rust_iterator_captures_are_traced() {
+    let mut context = Context::default();
+    let dropped = Rc::new(Cell::new(false));
+
+    let object = ObjectInitializer::new(&mut context)
+        .property("remaining", 3, Attribute::all())
+        .build();
+    let mut iterator = JsIterator::from_fn_with_captures(
+        |captures: &mut IteratorCaptures, context| {
+            let remaining = captures.object.get("remaining", context)?.to_u32(context)?;
+            if remaining == 0 {
+                return Ok(None);
+            }
+            captures
+                .object
+                .set("remaining", remaining - 1, true, context)?;
+            Ok(Some(remaining.into()))
+        },
+        IteratorCaptures {
+            object: object.clone(),
+            resource: Resource {
+                dropped: dropped.clone(),
+            },
+        },
+        &mut context,
+    );
+
+    // The captured object references the iterator, so they form a cycle.
+    object
+        .set("iterator", iterator.clone(), true, &mut context)
+        .unwrap();
+    let values: Vec<_> = iterator
+        .iter(&mut context)
+        .map(|value| value.unwrap())
+        .collect();
+    assert_eq!(values, [JsValue::new(3), JsValue::new(2), JsValue::new(1)]);
+
+    drop((object, iterator));
+    boa_gc::force_collect();
+    assert!(dropped.get());
+}
+
+#[test]
+fn rust_iterator_errors() {
+    let mut context = Context::default();
+
+    let mut count = 0;
+    let iterator = JsIterator::from_fn(
+        move |context| {
+            count += 1;
+            if count > 2 {
+                return context.throw_range_error("no more values");
+            }
+            Ok(Some(count.into()))
+        },
+        &mut context,
+    );
+    context.register_global_property("failing", iterator, Attribute::all());
+    assert_eq!(
+        forward(
+            &mut context,
+            "var seen = []; try { for (const n of failing) seen.push(n) } catch (e) { seen.push(e.message) } seen.join()"
+        ),
+        "\"1,2,no more values\""
+    );
+
+    let mut iterator = JsIterator::from_object(
+        forward_val(&mut context, "[1, 2, 3]")
+            .unwrap()
+            .as_object()
+            .unwrap()
+            .clone(),
+        &mut context,
+    )
+    .unwrap();
+    assert_eq!(iterator.next(&mut context).unwrap(), Some(JsValue::new(1)));
+    iterator.close(&mut context).unwrap();
+    assert!(iterator.next(&mut context).unwrap().is_none());
+
+    let not_iterable = ObjectInitializer::new(&mut context).build();
+    assert!(JsIterator::from_object(not_iterable, &mut context).is_err());
+}
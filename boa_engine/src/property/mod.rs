@@ -495,10 +495,24 @@ pub enum PropertyKey {
     Index(u32),
 }
 
+/// Parses a string as an array index.
+///
+/// Array indices are the canonical numeric strings of the integers from `0` to `2^32 - 2`, so
+/// strings with leading zeros or signs, like `"01"` or `"+1"`, are regular string keys.
+fn parse_array_index(string: &str) -> Option<u32> {
+    if !string.bytes().all(|byte| byte.is_ascii_digit())
+        || (string.len() > 1 && string.starts_with('0'))
+    {
+        return None;
+    }
+
+    string.parse().ok().filter(|&index| index != u32::MAX)
+}
+
 impl From<JsString> for PropertyKey {
     #[inline]
     fn from(string: JsString) -> Self {
-        if let Ok(index) = string.parse() {
+        if let Some(index) = parse_array_index(&string) {
             Self::Index(index)
         } else {
             Self::String(string)
@@ -509,7 +523,7 @@ impl From<JsString> for PropertyKey {
 impl From<&str> for PropertyKey {
     #[inline]
     fn from(string: &str) -> Self {
-        if let Ok(index) = string.parse() {
+        if let Some(index) = parse_array_index(string) {
             Self::Index(index)
         } else {
             Self::String(string.into())
@@ -520,7 +534,7 @@ impl From<&str> for PropertyKey {
 impl From<String> for PropertyKey {
     #[inline]
     fn from(string: String) -> Self {
-        if let Ok(index) = string.parse() {
+        if let Some(index) = parse_array_index(&string) {
             Self::Index(index)
         } else {
             Self::String(string.into())
@@ -531,7 +545,7 @@ impl From<String> for PropertyKey {
 impl From<Box<str>> for PropertyKey {
     #[inline]
     fn from(string: Box<str>) -> Self {
-        if let Ok(index) = string.parse() {
+        if let Some(index) = parse_array_index(&string) {
             Self::Index(index)
         } else {
             Self::String(string.into())
@@ -599,14 +613,18 @@ impl From<u16> for PropertyKey {
 
 impl From<u32> for PropertyKey {
     fn from(value: u32) -> Self {
-        Self::Index(value)
+        if value == u32::MAX {
+            Self::String(JsString::from(value.to_string()))
+        } else {
+            Self::Index(value)
+        }
     }
 }
 
 impl From<usize> for PropertyKey {
     fn from(value: usize) -> Self {
         if let Ok(index) = u32::try_from(value) {
-            Self::Index(index)
+            index.into()
         } else {
             Self::String(JsString::from(value.to_string()))
         }
@@ -616,7 +634,7 @@ impl From<usize> for PropertyKey {
 impl From<i64> for PropertyKey {
     fn from(value: i64) -> Self {
         if let Ok(index) = u32::try_from(value) {
-            Self::Index(index)
+            index.into()
         } else {
             Self::String(JsString::from(value.to_string()))
         }
@@ -626,7 +644,7 @@ impl From<i64> for PropertyKey {
 impl From<u64> for PropertyKey {
     fn from(value: u64) -> Self {
         if let Ok(index) = u32::try_from(value) {
-            Self::Index(index)
+            index.into()
         } else {
             Self::String(JsString::from(value.to_string()))
         }
@@ -636,7 +654,7 @@ impl From<u64> for PropertyKey {
 impl From<isize> for PropertyKey {
     fn from(value: isize) -> Self {
         if let Ok(index) = u32::try_from(value) {
-            Self::Index(index)
+            index.into()
         } else {
             Self::String(JsString::from(value.to_string()))
         }
@@ -646,7 +664,7 @@ impl From<isize> for PropertyKey {
 impl From<i32> for PropertyKey {
     fn from(value: i32) -> Self {
         if let Ok(index) = u32::try_from(value) {
-            Self::Index(index)
+            index.into()
         } else {
             Self::String(JsString::from(value.to_string()))
         }
@@ -654,10 +672,11 @@ impl From<i32> for PropertyKey {
 }
 
 impl From<f64> for PropertyKey {
+    #[allow(clippy::float_cmp)]
     fn from(value: f64) -> Self {
-        use num_traits::cast::FromPrimitive;
-        if let Some(index) = u32::from_f64(value) {
-            return Self::Index(index);
+        // `-0` is converted to the `"0"` key, like `ToString(-0)`.
+        if value >= 0.0 && value < f64::from(u32::MAX) && value.trunc() == value {
+            return Self::Index(value as u32);
         }
 
         Self::String(ryu_js::Buffer::new().format(value).into())
@@ -7,9 +7,12 @@
 use crate::{
     environments::{CompileTimeEnvironment, DeclarativeEnvironmentStack},
     object::{GlobalPropertyMap, JsObject, ObjectData, PropertyMap},
+    JsString,
 };
 use boa_gc::{Cell, Gc};
+use boa_interner::Sym;
 use boa_profiler::Profiler;
+use rustc_hash::FxHashSet;
 
 /// Representation of a Realm.
 ///
@@ -21,6 +24,9 @@ pub struct Realm {
     pub(crate) global_property_map: PropertyMap,
     pub(crate) environments: DeclarativeEnvironmentStack,
     pub(crate) compile_env: Gc<Cell<CompileTimeEnvironment>>,
+    /// The names bound by `var` and function declarations of the global code, which is the
+    /// `[[VarNames]]` field of the global environment record.
+    pub(crate) global_var_names: FxHashSet<Sym>,
 }
 
 impl Realm {
@@ -40,6 +46,7 @@ impl Realm {
             global_property_map: PropertyMap::default(),
             environments: DeclarativeEnvironmentStack::new(global_compile_environment.clone()),
             compile_env: global_compile_environment,
+            global_var_names: FxHashSet::default(),
         }
     }
 
@@ -59,4 +66,83 @@ impl Realm {
         let binding_number = self.compile_env.borrow().num_bindings();
         self.environments.set_global_binding_number(binding_number);
     }
+
+    /// Abstract operation `HasVarDeclaration`
+    ///
+    /// Returns `true` if a `var` or function declaration of the global code has already bound
+    /// the given name.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-hasvardeclaration
+    #[inline]
+    pub(crate) fn has_var_declaration(&self, name: Sym) -> bool {
+        self.global_var_names.contains(&name)
+    }
+
+    /// Abstract operation `HasLexicalDeclaration`
+    ///
+    /// Returns `true` if a `let`, `const` or class declaration of the global code has already
+    /// bound the given name.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-haslexicaldeclaration
+    #[inline]
+    pub(crate) fn has_lexical_declaration(&self, name: Sym) -> bool {
+        self.environments
+            .global_compile_environment()
+            .borrow()
+            .has_lex_binding(name)
+    }
+
+    /// Abstract operation `HasRestrictedGlobalProperty`
+    ///
+    /// Returns `true` if the global object has a non-configurable own property with the given
+    /// name, which cannot be shadowed by a lexical declaration.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-hasrestrictedglobalproperty
+    #[inline]
+    pub(crate) fn has_restricted_global_property(&self, name: &JsString) -> bool {
+        self.global_property_map
+            .string_property_map()
+            .get(name)
+            .map_or(false, |desc| !desc.expect_configurable())
+    }
+
+    /// Abstract operation `CanDeclareGlobalVar`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-candeclareglobalvar
+    #[inline]
+    pub(crate) fn can_declare_global_var(&self, name: &JsString) -> bool {
+        self.global_property_map
+            .string_property_map()
+            .contains_key(name)
+            || self.global_extensible
+    }
+
+    /// Abstract operation `CanDeclareGlobalFunction`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-candeclareglobalfunction
+    #[inline]
+    pub(crate) fn can_declare_global_function(&self, name: &JsString) -> bool {
+        match self.global_property_map.string_property_map().get(name) {
+            None => self.global_extensible,
+            Some(desc) if desc.expect_configurable() => true,
+            Some(desc) => {
+                desc.is_data_descriptor() && desc.expect_writable() && desc.expect_enumerable()
+            }
+        }
+    }
 }
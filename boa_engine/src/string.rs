@@ -1,4 +1,7 @@
-use crate::builtins::string::is_trimmable_whitespace;
+use crate::{
+    builtins::string::is_trimmable_whitespace,
+    context::memory::{Allocation, MemoryKind},
+};
 use boa_gc::{unsafe_empty_trace, Finalize, Trace};
 use rustc_hash::{FxHashMap, FxHasher};
 use std::{
@@ -9,7 +12,7 @@ use std::{
     hash::{Hash, Hasher},
     marker::PhantomData,
     ops::Deref,
-    ptr::{copy_nonoverlapping, NonNull},
+    ptr::{self, copy_nonoverlapping, NonNull},
     rc::Rc,
 };
 
@@ -508,25 +511,45 @@ struct Inner {
     /// The utf8 length, the number of bytes.
     len: usize,
 
+    /// The number of UTF-16 code units stored after the string data, or `0` if the string has no
+    /// unpaired surrogates.
+    ///
+    /// UTF-8 can't store unpaired surrogates, so the string data replaces each of them with
+    /// U+FFFD, and the code units of the whole string are stored after it.
+    utf16_len: usize,
+
     /// The number of references to the string.
     ///
     /// When this reaches `0` the string is deallocated.
     refcount: Cell<usize>,
 
+    /// The memory accounted for this string, which is released when the string is deallocated.
+    allocation: Allocation,
+
     /// An empty array which is used to get the offset of string data.
     data: [u8; 0],
 }
 
 impl Inner {
-    /// Create a new `Inner` from `&str`.
+    /// Returns the memory layout of an `Inner` with `len` bytes of string data and `utf16_len`
+    /// code units, along with the offset of the code units.
     #[inline]
-    fn new(s: &str) -> NonNull<Self> {
+    fn layout(len: usize, utf16_len: usize) -> (Layout, usize) {
         // We get the layout of the `Inner` type and we extend by the size
-        // of the string array.
-        let inner_layout = Layout::new::<Self>();
-        let (layout, offset) = inner_layout
-            .extend(Layout::array::<u8>(s.len()).expect("failed to create memory layout"))
+        // of the string array and the code units array.
+        let (layout, _offset) = Layout::new::<Self>()
+            .extend(Layout::array::<u8>(len).expect("failed to create memory layout"))
             .expect("failed to extend memory layout");
+        layout
+            .extend(Layout::array::<u16>(utf16_len).expect("failed to create memory layout"))
+            .expect("failed to extend memory layout")
+    }
+
+    /// Create a new `Inner` from `&str`, and the code units of the string if it has unpaired
+    /// surrogates.
+    #[inline]
+    fn new(s: &str, utf16: &[u16]) -> NonNull<Self> {
+        let (layout, utf16_offset) = Self::layout(s.len(), utf16.len());
 
         let inner = unsafe {
             let inner = try_alloc(layout).cast::<Self>();
@@ -534,18 +557,25 @@ impl Inner {
             // Write the first part, the Inner.
             inner.write(Self {
                 len: s.len(),
+                utf16_len: utf16.len(),
                 refcount: Cell::new(1),
+                allocation: Allocation::new(MemoryKind::Strings, layout.size()),
                 data: [0; 0],
             });
 
             // Get offset into the string data.
             let data = (*inner).data.as_mut_ptr();
 
-            debug_assert!(std::ptr::eq(inner.cast::<u8>().add(offset), data));
-
             // Copy string data into data offset.
             copy_nonoverlapping(s.as_ptr(), data, s.len());
 
+            // Copy the code units after the string data.
+            copy_nonoverlapping(
+                utf16.as_ptr(),
+                inner.cast::<u8>().add(utf16_offset).cast::<u16>(),
+                utf16.len(),
+            );
+
             inner
         };
 
@@ -561,12 +591,7 @@ impl Inner {
             total_string_size += string.len();
         }
 
-        // We get the layout of the `Inner` type and we extend by the size
-        // of the string array.
-        let inner_layout = Layout::new::<Self>();
-        let (layout, offset) = inner_layout
-            .extend(Layout::array::<u8>(total_string_size).expect("failed to create memory layout"))
-            .expect("failed to extend memory layout");
+        let (layout, _offset) = Self::layout(total_string_size, 0);
 
         let inner = unsafe {
             let inner = try_alloc(layout).cast::<Self>();
@@ -574,15 +599,15 @@ impl Inner {
             // Write the first part, the Inner.
             inner.write(Self {
                 len: total_string_size,
+                utf16_len: 0,
                 refcount: Cell::new(1),
+                allocation: Allocation::new(MemoryKind::Strings, layout.size()),
                 data: [0; 0],
             });
 
             // Get offset into the string data.
             let data = (*inner).data.as_mut_ptr();
 
-            debug_assert!(std::ptr::eq(inner.cast::<u8>().add(offset), data));
-
             // Copy the two string data into data offset.
             let mut offset = 0;
             for string in strings {
@@ -601,12 +626,11 @@ impl Inner {
     #[inline]
     unsafe fn dealloc(x: NonNull<Self>) {
         let len = (*x.as_ptr()).len;
+        let utf16_len = (*x.as_ptr()).utf16_len;
 
-        let inner_layout = Layout::new::<Self>();
-        let (layout, _offset) = inner_layout
-            .extend(Layout::array::<u8>(len).expect("failed to create memory layout"))
-            .expect("failed to extend memory layout");
+        let (layout, _offset) = Self::layout(len, utf16_len);
 
+        ptr::drop_in_place(ptr::addr_of_mut!((*x.as_ptr()).allocation));
         dealloc(x.as_ptr().cast::<_>(), layout);
     }
 
@@ -617,6 +641,20 @@ impl Inner {
             std::str::from_utf8_unchecked(slice)
         }
     }
+
+    /// Returns the code units of the string, if it has unpaired surrogates.
+    #[inline]
+    fn utf16(&self) -> Option<&[u16]> {
+        if self.utf16_len == 0 {
+            return None;
+        }
+
+        let (_layout, offset) = Self::layout(self.len, self.utf16_len);
+        unsafe {
+            let data = (self as *const Self).cast::<u8>().add(offset).cast::<u16>();
+            Some(std::slice::from_raw_parts(data, self.utf16_len))
+        }
+    }
 }
 
 /// This represents a JavaScript primitive string.
@@ -759,7 +797,7 @@ impl JsString {
 
         Self {
             // Safety: We already know it's a valid heap pointer.
-            inner: unsafe { TaggedInner::new_heap(Inner::new(s)) },
+            inner: unsafe { TaggedInner::new_heap(Inner::new(s, &[])) },
             _marker: PhantomData,
         }
     }
@@ -831,6 +869,65 @@ impl JsString {
         }
     }
 
+    /// Returns the code units of the string, if it has unpaired surrogates.
+    #[inline]
+    fn utf16(&self) -> Option<&[u16]> {
+        match self.inner() {
+            InnerKind::Heap(inner) => inner.utf16(),
+            InnerKind::Static(_inner) => None,
+        }
+    }
+
+    /// Create a new JavaScript string from its UTF-16 code units.
+    ///
+    /// Unpaired surrogates are kept: [`JsString::as_str`] replaces them with U+FFFD, but
+    /// [`JsString::encode_utf16`] returns them unchanged.
+    pub fn from_utf16(units: &[u16]) -> Self {
+        if let Ok(s) = String::from_utf16(units) {
+            return Self::new(s);
+        }
+
+        Self {
+            // Safety: We already know it's a valid heap pointer.
+            inner: unsafe {
+                TaggedInner::new_heap(Inner::new(&String::from_utf16_lossy(units), units))
+            },
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns an iterator over the UTF-16 code units of the string.
+    ///
+    /// Unlike [`str::encode_utf16`], this keeps the unpaired surrogates of the string.
+    #[inline]
+    pub fn encode_utf16(&self) -> CodeUnits<'_> {
+        CodeUnits {
+            inner: match self.utf16() {
+                Some(units) => CodeUnitsInner::Utf16(units.iter()),
+                None => CodeUnitsInner::Str(self.as_str().encode_utf16()),
+            },
+        }
+    }
+
+    /// Returns `true` if the string has unpaired surrogates.
+    #[inline]
+    pub fn has_lone_surrogates(&self) -> bool {
+        self.utf16().is_some()
+    }
+
+    /// Concatenate array of string, keeping their unpaired surrogates.
+    pub fn concat_code_units(strings: &[&Self]) -> Self {
+        if strings.iter().any(|s| s.has_lone_surrogates()) {
+            let units = strings
+                .iter()
+                .flat_map(|s| s.encode_utf16())
+                .collect::<Vec<_>>();
+            return Self::from_utf16(&units);
+        }
+
+        Self::concat_array(&strings.iter().map(|s| s.as_str()).collect::<Vec<_>>())
+    }
+
     /// Gets the number of `JsString`s which point to this allocation.
     #[inline]
     pub fn refcount(this: &Self) -> Option<usize> {
@@ -863,7 +960,8 @@ impl JsString {
         // 3. Assert: fromIndex is a non-negative integer.
 
         // 4. Let len be the length of string.
-        let len = self.encode_utf16().count();
+        let string = self.encode_utf16().collect::<Vec<u16>>();
+        let len = string.len();
 
         // 5. If searchValue is the empty String and fromIndex ≤ len, return fromIndex.
         if search_value.is_empty() && from_index <= len {
@@ -871,7 +969,8 @@ impl JsString {
         }
 
         // 6. Let searchLen be the length of searchValue.
-        let search_len = search_value.encode_utf16().count();
+        let search_value = search_value.encode_utf16().collect::<Vec<u16>>();
+        let search_len = search_value.len();
 
         // 7. For each integer i starting with fromIndex such that i ≤ len - searchLen, in ascending order, do
         for i in from_index..=len {
@@ -880,16 +979,10 @@ impl JsString {
             }
 
             // a. Let candidate be the substring of string from i to i + searchLen.
-            let candidate = String::from_utf16_lossy(
-                &self
-                    .encode_utf16()
-                    .skip(i)
-                    .take(search_len)
-                    .collect::<Vec<u16>>(),
-            );
+            let candidate = &string[i..i + search_len];
 
             // b. If candidate is the same sequence of code units as searchValue, return i.
-            if candidate == search_value.as_str() {
+            if candidate == search_value {
                 return Some(i);
             }
         }
@@ -948,6 +1041,40 @@ impl JsString {
     }
 }
 
+/// An iterator over the UTF-16 code units of a [`JsString`].
+///
+/// This is created by [`JsString::encode_utf16`].
+#[derive(Debug, Clone)]
+pub struct CodeUnits<'a> {
+    inner: CodeUnitsInner<'a>,
+}
+
+#[derive(Debug, Clone)]
+enum CodeUnitsInner<'a> {
+    Str(std::str::EncodeUtf16<'a>),
+    Utf16(std::slice::Iter<'a, u16>),
+}
+
+impl Iterator for CodeUnits<'_> {
+    type Item = u16;
+
+    #[inline]
+    fn next(&mut self) -> Option<u16> {
+        match &mut self.inner {
+            CodeUnitsInner::Str(iter) => iter.next(),
+            CodeUnitsInner::Utf16(iter) => iter.next().copied(),
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match &self.inner {
+            CodeUnitsInner::Str(iter) => iter.size_hint(),
+            CodeUnitsInner::Utf16(iter) => iter.size_hint(),
+        }
+    }
+}
+
 impl Clone for JsString {
     #[inline]
     fn clone(&self) -> Self {
@@ -1044,7 +1171,7 @@ impl PartialEq<Self> for JsString {
             return true;
         }
 
-        self.as_str() == other.as_str()
+        self.as_str() == other.as_str() && self.utf16() == other.utf16()
     }
 }
 
@@ -1060,42 +1187,43 @@ impl Hash for JsString {
 impl PartialOrd for JsString {
     #[inline]
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        self.as_str().partial_cmp(other.as_str())
+        Some(self.cmp(other))
     }
 }
 
 impl Ord for JsString {
+    /// Compares the code units of the strings, like the `<` operator of JavaScript.
     #[inline]
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        self.as_str().cmp(other)
+        self.encode_utf16().cmp(other.encode_utf16())
     }
 }
 
 impl PartialEq<str> for JsString {
     #[inline]
     fn eq(&self, other: &str) -> bool {
-        self.as_str() == other
+        !self.has_lone_surrogates() && self.as_str() == other
     }
 }
 
 impl PartialEq<JsString> for str {
     #[inline]
     fn eq(&self, other: &JsString) -> bool {
-        self == other.as_str()
+        other == self
     }
 }
 
 impl PartialEq<&str> for JsString {
     #[inline]
     fn eq(&self, other: &&str) -> bool {
-        self.as_str() == *other
+        self == *other
     }
 }
 
 impl PartialEq<JsString> for &str {
     #[inline]
     fn eq(&self, other: &JsString) -> bool {
-        *self == other.as_str()
+        other == *self
     }
 }
 
@@ -1224,4 +1352,39 @@ mod tests {
         assert_eq!(xyzw, "hello, world!");
         assert_eq!(JsString::refcount(&xyzw), Some(1));
     }
+
+    #[test]
+    fn from_utf16() {
+        let valid = JsString::from_utf16(&[0x68, 0xD83D, 0xDE00]);
+        assert_eq!(valid, "h😀");
+        assert!(!valid.has_lone_surrogates());
+
+        let lone = JsString::from_utf16(&[0x68, 0xD800]);
+        assert!(lone.has_lone_surrogates());
+        assert_eq!(lone.as_str(), "h\u{FFFD}");
+        assert_ne!(lone, "h\u{FFFD}");
+        assert_eq!(lone.encode_utf16().collect::<Vec<_>>(), [0x68, 0xD800]);
+        assert_eq!(lone, JsString::from_utf16(&[0x68, 0xD800]));
+        assert_ne!(lone, JsString::from_utf16(&[0x68, 0xD801]));
+    }
+
+    #[test]
+    fn concat_code_units() {
+        let high = JsString::from_utf16(&[0xD83D]);
+        let low = JsString::from_utf16(&[0xDE00]);
+        let pair = JsString::concat_code_units(&[&high, &low]);
+        assert_eq!(pair, "😀");
+        assert!(!pair.has_lone_surrogates());
+
+        let lone = JsString::concat_code_units(&[&JsString::new("a"), &high]);
+        assert_eq!(lone.encode_utf16().collect::<Vec<_>>(), [0x61, 0xD83D]);
+    }
+
+    #[test]
+    fn ord() {
+        // U+FFFD is greater than any surrogate when comparing code units.
+        assert!(JsString::from_utf16(&[0xD800]) < JsString::new("\u{FFFD}"));
+        assert!(JsString::new("\u{FF61}") > JsString::new("😀"));
+        assert!(JsString::from_utf16(&[0xD800]) < JsString::from_utf16(&[0xD801]));
+    }
 }
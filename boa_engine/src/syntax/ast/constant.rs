@@ -151,11 +151,24 @@ impl ToInternedString for Const {
     fn to_interned_string(&self, interner: &Interner) -> String {
         match *self {
             Self::String(st) => {
-                format!("\"{}\"", interner.resolve_expect(st))
+                let mut buf = String::from('"');
+                for c in interner.resolve_expect(st).chars() {
+                    match c {
+                        '"' => buf.push_str("\\\""),
+                        '\\' => buf.push_str("\\\\"),
+                        '\n' => buf.push_str("\\n"),
+                        '\r' => buf.push_str("\\r"),
+                        '\u{2028}' => buf.push_str("\\u2028"),
+                        '\u{2029}' => buf.push_str("\\u2029"),
+                        c => buf.push(c),
+                    }
+                }
+                buf.push('"');
+                buf
             }
             Self::Num(num) => num.to_string(),
             Self::Int(num) => num.to_string(),
-            Self::BigInt(ref num) => num.to_string(),
+            Self::BigInt(ref num) => format!("{num}n"),
             Self::Bool(v) => v.to_string(),
             Self::Null => "null".to_owned(),
             Self::Undefined => "undefined".to_owned(),
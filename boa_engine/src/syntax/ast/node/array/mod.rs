@@ -1,6 +1,6 @@
 //! Array declaration node.
 
-use super::{join_nodes, Node};
+use super::{precedence, Node, TakeNodes};
 use boa_interner::{Interner, ToInternedString};
 
 #[cfg(feature = "deser")]
@@ -71,7 +71,27 @@ where
 
 impl ToInternedString for ArrayDecl {
     fn to_interned_string(&self, interner: &Interner) -> String {
-        format!("[{}]", join_nodes(interner, &self.arr))
+        let elements = self
+            .arr
+            .iter()
+            .map(|element| match element {
+                Node::Empty => String::new(),
+                element => precedence::operand(element, precedence::ASSIGNMENT, interner),
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        // A trailing hole needs its own comma.
+        if let Some(Node::Empty) = self.arr.last() {
+            format!("[{elements},]")
+        } else {
+            format!("[{elements}]")
+        }
+    }
+}
+
+impl TakeNodes for ArrayDecl {
+    fn take_nodes(&mut self, nodes: &mut Vec<Node>) {
+        self.arr.take_nodes(nodes);
     }
 }
 
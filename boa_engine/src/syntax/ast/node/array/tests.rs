@@ -4,6 +4,8 @@ fn fmt() {
         r#"
         let a = [1, 2, 3, "words", "more words"];
         let b = [];
+        let c = [, 1, ,];
+        let d = ["a \"quoted\" word", "a \\ backslash"];
         "#,
     );
 }
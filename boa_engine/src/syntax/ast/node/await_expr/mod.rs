@@ -1,6 +1,6 @@
 //! Await expression node.
 
-use super::Node;
+use super::{precedence, Node, TakeNodes};
 use boa_interner::{Interner, ToInternedString};
 
 #[cfg(feature = "deser")]
@@ -42,7 +42,16 @@ where
 
 impl ToInternedString for AwaitExpr {
     fn to_interned_string(&self, interner: &Interner) -> String {
-        format!("await {}", self.expr.to_indented_string(interner, 0))
+        format!(
+            "await {}",
+            precedence::operand(&self.expr, precedence::UNARY, interner)
+        )
+    }
+}
+
+impl TakeNodes for AwaitExpr {
+    fn take_nodes(&mut self, nodes: &mut Vec<Node>) {
+        self.expr.take_nodes(nodes);
     }
 }
 
@@ -1,6 +1,6 @@
 //! Block AST node.
 
-use super::{Node, StatementList};
+use super::{Node, StatementList, TakeNodes};
 use boa_interner::{Interner, Sym, ToInternedString};
 
 #[cfg(feature = "deser")]
@@ -71,6 +71,12 @@ impl ToInternedString for Block {
     }
 }
 
+impl TakeNodes for Block {
+    fn take_nodes(&mut self, nodes: &mut Vec<Node>) {
+        self.statements.take_nodes(nodes);
+    }
+}
+
 impl From<Block> for Node {
     fn from(block: Block) -> Self {
         Self::Block(block)
@@ -1,4 +1,4 @@
-use crate::syntax::ast::node::{join_nodes, Node};
+use crate::syntax::ast::node::{precedence, Node, TakeNodes};
 use boa_interner::{Interner, ToInternedString};
 
 #[cfg(feature = "deser")]
@@ -56,12 +56,19 @@ impl ToInternedString for Call {
     fn to_interned_string(&self, interner: &Interner) -> String {
         format!(
             "{}({})",
-            self.expr.to_interned_string(interner),
-            join_nodes(interner, &self.args)
+            precedence::object(&self.expr, interner),
+            precedence::join_operands(interner, &self.args)
         )
     }
 }
 
+impl TakeNodes for Call {
+    fn take_nodes(&mut self, nodes: &mut Vec<Node>) {
+        self.expr.take_nodes(nodes);
+        self.args.take_nodes(nodes);
+    }
+}
+
 impl From<Call> for Node {
     fn from(call: Call) -> Self {
         Self::Call(call)
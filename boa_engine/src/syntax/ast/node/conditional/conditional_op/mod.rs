@@ -1,4 +1,4 @@
-use crate::syntax::ast::node::Node;
+use crate::syntax::ast::node::{precedence, Node, TakeNodes};
 use boa_interner::{Interner, ToInternedString};
 
 #[cfg(feature = "deser")]
@@ -58,13 +58,21 @@ impl ToInternedString for ConditionalOp {
     fn to_interned_string(&self, interner: &Interner) -> String {
         format!(
             "{} ? {} : {}",
-            self.cond().to_interned_string(interner),
-            self.if_true().to_interned_string(interner),
-            self.if_false().to_interned_string(interner)
+            precedence::operand(self.cond(), precedence::COALESCE, interner),
+            precedence::operand(self.if_true(), precedence::ASSIGNMENT, interner),
+            precedence::operand(self.if_false(), precedence::ASSIGNMENT, interner)
         )
     }
 }
 
+impl TakeNodes for ConditionalOp {
+    fn take_nodes(&mut self, nodes: &mut Vec<Node>) {
+        self.condition.take_nodes(nodes);
+        self.if_true.take_nodes(nodes);
+        self.if_false.take_nodes(nodes);
+    }
+}
+
 impl From<ConditionalOp> for Node {
     fn from(cond_op: ConditionalOp) -> Self {
         Self::ConditionalOp(cond_op)
@@ -1,4 +1,4 @@
-use crate::syntax::ast::node::Node;
+use crate::syntax::ast::node::{statement_to_string, Node, TakeNodes};
 use boa_interner::{Interner, ToInternedString};
 
 #[cfg(feature = "deser")]
@@ -62,17 +62,10 @@ impl If {
         indent: usize,
     ) -> String {
         let mut buf = format!("if ({}) ", self.cond().to_interned_string(interner));
-        match self.else_node() {
-            Some(else_e) => {
-                buf.push_str(&format!(
-                    "{} else {}",
-                    self.body().to_indented_string(interner, indent),
-                    else_e.to_indented_string(interner, indent)
-                ));
-            }
-            None => {
-                buf.push_str(&self.body().to_indented_string(interner, indent));
-            }
+        buf.push_str(&statement_to_string(self.body(), interner, indent));
+        if let Some(else_e) = self.else_node() {
+            buf.push_str(" else ");
+            buf.push_str(&statement_to_string(else_e, interner, indent));
         }
         buf
     }
@@ -84,6 +77,14 @@ impl ToInternedString for If {
     }
 }
 
+impl TakeNodes for If {
+    fn take_nodes(&mut self, nodes: &mut Vec<Node>) {
+        self.cond.take_nodes(nodes);
+        self.body.take_nodes(nodes);
+        self.else_node.take_nodes(nodes);
+    }
+}
+
 impl From<If> for Node {
     fn from(if_stm: If) -> Self {
         Self::If(if_stm)
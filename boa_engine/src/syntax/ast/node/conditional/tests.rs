@@ -11,3 +11,14 @@ fn fmt() {
         "#,
     );
 }
+
+#[test]
+fn fmt_branches() {
+    super::super::test_formatting(
+        r#"
+        if (a) b = 1; else c = 2;
+        if (a) b = 1;
+        c = 2;
+        "#,
+    );
+}
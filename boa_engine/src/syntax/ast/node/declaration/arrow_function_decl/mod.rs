@@ -1,4 +1,4 @@
-use crate::syntax::ast::node::{join_nodes, FormalParameterList, Node, StatementList};
+use crate::syntax::ast::node::{join_nodes, FormalParameterList, Node, StatementList, TakeNodes};
 use boa_interner::{Interner, Sym, ToInternedString};
 
 #[cfg(feature = "deser")]
@@ -86,6 +86,12 @@ impl ToInternedString for ArrowFunctionDecl {
     }
 }
 
+impl TakeNodes for ArrowFunctionDecl {
+    fn take_nodes(&mut self, nodes: &mut Vec<Node>) {
+        self.body.take_nodes(nodes);
+    }
+}
+
 impl From<ArrowFunctionDecl> for Node {
     fn from(decl: ArrowFunctionDecl) -> Self {
         Self::ArrowFunctionDecl(decl)
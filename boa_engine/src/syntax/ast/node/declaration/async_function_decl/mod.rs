@@ -1,6 +1,6 @@
 //! Async Function Declaration.
 
-use crate::syntax::ast::node::{join_nodes, FormalParameterList, Node, StatementList};
+use crate::syntax::ast::node::{join_nodes, FormalParameterList, Node, StatementList, TakeNodes};
 use boa_interner::{Interner, Sym, ToInternedString};
 
 #[cfg(feature = "deser")]
@@ -75,6 +75,12 @@ impl AsyncFunctionDecl {
     }
 }
 
+impl TakeNodes for AsyncFunctionDecl {
+    fn take_nodes(&mut self, nodes: &mut Vec<Node>) {
+        self.body.take_nodes(nodes);
+    }
+}
+
 impl From<AsyncFunctionDecl> for Node {
     fn from(decl: AsyncFunctionDecl) -> Self {
         Self::AsyncFunctionDecl(decl)
@@ -1,6 +1,6 @@
 //! Async Function Expression.
 
-use crate::syntax::ast::node::{join_nodes, FormalParameterList, Node, StatementList};
+use crate::syntax::ast::node::{join_nodes, FormalParameterList, Node, StatementList, TakeNodes};
 use boa_interner::{Interner, Sym, ToInternedString};
 
 #[cfg(feature = "deser")]
@@ -86,6 +86,12 @@ impl ToInternedString for AsyncFunctionExpr {
     }
 }
 
+impl TakeNodes for AsyncFunctionExpr {
+    fn take_nodes(&mut self, nodes: &mut Vec<Node>) {
+        self.body.take_nodes(nodes);
+    }
+}
+
 impl From<AsyncFunctionExpr> for Node {
     fn from(expr: AsyncFunctionExpr) -> Self {
         Self::AsyncFunctionExpr(expr)
@@ -1,6 +1,6 @@
 //! Async Generator Declaration
 
-use crate::syntax::ast::node::{join_nodes, FormalParameterList, Node, StatementList};
+use crate::syntax::ast::node::{join_nodes, FormalParameterList, Node, StatementList, TakeNodes};
 use boa_interner::{Interner, Sym, ToInternedString};
 
 #[cfg(feature = "deser")]
@@ -73,6 +73,12 @@ impl AsyncGeneratorDecl {
     }
 }
 
+impl TakeNodes for AsyncGeneratorDecl {
+    fn take_nodes(&mut self, nodes: &mut Vec<Node>) {
+        self.body.take_nodes(nodes);
+    }
+}
+
 impl From<AsyncGeneratorDecl> for Node {
     fn from(decl: AsyncGeneratorDecl) -> Self {
         Self::AsyncGeneratorDecl(decl)
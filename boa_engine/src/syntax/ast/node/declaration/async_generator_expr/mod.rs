@@ -1,6 +1,6 @@
 //! Async Generator Expression
 
-use crate::syntax::ast::node::{join_nodes, FormalParameterList, Node, StatementList};
+use crate::syntax::ast::node::{join_nodes, FormalParameterList, Node, StatementList, TakeNodes};
 use boa_interner::{Interner, Sym, ToInternedString};
 
 #[cfg(feature = "deser")]
@@ -77,6 +77,12 @@ impl ToInternedString for AsyncGeneratorExpr {
     }
 }
 
+impl TakeNodes for AsyncGeneratorExpr {
+    fn take_nodes(&mut self, nodes: &mut Vec<Node>) {
+        self.body.take_nodes(nodes);
+    }
+}
+
 impl From<AsyncGeneratorExpr> for Node {
     fn from(expr: AsyncGeneratorExpr) -> Self {
         Self::AsyncGeneratorExpr(expr)
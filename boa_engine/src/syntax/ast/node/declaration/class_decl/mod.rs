@@ -5,7 +5,7 @@ use crate::syntax::ast::node::{
     declaration::{block_to_string, FunctionExpr},
     join_nodes,
     object::{MethodDefinition, PropertyName},
-    Node, StatementList,
+    Node, StatementList, TakeNodes,
 };
 use boa_interner::{Interner, Sym, ToInternedString};
 
@@ -94,7 +94,7 @@ impl Class {
             "class {}{} {{\n",
             interner.resolve_expect(self.name),
             if let Some(node) = &self.super_ref {
-                format!("extends {}", node.to_interned_string(interner))
+                format!(" extends {}", node.to_interned_string(interner))
             } else {
                 "".to_string()
             }
@@ -364,3 +364,34 @@ pub enum ClassElement {
     PrivateStaticFieldDefinition(Sym, Option<Node>),
     StaticBlock(StatementList),
 }
+
+impl TakeNodes for Class {
+    fn take_nodes(&mut self, nodes: &mut Vec<Node>) {
+        self.super_ref.take_nodes(nodes);
+        self.constructor.take_nodes(nodes);
+        self.elements.take_nodes(nodes);
+    }
+}
+
+impl TakeNodes for ClassElement {
+    fn take_nodes(&mut self, nodes: &mut Vec<Node>) {
+        match self {
+            Self::MethodDefinition(name, method) | Self::StaticMethodDefinition(name, method) => {
+                name.take_nodes(nodes);
+                method.take_nodes(nodes);
+            }
+            Self::FieldDefinition(name, init) | Self::StaticFieldDefinition(name, init) => {
+                name.take_nodes(nodes);
+                init.take_nodes(nodes);
+            }
+            Self::PrivateMethodDefinition(_, method)
+            | Self::PrivateStaticMethodDefinition(_, method) => {
+                method.take_nodes(nodes);
+            }
+            Self::PrivateFieldDefinition(_, init) | Self::PrivateStaticFieldDefinition(_, init) => {
+                init.take_nodes(nodes);
+            }
+            Self::StaticBlock(list) => list.take_nodes(nodes),
+        }
+    }
+}
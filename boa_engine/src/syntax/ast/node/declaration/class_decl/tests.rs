@@ -18,6 +18,17 @@ fn class_declaration_empty_extends() {
     );
 }
 
+#[test]
+fn class_declaration_extends() {
+    test_formatting(
+        r#"
+        class A extends Object {
+            a;
+        };
+        "#,
+    );
+}
+
 #[test]
 fn class_declaration_constructor() {
     test_formatting(
@@ -1,4 +1,4 @@
-use crate::syntax::ast::node::{join_nodes, FormalParameterList, Node, StatementList};
+use crate::syntax::ast::node::{join_nodes, FormalParameterList, Node, StatementList, TakeNodes};
 use boa_interner::{Interner, Sym, ToInternedString};
 
 #[cfg(feature = "deser")]
@@ -84,6 +84,12 @@ impl FunctionDecl {
     }
 }
 
+impl TakeNodes for FunctionDecl {
+    fn take_nodes(&mut self, nodes: &mut Vec<Node>) {
+        self.body.take_nodes(nodes);
+    }
+}
+
 impl From<FunctionDecl> for Node {
     fn from(decl: FunctionDecl) -> Self {
         Self::FunctionDecl(decl)
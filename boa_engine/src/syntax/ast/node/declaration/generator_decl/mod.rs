@@ -1,4 +1,4 @@
-use crate::syntax::ast::node::{join_nodes, FormalParameterList, Node, StatementList};
+use crate::syntax::ast::node::{join_nodes, FormalParameterList, Node, StatementList, TakeNodes};
 use boa_interner::{Interner, Sym, ToInternedString};
 
 #[cfg(feature = "deser")]
@@ -75,6 +75,12 @@ impl GeneratorDecl {
     }
 }
 
+impl TakeNodes for GeneratorDecl {
+    fn take_nodes(&mut self, nodes: &mut Vec<Node>) {
+        self.body.take_nodes(nodes);
+    }
+}
+
 impl From<GeneratorDecl> for Node {
     fn from(decl: GeneratorDecl) -> Self {
         Self::GeneratorDecl(decl)
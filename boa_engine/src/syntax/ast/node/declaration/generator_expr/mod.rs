@@ -1,4 +1,4 @@
-use crate::syntax::ast::node::{join_nodes, FormalParameterList, Node, StatementList};
+use crate::syntax::ast::node::{join_nodes, FormalParameterList, Node, StatementList, TakeNodes};
 use boa_interner::{Interner, Sym, ToInternedString};
 
 #[cfg(feature = "deser")]
@@ -78,6 +78,12 @@ impl ToInternedString for GeneratorExpr {
     }
 }
 
+impl TakeNodes for GeneratorExpr {
+    fn take_nodes(&mut self, nodes: &mut Vec<Node>) {
+        self.body.take_nodes(nodes);
+    }
+}
+
 impl From<GeneratorExpr> for Node {
     fn from(expr: GeneratorExpr) -> Self {
         Self::GeneratorExpr(expr)
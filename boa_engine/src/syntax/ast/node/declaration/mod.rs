@@ -3,8 +3,9 @@ use crate::syntax::ast::node::{
     field::{GetConstField, GetField},
     join_nodes,
     object::PropertyName,
+    precedence,
     statement_list::StatementList,
-    ContainsSymbol, Identifier, Node,
+    ContainsSymbol, Identifier, Node, TakeNodes,
 };
 use boa_interner::{Interner, Sym, ToInternedString};
 
@@ -123,6 +124,14 @@ impl ToInternedString for DeclarationList {
     }
 }
 
+impl TakeNodes for DeclarationList {
+    fn take_nodes(&mut self, nodes: &mut Vec<Node>) {
+        match self {
+            Self::Const(list) | Self::Let(list) | Self::Var(list) => list.take_nodes(nodes),
+        }
+    }
+}
+
 impl From<DeclarationList> for Node {
     fn from(list: DeclarationList) -> Self {
         use DeclarationList::{Const, Let, Var};
@@ -168,7 +177,10 @@ impl ToInternedString for Declaration {
             Self::Identifier { ident, init } => {
                 let mut buf = ident.to_interned_string(interner);
                 if let Some(ref init) = &init {
-                    buf.push_str(&format!(" = {}", init.to_interned_string(interner)));
+                    buf.push_str(&format!(
+                        " = {}",
+                        precedence::operand(init, precedence::ASSIGNMENT, interner)
+                    ));
                 }
                 buf
             }
@@ -177,6 +189,15 @@ impl ToInternedString for Declaration {
     }
 }
 
+impl TakeNodes for Declaration {
+    fn take_nodes(&mut self, nodes: &mut Vec<Node>) {
+        // The nodes in binding patterns are dropped recursively.
+        if let Self::Identifier { init, .. } = self {
+            init.take_nodes(nodes);
+        }
+    }
+}
+
 impl Declaration {
     /// Creates a new variable declaration with a `BindingIdentifier`.
     #[inline]
@@ -1,4 +1,4 @@
-use crate::syntax::ast::node::Node;
+use crate::syntax::ast::node::{precedence, Node, TakeNodes};
 use boa_interner::{Interner, Sym, ToInternedString};
 
 #[cfg(feature = "deser")]
@@ -59,12 +59,18 @@ impl ToInternedString for GetConstField {
     fn to_interned_string(&self, interner: &Interner) -> String {
         format!(
             "{}.{}",
-            self.obj.to_interned_string(interner),
+            precedence::object(&self.obj, interner),
             interner.resolve_expect(self.field)
         )
     }
 }
 
+impl TakeNodes for GetConstField {
+    fn take_nodes(&mut self, nodes: &mut Vec<Node>) {
+        self.obj.take_nodes(nodes);
+    }
+}
+
 impl From<GetConstField> for Node {
     fn from(get_const_field: GetConstField) -> Self {
         Self::GetConstField(get_const_field)
@@ -1,4 +1,4 @@
-use crate::syntax::ast::node::Node;
+use crate::syntax::ast::node::{precedence, Node, TakeNodes};
 use boa_interner::{Interner, ToInternedString};
 
 #[cfg(feature = "deser")]
@@ -59,12 +59,19 @@ impl ToInternedString for GetField {
     fn to_interned_string(&self, interner: &Interner) -> String {
         format!(
             "{}[{}]",
-            self.obj.to_interned_string(interner),
+            precedence::object(&self.obj, interner),
             self.field.to_interned_string(interner)
         )
     }
 }
 
+impl TakeNodes for GetField {
+    fn take_nodes(&mut self, nodes: &mut Vec<Node>) {
+        self.obj.take_nodes(nodes);
+        self.field.take_nodes(nodes);
+    }
+}
+
 impl From<GetField> for Node {
     fn from(get_field: GetField) -> Self {
         Self::GetField(get_field)
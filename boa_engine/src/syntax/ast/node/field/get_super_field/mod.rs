@@ -1,4 +1,4 @@
-use crate::syntax::ast::node::Node;
+use crate::syntax::ast::node::{Node, TakeNodes};
 use boa_interner::{Interner, Sym, ToInternedString};
 
 #[cfg(feature = "deser")]
@@ -40,6 +40,14 @@ impl ToInternedString for GetSuperField {
     }
 }
 
+impl TakeNodes for GetSuperField {
+    fn take_nodes(&mut self, nodes: &mut Vec<Node>) {
+        if let Self::Expr(expr) = self {
+            expr.take_nodes(nodes);
+        }
+    }
+}
+
 impl From<GetSuperField> for Node {
     fn from(get_super_field: GetSuperField) -> Self {
         Self::GetSuperField(get_super_field)
@@ -1,4 +1,4 @@
-use crate::syntax::ast::node::Node;
+use crate::syntax::ast::node::{statement_to_string, Node, TakeNodes};
 use boa_interner::{Interner, Sym, ToInternedString};
 
 #[cfg(feature = "deser")]
@@ -67,7 +67,7 @@ impl DoWhileLoop {
         };
         buf.push_str(&format!(
             "do {} while ({})",
-            self.body().to_indented_string(interner, indentation),
+            statement_to_string(self.body(), interner, indentation),
             self.cond().to_interned_string(interner)
         ));
 
@@ -81,6 +81,13 @@ impl ToInternedString for DoWhileLoop {
     }
 }
 
+impl TakeNodes for DoWhileLoop {
+    fn take_nodes(&mut self, nodes: &mut Vec<Node>) {
+        self.body.take_nodes(nodes);
+        self.cond.take_nodes(nodes);
+    }
+}
+
 impl From<DoWhileLoop> for Node {
     fn from(do_while: DoWhileLoop) -> Self {
         Self::DoWhileLoop(do_while)
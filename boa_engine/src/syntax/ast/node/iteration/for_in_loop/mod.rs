@@ -1,4 +1,4 @@
-use crate::syntax::ast::node::{iteration::IterableLoopInitializer, Node};
+use crate::syntax::ast::node::{iteration::IterableLoopInitializer, Node, TakeNodes};
 use boa_interner::{Interner, Sym, ToInternedString};
 
 #[cfg(feature = "deser")]
@@ -75,6 +75,13 @@ impl ToInternedString for ForInLoop {
     }
 }
 
+impl TakeNodes for ForInLoop {
+    fn take_nodes(&mut self, nodes: &mut Vec<Node>) {
+        self.expr.take_nodes(nodes);
+        self.body.take_nodes(nodes);
+    }
+}
+
 impl From<ForInLoop> for Node {
     fn from(for_in: ForInLoop) -> Self {
         Self::ForInLoop(for_in)
@@ -1,4 +1,4 @@
-use crate::syntax::ast::node::Node;
+use crate::syntax::ast::node::{Node, TakeNodes};
 use boa_interner::{Interner, Sym, ToInternedString};
 
 #[cfg(feature = "deser")]
@@ -104,6 +104,15 @@ impl ToInternedString for ForLoop {
     }
 }
 
+impl TakeNodes for ForLoop {
+    fn take_nodes(&mut self, nodes: &mut Vec<Node>) {
+        self.inner.init.take_nodes(nodes);
+        self.inner.condition.take_nodes(nodes);
+        self.inner.final_expr.take_nodes(nodes);
+        self.inner.body.take_nodes(nodes);
+    }
+}
+
 impl From<ForLoop> for Node {
     fn from(for_loop: ForLoop) -> Self {
         Self::ForLoop(for_loop)
@@ -1,4 +1,4 @@
-use crate::syntax::ast::node::{iteration::IterableLoopInitializer, Node};
+use crate::syntax::ast::node::{iteration::IterableLoopInitializer, Node, TakeNodes};
 use boa_interner::{Interner, Sym, ToInternedString};
 
 #[cfg(feature = "deser")]
@@ -76,6 +76,13 @@ impl ToInternedString for ForOfLoop {
     }
 }
 
+impl TakeNodes for ForOfLoop {
+    fn take_nodes(&mut self, nodes: &mut Vec<Node>) {
+        self.iterable.take_nodes(nodes);
+        self.body.take_nodes(nodes);
+    }
+}
+
 impl From<ForOfLoop> for Node {
     fn from(for_of: ForOfLoop) -> Self {
         Self::ForOfLoop(for_of)
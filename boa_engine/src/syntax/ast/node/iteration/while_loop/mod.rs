@@ -1,4 +1,4 @@
-use crate::syntax::ast::node::Node;
+use crate::syntax::ast::node::{statement_to_string, Node, TakeNodes};
 use boa_interner::{Interner, Sym, ToInternedString};
 
 #[cfg(feature = "deser")]
@@ -67,7 +67,7 @@ impl WhileLoop {
         buf.push_str(&format!(
             "while ({}) {}",
             self.cond().to_interned_string(interner),
-            self.body().to_indented_string(interner, indentation)
+            statement_to_string(self.body(), interner, indentation)
         ));
 
         buf
@@ -80,6 +80,13 @@ impl ToInternedString for WhileLoop {
     }
 }
 
+impl TakeNodes for WhileLoop {
+    fn take_nodes(&mut self, nodes: &mut Vec<Node>) {
+        self.cond.take_nodes(nodes);
+        self.body.take_nodes(nodes);
+    }
+}
+
 impl From<WhileLoop> for Node {
     fn from(while_loop: WhileLoop) -> Self {
         Self::WhileLoop(while_loop)
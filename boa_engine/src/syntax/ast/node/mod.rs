@@ -1,6 +1,7 @@
 //! This module implements the `Node` structure, which composes the AST.
 
 mod parameters;
+mod precedence;
 
 pub mod array;
 pub mod await_expr;
@@ -14,6 +15,7 @@ pub mod iteration;
 pub mod new;
 pub mod object;
 pub mod operator;
+pub mod regexp;
 pub mod return_smt;
 pub mod spread;
 pub mod statement_list;
@@ -44,6 +46,7 @@ pub use self::{
     operator::{Assign, BinOp, UnaryOp},
     parameters::{FormalParameter, FormalParameterList},
     r#yield::Yield,
+    regexp::RegExpLiteral,
     return_smt::Return,
     spread::Spread,
     statement_list::StatementList,
@@ -60,7 +63,7 @@ use self::{
     operator::assign::AssignTarget,
 };
 
-pub(crate) use self::parameters::FormalParameterListFlags;
+pub(crate) use self::parameters::{FormalParameterListFlags, FrozenFormalParameterList};
 
 use super::Const;
 use boa_interner::{Interner, Sym, ToInternedString};
@@ -70,6 +73,14 @@ use std::cmp::Ordering;
 #[cfg(feature = "deser")]
 use serde::{Deserialize, Serialize};
 
+/// Returns the names bound by a declaration.
+fn declaration_names(declaration: &Declaration) -> Vec<Sym> {
+    match declaration {
+        Declaration::Identifier { ident, .. } => vec![ident.sym()],
+        Declaration::Pattern(pattern) => pattern.idents(),
+    }
+}
+
 // TODO: This should be split into Expression and Statement.
 #[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
 #[derive(Clone, Debug, PartialEq)]
@@ -176,6 +187,9 @@ pub enum Node {
     /// An object. [More information](./object/struct.Object.html).
     Object(Object),
 
+    /// A regular expression literal. [More information](./regexp/struct.RegExpLiteral.html).
+    RegExpLiteral(RegExpLiteral),
+
     /// A return statement. [More information](./object/struct.Return.html).
     Return(Return),
 
@@ -264,6 +278,54 @@ impl From<Const> for Node {
     }
 }
 
+impl Drop for Node {
+    fn drop(&mut self) {
+        // Dropping the children of a node recursively overflows the stack for deeply nested code,
+        // like a long chain of member accesses, so the descendants are moved to a worklist instead
+        // and dropped one by one after their own children have been taken.
+        let mut nodes = Vec::new();
+        self.take_children(&mut nodes);
+        while let Some(mut node) = nodes.pop() {
+            node.take_children(&mut nodes);
+        }
+    }
+}
+
+/// Moves the nodes stored in a value to a list, leaving empty nodes in their place.
+///
+/// This is used by the `Drop` implementation of [`Node`].
+trait TakeNodes {
+    fn take_nodes(&mut self, nodes: &mut Vec<Node>);
+}
+
+impl TakeNodes for Node {
+    fn take_nodes(&mut self, nodes: &mut Vec<Node>) {
+        nodes.push(std::mem::replace(self, Self::Empty));
+    }
+}
+
+impl<T: TakeNodes + ?Sized> TakeNodes for Box<T> {
+    fn take_nodes(&mut self, nodes: &mut Vec<Node>) {
+        (**self).take_nodes(nodes);
+    }
+}
+
+impl<T: TakeNodes> TakeNodes for Option<T> {
+    fn take_nodes(&mut self, nodes: &mut Vec<Node>) {
+        if let Some(value) = self {
+            value.take_nodes(nodes);
+        }
+    }
+}
+
+impl<T: TakeNodes> TakeNodes for [T] {
+    fn take_nodes(&mut self, nodes: &mut Vec<Node>) {
+        for value in self {
+            value.take_nodes(nodes);
+        }
+    }
+}
+
 impl Node {
     /// Returns a node ordering based on the hoistability of each node.
     pub(crate) fn hoistable_order(a: &Self, b: &Self) -> Ordering {
@@ -276,6 +338,65 @@ impl Node {
         }
     }
 
+    /// Moves the direct children of this node to the given list, leaving empty nodes in their
+    /// place.
+    ///
+    /// The nodes inside binding patterns and formal parameters are left in place.
+    fn take_children(&mut self, nodes: &mut Vec<Self>) {
+        match self {
+            Self::ArrayDecl(n) => n.take_nodes(nodes),
+            Self::ArrowFunctionDecl(n) => n.take_nodes(nodes),
+            Self::Assign(n) => n.take_nodes(nodes),
+            Self::AsyncFunctionDecl(n) => n.take_nodes(nodes),
+            Self::AsyncFunctionExpr(n) => n.take_nodes(nodes),
+            Self::AsyncGeneratorExpr(n) => n.take_nodes(nodes),
+            Self::AsyncGeneratorDecl(n) => n.take_nodes(nodes),
+            Self::AwaitExpr(n) => n.take_nodes(nodes),
+            Self::BinOp(n) => n.take_nodes(nodes),
+            Self::Block(n) => n.take_nodes(nodes),
+            Self::Call(n) => n.take_nodes(nodes),
+            Self::ConditionalOp(n) => n.take_nodes(nodes),
+            Self::ConstDeclList(n) | Self::LetDeclList(n) | Self::VarDeclList(n) => {
+                n.take_nodes(nodes);
+            }
+            Self::DoWhileLoop(n) => n.take_nodes(nodes),
+            Self::FunctionDecl(n) => n.take_nodes(nodes),
+            Self::FunctionExpr(n) => n.take_nodes(nodes),
+            Self::GetConstField(n) => n.take_nodes(nodes),
+            Self::GetPrivateField(n) => n.take_nodes(nodes),
+            Self::GetField(n) => n.take_nodes(nodes),
+            Self::GetSuperField(n) => n.take_nodes(nodes),
+            Self::ForLoop(n) => n.take_nodes(nodes),
+            Self::ForInLoop(n) => n.take_nodes(nodes),
+            Self::ForOfLoop(n) => n.take_nodes(nodes),
+            Self::If(n) => n.take_nodes(nodes),
+            Self::New(n) => n.take_nodes(nodes),
+            Self::Object(n) => n.take_nodes(nodes),
+            Self::Return(n) => n.take_nodes(nodes),
+            Self::Switch(n) => n.take_nodes(nodes),
+            Self::Spread(n) => n.take_nodes(nodes),
+            Self::TaggedTemplate(n) => n.take_nodes(nodes),
+            Self::TemplateLit(n) => n.take_nodes(nodes),
+            Self::Throw(n) => n.take_nodes(nodes),
+            Self::Try(n) => n.take_nodes(nodes),
+            Self::UnaryOp(n) => n.take_nodes(nodes),
+            Self::WhileLoop(n) => n.take_nodes(nodes),
+            Self::Yield(n) => n.take_nodes(nodes),
+            Self::GeneratorDecl(n) => n.take_nodes(nodes),
+            Self::GeneratorExpr(n) => n.take_nodes(nodes),
+            Self::ClassDecl(n) | Self::ClassExpr(n) => n.take_nodes(nodes),
+            Self::SuperCall(n) => n.take_nodes(nodes),
+            Self::Break(_)
+            | Self::Const(_)
+            | Self::Continue(_)
+            | Self::Identifier(_)
+            | Self::RegExpLiteral(_)
+            | Self::This
+            | Self::Empty
+            | Self::FormalParameterList(_) => {}
+        }
+    }
+
     /// Creates a `This` AST node.
     pub fn this() -> Self {
         Self::This
@@ -344,6 +465,7 @@ impl Node {
             Self::ArrowFunctionDecl(ref decl) => decl.to_indented_string(interner, indentation),
             Self::BinOp(ref op) => op.to_interned_string(interner),
             Self::UnaryOp(ref op) => op.to_interned_string(interner),
+            Self::RegExpLiteral(ref regexp) => regexp.to_interned_string(interner),
             Self::Return(ref ret) => ret.to_interned_string(interner),
             Self::TaggedTemplate(ref template) => template.to_interned_string(interner),
             Self::TemplateLit(ref template) => template.to_interned_string(interner),
@@ -480,6 +602,160 @@ impl Node {
         }
     }
 
+    /// Collects the names of the function declarations in the blocks of this statement that get a
+    /// var binding under the web compatibility semantics for block-level functions.
+    ///
+    /// `lexical` holds the names lexically declared by the enclosing blocks. A function declaration
+    /// doesn't get a var binding if replacing it with a `var` declaration would redeclare one of
+    /// them. Function declarations directly in a function body or script are not collected.
+    ///
+    /// More information:
+    ///  - [ECMAScript specification][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-block-level-function-declarations-web-legacy-compatibility-semantics
+    pub(crate) fn annex_b_function_names(
+        &self,
+        lexical: &mut Vec<Sym>,
+        names: &mut FxHashSet<Sym>,
+    ) {
+        let len = lexical.len();
+        match self {
+            Node::Block(block) => Self::annex_b_block_function_names(block.items(), lexical, names),
+            Node::If(if_statement) => {
+                if_statement.body().annex_b_function_names(lexical, names);
+                if let Some(node) = if_statement.else_node() {
+                    node.annex_b_function_names(lexical, names);
+                }
+            }
+            Node::DoWhileLoop(do_while_loop) => {
+                do_while_loop.body().annex_b_function_names(lexical, names);
+            }
+            Node::WhileLoop(while_loop) => {
+                while_loop.body().annex_b_function_names(lexical, names);
+            }
+            Node::ForLoop(for_loop) => {
+                if let Some(Node::LetDeclList(list) | Node::ConstDeclList(list)) = for_loop.init() {
+                    for declaration in list.as_ref() {
+                        lexical.extend(declaration_names(declaration));
+                    }
+                }
+                for_loop.body().annex_b_function_names(lexical, names);
+            }
+            Node::ForInLoop(for_in_loop) => {
+                if let IterableLoopInitializer::Let(declaration)
+                | IterableLoopInitializer::Const(declaration) = for_in_loop.init()
+                {
+                    lexical.extend(declaration_names(declaration));
+                }
+                for_in_loop.body().annex_b_function_names(lexical, names);
+            }
+            Node::ForOfLoop(for_of_loop) => {
+                if let IterableLoopInitializer::Let(declaration)
+                | IterableLoopInitializer::Const(declaration) = for_of_loop.init()
+                {
+                    lexical.extend(declaration_names(declaration));
+                }
+                for_of_loop.body().annex_b_function_names(lexical, names);
+            }
+            Node::Switch(switch) => {
+                let items: Vec<_> = switch
+                    .cases()
+                    .iter()
+                    .flat_map(|case| case.body().items())
+                    .chain(switch.default().into_iter().flatten())
+                    .collect();
+                Self::annex_b_block_function_names(items, lexical, names);
+            }
+            Node::Try(try_statement) => {
+                Self::annex_b_block_function_names(try_statement.block().items(), lexical, names);
+                if let Some(catch) = try_statement.catch() {
+                    if let Some(parameter) = catch.parameter() {
+                        lexical.extend(declaration_names(parameter));
+                    }
+                    Self::annex_b_block_function_names(catch.block().items(), lexical, names);
+                    lexical.truncate(len);
+                }
+                if let Some(finally) = try_statement.finally() {
+                    Self::annex_b_block_function_names(finally.items(), lexical, names);
+                }
+            }
+            _ => {}
+        }
+        lexical.truncate(len);
+    }
+
+    /// Collects the names of the function declarations of a block, and of the blocks nested in it,
+    /// that get a var binding under the web compatibility semantics.
+    fn annex_b_block_function_names<'a, I>(
+        items: I,
+        lexical: &mut Vec<Sym>,
+        names: &mut FxHashSet<Sym>,
+    ) where
+        I: IntoIterator<Item = &'a Node>,
+        I::IntoIter: Clone,
+    {
+        let items = items.into_iter();
+        let len = lexical.len();
+        for node in items.clone() {
+            match node {
+                Node::FunctionDecl(decl) => {
+                    if !lexical[..len].contains(&decl.name()) {
+                        names.insert(decl.name());
+                    }
+                    lexical.push(decl.name());
+                }
+                Node::GeneratorDecl(decl) => lexical.push(decl.name()),
+                Node::AsyncFunctionDecl(decl) => lexical.push(decl.name()),
+                Node::AsyncGeneratorDecl(decl) => lexical.push(decl.name()),
+                Node::ClassDecl(decl) => lexical.push(decl.name()),
+                Node::LetDeclList(list) | Node::ConstDeclList(list) => {
+                    for declaration in list.as_ref() {
+                        lexical.extend(declaration_names(declaration));
+                    }
+                }
+                _ => {}
+            }
+        }
+        for node in items {
+            node.annex_b_function_names(lexical, names);
+        }
+        lexical.truncate(len);
+    }
+
+    /// Walks down a chain of binary operations, property accesses and calls, like `a + b.c()`,
+    /// and returns the node that starts the chain.
+    ///
+    /// Every link of a chain is nested in the next one, so the walks of the syntax tree follow
+    /// chains in a loop, and only recurse into the other operands of each link with `visit`. The
+    /// walk stops early, returning `None`, as soon as `visit` returns `true`.
+    fn chain_start(&self, visit: impl Fn(&Self) -> bool) -> Option<&Self> {
+        let mut node = self;
+        loop {
+            node = match node {
+                Node::BinOp(bin_op) => {
+                    if visit(bin_op.rhs()) {
+                        return None;
+                    }
+                    bin_op.lhs()
+                }
+                Node::Call(call) => {
+                    if call.args().iter().any(&visit) {
+                        return None;
+                    }
+                    call.expr()
+                }
+                Node::GetConstField(field) => field.obj(),
+                Node::GetField(field) => {
+                    if visit(field.field()) {
+                        return None;
+                    }
+                    field.obj()
+                }
+                _ => return Some(node),
+            };
+        }
+    }
+
     /// Returns true if the node contains a identifier reference named 'arguments'.
     ///
     /// More information:
@@ -487,7 +763,11 @@ impl Node {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-static-semantics-containsarguments
     pub(crate) fn contains_arguments(&self) -> bool {
-        match self {
+        let node = match self.chain_start(Self::contains_arguments) {
+            Some(node) => node,
+            None => return true,
+        };
+        match node {
             Node::Identifier(ident) if ident.sym() == Sym::ARGUMENTS => return true,
             Node::ArrayDecl(array) => {
                 for node in array.as_ref() {
@@ -513,11 +793,6 @@ impl Node {
                     return true;
                 }
             }
-            Node::BinOp(bin_op) => {
-                if bin_op.lhs().contains_arguments() || bin_op.rhs().contains_arguments() {
-                    return true;
-                }
-            }
             Node::Block(block) => {
                 for node in block.items() {
                     if node.contains_arguments() {
@@ -525,16 +800,6 @@ impl Node {
                     }
                 }
             }
-            Node::Call(call) => {
-                if call.expr().contains_arguments() {
-                    return true;
-                }
-                for node in call.args() {
-                    if node.contains_arguments() {
-                        return true;
-                    }
-                }
-            }
             Node::ConditionalOp(conditional) => {
                 if conditional.cond().contains_arguments() {
                     return true;
@@ -554,24 +819,11 @@ impl Node {
                     return true;
                 }
             }
-            Node::GetConstField(get_const_field) => {
-                if get_const_field.obj().contains_arguments() {
-                    return true;
-                }
-            }
             Node::GetPrivateField(get_private_field) => {
                 if get_private_field.obj().contains_arguments() {
                     return true;
                 }
             }
-            Node::GetField(get_field) => {
-                if get_field.obj().contains_arguments() {
-                    return true;
-                }
-                if get_field.field().contains_arguments() {
-                    return true;
-                }
-            }
             Node::ForLoop(for_loop) => {
                 if let Some(node) = for_loop.init() {
                     if node.contains_arguments() {
@@ -909,7 +1161,11 @@ impl Node {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-static-semantics-contains
     pub(crate) fn contains(&self, symbol: ContainsSymbol) -> bool {
-        match self {
+        let node = match self.chain_start(|node| node.contains(symbol)) {
+            Some(node) => node,
+            None => return true,
+        };
+        match node {
             Node::ArrayDecl(array) => {
                 for node in array.as_ref() {
                     if node.contains(symbol) {
@@ -951,11 +1207,6 @@ impl Node {
                     return true;
                 }
             }
-            Node::BinOp(bin_op) => {
-                if bin_op.lhs().contains(symbol) || bin_op.rhs().contains(symbol) {
-                    return true;
-                }
-            }
             Node::Block(block) => {
                 for node in block.items() {
                     if node.contains(symbol) {
@@ -963,16 +1214,6 @@ impl Node {
                     }
                 }
             }
-            Node::Call(call) => {
-                if call.expr().contains(symbol) {
-                    return true;
-                }
-                for node in call.args() {
-                    if node.contains(symbol) {
-                        return true;
-                    }
-                }
-            }
             Node::ConditionalOp(conditional) => {
                 if conditional.cond().contains(symbol)
                     || conditional.if_true().contains(symbol)
@@ -999,21 +1240,11 @@ impl Node {
                     return true;
                 }
             }
-            Node::GetConstField(field) => {
-                if field.obj().contains(symbol) {
-                    return true;
-                }
-            }
             Node::GetPrivateField(field) => {
                 if field.obj().contains(symbol) {
                     return true;
                 }
             }
-            Node::GetField(field) => {
-                if field.obj().contains(symbol) || field.field().contains(symbol) {
-                    return true;
-                }
-            }
             Node::ForLoop(for_loop) => {
                 if let Some(node) = for_loop.init() {
                     if node.contains(symbol) {
@@ -1269,6 +1500,20 @@ impl ToInternedString for Node {
     }
 }
 
+/// Prints a statement that is nested in another statement, such as the body of a loop.
+///
+/// The statement is terminated here, since the surrounding statement might not be terminated
+/// after it.
+fn statement_to_string(node: &Node, interner: &Interner, indentation: usize) -> String {
+    let statement = node.to_no_indent_string(interner, indentation);
+    match node {
+        Node::Block(_) | Node::If(_) | Node::Switch(_) | Node::WhileLoop(_) | Node::Empty => {
+            statement
+        }
+        _ => format!("{statement};"),
+    }
+}
+
 /// Utility to join multiple Nodes into a single string.
 fn join_nodes<N>(interner: &Interner, nodes: &[N]) -> String
 where
@@ -1,4 +1,4 @@
-use crate::syntax::ast::node::{Call, Node};
+use crate::syntax::ast::node::{precedence, Call, Node, TakeNodes};
 use boa_interner::{Interner, ToInternedString};
 
 #[cfg(feature = "deser")]
@@ -53,7 +53,17 @@ impl From<Call> for New {
 
 impl ToInternedString for New {
     fn to_interned_string(&self, interner: &Interner) -> String {
-        format!("new {}", self.call.to_interned_string(interner))
+        format!(
+            "new {}({})",
+            precedence::constructor(self.expr(), interner),
+            precedence::join_operands(interner, self.args())
+        )
+    }
+}
+
+impl TakeNodes for New {
+    fn take_nodes(&mut self, nodes: &mut Vec<Node>) {
+        self.call.take_nodes(nodes);
     }
 }
 
@@ -4,6 +4,7 @@ fn fmt() {
         r#"
         function MyClass() {};
         let inst = new MyClass();
+        let other = new (factory())();
         "#,
     );
 }
@@ -2,8 +2,9 @@
 
 use crate::syntax::ast::{
     node::{
-        declaration::block_to_string, join_nodes, AsyncFunctionExpr, AsyncGeneratorExpr,
-        FormalParameterList, FunctionExpr, GeneratorExpr, Node, StatementList,
+        declaration::block_to_string, join_nodes, precedence, AsyncFunctionExpr,
+        AsyncGeneratorExpr, FormalParameterList, FunctionExpr, GeneratorExpr, Node, StatementList,
+        TakeNodes,
     },
     Const,
 };
@@ -144,6 +145,12 @@ where
     }
 }
 
+impl TakeNodes for Object {
+    fn take_nodes(&mut self, nodes: &mut Vec<Node>) {
+        self.properties.take_nodes(nodes);
+    }
+}
+
 impl From<Object> for Node {
     fn from(obj: Object) -> Self {
         Self::Object(obj)
@@ -420,8 +427,23 @@ impl PropertyName {
 impl ToInternedString for PropertyName {
     fn to_interned_string(&self, interner: &Interner) -> String {
         match self {
-            PropertyName::Literal(key) => interner.resolve_expect(*key).to_owned(),
-            PropertyName::Computed(key) => key.to_interned_string(interner),
+            PropertyName::Literal(key) => {
+                let name = interner.resolve_expect(*key);
+                let is_identifier_name = !name.starts_with(|c: char| c.is_ascii_digit())
+                    && !name.is_empty()
+                    && name
+                        .chars()
+                        .all(|c| c.is_alphanumeric() || c == '_' || c == '$');
+                if is_identifier_name {
+                    name.to_owned()
+                } else {
+                    Const::from(*key).to_interned_string(interner)
+                }
+            }
+            PropertyName::Computed(key) => format!(
+                "[{}]",
+                precedence::operand(key, precedence::ASSIGNMENT, interner)
+            ),
         }
     }
 }
@@ -450,3 +472,41 @@ pub(crate) enum ClassElementName {
     PropertyName(PropertyName),
     PrivateIdentifier(Sym),
 }
+
+impl TakeNodes for PropertyDefinition {
+    fn take_nodes(&mut self, nodes: &mut Vec<Node>) {
+        match self {
+            Self::IdentifierReference(_) => {}
+            Self::Property(name, node) => {
+                name.take_nodes(nodes);
+                node.take_nodes(nodes);
+            }
+            Self::MethodDefinition(method, name) => {
+                method.take_nodes(nodes);
+                name.take_nodes(nodes);
+            }
+            Self::SpreadObject(node) | Self::CoverInitializedName(_, node) => {
+                node.take_nodes(nodes);
+            }
+        }
+    }
+}
+
+impl TakeNodes for MethodDefinition {
+    fn take_nodes(&mut self, nodes: &mut Vec<Node>) {
+        match self {
+            Self::Get(expr) | Self::Set(expr) | Self::Ordinary(expr) => expr.take_nodes(nodes),
+            Self::Generator(expr) => expr.take_nodes(nodes),
+            Self::AsyncGenerator(expr) => expr.take_nodes(nodes),
+            Self::Async(expr) => expr.take_nodes(nodes),
+        }
+    }
+}
+
+impl TakeNodes for PropertyName {
+    fn take_nodes(&mut self, nodes: &mut Vec<Node>) {
+        if let Self::Computed(node) = self {
+            node.take_nodes(nodes);
+        }
+    }
+}
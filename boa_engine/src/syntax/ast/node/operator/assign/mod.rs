@@ -6,7 +6,8 @@ use crate::syntax::{
         },
         field::get_private_field::GetPrivateField,
         object::{PropertyDefinition, PropertyName},
-        ArrayDecl, DeclarationPattern, GetConstField, GetField, Identifier, Node, Object,
+        precedence, ArrayDecl, DeclarationPattern, GetConstField, GetField, Identifier, Node,
+        Object, TakeNodes,
     },
     parser::RESERVED_IDENTIFIERS_STRICT,
 };
@@ -62,11 +63,18 @@ impl ToInternedString for Assign {
         format!(
             "{} = {}",
             self.lhs.to_interned_string(interner),
-            self.rhs.to_interned_string(interner)
+            precedence::operand(&self.rhs, precedence::ASSIGNMENT, interner)
         )
     }
 }
 
+impl TakeNodes for Assign {
+    fn take_nodes(&mut self, nodes: &mut Vec<Node>) {
+        self.lhs.take_nodes(nodes);
+        self.rhs.take_nodes(nodes);
+    }
+}
+
 impl From<Assign> for Node {
     fn from(op: Assign) -> Self {
         Self::Assign(op)
@@ -111,6 +119,17 @@ impl AssignTarget {
     }
 }
 
+impl TakeNodes for AssignTarget {
+    fn take_nodes(&mut self, nodes: &mut Vec<Node>) {
+        match self {
+            Self::Identifier(_) | Self::DeclarationPattern(_) => {}
+            Self::GetPrivateField(field) => field.take_nodes(nodes),
+            Self::GetConstField(field) => field.take_nodes(nodes),
+            Self::GetField(field) => field.take_nodes(nodes),
+        }
+    }
+}
+
 impl ToInternedString for AssignTarget {
     fn to_interned_string(&self, interner: &Interner) -> String {
         match self {
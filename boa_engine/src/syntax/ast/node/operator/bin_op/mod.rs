@@ -1,4 +1,7 @@
-use crate::syntax::ast::{node::Node, op};
+use crate::syntax::ast::{
+    node::{precedence, Node, TakeNodes},
+    op::{self, LogOp, NumOp},
+};
 use boa_interner::{Interner, ToInternedString};
 
 #[cfg(feature = "deser")]
@@ -51,12 +54,29 @@ impl BinOp {
 
 impl ToInternedString for BinOp {
     fn to_interned_string(&self, interner: &Interner) -> String {
-        format!(
-            "{} {} {}",
-            self.lhs.to_interned_string(interner),
-            self.op,
-            self.rhs.to_interned_string(interner)
-        )
+        let precedence = precedence::binary(self.op);
+        let (lhs_precedence, rhs_precedence) = match self.op {
+            // The base of an exponentiation can't be an unary expression.
+            op::BinOp::Num(NumOp::Exp) => (precedence::UPDATE, precedence),
+            op::BinOp::Assign(_) => (precedence + 1, precedence),
+            // The nullish coalescing operator can't be mixed with the other logical operators.
+            op::BinOp::Log(LogOp::Coalesce) => (precedence::BIT_OR, precedence::BIT_OR),
+            _ => (precedence, precedence + 1),
+        };
+        let lhs = precedence::operand(&self.lhs, lhs_precedence, interner);
+        let rhs = precedence::operand(&self.rhs, rhs_precedence, interner);
+        if self.op == op::BinOp::Comma {
+            format!("{lhs}, {rhs}")
+        } else {
+            format!("{lhs} {} {rhs}", self.op)
+        }
+    }
+}
+
+impl TakeNodes for BinOp {
+    fn take_nodes(&mut self, nodes: &mut Vec<Node>) {
+        self.lhs.take_nodes(nodes);
+        self.rhs.take_nodes(nodes);
     }
 }
 
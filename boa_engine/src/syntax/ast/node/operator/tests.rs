@@ -138,3 +138,25 @@ fn fmt() {
         "#,
     );
 }
+
+#[test]
+fn fmt_precedence() {
+    super::super::test_formatting(
+        r#"
+        (a + b) * c;
+        a + b * c;
+        a - (b - c);
+        a ** b ** c;
+        (a ** b) ** c;
+        (-a) ** 2;
+        -(-a);
+        typeof a;
+        !(a && b);
+        (a ?? b) || c;
+        (a, b) ? c : d;
+        x = (a, b);
+        (a = b).c;
+        (1).toString();
+        "#,
+    );
+}
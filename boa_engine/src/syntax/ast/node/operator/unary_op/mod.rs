@@ -1,4 +1,7 @@
-use crate::syntax::ast::{node::Node, op};
+use crate::syntax::ast::{
+    node::{precedence, Node, TakeNodes},
+    op,
+};
 use boa_interner::{Interner, ToInternedString};
 
 #[cfg(feature = "deser")]
@@ -44,7 +47,38 @@ impl UnaryOp {
 
 impl ToInternedString for UnaryOp {
     fn to_interned_string(&self, interner: &Interner) -> String {
-        format!("{}{}", self.op, self.target.to_interned_string(interner))
+        match self.op {
+            op::UnaryOp::IncrementPost | op::UnaryOp::DecrementPost => format!(
+                "{}{}",
+                precedence::operand(&self.target, precedence::LEFT_HAND_SIDE, interner),
+                self.op
+            ),
+            op::UnaryOp::IncrementPre | op::UnaryOp::DecrementPre => format!(
+                "{}{}",
+                self.op,
+                precedence::operand(&self.target, precedence::LEFT_HAND_SIDE, interner)
+            ),
+            op::UnaryOp::Delete | op::UnaryOp::TypeOf | op::UnaryOp::Void => format!(
+                "{} {}",
+                self.op,
+                precedence::operand(&self.target, precedence::UNARY, interner)
+            ),
+            _ => {
+                let target = precedence::operand(&self.target, precedence::UNARY, interner);
+                // `- -a` must not be printed as the decrement `--a`.
+                if target.starts_with(&self.op.to_string()) {
+                    format!("{}({target})", self.op)
+                } else {
+                    format!("{}{target}", self.op)
+                }
+            }
+        }
+    }
+}
+
+impl TakeNodes for UnaryOp {
+    fn take_nodes(&mut self, nodes: &mut Vec<Node>) {
+        self.target.take_nodes(nodes);
     }
 }
 
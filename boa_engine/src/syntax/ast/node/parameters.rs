@@ -1,6 +1,9 @@
 use crate::syntax::{
     ast::{
-        node::{ContainsSymbol, Declaration, DeclarationPattern, Node},
+        node::{
+            declaration::BindingPatternTypeArray, ContainsSymbol, Declaration, DeclarationPattern,
+            Node,
+        },
         Position,
     },
     parser::ParseError,
@@ -129,6 +132,91 @@ impl FormalParameterList {
     }
 }
 
+/// The parameters of a compiled function, as the VM reads them at runtime, which can be sent to
+/// other threads.
+///
+/// The initializers and binding patterns of the parameters are already compiled to bytecode, so
+/// only their names and kinds are kept.
+#[derive(Debug, Clone)]
+pub(crate) struct FrozenFormalParameterList {
+    parameters: Box<[FrozenFormalParameter]>,
+    flags: FormalParameterListFlags,
+    length: u32,
+}
+
+/// A parameter of a [`FrozenFormalParameterList`].
+#[derive(Debug, Clone)]
+struct FrozenFormalParameter {
+    names: Vec<Sym>,
+    is_identifier: bool,
+    has_init: bool,
+    is_rest_param: bool,
+}
+
+impl FrozenFormalParameterList {
+    /// Gets the names of the parameters.
+    pub(crate) fn names(&self) -> impl Iterator<Item = Sym> + '_ {
+        self.parameters
+            .iter()
+            .flat_map(|parameter| parameter.names.iter().copied())
+    }
+}
+
+impl FormalParameterList {
+    /// Copies the names and kinds of the parameters, so they can be sent to other threads.
+    pub(crate) fn freeze(&self) -> FrozenFormalParameterList {
+        FrozenFormalParameterList {
+            parameters: self
+                .parameters
+                .iter()
+                .map(|parameter| FrozenFormalParameter {
+                    names: parameter.names(),
+                    is_identifier: parameter.is_identifier(),
+                    has_init: parameter.init().is_some(),
+                    is_rest_param: parameter.is_rest_param(),
+                })
+                .collect(),
+            flags: self.flags,
+            length: self.length,
+        }
+    }
+
+    /// Creates a parameter list with the names and kinds of frozen parameters, renaming them
+    /// with `rename`.
+    ///
+    /// Initializers are replaced by empty nodes, and binding patterns by array patterns that
+    /// bind the same names.
+    pub(crate) fn thaw(frozen: &FrozenFormalParameterList, rename: impl Fn(Sym) -> Sym) -> Self {
+        let parameters = frozen
+            .parameters
+            .iter()
+            .map(|parameter| {
+                let init = parameter.has_init.then(|| Node::Empty);
+                let declaration = if parameter.is_identifier {
+                    Declaration::new_with_identifier(rename(parameter.names[0]), init)
+                } else {
+                    let bindings = parameter
+                        .names
+                        .iter()
+                        .map(|name| BindingPatternTypeArray::SingleName {
+                            ident: rename(*name),
+                            default_init: None,
+                        })
+                        .collect();
+                    Declaration::new_with_array_pattern(bindings, init)
+                };
+                FormalParameter::new(declaration, parameter.is_rest_param)
+            })
+            .collect();
+
+        Self {
+            parameters,
+            flags: frozen.flags,
+            length: frozen.length,
+        }
+    }
+}
+
 impl From<Vec<FormalParameter>> for FormalParameterList {
     fn from(parameters: Vec<FormalParameter>) -> Self {
         let mut flags = FormalParameterListFlags::default();
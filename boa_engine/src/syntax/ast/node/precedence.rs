@@ -0,0 +1,131 @@
+//! Precedences of the expressions, used to print the AST.
+//!
+//! The AST doesn't keep the parentheses of the source code, so they are added back to the printed
+//! code wherever it would otherwise be parsed into a different expression.
+
+use super::Node;
+use crate::syntax::ast::{
+    op::{self, BitOp, CompOp, LogOp, NumOp, UnaryOp},
+    Const,
+};
+use boa_interner::{Interner, ToInternedString};
+
+pub(super) const COMMA: u8 = 1;
+pub(super) const ASSIGNMENT: u8 = 2;
+pub(super) const CONDITIONAL: u8 = 3;
+pub(super) const COALESCE: u8 = 4;
+pub(super) const OR: u8 = 5;
+pub(super) const AND: u8 = 6;
+pub(super) const BIT_OR: u8 = 7;
+pub(super) const BIT_XOR: u8 = 8;
+pub(super) const BIT_AND: u8 = 9;
+pub(super) const EQUALITY: u8 = 10;
+pub(super) const RELATIONAL: u8 = 11;
+pub(super) const SHIFT: u8 = 12;
+pub(super) const ADDITIVE: u8 = 13;
+pub(super) const MULTIPLICATIVE: u8 = 14;
+pub(super) const EXPONENTIATION: u8 = 15;
+pub(super) const UNARY: u8 = 16;
+pub(super) const UPDATE: u8 = 17;
+pub(super) const LEFT_HAND_SIDE: u8 = 18;
+pub(super) const PRIMARY: u8 = 19;
+
+/// Gets the precedence of a binary operator.
+pub(super) fn binary(op: op::BinOp) -> u8 {
+    match op {
+        op::BinOp::Comma => COMMA,
+        op::BinOp::Assign(_) => ASSIGNMENT,
+        op::BinOp::Log(LogOp::Coalesce) => COALESCE,
+        op::BinOp::Log(LogOp::Or) => OR,
+        op::BinOp::Log(LogOp::And) => AND,
+        op::BinOp::Bit(BitOp::Or) => BIT_OR,
+        op::BinOp::Bit(BitOp::Xor) => BIT_XOR,
+        op::BinOp::Bit(BitOp::And) => BIT_AND,
+        op::BinOp::Bit(BitOp::Shl | BitOp::Shr | BitOp::UShr) => SHIFT,
+        op::BinOp::Comp(
+            CompOp::Equal | CompOp::NotEqual | CompOp::StrictEqual | CompOp::StrictNotEqual,
+        ) => EQUALITY,
+        op::BinOp::Comp(_) => RELATIONAL,
+        op::BinOp::Num(NumOp::Add | NumOp::Sub) => ADDITIVE,
+        op::BinOp::Num(NumOp::Exp) => EXPONENTIATION,
+        op::BinOp::Num(NumOp::Mul | NumOp::Div | NumOp::Mod) => MULTIPLICATIVE,
+    }
+}
+
+/// Gets the precedence of a node, as an expression.
+pub(super) fn of(node: &Node) -> u8 {
+    match node {
+        Node::BinOp(bin_op) => binary(bin_op.op()),
+        Node::Assign(_) | Node::ArrowFunctionDecl(_) | Node::Yield(_) | Node::Spread(_) => {
+            ASSIGNMENT
+        }
+        Node::ConditionalOp(_) => CONDITIONAL,
+        Node::UnaryOp(unary_op) => match unary_op.op() {
+            UnaryOp::IncrementPost
+            | UnaryOp::IncrementPre
+            | UnaryOp::DecrementPost
+            | UnaryOp::DecrementPre => UPDATE,
+            _ => UNARY,
+        },
+        Node::AwaitExpr(_) => UNARY,
+        Node::Call(_)
+        | Node::New(_)
+        | Node::GetConstField(_)
+        | Node::GetPrivateField(_)
+        | Node::GetField(_)
+        | Node::GetSuperField(_)
+        | Node::TaggedTemplate(_)
+        | Node::SuperCall(_) => LEFT_HAND_SIDE,
+        _ => PRIMARY,
+    }
+}
+
+/// Prints an operand, in parentheses if it binds looser than the given precedence.
+pub(super) fn operand(node: &Node, precedence: u8, interner: &Interner) -> String {
+    if of(node) < precedence {
+        format!("({})", node.to_interned_string(interner))
+    } else {
+        node.to_interned_string(interner)
+    }
+}
+
+/// Prints a list of arguments or elements, separated by commas.
+pub(super) fn join_operands(interner: &Interner, nodes: &[Node]) -> String {
+    nodes
+        .iter()
+        .map(|node| operand(node, ASSIGNMENT, interner))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Prints the object of a property access, or the callee of a call.
+pub(super) fn object(node: &Node, interner: &Interner) -> String {
+    // The dot after a number literal would be parsed as its decimal point.
+    if matches!(node, Node::Const(Const::Int(_) | Const::Num(_))) {
+        return format!("({})", node.to_interned_string(interner));
+    }
+    operand(node, LEFT_HAND_SIDE, interner)
+}
+
+/// Prints the constructor of a `new` expression.
+///
+/// The arguments of a call in the constructor expression would be taken as the arguments of the
+/// `new` expression, so such a constructor is put in parentheses.
+pub(super) fn constructor(node: &Node, interner: &Interner) -> String {
+    fn contains_call(node: &Node) -> bool {
+        match node {
+            Node::Call(_) | Node::SuperCall(_) => true,
+            Node::GetConstField(field) => contains_call(field.obj()),
+            Node::GetField(field) => contains_call(field.obj()),
+            Node::GetPrivateField(field) => contains_call(field.obj()),
+            Node::TaggedTemplate(template) => contains_call(template.tag()),
+            _ => false,
+        }
+    }
+
+    if contains_call(node) {
+        format!("({})", node.to_interned_string(interner))
+    } else {
+        object(node, interner)
+    }
+}
@@ -0,0 +1,61 @@
+//! Regular expression literal node.
+
+use crate::syntax::ast::node::Node;
+use boa_interner::{Interner, Sym, ToInternedString};
+
+#[cfg(feature = "deser")]
+use serde::{Deserialize, Serialize};
+
+/// A regular expression literal is a pattern enclosed between slashes, optionally followed by
+/// flags.
+///
+/// Syntax: `/pattern/flags`
+///
+/// Each evaluation of a regular expression literal creates a new `RegExp` object, using the
+/// intrinsic `%RegExp%` constructor even if the global `RegExp` binding has been modified.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-literals-regular-expression-literals
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Guide/Regular_Expressions
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RegExpLiteral {
+    pattern: Sym,
+    flags: Sym,
+}
+
+impl RegExpLiteral {
+    /// Creates a new regular expression literal AST node.
+    pub fn new(pattern: Sym, flags: Sym) -> Self {
+        Self { pattern, flags }
+    }
+
+    /// Gets the body of the regular expression.
+    pub fn pattern(self) -> Sym {
+        self.pattern
+    }
+
+    /// Gets the flags of the regular expression.
+    pub fn flags(self) -> Sym {
+        self.flags
+    }
+}
+
+impl ToInternedString for RegExpLiteral {
+    fn to_interned_string(&self, interner: &Interner) -> String {
+        format!(
+            "/{}/{}",
+            interner.resolve_expect(self.pattern),
+            interner.resolve_expect(self.flags)
+        )
+    }
+}
+
+impl From<RegExpLiteral> for Node {
+    fn from(regexp: RegExpLiteral) -> Self {
+        Self::RegExpLiteral(regexp)
+    }
+}
@@ -1,4 +1,4 @@
-use crate::syntax::ast::node::Node;
+use crate::syntax::ast::node::{Node, TakeNodes};
 use boa_interner::{Interner, Sym, ToInternedString};
 
 #[cfg(feature = "deser")]
@@ -55,6 +55,12 @@ impl Return {
     }
 }
 
+impl TakeNodes for Return {
+    fn take_nodes(&mut self, nodes: &mut Vec<Node>) {
+        self.expr.take_nodes(nodes);
+    }
+}
+
 impl From<Return> for Node {
     fn from(return_smt: Return) -> Self {
         Self::Return(return_smt)
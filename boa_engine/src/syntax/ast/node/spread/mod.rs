@@ -1,4 +1,4 @@
-use crate::syntax::ast::node::Node;
+use crate::syntax::ast::node::{precedence, Node, TakeNodes};
 use boa_interner::{Interner, ToInternedString};
 
 #[cfg(feature = "deser")]
@@ -48,7 +48,16 @@ impl Spread {
 
 impl ToInternedString for Spread {
     fn to_interned_string(&self, interner: &Interner) -> String {
-        format!("...{}", self.val().to_interned_string(interner))
+        format!(
+            "...{}",
+            precedence::operand(self.val(), precedence::ASSIGNMENT, interner)
+        )
+    }
+}
+
+impl TakeNodes for Spread {
+    fn take_nodes(&mut self, nodes: &mut Vec<Node>) {
+        self.val.take_nodes(nodes);
     }
 }
 
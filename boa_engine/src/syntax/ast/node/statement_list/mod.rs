@@ -1,6 +1,6 @@
 //! Statement list node.
 
-use crate::syntax::ast::node::{Declaration, Node};
+use crate::syntax::ast::node::{Declaration, Node, TakeNodes};
 use boa_interner::{Interner, Sym, ToInternedString};
 
 use rustc_hash::FxHashSet;
@@ -54,7 +54,14 @@ impl StatementList {
         // Print statements
         for node in self.items.iter() {
             // We rely on the node to add the correct indent.
-            buf.push_str(&node.to_indented_string(interner, indentation));
+            let statement = node.to_indented_string(interner, indentation);
+            let code = statement.trim_start();
+            if is_ambiguous_expression(node, code) {
+                buf.push_str(&statement[..statement.len() - code.len()]);
+                buf.push_str(&format!("({code})"));
+            } else {
+                buf.push_str(&statement);
+            }
 
             match node {
                 Node::Block(_) | Node::If(_) | Node::Switch(_) | Node::WhileLoop(_) => {}
@@ -185,6 +192,41 @@ where
     }
 }
 
+/// Returns `true` if the printed code of an expression statement would be parsed as a block or a
+/// declaration, and so needs parentheses.
+fn is_ambiguous_expression(node: &Node, code: &str) -> bool {
+    if matches!(
+        node,
+        Node::Block(_)
+            | Node::FunctionDecl(_)
+            | Node::GeneratorDecl(_)
+            | Node::AsyncFunctionDecl(_)
+            | Node::AsyncGeneratorDecl(_)
+            | Node::ClassDecl(_)
+            | Node::LetDeclList(_)
+    ) {
+        return false;
+    }
+
+    let starts_with_keyword = |keyword: &str| {
+        code.strip_prefix(keyword).map_or(false, |rest| {
+            !rest.starts_with(|c: char| c.is_alphanumeric() || c == '_' || c == '$')
+        })
+    };
+    code.starts_with('{')
+        || starts_with_keyword("function")
+        || starts_with_keyword("class")
+        || starts_with_keyword("async")
+        || code.starts_with("let[")
+        || code.starts_with("let [")
+}
+
+impl TakeNodes for StatementList {
+    fn take_nodes(&mut self, nodes: &mut Vec<Node>) {
+        self.items.take_nodes(nodes);
+    }
+}
+
 impl ToInternedString for StatementList {
     fn to_interned_string(&self, interner: &Interner) -> String {
         self.to_indented_string(interner, 0)
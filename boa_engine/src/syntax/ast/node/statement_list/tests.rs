@@ -1,4 +1,15 @@
-use crate::exec;
+use crate::{
+    exec,
+    syntax::ast::{
+        node::{
+            object::PropertyDefinition, ArrayDecl, BinOp, Block, Call, FormalParameterList,
+            FunctionExpr, GetConstField, GetField, If, Node, Object, Return, StatementList,
+        },
+        op::NumOp,
+        Const,
+    },
+};
+use boa_interner::Sym;
 
 #[test]
 fn strict_mode_global() {
@@ -116,3 +127,28 @@ fn strict_mode_function_return() {
 
     assert_eq!(&exec(scenario), "true");
 }
+
+#[test]
+fn drop_deeply_nested_nodes() {
+    // The nodes are created programmatically, since the parser can't handle such deep nesting.
+    let mut node = Node::from(Const::from(1));
+    for i in 0..100_000 {
+        node = match i % 8 {
+            0 => GetConstField::new(node, Sym::EMPTY_STRING).into(),
+            1 => GetField::new(node, Const::from(0)).into(),
+            2 => BinOp::new(NumOp::Add, node, Const::from(1)).into(),
+            3 => Call::new(Node::This, vec![node]).into(),
+            4 => ArrayDecl::new(vec![node], false).into(),
+            5 => Object::from(vec![PropertyDefinition::property(Sym::EMPTY_STRING, node)]).into(),
+            6 => FunctionExpr::new(
+                None,
+                FormalParameterList::default(),
+                vec![Return::new(node, None).into()],
+            )
+            .into(),
+            _ => If::new::<_, _, Node, _>(Node::This, Block::from(vec![node]), None).into(),
+        };
+    }
+    let list = StatementList::from(vec![Node::Empty, node]);
+    drop(list);
+}
@@ -1,4 +1,4 @@
-use crate::syntax::ast::node::{join_nodes, Node};
+use crate::syntax::ast::node::{join_nodes, Node, TakeNodes};
 use boa_interner::{Interner, ToInternedString};
 
 #[cfg(feature = "deser")]
@@ -39,6 +39,12 @@ impl ToInternedString for SuperCall {
     }
 }
 
+impl TakeNodes for SuperCall {
+    fn take_nodes(&mut self, nodes: &mut Vec<Node>) {
+        self.args.take_nodes(nodes);
+    }
+}
+
 impl From<SuperCall> for Node {
     fn from(call: SuperCall) -> Self {
         Self::SuperCall(call)
@@ -1,6 +1,6 @@
 //! Switch node.
 //!
-use crate::syntax::ast::node::Node;
+use crate::syntax::ast::node::{Node, TakeNodes};
 use boa_interner::{Interner, ToInternedString};
 
 use crate::syntax::ast::node::StatementList;
@@ -42,6 +42,13 @@ impl Case {
     }
 }
 
+impl TakeNodes for Case {
+    fn take_nodes(&mut self, nodes: &mut Vec<Node>) {
+        self.condition.take_nodes(nodes);
+        self.body.take_nodes(nodes);
+    }
+}
+
 /// The `switch` statement evaluates an expression, matching the expression's value to a case
 /// clause, and executes statements associated with that case, as well as statements in cases
 /// that follow the matching case.
@@ -131,6 +138,14 @@ impl ToInternedString for Switch {
     }
 }
 
+impl TakeNodes for Switch {
+    fn take_nodes(&mut self, nodes: &mut Vec<Node>) {
+        self.val.take_nodes(nodes);
+        self.cases.take_nodes(nodes);
+        self.default.take_nodes(nodes);
+    }
+}
+
 impl From<Switch> for Node {
     fn from(switch: Switch) -> Self {
         Self::Switch(switch)
@@ -1,6 +1,6 @@
 //! Template literal node.
 
-use super::Node;
+use super::{precedence, Node, TakeNodes};
 use boa_interner::{Interner, Sym, ToInternedString};
 
 #[cfg(feature = "deser")]
@@ -44,7 +44,19 @@ impl ToInternedString for TemplateLit {
 
         for elt in self.elements.iter() {
             match elt {
-                TemplateElement::String(s) => buf.push_str(interner.resolve_expect(*s)),
+                TemplateElement::String(s) => {
+                    // The strings are cooked, so they have to be escaped again.
+                    let mut chars = interner.resolve_expect(*s).chars().peekable();
+                    while let Some(c) = chars.next() {
+                        match c {
+                            '`' => buf.push_str("\\`"),
+                            '\\' => buf.push_str("\\\\"),
+                            '\r' => buf.push_str("\\r"),
+                            '$' if chars.peek() == Some(&'{') => buf.push_str("\\$"),
+                            c => buf.push(c),
+                        }
+                    }
+                }
                 TemplateElement::Expr(n) => {
                     buf.push_str(&format!("${{{}}}", n.to_interned_string(interner)));
                 }
@@ -55,6 +67,13 @@ impl ToInternedString for TemplateLit {
         buf
     }
 }
+
+impl TakeNodes for TemplateLit {
+    fn take_nodes(&mut self, nodes: &mut Vec<Node>) {
+        self.elements.take_nodes(nodes);
+    }
+}
+
 #[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
 #[derive(Clone, Debug, PartialEq)]
 pub struct TaggedTemplate {
@@ -100,7 +119,7 @@ impl TaggedTemplate {
 
 impl ToInternedString for TaggedTemplate {
     fn to_interned_string(&self, interner: &Interner) -> String {
-        let mut buf = format!("{}`", self.tag.to_interned_string(interner));
+        let mut buf = format!("{}`", precedence::object(&self.tag, interner));
         for (&raw, expr) in self.raws.iter().zip(self.exprs.iter()) {
             buf.push_str(&format!(
                 "{}${{{}}}",
@@ -114,6 +133,13 @@ impl ToInternedString for TaggedTemplate {
     }
 }
 
+impl TakeNodes for TaggedTemplate {
+    fn take_nodes(&mut self, nodes: &mut Vec<Node>) {
+        self.tag.take_nodes(nodes);
+        self.exprs.take_nodes(nodes);
+    }
+}
+
 impl From<TaggedTemplate> for Node {
     fn from(template: TaggedTemplate) -> Self {
         Self::TaggedTemplate(Box::new(template))
@@ -126,3 +152,11 @@ pub enum TemplateElement {
     String(Sym),
     Expr(Node),
 }
+
+impl TakeNodes for TemplateElement {
+    fn take_nodes(&mut self, nodes: &mut Vec<Node>) {
+        if let Self::Expr(node) = self {
+            node.take_nodes(nodes);
+        }
+    }
+}
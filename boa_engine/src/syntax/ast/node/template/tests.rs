@@ -43,6 +43,7 @@ fn fmt() {
         };
         let a = 10;
         tag`result: ${a} \x26 ${a + 10}`;
+        let b = `a \`quoted\` \${a} ${a}`;
         "#,
     );
 }
@@ -1,4 +1,4 @@
-use crate::syntax::ast::node::Node;
+use crate::syntax::ast::node::{Node, TakeNodes};
 use boa_interner::{Interner, ToInternedString};
 
 #[cfg(feature = "deser")]
@@ -49,6 +49,12 @@ impl ToInternedString for Throw {
     }
 }
 
+impl TakeNodes for Throw {
+    fn take_nodes(&mut self, nodes: &mut Vec<Node>) {
+        self.expr.take_nodes(nodes);
+    }
+}
+
 impl From<Throw> for Node {
     fn from(trw: Throw) -> Self {
         Self::Throw(trw)
@@ -1,4 +1,4 @@
-use crate::syntax::ast::node::{Block, Declaration, Node};
+use crate::syntax::ast::node::{Block, Declaration, Node, TakeNodes};
 use boa_interner::{Interner, ToInternedString};
 
 #[cfg(feature = "deser")]
@@ -94,6 +94,14 @@ impl ToInternedString for Try {
     }
 }
 
+impl TakeNodes for Try {
+    fn take_nodes(&mut self, nodes: &mut Vec<Node>) {
+        self.block.take_nodes(nodes);
+        self.catch.take_nodes(nodes);
+        self.finally.take_nodes(nodes);
+    }
+}
+
 impl From<Try> for Node {
     fn from(try_catch: Try) -> Self {
         Self::Try(Box::new(try_catch))
@@ -153,6 +161,12 @@ impl ToInternedString for Catch {
     }
 }
 
+impl TakeNodes for Catch {
+    fn take_nodes(&mut self, nodes: &mut Vec<Node>) {
+        self.block.take_nodes(nodes);
+    }
+}
+
 /// Finally block.
 #[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
 #[derive(Clone, Debug, PartialEq)]
@@ -185,3 +199,9 @@ where
         }
     }
 }
+
+impl TakeNodes for Finally {
+    fn take_nodes(&mut self, nodes: &mut Vec<Node>) {
+        self.block.take_nodes(nodes);
+    }
+}
@@ -1,4 +1,4 @@
-use crate::syntax::ast::node::Node;
+use crate::syntax::ast::node::{Node, TakeNodes};
 use boa_interner::{Interner, ToInternedString};
 
 #[cfg(feature = "deser")]
@@ -40,6 +40,12 @@ impl Yield {
     }
 }
 
+impl TakeNodes for Yield {
+    fn take_nodes(&mut self, nodes: &mut Vec<Node>) {
+        self.expr.take_nodes(nodes);
+    }
+}
+
 impl From<Yield> for Node {
     fn from(r#yield: Yield) -> Self {
         Self::Yield(r#yield)
@@ -23,6 +23,7 @@ mod operator;
 mod private_identifier;
 pub mod regex;
 mod spread;
+mod stream;
 mod string;
 mod template;
 pub mod token;
@@ -49,6 +50,7 @@ use std::io::Read;
 
 pub use self::{
     error::Error,
+    stream::TokenStream,
     token::{Token, TokenKind},
 };
 
@@ -0,0 +1,90 @@
+//! Lexing of a whole source without a parser.
+
+use super::{Error, InputElement, Lexer, Token, TokenKind};
+use crate::syntax::ast::{Keyword, Position, Punctuator};
+use boa_interner::Interner;
+use std::io::Read;
+
+/// The tokens of a source, lexed without a parser.
+///
+/// The lexer depends on the parser to know if a `/` starts a regular expression and where the
+/// substitutions of a template literal end. Without one, the goal symbol is chosen from the
+/// previous token, so a `/` after an expression is lexed as a division and as a regular
+/// expression anywhere else, and template literals are continued after the `}` that closes
+/// each of their substitutions.
+#[derive(Debug)]
+pub struct TokenStream<R> {
+    lexer: Lexer<R>,
+    /// The block depths at which the substitutions of the open template literals started.
+    substitutions: Vec<usize>,
+    /// The number of blocks that are currently open.
+    depth: usize,
+    /// The start of the rest of a template literal, after the end of one of its substitutions.
+    template_start: Option<Position>,
+}
+
+impl<R> TokenStream<R> {
+    /// Creates a new token stream.
+    #[inline]
+    pub fn new(reader: R) -> Self
+    where
+        R: Read,
+    {
+        Self {
+            lexer: Lexer::new(reader),
+            substitutions: Vec::new(),
+            depth: 0,
+            template_start: None,
+        }
+    }
+
+    /// Retrieves the next token of the source.
+    // Like the lexer, this doesn't implement the Iterator trait as Result<Option> is cleaner to
+    // handle.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self, interner: &mut Interner) -> Result<Option<Token>, Error>
+    where
+        R: Read,
+    {
+        let token = match self.template_start.take() {
+            Some(start) => self.lexer.lex_template(start, interner)?,
+            None => match self.lexer.next(interner)? {
+                Some(token) => token,
+                None => return Ok(None),
+            },
+        };
+
+        match token.kind() {
+            TokenKind::Punctuator(Punctuator::OpenBlock) => self.depth += 1,
+            TokenKind::Punctuator(Punctuator::CloseBlock) => {
+                if self.substitutions.last() == Some(&self.depth) {
+                    self.substitutions.pop();
+                    self.template_start = Some(token.span().end());
+                } else {
+                    self.depth = self.depth.saturating_sub(1);
+                }
+            }
+            TokenKind::TemplateMiddle(_) => self.substitutions.push(self.depth),
+            _ => {}
+        }
+
+        match token.kind() {
+            TokenKind::LineTerminator | TokenKind::Comment => {}
+            TokenKind::BooleanLiteral(_)
+            | TokenKind::Identifier(_)
+            | TokenKind::PrivateIdentifier(_)
+            | TokenKind::Keyword((Keyword::This | Keyword::Super, _))
+            | TokenKind::NullLiteral
+            | TokenKind::NumericLiteral(_)
+            | TokenKind::StringLiteral(_)
+            | TokenKind::TemplateNoSubstitution(_)
+            | TokenKind::RegularExpressionLiteral(_, _)
+            | TokenKind::Punctuator(
+                Punctuator::CloseParen | Punctuator::CloseBracket | Punctuator::CloseBlock,
+            ) => self.lexer.set_goal(InputElement::Div),
+            _ => self.lexer.set_goal(InputElement::RegExp),
+        }
+
+        Ok(Some(token))
+    }
+}
@@ -7,10 +7,7 @@ use crate::syntax::{
 };
 use boa_interner::Interner;
 use boa_profiler::Profiler;
-use std::{
-    io::{self, ErrorKind, Read},
-    str,
-};
+use std::io::{self, ErrorKind, Read};
 
 /// String literal lexing.
 ///
@@ -91,7 +88,7 @@ impl<R> Tokenizer<R> for StringLiteral {
             Self::take_string_characters(cursor, start_pos, self.terminator, cursor.strict_mode())?;
 
         Ok(Token::new(
-            TokenKind::string_literal(interner.get_or_intern(lit)),
+            TokenKind::string_literal(interner.get_or_intern_utf16(&lit)),
             span,
         ))
     }
@@ -118,7 +115,7 @@ impl StringLiteral {
         start_pos: Position,
         terminator: StringTerminator,
         is_strict_mode: bool,
-    ) -> Result<(String, Span), Error>
+    ) -> Result<(Vec<u16>, Span), Error>
     where
         R: Read,
     {
@@ -157,7 +154,7 @@ impl StringLiteral {
             }
         }
 
-        Ok((buf.to_string_lossy(), Span::new(start_pos, cursor.pos())))
+        Ok((buf, Span::new(start_pos, cursor.pos())))
     }
 
     #[inline]
@@ -261,15 +258,11 @@ impl StringLiteral {
             let mut code_point_buf = Vec::with_capacity(6);
             cursor.take_until(b'}', &mut code_point_buf)?;
 
-            let code_point = str::from_utf8(code_point_buf.as_slice())
-                .ok()
-                .and_then(|code_point_str| {
-                    // The `code_point_str` should represent a single unicode codepoint, convert to u32
-                    u32::from_str_radix(code_point_str, 16).ok()
-                })
-                .ok_or_else(|| {
-                    Error::syntax("malformed Unicode character escape sequence", start_pos)
-                })?;
+            // Grammar: CodePoint
+            // Leading zeros are allowed, so the digits can be any number of them.
+            let code_point = Self::parse_hex_digits(&code_point_buf).ok_or_else(|| {
+                Error::syntax("malformed Unicode character escape sequence", start_pos)
+            })?;
 
             // UTF16Encoding of a numeric code point value
             if code_point > 0x10_FFFF {
@@ -286,13 +279,8 @@ impl StringLiteral {
             let mut code_point_utf8_bytes = [0u8; 4];
             cursor.fill_bytes(&mut code_point_utf8_bytes)?;
 
-            // Convert to u16
-            let code_point = str::from_utf8(&code_point_utf8_bytes)
-                .ok()
-                .and_then(|code_point_str| u16::from_str_radix(code_point_str, 16).ok())
-                .ok_or_else(|| Error::syntax("invalid Unicode escape sequence", start_pos))?;
-
-            Ok(u32::from(code_point))
+            Self::parse_hex_digits(&code_point_utf8_bytes)
+                .ok_or_else(|| Error::syntax("invalid Unicode escape sequence", start_pos))
         }
     }
 
@@ -306,12 +294,24 @@ impl StringLiteral {
     {
         let mut code_point_utf8_bytes = [0u8; 2];
         cursor.fill_bytes(&mut code_point_utf8_bytes)?;
-        let code_point = str::from_utf8(&code_point_utf8_bytes)
-            .ok()
-            .and_then(|code_point_str| u16::from_str_radix(code_point_str, 16).ok())
-            .ok_or_else(|| Error::syntax("invalid Hexadecimal escape sequence", start_pos))?;
 
-        Ok(u32::from(code_point))
+        Self::parse_hex_digits(&code_point_utf8_bytes)
+            .ok_or_else(|| Error::syntax("invalid Hexadecimal escape sequence", start_pos))
+    }
+
+    /// Parses the hexadecimal digits of an escape sequence.
+    ///
+    /// Returns `None` if there are no digits, or if any of them is not an ASCII hexadecimal
+    /// digit. Values that don't fit in a `u32` saturate, since they are bigger than any code point.
+    fn parse_hex_digits(digits: &[u8]) -> Option<u32> {
+        if digits.is_empty() {
+            return None;
+        }
+
+        digits.iter().try_fold(0u32, |value, &digit| {
+            let digit = char::from(digit).to_digit(16)?;
+            Some(value.saturating_mul(16).saturating_add(digit))
+        })
     }
 
     #[inline]
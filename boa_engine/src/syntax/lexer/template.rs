@@ -71,18 +71,15 @@ impl TemplateString {
                     }
                 }
                 Some(ch) => {
-                    // The caller guarantees that sequences '`' and '${' never appear
-                    // LineTerminatorSequence <CR> <LF> is consumed by `cursor.next_char()` and
-                    // returns <LF>, which matches the TV of <CR> <LF>
+                    // The caller guarantees that sequences '`' and '${' never appear, and the
+                    // line terminator sequences of the raw string are already normalized to <LF>.
                     buf.push_code_point(ch);
                 }
                 None => break,
             }
         }
 
-        let str = buf.to_string_lossy();
-
-        Ok(interner.get_or_intern(&str))
+        Ok(interner.get_or_intern_utf16(&buf))
     }
 }
 
@@ -162,6 +159,11 @@ impl<R> Tokenizer<R> for TemplateLiteral {
                         _ => continue,
                     }
                 }
+                // <CR> and <CR> <LF>, which `cursor.next_char()` consumes as a single <CR>
+                0x000D => {
+                    // The TRV and the TV of both line terminator sequences is <LF>.
+                    buf.push(0x000A);
+                }
                 ch => {
                     buf.push_code_point(ch);
                 }
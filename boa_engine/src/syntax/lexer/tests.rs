@@ -1085,6 +1085,131 @@ fn string_line_continuation() {
     expect_tokens(&mut lexer, &expected_tokens, &mut interner);
 }
 
+#[test]
+fn string_line_continuation_sequences() {
+    // The line continuations don't add any character, but they are line terminators.
+    let s = "'a\\\r\nb\\\rc\\\u{2028}d\\\u{2029}e' f";
+
+    let mut lexer = Lexer::new(s.as_bytes());
+    let mut interner = Interner::default();
+
+    let string = lexer.next(&mut interner).unwrap().unwrap();
+    assert_eq!(
+        string.kind(),
+        &TokenKind::StringLiteral(interner.get_or_intern_static("abcde"))
+    );
+    assert_eq!(string.span(), span((1, 1), (5, 3)));
+
+    let identifier = lexer.next(&mut interner).unwrap().unwrap();
+    assert_eq!(identifier.span(), span((5, 4), (5, 5)));
+}
+
+#[test]
+fn string_astral_escapes() {
+    let test_cases = [
+        (r#"'\u{1F600}'"#, "\u{1F600}"),
+        (r#"'\u{0000000041}'"#, "A"),
+        (r#"'\u{10FFFF}'"#, "\u{10FFFF}"),
+        (r#"'😀'"#, "\u{1F600}"),
+        (r#"'\x41\xe9'"#, "A\u{e9}"),
+        (r#"'\b\t\n\v\f\r\"\'\\'"#, "\u{8}\t\n\u{b}\u{c}\r\"'\\"),
+    ];
+
+    for (s, expected) in &test_cases {
+        let mut lexer = Lexer::new(s.as_bytes());
+        let mut interner = Interner::default();
+
+        let sym = interner.get_or_intern(expected);
+        let expected_tokens = [TokenKind::StringLiteral(sym)];
+
+        expect_tokens(&mut lexer, &expected_tokens, &mut interner);
+    }
+}
+
+#[test]
+fn string_invalid_escapes() {
+    for s in [
+        r#"'\u{110000}'"#,
+        r#"'\u{FFFFFFFFFFFF}'"#,
+        r#"'\u{}'"#,
+        r#"'\u{+41}'"#,
+        r#"'\u+041'"#,
+        r#"'\u00G1'"#,
+        r#"'\x+4'"#,
+        r#"'\x4'"#,
+    ] {
+        let mut lexer = Lexer::new(s.as_bytes());
+        let mut interner = Interner::default();
+
+        if let Error::Syntax(_, pos) = lexer
+            .next(&mut interner)
+            .expect_err("invalid escape sequence not rejected as expected")
+        {
+            assert_eq!(pos, Position::new(1, 2), "{s}");
+        } else {
+            panic!("invalid error type for {s}");
+        }
+    }
+}
+
+#[test]
+fn template_literal_line_terminators() {
+    let s = "`a\r\nb\rc\\\r\nd`";
+
+    let mut lexer = Lexer::new(s.as_bytes());
+    let mut interner = Interner::default();
+
+    let token = lexer.next(&mut interner).unwrap().unwrap();
+    let template = match token.kind() {
+        TokenKind::TemplateNoSubstitution(template) => *template,
+        kind => panic!("unexpected token {kind:?}"),
+    };
+
+    assert_eq!(interner.resolve_expect(template.as_raw()), "a\nb\nc\\\nd");
+    let cooked = template.to_owned_cooked(&mut interner).unwrap();
+    assert_eq!(interner.resolve_expect(cooked), "a\nb\ncd");
+    assert_eq!(token.span(), span((1, 1), (4, 3)));
+}
+
+#[test]
+fn token_stream_chooses_goal_symbol() {
+    let s = "a / 2 / /re/g;\n`x${ {} }y${b}z` / 1";
+    let mut tokens = TokenStream::new(s.as_bytes());
+    let mut interner = Interner::default();
+
+    let mut lexed = Vec::new();
+    while let Some(token) = tokens.next(&mut interner).unwrap() {
+        let kind = match token.kind() {
+            TokenKind::RegularExpressionLiteral(_, _) => "regexp",
+            TokenKind::TemplateMiddle(_) => "template middle",
+            TokenKind::TemplateNoSubstitution(_) => "template tail",
+            _ => "",
+        };
+        lexed.push(format!("{kind} {}", token.kind().to_string(&interner)));
+    }
+
+    let expected = [
+        " a",
+        " /",
+        " 2",
+        " /",
+        "regexp /re/g",
+        " ;",
+        " line terminator",
+        "template middle x",
+        " {",
+        " }",
+        " }",
+        "template middle y",
+        " b",
+        " }",
+        "template tail z",
+        " /",
+        " 1",
+    ];
+    assert_eq!(lexed, expected);
+}
+
 mod carriage_return {
     use super::*;
 
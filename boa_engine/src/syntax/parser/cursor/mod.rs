@@ -11,6 +11,14 @@ use buffered_lexer::BufferedLexer;
 use rustc_hash::FxHashMap;
 use std::io::Read;
 
+/// The default maximum nesting depth of statements and expressions.
+///
+/// Every nesting level takes some space in the native stack, both while parsing and while
+/// compiling the parsed code. This keeps deeply nested input from overflowing the two megabyte
+/// stack of spawned threads in optimized builds. Debug builds take several times more stack per
+/// level.
+pub(crate) const DEFAULT_RECURSION_LIMIT: usize = 128;
+
 /// The result of a peek for a semicolon.
 #[derive(Debug)]
 pub(super) enum SemicolonResult<'s> {
@@ -30,6 +38,12 @@ pub(super) struct Cursor<R> {
 
     /// Tracks if the cursor is in a arrow function declaration.
     arrow: bool,
+
+    /// The current nesting depth of the statements and expressions being parsed.
+    depth: usize,
+
+    /// The maximum nesting depth of statements and expressions.
+    recursion_limit: usize,
 }
 
 impl<R> Cursor<R>
@@ -43,6 +57,8 @@ where
             buffered_lexer: Lexer::new(reader).into(),
             private_environments_stack: Vec::new(),
             arrow: false,
+            depth: 0,
+            recursion_limit: DEFAULT_RECURSION_LIMIT,
         }
     }
 
@@ -105,6 +121,38 @@ where
         self.arrow = arrow;
     }
 
+    /// Sets the maximum nesting depth of statements and expressions.
+    #[inline]
+    pub(super) fn set_recursion_limit(&mut self, limit: usize) {
+        self.recursion_limit = limit;
+    }
+
+    /// Enters a nested statement or expression.
+    ///
+    /// This returns an error if the maximum nesting depth is exceeded.
+    #[inline]
+    pub(super) fn enter_nested(&mut self, interner: &mut Interner) -> Result<(), ParseError> {
+        if self.depth >= self.recursion_limit {
+            let position = self
+                .peek(0, interner)?
+                .ok_or(ParseError::AbruptEnd)?
+                .span()
+                .start();
+            return Err(ParseError::general(
+                "maximum nesting depth exceeded",
+                position,
+            ));
+        }
+        self.depth += 1;
+        Ok(())
+    }
+
+    /// Exits a nested statement or expression.
+    #[inline]
+    pub(super) fn exit_nested(&mut self) {
+        self.depth -= 1;
+    }
+
     /// Push a new private environment.
     #[inline]
     pub(super) fn push_private_environment(&mut self) {
@@ -88,8 +88,21 @@ where
 {
     type Output = Node;
 
-    fn parse(mut self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult {
+    fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult {
         let _timer = Profiler::global().start_event("AssignmentExpression", "Parsing");
+        cursor.enter_nested(interner)?;
+        let result = self.parse_expression(cursor, interner);
+        cursor.exit_nested();
+        result
+    }
+}
+
+impl AssignmentExpression {
+    /// Parses the assignment expression, once its nesting depth has been checked.
+    fn parse_expression<R>(mut self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult
+    where
+        R: Read,
+    {
         cursor.set_goal(InputElement::Div);
 
         match cursor
@@ -148,7 +161,8 @@ where
         .parse(cursor, interner)?;
 
         // If the left hand side is a parameter list, we must parse an arrow function.
-        if let Node::FormalParameterList(parameters) = lhs {
+        if let Node::FormalParameterList(parameters) = &mut lhs {
+            let parameters = std::mem::take(parameters);
             cursor.peek_expect_no_lineterminator(0, "arrow function", interner)?;
 
             cursor.expect(
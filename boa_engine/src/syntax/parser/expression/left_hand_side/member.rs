@@ -74,7 +74,10 @@ where
             }
             TokenKind::Keyword((Keyword::New, false)) => {
                 let _next = cursor.next(interner).expect("new keyword disappeared");
-                let lhs = self.parse(cursor, interner)?;
+                cursor.enter_nested(interner)?;
+                let lhs = self.parse(cursor, interner);
+                cursor.exit_nested();
+                let lhs = lhs?;
                 let args = match cursor.peek(0, interner)? {
                     Some(next) if next.kind() == &TokenKind::Punctuator(Punctuator::OpenParen) => {
                         Arguments::new(self.allow_yield, self.allow_await)
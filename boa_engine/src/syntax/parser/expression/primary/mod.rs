@@ -33,8 +33,8 @@ use crate::syntax::{
             operator::assign::{
                 array_decl_to_declaration_pattern, object_decl_to_declaration_pattern, AssignTarget,
             },
-            Call, Declaration, DeclarationPattern, FormalParameter, FormalParameterList,
-            Identifier, New, Node,
+            Declaration, DeclarationPattern, FormalParameter, FormalParameterList, Node,
+            RegExpLiteral,
         },
         op::BinOp,
         Const, Keyword, Punctuator, Span,
@@ -226,10 +226,7 @@ where
                 Ok(node)
             }
             TokenKind::RegularExpressionLiteral(body, flags) => {
-                let node = Node::from(New::from(Call::new(
-                    Identifier::new(Sym::REGEXP),
-                    vec![Const::from(*body).into(), Const::from(*flags).into()],
-                )));
+                let node = RegExpLiteral::new(*body, *flags).into();
                 cursor.next(interner).expect("token disappeared");
                 Ok(node)
             }
@@ -239,10 +236,7 @@ where
                 let tok = cursor.lex_regex(position, interner)?;
 
                 if let TokenKind::RegularExpressionLiteral(body, flags) = *tok.kind() {
-                    Ok(Node::from(New::from(Call::new(
-                        Identifier::new(Sym::REGEXP),
-                        vec![Const::from(body).into(), Const::from(flags).into()],
-                    ))))
+                    Ok(RegExpLiteral::new(body, flags).into())
                 } else {
                     // A regex was expected and nothing else.
                     Err(ParseError::unexpected(
@@ -1,4 +1,7 @@
-use crate::syntax::{ast::Const, parser::tests::check_parser};
+use crate::syntax::{
+    ast::{node::RegExpLiteral, Const},
+    parser::tests::check_parser,
+};
 use boa_interner::{Interner, Sym};
 
 #[test]
@@ -18,3 +21,17 @@ fn check_string() {
         interner,
     );
 }
+
+#[test]
+fn check_regexp_literal() {
+    let mut interner = Interner::default();
+    check_parser(
+        "/a+b/gi",
+        vec![RegExpLiteral::new(
+            interner.get_or_intern_static("a+b"),
+            interner.get_or_intern_static("gi"),
+        )
+        .into()],
+        interner,
+    );
+}
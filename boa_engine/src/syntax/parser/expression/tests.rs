@@ -591,6 +591,17 @@ fn check_logical_expressions() {
     check_invalid("a || b ?? c");
 }
 
+#[test]
+fn check_invalid_update_targets() {
+    check_invalid("++this");
+    check_invalid("--this");
+    check_invalid("this++");
+    check_invalid("this--");
+    check_invalid("++1");
+    check_invalid("--f()");
+    check_invalid("'use strict'; ++eval");
+}
+
 #[track_caller]
 fn check_non_reserved_identifier(keyword: &'static str) {
     let mut interner = Interner::default();
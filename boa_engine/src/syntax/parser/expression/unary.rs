@@ -62,7 +62,19 @@ where
 
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult {
         let _timer = Profiler::global().start_event("UnaryExpression", "Parsing");
+        cursor.enter_nested(interner)?;
+        let result = self.parse_expression(cursor, interner);
+        cursor.exit_nested();
+        result
+    }
+}
 
+impl UnaryExpression {
+    /// Parses the unary expression, once its nesting depth has been checked.
+    fn parse_expression<R>(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult
+    where
+        R: Read,
+    {
         let tok = cursor.peek(0, interner)?.ok_or(ParseError::AbruptEnd)?;
         let token_start = tok.span().start();
         match tok.kind() {
@@ -72,6 +72,16 @@ where
                         ident.check_strict_arguments_or_eval(position)?;
                     }
                 }
+                // https://tc39.es/ecma262/#sec-update-expressions-static-semantics-early-errors
+                if !matches!(
+                    target,
+                    Node::Identifier(_) | Node::GetConstField(_) | Node::GetField(_)
+                ) {
+                    return Err(ParseError::lex(LexError::Syntax(
+                        "Invalid left-hand side in assignment".into(),
+                        position,
+                    )));
+                }
 
                 return Ok(node::UnaryOp::new(UnaryOp::IncrementPre, target).into());
             }
@@ -88,6 +98,16 @@ where
                         ident.check_strict_arguments_or_eval(position)?;
                     }
                 }
+                // https://tc39.es/ecma262/#sec-update-expressions-static-semantics-early-errors
+                if !matches!(
+                    target,
+                    Node::Identifier(_) | Node::GetConstField(_) | Node::GetField(_)
+                ) {
+                    return Err(ParseError::lex(LexError::Syntax(
+                        "Invalid left-hand side in assignment".into(),
+                        position,
+                    )));
+                }
 
                 return Ok(node::UnaryOp::new(UnaryOp::DecrementPre, target).into());
             }
@@ -30,6 +30,7 @@ use rustc_hash::{FxHashMap, FxHashSet};
 use std::io::Read;
 
 pub use self::error::{ParseError, ParseResult};
+pub(crate) use cursor::DEFAULT_RECURSION_LIMIT;
 pub(in crate::syntax) use expression::RESERVED_IDENTIFIERS_STRICT;
 
 /// Trait implemented by parsers.
@@ -119,6 +120,21 @@ impl<R> Parser<R> {
         }
     }
 
+    /// Sets the maximum nesting depth of the statements and expressions in the input.
+    ///
+    /// Input that is nested deeper than this fails to parse with an error, instead of
+    /// overflowing the native stack while parsing or compiling it. Statements, declarations, and
+    /// assignment and unary expressions count as nesting levels, but chains of operations like
+    /// `a + b + c` or `a.b().c` don't. The default limit of 128 fits the two megabyte stack of
+    /// spawned threads in optimized builds; it should be lowered for smaller stacks or debug
+    /// builds.
+    pub fn set_recursion_limit(&mut self, limit: usize)
+    where
+        R: Read,
+    {
+        self.cursor.set_recursion_limit(limit);
+    }
+
     /// Set the parser strict mode to true.
     pub(crate) fn set_strict(&mut self)
     where
@@ -292,38 +308,6 @@ impl Script {
                             Position::new(1, 1),
                         ));
                     }
-                    if context.has_binding(*name) {
-                        return Err(ParseError::general(
-                            "lexical name declared multiple times",
-                            Position::new(1, 1),
-                        ));
-                    }
-                    if !is_function_declaration {
-                        let name_str = context.interner().resolve_expect(*name);
-                        let desc = context
-                            .realm
-                            .global_property_map
-                            .string_property_map()
-                            .get(name_str);
-                        let non_configurable_binding_exists = match desc {
-                            Some(desc) => !matches!(desc.configurable(), Some(true)),
-                            None => false,
-                        };
-                        if non_configurable_binding_exists {
-                            return Err(ParseError::general(
-                                "lexical name declared in var names",
-                                Position::new(1, 1),
-                            ));
-                        }
-                    }
-                }
-                for name in var_declared_names {
-                    if context.has_binding(name) {
-                        return Err(ParseError::general(
-                            "lexical name declared in var names",
-                            Position::new(1, 1),
-                        ));
-                    }
                 }
 
                 Ok(statement_list)
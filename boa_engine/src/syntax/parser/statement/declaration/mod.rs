@@ -64,6 +64,23 @@ where
         interner: &mut Interner,
     ) -> Result<Self::Output, ParseError> {
         let _timer = Profiler::global().start_event("Declaration", "Parsing");
+        cursor.enter_nested(interner)?;
+        let result = self.parse_declaration(cursor, interner);
+        cursor.exit_nested();
+        result
+    }
+}
+
+impl Declaration {
+    /// Parses the declaration, once its nesting depth has been checked.
+    fn parse_declaration<R>(
+        self,
+        cursor: &mut Cursor<R>,
+        interner: &mut Interner,
+    ) -> Result<Node, ParseError>
+    where
+        R: Read,
+    {
         let tok = cursor.peek(0, interner)?.ok_or(ParseError::AbruptEnd)?;
 
         match tok.kind() {
@@ -121,6 +121,23 @@ where
         interner: &mut Interner,
     ) -> Result<Self::Output, ParseError> {
         let _timer = Profiler::global().start_event("Statement", "Parsing");
+        cursor.enter_nested(interner)?;
+        let result = self.parse_statement(cursor, interner);
+        cursor.exit_nested();
+        result
+    }
+}
+
+impl Statement {
+    /// Parses the statement, once its nesting depth has been checked.
+    fn parse_statement<R>(
+        self,
+        cursor: &mut Cursor<R>,
+        interner: &mut Interner,
+    ) -> Result<Node, ParseError>
+    where
+        R: Read,
+    {
         // TODO: add BreakableStatement and divide Whiles, fors and so on to another place.
         let tok = cursor.peek(0, interner)?.ok_or(ParseError::AbruptEnd)?;
 
@@ -418,7 +435,23 @@ where
         interner: &mut Interner,
     ) -> Result<Self::Output, ParseError> {
         let _timer = Profiler::global().start_event("ObjectBindingPattern", "Parsing");
+        cursor.enter_nested(interner)?;
+        let result = self.parse_pattern(cursor, interner);
+        cursor.exit_nested();
+        result
+    }
+}
 
+impl ObjectBindingPattern {
+    /// Parses the object binding pattern, once its nesting depth has been checked.
+    fn parse_pattern<R>(
+        self,
+        cursor: &mut Cursor<R>,
+        interner: &mut Interner,
+    ) -> Result<Vec<BindingPatternTypeObject>, ParseError>
+    where
+        R: Read,
+    {
         cursor.expect(
             TokenKind::Punctuator(Punctuator::OpenBlock),
             "object binding pattern",
@@ -712,7 +745,23 @@ where
         interner: &mut Interner,
     ) -> Result<Self::Output, ParseError> {
         let _timer = Profiler::global().start_event("ArrayBindingPattern", "Parsing");
+        cursor.enter_nested(interner)?;
+        let result = self.parse_pattern(cursor, interner);
+        cursor.exit_nested();
+        result
+    }
+}
 
+impl ArrayBindingPattern {
+    /// Parses the array binding pattern, once its nesting depth has been checked.
+    fn parse_pattern<R>(
+        self,
+        cursor: &mut Cursor<R>,
+        interner: &mut Interner,
+    ) -> Result<Vec<BindingPatternTypeArray>, ParseError>
+    where
+        R: Read,
+    {
         cursor.expect(
             TokenKind::Punctuator(Punctuator::OpenBracket),
             "array binding pattern",
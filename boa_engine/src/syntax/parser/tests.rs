@@ -3,6 +3,7 @@
 use super::Parser;
 use crate::{
     context::ContextBuilder,
+    forward,
     syntax::ast::{
         node::{
             field::GetConstField, object::PropertyDefinition, ArrowFunctionDecl, Assign, BinOp,
@@ -482,3 +483,112 @@ fn hashbang_use_strict_with_with_statement() {
 fn hashbang_comment() {
     check_parser(r"#!Comment Here", vec![], Interner::default());
 }
+
+#[test]
+fn recursion_limit() {
+    let parse = |js: &str, limit| {
+        let mut context = Context::default();
+        let mut parser = Parser::new(js.as_bytes());
+        parser.set_recursion_limit(limit);
+        parser.parse_all(&mut context)
+    };
+
+    let nested_expression = format!("{}1{}", "(".repeat(10), ")".repeat(10));
+    let nested_block = format!("{}{}", "{".repeat(10), "}".repeat(10));
+
+    assert!(parse(&nested_expression, 256).is_ok());
+    assert!(parse(&nested_block, 256).is_ok());
+
+    for js in [&nested_expression, &nested_block] {
+        let error = parse(js, 4).expect_err("the nesting should exceed the limit");
+        assert!(error
+            .to_string()
+            .starts_with("maximum nesting depth exceeded"));
+    }
+}
+
+#[test]
+fn recursion_limit_ignores_chains() {
+    let parse = |js: &str, limit| {
+        let mut context = Context::default();
+        let mut parser = Parser::new(js.as_bytes());
+        parser.set_recursion_limit(limit);
+        parser.parse_all(&mut context)
+    };
+
+    let chains = [
+        format!("1{}", " + 1".repeat(600)),
+        format!("a{}", " && a".repeat(600)),
+        format!("a{}", ", a".repeat(600)),
+        format!("a{}", ".o".repeat(600)),
+        format!("a{}", "[0]".repeat(600)),
+        format!("f(){}", "()".repeat(600)),
+        format!("a{}", ".o()".repeat(600)),
+    ];
+    for js in &chains {
+        assert!(parse(js, 8).is_ok());
+    }
+}
+
+/// Runs a test on a thread with a bigger stack.
+///
+/// Deep nesting that is accepted by the default recursion limit fits the stack of test threads
+/// in optimized builds, but debug builds take several times more stack per nesting level.
+fn with_big_stack(test: impl FnOnce() + Send + 'static) {
+    std::thread::Builder::new()
+        .stack_size(64 * 1024 * 1024)
+        .spawn(test)
+        .expect("could not spawn the test thread")
+        .join()
+        .expect("the test thread panicked");
+}
+
+#[test]
+fn deep_nesting_is_a_syntax_error() {
+    with_big_stack(|| {
+        let mut context = Context::default();
+        let inputs = [
+            format!("{}1{}", "(".repeat(1000), ")".repeat(1000)),
+            format!("{}1{}", "[".repeat(1000), "]".repeat(1000)),
+            format!("{}{}", "{".repeat(5000), "}".repeat(5000)),
+            format!("{}1", "() => ".repeat(5000)),
+            format!("{}1", "!".repeat(5000)),
+            format!("{}f", "new ".repeat(5000)),
+            format!("var {}a{} = 1", "{a: ".repeat(1000), "}".repeat(1000)),
+        ];
+        for js in &inputs {
+            let result = forward(&mut context, js);
+            assert!(
+                result.starts_with("Uncaught \"SyntaxError\": \"maximum nesting depth exceeded"),
+                "unexpected result: {result}"
+            );
+        }
+    });
+}
+
+#[test]
+fn common_nesting_is_accepted() {
+    with_big_stack(|| {
+        let mut context = Context::default();
+
+        let js = format!("0{}", " + 1".repeat(600));
+        assert_eq!(forward(&mut context, &js), "600");
+        let js = format!("0{}", ", 1".repeat(600));
+        assert_eq!(forward(&mut context, &js), "1");
+        let js = format!("var o = {{ v: 1 }}; o.o = o; o{}.v", ".o".repeat(600));
+        assert_eq!(forward(&mut context, &js), "1");
+        let js = format!("function f() {{ return f; }} typeof f{}", "()".repeat(600));
+        assert_eq!(forward(&mut context, &js), "\"function\"");
+
+        let js = format!("{}1{}.flat(Infinity)[0]", "[".repeat(50), "]".repeat(50));
+        assert_eq!(forward(&mut context, &js), "1");
+        let js = format!("typeof ({}1{}).a", "{ a: ".repeat(50), " }".repeat(50));
+        assert_eq!(forward(&mut context, &js), "\"object\"");
+        let js = format!(
+            "function f(x) {{ return x; }} {}1{}",
+            "(function () { return f(".repeat(13),
+            "); })()".repeat(13)
+        );
+        assert_eq!(forward(&mut context, &js), "1");
+    });
+}
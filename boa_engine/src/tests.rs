@@ -45,6 +45,16 @@ fn length_correct_value_on_string_literal() {
     assert_eq!(&exec(scenario), "5");
 }
 
+#[test]
+fn lone_surrogate_in_string_literal() {
+    let scenario = r#"
+    var s = "\uD800";
+    s.length === 1 && s.charCodeAt(0) === 0xD800 && "\uDC00\uD800".charCodeAt(1) === 0xD800;
+    "#;
+
+    assert_eq!(&exec(scenario), "true");
+}
+
 #[test]
 fn property_accessor_member_expression_dot_notation_on_function() {
     let scenario = r#"
@@ -446,6 +456,55 @@ fn for_loop_iteration_variable_does_not_leak() {
     assert_eq!(&exec(inner_scope), "\"i is not defined\"");
 }
 
+#[test]
+fn for_loop_per_iteration_bindings() {
+    let closures_in_body = r"
+        const fns = [];
+        for (let i = 0; i < 3; i++) {
+            fns.push(() => i);
+        }
+        fns.map(f => f()).join()
+        ";
+    assert_eq!(&exec(closures_in_body), "\"0,1,2\"");
+
+    let closures_in_increment = r"
+        const fns = [];
+        for (let i = 0; i < 3; fns.push(() => i), i++) {}
+        fns.map(f => f()).join()
+        ";
+    assert_eq!(&exec(closures_in_increment), "\"1,2,3\"");
+
+    let continue_in_body = r"
+        const fns = [];
+        for (let i = 0; i < 4; i++) {
+            if (i % 2 === 0) {
+                continue;
+            }
+            fns.push(() => i);
+        }
+        fns.map(f => f()).join()
+        ";
+    assert_eq!(&exec(continue_in_body), "\"1,3\"");
+
+    let const_for_of = r"
+        const fns = [];
+        for (const x of [4, 5]) {
+            fns.push(() => x);
+        }
+        fns.map(f => f()).join()
+        ";
+    assert_eq!(&exec(const_for_of), "\"4,5\"");
+
+    let const_increment = r"
+        try {
+            for (const i = 0; i < 1; i++) {}
+        } catch (err) {
+            err.name
+        }
+        ";
+    assert_eq!(&exec(const_increment), "\"TypeError\"");
+}
+
 #[test]
 fn test_invalid_break_target() {
     let src = r#"
@@ -681,6 +740,166 @@ fn unary_post() {
     assert_eq!(&exec(execs_after_dec), "true");
 }
 
+/// Logs the evaluation of the base object, the key and the right-hand side of an assignment, and
+/// the gets and sets of the `p` property of `obj`.
+const REFERENCE_LOGGING: &str = r#"
+    var log = [];
+    var value = 1;
+    var obj = {
+        get p() { log.push('get'); return value; },
+        set p(v) { log.push('set ' + v); value = v; }
+    };
+    function base() { log.push('base'); return obj; }
+    function key() { log.push('key'); return 'p'; }
+    function rhs(v) { log.push('rhs'); return v; }
+"#;
+
+#[test]
+fn compound_assignment_evaluation_order() {
+    let by_value = format!(
+        "{REFERENCE_LOGGING}
+        var result = base()[key()] += rhs(2);
+        log.push('= ' + result);
+        log.join(', ');"
+    );
+    assert_eq!(exec(&by_value), "\"base, key, get, rhs, set 3, = 3\"");
+
+    let by_name = format!(
+        "{REFERENCE_LOGGING}
+        base().p *= rhs(5);
+        log.join(', ');"
+    );
+    assert_eq!(exec(&by_name), "\"base, get, rhs, set 5\"");
+
+    let throwing_rhs = format!(
+        "{REFERENCE_LOGGING}
+        try {{ base()[key()] -= (() => {{ throw 'rhs' }})(); }} catch (e) {{ log.push(e); }}
+        log.join(', ');"
+    );
+    assert_eq!(exec(&throwing_rhs), "\"base, key, get, rhs\"");
+}
+
+#[test]
+fn logical_assignment_evaluation_order() {
+    let coalesce_short_circuit = format!(
+        "{REFERENCE_LOGGING}
+        var result = base()[key()] ??= rhs(2);
+        log.push('= ' + result);
+        log.join(', ');"
+    );
+    assert_eq!(exec(&coalesce_short_circuit), "\"base, key, get, = 1\"");
+
+    let coalesce = format!(
+        "{REFERENCE_LOGGING}
+        value = null;
+        var result = base()[key()] ??= rhs(2);
+        log.push('= ' + result);
+        log.join(', ');"
+    );
+    assert_eq!(exec(&coalesce), "\"base, key, get, rhs, set 2, = 2\"");
+
+    let or = format!(
+        "{REFERENCE_LOGGING}
+        base().p ||= rhs(2);
+        value = 0;
+        base().p ||= rhs(3);
+        log.join(', ');"
+    );
+    assert_eq!(exec(&or), "\"base, get, base, get, rhs, set 3\"");
+
+    let and = format!(
+        "{REFERENCE_LOGGING}
+        base()[key()] &&= rhs(2);
+        value = 0;
+        base()[key()] &&= rhs(3);
+        log.join(', ');"
+    );
+    assert_eq!(exec(&and), "\"base, key, get, rhs, set 2, base, key, get\"");
+
+    let statements_in_loop = r#"
+        var o = { a: null, b: 0 }, k = 'b', x = 0;
+        for (var i = 0; i < 3; i++) {
+            o.a ??= i;
+            o[k] ||= i;
+            x ||= 5;
+        }
+        [o.a, o.b, x, i].join(', ');
+    "#;
+    assert_eq!(exec(statements_in_loop), "\"0, 1, 5, 3\"");
+
+    let frozen = r#"
+        'use strict';
+        var frozen = Object.freeze({ p: 1 });
+        frozen.p ??= 2;
+        frozen.p;
+    "#;
+    assert_eq!(exec(frozen), "1");
+}
+
+#[test]
+fn update_expression_evaluation_order() {
+    let postfix = format!(
+        "{REFERENCE_LOGGING}
+        value = '5';
+        var result = base()[key()]++;
+        log.push('= ' + typeof result + ' ' + result);
+        log.join(', ');"
+    );
+    assert_eq!(exec(&postfix), "\"base, key, get, set 6, = number 5\"");
+
+    let prefix = format!(
+        "{REFERENCE_LOGGING}
+        var result = --base().p;
+        log.push('= ' + result);
+        log.join(', ');"
+    );
+    assert_eq!(exec(&prefix), "\"base, get, set 0, = 0\"");
+
+    let bigint = r#"
+        var o = { p: 5n };
+        var old = o['p']--;
+        [typeof old, old, o.p].join(', ');
+    "#;
+    assert_eq!(exec(bigint), "\"bigint, 5, 4\"");
+
+    let in_loop = r#"
+        var counts = {};
+        var words = ['a', 'b', 'a'];
+        for (var i = 0; i < words.length; i++) {
+            counts[words[i]] = counts[words[i]] || 0;
+            counts[words[i]]++;
+        }
+        [counts.a, counts.b].join(', ');
+    "#;
+    assert_eq!(exec(in_loop), "\"2, 1\"");
+}
+
+#[test]
+fn direct_eval_var_in_block() {
+    let scenario = r"
+        function f(price) {
+            {
+                eval('var e1 = 1');
+            }
+            return e1 + price;
+        }
+        f(4);
+    ";
+    assert_eq!(&exec(scenario), "5");
+
+    let with_parameter_expressions = r"
+        function f(price = 4) {
+            if (true) {
+                let inner = 2;
+                eval('var e1 = inner');
+            }
+            return e1 + price;
+        }
+        f();
+    ";
+    assert_eq!(&exec(with_parameter_expressions), "6");
+}
+
 #[test]
 fn unary_void() {
     let void_should_return_undefined = r#"
@@ -902,7 +1121,6 @@ fn var_decl_hoisting_with_initialization() {
 }
 
 #[test]
-#[ignore]
 fn var_decl_hoisting_2_variables_hoisting() {
     let scenario = r#"
         x = y;
@@ -916,7 +1134,6 @@ fn var_decl_hoisting_2_variables_hoisting() {
 }
 
 #[test]
-#[ignore]
 fn var_decl_hoisting_2_variables_hoisting_2() {
     let scenario = r#"
         var x = y;
@@ -928,7 +1145,6 @@ fn var_decl_hoisting_2_variables_hoisting_2() {
 }
 
 #[test]
-#[ignore]
 fn var_decl_hoisting_2_variables_hoisting_3() {
     let scenario = r#"
         let y = x;
@@ -940,6 +1156,95 @@ fn var_decl_hoisting_2_variables_hoisting_3() {
     assert_eq!(&exec(scenario), "undefined");
 }
 
+#[test]
+fn var_decl_hoisting_nested_statements() {
+    let scenario = r"
+        function f() {
+            var before = [a, b, c, d, e];
+            { var a = 1; }
+            for (;;) { var b = 2; break; }
+            for (var c in { key: 3 }) {}
+            switch (4) { case 4: var d = 4; }
+            try { var e = 5; } catch (error) {}
+            return before.map(String).join() + ' ' + [a, b, c, d, e].join();
+        }
+        f();
+    ";
+    assert_eq!(
+        &exec(scenario),
+        "\"undefined,undefined,undefined,undefined,undefined 1,2,key,4,5\""
+    );
+
+    let scenario = r"
+        function f() {
+            var before = x;
+            var x = 1;
+            return before;
+        }
+        f();
+    ";
+    assert_eq!(&exec(scenario), "undefined");
+
+    let scenario = r"
+        function f(a = 1) {
+            var a;
+            return a;
+        }
+        f();
+    ";
+    assert_eq!(&exec(scenario), "1");
+}
+
+#[test]
+fn function_decl_hoisting_over_var() {
+    let scenario = r"
+        function f() {
+            var before = typeof g;
+            var g = 1;
+            function g() {}
+            return before + ' ' + typeof g;
+        }
+        f();
+    ";
+    assert_eq!(&exec(scenario), "\"function number\"");
+}
+
+#[test]
+fn lexical_decl_tdz() {
+    let scenario = r"
+        try {
+            { let a = outer; let outer; }
+        } catch (e) {
+            e.name;
+        }
+    ";
+    assert_eq!(&exec(scenario), "\"ReferenceError\"");
+
+    let scenario = r"
+        try { let x = x; } catch (e) { e.name; }
+    ";
+    assert_eq!(&exec(scenario), "\"ReferenceError\"");
+
+    let scenario = r"
+        try {
+            (function () { g(); const c = 1; function g() { return c; } })();
+        } catch (e) {
+            e.name;
+        }
+    ";
+    assert_eq!(&exec(scenario), "\"ReferenceError\"");
+
+    let scenario = r"
+        try { typeof t; let t; } catch (e) { e.name; }
+    ";
+    assert_eq!(&exec(scenario), "\"ReferenceError\"");
+
+    let scenario = r"
+        typeof undeclared;
+    ";
+    assert_eq!(&exec(scenario), "\"undefined\"");
+}
+
 #[test]
 fn function_decl_hoisting() {
     let scenario = r#"
@@ -988,6 +1293,108 @@ fn function_decl_hoisting() {
     assert_eq!(&exec(scenario), "5");
 }
 
+#[test]
+fn block_function_decl_annex_b() {
+    let scenario = r"
+        var before = typeof f;
+        if (true) { function f() { return 1; } }
+        [before, f()].join();
+    ";
+    assert_eq!(&exec(scenario), "\"undefined,1\"");
+
+    let scenario = r"
+        if (false) { function g() { return 1; } } else { function g() { return 2; } }
+        g();
+    ";
+    assert_eq!(&exec(scenario), "2");
+
+    let scenario = r"
+        function f() {
+            var before = h;
+            { function h() {} }
+            return [before, typeof h].join();
+        }
+        f();
+    ";
+    assert_eq!(&exec(scenario), "\",function\"");
+
+    let scenario = r"
+        function f() {
+            if (false) { function h() {} }
+            return h;
+        }
+        f();
+    ";
+    assert_eq!(&exec(scenario), "undefined");
+
+    let scenario = r"
+        var functions = [];
+        for (var i = 0; i < 2; i++) { function f() {} functions.push(f); }
+        functions[0] === functions[1];
+    ";
+    assert_eq!(&exec(scenario), "false");
+}
+
+#[test]
+fn block_function_decl_annex_b_conflicts() {
+    let scenario = r"
+        let f = 1;
+        { function f() {} }
+        typeof f;
+    ";
+    assert_eq!(&exec(scenario), "\"number\"");
+
+    let scenario = r"
+        function f(g) {
+            { function g() {} }
+            return typeof g;
+        }
+        f(1);
+    ";
+    assert_eq!(&exec(scenario), "\"number\"");
+
+    let scenario = r"
+        {
+            let g = 1;
+            { function g() {} }
+        }
+        typeof g;
+    ";
+    assert_eq!(&exec(scenario), "\"undefined\"");
+
+    let scenario = r"
+        for (let g of [1]) { { function g() {} } }
+        typeof g;
+    ";
+    assert_eq!(&exec(scenario), "\"undefined\"");
+
+    let scenario = r"
+        { function* g() {} async function h() {} }
+        [typeof g, typeof h].join();
+    ";
+    assert_eq!(&exec(scenario), "\"undefined,undefined\"");
+}
+
+#[test]
+fn block_function_decl_strict() {
+    let scenario = r"
+        'use strict';
+        { function f() {} }
+        typeof f;
+    ";
+    assert_eq!(&exec(scenario), "\"undefined\"");
+
+    let scenario = r"
+        function f() {
+            'use strict';
+            { function g() { return 1; } var inside = g(); }
+            return [inside, typeof g].join();
+        }
+        f();
+    ";
+    assert_eq!(&exec(scenario), "\"1,undefined\"");
+}
+
 #[test]
 fn to_bigint() {
     let mut context = Context::default();
@@ -1235,10 +1642,12 @@ fn to_object() {
     assert!(JsValue::undefined()
         .to_object(&mut context)
         .unwrap_err()
+        .to_opaque(&mut context)
         .is_object());
     assert!(JsValue::null()
         .to_object(&mut context)
         .unwrap_err()
+        .to_opaque(&mut context)
         .is_object());
 }
 
@@ -32,98 +32,93 @@ impl JsValue {
         }
     }
 
-    /// Abstract equality comparison.
+    /// Abstract operation `IsLooselyEqual ( x, y )`.
     ///
     /// This method is executed when doing abstract equality comparisons with the `==` operator.
-    ///  For more information, check <https://tc39.es/ecma262/#sec-abstract-equality-comparison>
-    #[allow(clippy::float_cmp)]
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-islooselyequal
     pub fn equals(&self, other: &Self, context: &mut Context) -> JsResult<bool> {
         // 1. If Type(x) is the same as Type(y), then
-        //     a. Return the result of performing Strict Equality Comparison x === y.
+        //     a. Return IsStrictlyEqual(x, y).
         if self.get_type() == other.get_type() {
             return Ok(self.strict_equals(other));
         }
 
-        Ok(match (self, other) {
+        match (self, other) {
             // 2. If x is null and y is undefined, return true.
             // 3. If x is undefined and y is null, return true.
-            (Self::Null, Self::Undefined) | (Self::Undefined, Self::Null) => true,
-
-            // 3. If Type(x) is Number and Type(y) is String, return the result of the comparison x == ! ToNumber(y).
-            // 4. If Type(x) is String and Type(y) is Number, return the result of the comparison ! ToNumber(x) == y.
-            //
-            // https://github.com/rust-lang/rust/issues/54883
-            (Self::Integer(_) | Self::Rational(_), Self::String(_) | Self::Boolean(_))
-            | (Self::String(_), Self::Integer(_) | Self::Rational(_)) => {
-                let x = self.to_number(context)?;
-                let y = other.to_number(context)?;
-                Number::equal(x, y)
+            (Self::Null, Self::Undefined) | (Self::Undefined, Self::Null) => Ok(true),
+
+            // 4. NOTE: This step is replaced in section B.3.6.2.
+            // 5. If Type(x) is Number and Type(y) is String, return IsLooselyEqual(x, ! ToNumber(y)).
+            (Self::Integer(_) | Self::Rational(_), Self::String(_)) => {
+                let y = Self::new(other.to_number(context)?);
+                self.equals(&y, context)
             }
 
-            // 6. If Type(x) is BigInt and Type(y) is String, then
-            //    a. Let n be ! StringToBigInt(y).
-            //    b. If n is NaN, return false.
-            //    c. Return the result of the comparison x == n.
-            (Self::BigInt(ref a), Self::String(ref b)) => match JsBigInt::from_string(b) {
-                Some(ref b) => a == b,
-                None => false,
-            },
-
-            // 7. If Type(x) is String and Type(y) is BigInt, return the result of the comparison y == x.
-            (Self::String(ref a), Self::BigInt(ref b)) => match JsBigInt::from_string(a) {
-                Some(ref a) => a == b,
-                None => false,
-            },
-
-            // 8. If Type(x) is Boolean, return the result of the comparison ! ToNumber(x) == y.
-            (Self::Boolean(x), _) => return other.equals(&Self::new(i32::from(*x)), context),
-
-            // 9. If Type(y) is Boolean, return the result of the comparison x == ! ToNumber(y).
-            (_, Self::Boolean(y)) => return self.equals(&Self::new(i32::from(*y)), context),
-
-            // 10. If Type(x) is either String, Number, BigInt, or Symbol and Type(y) is Object, return the result
-            // of the comparison x == ? ToPrimitive(y).
+            // 6. If Type(x) is String and Type(y) is Number, return IsLooselyEqual(! ToNumber(x), y).
+            (Self::String(_), Self::Integer(_) | Self::Rational(_)) => {
+                let x = Self::new(self.to_number(context)?);
+                x.equals(other, context)
+            }
+
+            // 7. If Type(x) is BigInt and Type(y) is String, then
+            (Self::BigInt(_), Self::String(y)) => {
+                // a. Let n be StringToBigInt(y).
+                // b. If n is undefined, return false.
+                // c. Return IsLooselyEqual(x, n).
+                JsBigInt::from_string(y).map_or(Ok(false), |n| self.equals(&n.into(), context))
+            }
+
+            // 8. If Type(x) is String and Type(y) is BigInt, return IsLooselyEqual(y, x).
+            (Self::String(_), Self::BigInt(_)) => other.equals(self, context),
+
+            // 9. If Type(x) is Boolean, return IsLooselyEqual(! ToNumber(x), y).
+            (Self::Boolean(x), _) => Self::new(i32::from(*x)).equals(other, context),
+
+            // 10. If Type(y) is Boolean, return IsLooselyEqual(x, ! ToNumber(y)).
+            (_, Self::Boolean(y)) => self.equals(&Self::new(i32::from(*y)), context),
+
+            // 11. If Type(x) is either String, Number, BigInt, or Symbol and Type(y) is Object, return IsLooselyEqual(x, ? ToPrimitive(y)).
             (
-                Self::Object(_),
                 Self::String(_)
                 | Self::Rational(_)
                 | Self::Integer(_)
                 | Self::BigInt(_)
                 | Self::Symbol(_),
+                Self::Object(_),
             ) => {
-                let primitive = self.to_primitive(context, PreferredType::Default)?;
-                return Ok(primitive
-                    .equals(other, context)
-                    .expect("should not fail according to spec"));
+                let y = other.to_primitive(context, PreferredType::Default)?;
+                self.equals(&y, context)
             }
 
-            // 11. If Type(x) is Object and Type(y) is either String, Number, BigInt, or Symbol, return the result
-            // of the comparison ? ToPrimitive(x) == y.
+            // 12. If Type(x) is Object and Type(y) is either String, Number, BigInt, or Symbol, return IsLooselyEqual(? ToPrimitive(x), y).
             (
+                Self::Object(_),
                 Self::String(_)
                 | Self::Rational(_)
                 | Self::Integer(_)
                 | Self::BigInt(_)
                 | Self::Symbol(_),
-                Self::Object(_),
             ) => {
-                let primitive = other.to_primitive(context, PreferredType::Default)?;
-                return Ok(primitive
-                    .equals(self, context)
-                    .expect("should not fail according to spec"));
+                let x = self.to_primitive(context, PreferredType::Default)?;
+                x.equals(other, context)
             }
 
-            // 12. If Type(x) is BigInt and Type(y) is Number, or if Type(x) is Number and Type(y) is BigInt, then
-            //    a. If x or y are any of NaN, +∞, or -∞, return false.
-            //    b. If the mathematical value of x is equal to the mathematical value of y, return true; otherwise return false.
-            (Self::BigInt(ref a), Self::Rational(ref b)) => a == b,
-            (Self::Rational(ref a), Self::BigInt(ref b)) => a == b,
-            (Self::BigInt(ref a), Self::Integer(ref b)) => a == b,
-            (Self::Integer(ref a), Self::BigInt(ref b)) => a == b,
+            // 13. If Type(x) is BigInt and Type(y) is Number, or if Type(x) is Number and Type(y) is BigInt, then
+            //    a. If x or y are any of NaN, +∞𝔽, or -∞𝔽, return false.
+            //    b. If ℝ(x) = ℝ(y), return true; otherwise return false.
+            (Self::BigInt(x), Self::Rational(y)) | (Self::Rational(y), Self::BigInt(x)) => {
+                Ok(x == y)
+            }
+            (Self::BigInt(x), Self::Integer(y)) | (Self::Integer(y), Self::BigInt(x)) => Ok(x == y),
 
-            // 13. Return false.
-            _ => false,
-        })
+            // 14. Return false.
+            _ => Ok(false),
+        }
     }
 
     /// The internal comparison abstract operation SameValue(x, y),
@@ -10,10 +10,10 @@ use crate::{
         number::{f64_to_int32, f64_to_uint32},
         Number,
     },
-    object::{JsObject, ObjectData},
+    object::{JsObject, NativeObject, Object, ObjectData, Ref, RefMut},
     property::{PropertyDescriptor, PropertyKey},
     symbol::{JsSymbol, WellKnownSymbols},
-    Context, JsBigInt, JsResult, JsString,
+    Context, JsBigInt, JsNativeError, JsResult, JsString,
 };
 use boa_gc::{custom_trace, Finalize, Trace};
 use boa_profiler::Profiler;
@@ -140,6 +140,50 @@ impl JsValue {
         }
     }
 
+    /// Downcasts a reference to the native Rust value of type `T` held by this object.
+    ///
+    /// This is mostly useful to recover the native data of the `this` value of a native method,
+    /// since it throws a `TypeError` if the value is not an object holding a `T`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the object is currently mutably borrowed.
+    #[track_caller]
+    pub fn downcast_native_ref<T>(&self, context: &mut Context) -> JsResult<Ref<'_, T>>
+    where
+        T: NativeObject,
+    {
+        if let Some(data) = self.as_object().and_then(JsObject::downcast_ref::<T>) {
+            return Ok(data);
+        }
+        context.throw_type_error(format!(
+            "expected an object holding native data of type `{}`",
+            std::any::type_name::<T>()
+        ))
+    }
+
+    /// Downcasts a mutable reference to the native Rust value of type `T` held by this object.
+    ///
+    /// This is mostly useful to recover the native data of the `this` value of a native method,
+    /// since it throws a `TypeError` if the value is not an object holding a `T`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the object is currently borrowed.
+    #[track_caller]
+    pub fn downcast_native_mut<T>(&self, context: &mut Context) -> JsResult<RefMut<'_, Object, T>>
+    where
+        T: NativeObject,
+    {
+        if let Some(data) = self.as_object().and_then(JsObject::downcast_mut::<T>) {
+            return Ok(data);
+        }
+        context.throw_type_error(format!(
+            "expected an object holding native data of type `{}`",
+            std::any::type_name::<T>()
+        ))
+    }
+
     /// It determines if the value is a callable function with a `[[Call]]` internal method.
     ///
     /// More information:
@@ -927,9 +971,9 @@ impl JsValue {
         // 1. If Type(Obj) is not Object, throw a TypeError exception.
         self.as_object()
             .ok_or_else(|| {
-                context.construct_type_error(
-                    "Cannot construct a property descriptor from a non-object",
-                )
+                JsNativeError::typ()
+                    .with_message("Cannot construct a property descriptor from a non-object")
+                    .into()
             })
             .and_then(|obj| obj.to_property_descriptor(context))
     }
@@ -969,13 +1013,13 @@ impl JsValue {
     ///  - [ECMAScript reference][spec]
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-isarray
-    pub(crate) fn is_array(&self, context: &mut Context) -> JsResult<bool> {
+    pub(crate) fn is_array(&self) -> JsResult<bool> {
         // Note: The spec specifies this function for JsValue.
         // The main part of the function is implemented for JsObject.
 
         // 1. If Type(argument) is not Object, return false.
         if let Some(object) = self.as_object() {
-            object.is_array_abstract(context)
+            object.is_array_abstract()
         }
         // 4. Return false.
         else {
@@ -19,9 +19,9 @@ impl JsValue {
             (Self::BigInt(ref x), Self::BigInt(ref y)) => Self::new(JsBigInt::add(x, y)),
 
             // String concat
-            (Self::String(ref x), Self::String(ref y)) => Self::from(JsString::concat(x, y)),
-            (Self::String(ref x), y) => Self::from(JsString::concat(x, y.to_string(context)?)),
-            (x, Self::String(ref y)) => Self::from(JsString::concat(x.to_string(context)?, y)),
+            (Self::String(ref x), Self::String(ref y)) => Self::concat_strings(x, y, context)?,
+            (Self::String(ref x), y) => Self::concat_strings(x, &y.to_string(context)?, context)?,
+            (x, Self::String(ref y)) => Self::concat_strings(&x.to_string(context)?, y, context)?,
 
             // Slow path:
             (_, _) => match (
@@ -29,10 +29,10 @@ impl JsValue {
                 other.to_primitive(context, PreferredType::Default)?,
             ) {
                 (Self::String(ref x), ref y) => {
-                    Self::from(JsString::concat(x, y.to_string(context)?))
+                    Self::concat_strings(x, &y.to_string(context)?, context)?
                 }
                 (ref x, Self::String(ref y)) => {
-                    Self::from(JsString::concat(x.to_string(context)?, y))
+                    Self::concat_strings(&x.to_string(context)?, y, context)?
                 }
                 (x, y) => match (x.to_numeric(context)?, y.to_numeric(context)?) {
                     (Numeric::Number(x), Numeric::Number(y)) => Self::new(x + y),
@@ -49,6 +49,15 @@ impl JsValue {
         })
     }
 
+    /// Concatenates two strings, throwing a `RangeError` if the result would exceed the maximum
+    /// string length of the context.
+    fn concat_strings(x: &JsString, y: &JsString, context: &Context) -> JsResult<Self> {
+        context
+            .resource_limits()
+            .check_string_length(&[x.as_str(), y.as_str()])?;
+        Ok(Self::from(JsString::concat_code_units(&[x, y])))
+    }
+
     #[inline]
     pub fn sub(&self, other: &Self, context: &mut Context) -> JsResult<Self> {
         Ok(match (self, other) {
@@ -512,20 +521,7 @@ impl JsValue {
                 };
 
                 match (px, py) {
-                    (Self::String(ref x), Self::String(ref y)) => {
-                        if x.starts_with(y.as_str()) {
-                            return Ok(AbstractRelation::False);
-                        }
-                        if y.starts_with(x.as_str()) {
-                            return Ok(AbstractRelation::True);
-                        }
-                        for (x, y) in x.chars().zip(y.chars()) {
-                            if x != y {
-                                return Ok((x < y).into());
-                            }
-                        }
-                        unreachable!()
-                    }
+                    (Self::String(ref x), Self::String(ref y)) => (x < y).into(),
                     (Self::BigInt(ref x), Self::String(ref y)) => {
                         if let Some(y) = JsBigInt::from_string(y) {
                             (*x < y).into()
@@ -3,8 +3,9 @@
 use super::JsValue;
 use crate::{
     builtins::Array,
+    object::JsObject,
     property::{PropertyDescriptor, PropertyKey},
-    Context, JsResult,
+    Context, JsNativeError, JsResult,
 };
 use serde_json::{Map, Value};
 
@@ -49,9 +50,9 @@ impl JsValue {
                 .map(|i| Self::Integer(i as i32))
                 .or_else(|| num.as_f64().map(Self::Rational))
                 .ok_or_else(|| {
-                    context.construct_type_error(format!(
-                        "could not convert JSON number {num} to JsValue"
-                    ))
+                    JsNativeError::typ()
+                        .with_message(format!("could not convert JSON number {num} to JsValue"))
+                        .into()
                 }),
             Value::String(string) => Ok(Self::from(string.as_str())),
             Value::Array(vec) => {
@@ -59,7 +60,7 @@ impl JsValue {
                 for val in vec {
                     arr.push(Self::from_json(val, context)?);
                 }
-                Ok(Array::create_array_from_list(arr, context).into())
+                Ok(Array::create_array_from_list(arr, context)?.into())
             }
             Value::Object(obj) => {
                 let js_obj = context.construct_object();
@@ -104,52 +105,89 @@ impl JsValue {
     /// # assert_eq!(json, back_to_json);
     /// ```
     pub fn to_json(&self, context: &mut Context) -> JsResult<Value> {
+        self.to_json_inner(context, &mut Vec::new())
+    }
+
+    /// Converts the `JsValue` to a [`serde_json::Value`], keeping track of the objects that are
+    /// being converted, since a cyclic object cannot be represented as JSON.
+    fn to_json_inner(
+        &self,
+        context: &mut Context,
+        ancestors: &mut Vec<JsObject>,
+    ) -> JsResult<Value> {
         match self {
             Self::Null => Ok(Value::Null),
-            Self::Undefined => todo!("undefined to JSON"),
+            Self::Undefined => context.throw_type_error("cannot convert undefined to JSON"),
             &Self::Boolean(b) => Ok(b.into()),
             Self::String(string) => Ok(string.as_str().into()),
             &Self::Rational(rat) => Ok(rat.into()),
             &Self::Integer(int) => Ok(int.into()),
             Self::BigInt(_bigint) => context.throw_type_error("cannot convert bigint to JSON"),
             Self::Object(obj) => {
-                if obj.is_array() {
-                    let len = obj.length_of_array_like(context)?;
-                    let mut arr = Vec::with_capacity(len as usize);
+                if ancestors
+                    .iter()
+                    .any(|ancestor| JsObject::equals(ancestor, obj))
+                {
+                    return context.throw_type_error("cannot convert a cyclic object to JSON");
+                }
+                ancestors.push(obj.clone());
+                let json = Self::object_to_json(obj, context, ancestors);
+                ancestors.pop();
+                json
+            }
+            Self::Symbol(_sym) => context.throw_type_error("cannot convert Symbol to JSON"),
+        }
+    }
 
-                    let obj = obj.borrow();
+    /// Converts an object to a JSON array or object.
+    fn object_to_json(
+        obj: &JsObject,
+        context: &mut Context,
+        ancestors: &mut Vec<JsObject>,
+    ) -> JsResult<Value> {
+        if obj.is_array() {
+            let len = obj.length_of_array_like(context)?;
+            let mut arr = Vec::with_capacity(len as usize);
 
-                    for k in 0..len as u32 {
-                        let val = obj.properties().get(&k.into()).map_or(Self::Null, |desc| {
-                            desc.value().cloned().unwrap_or(Self::Null)
-                        });
-                        arr.push(val.to_json(context)?);
-                    }
+            for k in 0..len as u32 {
+                let val = obj
+                    .borrow()
+                    .properties()
+                    .get(&k.into())
+                    .map_or(Self::Null, |desc| {
+                        desc.value().cloned().unwrap_or(Self::Null)
+                    });
+                arr.push(val.to_json_inner(context, ancestors)?);
+            }
 
-                    Ok(Value::Array(arr))
-                } else {
-                    let mut map = Map::new();
-                    for (key, property) in obj.borrow().properties().iter() {
-                        let key = match &key {
-                            PropertyKey::String(string) => string.as_str().to_owned(),
-                            PropertyKey::Index(i) => i.to_string(),
-                            PropertyKey::Symbol(_sym) => {
-                                return context.throw_type_error("cannot convert Symbol to JSON")
-                            }
-                        };
-
-                        let value = match property.value() {
-                            Some(val) => val.to_json(context)?,
-                            None => Value::Null,
-                        };
-
-                        map.insert(key, value);
+            Ok(Value::Array(arr))
+        } else {
+            let properties: Vec<_> = obj
+                .borrow()
+                .properties()
+                .iter()
+                .map(|(key, property)| (key.clone(), property.value().cloned()))
+                .collect();
+
+            let mut map = Map::new();
+            for (key, value) in properties {
+                let key = match &key {
+                    PropertyKey::String(string) => string.as_str().to_owned(),
+                    PropertyKey::Index(i) => i.to_string(),
+                    PropertyKey::Symbol(_sym) => {
+                        return context.throw_type_error("cannot convert Symbol to JSON")
                     }
+                };
 
-                    Ok(Value::Object(map))
-                }
+                let value = match value {
+                    Some(val) => val.to_json_inner(context, ancestors)?,
+                    None => Value::Null,
+                };
+
+                map.insert(key, value);
             }
-            Self::Symbol(_sym) => context.throw_type_error("cannot convert Symbol to JSON"),
+
+            Ok(Value::Object(map))
         }
     }
 }
@@ -274,4 +312,27 @@ mod tests {
 
         assert_eq!(pow, 60466176);
     }
+
+    #[test]
+    fn unsupported_to_json() {
+        let mut context = Context::default();
+
+        for code in [
+            "undefined",
+            "({ a: undefined })",
+            "10n",
+            "Symbol()",
+            "let a = {}; a.self = a; a",
+            "let b = []; b.push({ b }); b",
+        ] {
+            let value = context.eval(code).unwrap();
+            assert!(value.to_json(&mut context).is_err(), "{code}");
+        }
+
+        let value = context.eval("let c = {}; [c, c]").unwrap();
+        assert_eq!(
+            value.to_json(&mut context).unwrap(),
+            serde_json::json!([{}, {}])
+        );
+    }
 }
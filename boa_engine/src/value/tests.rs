@@ -95,6 +95,80 @@ fn abstract_equality_comparison() {
     ]);
 }
 
+#[test]
+fn abstract_equality_coercion_matrix() {
+    check_output(&[
+        TestAction::TestEq("undefined == 0", "false"),
+        TestAction::TestEq("undefined == ''", "false"),
+        TestAction::TestEq("undefined == false", "false"),
+        TestAction::TestEq("null == 0", "false"),
+        TestAction::TestEq("null == ''", "false"),
+        TestAction::TestEq("null == []", "false"),
+        TestAction::TestEq("undefined == {}", "false"),
+        TestAction::TestEq("false == '0'", "true"),
+        TestAction::TestEq("false == []", "true"),
+        TestAction::TestEq("false == [0]", "true"),
+        TestAction::TestEq("false == [[]]", "true"),
+        TestAction::TestEq("true == '1'", "true"),
+        TestAction::TestEq("true == [1]", "true"),
+        TestAction::TestEq("true == 2", "false"),
+        TestAction::TestEq("true == 'true'", "false"),
+        TestAction::TestEq("0 == '0'", "true"),
+        TestAction::TestEq("0 == ''", "true"),
+        TestAction::TestEq("0 == ' \\n\\t'", "true"),
+        TestAction::TestEq("0 == []", "true"),
+        TestAction::TestEq("0 == [0]", "true"),
+        TestAction::TestEq("0 == {}", "false"),
+        TestAction::TestEq("1 == '0x1'", "true"),
+        TestAction::TestEq("1000 == '1e3'", "true"),
+        TestAction::TestEq("12 == ' 12 '", "true"),
+        TestAction::TestEq("'' == []", "true"),
+        TestAction::TestEq("'0' == []", "false"),
+        TestAction::TestEq("'1,2' == [1, 2]", "true"),
+        TestAction::TestEq("'[object Object]' == {}", "true"),
+        TestAction::TestEq("NaN == 'NaN'", "false"),
+        TestAction::TestEq("Symbol.iterator == 'Symbol(Symbol.iterator)'", "false"),
+        TestAction::TestEq("Symbol.iterator == Object(Symbol.iterator)", "true"),
+        TestAction::TestEq(
+            "({ valueOf() { return 1; }, toString() { return '2'; } }) == 1",
+            "true",
+        ),
+        TestAction::TestEq(
+            "({ [Symbol.toPrimitive](hint) { return hint; } }) == 'default'",
+            "true",
+        ),
+        TestAction::TestEq("new Date(0) == new Date(0).toString()", "true"),
+        TestAction::TestEq("1n == 1", "true"),
+        TestAction::TestEq("1n == 1.5", "false"),
+        TestAction::TestEq("1n == NaN", "false"),
+        TestAction::TestEq("1n == Infinity", "false"),
+        TestAction::TestEq("2n ** 64n == 2 ** 64", "true"),
+        TestAction::TestEq("2 ** 64 == 2n ** 64n + 1n", "false"),
+        TestAction::TestEq("1n == true", "true"),
+        TestAction::TestEq("0n == false", "true"),
+        TestAction::TestEq("0n == ''", "true"),
+        TestAction::TestEq("1n == ' 1 '", "true"),
+        TestAction::TestEq("1n == '+1'", "true"),
+        TestAction::TestEq("-1n == '-1'", "true"),
+        TestAction::TestEq("16n == '0x10'", "true"),
+        TestAction::TestEq("1n == '1n'", "false"),
+        TestAction::TestEq("10n == '1e1'", "false"),
+        TestAction::TestEq("10n == '1_0'", "false"),
+        TestAction::TestEq("1n == '0x+1'", "false"),
+        TestAction::TestEq("-1n == '-0x1'", "false"),
+        TestAction::TestEq("1n == '\\uFEFF1'", "true"),
+        TestAction::TestEq("1n == '\\u00851'", "false"),
+        TestAction::TestEq("1n == Object(1n)", "true"),
+        TestAction::TestEq("1n == [1]", "true"),
+        TestAction::TestEq("1n == Symbol.iterator", "false"),
+    ]);
+
+    check_output(&[TestAction::TestStartsWith(
+        "({ [Symbol.toPrimitive]() { return {}; } }) == 1",
+        r#"Uncaught "TypeError": "#,
+    )]);
+}
+
 /// Helper function to get the hash of a `Value`.
 fn hash_value(value: &JsValue) -> u64 {
     let mut hasher = DefaultHasher::new();
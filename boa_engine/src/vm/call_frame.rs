@@ -2,7 +2,7 @@
 //!
 //! This module will provides everything needed to implement the `CallFrame`
 
-use crate::{object::JsObject, vm::CodeBlock};
+use crate::{builtins::promise::PromiseCapability, object::JsObject, vm::CodeBlock, JsValue};
 use boa_gc::{Finalize, Gc, Trace};
 
 #[derive(Clone, Debug, Finalize, Trace)]
@@ -36,6 +36,14 @@ pub struct CallFrame {
     // When an async generator is resumed, the generator object is needed
     // to fulfill the steps 4.e-j in [AsyncGeneratorStart](https://tc39.es/ecma262/#sec-asyncgeneratorstart).
     pub(crate) async_generator: Option<JsObject>,
+
+    // The promise capability of the running call of an async function, that is resolved or
+    // rejected once the function returns or throws.
+    pub(crate) promise_capability: Option<PromiseCapability>,
+
+    // The completion value of the statements of a script or `eval` code, which is empty until
+    // a statement that produces a value completes.
+    pub(crate) completion_value: Option<JsValue>,
 }
 
 impl CallFrame {
@@ -10,13 +10,17 @@ use crate::{
         },
         generator::{Generator, GeneratorContext, GeneratorState},
         promise::PromiseCapability,
+        regexp::RegExp,
+    },
+    context::{
+        intrinsics::StandardConstructors,
+        memory::{Allocation, MemoryKind},
     },
-    context::intrinsics::StandardConstructors,
     environments::{BindingLocator, CompileTimeEnvironment},
     object::{
         internal_methods::get_prototype_from_constructor, JsObject, ObjectData, PrivateElement,
     },
-    property::PropertyDescriptor,
+    property::{PropertyDescriptor, PropertyKey},
     syntax::ast::node::FormalParameterList,
     vm::call_frame::GeneratorResumeKind,
     vm::{call_frame::FinallyReturn, CallFrame, Opcode},
@@ -83,6 +87,14 @@ pub struct CodeBlock {
     #[unsafe_ignore_trace]
     pub(crate) names: Vec<Sym>,
 
+    /// Compiled regular expression literals.
+    #[unsafe_ignore_trace]
+    pub(crate) regexps: Vec<RegExp>,
+
+    /// Property keys of object literals that only define data properties with literal names.
+    #[unsafe_ignore_trace]
+    pub(crate) object_literals: Vec<Box<[PropertyKey]>>,
+
     /// Locators for all bindings in the codeblock.
     #[unsafe_ignore_trace]
     pub(crate) bindings: Vec<BindingLocator>,
@@ -108,6 +120,9 @@ pub struct CodeBlock {
     /// We execute the parameter expressions in the function code and push the function environment afterward.
     /// When the execution of the parameter expressions throws an error, we do not need to pop the function environment.
     pub(crate) function_environment_push_location: u32,
+
+    /// The memory accounted for this code block.
+    pub(crate) allocation: Allocation,
 }
 
 impl CodeBlock {
@@ -117,6 +132,8 @@ impl CodeBlock {
             code: Vec::new(),
             literals: Vec::new(),
             names: Vec::new(),
+            regexps: Vec::new(),
+            object_literals: Vec::new(),
             bindings: Vec::new(),
             num_bindings: 0,
             functions: Vec::new(),
@@ -129,9 +146,38 @@ impl CodeBlock {
             compile_environments: Vec::new(),
             is_class_constructor: false,
             function_environment_push_location: 0,
+            allocation: Allocation::new(MemoryKind::CodeBlocks, boa_gc::allocation_size::<Self>()),
         }
     }
 
+    /// Gets the number of bytes allocated by this code block, excluding its inner functions.
+    pub(crate) fn allocated_bytes(&self) -> usize {
+        boa_gc::allocation_size::<Self>()
+            + self.code.capacity()
+            + self.literals.capacity() * size_of::<JsValue>()
+            + self.names.capacity() * size_of::<Sym>()
+            + self.regexps.capacity() * size_of::<RegExp>()
+            + self
+                .object_literals
+                .iter()
+                .map(|keys| keys.len() * size_of::<PropertyKey>())
+                .sum::<usize>()
+            + self.object_literals.capacity() * size_of::<Box<[PropertyKey]>>()
+            + self.bindings.capacity() * size_of::<BindingLocator>()
+            + self.functions.capacity() * size_of::<Gc<Self>>()
+    }
+
+    /// Gets the compile time environment of the function scope.
+    ///
+    /// The function scope is popped after the scopes nested in the body of the function, so its
+    /// environment is the last one pushed.
+    fn function_compile_environment(&self) -> Gc<Cell<CompileTimeEnvironment>> {
+        self.compile_environments
+            .last()
+            .expect("function code must have a compile time environment")
+            .clone()
+    }
+
     /// Read type T from code.
     ///
     /// # Safety
@@ -172,6 +218,11 @@ impl CodeBlock {
                 *pc += size_of::<i8>();
                 result
             }
+            Opcode::RotateRight => {
+                let result = self.read::<u8>(*pc).to_string();
+                *pc += size_of::<u8>();
+                result
+            }
             Opcode::PushInt16 => {
                 let result = self.read::<i16>(*pc).to_string();
                 *pc += size_of::<i16>();
@@ -188,6 +239,7 @@ impl CodeBlock {
                 ryu_js::Buffer::new().format(operand).to_string()
             }
             Opcode::PushLiteral
+            | Opcode::PushRegExp
             | Opcode::Jump
             | Opcode::JumpIfFalse
             | Opcode::JumpIfNotUndefined
@@ -220,6 +272,16 @@ impl CodeBlock {
                 *pc += size_of::<u32>();
                 format!("{operand1}, {operand2}")
             }
+            Opcode::PushObjectLiteral => {
+                let operand = self.read::<u32>(*pc);
+                *pc += size_of::<u32>();
+                let keys = self.object_literals[operand as usize]
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                format!("{operand:04}: {{{keys}}}")
+            }
             Opcode::GetFunction
             | Opcode::GetFunctionAsync
             | Opcode::GetGenerator
@@ -250,6 +312,7 @@ impl CodeBlock {
                 )
             }
             Opcode::GetPropertyByName
+            | Opcode::GetSuperPropertyByName
             | Opcode::SetPropertyByName
             | Opcode::DefineOwnPropertyByName
             | Opcode::DefineClassMethodByName
@@ -326,6 +389,7 @@ impl CodeBlock {
             | Opcode::DecPost
             | Opcode::GetPropertyByValue
             | Opcode::GetPropertyByValuePush
+            | Opcode::GetSuperPropertyByValue
             | Opcode::SetPropertyByValue
             | Opcode::DefineOwnPropertyByValue
             | Opcode::DefineClassMethodByValue
@@ -345,9 +409,11 @@ impl CodeBlock {
             | Opcode::This
             | Opcode::Super
             | Opcode::Return
+            | Opcode::SetCompletionValue
             | Opcode::PopEnvironment
             | Opcode::LoopStart
             | Opcode::LoopContinue
+            | Opcode::CopyDeclarativeEnvironment
             | Opcode::LoopEnd
             | Opcode::InitIterator
             | Opcode::IteratorNext
@@ -485,22 +551,13 @@ pub(crate) fn create_function_object(
         .configurable(true)
         .build();
 
-    let function = if r#async {
-        let promise_capability = PromiseCapability::new(
-            &context
-                .intrinsics()
-                .constructors()
-                .promise()
-                .constructor()
-                .into(),
-            context,
-        )
-        .expect("cannot  fail per spec");
+    // Arrow functions are not constructors, so they don't have a `prototype` property.
+    let arrow = code.this_mode == ThisMode::Lexical;
 
+    let function = if r#async {
         Function::Async {
             code,
             environments: context.realm.environments.clone(),
-            promise_capability,
         }
     } else {
         Function::Ordinary {
@@ -523,9 +580,7 @@ pub(crate) fn create_function_object(
         .configurable(true)
         .build();
 
-    prototype
-        .define_property_or_throw("constructor", constructor_property, context)
-        .expect("failed to define the constructor property of the function");
+    prototype.insert("constructor", constructor_property);
 
     let prototype_property = PropertyDescriptor::builder()
         .value(prototype)
@@ -534,17 +589,13 @@ pub(crate) fn create_function_object(
         .configurable(false)
         .build();
 
-    if !r#async {
-        constructor
-            .define_property_or_throw("prototype", prototype_property, context)
-            .expect("failed to define the prototype property of the function");
+    // The properties are defined in the order of the specification, which is the order in which
+    // they are listed as own property keys.
+    constructor.insert("length", length_property);
+    constructor.insert("name", name_property);
+    if !r#async && !arrow {
+        constructor.insert("prototype", prototype_property);
     }
-    constructor
-        .define_property_or_throw("name", name_property, context)
-        .expect("failed to define the name property of the function");
-    constructor
-        .define_property_or_throw("length", length_property, context)
-        .expect("failed to define the length property of the function");
 
     constructor
 }
@@ -620,15 +671,9 @@ pub(crate) fn create_generator_function_object(
         .configurable(false)
         .build();
 
-    constructor
-        .define_property_or_throw("prototype", prototype_property, context)
-        .expect("failed to define the prototype property of the generator function");
-    constructor
-        .define_property_or_throw("name", name_property, context)
-        .expect("failed to define the name property of the generator function");
-    constructor
-        .define_property_or_throw("length", length_property, context)
-        .expect("failed to define the length property of the generator function");
+    constructor.insert("length", length_property);
+    constructor.insert("name", name_property);
+    constructor.insert("prototype", prototype_property);
 
     constructor
 }
@@ -640,6 +685,7 @@ impl JsObject {
         args: &[JsValue],
         context: &mut Context,
     ) -> JsResult<JsValue> {
+        let _active = context.enter();
         let this_function_object = self.clone();
 
         if !self.is_callable() {
@@ -685,6 +731,10 @@ impl JsObject {
                         .throw_type_error("Class constructor cannot be invoked without 'new'");
                 }
 
+                if code.arguments_binding.is_some() {
+                    Arguments::check_property_count(args, context)?;
+                }
+
                 std::mem::swap(&mut environments, &mut context.realm.environments);
 
                 let lexical_this_mode = code.this_mode == ThisMode::Lexical;
@@ -703,25 +753,14 @@ impl JsObject {
                     )
                 };
 
-                if code.params.has_expressions() {
-                    context.realm.environments.push_function(
-                        code.num_bindings,
-                        code.compile_environments[1].clone(),
-                        this,
-                        self.clone(),
-                        None,
-                        lexical_this_mode,
-                    );
-                } else {
-                    context.realm.environments.push_function(
-                        code.num_bindings,
-                        code.compile_environments[0].clone(),
-                        this,
-                        self.clone(),
-                        None,
-                        lexical_this_mode,
-                    );
-                }
+                context.realm.environments.push_function(
+                    code.num_bindings,
+                    code.function_compile_environment(),
+                    this,
+                    self.clone(),
+                    None,
+                    lexical_this_mode,
+                );
 
                 if let Some(binding) = code.arguments_binding {
                     let arguments_obj = if code.strict || !code.params.is_simple() {
@@ -781,6 +820,8 @@ impl JsObject {
                     generator_resume_kind: GeneratorResumeKind::Normal,
                     thrown: false,
                     async_generator: None,
+                    promise_capability: None,
+                    completion_value: None,
                 });
 
                 let result = context.run();
@@ -798,16 +839,27 @@ impl JsObject {
                 let (result, _) = result?;
                 Ok(result)
             }
-            Function::Async {
-                code,
-                environments,
-                promise_capability,
-            } => {
+            Function::Async { code, environments } => {
                 let code = code.clone();
                 let mut environments = environments.clone();
-                let promise = promise_capability.promise().clone();
                 drop(object);
 
+                let promise_capability = PromiseCapability::new(
+                    &context
+                        .intrinsics()
+                        .constructors()
+                        .promise()
+                        .constructor()
+                        .into(),
+                    context,
+                )
+                .expect("cannot  fail per spec");
+                let promise = promise_capability.promise().clone();
+
+                if code.arguments_binding.is_some() {
+                    Arguments::check_property_count(args, context)?;
+                }
+
                 std::mem::swap(&mut environments, &mut context.realm.environments);
 
                 let lexical_this_mode = code.this_mode == ThisMode::Lexical;
@@ -826,25 +878,14 @@ impl JsObject {
                     )
                 };
 
-                if code.params.has_expressions() {
-                    context.realm.environments.push_function(
-                        code.num_bindings,
-                        code.compile_environments[1].clone(),
-                        this,
-                        self.clone(),
-                        None,
-                        lexical_this_mode,
-                    );
-                } else {
-                    context.realm.environments.push_function(
-                        code.num_bindings,
-                        code.compile_environments[0].clone(),
-                        this,
-                        self.clone(),
-                        None,
-                        lexical_this_mode,
-                    );
-                }
+                context.realm.environments.push_function(
+                    code.num_bindings,
+                    code.function_compile_environment(),
+                    this,
+                    self.clone(),
+                    None,
+                    lexical_this_mode,
+                );
 
                 if let Some(binding) = code.arguments_binding {
                     let arguments_obj = if code.strict || !code.params.is_simple() {
@@ -904,6 +945,8 @@ impl JsObject {
                     generator_resume_kind: GeneratorResumeKind::Normal,
                     thrown: false,
                     async_generator: None,
+                    promise_capability: Some(promise_capability),
+                    completion_value: None,
                 });
 
                 let _result = context.run();
@@ -925,6 +968,10 @@ impl JsObject {
                 let mut environments = environments.clone();
                 drop(object);
 
+                if code.arguments_binding.is_some() {
+                    Arguments::check_property_count(args, context)?;
+                }
+
                 std::mem::swap(&mut environments, &mut context.realm.environments);
 
                 let lexical_this_mode = code.this_mode == ThisMode::Lexical;
@@ -943,25 +990,14 @@ impl JsObject {
                     )
                 };
 
-                if code.params.has_expressions() {
-                    context.realm.environments.push_function(
-                        code.num_bindings,
-                        code.compile_environments[1].clone(),
-                        this,
-                        self.clone(),
-                        None,
-                        lexical_this_mode,
-                    );
-                } else {
-                    context.realm.environments.push_function(
-                        code.num_bindings,
-                        code.compile_environments[0].clone(),
-                        this,
-                        self.clone(),
-                        None,
-                        lexical_this_mode,
-                    );
-                }
+                context.realm.environments.push_function(
+                    code.num_bindings,
+                    code.function_compile_environment(),
+                    this,
+                    self.clone(),
+                    None,
+                    lexical_this_mode,
+                );
 
                 if let Some(binding) = code.arguments_binding {
                     let arguments_obj = if code.strict || !code.params.is_simple() {
@@ -1017,6 +1053,8 @@ impl JsObject {
                     generator_resume_kind: GeneratorResumeKind::Normal,
                     thrown: false,
                     async_generator: None,
+                    promise_capability: None,
+                    completion_value: None,
                 };
                 let mut stack = args;
 
@@ -1060,6 +1098,10 @@ impl JsObject {
                 let mut environments = environments.clone();
                 drop(object);
 
+                if code.arguments_binding.is_some() {
+                    Arguments::check_property_count(args, context)?;
+                }
+
                 std::mem::swap(&mut environments, &mut context.realm.environments);
 
                 let lexical_this_mode = code.this_mode == ThisMode::Lexical;
@@ -1078,25 +1120,14 @@ impl JsObject {
                     )
                 };
 
-                if code.params.has_expressions() {
-                    context.realm.environments.push_function(
-                        code.num_bindings,
-                        code.compile_environments[1].clone(),
-                        this,
-                        self.clone(),
-                        None,
-                        lexical_this_mode,
-                    );
-                } else {
-                    context.realm.environments.push_function(
-                        code.num_bindings,
-                        code.compile_environments[0].clone(),
-                        this,
-                        self.clone(),
-                        None,
-                        lexical_this_mode,
-                    );
-                }
+                context.realm.environments.push_function(
+                    code.num_bindings,
+                    code.function_compile_environment(),
+                    this,
+                    self.clone(),
+                    None,
+                    lexical_this_mode,
+                );
 
                 if let Some(binding) = code.arguments_binding {
                     let arguments_obj = if code.strict || !code.params.is_simple() {
@@ -1152,6 +1183,8 @@ impl JsObject {
                     generator_resume_kind: GeneratorResumeKind::Normal,
                     thrown: false,
                     async_generator: None,
+                    promise_capability: None,
+                    completion_value: None,
                 };
                 let mut stack = args;
 
@@ -1213,6 +1246,7 @@ impl JsObject {
         this_target: &JsValue,
         context: &mut Context,
     ) -> JsResult<JsObject> {
+        let _active = context.enter();
         let this_function_object = self.clone();
 
         let create_this = |context| {
@@ -1286,6 +1320,10 @@ impl JsObject {
                 let constructor_kind = *constructor_kind;
                 drop(object);
 
+                if code.arguments_binding.is_some() {
+                    Arguments::check_property_count(args, context)?;
+                }
+
                 std::mem::swap(&mut environments, &mut context.realm.environments);
 
                 let this = if constructor_kind.is_base() {
@@ -1309,25 +1347,14 @@ impl JsObject {
 
                 let new_target = this_target.as_object().expect("must be object");
 
-                if code.params.has_expressions() {
-                    context.realm.environments.push_function(
-                        code.num_bindings,
-                        code.compile_environments[1].clone(),
-                        this.clone().map(Into::into),
-                        self.clone(),
-                        Some(new_target.clone()),
-                        false,
-                    );
-                } else {
-                    context.realm.environments.push_function(
-                        code.num_bindings,
-                        code.compile_environments[0].clone(),
-                        this.clone().map(Into::into),
-                        self.clone(),
-                        Some(new_target.clone()),
-                        false,
-                    );
-                }
+                context.realm.environments.push_function(
+                    code.num_bindings,
+                    code.function_compile_environment(),
+                    this.clone().map(Into::into),
+                    self.clone(),
+                    Some(new_target.clone()),
+                    false,
+                );
 
                 let mut arguments_in_parameter_names = false;
                 let mut is_simple_parameter_list = true;
@@ -1400,6 +1427,8 @@ impl JsObject {
                     generator_resume_kind: GeneratorResumeKind::Normal,
                     thrown: false,
                     async_generator: None,
+                    promise_capability: None,
+                    completion_value: None,
                 });
 
                 let result = context.run();
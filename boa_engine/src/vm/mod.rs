@@ -7,7 +7,7 @@ use crate::{
         async_generator::{AsyncGenerator, AsyncGeneratorState},
         function::{ConstructorKind, Function},
         iterable::IteratorRecord,
-        Array, ForInIterator, JsArgs, Number, Promise,
+        Array, ForInIterator, JsArgs, Number, Promise, RegExp,
     },
     environments::EnvironmentSlots,
     object::{FunctionBuilder, JsFunction, JsObject, ObjectData, PrivateElement},
@@ -17,7 +17,7 @@ use crate::{
         call_frame::CatchAddresses,
         code_block::{initialize_instance_elements, Readable},
     },
-    Context, JsBigInt, JsResult, JsString, JsValue,
+    Context, JsBigInt, JsError, JsNativeError, JsResult, JsString, JsValue,
 };
 use boa_interner::ToInternedString;
 use boa_profiler::Profiler;
@@ -44,6 +44,7 @@ pub struct Vm {
     pub(crate) stack: Vec<JsValue>,
     pub(crate) trace: bool,
     pub(crate) stack_size_limit: usize,
+    pub(crate) instruction_count: u64,
 }
 
 impl Vm {
@@ -133,6 +134,11 @@ impl Context {
             }};
         }
 
+        self.vm.instruction_count += 1;
+        if self.vm.instruction_count > self.resource_limits().max_instructions() {
+            return self.throw_range_error("Maximum number of instructions exceeded");
+        }
+
         let opcode: Opcode = {
             let _timer = Profiler::global().start_event("Opcode retrieval", "vm");
             let opcode = self.vm.frame().code.code[self.vm.frame().pc]
@@ -168,6 +174,11 @@ impl Context {
                 self.vm.push(first);
                 self.vm.push(second);
             }
+            Opcode::RotateRight => {
+                let n = self.vm.read::<u8>() as usize;
+                let start = self.vm.stack.len() - n;
+                self.vm.stack[start..].rotate_right(1);
+            }
             Opcode::PushUndefined => self.vm.push(JsValue::undefined()),
             Opcode::PushNull => self.vm.push(JsValue::null()),
             Opcode::PushTrue => self.vm.push(true),
@@ -199,40 +210,83 @@ impl Context {
                 self.vm.push(value);
             }
             Opcode::PushEmptyObject => self.vm.push(self.construct_object()),
+            Opcode::PushObjectLiteral => {
+                let index = self.vm.read::<u32>();
+                let code = self.vm.frame().code.clone();
+                let keys = &code.object_literals[index as usize];
+                let values = self.vm.stack.split_off(self.vm.stack.len() - keys.len());
+                self.vm.frame_mut().pop_on_return -= keys.len();
+
+                let object = self.construct_object();
+                let max_count = self.resource_limits().max_property_count();
+                {
+                    let mut object = object.borrow_mut();
+                    for (key, value) in keys.iter().zip(values) {
+                        if object.properties().len() >= max_count
+                            && !object.properties().contains_key(key)
+                        {
+                            drop(object);
+                            return self.throw_range_error(format!(
+                                "object exceeds the maximum property count ({max_count})"
+                            ));
+                        }
+                        object.insert(
+                            key.clone(),
+                            PropertyDescriptor::builder()
+                                .value(value)
+                                .writable(true)
+                                .enumerable(true)
+                                .configurable(true),
+                        );
+                    }
+                }
+                self.vm.push(object);
+            }
+            Opcode::PushRegExp => {
+                let index = self.vm.read::<u32>();
+                let code = self.vm.frame().code.clone();
+                let regexp = RegExp::create_from_compiled(&code.regexps[index as usize], self);
+                self.vm.push(regexp);
+            }
             Opcode::PushClassPrototype => {
                 let superclass = self.vm.pop();
 
-                if let Some(superclass) = superclass.as_constructor() {
+                // If superclass is null, the prototype parent is null and the constructor parent
+                // is %Function.prototype%, which the class object already has.
+                let proto = if let Some(superclass) = superclass.as_constructor() {
                     let proto = superclass.get("prototype", self)?;
                     if !proto.is_object() && !proto.is_null() {
                         return self
                             .throw_type_error("superclass prototype must be an object or null");
                     }
+                    proto
+                } else if superclass.is_null() {
+                    JsValue::Null
+                } else {
+                    return self.throw_type_error("superclass must be a constructor");
+                };
 
-                    let class = self.vm.pop();
-                    {
-                        let class_object = class.as_object().expect("class must be object");
+                let class = self.vm.pop();
+                {
+                    let class_object = class.as_object().expect("class must be object");
+                    if let Some(superclass) = superclass.as_object() {
                         class_object.set_prototype(Some(superclass.clone()));
-
-                        let mut class_object_mut = class_object.borrow_mut();
-                        let class_function = class_object_mut
-                            .as_function_mut()
-                            .expect("class must be function object");
-                        if let Function::Ordinary {
-                            constructor_kind, ..
-                        } = class_function
-                        {
-                            *constructor_kind = ConstructorKind::Derived;
-                        }
                     }
 
-                    self.vm.push(class);
-                    self.vm.push(proto);
-                } else if superclass.is_null() {
-                    self.vm.push(JsValue::Null);
-                } else {
-                    return self.throw_type_error("superclass must be a constructor");
+                    let mut class_object_mut = class_object.borrow_mut();
+                    let class_function = class_object_mut
+                        .as_function_mut()
+                        .expect("class must be function object");
+                    if let Function::Ordinary {
+                        constructor_kind, ..
+                    } = class_function
+                    {
+                        *constructor_kind = ConstructorKind::Derived;
+                    }
                 }
+
+                self.vm.push(class);
+                self.vm.push(proto);
             }
             Opcode::SetClassPrototype => {
                 let prototype_value = self.vm.pop();
@@ -250,17 +304,15 @@ impl Context {
 
                 {
                     let class_object = class.as_object().expect("class must be object");
-                    class_object
-                        .define_property_or_throw(
-                            "prototype",
-                            PropertyDescriptorBuilder::new()
-                                .value(proto.clone())
-                                .writable(false)
-                                .enumerable(false)
-                                .configurable(false),
-                            self,
-                        )
-                        .expect("cannot fail per spec");
+                    class_object.define_property_or_throw(
+                        "prototype",
+                        PropertyDescriptorBuilder::new()
+                            .value(proto.clone())
+                            .writable(false)
+                            .enumerable(false)
+                            .configurable(false),
+                        self,
+                    )?;
                     let mut class_object_mut = class_object.borrow_mut();
                     let class_function = class_object_mut
                         .as_function_mut()
@@ -268,18 +320,16 @@ impl Context {
                     class_function.set_home_object(proto.clone());
                 }
 
-                proto
-                    .__define_own_property__(
-                        "constructor".into(),
-                        PropertyDescriptorBuilder::new()
-                            .value(class)
-                            .writable(true)
-                            .enumerable(false)
-                            .configurable(true)
-                            .build(),
-                        self,
-                    )
-                    .expect("cannot fail per spec");
+                proto.__define_own_property__(
+                    "constructor".into(),
+                    PropertyDescriptorBuilder::new()
+                        .value(class)
+                        .writable(true)
+                        .enumerable(false)
+                        .configurable(true)
+                        .build(),
+                    self,
+                )?;
 
                 self.vm.push(proto);
             }
@@ -310,8 +360,7 @@ impl Context {
                 let len = o
                     .length_of_array_like(self)
                     .expect("should have 'length' property");
-                o.create_data_property_or_throw(len, value, self)
-                    .expect("should be able to create new data property");
+                o.create_data_property_or_throw(len, value, self)?;
                 self.vm.push(array);
             }
             Opcode::PushElisionToArray => {
@@ -630,7 +679,9 @@ impl Context {
                 ) {
                     value
                 } else {
-                    JsValue::undefined()
+                    let name =
+                        JsString::from(self.interner().resolve_expect(binding_locator.name()));
+                    return self.throw_reference_error(format!("{name} is not initialized"));
                 };
 
                 self.vm.push(value);
@@ -734,47 +785,58 @@ impl Context {
                 let index = self.vm.read::<u32>();
 
                 let value = self.vm.pop();
-                let object = if let Some(object) = value.as_object() {
-                    object.clone()
-                } else {
-                    value.to_object(self)?
-                };
 
                 let name = self.vm.frame().code.names[index as usize];
-                let name: PropertyKey = self.interner().resolve_expect(name).into();
-                let result = object.get(name, self)?;
+                let name: PropertyKey = self.interned_string(name).into();
+                let result = value.get_v(name, self)?;
 
                 self.vm.push(result);
             }
             Opcode::GetPropertyByValue => {
                 let object = self.vm.pop();
                 let key = self.vm.pop();
-                let object = if let Some(object) = object.as_object() {
-                    object.clone()
-                } else {
-                    object.to_object(self)?
-                };
+                object.require_object_coercible(self)?;
 
                 let key = key.to_property_key(self)?;
-                let value = object.get(key, self)?;
+                let value = object.get_v(key, self)?;
 
                 self.vm.push(value);
             }
             Opcode::GetPropertyByValuePush => {
                 let object = self.vm.pop();
                 let key = self.vm.pop();
-                let object = if let Some(object) = object.as_object() {
-                    object.clone()
-                } else {
-                    object.to_object(self)?
-                };
+                object.require_object_coercible(self)?;
 
                 let property_key = key.to_property_key(self)?;
-                let value = object.get(property_key, self)?;
+                let value = object.get_v(property_key, self)?;
 
                 self.vm.push(key);
                 self.vm.push(value);
             }
+            Opcode::GetSuperPropertyByName => {
+                let index = self.vm.read::<u32>();
+
+                let object = self.vm.pop();
+                let this = self.vm.pop();
+                let object = object.to_object(self)?;
+
+                let name = self.vm.frame().code.names[index as usize];
+                let name: PropertyKey = self.interned_string(name).into();
+                let result = object.__get__(&name, this, self)?;
+
+                self.vm.push(result);
+            }
+            Opcode::GetSuperPropertyByValue => {
+                let object = self.vm.pop();
+                let key = self.vm.pop();
+                let this = self.vm.pop();
+                let object = object.to_object(self)?;
+
+                let key = key.to_property_key(self)?;
+                let value = object.__get__(&key, this, self)?;
+
+                self.vm.push(value);
+            }
             Opcode::SetPropertyByName => {
                 let index = self.vm.read::<u32>();
 
@@ -787,7 +849,7 @@ impl Context {
                 };
 
                 let name = self.vm.frame().code.names[index as usize];
-                let name: PropertyKey = self.interner().resolve_expect(name).into();
+                let name: PropertyKey = self.interned_string(name).into();
 
                 object.set(name, value, self.vm.frame().code.strict, self)?;
             }
@@ -801,7 +863,7 @@ impl Context {
                     object.to_object(self)?
                 };
                 let name = self.vm.frame().code.names[index as usize];
-                let name = self.interner().resolve_expect(name);
+                let name = self.interned_string(name);
                 object.__define_own_property__(
                     name.into(),
                     PropertyDescriptor::builder()
@@ -830,7 +892,7 @@ impl Context {
                     .expect("method must be function object")
                     .set_home_object(object.clone());
                 let name = self.vm.frame().code.names[index as usize];
-                let name = self.interner().resolve_expect(name);
+                let name = self.interned_string(name);
                 object.__define_own_property__(
                     name.into(),
                     PropertyDescriptor::builder()
@@ -910,7 +972,7 @@ impl Context {
                 let value = self.vm.pop();
                 let object = object.to_object(self)?;
                 let name = self.vm.frame().code.names[index as usize];
-                let name = self.interner().resolve_expect(name).into();
+                let name = self.interned_string(name).into();
                 let set = object
                     .__get_own_property__(&name, self)?
                     .as_ref()
@@ -940,7 +1002,7 @@ impl Context {
                     .expect("method must be function object")
                     .set_home_object(object.clone());
                 let name = self.vm.frame().code.names[index as usize];
-                let name = self.interner().resolve_expect(name).into();
+                let name = self.interned_string(name).into();
                 let set = object
                     .__get_own_property__(&name, self)?
                     .as_ref()
@@ -1014,7 +1076,7 @@ impl Context {
                 let value = self.vm.pop();
                 let object = object.to_object(self)?;
                 let name = self.vm.frame().code.names[index as usize];
-                let name = self.interner().resolve_expect(name).into();
+                let name = self.interned_string(name).into();
                 let get = object
                     .__get_own_property__(&name, self)?
                     .as_ref()
@@ -1044,7 +1106,7 @@ impl Context {
                     .expect("method must be function object")
                     .set_home_object(object.clone());
                 let name = self.vm.frame().code.names[index as usize];
-                let name = self.interner().resolve_expect(name).into();
+                let name = self.interned_string(name).into();
                 let get = object
                     .__get_own_property__(&name, self)?
                     .as_ref()
@@ -1244,6 +1306,14 @@ impl Context {
                 let class_value = self.vm.pop();
 
                 let field_name_key = field_name_value.to_property_key(self)?;
+                let class_object = class_value
+                    .as_object()
+                    .expect("class must be function object");
+                let prototype = class_object
+                    .get("prototype", self)?
+                    .as_object()
+                    .cloned()
+                    .expect("class prototype must be an object");
                 let field_function_object = field_function_value
                     .as_object()
                     .expect("field value must be function object");
@@ -1251,10 +1321,9 @@ impl Context {
                 let field_function = field_function_object_borrow
                     .as_function_mut()
                     .expect("field value must be function object");
-                let class_object = class_value
-                    .as_object()
-                    .expect("class must be function object");
-                field_function.set_home_object(class_object.clone());
+                // Instance field initializers are evaluated with the class prototype as their
+                // home object, like instance methods.
+                field_function.set_home_object(prototype);
                 class_object
                     .borrow_mut()
                     .as_function_mut()
@@ -1270,6 +1339,14 @@ impl Context {
                 let field_function_value = self.vm.pop();
                 let class_value = self.vm.pop();
 
+                let class_object = class_value
+                    .as_object()
+                    .expect("class must be function object");
+                let prototype = class_object
+                    .get("prototype", self)?
+                    .as_object()
+                    .cloned()
+                    .expect("class prototype must be an object");
                 let field_function_object = field_function_value
                     .as_object()
                     .expect("field value must be function object");
@@ -1277,10 +1354,9 @@ impl Context {
                 let field_function = field_function_object_borrow
                     .as_function_mut()
                     .expect("field value must be function object");
-                let class_object = class_value
-                    .as_object()
-                    .expect("class must be function object");
-                field_function.set_home_object(class_object.clone());
+                // Instance field initializers are evaluated with the class prototype as their
+                // home object, like instance methods.
+                field_function.set_home_object(prototype);
                 class_object
                     .borrow_mut()
                     .as_function_mut()
@@ -1296,9 +1372,18 @@ impl Context {
                 let getter = self.vm.pop();
                 let getter_object = getter.as_callable().expect("getter must be callable");
                 let class = self.vm.pop();
-                class
+                let class_object = class.as_object().expect("class must be function object");
+                let prototype = class_object
+                    .get("prototype", self)?
                     .as_object()
-                    .expect("class must be function object")
+                    .cloned()
+                    .expect("class prototype must be an object");
+                getter_object
+                    .borrow_mut()
+                    .as_function_mut()
+                    .expect("getter must be function object")
+                    .set_home_object(prototype);
+                class_object
                     .borrow_mut()
                     .as_function_mut()
                     .expect("class must be function object")
@@ -1316,9 +1401,18 @@ impl Context {
                 let setter = self.vm.pop();
                 let setter_object = setter.as_callable().expect("getter must be callable");
                 let class = self.vm.pop();
-                class
+                let class_object = class.as_object().expect("class must be function object");
+                let prototype = class_object
+                    .get("prototype", self)?
                     .as_object()
-                    .expect("class must be function object")
+                    .cloned()
+                    .expect("class prototype must be an object");
+                setter_object
+                    .borrow_mut()
+                    .as_function_mut()
+                    .expect("setter must be function object")
+                    .set_home_object(prototype);
+                class_object
                     .borrow_mut()
                     .as_function_mut()
                     .expect("class must be function object")
@@ -1336,9 +1430,18 @@ impl Context {
                 let method = self.vm.pop();
                 let method_object = method.as_callable().expect("method must be callable");
                 let class = self.vm.pop();
-                class
+                let class_object = class.as_object().expect("class must be function object");
+                let prototype = class_object
+                    .get("prototype", self)?
                     .as_object()
-                    .expect("class must be function object")
+                    .cloned()
+                    .expect("class prototype must be an object");
+                method_object
+                    .borrow_mut()
+                    .as_function_mut()
+                    .expect("method must be function object")
+                    .set_home_object(prototype);
+                class_object
                     .borrow_mut()
                     .as_function_mut()
                     .expect("class must be function object")
@@ -1347,11 +1450,13 @@ impl Context {
             Opcode::DeletePropertyByName => {
                 let index = self.vm.read::<u32>();
                 let key = self.vm.frame().code.names[index as usize];
-                let key = self.interner().resolve_expect(key).into();
+                let key = self.interned_string(key).into();
                 let object = self.vm.pop();
                 let result = object.to_object(self)?.__delete__(&key, self)?;
                 if !result && self.vm.frame().code.strict {
-                    return Err(self.construct_type_error("Cannot delete property"));
+                    return Err(JsNativeError::typ()
+                        .with_message("Cannot delete property")
+                        .into());
                 }
                 self.vm.push(result);
             }
@@ -1362,7 +1467,9 @@ impl Context {
                     .to_object(self)?
                     .__delete__(&key.to_property_key(self)?, self)?;
                 if !result && self.vm.frame().code.strict {
-                    return Err(self.construct_type_error("Cannot delete property"));
+                    return Err(JsNativeError::typ()
+                        .with_message("Cannot delete property")
+                        .into());
                 }
                 self.vm.push(result);
             }
@@ -1393,7 +1500,7 @@ impl Context {
             }
             Opcode::Throw => {
                 let value = self.vm.pop();
-                return Err(value);
+                return Err(JsError::from_opaque(value));
             }
             Opcode::TryStart => {
                 let next = self.vm.read::<u32>();
@@ -1476,7 +1583,7 @@ impl Context {
                         return Ok(ShouldExit::True);
                     }
                     FinallyReturn::Err => {
-                        return Err(self.vm.pop());
+                        return Err(JsError::from_opaque(self.vm.pop()));
                     }
                 }
             }
@@ -1881,7 +1988,11 @@ impl Context {
 
                 let result = func
                     .as_constructor()
-                    .ok_or_else(|| self.construct_type_error("not a constructor"))
+                    .ok_or_else(|| {
+                        JsNativeError::typ()
+                            .with_message("not a constructor")
+                            .into()
+                    })
                     .and_then(|cons| cons.__construct__(&arguments, cons, self))?;
 
                 self.vm.push(result);
@@ -1906,7 +2017,11 @@ impl Context {
 
                 let result = func
                     .as_constructor()
-                    .ok_or_else(|| self.construct_type_error("not a constructor"))
+                    .ok_or_else(|| {
+                        JsNativeError::typ()
+                            .with_message("not a constructor")
+                            .into()
+                    })
                     .and_then(|cons| cons.__construct__(&arguments, cons, self))?;
 
                 self.vm.push(result);
@@ -1986,6 +2101,13 @@ impl Context {
                     self.realm.environments.pop();
                 }
             }
+            Opcode::SetCompletionValue => {
+                let value = self.vm.pop();
+                self.vm.frame_mut().completion_value = Some(value);
+            }
+            Opcode::CopyDeclarativeEnvironment => {
+                self.realm.environments.copy_current();
+            }
             Opcode::LoopEnd => {
                 let env_num = self
                     .vm
@@ -2015,7 +2137,9 @@ impl Context {
                     .as_ref()
                     .map(PropertyDescriptor::expect_value)
                     .cloned()
-                    .ok_or_else(|| self.construct_type_error("Could not find property `next`"))?;
+                    .ok_or_else(|| {
+                        JsNativeError::typ().with_message("Could not find property `next`")
+                    })?;
 
                 self.vm.push(iterator);
                 self.vm.push(next_method);
@@ -2085,7 +2209,7 @@ impl Context {
                     values.push(result.value(self)?);
                 }
 
-                let array = Array::create_array_from_list(values, self);
+                let array = Array::create_array_from_list(values, self)?;
 
                 self.vm.push(iterator.clone());
                 self.vm.push(next_method);
@@ -2129,9 +2253,10 @@ impl Context {
                     strings.push(self.vm.pop().to_string(self)?);
                 }
                 strings.reverse();
-                let s = JsString::concat_array(
-                    &strings.iter().map(JsString::as_str).collect::<Vec<&str>>(),
-                );
+                self.resource_limits().check_string_length(
+                    &strings.iter().map(JsString::as_str).collect::<Vec<_>>(),
+                )?;
+                let s = JsString::concat_code_units(&strings.iter().collect::<Vec<_>>());
                 self.vm.push(s);
             }
             Opcode::RequireObjectCoercible => {
@@ -2158,7 +2283,7 @@ impl Context {
                     for _ in 0..rest_count {
                         args.push(self.vm.pop());
                     }
-                    let array: _ = Array::create_array_from_list(args, self);
+                    let array = Array::create_array_from_list(args, self)?;
 
                     self.vm.push(array);
                 } else {
@@ -2189,7 +2314,7 @@ impl Context {
                 GeneratorResumeKind::Normal => return Ok(ShouldExit::False),
                 GeneratorResumeKind::Throw => {
                     let received = self.vm.pop();
-                    return Err(received);
+                    return Err(JsError::from_opaque(received));
                 }
                 GeneratorResumeKind::Return => {
                     let mut finally_left = false;
@@ -2216,7 +2341,7 @@ impl Context {
                 let value = self.vm.pop();
 
                 if self.vm.frame().generator_resume_kind == GeneratorResumeKind::Throw {
-                    return Err(value);
+                    return Err(JsError::from_opaque(value));
                 }
 
                 let completion = Ok(value);
@@ -2245,7 +2370,11 @@ impl Context {
                     let (completion, r#return) = &next.completion;
                     if *r#return {
                         match completion {
-                            Ok(value) | Err(value) => self.vm.push(value),
+                            Ok(value) => self.vm.push(value),
+                            Err(error) => {
+                                let value = error.to_opaque(self);
+                                self.vm.push(value);
+                            }
                         }
                         self.vm.push(true);
                     } else {
@@ -2277,7 +2406,8 @@ impl Context {
                         let result =
                             self.call(&next_method, &iterator.clone().into(), &[received])?;
                         let result_object = result.as_object().ok_or_else(|| {
-                            self.construct_type_error("generator next method returned non-object")
+                            JsNativeError::typ()
+                                .with_message("generator next method returned non-object")
                         })?;
                         let done = result_object.get("done", self)?.to_boolean();
                         if done {
@@ -2298,9 +2428,8 @@ impl Context {
                         if let Some(throw) = throw {
                             let result = throw.call(&iterator.clone().into(), &[received], self)?;
                             let result_object = result.as_object().ok_or_else(|| {
-                                self.construct_type_error(
-                                    "generator throw method returned non-object",
-                                )
+                                JsNativeError::typ()
+                                    .with_message("generator throw method returned non-object")
                             })?;
                             let done = result_object.get("done", self)?.to_boolean();
                             if done {
@@ -2320,9 +2449,9 @@ impl Context {
                         let iterator_record =
                             IteratorRecord::new(iterator.clone(), next_method, done);
                         iterator_record.close(Ok(JsValue::Undefined), self)?;
-                        let error =
-                            self.construct_type_error("iterator does not have a throw method");
-                        return Err(error);
+                        return Err(JsNativeError::typ()
+                            .with_message("iterator does not have a throw method")
+                            .into());
                     }
                     GeneratorResumeKind::Return => {
                         let r#return = iterator.get_method("return", self)?;
@@ -2330,9 +2459,8 @@ impl Context {
                             let result =
                                 r#return.call(&iterator.clone().into(), &[received], self)?;
                             let result_object = result.as_object().ok_or_else(|| {
-                                self.construct_type_error(
-                                    "generator return method returned non-object",
-                                )
+                                JsNativeError::typ()
+                                    .with_message("generator return method returned non-object")
                             })?;
                             let done = result_object.get("done", self)?.to_boolean();
                             if done {
@@ -2390,6 +2518,7 @@ impl Context {
                             .expect("generator call frame must exist");
                         std::mem::swap(&mut context.realm.environments, environment);
                         std::mem::swap(&mut context.vm.stack, stack);
+                        AsyncGenerator::save_context(frame, environment, stack);
 
                         Ok(JsValue::undefined())
                     },
@@ -2429,6 +2558,7 @@ impl Context {
                             .expect("generator call frame must exist");
                         std::mem::swap(&mut context.realm.environments, environment);
                         std::mem::swap(&mut context.vm.stack, stack);
+                        AsyncGenerator::save_context(frame, environment, stack);
 
                         Ok(JsValue::undefined())
                     },
@@ -2443,13 +2573,13 @@ impl Context {
                 .build();
 
                 // 7. Perform PerformPromiseThen(promise, onFulfilled, onRejected).
-                promise
-                    .as_object()
-                    .expect("promise was not an object")
-                    .borrow_mut()
-                    .as_promise_mut()
-                    .expect("promise was not a promise")
-                    .perform_promise_then(&on_fulfilled.into(), &on_rejected.into(), None, self);
+                Promise::perform_promise_then(
+                    promise.as_object().expect("promise was not an object"),
+                    &on_fulfilled.into(),
+                    &on_rejected.into(),
+                    None,
+                    self,
+                );
 
                 self.vm.push(JsValue::undefined());
                 return Ok(ShouldExit::Await);
@@ -2467,6 +2597,7 @@ impl Context {
         const NUMBER_OF_COLUMNS: usize = 4;
 
         let _timer = Profiler::global().start_event("run", "vm");
+        let _active = self.enter();
 
         if self.vm.trace {
             let msg = if self.vm.frames.last().is_some() {
@@ -2495,19 +2626,7 @@ impl Context {
 
         // If the current executing function is an async function we have to resolve/reject it's promise at the end.
         // The relevant spec section is 3. in [AsyncBlockStart](https://tc39.es/ecma262/#sec-asyncblockstart).
-        let promise_capability = self
-            .realm
-            .environments
-            .get_this_environment()
-            .as_function_slots()
-            .and_then(|slots| {
-                let slots_borrow = slots.borrow();
-                let function_object = slots_borrow.function_object();
-                let function = function_object.borrow();
-                function
-                    .as_function()
-                    .and_then(|f| f.get_promise_capability().cloned())
-            });
+        let promise_capability = self.vm.frame().promise_capability.clone();
 
         while self.vm.frame().pc < self.vm.frame().code.code.len() {
             let result = if self.vm.trace {
@@ -2634,12 +2753,14 @@ impl Context {
                         self.vm.frame_mut().catch.pop();
                         self.vm.frame_mut().finally_return = FinallyReturn::Err;
                         self.vm.frame_mut().thrown = true;
+                        let e = e.to_opaque(self);
                         self.vm.push(e);
                     } else {
                         self.vm.stack.truncate(start_stack_size);
 
                         // Step 3.f in [AsyncBlockStart](https://tc39.es/ecma262/#sec-asyncblockstart).
                         if let Some(promise_capability) = promise_capability {
+                            let e = e.to_opaque(self);
                             promise_capability
                                 .reject()
                                 .call(&JsValue::undefined(), &[e.clone()], self)
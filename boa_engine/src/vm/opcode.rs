@@ -30,6 +30,13 @@ pub enum Opcode {
     /// Stack: second, first **=>** first, second
     Swap,
 
+    /// Move the value on top of the stack below the `n - 1` values under it.
+    ///
+    /// Operands: n: `u8`
+    ///
+    /// Stack: a, b, c **=>** c, a, b (with `n` = 3)
+    RotateRight,
+
     /// Push integer `0` on the stack.
     ///
     /// Operands:
@@ -138,6 +145,27 @@ pub enum Opcode {
     /// Stack: **=>** `{}`
     PushEmptyObject,
 
+    /// Push an object literal with only data properties on the stack.
+    ///
+    /// The index operand is used to index into the `object_literals` array to get the property
+    /// keys, which are assigned the values on the stack in the same order. Each value must have
+    /// been followed by a `PopOnReturnAdd`, which this instruction accounts for.
+    ///
+    /// Operands: index: `u32`
+    ///
+    /// Stack: `value_1`, ... `value_n` **=>** object
+    PushObjectLiteral,
+
+    /// Push a new `RegExp` object on the stack.
+    ///
+    /// The index operand is used to index into the `regexps` array to get the compiled regular
+    /// expression of the literal.
+    ///
+    /// Operands: index: `u32`
+    ///
+    /// Stack: **=>** regexp
+    PushRegExp,
+
     /// Get the prototype of a superclass and push it on the stack.
     ///
     /// Operands:
@@ -490,6 +518,8 @@ pub enum Opcode {
 
     /// Find a binding on the environment chain and push its value. If the binding does not exist push undefined.
     ///
+    /// Like `GetName`, this throws if the binding exists but is not initialized yet.
+    ///
     /// Operands: name_index: `u32`
     ///
     /// Stack: **=>** value
@@ -529,6 +559,28 @@ pub enum Opcode {
     /// Stack: key, object **=>** key, value
     GetPropertyByValuePush,
 
+    /// Get a property by name from the base object of a `super` reference and push it on the stack.
+    ///
+    /// The `this` value is the receiver of the property lookup.
+    ///
+    /// Like `super.name`
+    ///
+    /// Operands: `name_index`: `u32`
+    ///
+    /// Stack: this, object **=>** value
+    GetSuperPropertyByName,
+
+    /// Get a property by value from the base object of a `super` reference and push it on the stack.
+    ///
+    /// The `this` value is the receiver of the property lookup.
+    ///
+    /// Like `super[key]`
+    ///
+    /// Operands:
+    ///
+    /// Stack: this, key, object **=>** value
+    GetSuperPropertyByValue,
+
     /// Sets a property by name of an object.
     ///
     /// Like `object.name = value`
@@ -992,6 +1044,13 @@ pub enum Opcode {
     /// Stack: **=>**
     Return,
 
+    /// Set the completion value of the script or `eval` code to the value on the top of the stack.
+    ///
+    /// Operands:
+    ///
+    /// Stack: value **=>**
+    SetCompletionValue,
+
     /// Push a declarative environment.
     ///
     /// Operands: num_bindings: `u32`, compile_environments_index: `u32`
@@ -1027,6 +1086,15 @@ pub enum Opcode {
     /// Stack: **=>**
     LoopContinue,
 
+    /// Replace the current declarative environment with a copy of it.
+    ///
+    /// This creates the per-iteration environment of a `for` loop with `let` declarations.
+    ///
+    /// Operands:
+    ///
+    /// Stack: **=>**
+    CopyDeclarativeEnvironment,
+
     /// Clean up environments at the end of a loop.
     ///
     /// Operands:
@@ -1188,6 +1256,7 @@ impl Opcode {
             Self::PopIfThrown => "PopIfThrown",
             Self::Dup => "Dup",
             Self::Swap => "Swap",
+            Self::RotateRight => "RotateRight",
             Self::PushZero => "PushZero",
             Self::PushOne => "PushOne",
             Self::PushInt8 => "PushInt8",
@@ -1203,6 +1272,8 @@ impl Opcode {
             Self::PushUndefined => "PushUndefined",
             Self::PushLiteral => "PushLiteral",
             Self::PushEmptyObject => "PushEmptyObject",
+            Self::PushObjectLiteral => "PushObjectLiteral",
+            Self::PushRegExp => "PushRegExp",
             Self::PushClassPrototype => "PushClassPrototype",
             Self::SetClassPrototype => "SetClassPrototype",
             Self::SetHomeObject => "SetHomeObject",
@@ -1257,6 +1328,8 @@ impl Opcode {
             Self::GetPropertyByName => "GetPropertyByName",
             Self::GetPropertyByValue => "GetPropertyByValue",
             Self::GetPropertyByValuePush => "GetPropertyByValuePush",
+            Self::GetSuperPropertyByName => "GetSuperPropertyByName",
+            Self::GetSuperPropertyByValue => "GetSuperPropertyByValue",
             Self::SetPropertyByName => "SetPropertyByName",
             Self::DefineOwnPropertyByName => "DefineOwnPropertyByName",
             Self::DefineClassMethodByName => "DefineClassMethodByName",
@@ -1317,11 +1390,13 @@ impl Opcode {
             Self::New => "New",
             Self::NewSpread => "NewSpread",
             Self::Return => "Return",
+            Self::SetCompletionValue => "SetCompletionValue",
             Self::PushDeclarativeEnvironment => "PushDeclarativeEnvironment",
             Self::PushFunctionEnvironment => "PushFunctionEnvironment",
             Self::PopEnvironment => "PopEnvironment",
             Self::LoopStart => "LoopStart",
             Self::LoopContinue => "LoopContinue",
+            Self::CopyDeclarativeEnvironment => "CopyDeclarativeEnvironment",
             Self::LoopEnd => "LoopEnd",
             Self::ForInLoopInitIterator => "ForInLoopInitIterator",
             Self::InitIterator => "InitIterator",
@@ -1352,6 +1427,7 @@ impl Opcode {
             Self::PopIfThrown => "INST - PopIfThrown",
             Self::Dup => "INST - Dup",
             Self::Swap => "INST - Swap",
+            Self::RotateRight => "INST - RotateRight",
             Self::PushZero => "INST - PushZero",
             Self::PushOne => "INST - PushOne",
             Self::PushInt8 => "INST - PushInt8",
@@ -1367,6 +1443,8 @@ impl Opcode {
             Self::PushUndefined => "INST - PushUndefined",
             Self::PushLiteral => "INST - PushLiteral",
             Self::PushEmptyObject => "INST - PushEmptyObject",
+            Self::PushObjectLiteral => "INST - PushObjectLiteral",
+            Self::PushRegExp => "INST - PushRegExp",
             Self::PushNewArray => "INST - PushNewArray",
             Self::PushValueToArray => "INST - PushValueToArray",
             Self::PushElisionToArray => "INST - PushElisionToArray",
@@ -1418,6 +1496,8 @@ impl Opcode {
             Self::GetPropertyByName => "INST - GetPropertyByName",
             Self::GetPropertyByValue => "INST - GetPropertyByValue",
             Self::GetPropertyByValuePush => "INST - GetPropertyByValuePush",
+            Self::GetSuperPropertyByName => "INST - GetSuperPropertyByName",
+            Self::GetSuperPropertyByValue => "INST - GetSuperPropertyByValue",
             Self::SetPropertyByName => "INST - SetPropertyByName",
             Self::DefineOwnPropertyByName => "INST - DefineOwnPropertyByName",
             Self::SetPropertyByValue => "INST - SetPropertyByValue",
@@ -1460,11 +1540,13 @@ impl Opcode {
             Self::New => "INST - New",
             Self::NewSpread => "INST - NewSpread",
             Self::Return => "INST - Return",
+            Self::SetCompletionValue => "INST - SetCompletionValue",
             Self::PushDeclarativeEnvironment => "INST - PushDeclarativeEnvironment",
             Self::PushFunctionEnvironment => "INST - PushFunctionEnvironment",
             Self::PopEnvironment => "INST - PopEnvironment",
             Self::LoopStart => "INST - LoopStart",
             Self::LoopContinue => "INST - LoopContinue",
+            Self::CopyDeclarativeEnvironment => "INST - CopyDeclarativeEnvironment",
             Self::LoopEnd => "INST - LoopEnd",
             Self::ForInLoopInitIterator => "INST - ForInLoopInitIterator",
             Self::InitIterator => "INST - InitIterator",
@@ -1,4 +1,4 @@
-use crate::{exec, Context, JsValue};
+use crate::{exec, Context, JsError, JsValue};
 
 #[test]
 fn typeof_string() {
@@ -43,7 +43,10 @@ fn try_catch_finally_from_init() {
         }
     "#;
 
-    assert_eq!(Context::default().eval(source.as_bytes()), Err("h".into()));
+    assert_eq!(
+        Context::default().eval(source.as_bytes()),
+        Err(JsError::from_opaque("h".into()))
+    );
 }
 
 #[test]
@@ -182,3 +185,235 @@ fn get_reference_by_super() {
         Ok(JsValue::from("ab"))
     )
 }
+
+#[test]
+fn super_property_receiver_is_this() {
+    let source = r#"
+        class A {
+            get who() { return this.tag; }
+            static get staticWho() { return this.name; }
+        }
+        class B extends A {
+            constructor() { super(); this.tag = "b"; }
+            get who() { return "B:" + super.who; }
+            static get staticWho() { return "B:" + super.staticWho; }
+        }
+        new B().who + " " + B.staticWho
+    "#;
+
+    assert_eq!(
+        Context::default().eval(source.as_bytes()),
+        Ok(JsValue::from("B:b B:B"))
+    );
+}
+
+#[test]
+fn class_static_inheritance() {
+    let mut context = Context::default();
+    let init = r#"
+        class A {
+            static helper() { return "helper:" + this.name; }
+            static m() { return "A"; }
+            static get value() { return "get:" + this.name; }
+            static set value(v) { this.stored = v + "@" + this.name; }
+            f() { return "A.f"; }
+        }
+        class B extends A {
+            static m() { return "B>" + super.m(); }
+            static field = super.m();
+            static #private = super.m();
+            static get private() { return B.#private; }
+            inst = super.f();
+        }
+        class C extends B {
+            static m() { return "C>" + super.m(); }
+        }
+    "#;
+    context.eval(init).unwrap();
+    assert_eq!(
+        context.eval("Object.getPrototypeOf(B) === A"),
+        Ok(JsValue::from(true))
+    );
+    assert_eq!(context.eval("B.helper()"), Ok(JsValue::from("helper:B")));
+    assert_eq!(context.eval("C.m()"), Ok(JsValue::from("C>B>A")));
+    assert_eq!(context.eval("C.value"), Ok(JsValue::from("get:C")));
+    assert_eq!(
+        context.eval("C.value = 1; C.stored + ' ' + B.stored"),
+        Ok(JsValue::from("1@C undefined"))
+    );
+    assert_eq!(context.eval("B.field"), Ok(JsValue::from("A")));
+    assert_eq!(context.eval("B.private"), Ok(JsValue::from("A")));
+    assert_eq!(context.eval("new C().inst"), Ok(JsValue::from("A.f")));
+}
+
+#[test]
+fn class_extends_null() {
+    let mut context = Context::default();
+    context.eval("class N extends null {}").unwrap();
+    assert_eq!(
+        context.eval("Object.getPrototypeOf(N) === Function.prototype"),
+        Ok(JsValue::from(true))
+    );
+    assert_eq!(
+        context.eval("Object.getPrototypeOf(N.prototype)"),
+        Ok(JsValue::null())
+    );
+    assert!(context.eval("new N()").is_err());
+}
+
+#[test]
+fn uncaught_error_in_loop_restores_environments() {
+    let mut context = Context::default();
+    context.eval("let n = 1;").unwrap();
+    assert!(context.eval("while (true) { throw 1 }").is_err());
+    assert_eq!(
+        context.eval("try { throw 2 } catch (e) { e + n }"),
+        Ok(JsValue::from(3))
+    );
+}
+
+#[test]
+fn async_function_call_returns_new_promise() {
+    let mut context = Context::default();
+    let init = r"
+        async function f(x) { await null; return x; }
+        var p1 = f(1);
+        var p2 = f(2);
+        var results = [];
+        p1.then((x) => results.push(x));
+        p2.then((x) => results.push(x));
+    ";
+    context.eval(init).unwrap();
+    assert_eq!(context.eval("p1 === p2"), Ok(JsValue::from(false)));
+    assert_eq!(context.eval("results.join()"), Ok(JsValue::from("1,2")));
+}
+
+#[test]
+fn async_generator_yield_after_await() {
+    let mut context = Context::default();
+    let init = r"
+        async function* gen() { yield 1; await null; yield 2; await null; yield 3; }
+        var g = gen();
+        var results = [];
+        function next() {
+            return g.next().then((r) => { results.push(r.value, r.done); if (!r.done) return next(); });
+        }
+        next();
+    ";
+    context.eval(init).unwrap();
+    assert_eq!(
+        context.eval("results.join()"),
+        Ok(JsValue::from("1,false,2,false,3,false,,true"))
+    );
+}
+
+#[test]
+fn primitive_property_access_receiver() {
+    let mut context = Context::default();
+    let init = r#"
+        Object.defineProperty(String.prototype, "strictThis", {
+            get() { "use strict"; return typeof this; }
+        });
+        Object.defineProperty(String.prototype, "sloppyThis", {
+            get() { return typeof this; }
+        });
+        Number.prototype.identity = function () { "use strict"; return this; };
+    "#;
+    context.eval(init).unwrap();
+    assert_eq!(
+        context.eval("'abc'.strictThis"),
+        Ok(JsValue::from("string"))
+    );
+    assert_eq!(
+        context.eval("'abc'.sloppyThis"),
+        Ok(JsValue::from("object"))
+    );
+    assert_eq!(
+        context.eval("(5).identity() === 5"),
+        Ok(JsValue::from(true))
+    );
+    assert_eq!(context.eval("'abc'.length"), Ok(JsValue::from(3)));
+    assert_eq!(context.eval("'abc'[1]"), Ok(JsValue::from("b")));
+    assert_eq!(context.eval("'abc'[3]"), Ok(JsValue::undefined()));
+    assert_eq!(context.eval("true.toString()"), Ok(JsValue::from("true")));
+    assert!(context.eval("null.length").is_err());
+}
+
+#[test]
+fn literals_create_distinct_objects() {
+    let mut context = Context::default();
+    let init = r"
+        function regexp() { return /a+/g; }
+        function object() { return { a: 1, b: 'two' }; }
+        function array() { return [1, 2]; }
+    ";
+    context.eval(init).unwrap();
+    for f in ["regexp", "object", "array"] {
+        assert_eq!(
+            context.eval(format!("{f}() !== {f}()")),
+            Ok(JsValue::from(true))
+        );
+    }
+    assert_eq!(
+        context.eval("var o = object(); o.a = 5; object().a"),
+        Ok(JsValue::from(1))
+    );
+}
+
+#[test]
+fn regexp_literal_independent_last_index() {
+    let mut context = Context::default();
+    let init = r"
+        function regexp() { return /a/g; }
+        var r1 = regexp();
+        r1.exec('aaa');
+        r1.exec('aaa');
+        var r2 = regexp();
+    ";
+    context.eval(init).unwrap();
+    assert_eq!(context.eval("r1.lastIndex"), Ok(JsValue::from(2)));
+    assert_eq!(context.eval("r2.lastIndex"), Ok(JsValue::from(0)));
+    assert_eq!(context.eval("r2.exec('aaa').index"), Ok(JsValue::from(0)));
+}
+
+#[test]
+fn regexp_literal_ignores_global_binding() {
+    let mut context = Context::default();
+    let init = r"
+        var OriginalRegExp = RegExp;
+        RegExp = function () { throw 'shadowed'; };
+        var r = /[0-9]+/;
+    ";
+    context.eval(init).unwrap();
+    assert_eq!(
+        context.eval("r instanceof OriginalRegExp && r.test('42')"),
+        Ok(JsValue::from(true))
+    );
+}
+
+#[test]
+fn regexp_literal_early_error() {
+    let mut context = Context::default();
+    assert!(context.eval("function f() { return /(/; }").is_err());
+    assert_eq!(context.eval("typeof f"), Ok(JsValue::from("undefined")));
+    assert_eq!(
+        context.eval("try { eval('/a/gg') } catch (e) { e instanceof SyntaxError }"),
+        Ok(JsValue::from(true))
+    );
+}
+
+#[test]
+fn try_catch_in_object_literal_value() {
+    // see explanation on `try_catch_finally_from_init`
+    let source = r#"
+        var result;
+        try {
+            result = { a: 1, b: (() => { throw "h"; })() };
+        } catch (x) {
+            result = x;
+        }
+        result
+    "#;
+
+    assert_eq!(Context::default().eval(source), Ok("h".into()));
+}
@@ -0,0 +1,105 @@
+// This example shows how to run scripts on a pool of worker threads.
+//
+// A `Context` cannot be sent to another thread, since its objects are managed by a garbage
+// collector that is local to the thread that created them. Instead, every worker thread creates
+// its own `Context`, and only `Send` data is shared between threads. Scripts are compiled once
+// into a `CompiledScript`, which is `Send + Sync`, and every worker executes the same compiled
+// code in its own `Context`.
+
+use boa_engine::{context::CompiledScript, Context};
+use std::{sync::mpsc, thread};
+
+/// A script to run, and the channel where its result must be sent.
+struct Job {
+    script: CompiledScript,
+    result: mpsc::Sender<Result<String, String>>,
+}
+
+/// A pool of worker threads, each one owning a `Context`.
+struct ContextPool {
+    workers: Vec<(mpsc::Sender<Job>, thread::JoinHandle<()>)>,
+    next: usize,
+}
+
+impl ContextPool {
+    /// Starts `size` worker threads, running `setup` on the `Context` of each of them.
+    fn new(size: usize, setup: &CompiledScript) -> Self {
+        let workers = (0..size)
+            .map(|_| {
+                let (sender, receiver) = mpsc::channel::<Job>();
+                let setup = setup.clone();
+                let handle = thread::spawn(move || {
+                    // The context is created inside the worker thread and never leaves it.
+                    let mut context = Context::default();
+                    setup
+                        .execute(&mut context)
+                        .expect("setup script should not fail");
+
+                    for job in receiver {
+                        let result = job
+                            .script
+                            .execute(&mut context)
+                            .and_then(|value| value.to_string(&mut context))
+                            .map(|value| value.to_string())
+                            .map_err(|error| error.to_string());
+
+                        // The caller may have stopped waiting for the result.
+                        let _ = job.result.send(result);
+                    }
+                });
+                (sender, handle)
+            })
+            .collect();
+
+        Self { workers, next: 0 }
+    }
+
+    /// Runs a script on the next worker, returning a channel that receives its result.
+    fn run(&mut self, script: CompiledScript) -> mpsc::Receiver<Result<String, String>> {
+        let (result, receiver) = mpsc::channel();
+        let (worker, _) = &self.workers[self.next];
+        self.next = (self.next + 1) % self.workers.len();
+        worker
+            .send(Job { script, result })
+            .expect("worker thread should be running");
+        receiver
+    }
+
+    /// Stops all the workers, waiting for their pending jobs to finish.
+    fn join(self) {
+        for (sender, handle) in self.workers {
+            drop(sender);
+            handle.join().expect("worker thread should not panic");
+        }
+    }
+}
+
+fn main() {
+    let setup = CompiledScript::compile(
+        r#"
+        function fibonacci(n) {
+            return n < 2 ? n : fibonacci(n - 1) + fibonacci(n - 2);
+        }
+    "#,
+    )
+    .expect("setup script should compile");
+
+    let mut pool = ContextPool::new(4, &setup);
+
+    let results: Vec<_> = (10..20)
+        .map(|n| {
+            let script =
+                CompiledScript::compile(format!("fibonacci({n})")).expect("script should compile");
+            pool.run(script)
+        })
+        .collect();
+
+    for (n, result) in (10..20).zip(results) {
+        match result.recv().expect("worker should send a result") {
+            Ok(value) => println!("fibonacci({n}) = {value}"),
+            Err(error) => eprintln!("Uncaught {error}"),
+        }
+    }
+
+    pool.join();
+}
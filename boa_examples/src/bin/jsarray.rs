@@ -1,6 +1,7 @@
 // This example shows how to manipulate a Javascript array using Rust code.
 
 use boa_engine::{
+    builtins::JsArgs,
     object::{FunctionBuilder, JsArray},
     Context, JsResult, JsValue,
 };
@@ -60,15 +61,12 @@ fn main() -> JsResult<()> {
     assert_eq!(joined_array, "14::false::false::false::10");
 
     let filter_callback = FunctionBuilder::native(context, |_this, args, _context| {
-        Ok(args.get(0).cloned().unwrap_or_default().is_number().into())
+        Ok(args.get_or_undefined(0).is_number().into())
     })
     .build();
 
     let map_callback = FunctionBuilder::native(context, |_this, args, context| {
-        args.get(0)
-            .cloned()
-            .unwrap_or_default()
-            .pow(&JsValue::new(2), context)
+        args.get_or_undefined(0).pow(&JsValue::new(2), context)
     })
     .build();
 
@@ -88,10 +86,8 @@ fn main() -> JsResult<()> {
     assert_eq!(chained_array.join(None, context)?, "196,1,2,3");
 
     let reduce_callback = FunctionBuilder::native(context, |_this, args, context| {
-        let accumulator = args.get(0).cloned().unwrap_or_default();
-        let value = args.get(1).cloned().unwrap_or_default();
-
-        accumulator.add(&value, context)
+        args.get_or_undefined(0)
+            .add(args.get_or_undefined(1), context)
     })
     .build();
 
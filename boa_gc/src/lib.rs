@@ -1,6 +1,165 @@
 //! Garbage collector for the Boa JavaScript engine.
+//!
+//! # Tracing user types
+//!
+//! Types that store garbage collected values, like the values of the engine, must implement
+//! [`Trace`] and [`Finalize`] so that the collector can find the values they hold. Both traits
+//! can be derived for structs and enums, including generic ones:
+//!
+//! ```
+//! use boa_gc::{Finalize, Gc, Trace};
+//!
+//! #[derive(Trace, Finalize)]
+//! struct Pair<T: Trace + 'static> {
+//!     first: T,
+//!     second: Option<Gc<T>>,
+//! }
+//!
+//! #[derive(Trace, Finalize)]
+//! enum Tree {
+//!     Leaf(Gc<u32>),
+//!     Branch(Vec<Tree>),
+//! }
+//!
+//! let pair = Pair { first: 1, second: Some(Gc::new(2)) };
+//! let tree = Tree::Branch(vec![Tree::Leaf(Gc::new(1))]);
+//! ```
+//!
+//! Fields whose type doesn't implement [`Trace`] can be skipped with `#[unsafe_ignore_trace]`.
+//! This is only sound if the field doesn't contain any garbage collected values, since those
+//! would be freed while still being referenced:
+//!
+//! ```
+//! use boa_gc::{Finalize, Gc, Trace};
+//! use std::time::Instant;
+//!
+//! #[derive(Trace, Finalize)]
+//! struct Timer {
+//!     callback: Gc<String>,
+//!     #[unsafe_ignore_trace]
+//!     started: Instant,
+//! }
+//! ```
+//!
+//! Deriving [`Trace`] fails to compile if a field doesn't implement it, instead of silently
+//! leaving the field untraced:
+//!
+//! ```compile_fail
+//! use boa_gc::{Finalize, Trace};
+//! use std::time::Instant;
+//!
+//! #[derive(Trace, Finalize)]
+//! struct Timer {
+//!     started: Instant,
+//! }
+//! ```
+//!
+//! The derived [`Trace`] implementation calls [`Finalize::finalize`] when the value is
+//! dropped, so a type deriving it cannot implement [`Drop`] itself:
+//!
+//! ```compile_fail
+//! use boa_gc::{Finalize, Gc, Trace};
+//!
+//! #[derive(Trace, Finalize)]
+//! struct Resource {
+//!     value: Gc<u32>,
+//! }
+//!
+//! impl Drop for Resource {
+//!     fn drop(&mut self) {}
+//! }
+//! ```
+//!
+//! Such types, and types that store values in containers that don't implement [`Trace`], must
+//! implement it manually with [`custom_trace!`] or [`unsafe_empty_trace!`].
+//!
+//! # Deeply nested values
+//!
+//! Tracing a value recursively traces every value reachable from it, so a long chain of
+//! references, like a linked list with many nodes, can overflow the stack while collecting
+//! garbage. Types that can form such chains should trace the references in their manual
+//! [`Trace`] implementations with [`trace_with_explicit_stack`] instead.
 
 pub use gc::{
     custom_trace, finalizer_safe, force_collect, unsafe_empty_trace, Finalize, Gc, GcCell as Cell,
     GcCellRef as Ref, GcCellRefMut as RefMut, Trace,
 };
+
+use std::{
+    cell::{Cell as StdCell, RefCell},
+    mem::size_of,
+    ptr::NonNull,
+};
+
+/// The layout of the heap allocation of a [`Gc`], which is private to the collector.
+#[allow(dead_code)]
+#[repr(C)]
+struct GcBox<T> {
+    header: GcBoxHeader,
+    data: T,
+}
+
+/// The header of a [`GcBox`], used by the collector to track the value.
+#[allow(dead_code)]
+struct GcBoxHeader {
+    roots: StdCell<usize>,
+    next: Option<NonNull<dyn Trace>>,
+    marked: StdCell<bool>,
+}
+
+/// Returns the number of bytes allocated in the garbage collected heap by a `Gc<T>`.
+///
+/// This includes the header used by the collector to track the value.
+#[inline]
+pub const fn allocation_size<T>() -> usize {
+    size_of::<GcBox<T>>()
+}
+
+/// The number of nested calls to [`trace_with_explicit_stack`] after which the traced values are
+/// deferred to the explicit stack.
+const MAX_TRACE_DEPTH: usize = 128;
+
+thread_local! {
+    static TRACE_DEPTH: StdCell<usize> = const { StdCell::new(0) };
+    static DEFERRED_TRACES: RefCell<Vec<*const dyn Trace>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Traces the given value like [`Trace::trace`], but without growing the stack for every
+/// nested call.
+///
+/// Once the calls are nested too deeply, the value is pushed to an explicit stack instead of
+/// being traced, and the outermost call traces the values in that stack before returning.
+///
+/// # Safety
+///
+/// This has the same requirements as [`Trace::trace`], so it must only be called from the
+/// [`Trace::trace`] implementation of a value that is being traced by the collector.
+pub unsafe fn trace_with_explicit_stack<T: Trace + 'static>(value: &T) {
+    let depth = TRACE_DEPTH.with(StdCell::get);
+    if depth >= MAX_TRACE_DEPTH {
+        // The collector doesn't mutate or free any value while tracing, so the value is still
+        // valid when the outermost call traces it.
+        let value: &dyn Trace = value;
+        let deferred = DEFERRED_TRACES.try_with(|deferred| deferred.borrow_mut().push(value));
+
+        // The collector also runs while the thread exits, and the explicit stack may already be
+        // destroyed by then, in which case the value is traced recursively.
+        if deferred.is_err() {
+            value.trace();
+        }
+        return;
+    }
+
+    TRACE_DEPTH.with(|d| d.set(depth + 1));
+    value.trace();
+    if depth == 0 {
+        while let Some(value) = DEFERRED_TRACES
+            .try_with(|deferred| deferred.borrow_mut().pop())
+            .ok()
+            .flatten()
+        {
+            (*value).trace();
+        }
+    }
+    TRACE_DEPTH.with(|d| d.set(depth));
+}
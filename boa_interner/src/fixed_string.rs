@@ -1,4 +1,5 @@
 use crate::interned_str::InternedStr;
+use alloc::string::String;
 
 #[derive(Debug, Default)]
 pub(super) struct FixedString {
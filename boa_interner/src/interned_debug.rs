@@ -0,0 +1,52 @@
+//! Formatting of values with their `Debug` implementation, resolving the symbols in them.
+
+use crate::{Interner, Sym};
+use core::{fmt, ptr};
+use std::{cell::Cell, string::String};
+
+std::thread_local! {
+    /// The interner of the [`InternedDebug`] that the current thread is formatting, if any.
+    static INTERNER: Cell<*const Interner> = const { Cell::new(ptr::null()) };
+}
+
+/// Formats a value with its [`Debug`](fmt::Debug) implementation, showing every [`Sym`] in it as
+/// the string it represents instead of its index.
+///
+/// This is created by [`Interner::debug`].
+pub struct InternedDebug<'a, T: ?Sized> {
+    interner: &'a Interner,
+    value: &'a T,
+}
+
+impl<'a, T: ?Sized> InternedDebug<'a, T> {
+    pub(crate) fn new(interner: &'a Interner, value: &'a T) -> Self {
+        Self { interner, value }
+    }
+}
+
+impl<T: fmt::Debug + ?Sized> fmt::Debug for InternedDebug<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        /// Restores the interner of an outer `InternedDebug`, even if formatting panics.
+        struct Restore(*const Interner);
+
+        impl Drop for Restore {
+            fn drop(&mut self) {
+                INTERNER.with(|interner| interner.set(self.0));
+            }
+        }
+
+        let _restore = Restore(INTERNER.with(|interner| interner.replace(self.interner)));
+        self.value.fmt(f)
+    }
+}
+
+/// Resolves `sym` with the interner of the [`InternedDebug`] that the current thread is
+/// formatting, if any.
+pub(crate) fn resolve(sym: Sym) -> Option<String> {
+    let interner = INTERNER.try_with(Cell::get).ok()?;
+
+    // SAFETY: The pointer is only set while `InternedDebug::fmt` runs, and the interner is
+    // borrowed by the `InternedDebug` for longer than that.
+    let interner = unsafe { interner.as_ref() }?;
+    interner.resolve(sym).map(String::from)
+}
@@ -1,4 +1,4 @@
-use std::{borrow::Borrow, ptr::NonNull};
+use core::{borrow::Borrow, ptr::NonNull};
 
 /// Wrapper for an interned str pointer, required to
 /// quickly check using a hash if a string is inside an [`Interner`][`super::Interner`].
@@ -41,8 +41,8 @@ impl InternedStr {
     }
 }
 
-impl std::hash::Hash for InternedStr {
-    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+impl core::hash::Hash for InternedStr {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
         // SAFETY: The caller must verify the invariants
         // specified in the struct definition.
         unsafe {
@@ -7,7 +7,11 @@
 //! to `usize`, and also it's easier to store, since instead of a heap-allocated string, you only
 //! need to store a `usize`. This reduces memory consumption and improves performance in the
 //! compiler.
+//!
+//! The interner only needs the `alloc` crate, so it can be used in `no_std` environments by
+//! disabling the default `std` feature.
 
+#![cfg_attr(not(feature = "std"), no_std)]
 #![doc(
     html_logo_url = "https://raw.githubusercontent.com/boa-dev/boa/main/assets/logo.svg",
     html_favicon_url = "https://raw.githubusercontent.com/boa-dev/boa/main/assets/logo.svg"
@@ -70,21 +74,38 @@
     rustdoc::missing_doc_code_examples
 )]
 
+extern crate alloc;
 extern crate static_assertions as sa;
 
 mod fixed_string;
+#[cfg(feature = "std")]
+mod interned_debug;
 mod interned_str;
 mod sym;
 #[cfg(test)]
 mod tests;
 
 use fixed_string::FixedString;
+#[cfg(feature = "std")]
+pub use interned_debug::InternedDebug;
 pub use sym::*;
 
-use std::fmt::{Debug, Display};
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{
+    fmt::{Debug, Display},
+    hash::BuildHasherDefault,
+    mem::size_of,
+};
 
+use hashbrown::HashMap;
 use interned_str::InternedStr;
-use rustc_hash::FxHashMap;
+use rustc_hash::FxHasher;
+
+type FxHashMap<K, V> = HashMap<K, V, BuildHasherDefault<FxHasher>>;
 
 /// The string interner for Boa.
 #[derive(Debug, Default)]
@@ -105,6 +126,11 @@ pub struct Interner {
     spans: Vec<InternedStr>,
     head: FixedString,
     full: Vec<FixedString>,
+
+    // Strings with unpaired surrogates are stored in `spans` with U+FFFD in place of each
+    // surrogate, so their code units are kept here, and they are never added to `symbols`.
+    utf16: FxHashMap<Box<[u16]>, Sym>,
+    utf16_symbols: FxHashMap<Sym, Box<[u16]>>,
 }
 
 impl Interner {
@@ -122,6 +148,8 @@ impl Interner {
             spans: Vec::with_capacity(capacity),
             head: FixedString::new(capacity),
             full: Vec::new(),
+            utf16: FxHashMap::default(),
+            utf16_symbols: FxHashMap::default(),
         }
     }
 
@@ -131,6 +159,24 @@ impl Interner {
         COMMON_STRINGS.len() + self.spans.len()
     }
 
+    /// Returns the number of bytes allocated by the [`Interner`] to store its strings.
+    ///
+    /// This doesn't include the common strings, since those are stored statically.
+    pub fn allocated_bytes(&self) -> usize {
+        let strings =
+            self.head.capacity() + self.full.iter().map(FixedString::capacity).sum::<usize>();
+        let spans = self.spans.capacity() * size_of::<InternedStr>();
+        let symbols = self.symbols.capacity() * size_of::<(InternedStr, Sym)>();
+        let utf16 = self
+            .utf16_symbols
+            .values()
+            .map(|units| units.len() * size_of::<u16>() * 2)
+            .sum::<usize>()
+            + self.utf16.capacity() * size_of::<(Box<[u16]>, Sym)>()
+            + self.utf16_symbols.capacity() * size_of::<(Sym, Box<[u16]>)>();
+        strings + spans + symbols + utf16
+    }
+
     /// Returns `true` if the [`Interner`] contains no interned strings.
     #[inline]
     pub fn is_empty(&self) -> bool {
@@ -164,6 +210,48 @@ impl Interner {
             return sym;
         }
 
+        // SAFETY: `store` returns a pointer to the internal memory of `head`, which is alive
+        // through the whole life of `Interner`.
+        unsafe {
+            let interned_str = self.store(string);
+            self.generate_symbol(interned_str)
+        }
+    }
+
+    /// Interns the string with the given UTF-16 code units.
+    ///
+    /// Unpaired surrogates are kept: [`Interner::resolve`] replaces them with U+FFFD, and
+    /// [`Interner::resolve_utf16`] returns the original code units.
+    ///
+    /// # Panics
+    ///
+    /// If the interner already interns the maximum number of strings possible by the chosen symbol type.
+    pub fn get_or_intern_utf16(&mut self, units: &[u16]) -> Sym {
+        if let Ok(string) = String::from_utf16(units) {
+            return self.get_or_intern(string);
+        }
+        if let Some(sym) = self.utf16.get(units) {
+            return *sym;
+        }
+
+        let string = String::from_utf16_lossy(units);
+        // SAFETY: `store` returns a pointer to the internal memory of `head`, which is alive
+        // through the whole life of `Interner`.
+        let sym = unsafe {
+            let interned_str = self.store(&string);
+            self.generate_unlisted_symbol(interned_str)
+        };
+        self.utf16.insert(units.into(), sym);
+        self.utf16_symbols.insert(sym, units.into());
+        sym
+    }
+
+    /// Stores a copy of the given string in the interner, without generating a symbol for it.
+    ///
+    /// # Safety
+    ///
+    /// The returned [`InternedStr`] is only valid while the interner is alive.
+    unsafe fn store(&mut self, string: &str) -> InternedStr {
         // SAFETY:
         //
         // Firstly, this interner works on the assumption that the allocated
@@ -190,12 +278,12 @@ impl Interner {
         // of `head` is not deallocated until the whole `Interner` deallocates,
         // which we can do by moving it inside the `Interner` itself, specifically
         // on the `full` vector, where every other old `head` also lives.
-        let interned_str = unsafe {
+        unsafe {
             self.head.push(string).unwrap_or_else(|| {
                 let new_cap =
                     (usize::max(self.head.capacity(), string.len()) + 1).next_power_of_two();
                 let new_head = FixedString::new(new_cap);
-                let old_head = std::mem::replace(&mut self.head, new_head);
+                let old_head = core::mem::replace(&mut self.head, new_head);
 
                 // If the user creates an `Interner`
                 // with `Interner::with_capacity(BIG_NUMBER)` and
@@ -208,12 +296,7 @@ impl Interner {
                 }
                 self.head.push_unchecked(string)
             })
-        };
-
-        // SAFETY: We are obtaining a pointer to the internal memory of
-        // `head`, which is alive through the whole life of `Interner`, so
-        // this is safe.
-        unsafe { self.generate_symbol(interned_str) }
+        }
     }
 
     /// Interns the given `'static` string.
@@ -250,6 +333,15 @@ impl Interner {
         })
     }
 
+    /// Returns the UTF-16 code units of the string for the given symbol, if the string has
+    /// unpaired surrogates.
+    ///
+    /// Those strings can only be interned by [`Interner::get_or_intern_utf16`].
+    #[inline]
+    pub fn resolve_utf16(&self, symbol: Sym) -> Option<&[u16]> {
+        self.utf16_symbols.get(&symbol).map(AsRef::as_ref)
+    }
+
     /// Returns the string for the given symbol.
     ///
     /// # Panics
@@ -260,6 +352,22 @@ impl Interner {
         self.resolve(symbol).expect("string disappeared")
     }
 
+    /// Formats `value` with its [`Debug`] implementation, showing every [`Sym`] in it as the
+    /// string it represents.
+    ///
+    /// ```
+    /// use boa_interner::Interner;
+    ///
+    /// let mut interner = Interner::default();
+    /// let sym = interner.get_or_intern("answer");
+    /// assert_eq!(format!("{:?}", interner.debug(&[sym])), r#"[Sym("answer")]"#);
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn debug<'a, T: Debug + ?Sized>(&'a self, value: &'a T) -> InternedDebug<'a, T> {
+        InternedDebug::new(self, value)
+    }
+
     /// Gets the symbol of the common string if one of them
     fn get_common(string: &str) -> Option<Sym> {
         COMMON_STRINGS.get_index(string).map(|idx|
@@ -280,11 +388,25 @@ impl Interner {
     /// memory inside `head` and that it won't be invalidated
     /// by allocations and deallocations.
     unsafe fn generate_symbol(&mut self, string: InternedStr) -> Sym {
-        let next = Sym::new(self.len() + 1).expect("cannot get interner symbol: integer overflow");
-        self.spans.push(string.clone());
+        // SAFETY: The caller ensures `string` is valid.
+        let next = unsafe { self.generate_unlisted_symbol(string.clone()) };
         self.symbols.insert(string, next);
         next
     }
+
+    /// Generates a new symbol for the provided [`str`] pointer, without making it the symbol
+    /// that [`Interner::get`] returns for that string.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure `string` points to a valid
+    /// memory inside `head` and that it won't be invalidated
+    /// by allocations and deallocations.
+    unsafe fn generate_unlisted_symbol(&mut self, string: InternedStr) -> Sym {
+        let next = Sym::new(self.len() + 1).expect("cannot get interner symbol: integer overflow");
+        self.spans.push(string);
+        next
+    }
 }
 
 /// Converts a given element to a string using an interner.
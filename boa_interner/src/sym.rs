@@ -1,4 +1,4 @@
-use std::num::NonZeroUsize;
+use core::{fmt, num::NonZeroUsize};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -7,7 +7,7 @@ use serde::{Deserialize, Serialize};
 ///
 /// This symbol type is internally a `NonZeroUsize`, which makes it pointer-width in size and it's
 /// optimized so that it can occupy 1 pointer width even in an `Option` type.
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "serde", serde(transparent))]
 #[allow(clippy::unsafe_derive_deserialize)]
@@ -126,6 +126,18 @@ impl Sym {
     }
 }
 
+impl fmt::Debug for Sym {
+    /// Formats the symbol as the string it represents while it's formatted by
+    /// [`Interner::debug`](crate::Interner::debug), and as its index otherwise.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        #[cfg(feature = "std")]
+        if let Some(string) = crate::interned_debug::resolve(*self) {
+            return f.debug_tuple("Sym").field(&string).finish();
+        }
+        f.debug_struct("Sym").field("value", &self.value).finish()
+    }
+}
+
 /// Ordered set of commonly used static strings.
 ///
 /// # Note
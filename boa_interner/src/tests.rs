@@ -56,3 +56,74 @@ fn check_static_resolve() {
         assert_eq!(sym, new_sym);
     }
 }
+
+#[test]
+fn check_allocated_bytes() {
+    let mut interner = Interner::default();
+    assert_eq!(interner.allocated_bytes(), 0);
+
+    interner.get_or_intern("arguments");
+    assert_eq!(interner.allocated_bytes(), 0);
+
+    interner.get_or_intern("my test string");
+    let allocated = interner.allocated_bytes();
+    assert!(allocated >= "my test string".len());
+
+    interner.get_or_intern("a".repeat(1000));
+    assert!(interner.allocated_bytes() >= allocated + 1000);
+}
+
+#[test]
+fn check_utf16() {
+    let mut interner = Interner::default();
+
+    // Valid UTF-16 is interned like the equivalent string.
+    let hello = "hello".encode_utf16().collect::<Vec<_>>();
+    assert_eq!(
+        interner.get_or_intern_utf16(&hello),
+        interner.get_or_intern("hello")
+    );
+    let hello = interner.get_or_intern("hello");
+    assert_eq!(interner.resolve_utf16(hello), None);
+
+    // Unpaired surrogates are kept, and each string keeps its own symbol.
+    let high = [0x61, 0xD800];
+    let low = [0x61, 0xDC00];
+    let high_sym = interner.get_or_intern_utf16(&high);
+    let low_sym = interner.get_or_intern_utf16(&low);
+    assert_ne!(high_sym, low_sym);
+    assert_eq!(interner.get_or_intern_utf16(&high), high_sym);
+    assert_eq!(interner.resolve_expect(high_sym), "a\u{FFFD}");
+    assert_eq!(interner.resolve_utf16(high_sym), Some(&high[..]));
+    assert_eq!(interner.resolve_utf16(low_sym), Some(&low[..]));
+
+    // The replacement character itself is a different string.
+    let replaced = interner.get_or_intern("a\u{FFFD}");
+    assert_ne!(replaced, high_sym);
+    assert_ne!(replaced, low_sym);
+    assert_eq!(interner.resolve_utf16(replaced), None);
+}
+
+#[test]
+fn debug_resolves_symbols() {
+    let mut interner = Interner::default();
+    let name = interner.get_or_intern("name");
+    let value = (name, [Some(interner.get_or_intern("child")), None]);
+
+    assert_eq!(
+        format!("{:?}", interner.debug(&value)),
+        r#"(Sym("name"), [Some(Sym("child")), None])"#
+    );
+
+    // Outside of `Interner::debug`, symbols are shown by their index.
+    let index = format!("Sym {{ value: {} }}", name.get());
+    assert_eq!(format!("{name:?}"), index);
+
+    // A nested formatter uses its own interner, and the outer one is restored after it.
+    let other = Interner::default();
+    assert_eq!(
+        format!("{:?}", interner.debug(&(other.debug(&name), name))),
+        format!(r#"({index}, Sym("name"))"#)
+    );
+    assert_eq!(format!("{name:?}"), index);
+}
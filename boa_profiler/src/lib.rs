@@ -5,12 +5,17 @@ use measureme::{EventId, Profiler as MeasuremeProfiler, TimingGuard};
 #[cfg(feature = "profiler")]
 use once_cell::sync::OnceCell;
 use std::fmt::{self, Debug};
+use std::io::{self, Write};
 #[cfg(feature = "profiler")]
 use std::{
     path::Path,
     thread::{current, ThreadId},
 };
 
+mod runtime;
+
+pub use runtime::{EventGuard, Report, ReportEntry};
+
 #[cfg(feature = "profiler")]
 pub struct Profiler {
     profiler: MeasuremeProfiler,
@@ -42,6 +47,35 @@ impl Profiler {
         unsafe { INSTANCE.get_or_init(Self::default) }
     }
 
+    /// Always returns `true`, since `measureme` records all events.
+    pub fn is_enabled(&self) -> bool {
+        true
+    }
+
+    /// Does nothing, since `measureme` records all events.
+    pub fn set_enabled(&self, _enabled: bool) {}
+
+    /// Does nothing, since the events are recorded by `measureme`.
+    pub fn clear(&self) {}
+
+    /// Writes an empty trace, since the events are recorded by `measureme`.
+    ///
+    /// Use `crox` to convert the `measureme` trace to the Chrome trace event format.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to `writer` fails.
+    pub fn write_chrome_trace<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        runtime::write_chrome_trace(writer)
+    }
+
+    /// Returns an empty report, since the events are recorded by `measureme`.
+    ///
+    /// Use `summarize` to get a summary of the `measureme` trace.
+    pub fn report(&self) -> Report {
+        runtime::report()
+    }
+
     pub fn drop(&self) {
         // In order to drop the INSTANCE we need to get ownership of it, which isn't possible on a static unless you make it a mutable static
         // mutating statics is unsafe, so we need to wrap it as so.
@@ -69,17 +103,62 @@ impl Debug for Profiler {
     }
 }
 
+/// Profiler that records events only while it is enabled at runtime.
+///
+/// When disabled, starting an event costs a single branch.
 #[cfg(not(feature = "profiler"))]
 pub struct Profiler;
 
-#[allow(clippy::unused_unit, clippy::unused_self)]
+#[allow(clippy::unused_self)]
 #[cfg(not(feature = "profiler"))]
 impl Profiler {
-    pub fn start_event(&self, _label: &str, _category: &str) -> () {}
+    /// Starts an event, which ends when the returned guard is dropped.
+    #[inline]
+    pub fn start_event(&self, label: &str, category: &str) -> EventGuard {
+        runtime::start_event(label, category)
+    }
 
     pub fn drop(&self) {}
 
     pub fn global() -> Self {
         Self
     }
+
+    /// Returns `true` if the profiler is recording events.
+    #[inline]
+    pub fn is_enabled(&self) -> bool {
+        runtime::is_enabled()
+    }
+
+    /// Enables or disables the recording of events in all threads.
+    #[inline]
+    pub fn set_enabled(&self, enabled: bool) {
+        runtime::set_enabled(enabled);
+    }
+
+    /// Removes all the events recorded in the current thread.
+    pub fn clear(&self) {
+        runtime::clear();
+    }
+
+    /// Writes the events recorded in the current thread in the Chrome trace event format,
+    /// which can be loaded in `about://tracing` or [Perfetto](https://ui.perfetto.dev).
+    ///
+    /// Only the most recent events are kept for the trace, about half a million of them, so
+    /// that long-running processes don't run out of memory.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to `writer` fails.
+    pub fn write_chrome_trace<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        runtime::write_chrome_trace(writer)
+    }
+
+    /// Summarizes the time spent in the events recorded in the current thread.
+    ///
+    /// The time of the events is aggregated as they are recorded, so the report includes the
+    /// events that were discarded from the trace.
+    pub fn report(&self) -> Report {
+        runtime::report()
+    }
 }
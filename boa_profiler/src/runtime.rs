@@ -0,0 +1,386 @@
+//! A lightweight profiler that can be enabled and disabled at runtime.
+//!
+//! Events are recorded per thread, since every `Context` is bound to the thread it was created
+//! in. The time of the events is aggregated as they end, so a [`Report`] covers every recorded
+//! event. The most recent events can also be exported in the Chrome [trace event format][format].
+//!
+//! [format]: https://docs.google.com/document/d/1CvAClvFfyA5R-PhYUmn5OOQtYMH4h6I0nSsKchNAySU
+
+use std::{
+    cell::RefCell,
+    collections::{HashMap, VecDeque},
+    fmt,
+    io::{self, Write},
+    sync::atomic::{AtomicBool, AtomicU64, Ordering},
+    time::{Duration, Instant},
+};
+
+/// Whether the profiler is currently recording events.
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Source of the identifiers given to the threads that record events.
+static NEXT_THREAD_ID: AtomicU64 = AtomicU64::new(1);
+
+/// The maximum number of begin and end events kept for the trace of a thread.
+///
+/// Older events are discarded once this is reached, so that profiling a long-running process
+/// doesn't use an unbounded amount of memory.
+const MAX_TRACE_EVENTS: usize = 1 << 20;
+
+thread_local! {
+    static RECORDER: RefCell<Recorder> = RefCell::new(Recorder::new());
+}
+
+/// Returns `true` if the profiler is currently recording events.
+#[inline]
+#[cfg_attr(feature = "profiler", allow(dead_code))]
+pub(crate) fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Enables or disables the recording of events.
+#[inline]
+#[cfg_attr(feature = "profiler", allow(dead_code))]
+pub(crate) fn set_enabled(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// Starts recording a new event in the current thread.
+#[inline]
+#[cfg_attr(feature = "profiler", allow(dead_code))]
+pub(crate) fn start_event(label: &str, category: &str) -> EventGuard {
+    if !is_enabled() {
+        return EventGuard { active: false };
+    }
+    RECORDER.with(|recorder| recorder.borrow_mut().begin(label, category));
+    EventGuard { active: true }
+}
+
+/// Removes all the events recorded in the current thread.
+#[cfg_attr(feature = "profiler", allow(dead_code))]
+pub(crate) fn clear() {
+    RECORDER.with(|recorder| recorder.borrow_mut().clear());
+}
+
+/// Writes the events recorded in the current thread in the Chrome trace event format.
+pub(crate) fn write_chrome_trace<W: Write>(writer: &mut W) -> io::Result<()> {
+    RECORDER.with(|recorder| recorder.borrow().write_chrome_trace(writer))
+}
+
+/// Aggregates the events recorded in the current thread.
+pub(crate) fn report() -> Report {
+    RECORDER.with(|recorder| recorder.borrow().report())
+}
+
+/// Guard of a recorded event, which ends the event when dropped.
+#[must_use = "the event ends as soon as the guard is dropped"]
+pub struct EventGuard {
+    active: bool,
+}
+
+impl Drop for EventGuard {
+    #[inline]
+    fn drop(&mut self) {
+        if self.active {
+            RECORDER.with(|recorder| recorder.borrow_mut().end());
+        }
+    }
+}
+
+impl fmt::Debug for EventGuard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EventGuard")
+            .field("active", &self.active)
+            .finish()
+    }
+}
+
+/// A begin or end event, with its timestamp relative to the creation of the recorder.
+#[derive(Debug, Clone, Copy)]
+enum Event {
+    Begin {
+        label: usize,
+        category: usize,
+        timestamp: Duration,
+    },
+    End {
+        timestamp: Duration,
+    },
+}
+
+/// The events recorded in a single thread.
+#[derive(Debug)]
+struct Recorder {
+    thread_id: u64,
+    epoch: Instant,
+    /// The most recent events, for the trace.
+    events: VecDeque<Event>,
+    strings: Vec<Box<str>>,
+    string_ids: HashMap<Box<str>, usize>,
+    /// The events that have begun but not ended yet, with their start time.
+    open: Vec<(usize, usize, Duration)>,
+    /// The number of open events of each category.
+    category_depths: HashMap<usize, usize>,
+    /// The count and the total time of the events with the same category and label.
+    totals: HashMap<(usize, usize), (u64, Duration)>,
+    /// The total time of each category.
+    category_totals: HashMap<usize, Duration>,
+}
+
+impl Recorder {
+    fn new() -> Self {
+        Self {
+            thread_id: NEXT_THREAD_ID.fetch_add(1, Ordering::Relaxed),
+            epoch: Instant::now(),
+            events: VecDeque::new(),
+            strings: Vec::new(),
+            string_ids: HashMap::new(),
+            open: Vec::new(),
+            category_depths: HashMap::new(),
+            totals: HashMap::new(),
+            category_totals: HashMap::new(),
+        }
+    }
+
+    /// Interns a label or a category, so that it is only stored once.
+    fn intern(&mut self, string: &str) -> usize {
+        if let Some(id) = self.string_ids.get(string) {
+            return *id;
+        }
+        let id = self.strings.len();
+        self.strings.push(string.into());
+        self.string_ids.insert(string.into(), id);
+        id
+    }
+
+    /// Adds an event to the trace, discarding the oldest event if the trace is full.
+    fn push_event(&mut self, event: Event) {
+        if self.events.len() == MAX_TRACE_EVENTS {
+            self.events.pop_front();
+        }
+        self.events.push_back(event);
+    }
+
+    fn begin(&mut self, label: &str, category: &str) {
+        let label = self.intern(label);
+        let category = self.intern(category);
+        let timestamp = self.epoch.elapsed();
+        self.open.push((label, category, timestamp));
+        *self.category_depths.entry(category).or_default() += 1;
+        self.push_event(Event::Begin {
+            label,
+            category,
+            timestamp,
+        });
+    }
+
+    fn end(&mut self) {
+        let timestamp = self.epoch.elapsed();
+        let (label, category, start) = match self.open.pop() {
+            Some(event) => event,
+            // The begin event was removed by `clear`.
+            None => return,
+        };
+
+        let time = timestamp.saturating_sub(start);
+        let total = self.totals.entry((category, label)).or_default();
+        total.0 += 1;
+        total.1 += time;
+
+        // Only the outermost events of a category count towards its total, so that nested
+        // events are not counted twice.
+        let depth = self.category_depths.entry(category).or_default();
+        *depth -= 1;
+        if *depth == 0 {
+            *self.category_totals.entry(category).or_default() += time;
+        }
+
+        self.push_event(Event::End { timestamp });
+    }
+
+    fn clear(&mut self) {
+        self.events.clear();
+        self.open.clear();
+        self.category_depths.clear();
+        self.totals.clear();
+        self.category_totals.clear();
+    }
+
+    fn write_chrome_trace<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        write!(writer, r#"{{"traceEvents":["#)?;
+        // The end events are emitted with the label of their begin event, which makes the
+        // trace easier to read for tools that don't match the events by their nesting.
+        let mut open = Vec::new();
+        let mut first = true;
+        for event in &self.events {
+            let (phase, label, category, timestamp) = match *event {
+                Event::Begin {
+                    label,
+                    category,
+                    timestamp,
+                } => {
+                    open.push((label, category));
+                    ("B", label, category, timestamp)
+                }
+                Event::End { timestamp } => match open.pop() {
+                    Some((label, category)) => ("E", label, category, timestamp),
+                    // The begin event was removed by `clear`, or discarded because the trace
+                    // was full.
+                    None => continue,
+                },
+            };
+            if !first {
+                write!(writer, ",")?;
+            }
+            first = false;
+            write!(writer, r#"{{"name":"#)?;
+            write_json_string(writer, &self.strings[label])?;
+            write!(writer, r#","cat":"#)?;
+            write_json_string(writer, &self.strings[category])?;
+            write!(
+                writer,
+                r#","ph":"{phase}","ts":{},"pid":1,"tid":{}}}"#,
+                timestamp.as_nanos() as f64 / 1000.0,
+                self.thread_id
+            )?;
+        }
+        write!(writer, "]}}")
+    }
+
+    fn report(&self) -> Report {
+        let mut categories: Vec<_> = self
+            .category_totals
+            .iter()
+            .map(|(&category, &time)| (self.strings[category].to_string(), time))
+            .collect();
+        categories.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+
+        let mut entries: Vec<_> = self
+            .totals
+            .iter()
+            .map(|(&(category, label), &(count, time))| ReportEntry {
+                category: self.strings[category].to_string(),
+                label: self.strings[label].to_string(),
+                count,
+                time,
+            })
+            .collect();
+        entries.sort_by(|a, b| {
+            a.category
+                .cmp(&b.category)
+                .then(b.time.cmp(&a.time))
+                .then_with(|| a.label.cmp(&b.label))
+        });
+        Report {
+            categories,
+            entries,
+        }
+    }
+}
+
+/// Writes `string` as a JSON string literal.
+fn write_json_string<W: Write>(writer: &mut W, string: &str) -> io::Result<()> {
+    write!(writer, "\"")?;
+    for c in string.chars() {
+        match c {
+            '"' => write!(writer, "\\\"")?,
+            '\\' => write!(writer, "\\\\")?,
+            c if c.is_control() => write!(writer, "\\u{:04x}", c as u32)?,
+            c => write!(writer, "{c}")?,
+        }
+    }
+    write!(writer, "\"")
+}
+
+/// Summary of the time spent in each profiled event.
+///
+/// The time of an event includes the time of all the events nested in it.
+#[derive(Debug, Clone, Default)]
+pub struct Report {
+    categories: Vec<(String, Duration)>,
+    entries: Vec<ReportEntry>,
+}
+
+impl Report {
+    /// Gets the entries of the report, sorted by category and then by descending time.
+    #[inline]
+    pub fn entries(&self) -> &[ReportEntry] {
+        &self.entries
+    }
+
+    /// Gets the categories of the report and the total time spent in each of them, sorted by
+    /// descending time.
+    ///
+    /// Events nested in an event of the same category are not counted twice.
+    #[inline]
+    pub fn categories(&self) -> &[(String, Duration)] {
+        &self.categories
+    }
+
+    /// Gets the total time spent in the events of a category.
+    pub fn category_time(&self, category: &str) -> Duration {
+        self.categories
+            .iter()
+            .find(|(name, _)| name == category)
+            .map(|(_, time)| *time)
+            .unwrap_or_default()
+    }
+
+    /// Returns `true` if no events were recorded.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{:<50} {:>12} {:>10}", "Event", "Time", "Count")?;
+        for (category, time) in &self.categories {
+            writeln!(f, "{category:<50} {time:>12.3?}")?;
+            for entry in self.entries.iter().filter(|e| &e.category == category) {
+                writeln!(
+                    f,
+                    "  {:<48} {:>12.3?} {:>10}",
+                    entry.label, entry.time, entry.count
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The time spent in all the events with the same category and label.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReportEntry {
+    category: String,
+    label: String,
+    count: u64,
+    time: Duration,
+}
+
+impl ReportEntry {
+    /// Gets the category of the events.
+    #[inline]
+    pub fn category(&self) -> &str {
+        &self.category
+    }
+
+    /// Gets the label of the events.
+    #[inline]
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Gets the number of times the events were recorded.
+    #[inline]
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Gets the total time spent in the events.
+    #[inline]
+    pub fn time(&self) -> Duration {
+        self.time
+    }
+}
@@ -2,7 +2,7 @@ use boa_engine::{
     builtins::JsArgs,
     object::{JsObject, ObjectInitializer},
     property::Attribute,
-    Context, JsResult, JsValue,
+    Context, JsNativeError, JsResult, JsValue,
 };
 
 /// Initializes the object in the context.
@@ -46,18 +46,16 @@ fn detach_array_buffer(
     context: &mut Context,
 ) -> JsResult<JsValue> {
     #[inline]
-    fn type_err(context: &mut Context) -> JsValue {
-        context.construct_type_error("The provided object was not an ArrayBuffer")
+    fn type_err() -> JsNativeError {
+        JsNativeError::typ().with_message("The provided object was not an ArrayBuffer")
     }
 
     let array_buffer = args
         .get(0)
         .and_then(JsValue::as_object)
-        .ok_or_else(|| type_err(context))?;
+        .ok_or_else(type_err)?;
     let mut array_buffer = array_buffer.borrow_mut();
-    let array_buffer = array_buffer
-        .as_array_buffer_mut()
-        .ok_or_else(|| type_err(context))?;
+    let array_buffer = array_buffer.as_array_buffer_mut().ok_or_else(type_err)?;
 
     // 1. Assert: IsSharedArrayBuffer(arrayBuffer) is false. TODO
     // 2. If key is not present, set key to undefined.
@@ -3,8 +3,8 @@
 mod js262;
 
 use super::{
-    Harness, Outcome, Phase, SuiteResult, Test, TestFlags, TestOutcomeResult, TestResult,
-    TestSuite, IGNORED,
+    Filter, Harness, Outcome, Phase, Progress, SuiteResult, Test, TestFlags, TestOutcomeResult,
+    TestResult, TestSuite, IGNORED,
 };
 use boa_engine::{
     builtins::JsArgs, object::FunctionBuilder, property::Attribute, syntax::Parser, Context,
@@ -16,38 +16,68 @@ use rayon::prelude::*;
 use std::panic;
 
 impl TestSuite {
+    /// Counts the runs of the tests of the suite selected by the filter.
+    pub(crate) fn count_runs(&self, filter: &Filter) -> usize {
+        let tests: usize = self
+            .tests
+            .iter()
+            .filter(|test| filter.matches(test))
+            .map(|test| test.modes().len())
+            .sum();
+        tests
+            + self
+                .suites
+                .iter()
+                .map(|suite| suite.count_runs(filter))
+                .sum::<usize>()
+    }
+
     /// Runs the test suite.
-    pub(crate) fn run(&self, harness: &Harness, verbose: u8, parallel: bool) -> SuiteResult {
+    pub(crate) fn run(
+        &self,
+        harness: &Harness,
+        verbose: u8,
+        parallel: bool,
+        filter: &Filter,
+        progress: &Progress,
+    ) -> SuiteResult {
         if verbose != 0 {
             println!("Suite {}:", self.name);
         }
 
-        let suites: Vec<_> = if parallel {
+        let mut suites: Vec<_> = if parallel {
             self.suites
                 .par_iter()
-                .map(|suite| suite.run(harness, verbose, parallel))
+                .map(|suite| suite.run(harness, verbose, parallel, filter, progress))
                 .collect()
         } else {
             self.suites
                 .iter()
-                .map(|suite| suite.run(harness, verbose, parallel))
+                .map(|suite| suite.run(harness, verbose, parallel, filter, progress))
                 .collect()
         };
 
+        // The suites without any of the selected tests are left out of the results.
+        if !filter.is_empty() {
+            suites.retain(|suite| suite.total != 0);
+        }
+
         let tests: Vec<_> = if parallel {
             self.tests
                 .par_iter()
-                .flat_map(|test| test.run(harness, verbose))
+                .filter(|test| filter.matches(test))
+                .flat_map(|test| test.run(harness, verbose, progress))
                 .collect()
         } else {
             self.tests
                 .iter()
-                .flat_map(|test| test.run(harness, verbose))
+                .filter(|test| filter.matches(test))
+                .flat_map(|test| test.run(harness, verbose, progress))
                 .collect()
         };
 
         let mut features = Vec::new();
-        for test_iter in self.tests.iter() {
+        for test_iter in self.tests.iter().filter(|test| filter.matches(test)) {
             for feature_iter in test_iter.features.iter() {
                 features.push(feature_iter.to_string());
             }
@@ -112,22 +142,41 @@ impl TestSuite {
 }
 
 impl Test {
-    /// Runs the test.
-    pub(crate) fn run(&self, harness: &Harness, verbose: u8) -> Vec<TestResult> {
-        let mut results = Vec::new();
+    /// Returns the modes the test runs in, as whether each run is in strict mode.
+    pub(crate) fn modes(&self) -> Vec<bool> {
+        let mut modes = Vec::new();
         if self.flags.contains(TestFlags::STRICT) && !self.flags.contains(TestFlags::RAW) {
-            results.push(self.run_once(harness, true, verbose));
+            modes.push(true);
         }
 
         if self.flags.contains(TestFlags::NO_STRICT) || self.flags.contains(TestFlags::RAW) {
-            results.push(self.run_once(harness, false, verbose));
+            modes.push(false);
         }
 
-        results
+        modes
+    }
+
+    /// Runs the test.
+    pub(crate) fn run(
+        &self,
+        harness: &Harness,
+        verbose: u8,
+        progress: &Progress,
+    ) -> Vec<TestResult> {
+        self.modes()
+            .into_iter()
+            .map(|strict| self.run_once(harness, strict, verbose, progress))
+            .collect()
     }
 
     /// Runs the test once, in strict or non-strict mode
-    fn run_once(&self, harness: &Harness, strict: bool, verbose: u8) -> TestResult {
+    fn run_once(
+        &self,
+        harness: &Harness,
+        strict: bool,
+        verbose: u8,
+        progress: &Progress,
+    ) -> TestResult {
         if verbose > 1 {
             println!(
                 "`{}`{}: starting",
@@ -181,7 +230,7 @@ impl Test {
                                 && matches!(*callback_obj.result.borrow(), Some(true) | None);
                             let text = match res {
                                 Ok(val) => val.display().to_string(),
-                                Err(e) => format!("Uncaught {}", e.display()),
+                                Err(e) => format!("Uncaught {e}"),
                             };
 
                             (passed, text)
@@ -226,6 +275,7 @@ impl Test {
                             Ok(_) => match context.eval(&test_content) {
                                 Ok(res) => (false, res.display().to_string()),
                                 Err(e) => {
+                                    let e = e.to_opaque(&mut context);
                                     let passed = e
                                         .display()
                                         .internals(true)
@@ -268,15 +318,6 @@ impl Test {
                         "⚠ Panic ⚠".red()
                     }
                 );
-            } else {
-                print!(
-                    "{}",
-                    if matches!(result, (TestOutcomeResult::Passed, _)) {
-                        ".".green()
-                    } else {
-                        ".".red()
-                    }
-                );
             }
 
             result
@@ -288,11 +329,10 @@ impl Test {
                     if strict { " (strict mode)" } else { "" },
                     "Ignored".yellow()
                 );
-            } else {
-                print!("{}", ".".yellow());
             }
             (TestOutcomeResult::Ignored, String::new())
         };
+        progress.record(result);
 
         if verbose > 2 {
             println!(
@@ -331,15 +371,15 @@ impl Test {
 
         context
             .eval(harness.assert.as_ref())
-            .map_err(|e| format!("could not run assert.js:\n{}", e.display()))?;
+            .map_err(|e| format!("could not run assert.js:\n{e}"))?;
         context
             .eval(harness.sta.as_ref())
-            .map_err(|e| format!("could not run sta.js:\n{}", e.display()))?;
+            .map_err(|e| format!("could not run sta.js:\n{e}"))?;
 
         if self.flags.contains(TestFlags::ASYNC) {
             context
                 .eval(harness.doneprint_handle.as_ref())
-                .map_err(|e| format!("could not run doneprintHandle.js:\n{}", e.display()))?;
+                .map_err(|e| format!("could not run doneprintHandle.js:\n{e}"))?;
         }
 
         for include in self.includes.iter() {
@@ -351,12 +391,7 @@ impl Test {
                         .ok_or_else(|| format!("could not find the {include} include file."))?
                         .as_ref(),
                 )
-                .map_err(|e| {
-                    format!(
-                        "could not run the {include} include file:\nUncaught {}",
-                        e.display()
-                    )
-                })?;
+                .map_err(|e| format!("could not run the {include} include file:\nUncaught {e}"))?;
         }
 
         Ok(())
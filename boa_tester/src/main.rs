@@ -64,10 +64,14 @@
 )]
 
 mod exec;
+mod progress;
 mod read;
 mod results;
+#[cfg(test)]
+mod tests;
 
 use self::{
+    progress::Progress,
     read::{read_harness, read_suite, read_test, MetaData, Negative, TestFlag},
     results::{compare_results, write_json},
 };
@@ -130,6 +134,56 @@ impl Default for Ignored {
     }
 }
 
+/// Filters selecting the tests to run, given in the command line.
+#[derive(Debug, Default)]
+struct Filter {
+    features: Vec<String>,
+    skip_features: Vec<String>,
+    path: Option<String>,
+    /// The Test262 directory, which the path filter is relative to.
+    test262_path: PathBuf,
+}
+
+impl Filter {
+    /// Checks if the filter doesn't exclude any test.
+    fn is_empty(&self) -> bool {
+        self.features.is_empty() && self.skip_features.is_empty() && self.path.is_none()
+    }
+
+    /// Checks if the given test should be run.
+    ///
+    /// A test is run if it uses one of the selected features, if any, doesn't use any of the
+    /// skipped features, and its path relative to the Test262 directory contains the path
+    /// filter.
+    pub(crate) fn matches(&self, test: &Test) -> bool {
+        let uses_any = |patterns: &[String]| {
+            patterns.iter().any(|pattern| {
+                test.features
+                    .iter()
+                    .any(|feature| feature_matches(pattern, feature))
+            })
+        };
+
+        (self.features.is_empty() || uses_any(&self.features))
+            && !uses_any(&self.skip_features)
+            && self.path.as_ref().map_or(true, |path| {
+                test.path
+                    .strip_prefix(&self.test262_path)
+                    .unwrap_or(&test.path)
+                    .to_string_lossy()
+                    .contains(path.as_str())
+            })
+    }
+}
+
+/// Checks if a feature matches a feature pattern, which is either the name of the feature or a
+/// prefix followed by `*`, like `Intl.*`.
+fn feature_matches(pattern: &str, feature: &str) -> bool {
+    pattern
+        .strip_suffix('*')
+        .map_or(pattern == feature, |prefix| feature.starts_with(prefix))
+}
+
 /// List of ignored tests.
 static IGNORED: Lazy<Ignored> = Lazy::new(|| {
     let path = Path::new("test_ignore.txt");
@@ -217,6 +271,21 @@ enum Cli {
         /// Execute tests serially
         #[clap(short, long)]
         disable_parallelism: bool,
+
+        /// Only run the tests that use one of the given features. A feature ending in `*`
+        /// matches any feature with that prefix, like `Intl.*`.
+        #[clap(long, value_name = "FEATURE", use_value_delimiter = true)]
+        features: Vec<String>,
+
+        /// Don't run the tests that use one of the given features. A feature ending in `*`
+        /// matches any feature with that prefix.
+        #[clap(long, value_name = "FEATURE", use_value_delimiter = true)]
+        skip_features: Vec<String>,
+
+        /// Only run the tests whose path relative to the Test262 directory contains the given
+        /// string, like "test/built-ins/Array".
+        #[clap(long, value_name = "PATH")]
+        filter: Option<String>,
     },
     Compare {
         /// Base results of the suite.
@@ -230,6 +299,10 @@ enum Cli {
         /// Whether to use markdown output
         #[clap(short, long)]
         markdown: bool,
+
+        /// Exit with a non-zero code if any test was broken or started panicking.
+        #[clap(long)]
+        fail_on_regression: bool,
     },
 }
 
@@ -242,13 +315,23 @@ fn main() {
             suite,
             output,
             disable_parallelism,
+            features,
+            skip_features,
+            filter,
         } => {
+            let filter = Filter {
+                features,
+                skip_features,
+                path: filter,
+                test262_path: test262_path.clone(),
+            };
             if let Err(e) = run_test_suite(
                 verbose,
                 !disable_parallelism,
                 test262_path.as_path(),
                 suite.as_path(),
                 output.as_deref(),
+                &filter,
             ) {
                 eprintln!("Error: {e}");
                 let mut src = e.source();
@@ -263,7 +346,13 @@ fn main() {
             base,
             new,
             markdown,
-        } => compare_results(base.as_path(), new.as_path(), markdown),
+            fail_on_regression,
+        } => {
+            let regressions = compare_results(base.as_path(), new.as_path(), markdown);
+            if fail_on_regression && regressions {
+                std::process::exit(1);
+            }
+        }
     }
 }
 
@@ -274,6 +363,7 @@ fn run_test_suite(
     test262_path: &Path,
     suite: &Path,
     output: Option<&Path>,
+    filter: &Filter,
 ) -> anyhow::Result<()> {
     if let Some(path) = output {
         if path.exists() {
@@ -299,7 +389,9 @@ fn run_test_suite(
         if verbose != 0 {
             println!("Test loaded, starting...");
         }
-        test.run(&harness, verbose);
+        let progress = Progress::new(test.modes().len(), verbose == 0);
+        test.run(&harness, verbose, &progress);
+        progress.finish();
 
         println!();
     } else {
@@ -311,7 +403,9 @@ fn run_test_suite(
         if verbose != 0 {
             println!("Test suite loaded, starting tests...");
         }
-        let results = suite.run(&harness, verbose, parallel);
+        let progress = Progress::new(suite.count_runs(filter), verbose == 0);
+        let results = suite.run(&harness, verbose, parallel, filter, &progress);
+        progress.finish();
 
         println!();
         println!("Results:");
@@ -409,6 +503,7 @@ enum TestOutcomeResult {
 #[allow(dead_code)]
 struct Test {
     name: Box<str>,
+    path: PathBuf,
     description: Box<str>,
     esid: Option<Box<str>>,
     flags: TestFlags,
@@ -423,13 +518,15 @@ struct Test {
 impl Test {
     /// Creates a new test.
     #[inline]
-    fn new<N, C>(name: N, content: C, metadata: MetaData) -> Self
+    fn new<N, P, C>(name: N, path: P, content: C, metadata: MetaData) -> Self
     where
         N: Into<Box<str>>,
+        P: Into<PathBuf>,
         C: Into<Box<str>>,
     {
         Self {
             name: name.into(),
+            path: path.into(),
             description: metadata.description,
             esid: metadata.esid,
             flags: metadata.flags.into(),
@@ -449,6 +546,14 @@ impl Test {
     {
         self.name = name.into();
     }
+
+    /// Sets the path of the test.
+    fn set_path<P>(&mut self, path: P)
+    where
+        P: Into<PathBuf>,
+    {
+        self.path = path.into();
+    }
 }
 
 /// An outcome for a test.
@@ -0,0 +1,138 @@
+//! Progress reporting of the test runner.
+
+use super::TestOutcomeResult;
+use colored::Colorize;
+use std::{
+    io::{self, Write},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex, PoisonError,
+    },
+    time::{Duration, Instant},
+};
+
+/// Width of the progress bar, in characters.
+const BAR_WIDTH: usize = 40;
+
+/// Minimum time between two redraws of the progress bar.
+const REDRAW_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Progress of a test run, drawn as a bar with the number of passed, failed and ignored tests.
+///
+/// Results can be recorded from the threads running the tests in parallel.
+#[derive(Debug)]
+pub(crate) struct Progress {
+    total: usize,
+    passed: AtomicUsize,
+    failed: AtomicUsize,
+    ignored: AtomicUsize,
+    panic: AtomicUsize,
+    visible: bool,
+    /// The time of the last redraw, if the bar has been drawn.
+    last_draw: Mutex<Option<Instant>>,
+}
+
+impl Progress {
+    /// Creates the progress of a run of `total` tests, which is only drawn if `visible` is set.
+    pub(crate) fn new(total: usize, visible: bool) -> Self {
+        Self {
+            total,
+            passed: AtomicUsize::new(0),
+            failed: AtomicUsize::new(0),
+            ignored: AtomicUsize::new(0),
+            panic: AtomicUsize::new(0),
+            visible,
+            last_draw: Mutex::new(None),
+        }
+    }
+
+    /// Records the result of a test, redrawing the bar if it hasn't been redrawn recently.
+    pub(crate) fn record(&self, result: TestOutcomeResult) {
+        match result {
+            TestOutcomeResult::Passed => &self.passed,
+            TestOutcomeResult::Failed => &self.failed,
+            TestOutcomeResult::Ignored => &self.ignored,
+            TestOutcomeResult::Panic => &self.panic,
+        }
+        .fetch_add(1, Ordering::Relaxed);
+
+        if !self.visible {
+            return;
+        }
+
+        // If another thread is drawing the bar, it will be drawn with this result soon enough.
+        if let Ok(mut last_draw) = self.last_draw.try_lock() {
+            let now = Instant::now();
+            if last_draw.map_or(true, |last| now - last >= REDRAW_INTERVAL) {
+                *last_draw = Some(now);
+                self.draw();
+            }
+        }
+    }
+
+    /// Draws the final state of the bar.
+    pub(crate) fn finish(&self) {
+        if self.visible {
+            let mut last_draw = self
+                .last_draw
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner);
+            *last_draw = Some(Instant::now());
+            self.draw();
+        }
+    }
+
+    /// Returns the number of tests with a recorded result.
+    pub(crate) fn completed(&self) -> usize {
+        self.passed() + self.failed() + self.ignored()
+    }
+
+    /// Returns the number of passed tests.
+    pub(crate) fn passed(&self) -> usize {
+        self.passed.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of failed tests, including the ones that panicked.
+    pub(crate) fn failed(&self) -> usize {
+        self.failed.load(Ordering::Relaxed) + self.panic()
+    }
+
+    /// Returns the number of ignored tests.
+    pub(crate) fn ignored(&self) -> usize {
+        self.ignored.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of tests that panicked.
+    pub(crate) fn panic(&self) -> usize {
+        self.panic.load(Ordering::Relaxed)
+    }
+
+    /// Draws the bar over the current line of the standard output.
+    fn draw(&self) {
+        let completed = self.completed();
+        let filled = (completed * BAR_WIDTH)
+            .checked_div(self.total)
+            .map_or(BAR_WIDTH, |filled| filled.min(BAR_WIDTH));
+        let panic = self.panic();
+
+        let stdout = io::stdout();
+        let mut stdout = stdout.lock();
+        // The progress is only informative, so failing to draw it shouldn't stop the run.
+        let _ = write!(
+            stdout,
+            "\r[{}{}] {completed}/{} passed: {}, failed: {} (panics: {}), ignored: {}",
+            "=".repeat(filled),
+            " ".repeat(BAR_WIDTH - filled),
+            self.total,
+            self.passed().to_string().green(),
+            self.failed().to_string().red(),
+            if panic == 0 {
+                "0".normal()
+            } else {
+                panic.to_string().red()
+            },
+            self.ignored().to_string().yellow(),
+        );
+        let _ = stdout.flush();
+    }
+}
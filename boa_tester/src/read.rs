@@ -140,6 +140,7 @@ pub(super) fn read_suite(path: &Path) -> anyhow::Result<TestSuite> {
         } else if IGNORED.contains_file(&entry.file_name().to_string_lossy()) {
             let mut test = Test::default();
             test.set_name(entry.file_name().to_string_lossy());
+            test.set_path(entry.path());
             tests.push(test);
         } else {
             tests.push(read_test(entry.path().as_path()).with_context(|| {
@@ -178,7 +179,7 @@ pub(super) fn read_test(path: &Path) -> io::Result<Test> {
     let content = fs::read_to_string(path)?;
     let metadata = read_metadata(&content, path)?;
 
-    Ok(Test::new(name, content, metadata))
+    Ok(Test::new(name, path, content, metadata))
 }
 
 /// Reads the metadata from the input test code.
@@ -208,7 +208,9 @@ fn update_gh_pages_repo(path: &Path, verbose: u8) {
 }
 
 /// Compares the results of two test suite runs.
-pub(crate) fn compare_results(base: &Path, new: &Path, markdown: bool) {
+///
+/// Returns whether any test was broken or started panicking.
+pub(crate) fn compare_results(base: &Path, new: &Path, markdown: bool) -> bool {
     let base_results: ResultInfo = serde_json::from_reader(BufReader::new(
         fs::File::open(base).expect("could not open the base results file"),
     ))
@@ -244,6 +246,7 @@ pub(crate) fn compare_results(base: &Path, new: &Path, markdown: bool) {
     let conformance_diff = new_conformance - base_conformance;
 
     let test_diff = compute_result_diff(Path::new(""), &base_results.results, &new_results.results);
+    let regressions = !test_diff.broken.is_empty() || !test_diff.new_panics.is_empty();
 
     if markdown {
         /// Simple function to add commas as thousands separator for integers.
@@ -329,9 +332,7 @@ pub(crate) fn compare_results(base: &Path, new: &Path, markdown: bool) {
                 test_diff.fixed.len()
             );
             println!("\n```");
-            for test in test_diff.fixed {
-                println!("{test}");
-            }
+            print_grouped(&test_diff.fixed);
             println!("```");
             println!("</details>");
         }
@@ -343,9 +344,7 @@ pub(crate) fn compare_results(base: &Path, new: &Path, markdown: bool) {
                 test_diff.broken.len()
             );
             println!("\n```");
-            for test in test_diff.broken {
-                println!("{test}");
-            }
+            print_grouped(&test_diff.broken);
             println!("```");
             println!("</details>");
         }
@@ -357,9 +356,7 @@ pub(crate) fn compare_results(base: &Path, new: &Path, markdown: bool) {
                 test_diff.new_panics.len()
             );
             println!("\n```");
-            for test in test_diff.new_panics {
-                println!("{test}");
-            }
+            print_grouped(&test_diff.new_panics);
             println!("```");
             println!("</details>");
         }
@@ -371,9 +368,7 @@ pub(crate) fn compare_results(base: &Path, new: &Path, markdown: bool) {
                 test_diff.panic_fixes.len()
             );
             println!("\n```");
-            for test in test_diff.panic_fixes {
-                println!("{test}");
-            }
+            print_grouped(&test_diff.panic_fixes);
             println!("```");
             println!("</details>");
         }
@@ -400,44 +395,59 @@ pub(crate) fn compare_results(base: &Path, new: &Path, markdown: bool) {
         if !test_diff.fixed.is_empty() {
             println!();
             println!("Fixed tests ({}):", test_diff.fixed.len());
-            for test in test_diff.fixed {
-                println!("{test}");
-            }
+            print_grouped(&test_diff.fixed);
         }
 
         if !test_diff.broken.is_empty() {
             println!();
             println!("Broken tests ({}):", test_diff.broken.len());
-            for test in test_diff.broken {
-                println!("{test}");
-            }
+            print_grouped(&test_diff.broken);
         }
 
         if !test_diff.new_panics.is_empty() {
             println!();
             println!("New panics ({}):", test_diff.new_panics.len());
-            for test in test_diff.new_panics {
-                println!("{test}");
-            }
+            print_grouped(&test_diff.new_panics);
         }
 
         if !test_diff.panic_fixes.is_empty() {
             println!();
             println!("Fixed panics ({}):", test_diff.panic_fixes.len());
-            for test in test_diff.panic_fixes {
-                println!("{test}");
-            }
+            print_grouped(&test_diff.panic_fixes);
         }
     }
+
+    regressions
+}
+
+/// Prints a list of changed tests, grouped by their directory.
+fn print_grouped(tests: &[ChangedTest]) {
+    let mut directory = None;
+    for test in tests {
+        if directory != Some(&test.directory) {
+            directory = Some(&test.directory);
+            println!("{}:", test.directory);
+        }
+        println!("    {}", test.description);
+    }
+}
+
+/// A test whose result changed between two runs.
+#[derive(Debug, Clone)]
+struct ChangedTest {
+    /// The directory of the test.
+    directory: Box<str>,
+    /// The file name of the test, with its mode and previous result.
+    description: Box<str>,
 }
 
 /// Test differences.
 #[derive(Debug, Clone, Default)]
 struct ResultDiff {
-    fixed: Vec<Box<str>>,
-    broken: Vec<Box<str>>,
-    new_panics: Vec<Box<str>>,
-    panic_fixes: Vec<Box<str>>,
+    fixed: Vec<ChangedTest>,
+    broken: Vec<ChangedTest>,
+    new_panics: Vec<ChangedTest>,
+    panic_fixes: Vec<ChangedTest>,
 }
 
 impl ResultDiff {
@@ -466,18 +476,20 @@ fn compute_result_diff(
             .iter()
             .find(|new_test| new_test.strict == base_test.strict && new_test.name == base_test.name)
         {
-            let test_name = format!(
-                "test/{}/{}.js {}(previously {:?})",
-                base.display(),
-                new_test.name,
-                if base_test.strict {
-                    "[strict mode] "
-                } else {
-                    ""
-                },
-                base_test.result
-            )
-            .into_boxed_str();
+            let test_name = ChangedTest {
+                directory: format!("test/{}", base.display()).into_boxed_str(),
+                description: format!(
+                    "{}.js {}(previously {:?})",
+                    new_test.name,
+                    if base_test.strict {
+                        "[strict mode] "
+                    } else {
+                        ""
+                    },
+                    base_test.result
+                )
+                .into_boxed_str(),
+            };
 
             #[allow(clippy::match_same_arms)]
             match (base_test.result, new_test.result) {
@@ -0,0 +1,151 @@
+//! Tests for the test selection and progress reporting of the runner.
+
+use super::{feature_matches, Filter, Progress, Test, TestFlags, TestOutcomeResult, TestSuite};
+use std::path::PathBuf;
+
+/// Creates a test in the given path of the Test262 directory, using the given features.
+fn test(path: &str, features: &[&str]) -> Test {
+    Test {
+        name: path.rsplit('/').next().unwrap_or(path).into(),
+        path: PathBuf::from("/src/test262").join(path),
+        features: features.iter().map(|&feature| feature.into()).collect(),
+        ..Test::default()
+    }
+}
+
+/// Creates a filter relative to the Test262 directory of [`test`].
+fn new_filter(features: &[&str], skip_features: &[&str], path: Option<&str>) -> Filter {
+    Filter {
+        features: features.iter().map(|&feature| feature.to_owned()).collect(),
+        skip_features: skip_features
+            .iter()
+            .map(|&feature| feature.to_owned())
+            .collect(),
+        path: path.map(str::to_owned),
+        test262_path: PathBuf::from("/src/test262"),
+    }
+}
+
+#[test]
+fn feature_patterns() {
+    assert!(feature_matches("BigInt", "BigInt"));
+    assert!(!feature_matches("BigInt", "BigInt64Array"));
+    assert!(!feature_matches("Big", "BigInt"));
+
+    assert!(feature_matches("Intl.*", "Intl.DateTimeFormat"));
+    assert!(feature_matches("Intl.*", "Intl."));
+    assert!(!feature_matches("Intl.*", "Intl"));
+    assert!(feature_matches("*", "Symbol"));
+}
+
+#[test]
+fn empty_filter() {
+    let filter = new_filter(&[], &[], None);
+    assert!(filter.is_empty());
+    assert!(filter.matches(&test("test/language/types/number/S8.5_A1.js", &[])));
+    assert!(filter.matches(&test("test/built-ins/BigInt/asIntN.js", &["BigInt"])));
+}
+
+#[test]
+fn selected_features() {
+    let filter = new_filter(&["BigInt", "Intl.*"], &[], None);
+    assert!(!filter.is_empty());
+
+    assert!(filter.matches(&test("test/built-ins/BigInt/asIntN.js", &["BigInt"])));
+    assert!(filter.matches(&test(
+        "test/intl402/DateTimeFormat/constructor.js",
+        &["Symbol", "Intl.DateTimeFormat-datetimestyle"]
+    )));
+    assert!(!filter.matches(&test("test/built-ins/Symbol/for.js", &["Symbol"])));
+    assert!(!filter.matches(&test("test/language/types/number/S8.5_A1.js", &[])));
+}
+
+#[test]
+fn skipped_features() {
+    let filter = new_filter(&[], &["Intl.*"], None);
+    assert!(!filter.is_empty());
+
+    assert!(filter.matches(&test("test/built-ins/Symbol/for.js", &["Symbol"])));
+    assert!(filter.matches(&test("test/language/types/number/S8.5_A1.js", &[])));
+    assert!(!filter.matches(&test(
+        "test/intl402/DateTimeFormat/constructor.js",
+        &["Symbol", "Intl.DateTimeFormat-datetimestyle"]
+    )));
+
+    // Skipped features take precedence over the selected ones.
+    let filter = new_filter(&["Symbol"], &["Intl.*"], None);
+    assert!(filter.matches(&test("test/built-ins/Symbol/for.js", &["Symbol"])));
+    assert!(!filter.matches(&test(
+        "test/intl402/DateTimeFormat/constructor.js",
+        &["Symbol", "Intl.DateTimeFormat-datetimestyle"]
+    )));
+}
+
+#[test]
+fn path_filter_is_relative_to_test262() {
+    let array = test("test/built-ins/Array/from/source.js", &[]);
+    let string = test("test/built-ins/String/raw/length.js", &[]);
+
+    let filter = new_filter(&[], &[], Some("test/built-ins/Array"));
+    assert!(!filter.is_empty());
+    assert!(filter.matches(&array));
+    assert!(!filter.matches(&string));
+
+    // Parts of the path of the Test262 directory don't match any test.
+    let filter = new_filter(&[], &[], Some("src/test262"));
+    assert!(!filter.matches(&array));
+    assert!(!filter.matches(&string));
+
+    let filter = new_filter(&[], &[], Some("raw/"));
+    assert!(!filter.matches(&array));
+    assert!(filter.matches(&string));
+}
+
+#[test]
+fn count_runs() {
+    let only_strict = Test {
+        flags: TestFlags::STRICT,
+        ..test("test/built-ins/Array/from/source.js", &["Symbol"])
+    };
+    let raw = Test {
+        flags: TestFlags::STRICT | TestFlags::RAW,
+        ..test("test/built-ins/Array/of/source.js", &[])
+    };
+    let both = test("test/built-ins/String/raw/length.js", &["Symbol"]);
+    assert_eq!(only_strict.modes(), [true]);
+    assert_eq!(raw.modes(), [false]);
+    assert_eq!(both.modes(), [true, false]);
+
+    let suite = TestSuite {
+        name: "test".into(),
+        suites: Box::new([TestSuite {
+            name: "String".into(),
+            suites: Box::new([]),
+            tests: Box::new([both]),
+        }]),
+        tests: Box::new([only_strict, raw]),
+    };
+    assert_eq!(suite.count_runs(&new_filter(&[], &[], None)), 4);
+    assert_eq!(suite.count_runs(&new_filter(&["Symbol"], &[], None)), 3);
+    assert_eq!(suite.count_runs(&new_filter(&[], &[], Some("String"))), 2);
+}
+
+#[test]
+fn progress_counts() {
+    let progress = Progress::new(5, false);
+    for result in [
+        TestOutcomeResult::Passed,
+        TestOutcomeResult::Passed,
+        TestOutcomeResult::Failed,
+        TestOutcomeResult::Panic,
+        TestOutcomeResult::Ignored,
+    ] {
+        progress.record(result);
+    }
+
+    assert_eq!(progress.completed(), 5);
+    assert_eq!(progress.passed(), 2);
+    assert_eq!(progress.failed(), 2);
+    assert_eq!(progress.panic(), 1);
+    assert_eq!(progress.ignored(), 1);
+}
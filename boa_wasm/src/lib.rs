@@ -59,14 +59,159 @@
     rustdoc::missing_doc_code_examples
 )]
 
-use boa_engine::Context;
+use boa_engine::{Context, JsResult, JsValue as BoaValue};
+use js_sys::{Array, Object, Reflect};
+use serde_json::Value as Json;
 use wasm_bindgen::prelude::*;
 
+/// Evaluates the given script in a new context, and returns the displayed result.
+///
+/// Use [`WasmContext`] to evaluate several scripts against the same context, and to get the
+/// results as JavaScript values.
 #[wasm_bindgen]
 pub fn evaluate(src: &str) -> Result<String, JsValue> {
     // Setup executor
     Context::default()
         .eval(src)
-        .map_err(|e| JsValue::from(format!("Uncaught {}", e.display())))
+        .map_err(|e| JsValue::from(format!("Uncaught {e}")))
         .map(|v| v.display().to_string())
 }
+
+/// A Boa context that keeps its global state between evaluations.
+///
+/// Every evaluation returns an object with an `ok` property. Successful evaluations have the
+/// evaluated value in the `value` property, and failed evaluations have the thrown error in the
+/// `error` property, as an object with the `name`, `message` and `stack` of the error, and the
+/// thrown `value` itself.
+///
+/// Values are converted through their JSON representation where possible. Values that cannot be
+/// represented as JSON, like functions or cyclic objects, are converted to their displayed string.
+#[wasm_bindgen(js_name = Context)]
+#[derive(Debug)]
+pub struct WasmContext {
+    context: Context,
+}
+
+#[wasm_bindgen(js_class = Context)]
+impl WasmContext {
+    /// Creates a new context.
+    #[wasm_bindgen(constructor)]
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self {
+            context: Context::default(),
+        }
+    }
+
+    /// Evaluates a script in the context.
+    ///
+    /// The jobs queued by the script, like the reactions of its promises, are run before
+    /// returning.
+    pub fn eval(&mut self, src: &str) -> JsValue {
+        let result = self.context.eval(src);
+        self.completion(result)
+    }
+
+    /// Runs the jobs in the job queue of the context.
+    #[wasm_bindgen(js_name = runJobs)]
+    pub fn run_jobs(&mut self) -> JsValue {
+        let result = self.context.run_jobs().map(|()| BoaValue::undefined());
+        self.completion(result)
+    }
+
+    /// Destroys the context, releasing all of its memory.
+    ///
+    /// The context cannot be used after this.
+    pub fn destroy(self) {}
+
+    /// Converts the result of an evaluation to the object returned to JavaScript.
+    fn completion(&mut self, result: JsResult<BoaValue>) -> JsValue {
+        let completion = Object::new();
+        match result {
+            Ok(value) => {
+                set(&completion, "ok", &true.into());
+                set(&completion, "value", &self.value_to_js(&value));
+            }
+            Err(error) => {
+                let error = error.to_opaque(&mut self.context);
+                set(&completion, "ok", &false.into());
+                set(&completion, "error", &self.error_to_js(&error));
+            }
+        }
+        completion.into()
+    }
+
+    /// Converts a thrown value to an object with its `name`, `message` and `stack`.
+    fn error_to_js(&mut self, error: &BoaValue) -> JsValue {
+        let js_error = Object::new();
+        if let Some(object) = error.as_object() {
+            let mut property = |key: &str| -> Option<String> {
+                let value = object.get(key, &mut self.context).ok()?;
+                if value.is_undefined() {
+                    return None;
+                }
+                Some(value.to_string(&mut self.context).ok()?.as_str().to_owned())
+            };
+            let name = property("name");
+            let message = property("message").unwrap_or_default();
+            let stack = property("stack");
+
+            set(
+                &js_error,
+                "name",
+                &name.map_or(JsValue::UNDEFINED, Into::into),
+            );
+            set(&js_error, "message", &message.into());
+            set(
+                &js_error,
+                "stack",
+                &stack.map_or(JsValue::UNDEFINED, Into::into),
+            );
+        } else {
+            set(&js_error, "name", &JsValue::UNDEFINED);
+            set(&js_error, "message", &error.display().to_string().into());
+            set(&js_error, "stack", &JsValue::UNDEFINED);
+        }
+        set(&js_error, "value", &self.value_to_js(error));
+        js_error.into()
+    }
+
+    /// Converts a value of the context to a JavaScript value.
+    fn value_to_js(&mut self, value: &BoaValue) -> JsValue {
+        match value {
+            BoaValue::Undefined => JsValue::UNDEFINED,
+            BoaValue::BigInt(bigint) => js_sys::BigInt::new(&bigint.to_string().into())
+                .map_or_else(|_| bigint.to_string().into(), Into::into),
+            BoaValue::Symbol(_) => value.display().to_string().into(),
+            BoaValue::Object(object) if object.is_callable() => value.display().to_string().into(),
+            _ => value.to_json(&mut self.context).map_or_else(
+                |_| value.display().to_string().into(),
+                |json| json_to_js(&json),
+            ),
+        }
+    }
+}
+
+/// Converts a JSON value to a JavaScript value.
+fn json_to_js(json: &Json) -> JsValue {
+    match json {
+        Json::Null => JsValue::NULL,
+        Json::Bool(b) => (*b).into(),
+        Json::Number(num) => num.as_f64().unwrap_or(f64::NAN).into(),
+        Json::String(string) => string.into(),
+        Json::Array(vec) => vec.iter().map(json_to_js).collect::<Array>().into(),
+        Json::Object(map) => {
+            let object = Object::new();
+            for (key, value) in map {
+                set(&object, key, &json_to_js(value));
+            }
+            object.into()
+        }
+    }
+}
+
+/// Sets a property of an object created by the bindings.
+fn set(object: &Object, key: &str, value: &JsValue) {
+    // Setting a property of an ordinary object only fails if the object is frozen.
+    let _ = Reflect::set(object, &key.into(), value);
+}
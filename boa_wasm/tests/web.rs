@@ -0,0 +1,79 @@
+//! Tests of the bindings, run with `wasm-pack test --node boa_wasm`.
+
+#![cfg(target_arch = "wasm32")]
+
+use boa_wasm::WasmContext;
+use js_sys::{Array, Reflect};
+use wasm_bindgen::JsValue;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+fn get(object: &JsValue, key: &str) -> JsValue {
+    Reflect::get(object, &key.into()).expect("the property should be readable")
+}
+
+#[wasm_bindgen_test]
+fn value_conversion() {
+    let mut context = WasmContext::new();
+
+    let result = context.eval("({ name: 'boa', version: [0, 15], ok: true, none: null })");
+    assert_eq!(get(&result, "ok"), JsValue::TRUE);
+    let value = get(&result, "value");
+    assert_eq!(get(&value, "name"), "boa");
+    assert_eq!(get(&value, "ok"), JsValue::TRUE);
+    assert_eq!(get(&value, "none"), JsValue::NULL);
+    let version = Array::from(&get(&value, "version"));
+    assert_eq!(version.length(), 2);
+    assert_eq!(version.get(1), 15);
+
+    assert_eq!(get(&context.eval("1.5"), "value"), 1.5);
+    assert_eq!(get(&context.eval("undefined"), "value"), JsValue::UNDEFINED);
+    assert!(get(&context.eval("10n ** 20n"), "value").is_bigint());
+    assert!(get(&context.eval("(function f() {})"), "value").is_string());
+}
+
+#[wasm_bindgen_test]
+fn persistent_context() {
+    let mut context = WasmContext::new();
+
+    context.eval("var counter = 1;");
+    context.eval("counter += 1;");
+    assert_eq!(get(&context.eval("counter"), "value"), 2);
+
+    let other = WasmContext::new();
+    other.destroy();
+}
+
+#[wasm_bindgen_test]
+fn promises() {
+    let mut context = WasmContext::new();
+
+    context.eval("var settled; Promise.resolve(42).then((value) => { settled = value; });");
+    assert_eq!(get(&context.eval("settled"), "value"), 42);
+    assert_eq!(get(&context.run_jobs(), "ok"), JsValue::TRUE);
+}
+
+#[wasm_bindgen_test]
+fn error_reporting() {
+    let mut context = WasmContext::new();
+
+    let result = context.eval("null.property");
+    assert_eq!(get(&result, "ok"), JsValue::FALSE);
+    let error = get(&result, "error");
+    assert_eq!(get(&error, "name"), "TypeError");
+    assert!(get(&error, "message").is_string());
+
+    let error = get(
+        &context.eval("throw new RangeError('out of range')"),
+        "error",
+    );
+    assert_eq!(get(&error, "name"), "RangeError");
+    assert_eq!(get(&error, "message"), "out of range");
+
+    let error = get(&context.eval("let a = ;"), "error");
+    assert_eq!(get(&error, "name"), "SyntaxError");
+
+    let error = get(&context.eval("throw 42"), "error");
+    assert_eq!(get(&error, "name"), JsValue::UNDEFINED);
+    assert_eq!(get(&error, "message"), "42");
+    assert_eq!(get(&error, "value"), 42);
+}
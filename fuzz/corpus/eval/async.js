@@ -0,0 +1,5 @@
+let log = [];
+Promise.resolve(1).then(v => log.push(v)).catch(() => {}).finally(() => log.push("done"));
+async function f() { await null; throw new TypeError("rejected"); }
+f().catch(e => log.push(e.message));
+new Promise((_, reject) => reject(2));
@@ -0,0 +1,5 @@
+const a = [3, 1, 2].sort().map(x => x * 2).filter(Boolean);
+const o = Object.assign({}, { a }, JSON.parse('{"b": [1, 2]}'));
+JSON.stringify(o) + String(new Date(0).getTime()) + Math.max(...a);
+new Map([[1, 2]]).get(1) + new Set("abc").size + Symbol("s").toString();
+"abc".replace(/b/g, "$&$&").padStart(10, "-").split("");
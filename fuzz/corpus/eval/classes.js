@@ -0,0 +1,4 @@
+class A { #v = 1; get v() { return this.#v; } static create() { return new this(); } }
+class B extends A { constructor() { super(); this.w = super.v; } }
+const b = B.create();
+[b.v, b.w, b instanceof A, Object.getPrototypeOf(B) === A];
@@ -0,0 +1,6 @@
+let total = 0;
+for (let i = 0; i < 100; i++) { try { if (i % 3) throw i; } catch (e) { total += e; } finally { total--; } }
+function* g() { let i = 0; while (true) yield i++; }
+for (const x of g()) { if (x > 10) break; total += x; }
+label: while (true) { do { break label; } while (true); }
+total;
@@ -0,0 +1,10 @@
+class A extends B {
+  #x = 1;
+  static y = 2;
+  static { this.z = 3; }
+  constructor() { super(); }
+  get x() { return this.#x; }
+  set x(v) { this.#x = v; }
+  *[Symbol.iterator]() {}
+  m() { return super.m(); }
+}
@@ -0,0 +1,4 @@
+a = b ? c : (d ?? e) || f && g | h ^ i & j == k < l << m + n * o ** p;
+x ||= y; x &&= z; x ??= w; x **= 2; x >>>= 1;
+o.a[b](c); new A.b(...c); typeof void delete a[b]; -(-a); !(a && b);
+[, a, ...b, ,]; ({ a, [b]: c, "d e": f, ...g });
@@ -0,0 +1,6 @@
+function f(a, b = 1, ...c) { return a; }
+function* g() { yield 1; yield* [2, 3]; }
+async function h() { await i; }
+async function* j() {}
+const k = (a, { b }, [c]) => a + b + c;
+(function () { return this; })();
@@ -0,0 +1,3 @@
+"double \" quote"; 'single \' quote'; "\x41A\u{41}\n\t\\";
+`template ${a} with \` and \${} ${`nested ${b}`}`; tag`raw ${c}`;
+0x1F; 0o17; 0b101; 1_000; .5e-3; 10n; /re[g]ex?/gimsuy;
@@ -0,0 +1,5 @@
+label: for (let i = 0; i < 10; i++) { if (i) continue label; else break; }
+for (const [k, v] of entries) {} for (var p in obj) {} do x--; while (x)
+switch (x) { case 1: y++; default: --z; }
+try { a() } catch ({ message }) {} finally { b() }
+while (false) ; throw new Error("unreachable");
@@ -0,0 +1,54 @@
+//! Evaluates the input with tight resource limits, which must end every evaluation with a value
+//! or an error, without panicking or running forever.
+
+#![no_main]
+
+use boa_engine::{context::ResourceLimits, syntax::Parser, Context};
+use libfuzzer_sys::fuzz_target;
+use std::thread;
+
+/// The maximum nesting depth of the parsed code.
+///
+/// The frames of instrumented builds are bigger, so this is lower than the default limit.
+const RECURSION_LIMIT: usize = 64;
+
+/// The maximum number of instructions of an evaluation.
+///
+/// This also bounds the depth of the recursive calls.
+const MAX_INSTRUCTIONS: u64 = 10_000;
+
+/// The size of the stack of the thread that runs the evaluation.
+///
+/// Every call is run in a new native stack frame, so this must be big enough for the deepest
+/// recursion that fits in the instruction limit.
+const STACK_SIZE: usize = 256 * 1024 * 1024;
+
+fn eval(data: &[u8]) {
+    let mut limits = ResourceLimits::default();
+    limits.set_max_instructions(MAX_INSTRUCTIONS);
+    limits.set_max_array_length(10_000);
+    limits.set_max_string_length(10_000);
+    let mut context = Context::builder().resource_limits(limits).build();
+
+    let mut parser = Parser::new(data);
+    parser.set_recursion_limit(RECURSION_LIMIT);
+    let statement_list = match parser.parse_all(&mut context) {
+        Ok(statement_list) => statement_list,
+        Err(_) => return,
+    };
+    if let Ok(code_block) = context.compile(&statement_list) {
+        let _ = context.execute(code_block);
+    }
+}
+
+fuzz_target!(|data: &[u8]| {
+    let data = data.to_vec();
+    let result = thread::Builder::new()
+        .stack_size(STACK_SIZE)
+        .spawn(move || eval(&data))
+        .expect("could not spawn the evaluation thread")
+        .join();
+    if let Err(panic) = result {
+        std::panic::resume_unwind(panic);
+    }
+});
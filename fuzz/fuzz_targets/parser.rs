@@ -0,0 +1,27 @@
+//! Feeds arbitrary bytes to the lexer and the parser, which must reject invalid input with an
+//! error instead of panicking.
+
+#![no_main]
+
+use boa_engine::{
+    syntax::{Lexer, Parser},
+    Context,
+};
+use boa_interner::Interner;
+use libfuzzer_sys::fuzz_target;
+
+/// The maximum nesting depth of the parsed code.
+///
+/// The frames of instrumented builds are bigger, so this is lower than the default limit.
+const RECURSION_LIMIT: usize = 64;
+
+fuzz_target!(|data: &[u8]| {
+    let mut interner = Interner::default();
+    let mut lexer = Lexer::new(data);
+    while let Ok(Some(_)) = lexer.next(&mut interner) {}
+
+    let mut context = Context::default();
+    let mut parser = Parser::new(data);
+    parser.set_recursion_limit(RECURSION_LIMIT);
+    let _ = parser.parse_all(&mut context);
+});
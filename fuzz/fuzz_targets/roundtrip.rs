@@ -0,0 +1,43 @@
+//! Parses the input, prints the resulting AST and parses the printed code again, which must give
+//! back the same code when it is printed.
+
+#![no_main]
+
+use boa_engine::{
+    syntax::{ast::node::StatementList, parser::ParseError, Parser},
+    Context,
+};
+use boa_interner::ToInternedString;
+use libfuzzer_sys::fuzz_target;
+
+/// The maximum nesting depth of the parsed code.
+///
+/// The frames of instrumented builds are bigger, so this is lower than the default limit.
+const RECURSION_LIMIT: usize = 64;
+
+/// Parses some code with the recursion limit of the fuzzer.
+fn parse(src: &[u8], context: &mut Context) -> Result<StatementList, ParseError> {
+    let mut parser = Parser::new(src);
+    parser.set_recursion_limit(RECURSION_LIMIT);
+    parser.parse_all(context)
+}
+
+fuzz_target!(|data: &[u8]| {
+    let mut context = Context::default();
+    let statement_list = match parse(data, &mut context) {
+        Ok(statement_list) => statement_list,
+        Err(_) => return,
+    };
+    let printed = statement_list.to_interned_string(context.interner());
+
+    // The parentheses added by the printer can make the printed code nest deeper than the input.
+    let mut parser = Parser::new(printed.as_bytes());
+    parser.set_recursion_limit(RECURSION_LIMIT * 2);
+    let reparsed = match parser.parse_all(&mut context) {
+        Ok(statement_list) => statement_list,
+        Err(err) => panic!("the printed code could not be parsed: {err}\n{printed}"),
+    };
+    let reprinted = reparsed.to_interned_string(context.interner());
+
+    assert_eq!(printed, reprinted, "the printed code changed after parsing it");
+});